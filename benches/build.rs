@@ -0,0 +1,42 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use wot_td::{
+    builder::{
+        affordance::BuildableInteractionAffordance,
+        data_schema::{ObjectDataSchemaBuilderLike, SpecializableDataSchema},
+        ThingBuilder,
+    },
+    hlist::Nil,
+    Thing,
+};
+
+const PROPERTIES: usize = 1000;
+const OBJECT_PROPERTIES: usize = 20;
+
+fn build_thing() -> Thing {
+    (0..PROPERTIES)
+        .fold(
+            ThingBuilder::<Nil, _>::new("Large thing").finish_extend(),
+            |builder, index| {
+                builder.property(format!("property{index}"), |b| {
+                    (0..OBJECT_PROPERTIES)
+                        .fold(b.finish_extend_data_schema().object(), |b, index| {
+                            b.property(format!("field{index}"), index % 2 == 0, |b| {
+                                b.finish_extend().integer()
+                            })
+                        })
+                        .form(|b| b.href(format!("/properties/{index}")))
+                })
+            },
+        )
+        .build()
+        .unwrap()
+}
+
+fn build(c: &mut Criterion) {
+    c.bench_function("build 1000 object properties", |b| {
+        b.iter(|| black_box(build_thing()))
+    });
+}
+
+criterion_group!(benches, build);
+criterion_main!(benches);
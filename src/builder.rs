@@ -206,22 +206,32 @@ pub mod affordance;
 pub mod data_schema;
 mod human_readable_info;
 
-use alloc::{borrow::ToOwned, fmt, string::*, vec, vec::Vec};
-use core::{marker::PhantomData, ops::Not};
+use alloc::{borrow::ToOwned, boxed::Box, fmt, format, string::*, vec, vec::Vec};
+use core::{any::type_name, marker::PhantomData, ops::Not};
 
 use hashbrown::{hash_map::Entry, HashMap};
 use oxilangtag::LanguageTag;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use serde_with::{serde_as, skip_serializing_none};
 use time::OffsetDateTime;
 
 use crate::{
-    extend::{Extend, Extendable, ExtendableThing},
+    extend::{Extend, Extendable, ExtendableThing, ExtendableThingFields, ExtensionFields},
+    lint::{LintConfig, LintWarning},
+    template::TemplateError,
     thing::{
-        AdditionalExpectedResponse, ComboSecurityScheme, DataSchemaFromOther,
-        DefaultedFormOperations, ExpectedResponse, Form, FormOperation, KnownSecuritySchemeSubtype,
-        Link, SecurityScheme, SecuritySchemeSubtype, Thing, UnknownSecuritySchemeSubtype,
-        VersionInfo, TD_CONTEXT_11,
+        check_content_encodings, default_ops_for, format_at_types, parse_at_types,
+        ActionAffordance, AdditionalExpectedResponse, ComboSecurityScheme, DataSchema,
+        DataSchemaFromOther, DataSchemaSubtype, DefaultedFormOperations, EventAffordance,
+        ExpectedResponse, Form, FormFromOther, FormOperation, InteractionAffordance,
+        KnownSecuritySchemeSubtype, Link, PropertyAffordance, SecurityScheme,
+        SecuritySchemeSubtype, StringOrArray, Thing, UnknownSecuritySchemeSubtype, VersionInfo,
+        ACTION_AFFORDANCE_MEMBERS, DATA_SCHEMA_MEMBERS, EVENT_AFFORDANCE_MEMBERS,
+        EXPECTED_RESPONSE_MEMBERS, FORM_MEMBERS, INTERACTION_AFFORDANCE_MEMBERS,
+        PROPERTY_AFFORDANCE_MEMBERS, TD_CONTEXT_11, THING_MEMBERS, TM_MEDIA_TYPE,
     },
+    uri::{self, is_valid_reference},
 };
 
 use self::{
@@ -248,6 +258,29 @@ pub mod typetags {
     /// A _typetag_ for types that have been already extended.
     #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct Extended;
+
+    /// A _typetag_ for types whose extensions have been finalized.
+    ///
+    /// It is only implemented by [`Extended`], and it is required by the methods of
+    /// [`ThingBuilder`](super::ThingBuilder) that cannot be used before calling `finish_extend`.
+    #[diagnostic::on_unimplemented(
+        message = "the `ThingBuilder` has not been extended yet",
+        label = "this method can only be called after `finish_extend()`",
+        note = "call `finish_extend()` after the extensions have been added with `ext` or `ext_with`"
+    )]
+    pub trait IsExtended: sealed::Sealed {}
+
+    impl IsExtended for Extended {}
+
+    mod sealed {
+        pub trait Sealed {}
+
+        impl Sealed for super::Extended {}
+    }
+
+    /// A _typetag_ for a [`ThingBuilder`](super::ThingBuilder) whose title has not been set yet.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct MissingTitle;
 }
 
 pub use self::typetags::*;
@@ -257,12 +290,16 @@ pub use self::typetags::*;
 /// A `ThingBuilder` can be created using [`ThingBuilder::new`] or [`Thing::builder`], and after
 /// all the customization, [`ThingBuilder::build`] needs to be called in order to create a
 /// [`Thing`].
+///
+/// The `Title` parameter is [`MissingTitle`] for the builders created using
+/// [`ThingBuilder::new_untitled`] or [`Thing::builder_untitled`], which cannot be built until the
+/// title is set using [`ThingBuilder::title`].
 #[must_use]
-pub struct ThingBuilder<Other: ExtendableThing, Status> {
+pub struct ThingBuilder<Other: ExtendableThing, Status, Title = String> {
     context: Vec<Context>,
     id: Option<String>,
     attype: Option<Vec<String>>,
-    title: String,
+    title: Title,
     titles: Option<MultiLanguageBuilder<String>>,
     description: Option<String>,
     descriptions: Option<MultiLanguageBuilder<String>>,
@@ -281,12 +318,64 @@ pub struct ThingBuilder<Other: ExtendableThing, Status> {
     security_definitions: Vec<(String, UncheckedSecurityScheme)>,
     profile: Vec<String>,
     schema_definitions: HashMap<String, UncheckedDataSchemaFromOther<Other>>,
+    strict_forms: bool,
+    strict_content_encoding: bool,
+    extension_collision_check: Option<ThingCheck<Other>>,
+    default_content_type: Option<String>,
+    security_order: Vec<String>,
+    implicit_default_ops: bool,
 
     /// Thing extension.
     pub other: Other,
     _marker: PhantomData<Status>,
 }
 
+impl<Other, Status, Title> Clone for ThingBuilder<Other, Status, Title>
+where
+    Other: ExtendableThing + Clone,
+    Title: Clone,
+    AffordanceBuilder<UsablePropertyAffordanceBuilder<Other>>: Clone,
+    AffordanceBuilder<UsableActionAffordanceBuilder<Other>>: Clone,
+    AffordanceBuilder<UsableEventAffordanceBuilder<Other>>: Clone,
+    FormBuilder<Other, String, Other::Form>: Clone,
+    UncheckedDataSchemaFromOther<Other>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            context: self.context.clone(),
+            id: self.id.clone(),
+            attype: self.attype.clone(),
+            title: self.title.clone(),
+            titles: self.titles.clone(),
+            description: self.description.clone(),
+            descriptions: self.descriptions.clone(),
+            version: self.version.clone(),
+            created: self.created,
+            modified: self.modified,
+            support: self.support.clone(),
+            base: self.base.clone(),
+            properties: self.properties.clone(),
+            actions: self.actions.clone(),
+            events: self.events.clone(),
+            links: self.links.clone(),
+            forms: self.forms.clone(),
+            uri_variables: self.uri_variables.clone(),
+            security: self.security.clone(),
+            security_definitions: self.security_definitions.clone(),
+            profile: self.profile.clone(),
+            schema_definitions: self.schema_definitions.clone(),
+            strict_forms: self.strict_forms,
+            strict_content_encoding: self.strict_content_encoding,
+            extension_collision_check: self.extension_collision_check,
+            default_content_type: self.default_content_type.clone(),
+            security_order: self.security_order.clone(),
+            implicit_default_ops: self.implicit_default_ops,
+            other: self.other.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
 macro_rules! opt_field_builder {
     ($($field:ident : $ty:ty),* $(,)?) => {
         $(
@@ -299,16 +388,155 @@ macro_rules! opt_field_builder {
     };
 }
 
+/// Appends `value` unless it is already present, preserving the insertion order.
+///
+/// The `@type` and `security` lists are small, a linear search is cheaper than hashing.
+pub(crate) fn push_unique<T: PartialEq>(values: &mut Vec<T>, value: T) {
+    if !values.contains(&value) {
+        values.push(value);
+    }
+}
+
 /// Builder errors
 ///
 /// Most of the Thing Description conflicts are caught at compile time.
-/// The few errors that may be discovered at only runtime are the following.
+/// The few errors that may be discovered at only runtime are grouped by the part of the Thing
+/// Description they are about: [`DataSchemaError`], [`AffordanceError`], [`SecurityError`] and
+/// [`DocumentError`]. The errors of nested members are wrapped in [`Error::AtPath`], which carries
+/// the JSON pointer of the member.
+///
+/// # Migration
+///
+/// The errors used to be variants of `Error`, each of them is now a variant of one of the nested
+/// enums:
+///
+/// - `Error::InvalidMinMax` becomes `Error::DataSchema(DataSchemaError::InvalidMinMax)`.
+/// - `DataSchemaError::InvalidMinMax.into()` can be used where an `Error` is expected.
+/// - `Error::category()` is replaced by matching the outer variant of [`Error::without_path`].
+///
+/// ```
+/// # use wot_td::builder::{DataSchemaError, Error};
+/// #
+/// let error = Error::from(DataSchemaError::InvalidMinMax);
+/// assert!(matches!(error, Error::DataSchema(DataSchemaError::InvalidMinMax)));
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
 pub enum Error {
-    /// The WoT security definitions must have an unique name
-    #[error("Two security definitions use the name \"{0}\"")]
-    DuplicatedSecurityDefinition(String),
+    /// An invalid data schema.
+    #[error(transparent)]
+    DataSchema(#[from] DataSchemaError),
+
+    /// An invalid affordance or form.
+    #[error(transparent)]
+    Affordance(#[from] AffordanceError),
+
+    /// An invalid security definition or a reference to an undefined one.
+    #[error(transparent)]
+    Security(#[from] SecurityError),
+
+    /// An invalid member of the document that is not specific to the other errors, like a
+    /// language tag or a URI.
+    #[error(transparent)]
+    Document(#[from] DocumentError),
+
+    /// A nested member cannot be built.
+    ///
+    /// The path is the [JSON pointer](https://www.rfc-editor.org/rfc/rfc6901) of the nested
+    /// member, relative to the structure being built: a Thing built by the [`ThingBuilder`]
+    /// reports paths like `/actions/calibrate/input/properties/offset/descriptions`, while a
+    /// standalone data schema reports paths like `/properties/foo/items`.
+    #[error("Invalid member at {path}: {source}")]
+    AtPath {
+        /// The JSON pointer of the nested member.
+        path: String,
+
+        /// The error of the nested member.
+        source: Box<Error>,
+    },
+}
+
+/// Errors of the data schemas
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum DataSchemaError {
+    /// When both min and max are specified, min must be less or equal than max
+    #[error("Min value greater than max value")]
+    InvalidMinMax,
+
+    /// Neither minimum or maximum value can be NaN
+    #[error("Min or Max value is NaN")]
+    NanMinMax,
+
+    /// A bound is given both as inclusive and as exclusive value, like `minimum` and a numeric
+    /// `exclusiveMinimum`, and the two values cannot be compared.
+    ///
+    /// The tighter of two comparable values is kept instead.
+    #[error("Both \"{0}\" and its exclusive counterpart are set")]
+    AmbiguousBound(String),
+
+    /// The `contentEncoding` of a string schema is not one of the registered ones.
+    ///
+    /// It is only returned with [`ThingBuilder::strict_content_encoding`] or
+    /// [`ValidateOptions::strict_content_encoding`].
+    ///
+    /// [`ValidateOptions::strict_content_encoding`]: crate::thing::ValidateOptions::strict_content_encoding
+    #[error("Unknown content encoding \"{0}\"")]
+    UnknownContentEncoding(String),
+
+    /// Neither minimum, maximum nor `multiple_of` can be infinite, because JSON cannot represent
+    /// them. A `multiple_of` cannot be NaN either.
+    #[error("Min, Max or \"multipleOf\" value is not finite")]
+    NonFiniteBound,
+
+    /// Invalid `multiple_of` field, that must strictly greater than zero.
+    #[error("\"multipleOf\" field must be strictly greater than 0")]
+    InvalidMultipleOf,
+
+    /// A schema has been referenced using a specific name, but it is not been declared.
+    #[error("Using the data schema \"{0}\", which is not declared in the schema definitions")]
+    MissingSchemaDefinition(String),
+
+    /// A data schema has both `const` and `enum`.
+    #[error("A data schema cannot have both const and enum")]
+    ConstAndEnum,
+
+    /// A data schema has an empty `enum`, therefore no value is valid.
+    #[error("The enum of a data schema must have at least one value")]
+    EmptyEnum,
+
+    /// The `const` of a data schema does not match its `type`.
+    ///
+    /// It is also returned for a non-finite number passed to
+    /// [`constant_number`](crate::builder::data_schema::SpecializableDataSchema::constant_number),
+    /// because JSON cannot represent it and it becomes `null`.
+    #[error("The const does not match the type of the data schema")]
+    ConstTypeMismatch,
+
+    /// A value of the `enum` of a data schema does not match its `type`.
+    #[error(
+        "The value {value} of the enum at \"{path}\" does not match the type of the data schema"
+    )]
+    EnumTypeMismatch {
+        /// The JSON representation of the value.
+        value: String,
+
+        /// The JSON pointer of the value, relative to the checked data schema.
+        path: String,
+    },
+
+    /// A raw field of a data schema uses the name of one of its members.
+    #[error("The raw field \"{0}\" overrides a member of the data schema")]
+    ReservedKeyOverride(String),
 
+    /// The items of a tuple cannot be collapsed into a single data schema, because they differ.
+    ///
+    /// It is returned by [`ArraySchema::into_vec`](crate::thing::ArraySchema::into_vec).
+    #[error("The items of the tuple do not share the same data schema")]
+    HeterogeneousTuple,
+}
+
+/// Errors of the affordances and of the forms
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum AffordanceError {
     /// The forms have defaults that depend on the Affordance that contains them.
     /// The Thing-level forms must be explicit on the operation
     #[error("A Form directly placed in a Thing must contain at least one relevant operation")]
@@ -324,18 +552,6 @@ pub enum Error {
         operation: FormOperation,
     },
 
-    /// The security field must refer to existing security definitions.
-    #[error("Security \"{0}\" is not specified in Thing security definitions")]
-    UndefinedSecurity(String),
-
-    /// When both min and max are specified, min must be less or equal than max
-    #[error("Min value greater than max value")]
-    InvalidMinMax,
-
-    /// Neither minimum or maximum value can be NaN
-    #[error("Min or Max value is NaN")]
-    NanMinMax,
-
     /// For each type of affordance, names must be unique
     #[error("Two affordances of type {ty} use the name \"{name}\"")]
     DuplicatedAffordance {
@@ -346,18 +562,61 @@ pub enum Error {
         name: String,
     },
 
-    /// Invalid `multiple_of` field, that must strictly greater than zero.
-    #[error("\"multipleOf\" field must be strictly greater than 0")]
-    InvalidMultipleOf,
-
-    /// A schema has been referenced using a specific name, but it is not been declared.
-    #[error("Using the data schema \"{0}\", which is not declared in the schema definitions")]
-    MissingSchemaDefinition(String),
-
     /// Invalid URI variable, which cannot be an object or an array.
     #[error("An uriVariable cannot be an ObjectSchema or ArraySchema")]
     InvalidUriVariables,
 
+    /// The `href` of a form is not a valid URI template.
+    #[error("Invalid URI template in form href: {0}")]
+    InvalidHrefTemplate(#[from] TemplateError),
+
+    /// An `observable` property does not have any form to observe it.
+    #[error(
+        "The observable property \"{0}\" does not have a form with the observeproperty operation"
+    )]
+    ObservableWithoutObserveForm(String),
+
+    /// An affordance does not have any form, therefore it cannot be reached.
+    ///
+    /// It is only checked when [`ThingBuilder::strict_forms`] is used.
+    #[error("The affordance \"{0}\" does not have any form")]
+    AffordanceWithoutForm(String),
+
+    /// A URI variable of an affordance has the same name of one of the Thing.
+    ///
+    /// It is only checked when [`ThingBuilder::strict_forms`] is used, otherwise it is reported by
+    /// [`LintWarning::ShadowedUriVariable`].
+    #[error("The URI variable \"{variable}\" of the affordance \"{affordance}\" shadows a URI variable of the Thing")]
+    ShadowedUriVariable {
+        /// The name of the affordance.
+        affordance: String,
+
+        /// The name of the URI variable.
+        variable: String,
+    },
+
+    /// A variable of an `href` template is not declared in the `uriVariables` of the affordance or
+    /// of the Thing.
+    #[error("The URI variable \"{0}\" is not declared in uriVariables")]
+    UndeclaredUriVariable(String),
+}
+
+/// Errors of the security definitions
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum SecurityError {
+    /// The WoT security definitions must have an unique name
+    #[error("Two security definitions use the name \"{0}\"")]
+    DuplicatedSecurityDefinition(String),
+
+    /// The security field must refer to existing security definitions.
+    #[error("Security \"{0}\" is not specified in Thing security definitions")]
+    UndefinedSecurity(String),
+}
+
+/// Errors of the members of the document that are not specific to data schemas, affordances or
+/// security definitions
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum DocumentError {
     /// Language tag is not conforming to [BCP47](https://www.rfc-editor.org/info/bcp47).
     #[error("Invalid language tag \"{0}\"")]
     InvalidLanguageTag(String),
@@ -365,6 +624,111 @@ pub enum Error {
     /// A `Link` contains a `sizes` field but its `rel` field is not equal to `icon`.
     #[error("A sizes field can be used only when \"rel\" is \"icon\"")]
     SizesWithRelNotIcon,
+
+    /// More than one `Link` refers to the Thing Model of the Thing.
+    #[error("Only one link with \"rel\" equal to \"type\" can refer to a Thing Model")]
+    MultipleThingModelLinks,
+
+    /// The value is not a valid URI reference.
+    ///
+    /// The message names the validation backend that rejected it, which is selected by the cargo
+    /// features.
+    #[error("Invalid URI \"{0}\", rejected by the {backend} validation", backend = uri::BACKEND_NAME)]
+    InvalidUri(String),
+
+    /// A name is empty or made only of whitespace.
+    #[error("Empty name in {0}")]
+    EmptyName(NameKind),
+
+    /// Two extensions, or an extension and the structure it extends, serialize the same member.
+    ///
+    /// It is only checked when [`ThingBuilder::check_extension_collisions`] is used.
+    #[error("The member \"{field}\" is serialized by both {left} and {right}")]
+    ExtensionFieldCollision {
+        /// The name of the member.
+        field: String,
+
+        /// The type name of the extension that first serialized the member.
+        left: &'static str,
+
+        /// The type name of the extension or of the structure that serialized the member again.
+        right: &'static str,
+    },
+
+    /// The built Thing cannot be serialized.
+    ///
+    /// It can only be caused by the `Serialize` implementation of an extension.
+    #[error("The Thing cannot be serialized: {0}")]
+    Serialization(String),
+}
+
+impl From<TemplateError> for Error {
+    #[inline]
+    fn from(error: TemplateError) -> Self {
+        Self::Affordance(error.into())
+    }
+}
+
+impl Error {
+    /// Prepends a segment to the path of the error, wrapping it in [`Error::AtPath`] if needed.
+    ///
+    /// The segment is escaped, therefore the segments of a path must be prepended one by one.
+    pub(crate) fn at(self, segment: impl fmt::Display) -> Self {
+        let segment = segment.to_string().replace('~', "~0").replace('/', "~1");
+        match self {
+            Self::AtPath { path, source } => Self::AtPath {
+                path: format!("/{segment}{path}"),
+                source,
+            },
+            error => Self::AtPath {
+                path: format!("/{segment}"),
+                source: Box::new(error),
+            },
+        }
+    }
+
+    /// Returns the JSON pointer of the member that caused the error, if it is known.
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            Self::AtPath { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Returns the error without its path.
+    ///
+    /// It is useful to match the error independently of the member that caused it.
+    ///
+    /// ```
+    /// # use wot_td::builder::{DataSchemaError, Error};
+    /// #
+    /// let error = Error::AtPath {
+    ///     path: "/properties/level".to_string(),
+    ///     source: Box::new(DataSchemaError::InvalidMinMax.into()),
+    /// };
+    /// assert_eq!(error.path(), Some("/properties/level"));
+    /// assert!(matches!(
+    ///     error.without_path(),
+    ///     Error::DataSchema(DataSchemaError::InvalidMinMax),
+    /// ));
+    /// ```
+    pub fn without_path(&self) -> &Self {
+        match self {
+            Self::AtPath { source, .. } => source,
+            error => error,
+        }
+    }
+
+    /// Prepends a segment to the path of an [`Error::AtPath`], leaving the other errors unchanged.
+    ///
+    /// It is used by the affordances and the Thing, which only extend the paths of the errors of
+    /// their nested members.
+    pub(crate) fn within(self, segment: impl fmt::Display) -> Self {
+        match self {
+            Self::AtPath { .. } => self.at(segment),
+            error => error,
+        }
+    }
 }
 
 /// Context of a [`Form`]
@@ -433,6 +797,99 @@ impl fmt::Display for AffordanceType {
     }
 }
 
+/// The kinds of the maps and of the lists of names in a Thing Description
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameKind {
+    /// The names of the property affordances
+    Property,
+
+    /// The names of the action affordances
+    Action,
+
+    /// The names of the event affordances
+    Event,
+
+    /// The `properties` of an object data schema
+    ObjectProperty,
+
+    /// The `required` properties of an object data schema
+    Required,
+
+    /// The names of the `uriVariables`
+    UriVariable,
+
+    /// The names of the `schemaDefinitions`
+    SchemaDefinition,
+
+    /// The names of the `securityDefinitions`
+    SecurityDefinition,
+
+    /// The `security` references of a Thing or a form
+    Security,
+}
+
+impl fmt::Display for NameKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Property => "properties",
+            Self::Action => "actions",
+            Self::Event => "events",
+            Self::ObjectProperty => "object properties",
+            Self::Required => "required",
+            Self::UriVariable => "uriVariables",
+            Self::SchemaDefinition => "schemaDefinitions",
+            Self::SecurityDefinition => "securityDefinitions",
+            Self::Security => "security",
+        };
+
+        f.write_str(s)
+    }
+}
+
+impl From<AffordanceType> for NameKind {
+    fn from(ty: AffordanceType) -> Self {
+        match ty {
+            AffordanceType::Property => Self::Property,
+            AffordanceType::Action => Self::Action,
+            AffordanceType::Event => Self::Event,
+        }
+    }
+}
+
+/// Checks that a name is neither empty nor made only of whitespace.
+pub(crate) fn check_name(name: &str, kind: NameKind) -> Result<(), Error> {
+    if name.trim().is_empty() {
+        Err(DocumentError::EmptyName(kind).into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Parses a [BCP 47](https://www.rfc-editor.org/info/bcp47) language tag.
+///
+/// This is the validation performed by the builders for the language keys of multi-language
+/// fields and for the `hreflang` of links, it can be used to check user-provided tags in advance.
+///
+/// # Example
+///
+/// ```
+/// # use wot_td::builder::{parse_language_tag, DocumentError, Error};
+/// #
+/// assert_eq!(parse_language_tag("it-IT").unwrap().primary_language(), "it");
+/// assert_eq!(
+///     parse_language_tag("e!n").unwrap_err(),
+///     Error::Document(DocumentError::InvalidLanguageTag("e!n".to_string())),
+/// );
+/// ```
+pub fn parse_language_tag(tag: impl Into<String>) -> Result<LanguageTag<String>, Error> {
+    let tag = tag.into();
+
+    // See https://github.com/oxigraph/oxilangtag/issues/4 for the reason of this, which
+    // unnecessarily allocate.
+    tag.parse()
+        .map_err(|_| Error::Document(DocumentError::InvalidLanguageTag(tag)))
+}
+
 impl<Other: ExtendableThing> ThingBuilder<Other, ToExtend> {
     /// Create a new default builder with a specified title, using a default extension
     pub fn new(title: impl Into<String>) -> Self
@@ -465,6 +922,12 @@ impl<Other: ExtendableThing> ThingBuilder<Other, ToExtend> {
             uri_variables: Default::default(),
             profile: Default::default(),
             schema_definitions: Default::default(),
+            strict_forms: false,
+            strict_content_encoding: false,
+            extension_collision_check: None,
+            default_content_type: None,
+            security_order: Default::default(),
+            implicit_default_ops: false,
             other: Default::default(),
             _marker: PhantomData,
         }
@@ -502,42 +965,86 @@ impl<Other: ExtendableThing> ThingBuilder<Other, ToExtend> {
             uri_variables: Default::default(),
             profile: Default::default(),
             schema_definitions: Default::default(),
+            strict_forms: false,
+            strict_content_encoding: false,
+            extension_collision_check: None,
+            default_content_type: None,
+            security_order: Default::default(),
+            implicit_default_ops: false,
             other: Other::empty(),
             _marker: PhantomData,
         }
     }
+}
 
-    /// Finalize the set of extensions that must be populated
-    ///
-    /// Moves the builder status from [ToExtend] to [Extended].
-    /// From this point is not possible to add further extensions to the builder.
+impl<Other: ExtendableThing> ThingBuilder<Other, ToExtend, MissingTitle> {
+    /// Create a new default builder without a title, using a default extension
     ///
-    /// See [ThingBuilder::ext].
+    /// The title must be set using [`ThingBuilder::title`] before building the Thing.
+    pub fn new_untitled() -> Self
+    where
+        Other: Default,
+    {
+        let context = vec![Context::Simple(TD_CONTEXT_11.to_string())];
+
+        Self {
+            context,
+            id: Default::default(),
+            attype: Default::default(),
+            title: MissingTitle,
+            titles: Default::default(),
+            description: Default::default(),
+            descriptions: Default::default(),
+            version: Default::default(),
+            created: Default::default(),
+            modified: Default::default(),
+            support: Default::default(),
+            base: Default::default(),
+            properties: Default::default(),
+            actions: Default::default(),
+            events: Default::default(),
+            links: Default::default(),
+            forms: Default::default(),
+            security: Default::default(),
+            security_definitions: Default::default(),
+            uri_variables: Default::default(),
+            profile: Default::default(),
+            schema_definitions: Default::default(),
+            strict_forms: false,
+            strict_content_encoding: false,
+            extension_collision_check: None,
+            default_content_type: None,
+            security_order: Default::default(),
+            implicit_default_ops: false,
+            other: Default::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Other: ExtendableThing, Status> ThingBuilder<Other, Status, MissingTitle> {
+    /// Sets the title of a builder created without it
     ///
     /// # Example
     ///
     /// ```
-    /// # use wot_td::{builder::data_schema::SpecializableDataSchema, thing::Thing};
+    /// # use wot_td::thing::Thing;
     /// #
-    /// let thing = Thing::builder("Thing name")
-    ///     .id("thing-id-1234")
+    /// let thing = Thing::builder_untitled()
+    ///     .id("urn:dev:ops:my-lamp-1234")
     ///     .finish_extend()
-    ///     .property("first-property", |prop_builder| {
-    ///         prop_builder
-    ///             .finish_extend_data_schema()
-    ///             .observable(true)
-    ///             .bool()
-    ///     })
+    ///     .title("My lamp")
     ///     .build()
     ///     .unwrap();
-    /// # drop(thing);
+    ///
+    /// assert_eq!(thing.title, "My lamp");
     /// ```
-    pub fn finish_extend(self) -> ThingBuilder<Other, Extended> {
+    pub fn title(self, title: impl Into<String>) -> ThingBuilder<Other, Status> {
         let Self {
             context,
             id,
             attype,
-            title,
+            title: MissingTitle,
             titles,
             description,
             descriptions,
@@ -556,15 +1063,21 @@ impl<Other: ExtendableThing> ThingBuilder<Other, ToExtend> {
             security_definitions,
             profile,
             schema_definitions,
+            strict_forms,
+            strict_content_encoding,
+            extension_collision_check,
+            default_content_type,
+            security_order,
+            implicit_default_ops,
             other,
-            _marker: _,
+            _marker,
         } = self;
 
         ThingBuilder {
             context,
             id,
             attype,
-            title,
+            title: title.into(),
             titles,
             description,
             descriptions,
@@ -583,17 +1096,121 @@ impl<Other: ExtendableThing> ThingBuilder<Other, ToExtend> {
             security_definitions,
             profile,
             schema_definitions,
+            strict_forms,
+            strict_content_encoding,
+            extension_collision_check,
+            default_content_type,
+            security_order,
+            implicit_default_ops,
             other,
-            _marker: PhantomData,
+            _marker,
         }
     }
+}
 
-    /// Extends the Thing, passing a closure that returns `T`.
+impl<Other: ExtendableThing, Title> ThingBuilder<Other, ToExtend, Title> {
+    /// Finalize the set of extensions that must be populated
+    ///
+    /// Moves the builder status from [ToExtend] to [Extended].
+    /// From this point is not possible to add further extensions to the builder.
+    ///
+    /// The methods adding affordances, forms and schemas require the [IsExtended] status, therefore
+    /// calling them before `finish_extend` is a compile error pointing to this method.
+    ///
+    /// See [ThingBuilder::ext].
     ///
     /// # Example
     ///
     /// ```
-    /// # use serde::{Deserialize, Serialize};
+    /// # use wot_td::{builder::data_schema::SpecializableDataSchema, thing::Thing};
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .id("thing-id-1234")
+    ///     .finish_extend()
+    ///     .property("first-property", |prop_builder| {
+    ///         prop_builder
+    ///             .finish_extend_data_schema()
+    ///             .observable(true)
+    ///             .bool()
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    /// # drop(thing);
+    /// ```
+    pub fn finish_extend(self) -> ThingBuilder<Other, Extended, Title> {
+        let Self {
+            context,
+            id,
+            attype,
+            title,
+            titles,
+            description,
+            descriptions,
+            version,
+            created,
+            modified,
+            support,
+            base,
+            properties,
+            actions,
+            events,
+            links,
+            forms,
+            uri_variables,
+            security,
+            security_definitions,
+            profile,
+            schema_definitions,
+            strict_forms,
+            strict_content_encoding,
+            extension_collision_check,
+            default_content_type,
+            security_order,
+            implicit_default_ops,
+            other,
+            _marker: _,
+        } = self;
+
+        ThingBuilder {
+            context,
+            id,
+            attype,
+            title,
+            titles,
+            description,
+            descriptions,
+            version,
+            created,
+            modified,
+            support,
+            base,
+            properties,
+            actions,
+            events,
+            links,
+            forms,
+            uri_variables,
+            security,
+            security_definitions,
+            profile,
+            schema_definitions,
+            strict_forms,
+            strict_content_encoding,
+            extension_collision_check,
+            default_content_type,
+            security_order,
+            implicit_default_ops,
+            other,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Extends the Thing, passing a closure that returns `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Deserialize, Serialize};
     /// # use serde_json::json;
     /// # use wot_td::{extend::ExtendableThing, thing::Thing};
     /// #
@@ -636,7 +1253,7 @@ impl<Other: ExtendableThing> ThingBuilder<Other, ToExtend> {
     ///     })
     /// );
     /// ```
-    pub fn ext_with<F, T>(self, f: F) -> ThingBuilder<Other::Target, ToExtend>
+    pub fn ext_with<F, T>(self, f: F) -> ThingBuilder<Other::Target, ToExtend, Title>
     where
         F: FnOnce() -> T,
         Other: Extend<T>,
@@ -665,6 +1282,12 @@ impl<Other: ExtendableThing> ThingBuilder<Other, ToExtend> {
             security_definitions,
             profile,
             schema_definitions: _,
+            strict_forms,
+            strict_content_encoding,
+            extension_collision_check: _,
+            default_content_type,
+            security_order,
+            implicit_default_ops,
             other,
             _marker,
         } = self;
@@ -693,6 +1316,12 @@ impl<Other: ExtendableThing> ThingBuilder<Other, ToExtend> {
             security_definitions,
             profile,
             schema_definitions: Default::default(),
+            strict_forms,
+            strict_content_encoding,
+            extension_collision_check: None,
+            default_content_type,
+            security_order,
+            implicit_default_ops,
             other,
             _marker,
         }
@@ -747,7 +1376,7 @@ impl<Other: ExtendableThing> ThingBuilder<Other, ToExtend> {
     /// );
     /// ```
     #[inline]
-    pub fn ext<T>(self, t: T) -> ThingBuilder<Other::Target, ToExtend>
+    pub fn ext<T>(self, t: T) -> ThingBuilder<Other::Target, ToExtend, Title>
     where
         Other: Extend<T>,
         Other::Target: ExtendableThing,
@@ -760,6 +1389,10 @@ impl<Other: ExtendableThing, Status> ThingBuilder<Other, Status> {
     /// Consume the builder to produce the configured Thing
     ///
     /// This step will perform the final validation of the builder state.
+    ///
+    /// The forms of the affordances without an explicit `op` get the
+    /// [default operations](crate::thing::default_ops_for) of the specification, unless
+    /// [`implicit_default_ops`](ThingBuilder::implicit_default_ops) is used.
     pub fn build(self) -> Result<Thing<Other>, Error> {
         let Self {
             context,
@@ -779,25 +1412,49 @@ impl<Other: ExtendableThing, Status> ThingBuilder<Other, Status> {
             events,
             links,
             forms,
-            security,
+            mut security,
             security_definitions: security_definitions_vec,
             uri_variables,
             profile,
             schema_definitions,
+            strict_forms,
+            strict_content_encoding,
+            extension_collision_check,
+            default_content_type,
+            security_order,
+            implicit_default_ops,
             other,
             _marker: _,
         } = self;
 
+        if !security_order.is_empty() {
+            security.sort_by_key(|name| {
+                security_order
+                    .iter()
+                    .position(|ordered| ordered == name)
+                    .unwrap_or(security_order.len())
+            });
+        }
+        security
+            .iter()
+            .try_for_each(|name| check_name(name, NameKind::Security))?;
+
         let mut security_definitions = HashMap::with_capacity(security_definitions_vec.len());
         for (name, scheme) in security_definitions_vec {
-            let scheme: SecurityScheme = scheme.try_into()?;
+            check_name(&name, NameKind::SecurityDefinition)?;
+            let scheme: SecurityScheme = scheme
+                .try_into()
+                .map_err(|error: Error| error.within(&name).within(NameKind::SecurityDefinition))?;
 
             match security_definitions.entry(name) {
                 Entry::Vacant(entry) => {
                     entry.insert(scheme);
                 }
                 Entry::Occupied(entry) => {
-                    return Err(Error::DuplicatedSecurityDefinition(entry.remove_entry().0));
+                    return Err(SecurityError::DuplicatedSecurityDefinition(
+                        entry.remove_entry().0,
+                    )
+                    .into());
                 }
             }
         }
@@ -818,12 +1475,24 @@ impl<Other: ExtendableThing, Status> ThingBuilder<Other, Status> {
                 security_definitions
                     .contains_key(security_name)
                     .then_some(())
-                    .ok_or_else(|| Error::MissingSchemaDefinition(security_name.to_string()))
+                    .ok_or_else(|| {
+                        Error::DataSchema(DataSchemaError::MissingSchemaDefinition(
+                            security_name.to_string(),
+                        ))
+                    })
             })?;
-        let schema_definitions = schema_definitions
-            .into_iter()
-            .map(|(key, value)| value.try_into().map(|value| (key, value)))
-            .collect::<Result<_, _>>()?;
+        let schema_definitions = {
+            let mut map = HashMap::with_capacity(schema_definitions.len());
+            for (key, value) in schema_definitions {
+                check_name(&key, NameKind::SchemaDefinition)?;
+                value.check()?;
+                let value = value.try_into().map_err(|error: Error| {
+                    error.within(&key).within(NameKind::SchemaDefinition)
+                })?;
+                map.insert(key, value);
+            }
+            map
+        };
 
         let profile = profile.is_empty().not().then_some(profile);
 
@@ -870,19 +1539,24 @@ impl<Other: ExtendableThing, Status> ThingBuilder<Other, Status> {
             .map(uri_variables_contains_arrays_objects::<Other>)
             .unwrap_or(false);
         if invalid_uri_variables {
-            return Err(Error::InvalidUriVariables);
+            return Err(AffordanceError::InvalidUriVariables.into());
         }
 
         let uri_variables = uri_variables
             .map(|uri_variables| {
-                uri_variables
-                    .into_iter()
-                    .map(|(key, value)| value.try_into().map(|value| (key, value)))
-                    .collect()
+                let mut map = HashMap::with_capacity(uri_variables.len());
+                for (key, value) in uri_variables {
+                    check_name(&key, NameKind::UriVariable)?;
+                    let value = value
+                        .try_into()
+                        .map_err(|error: Error| error.within(&key).within(NameKind::UriVariable))?;
+                    map.insert(key, value);
+                }
+                Ok::<_, Error>(map)
             })
             .transpose()?;
 
-        let properties = try_build_affordance(
+        let mut properties = try_build_affordance(
             properties,
             AffordanceType::Property,
             |property| &property.interaction,
@@ -898,7 +1572,7 @@ impl<Other: ExtendableThing, Status> ThingBuilder<Other, Status> {
             },
             &security_definitions,
         )?;
-        let actions = try_build_affordance(
+        let mut actions = try_build_affordance(
             actions,
             AffordanceType::Action,
             |action| &action.interaction,
@@ -913,7 +1587,7 @@ impl<Other: ExtendableThing, Status> ThingBuilder<Other, Status> {
             },
             &security_definitions,
         )?;
-        let events = try_build_affordance(
+        let mut events = try_build_affordance(
             events,
             AffordanceType::Event,
             |event| &event.interaction,
@@ -932,19 +1606,120 @@ impl<Other: ExtendableThing, Status> ThingBuilder<Other, Status> {
             },
             &security_definitions,
         )?;
-        let links = links
+        if strict_forms {
+            let property_interactions = properties
+                .iter()
+                .flatten()
+                .map(|(name, property)| (name, &property.interaction));
+            let action_interactions = actions
+                .iter()
+                .flatten()
+                .map(|(name, action)| (name, &action.interaction));
+            let event_interactions = events
+                .iter()
+                .flatten()
+                .map(|(name, event)| (name, &event.interaction));
+            let interactions = property_interactions
+                .chain(action_interactions)
+                .chain(event_interactions);
+            for (name, interaction) in interactions.clone() {
+                if interaction.forms.is_empty() {
+                    return Err(AffordanceError::AffordanceWithoutForm(name.clone()).into());
+                }
+            }
+
+            for (name, interaction) in interactions {
+                let shadowed_variable = interaction
+                    .uri_variables
+                    .iter()
+                    .flat_map(|variables| variables.keys())
+                    .find(|&variable| {
+                        uri_variables
+                            .as_ref()
+                            .is_some_and(|uri_variables| uri_variables.contains_key(variable))
+                    });
+                if let Some(variable) = shadowed_variable {
+                    return Err(AffordanceError::ShadowedUriVariable {
+                        affordance: name.clone(),
+                        variable: variable.clone(),
+                    }
+                    .into());
+                }
+            }
+
+            let can_observe = |form: &FormFromOther<Other>| match &form.op {
+                DefaultedFormOperations::Custom(ops) => {
+                    ops.contains(&FormOperation::ObserveProperty)
+                }
+                DefaultedFormOperations::Default => false,
+            };
+            let unobservable_property = properties.iter().flatten().find(|(_, property)| {
+                property.observable == Some(true)
+                    && property.interaction.forms.iter().any(can_observe).not()
+            });
+            if let Some((name, _)) = unobservable_property {
+                return Err(AffordanceError::ObservableWithoutObserveForm(name.clone()).into());
+            }
+        }
+        let links: Option<Vec<Link>> = links
             .map(|links| links.into_iter().map(TryInto::try_into).collect())
             .transpose()?;
+        if links
+            .as_ref()
+            .is_some_and(|links| links.iter().filter(|link| link.is_thing_model()).count() > 1)
+        {
+            return Err(DocumentError::MultipleThingModelLinks.into());
+        }
 
-        let titles = titles.map(|titles| titles.build()).transpose()?;
+        let titles = titles
+            .map(|titles| titles.build().map_err(|error| error.at("titles")))
+            .transpose()?;
         let descriptions = descriptions
-            .map(|descriptions| descriptions.build())
+            .map(|descriptions| {
+                descriptions
+                    .build()
+                    .map_err(|error| error.at("descriptions"))
+            })
             .transpose()?;
 
-        Ok(Thing {
+        // The forms of the affordances without `op` get the default operations of the
+        // specification, so that the consumers do not need to know them.
+        if !implicit_default_ops {
+            let set_default_ops = |forms: &mut Vec<FormFromOther<Other>>,
+                                   ops: Vec<FormOperation>| {
+                if !ops.is_empty() {
+                    forms
+                        .iter_mut()
+                        .filter(|form| form.op.is_default())
+                        .for_each(|form| form.op = DefaultedFormOperations::Custom(ops.clone()));
+                }
+            };
+
+            for property in properties
+                .iter_mut()
+                .flat_map(|properties| properties.values_mut())
+            {
+                let ops = default_ops_for(
+                    AffordanceType::Property,
+                    property.data_schema.read_only,
+                    property.data_schema.write_only,
+                );
+                set_default_ops(&mut property.interaction.forms, ops);
+            }
+            for action in actions.iter_mut().flat_map(|actions| actions.values_mut()) {
+                let ops = default_ops_for(AffordanceType::Action, false, false);
+                set_default_ops(&mut action.interaction.forms, ops);
+            }
+            for event in events.iter_mut().flat_map(|events| events.values_mut()) {
+                let ops = default_ops_for(AffordanceType::Event, false, false);
+                set_default_ops(&mut event.interaction.forms, ops);
+            }
+        }
+
+        let mut thing = Thing {
             context,
             id,
-            attype,
+            attype: parse_at_types(attype),
             title,
             titles,
             description,
@@ -964,15 +1739,30 @@ impl<Other: ExtendableThing, Status> ThingBuilder<Other, Status> {
             uri_variables,
             profile,
             schema_definitions,
+            default_content_type,
             other,
-        })
+            #[cfg(feature = "preserve-unknown")]
+            unknown: Default::default(),
+        };
+
+        thing.declare_wot_td_prefix();
+        thing.check_href_templates()?;
+        thing.check_uris()?;
+        if strict_content_encoding {
+            check_content_encodings(&thing)?;
+        }
+        if let Some(check) = extension_collision_check {
+            check(&thing)?;
+        }
+
+        Ok(thing)
     }
 
     fn build_form_from_builder(
         form_builder: FormBuilder<Other, String, Other::Form>,
         security_definitions: &HashMap<String, SecurityScheme>,
         schema_definitions: &HashMap<String, DataSchemaFromOther<Other>>,
-    ) -> Result<Form<Other>, Error> {
+    ) -> Result<FormFromOther<Other>, Error> {
         use DefaultedFormOperations::*;
         use FormOperation::*;
 
@@ -994,17 +1784,20 @@ impl<Other: ExtendableThing, Status> ThingBuilder<Other, Status> {
             .as_mut()
             .map(|security| {
                 security.iter_mut().try_for_each(|security| {
+                    check_name(security, NameKind::Security)?;
                     if security_definitions.contains_key(security) {
                         Ok(())
                     } else {
-                        Err(Error::UndefinedSecurity(core::mem::take(security)))
+                        Err(Error::from(SecurityError::UndefinedSecurity(
+                            core::mem::take(security),
+                        )))
                     }
                 })
             })
             .transpose()?;
 
         match &op {
-            Default => return Err(Error::MissingOpInForm),
+            Default => return Err(AffordanceError::MissingOpInForm.into()),
             Custom(operations) => {
                 let wrong_op = operations
                     .iter()
@@ -1026,10 +1819,11 @@ impl<Other: ExtendableThing, Status> ThingBuilder<Other, Status> {
                     .copied();
 
                 if let Some(operation) = wrong_op {
-                    return Err(Error::InvalidOpInForm {
+                    return Err(AffordanceError::InvalidOpInForm {
                         context: FormContext::Thing,
                         operation,
-                    });
+                    }
+                    .into());
                 }
             }
         }
@@ -1041,7 +1835,9 @@ impl<Other: ExtendableThing, Status> ThingBuilder<Other, Status> {
                 schema_definitions
                     .contains_key(schema)
                     .then_some(())
-                    .ok_or_else(|| Error::MissingSchemaDefinition(schema.clone()))
+                    .ok_or_else(|| {
+                        Error::DataSchema(DataSchemaError::MissingSchemaDefinition(schema.clone()))
+                    })
             })?;
         let additional_responses = additional_responses
             .is_empty()
@@ -1059,9 +1855,305 @@ impl<Other: ExtendableThing, Status> ThingBuilder<Other, Status> {
             response,
             additional_responses,
             other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown: serde_json::Map::new(),
+        })
+    }
+
+    /// Consume the builder to produce the configured Thing, together with its lint warnings
+    ///
+    /// The Thing is built using [`ThingBuilder::build`] and then checked using [`Thing::lint`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{builder::data_schema::SpecializableDataSchema, thing::Thing};
+    /// #
+    /// let (thing, warnings) = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .schema_definition("level", |b| b.finish_extend().number())
+    ///     .build_with_report()
+    ///     .unwrap();
+    ///
+    /// assert!(warnings.is_empty());
+    /// # drop(thing);
+    /// ```
+    pub fn build_with_report(self) -> Result<(Thing<Other>, Vec<LintWarning>), Error> {
+        let thing = self.build()?;
+        let warnings = thing.lint();
+        Ok((thing, warnings))
+    }
+
+    /// Builds the Thing and lints it with the checks enabled by the specified [`LintConfig`].
+    ///
+    /// See [`Thing::lint_with`] for the checks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     lint::{LintConfig, LintWarning},
+    /// #     thing::Thing,
+    /// # };
+    /// #
+    /// let mut config = LintConfig::default();
+    /// config.max_serialized_size = Some(64);
+    ///
+    /// let (thing, warnings) = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .description("A Thing Description larger than the budget")
+    ///     .build_with_report_with(&config)
+    ///     .unwrap();
+    ///
+    /// assert!(matches!(
+    ///     warnings[..],
+    ///     [LintWarning::SerializedSizeExceeded(size)]
+    ///         if size > 64 && size <= thing.serialized_size_estimate(),
+    /// ));
+    /// ```
+    pub fn build_with_report_with(
+        self,
+        config: &LintConfig,
+    ) -> Result<(Thing<Other>, Vec<LintWarning>), Error>
+    where
+        Thing<Other>: Serialize,
+    {
+        let thing = self.build()?;
+        let warnings = thing.lint_with(config);
+        Ok((thing, warnings))
+    }
+
+    /// Builds a clone of the builder and serializes the resulting Thing, leaving the builder
+    /// untouched.
+    ///
+    /// This is meant to preview the Thing Description while it is being edited. The build stops
+    /// at the first error, therefore the returned vector currently contains a single error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{builder::data_schema::SpecializableDataSchema, thing::Thing};
+    /// #
+    /// let builder = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .schema_definition("level", |b| b.finish_extend().number());
+    ///
+    /// let preview = builder.dry_run_json().unwrap();
+    /// assert_eq!(preview["schemaDefinitions"]["level"]["type"], "number");
+    ///
+    /// let thing = builder.build().unwrap();
+    /// assert_eq!(serde_json::to_value(thing).unwrap(), preview);
+    /// ```
+    pub fn dry_run_json(&self) -> Result<Value, Vec<Error>>
+    where
+        Self: Clone,
+        Thing<Other>: Serialize,
+    {
+        let thing = self.clone().build().map_err(|error| vec![error])?;
+        serde_json::to_value(thing).map_err(|error| {
+            vec![Error::Document(DocumentError::Serialization(
+                error.to_string(),
+            ))]
+        })
+    }
+}
+
+/// A check performed on a built [`Thing`].
+type ThingCheck<Other> = fn(&Thing<Other>) -> Result<(), Error>;
+
+/// Checks that the extensions of a Thing serialize disjoint sets of members.
+///
+/// Every JSON object of the Thing is checked on its own, considering all the extensions that are
+/// flattened into it and the members of the structures that serialize it.
+fn check_extension_fields<Other: ExtendableThingFields>(thing: &Thing<Other>) -> Result<(), Error> {
+    fn base_name<T>() -> &'static str {
+        let name = type_name::<T>();
+        name.split_once('<').map_or(name, |(name, _)| name)
+    }
+
+    fn check_object(
+        base: &'static str,
+        members: &[&[&str]],
+        extensions: &[&dyn ExtensionFields],
+    ) -> Result<(), Error> {
+        let mut fields = Vec::new();
+        for extension in extensions {
+            extension.extension_fields(&mut fields);
+        }
+
+        let mut seen: HashMap<&str, &'static str> = HashMap::new();
+        for (extension, names) in &fields {
+            for name in names {
+                let left = if members
+                    .iter()
+                    .any(|members| members.contains(&name.as_str()))
+                {
+                    Some(base)
+                } else {
+                    seen.insert(name, extension)
+                };
+
+                if let Some(left) = left {
+                    return Err(DocumentError::ExtensionFieldCollision {
+                        field: name.clone(),
+                        left,
+                        right: extension,
+                    }
+                    .into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_data_schema<DS, AS, OS>(
+        data_schema: &DataSchema<DS, AS, OS>,
+        base: &'static str,
+        members: &[&[&str]],
+        extensions: &[&dyn ExtensionFields],
+    ) -> Result<(), Error>
+    where
+        DS: ExtensionFields,
+        AS: ExtensionFields,
+        OS: ExtensionFields,
+    {
+        data_schema.try_walk_with_path(&mut |path, data_schema| {
+            // The members and the extensions of the containing object only apply to the root.
+            let (base, members, extensions) = if path.is_empty() {
+                (base, members, extensions)
+            } else {
+                (base_name::<DataSchema<DS, AS, OS>>(), &[][..], &[][..])
+            };
+
+            let mut object = extensions.to_vec();
+            object.push(&data_schema.other);
+            match &data_schema.subtype {
+                Some(DataSchemaSubtype::Array(array)) => object.push(&array.other),
+                Some(DataSchemaSubtype::Object(object_schema)) => object.push(&object_schema.other),
+                _ => {}
+            }
+            let mut object_members = members.to_vec();
+            object_members.push(DATA_SCHEMA_MEMBERS);
+            check_object(base, &object_members, &object)
+        })
+    }
+
+    fn check_forms<F, ER>(forms: &[Form<F, ER>]) -> Result<(), Error>
+    where
+        F: ExtensionFields,
+        ER: ExtensionFields,
+    {
+        forms.iter().try_for_each(|form| {
+            check_object(base_name::<Form<(), ()>>(), &[FORM_MEMBERS], &[&form.other])?;
+            form.response.as_ref().map_or(Ok(()), |response| {
+                check_object(
+                    base_name::<ExpectedResponse<()>>(),
+                    &[EXPECTED_RESPONSE_MEMBERS],
+                    &[&response.other],
+                )
+            })
         })
     }
 
+    fn check_interaction<Other: ExtendableThingFields>(
+        interaction: &InteractionAffordance<Other>,
+    ) -> Result<(), Error> {
+        check_forms(&interaction.forms)?;
+        interaction
+            .uri_variables
+            .iter()
+            .flatten()
+            .try_for_each(|(_, variable)| {
+                check_data_schema(
+                    variable,
+                    base_name::<DataSchemaFromOther<Other>>(),
+                    &[],
+                    &[],
+                )
+            })
+    }
+
+    check_object(
+        base_name::<Thing<Other>>(),
+        &[THING_MEMBERS],
+        &[&thing.other],
+    )?;
+    check_forms(thing.forms.as_deref().unwrap_or_default())?;
+    [&thing.uri_variables, &thing.schema_definitions]
+        .into_iter()
+        .flatten()
+        .flat_map(|map| map.values())
+        .try_for_each(|data_schema| {
+            check_data_schema(
+                data_schema,
+                base_name::<DataSchemaFromOther<Other>>(),
+                &[],
+                &[],
+            )
+        })?;
+
+    for property in thing
+        .properties
+        .iter()
+        .flat_map(|properties| properties.values())
+    {
+        check_interaction(&property.interaction)?;
+        check_data_schema(
+            &property.data_schema,
+            base_name::<PropertyAffordance<Other>>(),
+            &[INTERACTION_AFFORDANCE_MEMBERS, PROPERTY_AFFORDANCE_MEMBERS],
+            &[&property.interaction.other, &property.other],
+        )?;
+    }
+    for action in thing.actions.iter().flat_map(|actions| actions.values()) {
+        check_interaction(&action.interaction)?;
+        check_object(
+            base_name::<ActionAffordance<Other>>(),
+            &[INTERACTION_AFFORDANCE_MEMBERS, ACTION_AFFORDANCE_MEMBERS],
+            &[&action.interaction.other, &action.other],
+        )?;
+        [&action.input, &action.output]
+            .into_iter()
+            .flatten()
+            .try_for_each(|data_schema| {
+                check_data_schema(
+                    data_schema,
+                    base_name::<DataSchemaFromOther<Other>>(),
+                    &[],
+                    &[],
+                )
+            })?;
+    }
+    for event in thing.events.iter().flat_map(|events| events.values()) {
+        check_interaction(&event.interaction)?;
+        check_object(
+            base_name::<EventAffordance<Other>>(),
+            &[INTERACTION_AFFORDANCE_MEMBERS, EVENT_AFFORDANCE_MEMBERS],
+            &[&event.interaction.other, &event.other],
+        )?;
+        [
+            &event.subscription,
+            &event.data,
+            &event.data_response,
+            &event.cancellation,
+        ]
+        .into_iter()
+        .flatten()
+        .try_for_each(|data_schema| {
+            check_data_schema(
+                data_schema,
+                base_name::<DataSchemaFromOther<Other>>(),
+                &[],
+                &[],
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
+impl<Other: ExtendableThing, Status, Title> ThingBuilder<Other, Status, Title> {
     opt_field_builder!(
         id: String,
         description: String,
@@ -1130,10 +2222,13 @@ impl<Other: ExtendableThing, Status> ThingBuilder<Other, Status> {
     }
 
     /// Add a JSON-LD @type to the thing
+    ///
+    /// Adding the same @type more than once has no effect.
     pub fn attype(mut self, value: impl Into<String>) -> Self {
-        self.attype
-            .get_or_insert_with(Default::default)
-            .push(value.into());
+        push_unique(
+            self.attype.get_or_insert_with(Default::default),
+            value.into(),
+        );
         self
     }
 
@@ -1169,18 +2264,24 @@ impl<Other: ExtendableThing, Status> ThingBuilder<Other, Status> {
     /// );
     /// ```
     ///
-    /// Creating a title using an invalid language tag is going to return an error when
-    /// `Thing::build` is called:
+    /// Creating a title using an invalid language tag is going to return an error, located at
+    /// the `titles` member, when `Thing::build` is called:
     ///
     /// ```
-    /// # use wot_td::{builder::Error, thing::Thing};
+    /// # use wot_td::{builder::{DocumentError, Error}, thing::Thing};
     /// #
     /// let error = Thing::builder("Thing name")
     ///     .titles(|builder| builder.add("e!n", "Invalid title"))
     ///     .build()
     ///     .unwrap_err();
     ///
-    /// assert_eq!(error, Error::InvalidLanguageTag("e!n".to_string()));
+    /// assert_eq!(
+    ///     error,
+    ///     Error::AtPath {
+    ///         path: "/titles".to_string(),
+    ///         source: Box::new(DocumentError::InvalidLanguageTag("e!n".to_string()).into()),
+    ///     },
+    /// );
     /// ```
     pub fn titles<F>(mut self, f: F) -> Self
     where
@@ -1223,6 +2324,42 @@ impl<Other: ExtendableThing, Status> ThingBuilder<Other, Status> {
         self
     }
 
+    /// Add a link to the Thing Model the Thing is an instance of.
+    ///
+    /// The link has `rel` set to `"type"` and `type` set to [`TM_MEDIA_TYPE`]. At most one link of
+    /// this kind is allowed, otherwise [`build`](Self::build) fails with
+    /// [`DocumentError::MultipleThingModelLinks`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::thing::Thing;
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .model_link("https://example.com/models/lamp.tm.jsonld")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(thing).unwrap(),
+    ///     json!({
+    ///         "title": "Thing name",
+    ///         "@context": "https://www.w3.org/2022/wot/td/v1.1",
+    ///         "links": [{
+    ///             "href": "https://example.com/models/lamp.tm.jsonld",
+    ///             "rel": "type",
+    ///             "type": "application/tm+json",
+    ///         }],
+    ///         "security": [],
+    ///         "securityDefinitions": {},
+    ///     })
+    /// );
+    /// ```
+    pub fn model_link(self, href: impl Into<String>) -> Self {
+        self.link_with(|builder| builder.href(href).rel("type").ty(TM_MEDIA_TYPE))
+    }
+
     /// Add an additional link to the Thing Description, with specified optional fields.
     ///
     /// # Example
@@ -1374,21 +2511,228 @@ impl<Other: ExtendableThing, Status> ThingBuilder<Other, Status> {
         });
 
         if required {
-            self.security.push(name.clone());
+            push_unique(&mut self.security, name.clone());
         }
         self.security_definitions.push((name, security_scheme));
 
         self
     }
 
-    /// Adds a new item to the `profile` field.
-    pub fn profile(mut self, value: impl Into<String>) -> Self {
-        self.profile.push(value.into());
-        self
+    /// Sets the order of the `security` field.
+    ///
+    /// Some consumers try the security schemes in order, therefore the order can matter. The
+    /// required security definitions listed in `order` come first, in the given order, followed
+    /// by the other ones in insertion order. The names that are not required are ignored.
+    ///
+    /// Calling it multiple times overwrites the order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::thing::Thing;
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .security(|b| b.basic().with_key("basic_sc").required())
+    ///     .security(|b| b.apikey().with_key("apikey_sc").required())
+    ///     .security(|b| b.bearer().with_key("bearer_sc").required())
+    ///     .security_order(["bearer_sc", "basic_sc"])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(thing.security, ["bearer_sc", "basic_sc", "apikey_sc"]);
+    /// ```
+    pub fn security_order<I>(mut self, order: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.security_order = order.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Adds a new item to the `profile` field.
+    pub fn profile(mut self, value: impl Into<String>) -> Self {
+        self.profile.push(value.into());
+        self
+    }
+
+    /// Enables the stricter checks on the forms of the affordances.
+    ///
+    /// When enabled, [`build`] fails with:
+    ///
+    /// - [`AffordanceError::AffordanceWithoutForm`] if a property, an action or an event does not
+    ///   have any form, regardless of the forms of the Thing;
+    /// - [`AffordanceError::ObservableWithoutObserveForm`] if an `observable` property does not have
+    ///   any form with the `observeproperty` operation;
+    /// - [`AffordanceError::ShadowedUriVariable`] if an affordance declares a URI variable with the
+    ///   same name of one of the Thing.
+    ///
+    /// Many runtimes reject or silently ignore these affordances.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     builder::{
+    /// #         data_schema::SpecializableDataSchema, AffordanceError, BuildableInteractionAffordance,
+    /// #         Error,
+    /// #     },
+    /// #     thing::Thing,
+    /// # };
+    /// #
+    /// let error = Thing::builder("Thing name")
+    ///     .strict_forms()
+    ///     .finish_extend()
+    ///     .property("on", |b| {
+    ///         b.finish_extend_data_schema()
+    ///             .observable(true)
+    ///             .form(|b| b.href("/on"))
+    ///             .bool()
+    ///     })
+    ///     .build()
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     error,
+    ///     Error::Affordance(AffordanceError::ObservableWithoutObserveForm("on".to_string())),
+    /// );
+    /// ```
+    ///
+    /// [`build`]: ThingBuilder::build
+    pub fn strict_forms(mut self) -> Self {
+        self.strict_forms = true;
+        self
+    }
+
+    /// Rejects the `contentEncoding` values outside the registered set.
+    ///
+    /// By default, any encoding is accepted, in order to support the vendor ones. With this
+    /// option, [`build`] fails with [`DataSchemaError::UnknownContentEncoding`] if a string schema
+    /// uses an encoding that is not a [`ContentEncoding`] known variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     builder::{
+    /// #         data_schema::{SpecializableDataSchema, StringDataSchemaBuilderLike},
+    /// #         BuildableInteractionAffordance, DataSchemaError, Error,
+    /// #     },
+    /// #     thing::Thing,
+    /// # };
+    /// #
+    /// let builder = || {
+    ///     Thing::builder("Thing name").finish_extend().action("upload", |b| {
+    ///         b.input(|b| {
+    ///             b.finish_extend()
+    ///                 .string()
+    ///                 .content_encoding("base85")
+    ///                 .content_media_type("image/png")
+    ///         })
+    ///         .form(|b| b.href("/upload"))
+    ///     })
+    /// };
+    ///
+    /// assert!(builder().build().is_ok());
+    /// assert_eq!(
+    ///     builder().strict_content_encoding().build().unwrap_err(),
+    ///     Error::DataSchema(DataSchemaError::UnknownContentEncoding("base85".to_string())),
+    /// );
+    /// ```
+    ///
+    /// [`build`]: ThingBuilder::build
+    /// [`ContentEncoding`]: crate::thing::ContentEncoding
+    pub fn strict_content_encoding(mut self) -> Self {
+        self.strict_content_encoding = true;
+        self
+    }
+
+    /// Sets the content type of the forms that do not declare one.
+    ///
+    /// The content type is stored in [`Thing::default_content_type`] and it is used by
+    /// [`Form::content_type_or`], while the forms are left untouched. The
+    /// [`SerializeOptions::omit_default_content_type`] option allows to omit the content type of
+    /// the forms matching the default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     builder::{data_schema::SpecializableDataSchema, BuildableInteractionAffordance},
+    /// #     thing::Thing,
+    /// # };
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .default_content_type("application/cbor")
+    ///     .finish_extend()
+    ///     .property("on", |b| {
+    ///         b.finish_extend_data_schema()
+    ///             .form(|b| b.href("/on"))
+    ///             .form(|b| b.href("/on.json").content_type("application/json"))
+    ///             .bool()
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let forms = &thing.properties.as_ref().unwrap()["on"].interaction.forms;
+    /// assert_eq!(forms[0].content_type, None);
+    /// assert_eq!(forms[0].content_type_or(&thing), "application/cbor");
+    /// assert_eq!(forms[1].content_type_or(&thing), "application/json");
+    /// ```
+    ///
+    /// [`SerializeOptions::omit_default_content_type`]: crate::thing::SerializeOptions::omit_default_content_type
+    pub fn default_content_type(mut self, value: impl Into<String>) -> Self {
+        self.default_content_type = Some(value.into());
+        self
+    }
+
+    /// Leaves the `op` of the forms of the affordances unset when it is not explicitly set.
+    ///
+    /// By default [`build`] sets the operations returned by [`default_ops_for`] on these forms,
+    /// so that the consumers do not need to know the default values of the specification. With
+    /// this option the operations are implied, like in a Thing Description that omits them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     builder::{
+    /// #         data_schema::{ReadableWriteableDataSchema, SpecializableDataSchema},
+    /// #         BuildableInteractionAffordance,
+    /// #     },
+    /// #     thing::{DefaultedFormOperations, FormOperation, Thing},
+    /// # };
+    /// #
+    /// let builder = || {
+    ///     Thing::builder("Thing name").finish_extend().property("on", |b| {
+    ///         b.finish_extend_data_schema()
+    ///             .form(|b| b.href("/on"))
+    ///             .bool()
+    ///             .read_only()
+    ///     })
+    /// };
+    ///
+    /// let thing = builder().build().unwrap();
+    /// assert_eq!(
+    ///     thing.properties.unwrap()["on"].interaction.forms[0].op,
+    ///     DefaultedFormOperations::Custom(vec![FormOperation::ReadProperty]),
+    /// );
+    ///
+    /// let thing = builder().implicit_default_ops().build().unwrap();
+    /// assert!(thing.properties.unwrap()["on"].interaction.forms[0]
+    ///     .op
+    ///     .is_default());
+    /// ```
+    ///
+    /// [`build`]: ThingBuilder::build
+    /// [`default_ops_for`]: crate::thing::default_ops_for
+    pub fn implicit_default_ops(mut self) -> Self {
+        self.implicit_default_ops = true;
+        self
     }
 }
 
-impl<Other> ThingBuilder<Other, Extended>
+impl<Other, Status, Title> ThingBuilder<Other, Status, Title>
 where
     Other: ExtendableThing,
     Other::Form: Extendable,
@@ -1438,7 +2782,7 @@ where
     /// returns an error:
     ///
     /// ```
-    /// # use wot_td::{builder::Error, thing::Thing};
+    /// # use wot_td::{builder::{AffordanceError, Error}, thing::Thing};
     /// #
     /// let error = Thing::builder("Thing name")
     ///     .finish_extend()
@@ -1446,7 +2790,7 @@ where
     ///     .build()
     ///     .unwrap_err();
     ///
-    /// assert_eq!(error, Error::MissingOpInForm);
+    /// assert_eq!(error, Error::Affordance(AffordanceError::MissingOpInForm));
     /// ```
     ///
     /// Furthermore, Thing-level form operations must be one or more of the following
@@ -1464,7 +2808,7 @@ where
     ///
     /// ```
     /// # use wot_td::{
-    /// #     builder::{Error, FormContext},
+    /// #     builder::{AffordanceError, Error, FormContext},
     /// #     thing::{FormOperation, Thing},
     /// # };
     /// #
@@ -1476,14 +2820,15 @@ where
     ///
     /// assert_eq!(
     ///     error,
-    ///     Error::InvalidOpInForm {
+    ///     Error::Affordance(AffordanceError::InvalidOpInForm {
     ///         context: FormContext::Thing,
     ///         operation: FormOperation::ReadProperty,
-    ///     }
+    ///     })
     /// );
     /// ```
     pub fn form<F, R>(mut self, f: F) -> Self
     where
+        Status: IsExtended,
         F: FnOnce(FormBuilder<Other, (), <Other::Form as Extendable>::Empty>) -> R,
         R: Into<FormBuilder<Other, String, Other::Form>>,
     {
@@ -1494,7 +2839,70 @@ where
     }
 }
 
-impl<Other> ThingBuilder<Other, Extended>
+impl<Other, Status, Title> ThingBuilder<Other, Status, Title>
+where
+    Other: ExtendableThingFields,
+{
+    /// Checks that the extensions do not serialize the same members when the Thing is built.
+    ///
+    /// Extensions are flattened into the structures they extend, therefore two extensions (or an
+    /// extension and the TD vocabulary) serializing a member with the same name produce an
+    /// ambiguous Thing Description. When this check is enabled, [`build`] serializes every
+    /// extension of the Thing and returns [`DocumentError::ExtensionFieldCollision`] in case of
+    /// collisions. This has a runtime cost proportional to the size of the Thing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Deserialize, Serialize};
+    /// # use wot_td::{
+    /// #     builder::{DocumentError, Error},
+    /// #     extend::ExtendableThing,
+    /// #     thing::Thing,
+    /// # };
+    /// #
+    /// #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    /// struct Labelled {
+    ///     title: String,
+    /// }
+    ///
+    /// impl ExtendableThing for Labelled {
+    ///     type InteractionAffordance = ();
+    ///     type PropertyAffordance = ();
+    ///     type ActionAffordance = ();
+    ///     type EventAffordance = ();
+    ///     type Form = ();
+    ///     type ExpectedResponse = ();
+    ///     type DataSchema = ();
+    ///     type ObjectSchema = ();
+    ///     type ArraySchema = ();
+    /// }
+    ///
+    /// let error = Thing::builder("Thing name")
+    ///     .ext(Labelled::default())
+    ///     .finish_extend()
+    ///     .check_extension_collisions()
+    ///     .build()
+    ///     .unwrap_err();
+    ///
+    /// assert!(matches!(
+    ///     error,
+    ///     Error::Document(DocumentError::ExtensionFieldCollision { field, .. })
+    ///         if field == "title",
+    /// ));
+    /// ```
+    ///
+    /// [`build`]: ThingBuilder::build
+    pub fn check_extension_collisions(mut self) -> Self
+    where
+        Status: IsExtended,
+    {
+        self.extension_collision_check = Some(check_extension_fields::<Other>);
+        self
+    }
+}
+
+impl<Other, Status, Title> ThingBuilder<Other, Status, Title>
 where
     Other: ExtendableThing,
 {
@@ -1536,6 +2944,7 @@ where
     /// ```
     pub fn uri_variable<F, T>(mut self, name: impl Into<String>, f: F) -> Self
     where
+        Status: IsExtended,
         F: FnOnce(
             DataSchemaBuilder<
                 <Other::DataSchema as Extendable>::Empty,
@@ -1565,6 +2974,7 @@ where
     /// works.
     pub fn property<F, T>(mut self, name: impl Into<String>, f: F) -> Self
     where
+        Status: IsExtended,
         F: FnOnce(
             PropertyAffordanceBuilder<
                 Other,
@@ -1592,6 +3002,81 @@ where
         self
     }
 
+    /// Adds a new property affordance from an already built data schema.
+    ///
+    /// The property is composed by the _partial_ data schema and the human readable information,
+    /// without forms, URI variables and with the default extensions. The data schema is checked
+    /// when the Thing is built, like the ones created through [`property`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::{
+    /// #     builder::{
+    /// #         data_schema::{
+    /// #             IntegerDataSchemaBuilderLike, PartialDataSchema, PartialDataSchemaBuilder,
+    /// #             SpecializableDataSchema,
+    /// #         },
+    /// #         BuildableHumanReadableInfo, HumanReadableInfo,
+    /// #     },
+    /// #     hlist::Nil,
+    /// #     thing::Thing,
+    /// # };
+    /// #
+    /// let data_schema: PartialDataSchema<Nil, Nil, Nil> =
+    ///     PartialDataSchemaBuilder::default().integer().minimum(0).into();
+    ///
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .property_from(
+    ///         "level",
+    ///         data_schema,
+    ///         HumanReadableInfo::default().title("Level"),
+    ///     )
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(&thing).unwrap()["properties"]["level"],
+    ///     json!({
+    ///         "title": "Level",
+    ///         "type": "integer",
+    ///         "minimum": 0,
+    ///         "forms": [],
+    ///         "readOnly": false,
+    ///         "writeOnly": false,
+    ///     }),
+    /// );
+    /// ```
+    ///
+    /// [`property`]: Self::property
+    pub fn property_from(
+        mut self,
+        name: impl Into<String>,
+        data_schema: PartialDataSchema<Other::DataSchema, Other::ArraySchema, Other::ObjectSchema>,
+        info: HumanReadableInfo,
+    ) -> Self
+    where
+        Status: IsExtended,
+        Other::InteractionAffordance: Default,
+        Other::PropertyAffordance: Default,
+    {
+        let affordance = PropertyAffordanceBuilder {
+            interaction: Default::default(),
+            info,
+            data_schema,
+            observable: Default::default(),
+            other: Default::default(),
+        };
+        let affordance_builder = AffordanceBuilder {
+            name: name.into(),
+            affordance,
+        };
+        self.properties.push(affordance_builder);
+        self
+    }
+
     /// Adds a new action affordance.
     ///
     /// It takes a function that accepts a `ActionAffordance` builder and must return a type
@@ -1601,6 +3086,7 @@ where
     /// works.
     pub fn action<F, T>(mut self, name: impl Into<String>, f: F) -> Self
     where
+        Status: IsExtended,
         F: FnOnce(
             ActionAffordanceBuilder<
                 Other,
@@ -1623,6 +3109,86 @@ where
         self
     }
 
+    /// Adds a new asynchronous action affordance.
+    ///
+    /// It works like [`action`], but the action is marked as not `synchronous` and a form to
+    /// query the status of the running action is added after the forms set by `f`. The status
+    /// form uses `status_href` and the `queryaction` operation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::{
+    /// #     builder::{affordance::BuildableInteractionAffordance, data_schema::SpecializableDataSchema},
+    /// #     thing::Thing,
+    /// # };
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .async_action(
+    ///         "fade",
+    ///         |b| {
+    ///             b.input(|b| b.finish_extend().integer())
+    ///                 .form(|b| b.href("/fade"))
+    ///         },
+    ///         "/fade/status",
+    ///     )
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(&thing.actions.unwrap()["fade"]).unwrap(),
+    ///     json!({
+    ///         "input": {
+    ///             "type": "integer",
+    ///             "readOnly": false,
+    ///             "writeOnly": false,
+    ///         },
+    ///         "forms": [
+    ///             { "href": "/fade", "op": ["invokeaction"] },
+    ///             { "href": "/fade/status", "op": ["queryaction"] },
+    ///         ],
+    ///         "safe": false,
+    ///         "idempotent": false,
+    ///         "synchronous": false,
+    ///     }),
+    /// );
+    /// ```
+    ///
+    /// [`action`]: Self::action
+    pub fn async_action<F, T>(
+        self,
+        name: impl Into<String>,
+        f: F,
+        status_href: impl Into<String>,
+    ) -> Self
+    where
+        Status: IsExtended,
+        F: FnOnce(
+            ActionAffordanceBuilder<
+                Other,
+                <Other::InteractionAffordance as Extendable>::Empty,
+                <Other::ActionAffordance as Extendable>::Empty,
+            >,
+        ) -> T,
+        Other::InteractionAffordance: Extendable,
+        Other::ActionAffordance: Extendable,
+        Other::Form: Extendable,
+        T: IntoUsable<
+            ActionAffordanceBuilder<Other, Other::InteractionAffordance, Other::ActionAffordance>,
+        >,
+        FormBuilder<Other, String, <Other::Form as Extendable>::Empty>:
+            Into<FormBuilder<Other, String, Other::Form>>,
+    {
+        let status_href = status_href.into();
+        self.action(name, |b| {
+            f(b).into_usable()
+                .synchronous(false)
+                .form(|b| b.href(status_href).op(FormOperation::QueryAction))
+        })
+    }
+
     /// Adds a new event affordance.
     ///
     /// It takes a function that accepts an `EventAffordance` builder and must return a type
@@ -1631,6 +3197,7 @@ where
     /// See [`EventAffordanceBuilder`] for more information about how the underlying builder works.
     pub fn event<F, T>(mut self, name: impl Into<String>, f: F) -> Self
     where
+        Status: IsExtended,
         F: FnOnce(
             EventAffordanceBuilder<
                 Other,
@@ -1661,6 +3228,7 @@ where
     /// See [`DataSchemaBuilder`] for more information about how the underlying builder works.
     pub fn schema_definition<F, T>(mut self, name: impl Into<String>, f: F) -> Self
     where
+        Status: IsExtended,
         F: FnOnce(
             DataSchemaBuilder<
                 <Other::DataSchema as Extendable>::Empty,
@@ -1678,6 +3246,48 @@ where
         );
         self
     }
+
+    /// Adds an already built `DataSchema` as a new schema definition.
+    ///
+    /// This is useful to share a library of data schemas between different Things. The data
+    /// schema is checked by [`ThingBuilder::build`] like every schema definition, therefore an
+    /// invalid data schema makes the build fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::{
+    /// #     hlist::Nil,
+    /// #     thing::{DataSchema, Thing},
+    /// # };
+    /// #
+    /// let color: DataSchema<Nil, Nil, Nil> = serde_json::from_value(json!({
+    ///     "type": "string",
+    ///     "enum": ["red", "green", "blue"],
+    /// }))
+    /// .unwrap();
+    ///
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .schema_definition_from("color", color.clone())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(thing.schema_definitions.unwrap()["color"], color);
+    /// ```
+    pub fn schema_definition_from(
+        mut self,
+        name: impl Into<String>,
+        data_schema: DataSchemaFromOther<Other>,
+    ) -> Self
+    where
+        Status: IsExtended,
+    {
+        self.schema_definitions
+            .insert(name.into(), data_schema.into());
+        self
+    }
 }
 
 fn try_build_affordance<A, F, IA, G, DS, T, H, const N: usize>(
@@ -1705,6 +3315,7 @@ where
                 .into_iter()
                 .try_fold(new_affordances, |mut affordances, affordance| {
                     let AffordanceBuilder { name, affordance } = affordance;
+                    check_name(&name, affordance_type.into())?;
 
                     get_interaction(&affordance).check(
                         security_definitions,
@@ -1718,15 +3329,21 @@ where
 
                     match affordances.entry(name) {
                         Entry::Vacant(entry) => {
-                            entry.insert(affordance.build()?);
+                            let affordance = affordance.build().map_err(|error| {
+                                error
+                                    .within(entry.key())
+                                    .within(NameKind::from(affordance_type))
+                            })?;
+                            entry.insert(affordance);
                             Ok(affordances)
                         }
                         Entry::Occupied(entry) => {
                             let name = entry.key().to_owned();
-                            Err(Error::DuplicatedAffordance {
+                            Err(AffordanceError::DuplicatedAffordance {
                                 ty: affordance_type,
                                 name,
-                            })
+                            }
+                            .into())
                         }
                     }
                 })
@@ -1734,6 +3351,7 @@ where
         .transpose()
 }
 
+#[derive(Clone)]
 enum Context {
     Simple(String),
     Map(HashMap<String, String>),
@@ -1763,7 +3381,8 @@ impl ContextMapBuilder {
 }
 
 /// Builder for language-specific variants of a field (e.g. titles, descriptions)
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(transparent)]
 pub struct MultiLanguageBuilder<T> {
     values: HashMap<String, T>,
 }
@@ -1777,17 +3396,20 @@ impl<T> MultiLanguageBuilder<T> {
         self
     }
 
-    pub(crate) fn build(self) -> Result<HashMap<LanguageTag<String>, T>, Error> {
-        self.values
+    pub(crate) fn from_built(values: HashMap<LanguageTag<String>, T>) -> Self {
+        let values = values
             .into_iter()
-            .map(|(k, v)| {
-                // See https://github.com/oxigraph/oxilangtag/issues/4 for the reason of this,
-                // which unnecessarily allocate.
-                k.parse()
-                    .map(|k| (k, v))
-                    .map_err(|_| Error::InvalidLanguageTag(k))
-            })
-            .collect()
+            .map(|(language, value)| (language.into_inner(), value))
+            .collect();
+        Self { values }
+    }
+
+    pub(crate) fn build(self) -> Result<HashMap<LanguageTag<String>, T>, Error> {
+        let mut map = HashMap::with_capacity(self.values.len());
+        for (k, v) in self.values {
+            map.insert(parse_language_tag(k)?, v);
+        }
+        Ok(map)
     }
 }
 
@@ -1859,7 +3481,7 @@ pub mod security {
         UnknownSecuritySchemeSubtype,
     };
 
-    use crate::builder::MultiLanguageBuilder;
+    use crate::builder::{push_unique, MultiLanguageBuilder};
 
     /// Builder for the Security Scheme
     pub struct SecuritySchemeBuilder<S> {
@@ -2140,13 +3762,19 @@ pub mod security {
     }
 
     impl<T> SecuritySchemeBuilder<T> {
-        opt_field_builder!(description: String, proxy: String);
+        opt_field_builder!(description: String);
+
+        /// Sets the URI of the proxy server this security configuration provides access to.
+        ///
+        /// The URI is validated when the Thing is built.
+        pub fn proxy(mut self, value: impl Into<String>) -> Self {
+            self.proxy = Some(value.into());
+            self
+        }
 
         /// JSON-LD @type
         pub fn attype(mut self, ty: impl Into<String>) -> Self {
-            self.attype
-                .get_or_insert_with(Default::default)
-                .push(ty.into());
+            push_unique(self.attype.get_or_insert_with(Default::default), ty.into());
             self
         }
 
@@ -2347,7 +3975,7 @@ pub mod security {
         /// security definitions, `Thing::build` returns an error:
         ///
         /// ```
-        /// # use wot_td::{builder::Error, thing::Thing};
+        /// # use wot_td::{builder::{DataSchemaError, Error}, thing::Thing};
         /// #
         /// let error = Thing::builder("Thing name")
         ///     .finish_extend()
@@ -2356,7 +3984,10 @@ pub mod security {
         ///     .build()
         ///     .unwrap_err();
         ///
-        /// assert_eq!(error, Error::MissingSchemaDefinition("basic".to_string()));
+        /// assert_eq!(
+        ///     error,
+        ///     Error::DataSchema(DataSchemaError::MissingSchemaDefinition("basic".to_string())),
+        /// );
         /// ```
         pub fn all_of<I, T>(
             self,
@@ -2431,7 +4062,7 @@ pub mod security {
         /// security definitions, `Thing::build` returns an error:
         ///
         /// ```
-        /// # use wot_td::{builder::Error, thing::Thing};
+        /// # use wot_td::{builder::{DataSchemaError, Error}, thing::Thing};
         /// #
         /// let error = Thing::builder("Thing name")
         ///     .finish_extend()
@@ -2440,7 +4071,10 @@ pub mod security {
         ///     .build()
         ///     .unwrap_err();
         ///
-        /// assert_eq!(error, Error::MissingSchemaDefinition("basic".to_string()));
+        /// assert_eq!(
+        ///     error,
+        ///     Error::DataSchema(DataSchemaError::MissingSchemaDefinition("basic".to_string())),
+        /// );
         /// ```
         pub fn one_of<I, T>(
             self,
@@ -2593,6 +4227,30 @@ pub struct FormBuilder<Other: ExtendableThing, Href, OtherForm> {
     _marker: PhantomData<fn() -> Other>,
 }
 
+impl<Other, Href, OtherForm> Clone for FormBuilder<Other, Href, OtherForm>
+where
+    Other: ExtendableThing,
+    Other::ExpectedResponse: Clone,
+    Href: Clone,
+    OtherForm: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            op: self.op.clone(),
+            href: self.href.clone(),
+            content_type: self.content_type.clone(),
+            content_coding: self.content_coding.clone(),
+            subprotocol: self.subprotocol.clone(),
+            security: self.security.clone(),
+            scopes: self.scopes.clone(),
+            response: self.response.clone(),
+            additional_responses: self.additional_responses.clone(),
+            other: self.other.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
 impl<Other> FormBuilder<Other, (), <Other::Form as Extendable>::Empty>
 where
     Other: ExtendableThing,
@@ -2668,24 +4326,63 @@ where
     ///
     /// Depending on its parent the form may have a Default operation
     /// or it must be explicitly set.
+    ///
+    /// It can be called multiple times to add more operations, adding the same operation more
+    /// than once has no effect.
     pub fn op(mut self, new_op: FormOperation) -> Self {
         match &mut self.op {
             ops @ DefaultedFormOperations::Default => {
                 *ops = DefaultedFormOperations::Custom(vec![new_op])
             }
-            DefaultedFormOperations::Custom(ops) => ops.push(new_op),
+            DefaultedFormOperations::Custom(ops) => push_unique(ops, new_op),
         }
 
         self
     }
 
-    /// Set the security definitions that must be satisfied to access the resource
+    /// Add multiple form operations at once
     ///
-    /// They must be set beforehand by [Thing::security].
+    /// It behaves like calling [`op`](Self::op) for each operation, therefore the order is
+    /// preserved and the duplicated operations are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::thing::{FormOperation, Thing};
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .form(|b| {
+    ///         b.href("/properties").ops([
+    ///             FormOperation::ReadAllProperties,
+    ///             FormOperation::WriteAllProperties,
+    ///             FormOperation::ReadAllProperties,
+    ///         ])
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(&thing.forms.unwrap()[0].op).unwrap(),
+    ///     json!(["readallproperties", "writeallproperties"]),
+    /// );
+    /// ```
+    pub fn ops<I>(self, ops: I) -> Self
+    where
+        I: IntoIterator<Item = FormOperation>,
+    {
+        ops.into_iter().fold(self, Self::op)
+    }
+
+    /// Set the security definitions that must be satisfied to access the resource
+    ///
+    /// They must be set beforehand by [Thing::security].
     pub fn security(mut self, value: impl Into<String>) -> Self {
-        self.security
-            .get_or_insert_with(Default::default)
-            .push(value.into());
+        push_unique(
+            self.security.get_or_insert_with(Default::default),
+            value.into(),
+        );
         self
     }
 
@@ -2869,7 +4566,7 @@ where
     }
 }
 
-impl<Other> From<FormBuilder<Other, String, Other::Form>> for Form<Other>
+impl<Other> From<FormBuilder<Other, String, Other::Form>> for FormFromOther<Other>
 where
     Other: ExtendableThing,
 {
@@ -2904,6 +4601,8 @@ where
             response,
             additional_responses,
             other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown: Default::default(),
         }
     }
 }
@@ -2944,14 +4643,40 @@ impl AdditionalExpectedResponseBuilder {
     }
 }
 
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Deserialize, Serialize)]
 pub(crate) struct UncheckedSecurityScheme {
+    #[serde(rename = "@type", default)]
+    #[serde_as(as = "Option<StringOrArray>")]
     attype: Option<Vec<String>>,
     description: Option<String>,
     descriptions: Option<MultiLanguageBuilder<String>>,
     proxy: Option<String>,
+    #[serde(flatten)]
     subtype: SecuritySchemeSubtype,
 }
 
+impl From<SecurityScheme> for UncheckedSecurityScheme {
+    fn from(scheme: SecurityScheme) -> Self {
+        let SecurityScheme {
+            attype,
+            description,
+            descriptions,
+            proxy,
+            subtype,
+        } = scheme;
+
+        Self {
+            attype: format_at_types(attype),
+            description,
+            descriptions: descriptions.map(MultiLanguageBuilder::from_built),
+            proxy,
+            subtype,
+        }
+    }
+}
+
 impl TryFrom<UncheckedSecurityScheme> for SecurityScheme {
     type Error = Error;
 
@@ -2965,11 +4690,19 @@ impl TryFrom<UncheckedSecurityScheme> for SecurityScheme {
         } = scheme;
 
         let descriptions = descriptions
-            .map(|descriptions| descriptions.build())
+            .map(|descriptions| {
+                descriptions
+                    .build()
+                    .map_err(|error| error.at("descriptions"))
+            })
             .transpose()?;
 
+        if let Some(proxy) = proxy.as_ref().filter(|proxy| !is_valid_reference(proxy)) {
+            return Err(DocumentError::InvalidUri(proxy.clone()).into());
+        }
+
         Ok(Self {
-            attype,
+            attype: parse_at_types(attype),
             description,
             descriptions,
             proxy,
@@ -2981,16 +4714,50 @@ impl TryFrom<UncheckedSecurityScheme> for SecurityScheme {
 /// The _unchecked_ variant of [`Link`](crate::thing::Link).
 ///
 /// The type needs to be _try-converted_ into `Link` in order to being used inside a
-/// [`Thing`](crate::thing::Thing).
+/// [`Thing`](crate::thing::Thing). It is serialized and deserialized like a `Link`.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct UncheckedLink {
     href: String,
+    #[serde(rename = "type")]
     ty: Option<String>,
     rel: Option<String>,
     anchor: Option<String>,
     sizes: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde_as(as = "StringOrArray")]
     hreflang: Vec<String>,
 }
 
+impl From<Link> for UncheckedLink {
+    fn from(link: Link) -> Self {
+        let Link {
+            href,
+            ty,
+            rel,
+            anchor,
+            sizes,
+            hreflang,
+        } = link;
+
+        let hreflang = hreflang
+            .into_iter()
+            .flatten()
+            .map(LanguageTag::into_inner)
+            .collect();
+
+        Self {
+            href,
+            ty,
+            rel,
+            anchor,
+            sizes,
+            hreflang,
+        }
+    }
+}
+
 impl TryFrom<UncheckedLink> for Link {
     type Error = Error;
 
@@ -3005,12 +4772,12 @@ impl TryFrom<UncheckedLink> for Link {
         } = link;
 
         if sizes.is_some() && rel.as_deref() != Some("icon") {
-            return Err(Error::SizesWithRelNotIcon);
+            return Err(DocumentError::SizesWithRelNotIcon.into());
         }
 
         let hreflang = hreflang
             .into_iter()
-            .map(|lang| lang.parse().map_err(|_| Error::InvalidLanguageTag(lang)))
+            .map(parse_language_tag)
             .collect::<Result<Vec<_>, _>>()?;
         let hreflang = hreflang.is_empty().not().then_some(hreflang);
 
@@ -3036,16 +4803,18 @@ mod tests {
     use crate::{
         builder::{
             affordance::BuildableInteractionAffordance,
-            data_schema::{NumberDataSchemaBuilderLike, SpecializableDataSchema},
+            data_schema::{
+                IntegerDataSchemaBuilderLike, NumberDataSchemaBuilderLike, SpecializableDataSchema,
+            },
             human_readable_info::BuildableHumanReadableInfo,
         },
         hlist::{Cons, Nil},
         thing::{
             ActionAffordance, ApiKeySecurityScheme, BasicSecurityScheme, BearerSecurityScheme,
-            DataSchema, DataSchemaSubtype, DigestSecurityScheme, EventAffordance,
-            InteractionAffordance, Maximum, Minimum, NumberSchema, OAuth2SecurityScheme,
-            ObjectSchema, PropertyAffordance, PskSecurityScheme, QualityOfProtection,
-            SecurityAuthenticationLocation, SecurityScheme, StringSchema,
+            ContentEncoding, DataSchema, DataSchemaSubtype, DigestSecurityScheme, EventAffordance,
+            IntegerSchema, InteractionAffordance, Maximum, Minimum, NumberSchema,
+            OAuth2SecurityScheme, ObjectSchema, PropertyAffordance, PskSecurityScheme,
+            QualityOfProtection, SecurityAuthenticationLocation, SecurityScheme, StringSchema,
         },
     };
 
@@ -3166,7 +4935,7 @@ mod tests {
             Thing {
                 context: TD_CONTEXT_11.into(),
                 title: "MyLampThing".to_string(),
-                attype: Some(vec!["test".to_string()]),
+                attype: Some(vec!["test".into()]),
                 ..Default::default()
             }
         );
@@ -3182,12 +4951,83 @@ mod tests {
             Thing {
                 context: TD_CONTEXT_11.into(),
                 title: "MyLampThing".to_string(),
-                attype: Some(vec!["test1".to_string(), "test2".to_string()]),
+                attype: Some(vec!["test1".into(), "test2".into()]),
                 ..Default::default()
             }
         );
     }
 
+    #[test]
+    fn repeated_attype_and_security_are_idempotent() {
+        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .attype("Lamp")
+            .attype("Light")
+            .attype("Lamp")
+            .security(|b| b.basic().required())
+            .form(|b| {
+                b.href("/all")
+                    .op(FormOperation::ReadAllProperties)
+                    .security("basic")
+                    .security("basic")
+            })
+            .property("on", |b| {
+                b.finish_extend_data_schema()
+                    .attype("OnOff")
+                    .attype("OnOff")
+                    .bool()
+            })
+            .build()
+            .unwrap();
+
+        assert_eq!(thing.attype.as_ref().unwrap(), &["Lamp", "Light"]);
+        assert_eq!(thing.security, ["basic"]);
+        assert_eq!(
+            thing.forms.unwrap()[0].security,
+            Some(vec!["basic".to_string()])
+        );
+        assert_eq!(
+            thing.properties.unwrap()["on"]
+                .data_schema
+                .attype
+                .as_ref()
+                .unwrap(),
+            &["OnOff"]
+        );
+    }
+
+    #[test]
+    fn explicit_security_order() {
+        let builder = || {
+            ThingBuilder::<Nil, _>::new("MyLampThing")
+                .security(|b| b.basic().with_key("basic_sc").required())
+                .security(|b| b.apikey().with_key("apikey_sc").required())
+                .security(|b| {
+                    b.oauth2("code")
+                        .with_key("oauth2_sc")
+                        .authorization("https://example.com/authorize")
+                        .token("https://example.com/token")
+                        .required()
+                })
+        };
+
+        let thing = builder().build().unwrap();
+        assert_eq!(thing.security, ["basic_sc", "apikey_sc", "oauth2_sc"]);
+
+        let thing = builder()
+            .security_order(["oauth2_sc", "basic_sc"])
+            .build()
+            .unwrap();
+        assert_eq!(thing.security, ["oauth2_sc", "basic_sc", "apikey_sc"]);
+
+        let thing = builder()
+            .security_order(["apikey_sc"])
+            .security_order(["oauth2_sc", "missing_sc", "apikey_sc"])
+            .build()
+            .unwrap();
+        assert_eq!(thing.security, ["oauth2_sc", "apikey_sc", "basic_sc"]);
+    }
+
     #[test]
     fn titles() {
         let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
@@ -3361,7 +5201,59 @@ mod tests {
             .build()
             .unwrap_err();
 
-        assert_eq!(error, Error::SizesWithRelNotIcon);
+        assert_eq!(error, Error::Document(DocumentError::SizesWithRelNotIcon));
+    }
+
+    #[test]
+    fn model_link() {
+        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .link("href1")
+            .model_link("lamp.tm.jsonld")
+            .build()
+            .unwrap();
+
+        let expected_link = Link {
+            href: "lamp.tm.jsonld".to_string(),
+            ty: Some("application/tm+json".to_string()),
+            rel: Some("type".to_string()),
+            anchor: Default::default(),
+            sizes: Default::default(),
+            hreflang: Default::default(),
+        };
+        assert_eq!(thing.links.as_ref().unwrap()[1], expected_link);
+        assert_eq!(thing.thing_model_link(), Some(&expected_link));
+    }
+
+    #[test]
+    fn multiple_model_links() {
+        let error = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .model_link("lamp.tm.jsonld")
+            .link_with(|link| {
+                link.href("other.tm.jsonld")
+                    .rel("type")
+                    .ty("application/tm+json")
+            })
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            Error::Document(DocumentError::MultipleThingModelLinks)
+        );
+    }
+
+    #[test]
+    fn type_links_to_other_media_types() {
+        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .model_link("lamp.tm.jsonld")
+            .link_with(|link| link.href("lamp.html").rel("type").ty("text/html"))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            thing.thing_model_link().map(|link| link.href.as_str()),
+            Some("lamp.tm.jsonld"),
+        );
     }
 
     #[test]
@@ -3376,7 +5268,10 @@ mod tests {
             .build()
             .unwrap_err();
 
-        assert_eq!(error, Error::InvalidLanguageTag("i18".to_string()));
+        assert_eq!(
+            error,
+            Error::Document(DocumentError::InvalidLanguageTag("i18".to_string()))
+        );
     }
 
     #[test]
@@ -3403,7 +5298,7 @@ mod tests {
                 security_definitions: [(
                     "nosec".to_string(),
                     SecurityScheme {
-                        attype: Some(vec!["ty1".to_string(), "ty2".to_string()]),
+                        attype: Some(vec!["ty1".into(), "ty2".into()]),
                         description: Some("desc".to_string()),
                         descriptions: Some(
                             [
@@ -3448,7 +5343,7 @@ mod tests {
                 security_definitions: [(
                     "auto".to_string(),
                     SecurityScheme {
-                        attype: Some(vec!["ty1".to_string(), "ty2".to_string()]),
+                        attype: Some(vec!["ty1".into(), "ty2".into()]),
                         description: Some("desc".to_string()),
                         descriptions: Some(
                             [
@@ -3495,7 +5390,7 @@ mod tests {
                 security_definitions: [(
                     "basic".to_string(),
                     SecurityScheme {
-                        attype: Some(vec!["ty1".to_string(), "ty2".to_string()]),
+                        attype: Some(vec!["ty1".into(), "ty2".into()]),
                         description: Some("desc".to_string()),
                         descriptions: Some(
                             [
@@ -3548,7 +5443,7 @@ mod tests {
                 security_definitions: [(
                     "digest".to_string(),
                     SecurityScheme {
-                        attype: Some(vec!["ty1".to_string(), "ty2".to_string()]),
+                        attype: Some(vec!["ty1".into(), "ty2".into()]),
                         description: Some("desc".to_string()),
                         descriptions: Some(
                             [
@@ -3601,7 +5496,7 @@ mod tests {
                 security_definitions: [(
                     "apikey".to_string(),
                     SecurityScheme {
-                        attype: Some(vec!["ty1".to_string(), "ty2".to_string()]),
+                        attype: Some(vec!["ty1".into(), "ty2".into()]),
                         description: Some("desc".to_string()),
                         descriptions: Some(
                             [
@@ -3656,7 +5551,7 @@ mod tests {
                 security_definitions: [(
                     "bearer".to_string(),
                     SecurityScheme {
-                        attype: Some(vec!["ty1".to_string(), "ty2".to_string()]),
+                        attype: Some(vec!["ty1".into(), "ty2".into()]),
                         description: Some("desc".to_string()),
                         descriptions: Some(
                             [
@@ -3685,6 +5580,158 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bearer_security_with_proxy() {
+        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .security(|b| {
+                b.bearer()
+                    .proxy("https://proxy.example.com:8080/")
+                    .required()
+            })
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            thing.security_definitions["bearer"].proxy.as_deref(),
+            Some("https://proxy.example.com:8080/"),
+        );
+        assert_eq!(
+            serde_json::to_value(&thing).unwrap()["securityDefinitions"]["bearer"]["proxy"],
+            "https://proxy.example.com:8080/",
+        );
+    }
+
+    #[test]
+    fn basic_security_with_proxy_round_trip() {
+        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .security(|b| {
+                b.basic()
+                    .proxy("http://proxy.example.com/")
+                    .name("user")
+                    .required()
+            })
+            .build()
+            .unwrap();
+
+        let value = serde_json::to_value(&thing).unwrap();
+        assert_eq!(
+            value["securityDefinitions"]["basic"],
+            json!({
+                "scheme": "basic",
+                "proxy": "http://proxy.example.com/",
+                "in": "header",
+                "name": "user",
+            }),
+        );
+
+        let deserialized: Thing = serde_json::from_value(value).unwrap();
+        assert_eq!(deserialized, thing);
+        assert_eq!(
+            deserialized.security_definitions["basic"].proxy(),
+            Some("http://proxy.example.com/"),
+        );
+        assert_eq!(SecurityScheme::default().proxy(), None);
+    }
+
+    #[test]
+    fn invalid_security_proxy() {
+        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .security(|b| b.bearer().proxy("https://proxy example.com"))
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            Error::Document(DocumentError::InvalidUri(
+                "https://proxy example.com".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn invalid_uris() {
+        let invalid = |builder: ThingBuilder<Nil, ToExtend>| builder.build().unwrap_err();
+
+        let err = invalid(ThingBuilder::new("MyLampThing").id("urn:my lamp"));
+        assert_eq!(err.path(), Some("/id"));
+        assert_eq!(
+            err.without_path(),
+            &Error::Document(DocumentError::InvalidUri("urn:my lamp".to_string())),
+        );
+
+        let err = invalid(ThingBuilder::new("MyLampThing").base("http://example.com/%zz"));
+        assert_eq!(err.path(), Some("/base"));
+
+        let err = invalid(
+            ThingBuilder::new("MyLampThing")
+                .context_map(|b| b.context("saref", "https://w3id.org/saref #")),
+        );
+        assert_eq!(err.path(), Some("/@context/1/saref"));
+
+        let err = invalid(ThingBuilder::new("MyLampThing").link("http://example.com/my link"));
+        assert_eq!(err.path(), Some("/links/0/href"));
+
+        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .property("on", |b| {
+                b.finish_extend_data_schema()
+                    .bool()
+                    .form(|b| b.href("/on"))
+                    .form(|b| b.href("/my on"))
+            })
+            .build()
+            .unwrap_err();
+        assert_eq!(err.path(), Some("/properties/on/forms/1/href"));
+        assert_eq!(
+            err.without_path(),
+            &Error::Document(DocumentError::InvalidUri("/my on".to_string())),
+        );
+
+        ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .uri_variable("id", |b| b.finish_extend().integer())
+            .property("on", |b| {
+                b.finish_extend_data_schema()
+                    .bool()
+                    .form(|b| b.href("/things/{id}/on{?lang}"))
+                    .uri_variable("lang", |b| b.finish_extend().string())
+            })
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn non_ascii_security_proxy() {
+        let result = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .security(|b| b.bearer().proxy("https://prøxy.example.com/ünïcode"))
+            .build();
+
+        #[cfg(feature = "iri")]
+        {
+            let thing = result.unwrap();
+            assert_eq!(
+                thing.security_definitions["bearer"].proxy(),
+                Some("https://prøxy.example.com/ünïcode"),
+            );
+        }
+
+        #[cfg(not(feature = "iri"))]
+        {
+            let err = result.unwrap_err();
+            assert_eq!(
+                err,
+                Error::Document(DocumentError::InvalidUri(
+                    "https://prøxy.example.com/ünïcode".to_string()
+                ))
+            );
+            assert_eq!(
+                err.to_string(),
+                "Invalid URI \"https://prøxy.example.com/ünïcode\", rejected by the RFC 3986 URI \
+                 validation",
+            );
+        }
+    }
+
     #[test]
     fn oauth2_security() {
         let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
@@ -3714,7 +5761,7 @@ mod tests {
                 security_definitions: [(
                     "oauth2".to_string(),
                     SecurityScheme {
-                        attype: Some(vec!["ty1".to_string(), "ty2".to_string()]),
+                        attype: Some(vec!["ty1".into(), "ty2".into()]),
                         description: Some("desc".to_string()),
                         descriptions: Some(
                             [
@@ -3771,7 +5818,7 @@ mod tests {
                 security_definitions: [(
                     "mysec".to_string(),
                     SecurityScheme {
-                        attype: Some(vec!["ty1".to_string(), "ty2".to_string()]),
+                        attype: Some(vec!["ty1".into(), "ty2".into()]),
                         description: Some("desc".to_string()),
                         descriptions: Some(
                             [
@@ -3946,7 +5993,9 @@ mod tests {
 
         assert_eq!(
             err,
-            Error::DuplicatedSecurityDefinition("basic".to_string())
+            Error::Security(SecurityError::DuplicatedSecurityDefinition(
+                "basic".to_string()
+            ))
         );
     }
 
@@ -4098,6 +6147,8 @@ mod tests {
                         AdditionalExpectedResponse::default(),
                     ]),
                     other: Nil,
+                    #[cfg(feature = "preserve-unknown")]
+                    unknown: Default::default(),
                 }]),
                 schema_definitions: Some(
                     [
@@ -4183,46 +6234,176 @@ mod tests {
     }
 
     #[test]
-    fn invalid_form_without_op() {
-        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+    fn error_hierarchy() {
+        let error = ThingBuilder::<Nil, _>::new("MyLamp")
             .finish_extend()
-            .form(|form| form.href("href"))
+            .property("level", |b| {
+                b.finish_extend_data_schema()
+                    .object()
+                    .property("min", false, |b| {
+                        b.finish_extend()
+                            .titles(|b| b.add("e1n", "Minimum"))
+                            .integer()
+                    })
+                    .form(|b| b.href("/level"))
+            })
             .build()
             .unwrap_err();
+        assert_eq!(
+            error.path(),
+            Some("/properties/level/properties/min/titles")
+        );
+        assert_eq!(
+            error.without_path(),
+            &Error::Document(DocumentError::InvalidLanguageTag("e1n".to_string()))
+        );
 
-        assert_eq!(err, Error::MissingOpInForm);
-    }
-
-    #[test]
-    fn invalid_form_with_invalid_op() {
-        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+        let error = ThingBuilder::<Nil, _>::new("MyLamp")
             .finish_extend()
-            .form(|form| form.href("href").op(FormOperation::ReadProperty))
+            .property("level", |b| {
+                b.finish_extend_data_schema()
+                    .integer()
+                    .minimum(10)
+                    .maximum(5)
+                    .form(|b| b.href("/level"))
+            })
             .build()
             .unwrap_err();
-
         assert_eq!(
-            err,
-            Error::InvalidOpInForm {
-                context: FormContext::Thing,
-                operation: FormOperation::ReadProperty
-            }
+            error.without_path(),
+            &Error::DataSchema(DataSchemaError::InvalidMinMax)
         );
-    }
 
-    #[test]
-    fn invalid_form_with_missing_security() {
-        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+        let error = ThingBuilder::<Nil, _>::new("MyLamp")
             .finish_extend()
-            .form(|form| {
-                form.href("href")
+            .form(|b| {
+                b.href("/all")
                     .op(FormOperation::ReadAllProperties)
                     .security("basic")
             })
             .build()
             .unwrap_err();
+        assert_eq!(error.path(), None);
+        assert_eq!(error.without_path(), &error);
 
-        assert_eq!(err, Error::UndefinedSecurity("basic".to_string()));
+        assert_eq!(
+            error,
+            Error::Security(SecurityError::UndefinedSecurity("basic".to_string()))
+        );
+
+        assert_eq!(
+            Error::from(TemplateError::UnclosedExpression(0)),
+            Error::Affordance(AffordanceError::InvalidHrefTemplate(
+                TemplateError::UnclosedExpression(0)
+            ))
+        );
+        assert_eq!(
+            Error::from(DocumentError::InvalidUri("a b".to_string())).to_string(),
+            DocumentError::InvalidUri("a b".to_string()).to_string()
+        );
+    }
+
+    #[test]
+    fn invalid_form_without_op() {
+        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .form(|form| form.href("href"))
+            .build()
+            .unwrap_err();
+
+        assert_eq!(err, Error::Affordance(AffordanceError::MissingOpInForm));
+    }
+
+    #[test]
+    fn invalid_form_with_invalid_op() {
+        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .form(|form| form.href("href").op(FormOperation::ReadProperty))
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            Error::Affordance(AffordanceError::InvalidOpInForm {
+                context: FormContext::Thing,
+                operation: FormOperation::ReadProperty
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_form_with_missing_security() {
+        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .form(|form| {
+                form.href("href")
+                    .op(FormOperation::ReadAllProperties)
+                    .security("basic")
+            })
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            Error::Security(SecurityError::UndefinedSecurity("basic".to_string()))
+        );
+    }
+
+    #[test]
+    fn form_accumulators_serialization() {
+        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .security(|b| b.basic())
+            .security(|b| b.digest())
+            .form(|form| {
+                form.href("all")
+                    .op(FormOperation::ReadAllProperties)
+                    .op(FormOperation::WriteAllProperties)
+                    .op(FormOperation::ReadAllProperties)
+                    .security("basic")
+                    .security("digest")
+                    .security("basic")
+                    .scope("read")
+                    .scope("write")
+            })
+            .form(|form| {
+                form.href("multiple")
+                    .op(FormOperation::ReadMultipleProperties)
+                    .security("basic")
+                    .scope("read")
+            })
+            .build()
+            .unwrap();
+
+        // The single-element lists are serialized as arrays too, and they are accepted back as
+        // plain strings.
+        let forms = serde_json::to_value(&thing).unwrap()["forms"].take();
+        assert_eq!(
+            forms,
+            json!([
+                {
+                    "href": "all",
+                    "op": ["readallproperties", "writeallproperties"],
+                    "security": ["basic", "digest"],
+                    "scopes": ["read", "write"],
+                },
+                {
+                    "href": "multiple",
+                    "op": ["readmultipleproperties"],
+                    "security": ["basic"],
+                    "scopes": ["read"],
+                },
+            ]),
+        );
+
+        let form: FormFromOther<Nil> = serde_json::from_value(json!({
+            "href": "multiple",
+            "op": "readmultipleproperties",
+            "security": "basic",
+            "scopes": "read",
+        }))
+        .unwrap();
+        assert_eq!(&form, &thing.forms.unwrap()[1]);
     }
 
     #[test]
@@ -4255,6 +6436,8 @@ mod tests {
                                     titles: None,
                                     description: None,
                                     descriptions: None,
+                                    deprecated: Default::default(),
+                                    deprecation_note: Default::default(),
                                     forms: vec![],
                                     uri_variables: None,
                                     other: Nil,
@@ -4265,6 +6448,8 @@ mod tests {
                                     titles: None,
                                     description: None,
                                     descriptions: None,
+                                    deprecated: Default::default(),
+                                    deprecation_note: Default::default(),
                                     constant: None,
                                     default: None,
                                     unit: None,
@@ -4273,8 +6458,11 @@ mod tests {
                                     read_only: false,
                                     write_only: false,
                                     format: None,
+                                    raw_fields: Default::default(),
                                     subtype: Some(DataSchemaSubtype::Boolean),
                                     other: Nil,
+                                    #[cfg(feature = "preserve-unknown")]
+                                    unknown: Default::default(),
                                 },
                                 observable: Some(true),
                                 other: Nil,
@@ -4289,6 +6477,8 @@ mod tests {
                                     titles: None,
                                     description: None,
                                     descriptions: None,
+                                    deprecated: Default::default(),
+                                    deprecation_note: Default::default(),
                                     forms: vec![],
                                     uri_variables: None,
                                     other: Nil,
@@ -4299,6 +6489,8 @@ mod tests {
                                     titles: None,
                                     description: None,
                                     descriptions: None,
+                                    deprecated: Default::default(),
+                                    deprecation_note: Default::default(),
                                     constant: None,
                                     default: None,
                                     unit: None,
@@ -4307,8 +6499,11 @@ mod tests {
                                     read_only: false,
                                     write_only: false,
                                     format: None,
+                                    raw_fields: Default::default(),
                                     subtype: Some(DataSchemaSubtype::Null),
                                     other: Nil,
+                                    #[cfg(feature = "preserve-unknown")]
+                                    unknown: Default::default(),
                                 },
                                 observable: None,
                                 other: Nil,
@@ -4352,6 +6547,8 @@ mod tests {
                                     titles: None,
                                     description: None,
                                     descriptions: None,
+                                    deprecated: Default::default(),
+                                    deprecation_note: Default::default(),
                                     forms: vec![],
                                     uri_variables: None,
                                     other: Nil,
@@ -4362,6 +6559,8 @@ mod tests {
                                 idempotent: false,
                                 synchronous: None,
                                 other: Nil,
+                                #[cfg(feature = "preserve-unknown")]
+                                unknown: Default::default(),
                             }
                         ),
                         (
@@ -4373,6 +6572,8 @@ mod tests {
                                     titles: None,
                                     description: None,
                                     descriptions: None,
+                                    deprecated: Default::default(),
+                                    deprecation_note: Default::default(),
                                     forms: vec![],
                                     uri_variables: None,
                                     other: Nil,
@@ -4383,6 +6584,8 @@ mod tests {
                                     titles: None,
                                     description: None,
                                     descriptions: None,
+                                    deprecated: Default::default(),
+                                    deprecation_note: Default::default(),
                                     constant: None,
                                     default: None,
                                     unit: None,
@@ -4391,14 +6594,19 @@ mod tests {
                                     read_only: false,
                                     write_only: false,
                                     format: None,
+                                    raw_fields: Default::default(),
                                     subtype: Some(DataSchemaSubtype::Null),
                                     other: Nil,
+                                    #[cfg(feature = "preserve-unknown")]
+                                    unknown: Default::default(),
                                 }),
                                 output: None,
                                 safe: false,
                                 idempotent: true,
                                 synchronous: None,
                                 other: Nil,
+                                #[cfg(feature = "preserve-unknown")]
+                                unknown: Default::default(),
                             }
                         ),
                     ]
@@ -4437,6 +6645,8 @@ mod tests {
                                     titles: None,
                                     description: None,
                                     descriptions: None,
+                                    deprecated: Default::default(),
+                                    deprecation_note: Default::default(),
                                     forms: vec![],
                                     uri_variables: None,
                                     other: Nil,
@@ -4446,6 +6656,8 @@ mod tests {
                                 cancellation: None,
                                 data_response: None,
                                 other: Nil,
+                                #[cfg(feature = "preserve-unknown")]
+                                unknown: Default::default(),
                             }
                         ),
                         (
@@ -4457,6 +6669,8 @@ mod tests {
                                     titles: None,
                                     description: None,
                                     descriptions: None,
+                                    deprecated: Default::default(),
+                                    deprecation_note: Default::default(),
                                     forms: vec![],
                                     uri_variables: None,
                                     other: Nil,
@@ -4469,6 +6683,8 @@ mod tests {
                                     titles: None,
                                     description: None,
                                     descriptions: None,
+                                    deprecated: Default::default(),
+                                    deprecation_note: Default::default(),
                                     constant: None,
                                     default: None,
                                     unit: None,
@@ -4477,11 +6693,16 @@ mod tests {
                                     read_only: false,
                                     write_only: false,
                                     format: None,
+                                    raw_fields: Default::default(),
                                     subtype: Some(DataSchemaSubtype::Null),
                                     other: Nil,
+                                    #[cfg(feature = "preserve-unknown")]
+                                    unknown: Default::default(),
                                 }),
                                 data_response: None,
                                 other: Nil,
+                                #[cfg(feature = "preserve-unknown")]
+                                unknown: Default::default(),
                             }
                         ),
                     ]
@@ -4517,7 +6738,10 @@ mod tests {
                         PropertyAffordance {
                             interaction: InteractionAffordance {
                                 forms: vec![Form {
-                                    op: DefaultedFormOperations::Default,
+                                    op: DefaultedFormOperations::Custom(vec![
+                                        FormOperation::ReadProperty,
+                                        FormOperation::WriteProperty,
+                                    ]),
                                     href: "href".to_owned(),
                                     security: Some(vec!["basic".to_owned()]),
                                     ..Default::default()
@@ -4566,7 +6790,10 @@ mod tests {
             .build()
             .unwrap_err();
 
-        assert_eq!(error, Error::UndefinedSecurity("oauth2".to_owned()));
+        assert_eq!(
+            error,
+            Error::Security(SecurityError::UndefinedSecurity("oauth2".to_owned()))
+        );
     }
 
     #[test]
@@ -4643,6 +6870,162 @@ mod tests {
                 ..Default::default()
             }
         );
+
+        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .schema_definition("schema", |b| {
+                b.finish_extend().number().minimum(5.).maximum(1.)
+            })
+            .build()
+            .unwrap_err();
+        assert_eq!(err, Error::DataSchema(DataSchemaError::InvalidMinMax));
+    }
+
+    #[test]
+    fn schema_definition_from_data_schema() {
+        let status: DataSchemaFromOther<Nil> = ThingBuilder::<Nil, _>::new("Library")
+            .finish_extend()
+            .schema_definition("status", |b| {
+                b.finish_extend()
+                    .object()
+                    .property("on", true, |b| b.finish_extend().bool())
+                    .property("level", false, |b| {
+                        b.finish_extend().integer().minimum(0).maximum(100)
+                    })
+                    .title("Status")
+                    .titles(|b| b.add("it", "Stato"))
+            })
+            .build()
+            .unwrap()
+            .schema_definitions
+            .unwrap()
+            .remove("status")
+            .unwrap();
+
+        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .schema_definition_from("status", status.clone())
+            .property("status", |b| {
+                b.finish_extend_data_schema()
+                    .object()
+                    .form(|b| b.href("/status"))
+            })
+            .build()
+            .unwrap();
+        assert_eq!(thing.schema_definitions.unwrap()["status"], status);
+
+        let invalid: DataSchemaFromOther<Nil> = DataSchema {
+            constant: Some(json!(1)),
+            enumeration: Some(vec![json!(1), json!(2)]),
+            ..Default::default()
+        };
+        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .schema_definition_from("invalid", invalid)
+            .build()
+            .unwrap_err();
+        assert_eq!(err, Error::DataSchema(DataSchemaError::ConstAndEnum));
+    }
+
+    #[test]
+    fn enum_mixed_types_warning() {
+        let (thing, warnings) = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .property("mode", |b| {
+                b.finish_extend_data_schema()
+                    .object()
+                    .property("value", true, |b| {
+                        b.finish_extend()
+                            .enumeration("on")
+                            .enumeration(1)
+                            .enumeration(true)
+                    })
+                    .property("level", false, |b| {
+                        b.finish_extend().enumeration(1).enumeration(2.5)
+                    })
+                    .form(|b| b.href("/mode"))
+            })
+            .build_with_report()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&thing).unwrap()["properties"]["mode"]["properties"]["value"]
+                ["enum"],
+            json!(["on", 1, true]),
+        );
+        assert_eq!(
+            warnings,
+            [LintWarning::MixedEnumTypes(
+                "properties.mode.properties.value".to_string()
+            )],
+        );
+    }
+
+    #[test]
+    fn enum_type_mismatch() {
+        let schema = |subtype: DataSchemaSubtype<Nil, Nil, Nil>, enumeration: Vec<Value>| {
+            DataSchemaFromOther::<Nil> {
+                subtype: Some(DataSchemaSubtype::Object(ObjectSchema {
+                    properties: Some(
+                        [(
+                            "value".to_string(),
+                            DataSchema {
+                                enumeration: Some(enumeration),
+                                subtype: Some(subtype),
+                                ..Default::default()
+                            },
+                        )]
+                        .into_iter()
+                        .collect(),
+                    ),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }
+        };
+        let build = |data_schema| {
+            ThingBuilder::<Nil, _>::new("MyLampThing")
+                .finish_extend()
+                .schema_definition_from("status", data_schema)
+                .build()
+        };
+
+        let err = build(schema(
+            DataSchemaSubtype::String(Default::default()),
+            vec![json!(1), json!(2)],
+        ))
+        .unwrap_err();
+        assert_eq!(
+            err,
+            Error::DataSchema(DataSchemaError::EnumTypeMismatch {
+                value: "1".to_string(),
+                path: "/properties/value/enum/0".to_string(),
+            })
+        );
+
+        let err = build(schema(
+            DataSchemaSubtype::Integer(Default::default()),
+            vec![json!(1), json!(2.5)],
+        ))
+        .unwrap_err();
+        assert_eq!(
+            err,
+            Error::DataSchema(DataSchemaError::EnumTypeMismatch {
+                value: "2.5".to_string(),
+                path: "/properties/value/enum/1".to_string(),
+            })
+        );
+
+        assert!(build(schema(
+            DataSchemaSubtype::Integer(Default::default()),
+            vec![json!(1), json!(2.0)],
+        ))
+        .is_ok());
+        assert!(build(schema(
+            DataSchemaSubtype::String(Default::default()),
+            vec![json!("on"), json!("off")],
+        ))
+        .is_ok());
     }
 
     #[test]
@@ -4723,6 +7106,8 @@ mod tests {
                     scopes: Default::default(),
                     response: Default::default(),
                     additional_responses: Default::default(),
+                    #[cfg(feature = "preserve-unknown")]
+                    unknown: Default::default(),
                 }]),
                 ..Default::default()
             }
@@ -4801,7 +7186,7 @@ mod tests {
                 .ext_with(|| ExpectedResponseExtB { d: B(2) })
             });
 
-        let form: Form<Cons<ThingB, Cons<ThingA, Nil>>> = builder.into();
+        let form: FormFromOther<Cons<ThingB, Cons<ThingA, Nil>>> = builder.into();
         assert_eq!(
             form,
             Form {
@@ -4824,6 +7209,8 @@ mod tests {
                 security: Default::default(),
                 scopes: Default::default(),
                 additional_responses: Default::default(),
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             },
         );
     }
@@ -5101,6 +7488,8 @@ mod tests {
                             titles: Default::default(),
                             description: Default::default(),
                             descriptions: Default::default(),
+                            deprecated: Default::default(),
+                            deprecation_note: Default::default(),
                             constant: Default::default(),
                             default: Default::default(),
                             unit: Default::default(),
@@ -5109,6 +7498,9 @@ mod tests {
                             read_only: Default::default(),
                             write_only: Default::default(),
                             format: Default::default(),
+                            raw_fields: Default::default(),
+                            #[cfg(feature = "preserve-unknown")]
+                            unknown: Default::default(),
                         }
                     )]
                     .into_iter()
@@ -5127,6 +7519,8 @@ mod tests {
                                 titles: Default::default(),
                                 description: Default::default(),
                                 descriptions: Default::default(),
+                                deprecated: Default::default(),
+                                deprecation_note: Default::default(),
                                 forms: vec![Form {
                                     href: "href1".to_string(),
                                     response: Some(ExpectedResponse {
@@ -5141,12 +7535,17 @@ mod tests {
                                         schema: Some("schema".to_string()),
                                     }]),
                                     other: Nil::cons(()).cons(FormExtB { m: 19 }).cons(()),
-                                    op: Default::default(),
+                                    op: DefaultedFormOperations::Custom(vec![
+                                        FormOperation::ReadProperty,
+                                        FormOperation::WriteProperty,
+                                    ]),
                                     content_type: Default::default(),
                                     content_coding: Default::default(),
                                     subprotocol: Default::default(),
                                     security: Default::default(),
                                     scopes: Default::default(),
+                                    #[cfg(feature = "preserve-unknown")]
+                                    unknown: Default::default(),
                                 }],
                                 uri_variables: Default::default(),
                             },
@@ -5166,6 +7565,8 @@ mod tests {
                                 titles: Default::default(),
                                 description: Default::default(),
                                 descriptions: Default::default(),
+                                deprecated: Default::default(),
+                                deprecation_note: Default::default(),
                                 constant: Default::default(),
                                 default: Default::default(),
                                 unit: Default::default(),
@@ -5174,6 +7575,9 @@ mod tests {
                                 read_only: Default::default(),
                                 write_only: Default::default(),
                                 format: Default::default(),
+                                raw_fields: Default::default(),
+                                #[cfg(feature = "preserve-unknown")]
+                                unknown: Default::default(),
                             },
                             other: Nil::cons(())
                                 .cons(PropertyAffordanceExtB { k: 14. })
@@ -5189,6 +7593,8 @@ mod tests {
                         "action".to_string(),
                         ActionAffordance {
                             interaction: InteractionAffordance {
+                                deprecated: Default::default(),
+                                deprecation_note: Default::default(),
                                 title: Some("action".to_string()),
                                 uri_variables: Some(
                                     [(
@@ -5205,6 +7611,8 @@ mod tests {
                                             titles: Default::default(),
                                             description: Default::default(),
                                             descriptions: Default::default(),
+                                            deprecated: Default::default(),
+                                            deprecation_note: Default::default(),
                                             constant: Default::default(),
                                             default: Default::default(),
                                             unit: Default::default(),
@@ -5213,6 +7621,9 @@ mod tests {
                                             read_only: Default::default(),
                                             write_only: Default::default(),
                                             format: Default::default(),
+                                            raw_fields: Default::default(),
+                                            #[cfg(feature = "preserve-unknown")]
+                                            unknown: Default::default(),
                                         }
                                     )]
                                     .into_iter()
@@ -5241,6 +7652,8 @@ mod tests {
                                 titles: Default::default(),
                                 description: Default::default(),
                                 descriptions: Default::default(),
+                                deprecated: Default::default(),
+                                deprecation_note: Default::default(),
                                 constant: Default::default(),
                                 default: Default::default(),
                                 unit: Default::default(),
@@ -5249,6 +7662,9 @@ mod tests {
                                 read_only: Default::default(),
                                 write_only: Default::default(),
                                 format: Default::default(),
+                                raw_fields: Default::default(),
+                                #[cfg(feature = "preserve-unknown")]
+                                unknown: Default::default(),
                             }),
                             other: Nil::cons(ActionAffordanceExtA { e: 20 })
                                 .cons(())
@@ -5257,6 +7673,8 @@ mod tests {
                             safe: Default::default(),
                             idempotent: Default::default(),
                             synchronous: Default::default(),
+                            #[cfg(feature = "preserve-unknown")]
+                            unknown: Default::default(),
                         }
                     )]
                     .into_iter()
@@ -5275,6 +7693,8 @@ mod tests {
                                 titles: Default::default(),
                                 description: Default::default(),
                                 descriptions: Default::default(),
+                                deprecated: Default::default(),
+                                deprecation_note: Default::default(),
                                 forms: Default::default(),
                                 uri_variables: Default::default(),
                             },
@@ -5288,6 +7708,8 @@ mod tests {
                                 titles: Default::default(),
                                 description: Default::default(),
                                 descriptions: Default::default(),
+                                deprecated: Default::default(),
+                                deprecation_note: Default::default(),
                                 constant: Default::default(),
                                 default: Default::default(),
                                 unit: Default::default(),
@@ -5296,6 +7718,9 @@ mod tests {
                                 read_only: Default::default(),
                                 write_only: Default::default(),
                                 format: Default::default(),
+                                raw_fields: Default::default(),
+                                #[cfg(feature = "preserve-unknown")]
+                                unknown: Default::default(),
                             }),
                             other: Nil::cons(EventAffordanceExtA { f: 29 })
                                 .cons(EventAffordanceExtB { l: 30 })
@@ -5303,6 +7728,8 @@ mod tests {
                             subscription: Default::default(),
                             data_response: Default::default(),
                             cancellation: Default::default(),
+                            #[cfg(feature = "preserve-unknown")]
+                            unknown: Default::default(),
                         }
                     )]
                     .into_iter()
@@ -5324,6 +7751,8 @@ mod tests {
                     security: Default::default(),
                     scopes: Default::default(),
                     additional_responses: Default::default(),
+                    #[cfg(feature = "preserve-unknown")]
+                    unknown: Default::default(),
                 }]),
                 schema_definitions: Some(
                     [(
@@ -5338,6 +7767,8 @@ mod tests {
                             titles: Default::default(),
                             description: Default::default(),
                             descriptions: Default::default(),
+                            deprecated: Default::default(),
+                            deprecation_note: Default::default(),
                             constant: Default::default(),
                             default: Default::default(),
                             unit: Default::default(),
@@ -5346,6 +7777,9 @@ mod tests {
                             read_only: Default::default(),
                             write_only: Default::default(),
                             format: Default::default(),
+                            raw_fields: Default::default(),
+                            #[cfg(feature = "preserve-unknown")]
+                            unknown: Default::default(),
                         }
                     )]
                     .into_iter()
@@ -5358,11 +7792,14 @@ mod tests {
                 created: Default::default(),
                 modified: Default::default(),
                 support: Default::default(),
+                default_content_type: Default::default(),
                 base: Default::default(),
                 links: Default::default(),
                 security: Default::default(),
                 security_definitions: Default::default(),
                 profile: Default::default(),
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             },
         );
     }
@@ -5423,7 +7860,9 @@ mod tests {
 
         assert_eq!(
             error,
-            Error::MissingSchemaDefinition("invalid_schema".to_string())
+            Error::DataSchema(DataSchemaError::MissingSchemaDefinition(
+                "invalid_schema".to_string()
+            ))
         );
     }
 
@@ -5435,7 +7874,10 @@ mod tests {
             .build()
             .unwrap_err();
 
-        assert_eq!(error, Error::InvalidUriVariables);
+        assert_eq!(
+            error,
+            Error::Affordance(AffordanceError::InvalidUriVariables)
+        );
 
         let error = ThingBuilder::<Nil, _>::new("MyLampThing")
             .finish_extend()
@@ -5443,7 +7885,10 @@ mod tests {
             .build()
             .unwrap_err();
 
-        assert_eq!(error, Error::InvalidUriVariables);
+        assert_eq!(
+            error,
+            Error::Affordance(AffordanceError::InvalidUriVariables)
+        );
     }
 
     #[test]
@@ -5456,7 +7901,10 @@ mod tests {
             .build()
             .unwrap_err();
 
-        assert_eq!(error, Error::InvalidUriVariables);
+        assert_eq!(
+            error,
+            Error::Affordance(AffordanceError::InvalidUriVariables)
+        );
 
         let error = ThingBuilder::<Nil, _>::new("MyLampThing")
             .finish_extend()
@@ -5468,477 +7916,1549 @@ mod tests {
             .build()
             .unwrap_err();
 
-        assert_eq!(error, Error::InvalidUriVariables);
+        assert_eq!(
+            error,
+            Error::Affordance(AffordanceError::InvalidUriVariables)
+        );
     }
 
     #[test]
-    fn combo_security_scheme_with_all_of() {
-        let builder = SecuritySchemeBuilder {
-            attype: Default::default(),
-            description: Default::default(),
-            descriptions: Default::default(),
-            proxy: Default::default(),
-            name: Default::default(),
-            subtype: (),
-            required: Default::default(),
-        }
-        .combo()
-        .attype("attype")
-        .all_of(["schema1", "schema2"])
-        .extend(["schema3", "schema4"])
-        .push("schema1")
-        .description("description");
+    fn undeclared_href_template_variables() {
+        let error = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .form(|form| {
+                form.href("href/{foo}{?bar}")
+                    .op(FormOperation::ReadAllProperties)
+            })
+            .uri_variable("foo", |v| v.finish_extend().integer())
+            .build()
+            .unwrap_err();
 
-        assert_eq!(builder.attype, Some(vec!["attype".to_string()]));
-        assert_eq!(builder.description, Some("description".to_string()));
         assert_eq!(
-            builder.subtype,
-            (
-                AllOfComboSecuritySchemeTag,
-                ["schema1", "schema2", "schema3", "schema4", "schema1"]
-                    .map(String::from)
-                    .into(),
-            ),
+            error,
+            Error::Affordance(AffordanceError::UndeclaredUriVariable("bar".to_string()))
         );
 
-        let subtype = builder.subtype.build();
+        let error = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .action("action", |b| b.form(|form| form.href("action/{id}")))
+            .build()
+            .unwrap_err();
+
         assert_eq!(
-            subtype,
-            SecuritySchemeSubtype::Known(KnownSecuritySchemeSubtype::Combo(
-                ComboSecurityScheme::AllOf(
-                    ["schema1", "schema2", "schema3", "schema4", "schema1"]
-                        .map(String::from)
-                        .into()
-                )
+            error,
+            Error::Affordance(AffordanceError::UndeclaredUriVariable("id".to_string()))
+        );
+
+        let error = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .property("property", |b| {
+                b.finish_extend_data_schema()
+                    .form(|form| form.href("property/{id"))
+                    .string()
+            })
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            Error::Affordance(AffordanceError::InvalidHrefTemplate(
+                TemplateError::UnclosedExpression(9)
             ))
         );
     }
 
     #[test]
-    fn combo_security_scheme_with_one_of() {
-        let builder = SecuritySchemeBuilder {
-            attype: Default::default(),
-            description: Default::default(),
-            descriptions: Default::default(),
-            proxy: Default::default(),
-            name: Default::default(),
-            subtype: (),
-            required: Default::default(),
-        }
-        .combo()
-        .attype("attype")
-        .one_of(["schema1", "schema2"])
-        .extend(["schema3", "schema4"])
-        .push("schema1")
-        .description("description");
+    fn declared_href_template_variables() {
+        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .uri_variable("page", |v| v.finish_extend().integer())
+            .action("action", |b| {
+                b.uri_variable("id", |v| v.finish_extend().string())
+                    .form(|form| form.href("action/{id}{?page}"))
+            })
+            .build()
+            .unwrap();
 
-        assert_eq!(builder.attype, Some(vec!["attype".to_string()]));
-        assert_eq!(builder.description, Some("description".to_string()));
+        assert!(thing.unused_uri_variables().is_empty());
+
+        // The operations on multiple properties imply a variable for each property.
+        let builder = |op| {
+            ThingBuilder::<Nil, _>::new("MyLampThing")
+                .finish_extend()
+                .property("on", |b| {
+                    b.finish_extend_data_schema()
+                        .form(|form| form.href("properties/on"))
+                        .bool()
+                })
+                .form(move |form| form.href("properties{?on}").op(op))
+                .build()
+        };
+        assert!(builder(FormOperation::WriteMultipleProperties).is_ok());
+        assert!(builder(FormOperation::ReadAllProperties).is_ok());
         assert_eq!(
-            builder.subtype,
-            (
-                OneOfComboSecuritySchemeTag,
-                ["schema1", "schema2", "schema3", "schema4", "schema1"]
-                    .map(String::from)
-                    .into(),
-            ),
+            builder(FormOperation::QueryAllActions).unwrap_err(),
+            Error::Affordance(AffordanceError::UndeclaredUriVariable("on".to_string()))
         );
+    }
+
+    #[test]
+    fn content_encoding_strict_and_lenient() {
+        let builder = |encoding: &str| {
+            let encoding = encoding.to_string();
+            ThingBuilder::<Nil, _>::new("MyLampThing")
+                .finish_extend()
+                .action("upload", |b| {
+                    b.input(|b| {
+                        b.finish_extend().object().property("image", true, |b| {
+                            b.finish_extend()
+                                .string()
+                                .content_encoding(encoding)
+                                .content_media_type("image/png")
+                        })
+                    })
+                    .form(|b| b.href("/upload"))
+                })
+        };
+
+        for encoding in [
+            "7bit",
+            "8bit",
+            "binary",
+            "quoted-printable",
+            "base16",
+            "base32",
+            "base64",
+        ] {
+            assert!(builder(encoding).build().is_ok());
+            assert!(builder(encoding).strict_content_encoding().build().is_ok());
+        }
 
-        let subtype = builder.subtype.build();
         assert_eq!(
-            subtype,
-            SecuritySchemeSubtype::Known(KnownSecuritySchemeSubtype::Combo(
-                ComboSecurityScheme::OneOf(
-                    ["schema1", "schema2", "schema3", "schema4", "schema1"]
-                        .map(String::from)
-                        .into()
-                )
-            ))
+            builder("x-custom")
+                .strict_content_encoding()
+                .build()
+                .unwrap_err(),
+            Error::DataSchema(DataSchemaError::UnknownContentEncoding(
+                "x-custom".to_string()
+            )),
+        );
+        let thing = builder("x-custom").build().unwrap();
+        let Some(DataSchemaSubtype::Object(input)) = &thing.actions.as_ref().unwrap()["upload"]
+            .input
+            .as_ref()
+            .unwrap()
+            .subtype
+        else {
+            unreachable!()
+        };
+        let Some(DataSchemaSubtype::String(image)) =
+            &input.properties.as_ref().unwrap()["image"].subtype
+        else {
+            unreachable!()
+        };
+        assert_eq!(
+            image.encoding_known(),
+            Some(ContentEncoding::Other("x-custom".to_string())),
         );
     }
 
     #[test]
-    fn valid_combo_security_scheme() {
-        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
-            .security(|b| b.basic())
-            .security(|b| b.combo().one_of(["basic", "nosec"]))
-            .security(|b| b.no_sec())
-            .build()
+    fn strict_content_encoding_flags_gzip() {
+        let builder = |encoding: &str| {
+            let encoding = encoding.to_string();
+            ThingBuilder::<Nil, _>::new("MyLampThing")
+                .finish_extend()
+                .action("upload", |b| {
+                    b.input(|b| {
+                        b.finish_extend()
+                            .string()
+                            .content_encoding(encoding)
+                            .content_media_type("application/octet-stream")
+                    })
+                    .form(|b| b.href("/upload"))
+                })
+        };
+
+        assert!(builder("base64").build().is_ok());
+        assert!(builder("base64").strict_content_encoding().build().is_ok());
+        assert!(builder("gzip").build().is_ok());
+        assert_eq!(
+            builder("gzip")
+                .strict_content_encoding()
+                .build()
+                .unwrap_err(),
+            Error::DataSchema(DataSchemaError::UnknownContentEncoding("gzip".to_string())),
+        );
+    }
+
+    #[test]
+    fn form_ops_deduplicated() {
+        let (thing, warnings) = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .form(|form| {
+                form.href("/properties")
+                    .op(FormOperation::ReadAllProperties)
+                    .ops([
+                        FormOperation::WriteAllProperties,
+                        FormOperation::ReadAllProperties,
+                    ])
+                    .op(FormOperation::WriteAllProperties)
+            })
+            .property("level", |b| {
+                b.finish_extend_data_schema()
+                    .form(|form| {
+                        form.href("/level")
+                            .ops([FormOperation::ReadProperty, FormOperation::WriteProperty])
+                            .op(FormOperation::ReadProperty)
+                    })
+                    .integer()
+                    .write_only()
+            })
+            .build_with_report()
             .unwrap();
 
+        let value = serde_json::to_value(&thing).unwrap();
         assert_eq!(
-            thing,
-            Thing {
-                context: TD_CONTEXT_11.into(),
-                title: "MyLampThing".to_string(),
-                security_definitions: [
-                    (
-                        "basic".to_string(),
-                        SecurityScheme {
-                            subtype: SecuritySchemeSubtype::Known(
-                                KnownSecuritySchemeSubtype::Basic(Default::default())
-                            ),
-                            ..Default::default()
-                        }
-                    ),
-                    (
-                        "combo".to_string(),
-                        SecurityScheme {
-                            subtype: SecuritySchemeSubtype::Known(
-                                KnownSecuritySchemeSubtype::Combo(ComboSecurityScheme::OneOf(
-                                    vec!["basic".to_string(), "nosec".to_string()]
-                                ))
-                            ),
-                            ..Default::default()
-                        }
-                    ),
-                    (
-                        "nosec".to_string(),
-                        SecurityScheme {
-                            subtype: SecuritySchemeSubtype::Known(
-                                KnownSecuritySchemeSubtype::NoSec
-                            ),
-                            ..Default::default()
-                        }
-                    ),
-                ]
-                .into_iter()
-                .collect(),
-                ..Default::default()
-            },
+            value["forms"][0]["op"],
+            json!(["readallproperties", "writeallproperties"]),
+        );
+        assert_eq!(
+            value["properties"]["level"]["forms"][0]["op"],
+            json!(["readproperty", "writeproperty"]),
+        );
+        assert_eq!(
+            warnings,
+            [LintWarning::ContradictoryFormOperation(
+                "properties.level.forms.0.op.0".to_string()
+            )],
         );
     }
 
     #[test]
-    fn missing_combo_security_scheme() {
-        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
-            .security(|b| b.combo().one_of(["basic", "nosec"]))
-            .security(|b| b.no_sec())
+    fn dry_run_json() {
+        let builder = ThingBuilder::<Nil, _>::new("MyLamp")
+            .finish_extend()
+            .security(|b| b.basic())
+            .property("on", |b| {
+                b.finish_extend_data_schema()
+                    .bool()
+                    .form(|form| form.href("/on"))
+            });
+
+        let preview = builder.dry_run_json().unwrap();
+        assert_eq!(preview["title"], "MyLamp");
+        assert_eq!(preview["properties"]["on"]["type"], "boolean");
+        assert!(preview["actions"].is_null());
+
+        let builder = builder.action("toggle", |b| b.form(|form| form.href("/toggle")));
+        let preview = builder.dry_run_json().unwrap();
+        assert_eq!(preview["actions"]["toggle"]["forms"][0]["href"], "/toggle");
+        assert_eq!(preview, builder.dry_run_json().unwrap());
+
+        let builder = builder.action("toggle", |b| b.form(|form| form.href("/toggle")));
+        assert_eq!(
+            builder.dry_run_json().unwrap_err(),
+            [Error::Affordance(AffordanceError::DuplicatedAffordance {
+                ty: AffordanceType::Action,
+                name: "toggle".to_string(),
+            })],
+        );
+
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn strict_forms_formless_affordance() {
+        let builder = || {
+            ThingBuilder::<Nil, _>::new("MyLampThing")
+                .finish_extend()
+                .form(|form| {
+                    form.href("/properties")
+                        .op(FormOperation::ReadAllProperties)
+                })
+                .property("on", |b| b.finish_extend_data_schema().bool())
+                .action("fade", |b| b.form(|form| form.href("/fade")))
+        };
+
+        assert_eq!(
+            builder().strict_forms().build().unwrap_err(),
+            Error::Affordance(AffordanceError::AffordanceWithoutForm("on".to_string())),
+        );
+        assert!(builder().build().is_ok());
+
+        let error = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .strict_forms()
+            .finish_extend()
+            .event("overheating", |b| b)
             .build()
             .unwrap_err();
-
-        assert_eq!(err, Error::MissingSchemaDefinition("basic".to_string()));
+        assert_eq!(
+            error,
+            Error::Affordance(AffordanceError::AffordanceWithoutForm(
+                "overheating".to_string()
+            ))
+        );
     }
 
     #[test]
-    fn checked_op_in_form() {
+    fn strict_forms_observable_property() {
+        let error = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .strict_forms()
+            .finish_extend()
+            .property("on", |b| {
+                b.finish_extend_data_schema()
+                    .observable(true)
+                    .form(|form| form.href("/on").op(FormOperation::ReadProperty))
+                    .bool()
+            })
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            Error::Affordance(AffordanceError::ObservableWithoutObserveForm(
+                "on".to_string()
+            ))
+        );
+
         let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .strict_forms()
             .finish_extend()
-            .form(|b| {
-                b.op(FormOperation::ReadAllProperties)
-                    .op(FormOperation::WriteAllProperties)
-                    .op(FormOperation::ReadMultipleProperties)
-                    .op(FormOperation::WriteMultipleProperties)
-                    .op(FormOperation::ObserveAllProperties)
-                    .op(FormOperation::UnobserveAllProperties)
-                    .op(FormOperation::SubscribeAllEvents)
-                    .op(FormOperation::UnsubscribeAllEvents)
-                    .op(FormOperation::QueryAllActions)
-                    .href("href")
+            .property("on", |b| {
+                b.finish_extend_data_schema()
+                    .observable(true)
+                    .form(|form| form.href("/on").op(FormOperation::ReadProperty))
+                    .form(|form| form.href("/on/events").op(FormOperation::ObserveProperty))
+                    .bool()
             })
-            .property("property", |b| {
-                b.finish_extend_data_schema().null().form(|b| {
-                    b.op(FormOperation::ReadProperty)
-                        .op(FormOperation::WriteProperty)
-                        .op(FormOperation::ObserveProperty)
-                        .op(FormOperation::UnobserveProperty)
-                        .href("href")
-                })
+            .build();
+
+        assert!(thing.is_ok());
+
+        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .property("on", |b| {
+                b.finish_extend_data_schema()
+                    .observable(true)
+                    .form(|form| form.href("/on"))
+                    .bool()
             })
-            .action("action", |b| {
-                b.form(|b| {
-                    b.op(FormOperation::InvokeAction)
-                        .op(FormOperation::QueryAction)
-                        .op(FormOperation::CancelAction)
-                        .href("href")
+            .build();
+
+        assert!(thing.is_ok());
+    }
+
+    #[test]
+    fn shadowed_uri_variable() {
+        let builder = || {
+            ThingBuilder::<Nil, _>::new("MyLampThing")
+                .finish_extend()
+                .uri_variable("id", |b| b.finish_extend().integer())
+                .property("level", |b| {
+                    b.finish_extend_data_schema()
+                        .uri_variable("id", |b| b.finish_extend().string())
+                        .form(|form| form.href("/lamps/{id}/level"))
+                        .integer()
                 })
-            })
-            .event("event", |b| {
-                b.form(|b| {
-                    b.op(FormOperation::SubscribeEvent)
-                        .op(FormOperation::UnsubscribeEvent)
-                        .href("href")
+                .action("fade", |b| {
+                    b.uri_variable("duration", |b| b.finish_extend().integer())
+                        .form(|form| form.href("/lamps/{id}/fade{?duration}"))
                 })
-            })
+        };
+
+        let (_, warnings) = builder().build_with_report().unwrap();
+        assert_eq!(
+            warnings,
+            [LintWarning::ShadowedUriVariable(
+                "properties.level.uriVariables.id".to_string()
+            )],
+        );
+
+        assert_eq!(
+            builder().strict_forms().build().unwrap_err(),
+            Error::Affordance(AffordanceError::ShadowedUriVariable {
+                affordance: "level".to_string(),
+                variable: "id".to_string(),
+            }),
+        );
+    }
+
+    #[test]
+    fn property_from_partial_data_schema() {
+        let data_schema: PartialDataSchema<Nil, Nil, Nil> = PartialDataSchemaBuilder::default()
+            .integer()
+            .minimum(0)
+            .maximum(10)
+            .into();
+
+        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .property_from(
+                "level",
+                data_schema,
+                HumanReadableInfo::default().title("Level"),
+            )
             .build()
             .unwrap();
 
         assert_eq!(
-            thing,
-            Thing {
-                context: TD_CONTEXT_11.into(),
-                title: "MyLampThing".to_string(),
-                forms: Some(vec![Form {
-                    op: DefaultedFormOperations::Custom(vec![
-                        FormOperation::ReadAllProperties,
-                        FormOperation::WriteAllProperties,
-                        FormOperation::ReadMultipleProperties,
-                        FormOperation::WriteMultipleProperties,
-                        FormOperation::ObserveAllProperties,
-                        FormOperation::UnobserveAllProperties,
-                        FormOperation::SubscribeAllEvents,
-                        FormOperation::UnsubscribeAllEvents,
-                        FormOperation::QueryAllActions
-                    ]),
-                    href: "href".to_string(),
+            thing.properties.unwrap()["level"],
+            PropertyAffordance {
+                interaction: InteractionAffordance {
+                    title: Some("Level".to_string()),
                     ..Default::default()
-                }]),
-                properties: Some(
-                    [(
-                        "property".to_string(),
-                        PropertyAffordance {
-                            interaction: InteractionAffordance {
-                                forms: vec![Form {
-                                    op: DefaultedFormOperations::Custom(vec![
-                                        FormOperation::ReadProperty,
-                                        FormOperation::WriteProperty,
-                                        FormOperation::ObserveProperty,
-                                        FormOperation::UnobserveProperty,
-                                    ]),
-                                    href: "href".to_string(),
-                                    ..Default::default()
-                                }],
-                                ..Default::default()
-                            },
-                            data_schema: DataSchema {
-                                subtype: Some(DataSchemaSubtype::Null),
-                                ..Default::default()
-                            },
+                },
+                data_schema: DataSchema {
+                    title: Some("Level".to_string()),
+                    subtype: Some(DataSchemaSubtype::Integer(IntegerSchema {
+                        minimum: Some(Minimum::Inclusive(0)),
+                        maximum: Some(Maximum::Inclusive(10)),
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+
+        let data_schema: PartialDataSchema<Nil, Nil, Nil> = PartialDataSchemaBuilder::default()
+            .integer()
+            .minimum(10)
+            .maximum(0)
+            .into();
+
+        let error = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .property_from("level", data_schema, Default::default())
+            .build()
+            .unwrap_err();
+
+        assert_eq!(error, Error::DataSchema(DataSchemaError::InvalidMinMax));
+    }
+
+    #[test]
+    fn empty_names() {
+        type Case = (fn() -> Result<Thing, Error>, NameKind);
+
+        let cases: [Case; 9] = [
+            (
+                || {
+                    ThingBuilder::<Nil, _>::new("MyLampThing")
+                        .finish_extend()
+                        .property(" ", |b| b.finish_extend_data_schema().null())
+                        .build()
+                },
+                NameKind::Property,
+            ),
+            (
+                || {
+                    ThingBuilder::<Nil, _>::new("MyLampThing")
+                        .finish_extend()
+                        .action("", |b| b)
+                        .build()
+                },
+                NameKind::Action,
+            ),
+            (
+                || {
+                    ThingBuilder::<Nil, _>::new("MyLampThing")
+                        .finish_extend()
+                        .event("\t", |b| b)
+                        .build()
+                },
+                NameKind::Event,
+            ),
+            (
+                || {
+                    ThingBuilder::<Nil, _>::new("MyLampThing")
+                        .finish_extend()
+                        .property("on", |b| {
+                            b.finish_extend_data_schema()
+                                .object()
+                                .property("", false, |b| b.finish_extend().bool())
+                        })
+                        .build()
+                },
+                NameKind::ObjectProperty,
+            ),
+            (
+                || {
+                    ThingBuilder::<Nil, _>::new("MyLampThing")
+                        .finish_extend()
+                        .uri_variable(" ", |b| b.finish_extend().integer())
+                        .build()
+                },
+                NameKind::UriVariable,
+            ),
+            (
+                || {
+                    ThingBuilder::<Nil, _>::new("MyLampThing")
+                        .finish_extend()
+                        .action("fade", |b| {
+                            b.uri_variable("", |b| b.finish_extend().integer())
+                        })
+                        .build()
+                },
+                NameKind::UriVariable,
+            ),
+            (
+                || {
+                    ThingBuilder::<Nil, _>::new("MyLampThing")
+                        .finish_extend()
+                        .schema_definition(" ", |b| b.finish_extend().null())
+                        .build()
+                },
+                NameKind::SchemaDefinition,
+            ),
+            (
+                || {
+                    ThingBuilder::<Nil, _>::new("MyLampThing")
+                        .finish_extend()
+                        .security(|b| b.no_sec().with_key(" "))
+                        .build()
+                },
+                NameKind::SecurityDefinition,
+            ),
+            (
+                || {
+                    ThingBuilder::<Nil, _>::new("MyLampThing")
+                        .finish_extend()
+                        .form(|b| {
+                            b.href("/all")
+                                .op(FormOperation::ReadAllProperties)
+                                .security("")
+                        })
+                        .build()
+                },
+                NameKind::Security,
+            ),
+        ];
+
+        for (build, kind) in cases {
+            assert_eq!(
+                build().unwrap_err(),
+                Error::Document(DocumentError::EmptyName(kind))
+            );
+        }
+    }
+
+    #[test]
+    fn combo_security_scheme_with_all_of() {
+        let builder = SecuritySchemeBuilder {
+            attype: Default::default(),
+            description: Default::default(),
+            descriptions: Default::default(),
+            proxy: Default::default(),
+            name: Default::default(),
+            subtype: (),
+            required: Default::default(),
+        }
+        .combo()
+        .attype("attype")
+        .all_of(["schema1", "schema2"])
+        .extend(["schema3", "schema4"])
+        .push("schema1")
+        .description("description");
+
+        assert_eq!(builder.attype, Some(vec!["attype".to_string()]));
+        assert_eq!(builder.description, Some("description".to_string()));
+        assert_eq!(
+            builder.subtype,
+            (
+                AllOfComboSecuritySchemeTag,
+                ["schema1", "schema2", "schema3", "schema4", "schema1"]
+                    .map(String::from)
+                    .into(),
+            ),
+        );
+
+        let subtype = builder.subtype.build();
+        assert_eq!(
+            subtype,
+            SecuritySchemeSubtype::Known(KnownSecuritySchemeSubtype::Combo(
+                ComboSecurityScheme::AllOf(
+                    ["schema1", "schema2", "schema3", "schema4", "schema1"]
+                        .map(String::from)
+                        .into()
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn combo_security_scheme_with_one_of() {
+        let builder = SecuritySchemeBuilder {
+            attype: Default::default(),
+            description: Default::default(),
+            descriptions: Default::default(),
+            proxy: Default::default(),
+            name: Default::default(),
+            subtype: (),
+            required: Default::default(),
+        }
+        .combo()
+        .attype("attype")
+        .one_of(["schema1", "schema2"])
+        .extend(["schema3", "schema4"])
+        .push("schema1")
+        .description("description");
+
+        assert_eq!(builder.attype, Some(vec!["attype".to_string()]));
+        assert_eq!(builder.description, Some("description".to_string()));
+        assert_eq!(
+            builder.subtype,
+            (
+                OneOfComboSecuritySchemeTag,
+                ["schema1", "schema2", "schema3", "schema4", "schema1"]
+                    .map(String::from)
+                    .into(),
+            ),
+        );
+
+        let subtype = builder.subtype.build();
+        assert_eq!(
+            subtype,
+            SecuritySchemeSubtype::Known(KnownSecuritySchemeSubtype::Combo(
+                ComboSecurityScheme::OneOf(
+                    ["schema1", "schema2", "schema3", "schema4", "schema1"]
+                        .map(String::from)
+                        .into()
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn valid_combo_security_scheme() {
+        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .security(|b| b.basic())
+            .security(|b| b.combo().one_of(["basic", "nosec"]))
+            .security(|b| b.no_sec())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            thing,
+            Thing {
+                context: TD_CONTEXT_11.into(),
+                title: "MyLampThing".to_string(),
+                security_definitions: [
+                    (
+                        "basic".to_string(),
+                        SecurityScheme {
+                            subtype: SecuritySchemeSubtype::Known(
+                                KnownSecuritySchemeSubtype::Basic(Default::default())
+                            ),
                             ..Default::default()
                         }
-                    )]
-                    .into_iter()
-                    .collect()
-                ),
-                actions: Some(
-                    [(
-                        "action".to_string(),
-                        ActionAffordance {
-                            interaction: InteractionAffordance {
-                                forms: vec![Form {
-                                    op: DefaultedFormOperations::Custom(vec![
-                                        FormOperation::InvokeAction,
-                                        FormOperation::QueryAction,
-                                        FormOperation::CancelAction,
-                                    ]),
-                                    href: "href".to_string(),
-                                    ..Default::default()
-                                }],
-                                ..Default::default()
-                            },
+                    ),
+                    (
+                        "combo".to_string(),
+                        SecurityScheme {
+                            subtype: SecuritySchemeSubtype::Known(
+                                KnownSecuritySchemeSubtype::Combo(ComboSecurityScheme::OneOf(
+                                    vec!["basic".to_string(), "nosec".to_string()]
+                                ))
+                            ),
                             ..Default::default()
                         }
-                    )]
-                    .into_iter()
-                    .collect()
-                ),
-                events: Some(
-                    [(
-                        "event".to_string(),
-                        EventAffordance {
-                            interaction: InteractionAffordance {
-                                forms: vec![Form {
-                                    op: DefaultedFormOperations::Custom(vec![
-                                        FormOperation::SubscribeEvent,
-                                        FormOperation::UnsubscribeEvent,
-                                    ]),
-                                    href: "href".to_string(),
-                                    ..Default::default()
-                                }],
-                                ..Default::default()
-                            },
+                    ),
+                    (
+                        "nosec".to_string(),
+                        SecurityScheme {
+                            subtype: SecuritySchemeSubtype::Known(
+                                KnownSecuritySchemeSubtype::NoSec
+                            ),
                             ..Default::default()
                         }
-                    )]
-                    .into_iter()
-                    .collect()
-                ),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
                 ..Default::default()
             },
-        )
+        );
+    }
+
+    #[test]
+    fn missing_combo_security_scheme() {
+        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .security(|b| b.combo().one_of(["basic", "nosec"]))
+            .security(|b| b.no_sec())
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            Error::DataSchema(DataSchemaError::MissingSchemaDefinition(
+                "basic".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn checked_op_in_form() {
+        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .form(|b| {
+                b.op(FormOperation::ReadAllProperties)
+                    .op(FormOperation::WriteAllProperties)
+                    .op(FormOperation::ReadMultipleProperties)
+                    .op(FormOperation::WriteMultipleProperties)
+                    .op(FormOperation::ObserveAllProperties)
+                    .op(FormOperation::UnobserveAllProperties)
+                    .op(FormOperation::SubscribeAllEvents)
+                    .op(FormOperation::UnsubscribeAllEvents)
+                    .op(FormOperation::QueryAllActions)
+                    .href("href")
+            })
+            .property("property", |b| {
+                b.finish_extend_data_schema().null().form(|b| {
+                    b.op(FormOperation::ReadProperty)
+                        .op(FormOperation::WriteProperty)
+                        .op(FormOperation::ObserveProperty)
+                        .op(FormOperation::UnobserveProperty)
+                        .href("href")
+                })
+            })
+            .action("action", |b| {
+                b.form(|b| {
+                    b.op(FormOperation::InvokeAction)
+                        .op(FormOperation::QueryAction)
+                        .op(FormOperation::CancelAction)
+                        .href("href")
+                })
+            })
+            .event("event", |b| {
+                b.form(|b| {
+                    b.op(FormOperation::SubscribeEvent)
+                        .op(FormOperation::UnsubscribeEvent)
+                        .href("href")
+                })
+            })
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            thing,
+            Thing {
+                context: TD_CONTEXT_11.into(),
+                title: "MyLampThing".to_string(),
+                forms: Some(vec![Form {
+                    op: DefaultedFormOperations::Custom(vec![
+                        FormOperation::ReadAllProperties,
+                        FormOperation::WriteAllProperties,
+                        FormOperation::ReadMultipleProperties,
+                        FormOperation::WriteMultipleProperties,
+                        FormOperation::ObserveAllProperties,
+                        FormOperation::UnobserveAllProperties,
+                        FormOperation::SubscribeAllEvents,
+                        FormOperation::UnsubscribeAllEvents,
+                        FormOperation::QueryAllActions
+                    ]),
+                    href: "href".to_string(),
+                    ..Default::default()
+                }]),
+                properties: Some(
+                    [(
+                        "property".to_string(),
+                        PropertyAffordance {
+                            interaction: InteractionAffordance {
+                                forms: vec![Form {
+                                    op: DefaultedFormOperations::Custom(vec![
+                                        FormOperation::ReadProperty,
+                                        FormOperation::WriteProperty,
+                                        FormOperation::ObserveProperty,
+                                        FormOperation::UnobserveProperty,
+                                    ]),
+                                    href: "href".to_string(),
+                                    ..Default::default()
+                                }],
+                                ..Default::default()
+                            },
+                            data_schema: DataSchema {
+                                subtype: Some(DataSchemaSubtype::Null),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        }
+                    )]
+                    .into_iter()
+                    .collect()
+                ),
+                actions: Some(
+                    [(
+                        "action".to_string(),
+                        ActionAffordance {
+                            interaction: InteractionAffordance {
+                                forms: vec![Form {
+                                    op: DefaultedFormOperations::Custom(vec![
+                                        FormOperation::InvokeAction,
+                                        FormOperation::QueryAction,
+                                        FormOperation::CancelAction,
+                                    ]),
+                                    href: "href".to_string(),
+                                    ..Default::default()
+                                }],
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        }
+                    )]
+                    .into_iter()
+                    .collect()
+                ),
+                events: Some(
+                    [(
+                        "event".to_string(),
+                        EventAffordance {
+                            interaction: InteractionAffordance {
+                                forms: vec![Form {
+                                    op: DefaultedFormOperations::Custom(vec![
+                                        FormOperation::SubscribeEvent,
+                                        FormOperation::UnsubscribeEvent,
+                                    ]),
+                                    href: "href".to_string(),
+                                    ..Default::default()
+                                }],
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        }
+                    )]
+                    .into_iter()
+                    .collect()
+                ),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn invalid_form_with_invalid_op_in_property_affordance() {
+        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .property("property", |b| {
+                b.finish_extend_data_schema().null().form(|b| {
+                    b.op(FormOperation::ReadProperty)
+                        .op(FormOperation::ReadAllProperties)
+                        .op(FormOperation::WriteAllProperties)
+                        .href("href")
+                })
+            })
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            Error::Affordance(AffordanceError::InvalidOpInForm {
+                context: FormContext::Property,
+                operation: FormOperation::ReadAllProperties
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_form_with_invalid_op_in_action_affordance() {
+        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .action("action", |b| {
+                b.form(|b| {
+                    b.op(FormOperation::InvokeAction)
+                        .op(FormOperation::WriteProperty)
+                        .op(FormOperation::QueryAction)
+                        .href("href")
+                })
+            })
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            Error::Affordance(AffordanceError::InvalidOpInForm {
+                context: FormContext::Action,
+                operation: FormOperation::WriteProperty
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_form_with_invalid_op_in_event_affordance() {
+        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .event("event", |b| {
+                b.form(|b| {
+                    b.op(FormOperation::SubscribeEvent)
+                        .op(FormOperation::ReadProperty)
+                        .op(FormOperation::UnsubscribeEvent)
+                        .href("href")
+                })
+            })
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            Error::Affordance(AffordanceError::InvalidOpInForm {
+                context: FormContext::Event,
+                operation: FormOperation::ReadProperty
+            })
+        );
+    }
+
+    #[test]
+    fn form_operation_serialize_display_coherence() {
+        const OPS: [FormOperation; 18] = [
+            FormOperation::ReadProperty,
+            FormOperation::WriteProperty,
+            FormOperation::ObserveProperty,
+            FormOperation::UnobserveProperty,
+            FormOperation::InvokeAction,
+            FormOperation::QueryAction,
+            FormOperation::CancelAction,
+            FormOperation::SubscribeEvent,
+            FormOperation::UnsubscribeEvent,
+            FormOperation::ReadAllProperties,
+            FormOperation::WriteAllProperties,
+            FormOperation::ReadMultipleProperties,
+            FormOperation::WriteMultipleProperties,
+            FormOperation::ObserveAllProperties,
+            FormOperation::UnobserveAllProperties,
+            FormOperation::SubscribeAllEvents,
+            FormOperation::UnsubscribeAllEvents,
+            FormOperation::QueryAllActions,
+        ];
+
+        for op in OPS {
+            assert_eq!(
+                serde_json::to_value(op).unwrap(),
+                Value::String(op.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn convert_valid_unchecked_security_schema() {
+        let schema = UncheckedSecurityScheme {
+            attype: Some(vec!["attype1".to_string(), "attype2".to_string()]),
+            description: Some("description".to_string()),
+            descriptions: Some({
+                let mut multilang = MultiLanguageBuilder::default();
+                multilang
+                    .add("it", "description1")
+                    .add("en", "description2");
+                multilang
+            }),
+            proxy: Some("proxy".to_string()),
+            subtype: SecuritySchemeSubtype::Known(KnownSecuritySchemeSubtype::Psk(
+                PskSecurityScheme {
+                    identity: Some("identity".to_string()),
+                },
+            )),
+        };
+
+        assert_eq!(
+            SecurityScheme::try_from(schema).unwrap(),
+            SecurityScheme {
+                attype: Some(vec!["attype1".into(), "attype2".into()]),
+                description: Some("description".to_string()),
+                descriptions: Some(
+                    [
+                        ("it".parse().unwrap(), "description1".to_string()),
+                        ("en".parse().unwrap(), "description2".to_string())
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+                proxy: Some("proxy".to_string()),
+                subtype: SecuritySchemeSubtype::Known(KnownSecuritySchemeSubtype::Psk(
+                    PskSecurityScheme {
+                        identity: Some("identity".to_string()),
+                    },
+                )),
+            },
+        );
+    }
+
+    #[test]
+    fn convert_invalid_unchecked_security_schema() {
+        let schema = UncheckedSecurityScheme {
+            attype: Some(vec!["attype1".to_string(), "attype2".to_string()]),
+            description: Some("description".to_string()),
+            descriptions: Some({
+                let mut multilang = MultiLanguageBuilder::default();
+                multilang
+                    .add("it", "description1")
+                    .add("e1n", "description2");
+                multilang
+            }),
+            proxy: Some("proxy".to_string()),
+            subtype: SecuritySchemeSubtype::Known(KnownSecuritySchemeSubtype::Psk(
+                PskSecurityScheme {
+                    identity: Some("identity".to_string()),
+                },
+            )),
+        };
+
+        assert_eq!(
+            SecurityScheme::try_from(schema).unwrap_err(),
+            Error::AtPath {
+                path: "/descriptions".to_string(),
+                source: Box::new(Error::Document(DocumentError::InvalidLanguageTag(
+                    "e1n".to_string()
+                ))),
+            },
+        );
+    }
+
+    #[test]
+    fn invalid_language_tag() {
+        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .security(|b| {
+                b.auto()
+                    .descriptions(|ml| ml.add("en", "desc_en").add("i1t", "desc_it"))
+            })
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            Error::AtPath {
+                path: "/securityDefinitions/auto/descriptions".to_string(),
+                source: Box::new(Error::Document(DocumentError::InvalidLanguageTag(
+                    "i1t".to_string()
+                ))),
+            },
+        );
+    }
+
+    #[test]
+    fn language_tag_error_paths() {
+        use crate::builder::data_schema::ObjectDataSchemaBuilderLike;
+
+        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .action("calibrate", |b| {
+                b.input(|b| {
+                    b.finish_extend().object().property("offset", false, |b| {
+                        b.finish_extend()
+                            .number()
+                            .descriptions(|ml| ml.add("en", "Offset").add("e1n", "Offset"))
+                    })
+                })
+            })
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            Error::AtPath {
+                path: "/actions/calibrate/input/properties/offset/descriptions".to_string(),
+                source: Box::new(Error::Document(DocumentError::InvalidLanguageTag(
+                    "e1n".to_string()
+                ))),
+            },
+        );
+        assert_eq!(
+            err.to_string(),
+            "Invalid member at /actions/calibrate/input/properties/offset/descriptions: \
+             Invalid language tag \"e1n\"",
+        );
+
+        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .titles(|ml| ml.add("i1t", "Lampada"))
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            Error::AtPath {
+                path: "/titles".to_string(),
+                source: Box::new(Error::Document(DocumentError::InvalidLanguageTag(
+                    "i1t".to_string()
+                ))),
+            },
+        );
+
+        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .property("on", |b| {
+                b.finish_extend_data_schema()
+                    .bool()
+                    .titles(|ml| ml.add("i1t", "Acceso"))
+            })
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            Error::AtPath {
+                path: "/properties/on/titles".to_string(),
+                source: Box::new(Error::Document(DocumentError::InvalidLanguageTag(
+                    "i1t".to_string()
+                ))),
+            },
+        );
+
+        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .event("overheating", |b| {
+                b.data(|b| {
+                    b.finish_extend()
+                        .string()
+                        .titles(|ml| ml.add("e1n", "Data"))
+                })
+            })
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            Error::AtPath {
+                path: "/events/overheating/data/titles".to_string(),
+                source: Box::new(Error::Document(DocumentError::InvalidLanguageTag(
+                    "e1n".to_string()
+                ))),
+            },
+        );
+    }
+
+    #[test]
+    fn untitled_builder() {
+        let thing = ThingBuilder::<Nil, _, _>::new_untitled()
+            .id("urn:dev:ops:32473-WoTLamp-1234")
+            .description("A lamp")
+            .finish_extend()
+            .property("on", |b| b.finish_extend_data_schema().bool())
+            .title("MyLampThing")
+            .build()
+            .unwrap();
+
+        let expected = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .id("urn:dev:ops:32473-WoTLamp-1234")
+            .description("A lamp")
+            .finish_extend()
+            .property("on", |b| b.finish_extend_data_schema().bool())
+            .build()
+            .unwrap();
+
+        assert_eq!(thing, expected);
+    }
+
+    #[test]
+    fn parse_valid_and_invalid_language_tags() {
+        let tag = parse_language_tag("zh-Hant-TW").unwrap();
+        assert_eq!(tag.primary_language(), "zh");
+        assert_eq!(tag.script(), Some("Hant"));
+        assert_eq!(tag.region(), Some("TW"));
+
+        assert_eq!(
+            parse_language_tag("en_US"),
+            Err(Error::Document(DocumentError::InvalidLanguageTag(
+                "en_US".to_string()
+            ))),
+        );
+    }
+
+    #[test]
+    fn default_ops_in_build() {
+        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .property("level", |b| {
+                b.finish_extend_data_schema()
+                    .observable(true)
+                    .form(|form| form.href("/level"))
+                    .form(|form| form.href("/level/raw").op(FormOperation::ReadProperty))
+                    .integer()
+            })
+            .action("fade", |b| b.form(|form| form.href("/fade")))
+            .event("overheating", |b| b.form(|form| form.href("/overheating")))
+            .build()
+            .unwrap();
+
+        let properties = thing.properties.as_ref().unwrap();
+        let level = &properties["level"].interaction.forms;
+        assert_eq!(
+            level[0].op,
+            DefaultedFormOperations::Custom(vec![
+                FormOperation::ReadProperty,
+                FormOperation::WriteProperty,
+            ]),
+        );
+        assert_eq!(
+            level[1].op,
+            DefaultedFormOperations::Custom(vec![FormOperation::ReadProperty]),
+        );
+        assert_eq!(
+            thing.actions.as_ref().unwrap()["fade"].interaction.forms[0].op,
+            DefaultedFormOperations::Custom(vec![FormOperation::InvokeAction]),
+        );
+        assert_eq!(
+            thing.events.as_ref().unwrap()["overheating"]
+                .interaction
+                .forms[0]
+                .op,
+            DefaultedFormOperations::Custom(vec![
+                FormOperation::SubscribeEvent,
+                FormOperation::UnsubscribeEvent,
+            ]),
+        );
+    }
+
+    #[test]
+    fn implicit_default_ops() {
+        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .implicit_default_ops()
+            .finish_extend()
+            .property("level", |b| {
+                b.finish_extend_data_schema()
+                    .form(|form| form.href("/level"))
+                    .form(|form| form.href("/level/raw").op(FormOperation::ReadProperty))
+                    .integer()
+            })
+            .action("fade", |b| b.form(|form| form.href("/fade")))
+            .build()
+            .unwrap();
+
+        let level = &thing.properties.as_ref().unwrap()["level"]
+            .interaction
+            .forms;
+        assert!(level[0].op.is_default());
+        assert_eq!(
+            level[1].op,
+            DefaultedFormOperations::Custom(vec![FormOperation::ReadProperty]),
+        );
+        assert!(thing.actions.as_ref().unwrap()["fade"].interaction.forms[0]
+            .op
+            .is_default());
+        assert_eq!(
+            serde_json::to_value(&thing).unwrap()["actions"]["fade"]["forms"][0],
+            json!({ "href": "/fade" }),
+        );
+    }
+
+    #[test]
+    fn default_content_type_leaves_forms_untouched() {
+        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .default_content_type("application/cbor")
+            .finish_extend()
+            .form(|b| b.href("/all").op(FormOperation::ReadAllProperties))
+            .property("on", |b| {
+                b.finish_extend_data_schema()
+                    .form(|b| b.href("/on").content_type("text/plain"))
+                    .bool()
+            })
+            .action("fade", |b| b.form(|b| b.href("/fade")))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            thing.default_content_type,
+            Some("application/cbor".to_string())
+        );
+
+        let form = &thing.forms.as_ref().unwrap()[0];
+        assert_eq!(form.content_type, None);
+        assert_eq!(form.content_type_or(&thing), "application/cbor");
+
+        let form = &thing.properties.as_ref().unwrap()["on"].interaction.forms[0];
+        assert_eq!(form.content_type_or(&thing), "text/plain");
+
+        let form = &thing.actions.as_ref().unwrap()["fade"].interaction.forms[0];
+        assert_eq!(form.content_type, None);
+        assert_eq!(form.content_type_or(&thing), "application/cbor");
     }
 
     #[test]
-    fn invalid_form_with_invalid_op_in_property_affordance() {
-        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+    fn raw_fields() {
+        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
             .finish_extend()
-            .property("property", |b| {
-                b.finish_extend_data_schema().null().form(|b| {
-                    b.op(FormOperation::ReadProperty)
-                        .op(FormOperation::ReadAllProperties)
-                        .op(FormOperation::WriteAllProperties)
-                        .href("href")
+            .property("on", |b| {
+                b.finish_extend_data_schema()
+                    .raw_field("x-vendor", "switch")
+                    .bool()
+                    .form(|b| b.href("/on"))
+            })
+            .action("fade", |b| {
+                b.input(|b| {
+                    b.finish_extend()
+                        .integer()
+                        .raw_field("x-vendor", json!({ "step": 5 }))
+                        .raw_field("x-vendor", json!({ "step": 10 }))
                 })
             })
             .build()
-            .unwrap_err();
+            .unwrap();
 
+        let thing = serde_json::to_value(thing).unwrap();
+        assert_eq!(thing["properties"]["on"]["x-vendor"], "switch");
         assert_eq!(
-            err,
-            Error::InvalidOpInForm {
-                context: FormContext::Property,
-                operation: FormOperation::ReadAllProperties
-            }
+            thing["actions"]["fade"]["input"]["x-vendor"],
+            json!({ "step": 10 }),
         );
-    }
 
-    #[test]
-    fn invalid_form_with_invalid_op_in_action_affordance() {
         let err = ThingBuilder::<Nil, _>::new("MyLampThing")
             .finish_extend()
-            .action("action", |b| {
-                b.form(|b| {
-                    b.op(FormOperation::InvokeAction)
-                        .op(FormOperation::WriteProperty)
-                        .op(FormOperation::QueryAction)
-                        .href("href")
-                })
+            .property("on", |b| {
+                b.finish_extend_data_schema()
+                    .raw_field("readOnly", true)
+                    .bool()
             })
             .build()
             .unwrap_err();
-
         assert_eq!(
             err,
-            Error::InvalidOpInForm {
-                context: FormContext::Action,
-                operation: FormOperation::WriteProperty
-            }
+            Error::DataSchema(DataSchemaError::ReservedKeyOverride("readOnly".to_string()))
         );
-    }
 
-    #[test]
-    fn invalid_form_with_invalid_op_in_event_affordance() {
         let err = ThingBuilder::<Nil, _>::new("MyLampThing")
             .finish_extend()
-            .event("event", |b| {
-                b.form(|b| {
-                    b.op(FormOperation::SubscribeEvent)
-                        .op(FormOperation::ReadProperty)
-                        .op(FormOperation::UnsubscribeEvent)
-                        .href("href")
+            .schema_definition("level", |b| {
+                b.finish_extend().object().property("value", true, |b| {
+                    b.finish_extend().raw_field("minimum", 0).integer()
                 })
             })
             .build()
             .unwrap_err();
-
         assert_eq!(
             err,
-            Error::InvalidOpInForm {
-                context: FormContext::Event,
-                operation: FormOperation::ReadProperty
-            }
+            Error::AtPath {
+                path: "/schemaDefinitions/level/properties/value".to_string(),
+                source: Box::new(Error::DataSchema(DataSchemaError::ReservedKeyOverride(
+                    "minimum".to_string()
+                ))),
+            },
         );
     }
 
     #[test]
-    fn form_operation_serialize_display_coherence() {
-        const OPS: [FormOperation; 18] = [
-            FormOperation::ReadProperty,
-            FormOperation::WriteProperty,
-            FormOperation::ObserveProperty,
-            FormOperation::UnobserveProperty,
-            FormOperation::InvokeAction,
-            FormOperation::QueryAction,
-            FormOperation::CancelAction,
-            FormOperation::SubscribeEvent,
-            FormOperation::UnsubscribeEvent,
-            FormOperation::ReadAllProperties,
-            FormOperation::WriteAllProperties,
-            FormOperation::ReadMultipleProperties,
-            FormOperation::WriteMultipleProperties,
-            FormOperation::ObserveAllProperties,
-            FormOperation::UnobserveAllProperties,
-            FormOperation::SubscribeAllEvents,
-            FormOperation::UnsubscribeAllEvents,
-            FormOperation::QueryAllActions,
-        ];
+    fn raw_fields_of_properties() {
+        #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+        struct Hint {
+            #[serde(rename = "vendor:hint")]
+            hint: String,
+        }
 
-        for op in OPS {
+        #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+        struct Hinted {}
+
+        impl ExtendableThing for Hinted {
+            type InteractionAffordance = ();
+            type PropertyAffordance = Hint;
+            type ActionAffordance = ();
+            type EventAffordance = ();
+            type Form = ();
+            type ExpectedResponse = ();
+            type DataSchema = ();
+            type ObjectSchema = ();
+            type ArraySchema = ();
+        }
+
+        for key in ["forms", "observable", "uriVariables"] {
+            let err = ThingBuilder::<Nil, _>::new("MyLampThing")
+                .finish_extend()
+                .property("on", |b| {
+                    b.finish_extend_data_schema()
+                        .raw_field(key, true)
+                        .bool()
+                        .form(|b| b.href("/on"))
+                })
+                .build()
+                .unwrap_err();
             assert_eq!(
-                serde_json::to_value(op).unwrap(),
-                Value::String(op.to_string())
+                err,
+                Error::DataSchema(DataSchemaError::ReservedKeyOverride(key.to_string()))
             );
         }
-    }
 
-    #[test]
-    fn convert_valid_unchecked_security_schema() {
-        let schema = UncheckedSecurityScheme {
-            attype: Some(vec!["attype1".to_string(), "attype2".to_string()]),
-            description: Some("description".to_string()),
-            descriptions: Some({
-                let mut multilang = MultiLanguageBuilder::default();
-                multilang
-                    .add("it", "description1")
-                    .add("en", "description2");
-                multilang
-            }),
-            proxy: Some("proxy".to_string()),
-            subtype: SecuritySchemeSubtype::Known(KnownSecuritySchemeSubtype::Psk(
-                PskSecurityScheme {
-                    identity: Some("identity".to_string()),
-                },
-            )),
-        };
+        let err = Thing::builder("MyLampThing")
+            .ext(Hinted {})
+            .finish_extend()
+            .property("on", |b| {
+                b.ext_interaction(())
+                    .ext_data_schema(())
+                    .finish_extend_data_schema()
+                    .raw_field("vendor:hint", "slider")
+                    .ext(Hint {
+                        hint: "switch".to_string(),
+                    })
+                    .bool()
+                    .form(|b| b.ext(()).href("/on"))
+            })
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            Error::DataSchema(DataSchemaError::ReservedKeyOverride(
+                "vendor:hint".to_string()
+            ))
+        );
+
+        // The raw fields of the data schemas that are not flattened into an affordance can use
+        // the names of the affordance members.
+        let thing = ThingBuilder::<Nil, _>::new("MyLampThing")
+            .finish_extend()
+            .property("on", |b| {
+                b.finish_extend_data_schema()
+                    .raw_field("x-vendor", "switch")
+                    .bool()
+                    .form(|b| b.href("/on"))
+            })
+            .action("fade", |b| {
+                b.input(|b| b.finish_extend().raw_field("forms", "none").integer())
+            })
+            .build()
+            .unwrap();
 
+        // The raw fields are not deserialized back.
+        let serialized = serde_json::to_value(&thing).unwrap();
+        assert_eq!(serialized["actions"]["fade"]["input"]["forms"], "none");
+        let deserialized: Thing = serde_json::from_value(serialized.clone()).unwrap();
+        let property = &deserialized.properties.as_ref().unwrap()["on"];
+        assert!(property.data_schema.raw_fields.is_empty());
+        #[cfg(feature = "preserve-unknown")]
+        assert_eq!(property.data_schema.unknown["x-vendor"], "switch");
+        #[cfg(not(feature = "preserve-unknown"))]
         assert_eq!(
-            SecurityScheme::try_from(schema).unwrap(),
-            SecurityScheme {
-                attype: Some(vec!["attype1".to_string(), "attype2".to_string()]),
-                description: Some("description".to_string()),
-                descriptions: Some(
-                    [
-                        ("it".parse().unwrap(), "description1".to_string()),
-                        ("en".parse().unwrap(), "description2".to_string())
-                    ]
-                    .into_iter()
-                    .collect(),
-                ),
-                proxy: Some("proxy".to_string()),
-                subtype: SecuritySchemeSubtype::Known(KnownSecuritySchemeSubtype::Psk(
-                    PskSecurityScheme {
-                        identity: Some("identity".to_string()),
-                    },
-                )),
-            },
+            serde_json::to_value(&deserialized).unwrap()["properties"]["on"].get("x-vendor"),
+            None,
         );
     }
 
     #[test]
-    fn convert_invalid_unchecked_security_schema() {
-        let schema = UncheckedSecurityScheme {
-            attype: Some(vec!["attype1".to_string(), "attype2".to_string()]),
-            description: Some("description".to_string()),
-            descriptions: Some({
-                let mut multilang = MultiLanguageBuilder::default();
-                multilang
-                    .add("it", "description1")
-                    .add("e1n", "description2");
-                multilang
-            }),
-            proxy: Some("proxy".to_string()),
-            subtype: SecuritySchemeSubtype::Known(KnownSecuritySchemeSubtype::Psk(
-                PskSecurityScheme {
-                    identity: Some("identity".to_string()),
-                },
-            )),
+    fn extension_field_collisions() {
+        #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+        struct VendorA {
+            vendor: String,
+        }
+
+        #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+        struct VendorB {
+            vendor: u32,
+        }
+
+        #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+        struct FormHref {
+            href: String,
+        }
+
+        impl ExtendableThing for VendorA {
+            type InteractionAffordance = ();
+            type PropertyAffordance = ();
+            type ActionAffordance = ();
+            type EventAffordance = ();
+            type Form = ();
+            type ExpectedResponse = ();
+            type DataSchema = ();
+            type ObjectSchema = ();
+            type ArraySchema = ();
+        }
+
+        impl ExtendableThing for VendorB {
+            type InteractionAffordance = ();
+            type PropertyAffordance = ();
+            type ActionAffordance = ();
+            type EventAffordance = ();
+            type Form = FormHref;
+            type ExpectedResponse = ();
+            type DataSchema = ();
+            type ObjectSchema = ();
+            type ArraySchema = ();
+        }
+
+        let builder = || {
+            Thing::builder("MyLampThing")
+                .ext(VendorA::default())
+                .ext(VendorB::default())
+                .finish_extend()
         };
 
+        assert!(builder().build().is_ok());
         assert_eq!(
-            SecurityScheme::try_from(schema).unwrap_err(),
-            Error::InvalidLanguageTag("e1n".to_string()),
+            builder().check_extension_collisions().build().unwrap_err(),
+            Error::Document(DocumentError::ExtensionFieldCollision {
+                field: "vendor".to_string(),
+                left: type_name::<VendorB>(),
+                right: type_name::<VendorA>(),
+            }),
         );
-    }
 
-    #[test]
-    fn invalid_language_tag() {
-        let err = ThingBuilder::<Nil, _>::new("MyLampThing")
-            .security(|b| {
-                b.auto()
-                    .descriptions(|ml| ml.add("en", "desc_en").add("i1t", "desc_it"))
+        let error = Thing::builder("MyLampThing")
+            .ext(VendorB::default())
+            .finish_extend()
+            .check_extension_collisions()
+            .form(|b| {
+                b.ext(FormHref::default())
+                    .href("/properties")
+                    .op(FormOperation::ReadAllProperties)
+            })
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            error,
+            Error::Document(DocumentError::ExtensionFieldCollision {
+                field: "href".to_string(),
+                left: "wot_td::thing::Form",
+                right: type_name::<FormHref>(),
+            }),
+        );
+
+        // The members of a structure only collide with the extensions of the same structure.
+        #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+        struct Titled {
+            title: String,
+        }
+
+        #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+        struct ThingHref {
+            href: String,
+        }
+
+        impl ExtendableThing for Titled {
+            type InteractionAffordance = ();
+            type PropertyAffordance = ();
+            type ActionAffordance = ();
+            type EventAffordance = ();
+            type Form = ();
+            type ExpectedResponse = ();
+            type DataSchema = ();
+            type ObjectSchema = ();
+            type ArraySchema = ();
+        }
+
+        impl ExtendableThing for ThingHref {
+            type InteractionAffordance = ();
+            type PropertyAffordance = ();
+            type ActionAffordance = ();
+            type EventAffordance = ();
+            type Form = Titled;
+            type ExpectedResponse = ();
+            type DataSchema = ();
+            type ObjectSchema = ();
+            type ArraySchema = ();
+        }
+
+        let thing = Thing::builder("MyLampThing")
+            .ext(ThingHref::default())
+            .finish_extend()
+            .check_extension_collisions()
+            .form(|b| {
+                b.ext(Titled::default())
+                    .href("/properties")
+                    .op(FormOperation::ReadAllProperties)
             })
+            .build();
+        assert!(thing.is_ok());
+
+        let error = Thing::builder("MyLampThing")
+            .ext(Titled::default())
+            .finish_extend()
+            .check_extension_collisions()
             .build()
             .unwrap_err();
-        assert_eq!(err, Error::InvalidLanguageTag("i1t".to_string()));
+        assert_eq!(
+            error,
+            Error::Document(DocumentError::ExtensionFieldCollision {
+                field: "title".to_string(),
+                left: "wot_td::thing::Thing",
+                right: type_name::<Titled>(),
+            }),
+        );
     }
 }
@@ -9,33 +9,40 @@ use alloc::{string::String, vec::Vec};
 use core::ops::Not;
 
 use hashbrown::HashMap;
+use serde::{Deserialize, Serialize, Serializer};
 use serde_json::Value;
+use serde_with::{serde_as, skip_serializing_none};
 
 use crate::{
-    extend::{Extend, Extendable, ExtendableThing},
+    extend::{AutoExtend, Extend, Extendable, ExtendableThing},
+    template::template_variables,
     thing::{
-        ActionAffordance, DataSchema, DefaultedFormOperations, EventAffordance, Form,
-        FormOperation, InteractionAffordance, PropertyAffordance, SecurityScheme,
+        format_at_types, parse_at_types, ActionAffordance, DataSchema, DefaultedFormOperations,
+        EventAffordance, FormFromOther, FormOperation, InteractionAffordance, PropertyAffordance,
+        SecurityScheme, StringOrArray, INTERACTION_AFFORDANCE_MEMBERS, PROPERTY_AFFORDANCE_MEMBERS,
     },
 };
 
 use super::{
+    check_name,
     data_schema::{
-        buildable_data_schema_delegate, impl_inner_delegate_schema_builder_like_integer,
+        buildable_data_schema_delegate, check_raw_fields,
+        impl_inner_delegate_schema_builder_like_integer,
         impl_inner_delegate_schema_builder_like_number,
         impl_inner_delegate_schema_builder_like_object,
         impl_inner_delegate_schema_builder_like_tuple, impl_inner_delegate_schema_builder_like_vec,
-        uri_variables_contains_arrays_objects, BuildableDataSchema, DataSchemaBuilder,
-        EnumerableDataSchema, IntegerDataSchemaBuilderLike, NumberDataSchemaBuilderLike,
-        ObjectDataSchemaBuilderLike, PartialDataSchema, PartialDataSchemaBuilder,
-        ReadableWriteableDataSchema, SpecializableDataSchema, TupleDataSchemaBuilderLike,
-        UncheckedDataSchemaFromOther, UncheckedDataSchemaMap, UnionDataSchema,
-        VecDataSchemaBuilderLike,
+        serialized_members, uri_variables_contains_arrays_objects, BuildableDataSchema,
+        DataSchemaBuilder, EnumerableDataSchema, IntegerDataSchemaBuilderLike,
+        NumberDataSchemaBuilderLike, ObjectDataSchemaBuilderLike, PartialDataSchema,
+        PartialDataSchemaBuilder, ReadableWriteableDataSchema, SpecializableDataSchema,
+        TupleDataSchemaBuilderLike, UncheckedDataSchemaFromOther, UncheckedDataSchemaMap,
+        UnionDataSchema, VecDataSchemaBuilderLike,
     },
     human_readable_info::{
         impl_delegate_buildable_hr_info, BuildableHumanReadableInfo, HumanReadableInfo,
     },
-    AffordanceType, Error, Extended, FormBuilder, MultiLanguageBuilder, ToExtend,
+    AffordanceError, AffordanceType, Error, Extended, FormBuilder, MultiLanguageBuilder, NameKind,
+    SecurityError, ToExtend,
 };
 
 /// A conversion into an _usable_ form of a value.
@@ -47,6 +54,7 @@ pub trait IntoUsable<T>: Sized {
     fn into_usable(self) -> T;
 }
 
+#[derive(Clone)]
 pub(super) struct AffordanceBuilder<Affordance> {
     pub(super) name: String,
     pub(super) affordance: Affordance,
@@ -87,6 +95,7 @@ pub trait BuildableInteractionAffordance<Other: ExtendableThing> {
     ///             "aff": {
     ///                 "forms": [{
     ///                     "href": "href",
+    ///                     "op": ["invokeaction"],
     ///                 }],
     ///                 "idempotent": false,
     ///                 "safe": false,
@@ -188,6 +197,23 @@ where
     }
 }
 
+impl<Other, OtherInteractionAffordance> Clone
+    for PartialInteractionAffordanceBuilder<Other, OtherInteractionAffordance>
+where
+    Other: ExtendableThing,
+    FormBuilder<Other, String, Other::Form>: Clone,
+    UncheckedDataSchemaFromOther<Other>: Clone,
+    OtherInteractionAffordance: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            forms: self.forms.clone(),
+            uri_variables: self.uri_variables.clone(),
+            other: self.other.clone(),
+        }
+    }
+}
+
 impl<Other>
     PartialInteractionAffordanceBuilder<Other, <Other::InteractionAffordance as Extendable>::Empty>
 where
@@ -261,6 +287,20 @@ pub(crate) struct InteractionAffordanceBuilder<Other: ExtendableThing, OtherInte
     pub(super) info: HumanReadableInfo,
 }
 
+impl<Other, OtherInteractionAffordance> Clone
+    for InteractionAffordanceBuilder<Other, OtherInteractionAffordance>
+where
+    Other: ExtendableThing,
+    PartialInteractionAffordanceBuilder<Other, OtherInteractionAffordance>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            partial: self.partial.clone(),
+            info: self.info.clone(),
+        }
+    }
+}
+
 impl<Other: ExtendableThing, OtherInteractionAffordance>
     InteractionAffordanceBuilder<Other, OtherInteractionAffordance>
 {
@@ -564,6 +604,30 @@ where
     }
 }
 
+impl<Other, DataSchema, OtherInteractionAffordance, OtherPropertyAffordance> Clone
+    for PropertyAffordanceBuilder<
+        Other,
+        DataSchema,
+        OtherInteractionAffordance,
+        OtherPropertyAffordance,
+    >
+where
+    Other: ExtendableThing,
+    PartialInteractionAffordanceBuilder<Other, OtherInteractionAffordance>: Clone,
+    DataSchema: Clone,
+    OtherPropertyAffordance: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            interaction: self.interaction.clone(),
+            info: self.info.clone(),
+            data_schema: self.data_schema.clone(),
+            observable: self.observable,
+            other: self.other.clone(),
+        }
+    }
+}
+
 /// Builder for [`ActionAffordance`].
 ///
 /// A `ActionAffordanceBuilder` behaves like an interaction affordance builder, and because of this
@@ -665,7 +729,7 @@ where
 ///             .ext(AnotherActionExtension {
 ///                 another_action_field: "hello".to_string(),
 ///             })
-///             .input(|b| b.ext(()).ext(()).finish_extend().number())
+///             .input(|b| b.finish_extend().number())
 ///     })
 ///     .build()
 ///     .unwrap();
@@ -734,6 +798,27 @@ where
     }
 }
 
+impl<Other, OtherInteractionAffordance, OtherActionAffordance> Clone
+    for ActionAffordanceBuilder<Other, OtherInteractionAffordance, OtherActionAffordance>
+where
+    Other: ExtendableThing,
+    InteractionAffordanceBuilder<Other, OtherInteractionAffordance>: Clone,
+    UncheckedDataSchemaFromOther<Other>: Clone,
+    OtherActionAffordance: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            interaction: self.interaction.clone(),
+            input: self.input.clone(),
+            output: self.output.clone(),
+            safe: self.safe,
+            idempotent: self.idempotent,
+            synchronous: self.synchronous,
+            other: self.other.clone(),
+        }
+    }
+}
+
 impl<Other> ActionAffordanceBuilder<Other, Other::InteractionAffordance, Other::ActionAffordance>
 where
     Other: ExtendableThing,
@@ -858,7 +943,7 @@ where
 ///             .ext(AnotherEventExtension {
 ///                 another_event_field: "hello".to_string(),
 ///             })
-///             .subscription(|b| b.ext(()).ext(()).finish_extend().number())
+///             .subscription(|b| b.finish_extend().number())
 ///     })
 ///     .build()
 ///     .unwrap();
@@ -905,6 +990,26 @@ pub struct EventAffordanceBuilder<
     pub other: OtherEventAffordance,
 }
 
+impl<Other, OtherInteractionAffordance, OtherEventAffordance> Clone
+    for EventAffordanceBuilder<Other, OtherInteractionAffordance, OtherEventAffordance>
+where
+    Other: ExtendableThing,
+    InteractionAffordanceBuilder<Other, OtherInteractionAffordance>: Clone,
+    UncheckedDataSchemaFromOther<Other>: Clone,
+    OtherEventAffordance: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            interaction: self.interaction.clone(),
+            subscription: self.subscription.clone(),
+            data: self.data.clone(),
+            cancellation: self.cancellation.clone(),
+            data_response: self.data_response.clone(),
+            other: self.other.clone(),
+        }
+    }
+}
+
 type EmptyEventAffordanceBuilder<Other> = EventAffordanceBuilder<
     Other,
     <<Other as ExtendableThing>::InteractionAffordance as Extendable>::Empty,
@@ -1020,6 +1125,11 @@ where
     fn default_value(mut self, value: impl Into<Value>) -> Self {
         buildable_data_schema_delegate!(self.data_schema -> default_value(value))
     }
+
+    #[inline]
+    fn raw_field(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        buildable_data_schema_delegate!(self.data_schema -> raw_field(key, value))
+    }
 }
 
 impl_delegate_buildable_hr_info!(
@@ -1217,14 +1327,19 @@ where
     }
 
     /// Makes the builder unextendable and allows further customizations.
-    pub fn finish_extend_data_schema(
+    ///
+    /// Missing `()` extensions are automatically filled, see [`AutoExtend`] for more information.
+    pub fn finish_extend_data_schema<Target, Index>(
         self,
     ) -> PropertyAffordanceBuilder<
         Other,
-        PartialDataSchemaBuilder<DS, AS, OS, Extended>,
+        PartialDataSchemaBuilder<Target, AS, OS, Extended>,
         OtherInteractionAffordance,
         OtherPropertyAffordance,
-    > {
+    >
+    where
+        DS: AutoExtend<Target, Index>,
+    {
         let Self {
             interaction,
             info,
@@ -1302,14 +1417,19 @@ where
     }
 
     /// Makes the builder unextendable and allows further customizations.
-    pub fn finish_extend_data_schema(
+    ///
+    /// Missing `()` extensions are automatically filled, see [`AutoExtend`] for more information.
+    pub fn finish_extend_data_schema<Target, Index>(
         self,
     ) -> PropertyAffordanceBuilder<
         Other,
-        DataSchemaBuilder<DS, AS, OS, Extended>,
+        DataSchemaBuilder<Target, AS, OS, Extended>,
         OtherInteractionAffordance,
         OtherPropertyAffordance,
-    > {
+    >
+    where
+        DS: AutoExtend<Target, Index>,
+    {
         let Self {
             interaction,
             info,
@@ -1651,6 +1771,10 @@ where
         string => Self::String,
         null => Self::Stateless,
         constant(value: impl Into<Value>) => Self::Constant,
+        constant_bool(value: bool) => Self::Constant,
+        constant_int(value: i64) => Self::Constant,
+        constant_number(value: f64) => Self::Constant,
+        constant_string(value: impl Into<String>) => Self::Constant,
     );
 }
 
@@ -1692,6 +1816,30 @@ where
             other,
         }
     }
+
+    fn enum_from_iter<I>(self, iter: I) -> Self::Target
+    where
+        I: IntoIterator,
+        I::Item: Into<Value>,
+    {
+        let Self {
+            interaction,
+            info,
+            data_schema,
+            observable,
+            other,
+        } = self;
+
+        let data_schema = data_schema.enum_from_iter(iter);
+
+        PropertyAffordanceBuilder {
+            interaction,
+            info,
+            data_schema,
+            observable,
+            other,
+        }
+    }
 }
 
 impl<Other, CDS, DS, AS, OS, OtherInteractionAffordance, OtherPropertyAffordance>
@@ -2352,6 +2500,8 @@ where
                     titles,
                     description,
                     descriptions,
+                    deprecated,
+                    deprecation_note,
                 },
             partial:
                 PartialInteractionAffordanceBuilder {
@@ -2361,7 +2511,7 @@ where
                 },
         } = builder;
 
-        let forms = forms.into_iter().map(Form::from).collect();
+        let forms = forms.into_iter().map(Into::into).collect();
         let uri_variables = uri_variables.is_empty().not().then_some(uri_variables);
         let other = other.into();
 
@@ -2371,6 +2521,8 @@ where
             titles,
             description,
             descriptions,
+            deprecated,
+            deprecation_note,
             forms,
             uri_variables,
             other,
@@ -2459,8 +2611,11 @@ impl<Other: ExtendableThing> CheckableInteractionAffordanceBuilder
             is_allowed_op,
         )?;
         if uri_variables_contains_arrays_objects::<Other>(&self.uri_variables) {
-            return Err(Error::InvalidUriVariables);
+            return Err(AffordanceError::InvalidUriVariables.into());
         }
+        self.uri_variables
+            .keys()
+            .try_for_each(|name| check_name(name, NameKind::UriVariable))?;
 
         Ok(())
     }
@@ -2485,8 +2640,12 @@ impl<Other: ExtendableThing> CheckableInteractionAffordanceBuilder
             is_allowed_op,
         )?;
         if uri_variables_contains_arrays_objects::<Other>(&self.partial.uri_variables) {
-            return Err(Error::InvalidUriVariables);
+            return Err(AffordanceError::InvalidUriVariables.into());
         }
+        self.partial
+            .uri_variables
+            .keys()
+            .try_for_each(|name| check_name(name, NameKind::UriVariable))?;
 
         Ok(())
     }
@@ -2506,10 +2665,11 @@ where
         if let DefaultedFormOperations::Custom(ops) = &form.op {
             let invalid_op = ops.iter().copied().find(|&op| is_allowed_op(op).not());
             if let Some(operation) = invalid_op {
-                return Err(Error::InvalidOpInForm {
+                return Err(AffordanceError::InvalidOpInForm {
                     context: affordance_type.into(),
                     operation,
-                });
+                }
+                .into());
             }
         }
 
@@ -2517,10 +2677,13 @@ where
             .as_ref()
             .map(|securities| {
                 securities.iter().try_for_each(|security| {
+                    check_name(security, NameKind::Security)?;
                     if security_definitions.contains_key(security) {
                         Ok(())
                     } else {
-                        Err(Error::UndefinedSecurity(security.clone()))
+                        Err(Error::from(SecurityError::UndefinedSecurity(
+                            security.clone(),
+                        )))
                     }
                 })
             })
@@ -2530,17 +2693,108 @@ where
     Ok(())
 }
 
+/// Checks that every variable of the `href` template is declared in the `uriVariables`.
+pub(crate) fn check_href_template(
+    href: &str,
+    is_declared_uri_variable: impl Fn(&str) -> bool,
+) -> Result<(), Error> {
+    template_variables(href)?
+        .into_iter()
+        .find(|name| is_declared_uri_variable(name).not())
+        .map_or(Ok(()), |name| {
+            Err(AffordanceError::UndeclaredUriVariable(name).into())
+        })
+}
+
+/// The _unchecked_ variant of an [`InteractionAffordance`].
+///
+/// It is serialized and deserialized like an `InteractionAffordance`.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct UncheckedInteractionAffordance<Other: ExtendableThing> {
+    #[serde(rename = "@type", default)]
+    #[serde_as(as = "Option<StringOrArray>")]
     attype: Option<Vec<String>>,
     title: Option<String>,
     titles: Option<MultiLanguageBuilder<String>>,
     description: Option<String>,
     descriptions: Option<MultiLanguageBuilder<String>>,
-    forms: Vec<Form<Other>>,
+    #[serde(rename = "wot-td:deprecated")]
+    deprecated: Option<bool>,
+    #[serde(rename = "wot-td:deprecationNote")]
+    deprecation_note: Option<String>,
+    forms: Vec<FormFromOther<Other>>,
     uri_variables: Option<UncheckedDataSchemaMap<Other>>,
+    #[serde(flatten)]
     other: Other::InteractionAffordance,
 }
 
+impl<Other: ExtendableThing> From<InteractionAffordance<Other>>
+    for UncheckedInteractionAffordance<Other>
+{
+    fn from(interaction: InteractionAffordance<Other>) -> Self {
+        let InteractionAffordance {
+            attype,
+            title,
+            titles,
+            description,
+            descriptions,
+            deprecated,
+            deprecation_note,
+            forms,
+            uri_variables,
+            other,
+        } = interaction;
+
+        let uri_variables = uri_variables.map(|uri_variables| {
+            uri_variables
+                .into_iter()
+                .map(|(name, data_schema)| (name, data_schema.into()))
+                .collect()
+        });
+
+        Self {
+            attype: format_at_types(attype),
+            title,
+            titles: titles.map(MultiLanguageBuilder::from_built),
+            description,
+            descriptions: descriptions.map(MultiLanguageBuilder::from_built),
+            deprecated,
+            deprecation_note,
+            forms,
+            uri_variables,
+            other,
+        }
+    }
+}
+
+// Do not serialize the fields that are shared with the data schema of a property
+pub(crate) fn omit_unchecked_common<S, Other>(
+    interaction: &UncheckedInteractionAffordance<Other>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    Other: ExtendableThing,
+{
+    use serde::ser::SerializeMap;
+
+    let mut map = serializer.serialize_map(Some(3))?;
+    map.serialize_entry("forms", &interaction.forms)?;
+    if interaction.uri_variables.is_some() {
+        map.serialize_entry("uriVariables", &interaction.uri_variables)?;
+    }
+
+    Serialize::serialize(
+        &&interaction.other,
+        crate::flat_map_serialize::FlatMapSerializer(&mut map),
+    )?;
+
+    map.end()
+}
+
 impl<Other: ExtendableThing, OtherInteractionAffordance>
     TryFrom<InteractionAffordanceBuilder<Other, OtherInteractionAffordance>>
     for InteractionAffordance<Other>
@@ -2570,30 +2824,44 @@ impl<Other: ExtendableThing> TryFrom<UncheckedInteractionAffordance<Other>>
             titles,
             description,
             descriptions,
+            deprecated,
+            deprecation_note,
             forms,
             uri_variables,
             other,
         } = affordance;
 
-        let titles = titles.map(|titles| titles.build()).transpose()?;
+        let titles = titles
+            .map(|titles| titles.build().map_err(|error| error.at("titles")))
+            .transpose()?;
         let descriptions = descriptions
-            .map(|descriptions| descriptions.build())
+            .map(|descriptions| {
+                descriptions
+                    .build()
+                    .map_err(|error| error.at("descriptions"))
+            })
             .transpose()?;
         let uri_variables = uri_variables
             .map(|uri_variables| {
-                uri_variables
-                    .into_iter()
-                    .map(|(key, value)| value.try_into().map(|value| (key, value)))
-                    .collect()
+                let mut map = HashMap::with_capacity(uri_variables.len());
+                for (key, value) in uri_variables {
+                    let value = value
+                        .try_into()
+                        .map_err(|error: Error| error.within(&key).within("uriVariables"))?;
+                    map.insert(key, value);
+                }
+                Ok::<_, Error>(map)
             })
             .transpose()?;
 
         Ok(Self {
-            attype,
+            attype: parse_at_types(attype),
             title,
             titles,
             description,
             descriptions,
+            deprecated,
+            deprecation_note,
             forms,
             uri_variables,
             other,
@@ -2610,6 +2878,7 @@ pub(crate) trait BuildableAffordance {
 impl<Other, DS, AS, OS> BuildableAffordance for UsablePropertyAffordanceBuilder<Other>
 where
     Other: ExtendableThing<DataSchema = DS, ArraySchema = AS, ObjectSchema = OS>,
+    DS: Serialize,
 {
     type Target = PropertyAffordance<Other>;
 
@@ -2637,6 +2906,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other: data_schema_other,
         } = data_schema;
@@ -2647,12 +2917,31 @@ where
             titles,
             description,
             descriptions,
+            deprecated,
+            deprecation_note,
         } = info;
 
-        let titles = titles.map(|titles| titles.build()).transpose()?;
+        let titles = titles
+            .map(|titles| titles.build().map_err(|error| error.at("titles")))
+            .transpose()?;
         let descriptions = descriptions
-            .map(|descriptions| descriptions.build())
+            .map(|descriptions| {
+                descriptions
+                    .build()
+                    .map_err(|error| error.at("descriptions"))
+            })
             .transpose()?;
+        let extension_members = [
+            serialized_members(&other_interaction),
+            serialized_members(&data_schema_other),
+            serialized_members(&other),
+        ]
+        .concat();
+        check_raw_fields(&raw_fields, |key| {
+            INTERACTION_AFFORDANCE_MEMBERS.contains(&key)
+                || PROPERTY_AFFORDANCE_MEMBERS.contains(&key)
+                || extension_members.iter().any(|member| member == key)
+        })?;
         let forms = forms.into_iter().map(Into::into).collect();
         let uri_variables = uri_variables
             .is_empty()
@@ -2660,32 +2949,51 @@ where
             .then(|| {
                 uri_variables
                     .into_iter()
-                    .map(|(key, value)| value.try_into().map(|value| (key, value)))
+                    .map(|(key, value)| {
+                        let value = value
+                            .try_into()
+                            .map_err(|error: Error| error.within(&key).within("uriVariables"))?;
+                        Ok::<_, Error>((key, value))
+                    })
                     .collect()
             })
             .transpose()?;
         let one_of = one_of
-            .map(|one_of| one_of.into_iter().map(TryInto::try_into).collect())
+            .map(|one_of| {
+                one_of
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, data_schema)| {
+                        data_schema
+                            .try_into()
+                            .map_err(|error: Error| error.at(index).at("oneOf"))
+                    })
+                    .collect()
+            })
             .transpose()?;
         let subtype = subtype.map(TryInto::try_into).transpose()?;
 
         let interaction = InteractionAffordance {
-            attype: attype.clone(),
+            attype: parse_at_types(attype.clone()),
             title: title.clone(),
             titles: titles.clone(),
             description: description.clone(),
             descriptions: descriptions.clone(),
+            deprecated,
+            deprecation_note: deprecation_note.clone(),
             forms,
             uri_variables,
             other: other_interaction,
         };
 
         let data_schema = DataSchema {
-            attype,
+            attype: parse_at_types(attype),
             title,
             titles,
             description,
             descriptions,
+            deprecated,
+            deprecation_note,
             constant,
             default,
             unit,
@@ -2694,8 +3002,11 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other: data_schema_other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown: Default::default(),
         };
 
         Ok(PropertyAffordance {
@@ -2728,14 +3039,28 @@ where
 
         let interaction = interaction.try_into()?;
         let subscription = subscription
-            .map(|subscription| subscription.try_into())
+            .map(|subscription| {
+                subscription
+                    .try_into()
+                    .map_err(|error: Error| error.within("subscription"))
+            })
+            .transpose()?;
+        let data = data
+            .map(|data| data.try_into().map_err(|error: Error| error.within("data")))
             .transpose()?;
-        let data = data.map(|data| data.try_into()).transpose()?;
         let cancellation = cancellation
-            .map(|cancellation| cancellation.try_into())
+            .map(|cancellation| {
+                cancellation
+                    .try_into()
+                    .map_err(|error: Error| error.within("cancellation"))
+            })
             .transpose()?;
         let data_response = data_response
-            .map(|data_response| data_response.try_into())
+            .map(|data_response| {
+                data_response
+                    .try_into()
+                    .map_err(|error: Error| error.within("dataResponse"))
+            })
             .transpose()?;
         let other = other.into();
 
@@ -2746,6 +3071,8 @@ where
             cancellation,
             data_response,
             other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown: Default::default(),
         })
     }
 }
@@ -2771,8 +3098,20 @@ where
         } = self;
 
         let interaction = interaction.try_into()?;
-        let input = input.map(|input| input.try_into()).transpose()?;
-        let output = output.map(|output| output.try_into()).transpose()?;
+        let input = input
+            .map(|input| {
+                input
+                    .try_into()
+                    .map_err(|error: Error| error.within("input"))
+            })
+            .transpose()?;
+        let output = output
+            .map(|output| {
+                output
+                    .try_into()
+                    .map_err(|error: Error| error.within("output"))
+            })
+            .transpose()?;
         let other = other.into();
 
         Ok(Self::Target {
@@ -2783,6 +3122,8 @@ where
             idempotent,
             synchronous,
             other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown: Default::default(),
         })
     }
 }
@@ -2795,13 +3136,16 @@ mod test {
     use serde_json::json;
 
     use crate::{
-        builder::data_schema::{
-            BuildableDataSchema, NumberDataSchemaBuilderLike, PartialDataSchemaBuilder,
+        builder::{
+            data_schema::{
+                BuildableDataSchema, NumberDataSchemaBuilderLike, PartialDataSchemaBuilder,
+            },
+            DocumentError,
         },
         hlist::{Cons, Nil},
         thing::{
             ArraySchema, BoxedElemOrVec, DataSchemaFromOther, DataSchemaSubtype,
-            DefaultedFormOperations, FormOperation, Minimum, NumberSchema,
+            DefaultedFormOperations, Form, FormOperation, Minimum, NumberSchema,
         },
     };
 
@@ -2839,7 +3183,9 @@ mod test {
         assert_eq!(
             affordance,
             InteractionAffordance {
-                attype: Some(vec!["attype1".to_string(), "attype2".to_string()]),
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
+                attype: Some(vec!["attype1".into(), "attype2".into()]),
                 title: Some("title".to_string()),
                 titles: Some(
                     [("it", "title_it"), ("en", "title_en"),]
@@ -3152,6 +3498,8 @@ mod test {
                 idempotent: true,
                 synchronous: Some(true),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             },
         );
     }
@@ -3251,6 +3599,8 @@ mod test {
                     ..Default::default()
                 }),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             },
         );
     }
@@ -3390,6 +3740,8 @@ mod test {
         assert_eq!(
             affordance,
             InteractionAffordance {
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 title: Some("title".to_string()),
                 uri_variables: Some(
                     [(
@@ -3404,6 +3756,8 @@ mod test {
                             titles: Default::default(),
                             description: Default::default(),
                             descriptions: Default::default(),
+                            deprecated: Default::default(),
+                            deprecation_note: Default::default(),
                             constant: Default::default(),
                             default: Default::default(),
                             unit: Default::default(),
@@ -3412,6 +3766,9 @@ mod test {
                             read_only: Default::default(),
                             write_only: Default::default(),
                             format: Default::default(),
+                            raw_fields: Default::default(),
+                            #[cfg(feature = "preserve-unknown")]
+                            unknown: Default::default(),
                         }
                     )]
                     .into_iter()
@@ -3430,6 +3787,8 @@ mod test {
                     scopes: Default::default(),
                     response: Default::default(),
                     additional_responses: Default::default(),
+                    #[cfg(feature = "preserve-unknown")]
+                    unknown: Default::default(),
                 }],
                 other: Nil::cons(InteractionAffordanceExtA { a: A(1) }).cons(
                     InteractionAffordanceExtB {
@@ -3479,6 +3838,8 @@ mod test {
             affordance,
             PropertyAffordance {
                 interaction: InteractionAffordance {
+                    deprecated: Default::default(),
+                    deprecation_note: Default::default(),
                     other: Nil::cons(InteractionAffordanceExtA { a: A(2) }).cons(
                         InteractionAffordanceExtB {
                             g: B("b".to_string())
@@ -3497,6 +3858,8 @@ mod test {
                                 titles: Default::default(),
                                 description: Default::default(),
                                 descriptions: Default::default(),
+                                deprecated: Default::default(),
+                                deprecation_note: Default::default(),
                                 constant: Default::default(),
                                 default: Default::default(),
                                 unit: Default::default(),
@@ -3505,6 +3868,9 @@ mod test {
                                 read_only: Default::default(),
                                 write_only: Default::default(),
                                 format: Default::default(),
+                                raw_fields: Default::default(),
+                                #[cfg(feature = "preserve-unknown")]
+                                unknown: Default::default(),
                             }
                         )]
                         .into_iter()
@@ -3527,6 +3893,8 @@ mod test {
                     titles: Default::default(),
                     description: Default::default(),
                     descriptions: Default::default(),
+                    deprecated: Default::default(),
+                    deprecation_note: Default::default(),
                     constant: Default::default(),
                     default: Default::default(),
                     unit: Default::default(),
@@ -3535,6 +3903,9 @@ mod test {
                     read_only: Default::default(),
                     write_only: Default::default(),
                     format: Default::default(),
+                    raw_fields: Default::default(),
+                    #[cfg(feature = "preserve-unknown")]
+                    unknown: Default::default(),
                 },
                 other: Nil::cons(PropertyAffordanceExtA { b: A(1) }).cons(PropertyAffordanceExtB {
                     h: B("c".to_string())
@@ -3581,6 +3952,8 @@ mod test {
             affordance,
             EventAffordance {
                 interaction: InteractionAffordance {
+                    deprecated: Default::default(),
+                    deprecation_note: Default::default(),
                     title: Some("title".to_string()),
                     uri_variables: Some(
                         [(
@@ -3595,6 +3968,8 @@ mod test {
                                 titles: Default::default(),
                                 description: Default::default(),
                                 descriptions: Default::default(),
+                                deprecated: Default::default(),
+                                deprecation_note: Default::default(),
                                 constant: Default::default(),
                                 default: Default::default(),
                                 unit: Default::default(),
@@ -3603,6 +3978,9 @@ mod test {
                                 read_only: Default::default(),
                                 write_only: Default::default(),
                                 format: Default::default(),
+                                raw_fields: Default::default(),
+                                #[cfg(feature = "preserve-unknown")]
+                                unknown: Default::default(),
                             }
                         )]
                         .into_iter()
@@ -3629,6 +4007,8 @@ mod test {
                     titles: Default::default(),
                     description: Default::default(),
                     descriptions: Default::default(),
+                    deprecated: Default::default(),
+                    deprecation_note: Default::default(),
                     constant: Default::default(),
                     default: Default::default(),
                     unit: Default::default(),
@@ -3637,6 +4017,9 @@ mod test {
                     read_only: Default::default(),
                     write_only: Default::default(),
                     format: Default::default(),
+                    raw_fields: Default::default(),
+                    #[cfg(feature = "preserve-unknown")]
+                    unknown: Default::default(),
                 }),
                 other: Nil::cons(EventAffordanceExtA { c: A(3) }).cons(EventAffordanceExtB {
                     j: B("c".to_string())
@@ -3644,6 +4027,8 @@ mod test {
                 data: Default::default(),
                 data_response: Default::default(),
                 cancellation: Default::default(),
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             },
         );
     }
@@ -3685,6 +4070,8 @@ mod test {
             affordance,
             ActionAffordance {
                 interaction: InteractionAffordance {
+                    deprecated: Default::default(),
+                    deprecation_note: Default::default(),
                     title: Some("title".to_string()),
                     uri_variables: Some(
                         [(
@@ -3699,6 +4086,8 @@ mod test {
                                 titles: Default::default(),
                                 description: Default::default(),
                                 descriptions: Default::default(),
+                                deprecated: Default::default(),
+                                deprecation_note: Default::default(),
                                 constant: Default::default(),
                                 default: Default::default(),
                                 unit: Default::default(),
@@ -3707,6 +4096,9 @@ mod test {
                                 read_only: Default::default(),
                                 write_only: Default::default(),
                                 format: Default::default(),
+                                raw_fields: Default::default(),
+                                #[cfg(feature = "preserve-unknown")]
+                                unknown: Default::default(),
                             }
                         )]
                         .into_iter()
@@ -3733,6 +4125,8 @@ mod test {
                     titles: Default::default(),
                     description: Default::default(),
                     descriptions: Default::default(),
+                    deprecated: Default::default(),
+                    deprecation_note: Default::default(),
                     constant: Default::default(),
                     default: Default::default(),
                     unit: Default::default(),
@@ -3741,6 +4135,9 @@ mod test {
                     read_only: Default::default(),
                     write_only: Default::default(),
                     format: Default::default(),
+                    raw_fields: Default::default(),
+                    #[cfg(feature = "preserve-unknown")]
+                    unknown: Default::default(),
                 }),
                 other: Nil::cons(ActionAffordanceExtA { b: A(3) }).cons(ActionAffordanceExtB {
                     i: B("c".to_string())
@@ -3749,6 +4146,8 @@ mod test {
                 safe: Default::default(),
                 idempotent: Default::default(),
                 synchronous: Default::default(),
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             },
         );
     }
@@ -3767,7 +4166,12 @@ mod test {
 
         assert_eq!(
             builder.build().unwrap_err(),
-            Error::InvalidLanguageTag("i1t".to_string()),
+            Error::AtPath {
+                path: "/titles".to_string(),
+                source: Box::new(Error::Document(DocumentError::InvalidLanguageTag(
+                    "i1t".to_string()
+                ))),
+            },
         );
     }
 
@@ -3779,7 +4183,12 @@ mod test {
 
         assert_eq!(
             builder.build().unwrap_err(),
-            Error::InvalidLanguageTag("i1t".to_string()),
+            Error::AtPath {
+                path: "/titles".to_string(),
+                source: Box::new(Error::Document(DocumentError::InvalidLanguageTag(
+                    "i1t".to_string()
+                ))),
+            },
         );
     }
 
@@ -3791,7 +4200,12 @@ mod test {
 
         assert_eq!(
             builder.build().unwrap_err(),
-            Error::InvalidLanguageTag("i1t".to_string()),
+            Error::AtPath {
+                path: "/titles".to_string(),
+                source: Box::new(Error::Document(DocumentError::InvalidLanguageTag(
+                    "i1t".to_string()
+                ))),
+            },
         );
     }
 
@@ -9,7 +9,8 @@
 //! below) requires the modification of specific elements of the builder, which could cause data
 //! loss in case of further extension or non-zero cost approaches to avoid the problem. In this
 //! way, it is not possible to _specialize_ the `DataSchemaBuilder` before all the extensions have
-//! been performed.
+//! been performed. Extensions of type `()` do not need to be explicitly added, because
+//! `finish_extend` automatically fills them.
 //!
 //! The _specialization_ process involves the following traits:
 //!
@@ -35,52 +36,104 @@
 //! Any `DataSchema` builder also implements [`BuildableHumanReadableInfo`] and
 //! [`BuildableDataSchema`], in order to customize _common_ fields.
 //!
+//! # Call order for unions
+//!
+//! A union data schema can be obtained calling [`one_of`] on an extended builder. The following
+//! calls can be freely interleaved with the ones used to add more variants:
+//!
+//! - [`read_only`](ReadableWriteableDataSchema::read_only) or
+//!   [`write_only`](ReadableWriteableDataSchema::write_only), at most once and only one of them;
+//! - the functions of [`BuildableHumanReadableInfo`], like `title` or `description`;
+//! - the functions of [`BuildableDataSchema`], like `unit` or `default_value`.
+//!
+//! For instance, `one_of(..).read_only().title(..).one_of(..)` and
+//! `read_only().one_of(..).one_of(..)` produce the same data schema. Mixing `one_of` with
+//! [`enumeration`] or with the specialization functions is not allowed.
+//!
 //! [`builder`]: crate::builder
 //! [`finish_extend`]: DataSchemaBuilder::finish_extend
+//! [`one_of`]: UnionDataSchema::one_of
+//! [`enumeration`]: EnumerableDataSchema::enumeration
 //! [`read_only`]: crate::thing::DataSchema::read_only
 //! [`write_only`]: crate::thing::DataSchema::write_only
-use alloc::{boxed::Box, string::String, vec::Vec};
-use core::{cmp::Ordering, marker::PhantomData, num::NonZeroU64, ops::Not};
+use alloc::{borrow::ToOwned, boxed::Box, format, string::String, vec::Vec};
+use core::{cmp::Ordering, fmt, marker::PhantomData, num::NonZeroU64, ops::Not};
 
 use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none};
 
 use crate::{
-    extend::{Extend, Extendable, ExtendableThing},
+    extend::{AutoExtend, Extend, Extendable, ExtendableThing, Here},
     thing::{
-        ArraySchema, BoxedElemOrVec, DataSchema, DataSchemaSubtype, IntegerSchema, Maximum,
-        Minimum, NumberSchema, ObjectSchema, StringSchema, UncheckedArraySchema,
-        UncheckedDataSchemaSubtype, UncheckedObjectSchema,
+        format_at_types, parse_at_types, ArraySchema, BoxedElemOrVec, DataSchema,
+        DataSchemaSubtype, HasType, IntegerSchema, Maximum, Minimum, NumberSchema, ObjectSchema,
+        StringOrArray, StringSchema, UncheckedArraySchema, UncheckedDataSchemaSubtype,
+        UncheckedObjectSchema, DATA_SCHEMA_MEMBERS,
     },
 };
 
 use super::{
+    check_name,
     human_readable_info::{
         impl_delegate_buildable_hr_info, BuildableHumanReadableInfo, HumanReadableInfo,
     },
-    Error, Extended, MultiLanguageBuilder, ToExtend,
+    DataSchemaError, Error, Extended, MultiLanguageBuilder, NameKind, ToExtend,
 };
 
 /// The _unchecked_ variant of a [`DataSchema`](crate::thing::DataSchema).
 ///
 /// This can be transformed into a valid `DataSchema` by
 /// [`ThingBuilder::build`](crate::builder::ThingBuilder::build).
-#[derive(Clone, Debug, Default, PartialEq)]
+///
+/// It is serialized and deserialized like a `DataSchema`, but the deserialization does not check
+/// anything that can be modified afterwards, like the language tags of the translations.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "preserve-unknown",
+    serde(bound(deserialize = "DS: Serialize + serde::de::DeserializeOwned, \
+                               AS: Serialize + serde::de::DeserializeOwned, \
+                               OS: Serialize + serde::de::DeserializeOwned"))
+)]
 pub struct UncheckedDataSchema<DS, AS, OS> {
+    #[serde(rename = "@type", default)]
+    #[serde_as(as = "Option<StringOrArray>")]
     attype: Option<Vec<String>>,
     title: Option<String>,
     titles: Option<MultiLanguageBuilder<String>>,
     description: Option<String>,
     descriptions: Option<MultiLanguageBuilder<String>>,
+    #[serde(rename = "wot-td:deprecated")]
+    deprecated: Option<bool>,
+    #[serde(rename = "wot-td:deprecationNote")]
+    deprecation_note: Option<String>,
+    #[serde(rename = "const")]
     constant: Option<Value>,
     default: Option<Value>,
     unit: Option<String>,
     one_of: Option<Vec<Self>>,
+    #[serde(rename = "enum")]
     enumeration: Option<Vec<Value>>,
+    #[serde(default)]
     read_only: bool,
+    #[serde(default)]
     write_only: bool,
     format: Option<String>,
+    #[serde(flatten)]
     subtype: Option<UncheckedDataSchemaSubtype<DS, AS, OS>>,
+    #[serde(flatten)]
     other: DS,
+    #[serde(flatten, skip_deserializing)]
+    raw_fields: Map<String, Value>,
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::unknown::deserialize::<_, DataSchema<DS, AS, OS>, (DS, AS, OS)>"
+    )]
+    unknown: Map<String, Value>,
 }
 
 pub(crate) type UncheckedDataSchemaFromOther<Other> = UncheckedDataSchema<
@@ -109,6 +162,7 @@ pub(crate) type UncheckedDataSchemaMap<Other> = HashMap<
 /// [`PropertyAffordance`]: crate::thing::PropertyAffordance
 /// [`InteractionAffordance`]: crate::thing::InteractionAffordance
 #[derive(Debug, PartialEq)]
+#[must_use]
 pub struct PartialDataSchemaBuilder<DS, AS, OS, Status> {
     constant: Option<Value>,
     default: Option<Value>,
@@ -118,9 +172,15 @@ pub struct PartialDataSchemaBuilder<DS, AS, OS, Status> {
     read_only: bool,
     write_only: bool,
     format: Option<String>,
+    raw_fields: Map<String, Value>,
 
     /// Data schema extension.
     pub other: DS,
+
+    /// The extension status, either [`ToExtend`] or [`Extended`].
+    ///
+    /// `PhantomData<Status>` is covariant over the zero-sized status, the builder does not own
+    /// any value of that type.
     _marker: PhantomData<Status>,
 }
 
@@ -138,6 +198,7 @@ impl<DS, AS, OS> PartialDataSchemaBuilder<DS, AS, OS, ToExtend> {
             read_only: Default::default(),
             write_only: Default::default(),
             format: Default::default(),
+            raw_fields: Default::default(),
             other: DS::empty(),
             _marker: PhantomData,
         }
@@ -160,6 +221,7 @@ impl<DS, AS, OS> PartialDataSchemaBuilder<DS, AS, OS, ToExtend> {
             read_only,
             write_only,
             format,
+            raw_fields,
             other,
             _marker,
         } = self;
@@ -173,6 +235,7 @@ impl<DS, AS, OS> PartialDataSchemaBuilder<DS, AS, OS, ToExtend> {
             read_only,
             write_only,
             format,
+            raw_fields,
             other,
             _marker,
         }
@@ -188,7 +251,12 @@ impl<DS, AS, OS> PartialDataSchemaBuilder<DS, AS, OS, ToExtend> {
     }
 
     /// Makes the builder unextendable and allows further customizations.
-    pub fn finish_extend(self) -> PartialDataSchemaBuilder<DS, AS, OS, Extended> {
+    ///
+    /// Missing `()` extensions are automatically filled, see [`AutoExtend`] for more information.
+    pub fn finish_extend<Target, Index>(self) -> PartialDataSchemaBuilder<Target, AS, OS, Extended>
+    where
+        DS: AutoExtend<Target, Index>,
+    {
         let Self {
             constant,
             default,
@@ -198,22 +266,38 @@ impl<DS, AS, OS> PartialDataSchemaBuilder<DS, AS, OS, ToExtend> {
             read_only,
             write_only,
             format,
+            raw_fields,
             other,
             _marker: _,
         } = self;
+        // `one_of` can only be populated through `UnionDataSchema`, which is implemented for the
+        // extended builders only, therefore nothing is lost replacing it.
+        debug_assert!(one_of.is_empty());
+        let other = other.auto_extend();
         PartialDataSchemaBuilder {
             constant,
             default,
             unit,
-            one_of,
+            one_of: Default::default(),
             enumeration,
             read_only,
             write_only,
             format,
+            raw_fields,
             other,
             _marker: PhantomData,
         }
     }
+
+    /// Makes the builder unextendable, keeping the current extension type.
+    ///
+    /// Unlike [`finish_extend`](Self::finish_extend), the missing `()` extensions are not filled,
+    /// therefore the resulting type does not need to be known. This is meant for generic code
+    /// that receives a builder whose extension is already complete.
+    #[inline]
+    pub fn finish_extend_as_is(self) -> PartialDataSchemaBuilder<DS, AS, OS, Extended> {
+        self.finish_extend::<DS, Here>()
+    }
 }
 
 impl<DS, AS, OS> Default for PartialDataSchemaBuilder<DS, AS, OS, Extended>
@@ -230,6 +314,7 @@ where
             read_only: Default::default(),
             write_only: Default::default(),
             format: Default::default(),
+            raw_fields: Default::default(),
             other: Default::default(),
             _marker: Default::default(),
         }
@@ -240,7 +325,7 @@ where
 ///
 /// This variant does not include the _human readable_ fields. It is always converted into the
 /// complete `DataSchema` structure during the _building_ process.
-#[derive(Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct PartialDataSchema<DS, AS, OS> {
     pub(super) constant: Option<Value>,
     pub(super) default: Option<Value>,
@@ -251,6 +336,7 @@ pub struct PartialDataSchema<DS, AS, OS> {
     pub(super) write_only: bool,
     pub(super) format: Option<String>,
     pub(super) subtype: Option<UncheckedDataSchemaSubtype<DS, AS, OS>>,
+    pub(super) raw_fields: Map<String, Value>,
 
     /// Data schema extension.
     pub other: DS,
@@ -340,7 +426,6 @@ pub struct PartialDataSchema<DS, AS, OS> {
 ///         b.ext(DataExtension {
 ///             data_schema_field: 42,
 ///         })
-///         .ext(())
 ///         .finish_extend()
 ///         .number()
 ///     })
@@ -366,6 +451,7 @@ pub struct PartialDataSchema<DS, AS, OS> {
 /// );
 /// ```
 #[derive(Debug, PartialEq)]
+#[must_use]
 pub struct DataSchemaBuilder<DS, AS, OS, Status> {
     partial: PartialDataSchemaBuilder<DS, AS, OS, Status>,
     info: HumanReadableInfo,
@@ -393,11 +479,143 @@ impl<DS, AS, OS> DataSchemaBuilder<DS, AS, OS, ToExtend> {
     }
 
     /// Makes the builder unextendable and allows further customizations.
-    pub fn finish_extend(self) -> DataSchemaBuilder<DS, AS, OS, Extended> {
+    ///
+    /// The extensions that have not been explicitly added are filled with `()`, as long as the
+    /// corresponding extension types are `()` as well. For instance, the following works:
+    ///
+    /// ```
+    /// # use serde::{Deserialize, Serialize};
+    /// # use serde_json::json;
+    /// # use wot_td::{
+    /// #     builder::data_schema::SpecializableDataSchema, extend::ExtendableThing, thing::Thing,
+    /// # };
+    /// #
+    /// #[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+    /// struct ThingExtension {}
+    ///
+    /// impl ExtendableThing for ThingExtension {
+    ///     /* Types set to `()` */
+    /// #   type InteractionAffordance = ();
+    /// #   type PropertyAffordance = ();
+    /// #   type ActionAffordance = ();
+    /// #   type EventAffordance = ();
+    /// #   type Form = ();
+    /// #   type ExpectedResponse = ();
+    /// #   type DataSchema = ();
+    /// #   type ObjectSchema = ();
+    /// #   type ArraySchema = ();
+    /// }
+    ///
+    /// let thing = Thing::builder("Thing name")
+    ///     .ext(ThingExtension {})
+    ///     .ext(ThingExtension {})
+    ///     .finish_extend()
+    ///     .schema_definition("test", |b| b.finish_extend().number())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(thing).unwrap(),
+    ///     json!({
+    ///         "@context": "https://www.w3.org/2022/wot/td/v1.1",
+    ///         "title": "Thing name",
+    ///         "schemaDefinitions": {
+    ///             "test": {
+    ///                 "type": "number",
+    ///                 "readOnly": false,
+    ///                 "writeOnly": false,
+    ///             }
+    ///         },
+    ///         "security": [],
+    ///         "securityDefinitions": {},
+    ///     })
+    /// );
+    /// ```
+    ///
+    /// Extensions that are not `()` must be always added explicitly, therefore the following fails
+    /// to compile:
+    ///
+    /// ```compile_fail
+    /// # use serde::{Deserialize, Serialize};
+    /// # use wot_td::{
+    /// #     builder::data_schema::SpecializableDataSchema, extend::ExtendableThing, thing::Thing,
+    /// # };
+    /// #
+    /// #[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+    /// struct ThingExtension {}
+    ///
+    /// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    /// struct DataExtension {
+    ///     data_schema_field: u32,
+    /// }
+    ///
+    /// impl ExtendableThing for ThingExtension {
+    ///     type DataSchema = DataExtension;
+    ///     /* Other types set to `()` */
+    /// #   type InteractionAffordance = ();
+    /// #   type PropertyAffordance = ();
+    /// #   type ActionAffordance = ();
+    /// #   type EventAffordance = ();
+    /// #   type Form = ();
+    /// #   type ExpectedResponse = ();
+    /// #   type ObjectSchema = ();
+    /// #   type ArraySchema = ();
+    /// }
+    ///
+    /// let thing = Thing::builder("Thing name")
+    ///     .ext(ThingExtension {})
+    ///     .finish_extend()
+    ///     .schema_definition("test", |b| b.finish_extend().number())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn finish_extend<Target, Index>(self) -> DataSchemaBuilder<Target, AS, OS, Extended>
+    where
+        DS: AutoExtend<Target, Index>,
+    {
         let Self { partial, info } = self;
         let partial = partial.finish_extend();
         DataSchemaBuilder { partial, info }
     }
+
+    /// Makes the builder unextendable, keeping the current extension type.
+    ///
+    /// Unlike [`finish_extend`](Self::finish_extend), the missing `()` extensions are not filled,
+    /// therefore the resulting type does not need to be known. This allows writing reusable
+    /// schema fragments that are generic over the extensions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     builder::{
+    /// #         data_schema::{
+    /// #             DataSchemaBuilder, NumberDataSchemaBuilder, NumberDataSchemaBuilderLike,
+    /// #             SpecializableDataSchema,
+    /// #         },
+    /// #         Extended, ToExtend,
+    /// #     },
+    /// #     thing::Thing,
+    /// # };
+    /// #
+    /// fn percentage<DS, AS, OS>(
+    ///     builder: DataSchemaBuilder<DS, AS, OS, ToExtend>,
+    /// ) -> NumberDataSchemaBuilder<DataSchemaBuilder<DS, AS, OS, Extended>> {
+    ///     builder.finish_extend_as_is().number().minimum(0.).maximum(100.)
+    /// }
+    ///
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .schema_definition("percentage", percentage)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[inline]
+    pub fn finish_extend_as_is(self) -> DataSchemaBuilder<DS, AS, OS, Extended> {
+        let Self { partial, info } = self;
+        let partial = partial.finish_extend_as_is();
+        DataSchemaBuilder { partial, info }
+    }
 }
 
 impl<DS, AS, OS> DataSchemaBuilder<DS, AS, OS, ToExtend> {
@@ -441,6 +659,191 @@ pub trait BuildableDataSchema<DS, AS, OS, Status>: Sized {
 
     /// Sets the value of the `default` field.
     fn default_value(self, value: impl Into<Value>) -> Self;
+
+    /// Adds an arbitrary member to the data schema.
+    ///
+    /// This is meant for one-off keywords that are not modeled by [`DataSchema`], like vendor
+    /// specific ones, without the need of a data schema extension. The member is serialized after
+    /// the extension fields. Setting the same `key` twice keeps the last value.
+    ///
+    /// The `key` must not be one of the members of the data schema, otherwise
+    /// [`DataSchemaError::ReservedKeyOverride`] is returned when the Thing is built. The data schema
+    /// of a property affordance is serialized in the same object of the affordance, therefore its
+    /// `key` must not be one of the members of the affordance or of its extensions either.
+    ///
+    /// The raw fields are only serialized: once the Thing is parsed back, they are either dropped
+    /// or, with the `preserve-unknown` feature, collected among the unknown members.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::{
+    /// #     builder::data_schema::{BuildableDataSchema, SpecializableDataSchema},
+    /// #     thing::Thing,
+    /// # };
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .schema_definition("level", |b| {
+    ///         b.finish_extend()
+    ///             .raw_field("x-vendor", json!({ "widget": "slider" }))
+    ///             .integer()
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(thing).unwrap()["schemaDefinitions"]["level"],
+    ///     json!({
+    ///         "type": "integer",
+    ///         "readOnly": false,
+    ///         "writeOnly": false,
+    ///         "x-vendor": { "widget": "slider" },
+    ///     }),
+    /// );
+    /// ```
+    ///
+    /// [`DataSchema`]: crate::thing::DataSchema
+    fn raw_field(self, key: impl Into<String>, value: impl Into<Value>) -> Self;
+
+    /// Returns a [`Debug`](fmt::Debug) representation of the builder that elides the content
+    /// nested deeper than `max_depth`.
+    ///
+    /// See [`ShallowDebug`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::builder::data_schema::{
+    /// #     BuildableDataSchema, DataSchemaBuilder, ObjectDataSchemaBuilderLike,
+    /// #     SpecializableDataSchema,
+    /// # };
+    /// # use wot_td::hlist::Nil;
+    /// #
+    /// let builder = DataSchemaBuilder::<Nil, Nil, Nil, _>::default()
+    ///     .object()
+    ///     .property("inner", false, |b| b.finish_extend().object());
+    ///
+    /// assert_eq!(
+    ///     format!("{:?}", builder.debug_shallow(1)),
+    ///     "ObjectDataSchemaBuilder { inner: DataSchemaBuilder {…}, properties: […], required: [], \
+    ///      other: Nil }",
+    /// );
+    /// ```
+    #[inline]
+    fn debug_shallow(&self, max_depth: usize) -> ShallowDebug<'_, Self>
+    where
+        Self: fmt::Debug,
+    {
+        ShallowDebug::new(self, max_depth)
+    }
+}
+
+/// A [`Debug`](fmt::Debug) adapter that elides the content nested beyond a maximum depth.
+///
+/// Data schemas with many levels of `one_of`, object properties or array items produce a huge
+/// debug output, which makes panic messages and logs hard to read. This adapter formats the
+/// wrapped value normally, but every struct, tuple, list or map nested deeper than `max_depth` is
+/// printed as `…`. A `max_depth` equal to `0` only shows the outermost type name.
+///
+/// The alternate flag (`{:#?}`) is honored.
+pub struct ShallowDebug<'a, T: ?Sized> {
+    value: &'a T,
+    max_depth: usize,
+}
+
+impl<'a, T: ?Sized> ShallowDebug<'a, T> {
+    /// Creates a new adapter for the value.
+    #[inline]
+    pub fn new(value: &'a T, max_depth: usize) -> Self {
+        Self { value, max_depth }
+    }
+}
+
+impl<T> fmt::Debug for ShallowDebug<'_, T>
+where
+    T: fmt::Debug + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        let alternate = f.alternate();
+        let mut writer = ElidingWriter {
+            inner: f,
+            max_depth: self.max_depth,
+            depth: 0,
+            elided: false,
+            quote: None,
+            escaped: false,
+        };
+
+        if alternate {
+            write!(writer, "{:#?}", self.value)
+        } else {
+            write!(writer, "{:?}", self.value)
+        }
+    }
+}
+
+/// A writer that tracks the nesting of a `Debug` output and skips the content that is too deep.
+struct ElidingWriter<'a, 'b> {
+    inner: &'a mut fmt::Formatter<'b>,
+    max_depth: usize,
+    depth: usize,
+    elided: bool,
+    quote: Option<char>,
+    escaped: bool,
+}
+
+impl ElidingWriter<'_, '_> {
+    fn write_visible(&mut self, c: char) -> fmt::Result {
+        if self.depth <= self.max_depth {
+            fmt::Write::write_char(self.inner, c)
+        } else if !self.elided && !c.is_whitespace() {
+            self.elided = true;
+            fmt::Write::write_char(self.inner, '…')
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl fmt::Write for ElidingWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| {
+            if let Some(quote) = self.quote {
+                if self.escaped {
+                    self.escaped = false;
+                } else if c == '\\' {
+                    self.escaped = true;
+                } else if c == quote {
+                    self.quote = None;
+                }
+                return self.write_visible(c);
+            }
+
+            match c {
+                '"' | '\'' => {
+                    self.quote = Some(c);
+                    self.write_visible(c)
+                }
+                '{' | '[' | '(' => {
+                    let result = self.write_visible(c);
+                    if self.depth == self.max_depth {
+                        self.elided = false;
+                    }
+                    self.depth += 1;
+                    result
+                }
+                '}' | ']' | ')' => {
+                    self.depth = self.depth.saturating_sub(1);
+                    self.write_visible(c)
+                }
+                _ => self.write_visible(c),
+            }
+        })
+    }
 }
 
 /// An interface for a _specializable_ version of a [`DataSchema`](crate::thing::DataSchema).
@@ -519,7 +922,7 @@ pub trait SpecializableDataSchema<DS, AS, OS>: BuildableDataSchema<DS, AS, OS, E
     /// let thing = Thing::builder("Thing name")
     ///     .ext(ThingExtension {})
     ///     .finish_extend()
-    ///     .schema_definition("test", |b| b.ext(()).finish_extend().tuple())
+    ///     .schema_definition("test", |b| b.finish_extend().tuple())
     ///     .build()
     ///     .unwrap();
     ///
@@ -579,7 +982,7 @@ pub trait SpecializableDataSchema<DS, AS, OS>: BuildableDataSchema<DS, AS, OS, E
     /// let thing = Thing::builder("Thing name")
     ///     .ext(ThingExtension {})
     ///     .finish_extend()
-    ///     .schema_definition("test", |b| b.ext(()).finish_extend().tuple())
+    ///     .schema_definition("test", |b| b.finish_extend().tuple())
     ///     .build()
     ///     .unwrap();
     /// ```
@@ -623,7 +1026,7 @@ pub trait SpecializableDataSchema<DS, AS, OS>: BuildableDataSchema<DS, AS, OS, E
     ///     .ext(ThingExtension {})
     ///     .finish_extend()
     ///     .schema_definition("test", |b| {
-    ///         b.ext(()).finish_extend().tuple_ext(|b| {
+    ///         b.finish_extend().tuple_ext(|b| {
     ///             b.ext(ArraySchemaExtension {
     ///                 array_field: NotDefaultableU32(42),
     ///             })
@@ -695,7 +1098,7 @@ pub trait SpecializableDataSchema<DS, AS, OS>: BuildableDataSchema<DS, AS, OS, E
     /// let thing = Thing::builder("Thing name")
     ///     .ext(ThingExtension {})
     ///     .finish_extend()
-    ///     .schema_definition("test", |b| b.ext(()).finish_extend().vec())
+    ///     .schema_definition("test", |b| b.finish_extend().vec())
     ///     .build()
     ///     .unwrap();
     ///
@@ -754,7 +1157,7 @@ pub trait SpecializableDataSchema<DS, AS, OS>: BuildableDataSchema<DS, AS, OS, E
     /// let thing = Thing::builder("Thing name")
     ///     .ext(ThingExtension {})
     ///     .finish_extend()
-    ///     .schema_definition("test", |b| b.ext(()).finish_extend().vec())
+    ///     .schema_definition("test", |b| b.finish_extend().vec())
     ///     .build()
     ///     .unwrap();
     /// ```
@@ -798,7 +1201,7 @@ pub trait SpecializableDataSchema<DS, AS, OS>: BuildableDataSchema<DS, AS, OS, E
     ///     .ext(ThingExtension {})
     ///     .finish_extend()
     ///     .schema_definition("test", |b| {
-    ///         b.ext(()).finish_extend().vec_ext(|b| {
+    ///         b.finish_extend().vec_ext(|b| {
     ///             b.ext(ArraySchemaExtension {
     ///                 array_field: NotDefaultableU32(42),
     ///             })
@@ -871,7 +1274,7 @@ pub trait SpecializableDataSchema<DS, AS, OS>: BuildableDataSchema<DS, AS, OS, E
     ///     .ext(ThingExtension {})
     ///     .finish_extend()
     ///     .schema_definition("test", |b| {
-    ///         b.ext(()).finish_extend().tuple_ext(|b| {
+    ///         b.finish_extend().tuple_ext(|b| {
     ///             b.ext(ArraySchemaExtension {
     ///                 array_field: NotDefaultableU32(42),
     ///             })
@@ -946,7 +1349,7 @@ pub trait SpecializableDataSchema<DS, AS, OS>: BuildableDataSchema<DS, AS, OS, E
     ///     .ext(ThingExtension {})
     ///     .finish_extend()
     ///     .schema_definition("test", |b| {
-    ///         b.ext(()).finish_extend().vec_ext(|b| {
+    ///         b.finish_extend().vec_ext(|b| {
     ///             b.ext(ArraySchemaExtension {
     ///                 array_field: NotDefaultableU32(42),
     ///             })
@@ -1027,7 +1430,7 @@ pub trait SpecializableDataSchema<DS, AS, OS>: BuildableDataSchema<DS, AS, OS, E
     /// let thing = Thing::builder("Thing name")
     ///     .ext(ThingExtension {})
     ///     .finish_extend()
-    ///     .schema_definition("test", |b| b.ext(()).finish_extend().object())
+    ///     .schema_definition("test", |b| b.finish_extend().object())
     ///     .build()
     ///     .unwrap();
     ///
@@ -1086,7 +1489,7 @@ pub trait SpecializableDataSchema<DS, AS, OS>: BuildableDataSchema<DS, AS, OS, E
     /// let thing = Thing::builder("Thing name")
     ///     .ext(ThingExtension {})
     ///     .finish_extend()
-    ///     .schema_definition("test", |b| b.ext(()).finish_extend().object())
+    ///     .schema_definition("test", |b| b.finish_extend().object())
     ///     .build()
     ///     .unwrap();
     /// ```
@@ -1130,7 +1533,7 @@ pub trait SpecializableDataSchema<DS, AS, OS>: BuildableDataSchema<DS, AS, OS, E
     ///     .ext(ThingExtension {})
     ///     .finish_extend()
     ///     .schema_definition("test", |b| {
-    ///         b.ext(()).finish_extend().object_ext(|b| {
+    ///         b.finish_extend().object_ext(|b| {
     ///             b.ext(ObjectSchemaExtension {
     ///                 object_field: NotDefaultableU32(42),
     ///             })
@@ -1203,7 +1606,7 @@ pub trait SpecializableDataSchema<DS, AS, OS>: BuildableDataSchema<DS, AS, OS, E
     ///     .ext(ThingExtension {})
     ///     .finish_extend()
     ///     .schema_definition("test", |b| {
-    ///         b.ext(()).finish_extend().object_ext(|b| {
+    ///         b.finish_extend().object_ext(|b| {
     ///             b.ext(ObjectSchemaExtension {
     ///                 object_field: NotDefaultableU32(42),
     ///             })
@@ -1243,6 +1646,52 @@ pub trait SpecializableDataSchema<DS, AS, OS>: BuildableDataSchema<DS, AS, OS, E
 
     /// Specialize the builder into a _constant_ data schema builder.
     fn constant(self, value: impl Into<Value>) -> Self::Constant;
+
+    /// Specialize the builder into a _constant_ data schema builder of type _boolean_.
+    ///
+    /// Unlike [`constant`](Self::constant), the `type` of the data schema is set as well, as
+    /// expected by most JSON Schema validators.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::{builder::data_schema::SpecializableDataSchema, thing::Thing};
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .schema_definition("test", |b| b.finish_extend().constant_bool(true))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(thing).unwrap()["schemaDefinitions"]["test"],
+    ///     json!({
+    ///         "type": "boolean",
+    ///         "const": true,
+    ///         "readOnly": true,
+    ///         "writeOnly": false,
+    ///     }),
+    /// );
+    /// ```
+    fn constant_bool(self, value: bool) -> Self::Constant;
+
+    /// Specialize the builder into a _constant_ data schema builder of type _integer_.
+    ///
+    /// See [`constant_bool`](Self::constant_bool) for more details.
+    fn constant_int(self, value: i64) -> Self::Constant;
+
+    /// Specialize the builder into a _constant_ data schema builder of type _number_.
+    ///
+    /// See [`constant_bool`](Self::constant_bool) for more details. The value must be finite,
+    /// because JSON cannot represent NaN and the infinities, otherwise the check of the data
+    /// schema fails with [`DataSchemaError::ConstTypeMismatch`].
+    fn constant_number(self, value: f64) -> Self::Constant;
+
+    /// Specialize the builder into a _constant_ data schema builder of type _string_.
+    ///
+    /// See [`constant_bool`](Self::constant_bool) for more details.
+    fn constant_string(self, value: impl Into<String>) -> Self::Constant;
 }
 
 /// An interface to specialize an _enumerable_ version of a
@@ -1300,6 +1749,36 @@ pub trait EnumerableDataSchema<DS, AS, OS, Extended>:
     /// );
     /// ```
     fn enumeration(self, value: impl Into<Value>) -> Self::Target;
+
+    /// Returns a _specialized_ enumeration data schema and adds all the values to the
+    /// `enumeration` field.
+    ///
+    /// It behaves like calling [`enumeration`](Self::enumeration) for each value, therefore the
+    /// order is preserved. The data schema must end up with at least one value, otherwise
+    /// building it fails with [`DataSchemaError::EmptyEnum`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::{builder::data_schema::EnumerableDataSchema, thing::Thing};
+    /// #
+    /// let colors = vec!["red", "green", "blue"];
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .schema_definition("color", |b| b.finish_extend().enum_from_iter(colors))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     thing.schema_definitions.unwrap()["color"].enumeration,
+    ///     Some(vec![json!("red"), json!("green"), json!("blue")]),
+    /// );
+    /// ```
+    fn enum_from_iter<I>(self, iter: I) -> Self::Target
+    where
+        I: IntoIterator,
+        I::Item: Into<Value>;
 }
 
 /// An interface to specialize a _union_ version of a [`DataSchema`](crate::thing::DataSchema).
@@ -1474,6 +1953,8 @@ pub trait ReadableWriteableDataSchema<DS, AS, OS, Extended>:
 
 /// The builder for an [`ArraySchema`](crate::thing::ArraySchema) builder with a set of `items` to
 /// represent a tuple of elements.
+#[derive(Debug)]
+#[must_use]
 pub struct TupleDataSchemaBuilder<Inner, DS, AS, OS> {
     inner: Inner,
     items: Vec<UncheckedDataSchema<DS, AS, OS>>,
@@ -1484,6 +1965,8 @@ pub struct TupleDataSchemaBuilder<Inner, DS, AS, OS> {
 
 /// The builder for an [`ArraySchema`](crate::thing::ArraySchema) builder with a single `item` to
 /// represent the underlying type of a _homogeneous list_.
+#[derive(Debug)]
+#[must_use]
 pub struct VecDataSchemaBuilder<Inner, DS, AS, OS> {
     inner: Inner,
     item: Option<UncheckedDataSchema<DS, AS, OS>>,
@@ -1495,6 +1978,8 @@ pub struct VecDataSchemaBuilder<Inner, DS, AS, OS> {
 }
 
 /// The builder for an [`NumberSchema`](crate::thing::NumberSchema) builder.
+#[derive(Debug)]
+#[must_use]
 pub struct NumberDataSchemaBuilder<Inner> {
     inner: Inner,
     maximum: Option<Maximum<f64>>,
@@ -1503,6 +1988,8 @@ pub struct NumberDataSchemaBuilder<Inner> {
 }
 
 /// The builder for an [`IntegerSchema`](crate::thing::IntegerSchema) builder.
+#[derive(Debug)]
+#[must_use]
 pub struct IntegerDataSchemaBuilder<Inner> {
     inner: Inner,
     maximum: Option<Maximum<i64>>,
@@ -1511,6 +1998,8 @@ pub struct IntegerDataSchemaBuilder<Inner> {
 }
 
 /// The builder for an [`ObjectSchema`](crate::thing::ObjectSchema) builder.
+#[derive(Debug)]
+#[must_use]
 pub struct ObjectDataSchemaBuilder<Inner, DS, AS, OS> {
     inner: Inner,
     properties: Vec<(String, UncheckedDataSchema<DS, AS, OS>)>,
@@ -1521,6 +2010,8 @@ pub struct ObjectDataSchemaBuilder<Inner, DS, AS, OS> {
 }
 
 /// The builder for an [`StringSchema`](crate::thing::StringSchema) builder.
+#[derive(Debug)]
+#[must_use]
 pub struct StringDataSchemaBuilder<Inner> {
     inner: Inner,
     min_length: Option<u32>,
@@ -1532,37 +2023,57 @@ pub struct StringDataSchemaBuilder<Inner> {
 
 /// A _typetag_ for a `DataSchema` builder that has the
 /// [`enumeration`](crate::thing::DataSchema::enumeration) field populated.
+#[derive(Debug)]
+#[must_use]
 pub struct EnumDataSchemaBuilder<Inner> {
     inner: Inner,
 }
 
 /// A _typetag_ for a `DataSchema` builder that has the
 /// [`one_of`](crate::thing::DataSchema::one_of) field populated.
+#[derive(Debug)]
+#[must_use]
 pub struct OneOfDataSchemaBuilder<Inner> {
     inner: Inner,
 }
 
 /// The type of a stateless `DataSchema` specialization.
+#[derive(Debug)]
 pub enum StatelessDataSchemaType {
     /// A _boolean_ specialization.
     Boolean,
 
     /// A _null_ specialization.
     Null,
+
+    /// An _integer_ specialization without constraints.
+    Integer,
+
+    /// A _number_ specialization without constraints.
+    Number,
+
+    /// A _string_ specialization without constraints.
+    String,
 }
 
 /// A _typetag_ for a stateless specialized `DataSchema` builder.
+#[derive(Debug)]
+#[must_use]
 pub struct StatelessDataSchemaBuilder<Inner> {
     inner: Inner,
     ty: Option<StatelessDataSchemaType>,
 }
 
 /// A _typetag_ for a read-only `DataSchema` builder.
+#[derive(Debug)]
+#[must_use]
 pub struct ReadOnly<Inner> {
     inner: Inner,
 }
 
 /// A _typetag_ for a write-only `DataSchema` builder.
+#[derive(Debug)]
+#[must_use]
 pub struct WriteOnly<Inner> {
     inner: Inner,
 }
@@ -1786,6 +2297,46 @@ pub trait ObjectDataSchemaBuilderLike<DS, AS, OS> {
         F: FnOnce(DataSchemaBuilder<<DS as Extendable>::Empty, AS, OS, ToExtend>) -> T,
         DS: Extendable,
         T: Into<UncheckedDataSchema<DS, AS, OS>>;
+
+    /// Mark all the properties declared so far as required.
+    ///
+    /// The properties added after the call are not affected, and they are only required if
+    /// `required` is true when they are declared.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::{
+    /// #     builder::data_schema::{ObjectDataSchemaBuilderLike, SpecializableDataSchema},
+    /// #     thing::Thing,
+    /// # };
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .schema_definition("test", |b| {
+    ///         b.finish_extend()
+    ///             .object()
+    ///             .property("a", false, |b| b.finish_extend().integer())
+    ///             .property("b", false, |b| b.finish_extend().number())
+    ///             .required_all()
+    ///             .property("c", false, |b| b.finish_extend().bool())
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(thing).unwrap()["schemaDefinitions"]["test"]["required"],
+    ///     json!(["a", "b"]),
+    /// );
+    /// ```
+    fn required_all(self) -> Self;
+
+    /// Mark all the properties declared so far as not required.
+    ///
+    /// The properties added after the call are only required if `required` is true when they are
+    /// declared.
+    fn required_none(self) -> Self;
 }
 
 /// An interface for things behaving like a string data schema builder.
@@ -1929,6 +2480,20 @@ where
         self.properties.push((name, data_schema));
         self
     }
+
+    fn required_all(mut self) -> Self {
+        self.required = self
+            .properties
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect();
+        self
+    }
+
+    fn required_none(mut self) -> Self {
+        self.required.clear();
+        self
+    }
 }
 
 impl<Inner: BuildableDataSchema<DS, AS, OS, Extended>, DS, AS, OS>
@@ -2086,6 +2651,18 @@ macro_rules! impl_inner_delegate_schema_builder_like_object {
             self.$inner = self.$inner.property(name, required, f);
             self
         }
+
+        #[inline]
+        fn required_all(mut self) -> Self {
+            self.$inner = self.$inner.required_all();
+            self
+        }
+
+        #[inline]
+        fn required_none(mut self) -> Self {
+            self.$inner = self.$inner.required_none();
+            self
+        }
     };
 }
 
@@ -2114,7 +2691,6 @@ macro_rules! impl_delegate_schema_builder_like {
         )+
     };
 }
-pub(super) use impl_delegate_schema_builder_like;
 pub(super) use impl_inner_delegate_schema_builder_like_integer;
 pub(super) use impl_inner_delegate_schema_builder_like_number;
 pub(super) use impl_inner_delegate_schema_builder_like_object;
@@ -2154,6 +2730,11 @@ macro_rules! impl_delegate_buildable_data_schema {
             fn default_value(mut self, value: impl Into<Value>) -> Self {
                 crate::builder::data_schema::buildable_data_schema_delegate!(self.$inner -> default_value(value))
             }
+
+            #[inline]
+            fn raw_field(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+                crate::builder::data_schema::buildable_data_schema_delegate!(self.$inner -> raw_field(key, value))
+            }
         }
 
         $(
@@ -2182,6 +2763,11 @@ macro_rules! impl_delegate_buildable_data_schema {
             fn default_value(mut self, value: impl Into<Value>) -> Self {
                 crate::builder::data_schema::buildable_data_schema_delegate!(self.$inner -> default_value(value))
             }
+
+            #[inline]
+            fn raw_field(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+                crate::builder::data_schema::buildable_data_schema_delegate!(self.$inner -> raw_field(key, value))
+            }
         }
 
         $(
@@ -2225,11 +2811,16 @@ impl<DS, AS, OS, Status> BuildableDataSchema<DS, AS, OS, Status>
     fn default_value(mut self, value: impl Into<Value>) -> Self {
         buildable_data_schema_delegate!(self.partial -> default_value(value))
     }
+
+    #[inline]
+    fn raw_field(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        buildable_data_schema_delegate!(self.partial -> raw_field(key, value))
+    }
 }
 
 pub(crate) use buildable_data_schema_delegate;
 pub(crate) use impl_delegate_buildable_data_schema;
-use serde_json::Value;
+use serde_json::{Map, Value};
 
 macro_rules! trait_opt_field_builder {
     ($($field:ident : $ty:ty),* $(,)?) => {
@@ -2255,6 +2846,11 @@ impl<DS, AS, OS, Status> BuildableDataSchema<DS, AS, OS, Status>
         self.default = Some(value.into());
         self
     }
+
+    fn raw_field(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.raw_fields.insert(key.into(), value.into());
+        self
+    }
 }
 
 impl_delegate_buildable_hr_info!(
@@ -2408,13 +3004,36 @@ macro_rules! impl_specializable_data_schema {
                     }
                 }
 
-                fn constant(mut self, value: impl Into<Value>) -> Self::Constant {
-                    self $(. $($inner_path).+)?.constant = Some(value.into());
+                fn constant(self, value: impl Into<Value>) -> Self::Constant {
+                    self.typed_constant(value.into(), None)
+                }
+
+                fn constant_bool(self, value: bool) -> Self::Constant {
+                    self.typed_constant(value.into(), Some(StatelessDataSchemaType::Boolean))
+                }
+
+                fn constant_int(self, value: i64) -> Self::Constant {
+                    self.typed_constant(value.into(), Some(StatelessDataSchemaType::Integer))
+                }
+
+                fn constant_number(self, value: f64) -> Self::Constant {
+                    self.typed_constant(value.into(), Some(StatelessDataSchemaType::Number))
+                }
+
+                fn constant_string(self, value: impl Into<String>) -> Self::Constant {
+                    self.typed_constant(value.into().into(), Some(StatelessDataSchemaType::String))
+                }
+            }
+
+            impl<DS, AS, OS> $ty {
+                fn typed_constant(
+                    mut self,
+                    value: Value,
+                    ty: Option<StatelessDataSchemaType>,
+                ) -> ReadOnly<StatelessDataSchemaBuilder<Self>> {
+                    self $(. $($inner_path).+)?.constant = Some(value);
                     ReadOnly {
-                        inner: StatelessDataSchemaBuilder {
-                            inner: self,
-                            ty: None,
-                        },
+                        inner: StatelessDataSchemaBuilder { inner: self, ty },
                     }
                 }
             }
@@ -2434,6 +3053,15 @@ macro_rules! impl_enumerable_data_schema {
                 self $(. $($inner_path).+ )?.enumeration.push(value.into());
                 EnumDataSchemaBuilder { inner: self }
             }
+
+            fn enum_from_iter<I>(mut self, iter: I) -> EnumDataSchemaBuilder<Self>
+            where
+                I: IntoIterator,
+                I::Item: Into<Value>,
+            {
+                self $(. $($inner_path).+ )?.enumeration.extend(iter.into_iter().map(Into::into));
+                EnumDataSchemaBuilder { inner: self }
+            }
         }
         )+
     };
@@ -2454,6 +3082,18 @@ where
         let inner = inner.enumeration(value);
         ReadOnly { inner }
     }
+
+    #[inline]
+    fn enum_from_iter<I>(self, iter: I) -> Self::Target
+    where
+        I: IntoIterator,
+        I::Item: Into<Value>,
+    {
+        let Self { inner } = self;
+
+        let inner = inner.enum_from_iter(iter);
+        ReadOnly { inner }
+    }
 }
 
 impl<Inner, DS, AS, OS> EnumerableDataSchema<DS, AS, OS, Extended> for WriteOnly<Inner>
@@ -2469,6 +3109,18 @@ where
         let inner = inner.enumeration(value);
         WriteOnly { inner }
     }
+
+    #[inline]
+    fn enum_from_iter<I>(self, iter: I) -> Self::Target
+    where
+        I: IntoIterator,
+        I::Item: Into<Value>,
+    {
+        let Self { inner } = self;
+
+        let inner = inner.enum_from_iter(iter);
+        WriteOnly { inner }
+    }
 }
 
 impl<DS, AS, OS> EnumerableDataSchema<DS, AS, OS, Extended>
@@ -2481,6 +3133,18 @@ impl<DS, AS, OS> EnumerableDataSchema<DS, AS, OS, Extended>
         self.inner.enumeration.push(value.into());
         self
     }
+
+    #[inline]
+    fn enum_from_iter<I>(mut self, iter: I) -> Self::Target
+    where
+        I: IntoIterator,
+        I::Item: Into<Value>,
+    {
+        self.inner
+            .enumeration
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
 }
 
 impl<DS, AS, OS> EnumerableDataSchema<DS, AS, OS, Extended>
@@ -2493,6 +3157,19 @@ impl<DS, AS, OS> EnumerableDataSchema<DS, AS, OS, Extended>
         self.inner.partial.enumeration.push(value.into());
         self
     }
+
+    #[inline]
+    fn enum_from_iter<I>(mut self, iter: I) -> Self::Target
+    where
+        I: IntoIterator,
+        I::Item: Into<Value>,
+    {
+        self.inner
+            .partial
+            .enumeration
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
 }
 
 macro_rules! impl_union_data_schema {
@@ -2592,7 +3269,7 @@ impl<DS, AS, OS> UnionDataSchema<DS, AS, OS>
 }
 
 macro_rules! impl_rw_data_schema {
-    ($( $ty:ty; $($inner_path:ident).+ ),+ $(,)?) => {
+    ($( $ty:ty $(; $($inner_path:ident).+)? ),+ $(,)?) => {
         $(
             impl<DS, AS, OS> ReadableWriteableDataSchema<DS, AS, OS, Extended> for $ty
             {
@@ -2601,7 +3278,7 @@ macro_rules! impl_rw_data_schema {
 
                 #[inline]
                 fn read_only(mut self) -> Self::ReadOnly {
-                    self.$($inner_path).+.read_only = true;
+                    self $(.$($inner_path).+)?.read_only = true;
                     ReadOnly {
                         inner: self,
                     }
@@ -2609,7 +3286,7 @@ macro_rules! impl_rw_data_schema {
 
                 #[inline]
                 fn write_only(mut self) -> Self::WriteOnly {
-                    self.$($inner_path).+.write_only = true;
+                    self $(.$($inner_path).+)?.write_only = true;
                     WriteOnly {
                         inner: self,
                     }
@@ -2620,6 +3297,8 @@ macro_rules! impl_rw_data_schema {
 }
 
 impl_rw_data_schema!(
+    DataSchemaBuilder<DS, AS, OS, Extended>; partial,
+    PartialDataSchemaBuilder<DS, AS, OS, Extended>,
     StatelessDataSchemaBuilder<DataSchemaBuilder<DS, AS, OS, Extended>>; inner.partial,
     StatelessDataSchemaBuilder<PartialDataSchemaBuilder<DS, AS, OS, Extended>>; inner,
     TupleDataSchemaBuilder<DataSchemaBuilder<DS, AS, OS, Extended>, DS, AS, OS>; inner.partial,
@@ -2636,6 +3315,8 @@ impl_rw_data_schema!(
     StringDataSchemaBuilder<PartialDataSchemaBuilder<DS, AS, OS, Extended>>; inner,
     EnumDataSchemaBuilder<DataSchemaBuilder<DS, AS, OS, Extended>>; inner.partial,
     EnumDataSchemaBuilder<PartialDataSchemaBuilder<DS, AS, OS, Extended>>; inner,
+    OneOfDataSchemaBuilder<DataSchemaBuilder<DS, AS, OS, Extended>>; inner.partial,
+    OneOfDataSchemaBuilder<PartialDataSchemaBuilder<DS, AS, OS, Extended>>; inner,
 );
 
 impl<T, DS, AS, OS> From<ReadOnly<T>> for DataSchemaBuilder<DS, AS, OS, Extended>
@@ -2689,6 +3370,15 @@ impl<DS, AS, OS> From<StatelessDataSchemaType> for UncheckedDataSchemaSubtype<DS
         match ty {
             StatelessDataSchemaType::Boolean => UncheckedDataSchemaSubtype::Boolean,
             StatelessDataSchemaType::Null => UncheckedDataSchemaSubtype::Null,
+            StatelessDataSchemaType::Integer => {
+                UncheckedDataSchemaSubtype::Integer(Default::default())
+            }
+            StatelessDataSchemaType::Number => {
+                UncheckedDataSchemaSubtype::Number(Default::default())
+            }
+            StatelessDataSchemaType::String => {
+                UncheckedDataSchemaSubtype::String(Default::default())
+            }
         }
     }
 }
@@ -2710,6 +3400,7 @@ where
                     read_only,
                     write_only,
                     format,
+                    raw_fields,
                     other,
                     _marker: _,
                 },
@@ -2720,6 +3411,8 @@ where
                     titles,
                     description,
                     descriptions,
+                    deprecated,
+                    deprecation_note,
                 },
         } = inner.into();
 
@@ -2731,6 +3424,8 @@ where
             titles,
             description,
             descriptions,
+            deprecated,
+            deprecation_note,
             constant,
             default,
             unit,
@@ -2739,8 +3434,11 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown: Default::default(),
         }
     }
 }
@@ -2772,6 +3470,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             other,
             _marker: _,
         } = inner.into();
@@ -2787,6 +3486,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other,
         }
@@ -2814,6 +3514,7 @@ where
                     read_only,
                     write_only,
                     format,
+                    raw_fields,
                     other: other_data_schema,
                     _marker: _,
                 },
@@ -2824,6 +3525,8 @@ where
                     titles,
                     description,
                     descriptions,
+                    deprecated,
+                    deprecation_note,
                 },
         } = inner.into();
 
@@ -2841,6 +3544,8 @@ where
             titles,
             description,
             descriptions,
+            deprecated,
+            deprecation_note,
             constant: None,
             default,
             unit,
@@ -2849,8 +3554,11 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other: other_data_schema,
+            #[cfg(feature = "preserve-unknown")]
+            unknown: Default::default(),
         }
     }
 }
@@ -2878,6 +3586,7 @@ where
                     read_only,
                     write_only,
                     format,
+                    raw_fields,
                     other: other_data_schema,
                     _marker: _,
                 },
@@ -2888,6 +3597,8 @@ where
                     titles,
                     description,
                     descriptions,
+                    deprecated,
+                    deprecation_note,
                 },
         } = inner.into();
 
@@ -2905,6 +3616,8 @@ where
             titles,
             description,
             descriptions,
+            deprecated,
+            deprecation_note,
             constant: None,
             default,
             unit,
@@ -2913,8 +3626,11 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other: other_data_schema,
+            #[cfg(feature = "preserve-unknown")]
+            unknown: Default::default(),
         }
     }
 }
@@ -2962,6 +3678,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             other: other_data_schema,
             _marker: _,
         } = inner.into();
@@ -2983,6 +3700,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other: other_data_schema,
         }
@@ -3010,6 +3728,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             other: other_data_schema,
             _marker: _,
         } = inner.into();
@@ -3031,6 +3750,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other: other_data_schema,
         }
@@ -3059,6 +3779,7 @@ where
                     read_only,
                     write_only,
                     format,
+                    raw_fields,
                     other,
                     _marker: _,
                 },
@@ -3069,6 +3790,8 @@ where
                     titles,
                     description,
                     descriptions,
+                    deprecated,
+                    deprecation_note,
                 },
         } = inner.into();
 
@@ -3084,6 +3807,8 @@ where
             titles,
             description,
             descriptions,
+            deprecated,
+            deprecation_note,
             constant: None,
             default,
             unit,
@@ -3092,8 +3817,11 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown: Default::default(),
         }
     }
 }
@@ -3130,6 +3858,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             other,
             _marker: _,
         } = inner.into();
@@ -3149,6 +3878,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other,
         }
@@ -3177,6 +3907,7 @@ where
                     read_only,
                     write_only,
                     format,
+                    raw_fields,
                     other,
                     _marker: _,
                 },
@@ -3187,6 +3918,8 @@ where
                     titles,
                     description,
                     descriptions,
+                    deprecated,
+                    deprecation_note,
                 },
         } = inner.into();
 
@@ -3202,6 +3935,8 @@ where
             titles,
             description,
             descriptions,
+            deprecated,
+            deprecation_note,
             constant: None,
             default,
             unit,
@@ -3210,8 +3945,11 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown: Default::default(),
         }
     }
 }
@@ -3248,6 +3986,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             other,
             _marker: _,
         } = inner.into();
@@ -3267,6 +4006,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other,
         }
@@ -3295,6 +4035,7 @@ where
                     read_only,
                     write_only,
                     format,
+                    raw_fields,
                     other: other_data_schema,
                     _marker: _,
                 },
@@ -3305,13 +4046,18 @@ where
                     titles,
                     description,
                     descriptions,
+                    deprecated,
+                    deprecation_note,
                 },
         } = inner.into();
 
-        let properties = properties
-            .is_empty()
-            .not()
-            .then(|| properties.into_iter().collect());
+        let properties = properties.is_empty().not().then(|| {
+            let mut map = HashMap::with_capacity(properties.len());
+            for (name, data_schema) in properties {
+                map.insert(name, data_schema);
+            }
+            map
+        });
         let required = required.is_empty().not().then_some(required);
         let subtype = Some(UncheckedDataSchemaSubtype::Object(UncheckedObjectSchema {
             properties,
@@ -3325,6 +4071,8 @@ where
             titles,
             description,
             descriptions,
+            deprecated,
+            deprecation_note,
             constant: None,
             default,
             unit,
@@ -3333,8 +4081,11 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other: other_data_schema,
+            #[cfg(feature = "preserve-unknown")]
+            unknown: Default::default(),
         }
     }
 }
@@ -3371,14 +4122,18 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             other: other_data_schema,
             _marker: _,
         } = inner.into();
 
-        let properties = properties
-            .is_empty()
-            .not()
-            .then(|| properties.into_iter().collect());
+        let properties = properties.is_empty().not().then(|| {
+            let mut map = HashMap::with_capacity(properties.len());
+            for (name, data_schema) in properties {
+                map.insert(name, data_schema);
+            }
+            map
+        });
         let required = required.is_empty().not().then_some(required);
         let subtype = Some(UncheckedDataSchemaSubtype::Object(UncheckedObjectSchema {
             properties,
@@ -3395,6 +4150,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other: other_data_schema,
         }
@@ -3426,6 +4182,7 @@ where
                     read_only,
                     write_only,
                     format,
+                    raw_fields,
                     other,
                     _marker: _,
                 },
@@ -3436,6 +4193,8 @@ where
                     titles,
                     description,
                     descriptions,
+                    deprecated,
+                    deprecation_note,
                 },
         } = inner.into();
 
@@ -3453,6 +4212,8 @@ where
             titles,
             description,
             descriptions,
+            deprecated,
+            deprecation_note,
             constant: None,
             default,
             unit,
@@ -3461,8 +4222,11 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown: Default::default(),
         }
     }
 }
@@ -3502,6 +4266,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             other,
             _marker: _,
         } = inner.into();
@@ -3523,6 +4288,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other,
         }
@@ -3621,6 +4387,7 @@ where
                     read_only,
                     write_only,
                     format,
+                    raw_fields,
                     other,
                     _marker: _,
                 },
@@ -3631,6 +4398,8 @@ where
                     titles,
                     description,
                     descriptions,
+                    deprecated,
+                    deprecation_note,
                 },
         } = builder.inner.into();
 
@@ -3641,6 +4410,8 @@ where
             titles,
             description,
             descriptions,
+            deprecated,
+            deprecation_note,
             constant: None,
             default,
             unit,
@@ -3649,8 +4420,11 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype: None,
             other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown: Default::default(),
         }
     }
 }
@@ -3681,6 +4455,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             other,
             _marker: _,
         } = builder.inner.into();
@@ -3695,6 +4470,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype: None,
             other,
         }
@@ -3717,6 +4493,7 @@ where
                     read_only,
                     write_only,
                     format,
+                    raw_fields,
                     other,
                     _marker: _,
                 },
@@ -3727,6 +4504,8 @@ where
                     titles,
                     description,
                     descriptions,
+                    deprecated,
+                    deprecation_note,
                 },
         } = builder.inner.into();
 
@@ -3737,6 +4516,8 @@ where
             titles,
             description,
             descriptions,
+            deprecated,
+            deprecation_note,
             constant: None,
             default,
             unit,
@@ -3745,8 +4526,11 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype: None,
             other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown: Default::default(),
         }
     }
 }
@@ -3777,6 +4561,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             other,
             _marker: _,
         } = builder.inner.into();
@@ -3791,6 +4576,7 @@ where
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype: None,
             other,
         }
@@ -3798,31 +4584,113 @@ where
 }
 
 pub(super) trait CheckableDataSchema {
-    fn check(&self) -> Result<(), Error>;
+    /// Checks the data schema, whose JSON pointer relative to the checked root is `path`.
+    fn check_at(&self, path: &str) -> Result<(), Error>;
+
+    fn check(&self) -> Result<(), Error> {
+        self.check_at("")
+    }
 }
 
 impl<DS, AS, OS> CheckableDataSchema for UncheckedDataSchema<DS, AS, OS> {
-    fn check(&self) -> Result<(), Error> {
-        check_data_schema_subtype(&self.subtype)?;
-        check_one_of_schema(self.one_of.as_deref())?;
+    fn check_at(&self, path: &str) -> Result<(), Error> {
+        check_const_and_enum(
+            self.constant.as_ref(),
+            self.enumeration.as_deref(),
+            self.subtype.as_ref(),
+            path,
+        )?;
+        check_data_schema_subtype(&self.subtype, path)?;
+        check_one_of_schema(self.one_of.as_deref(), path)?;
         Ok(())
     }
 }
 
 impl<DS, AS, OS> CheckableDataSchema for PartialDataSchema<DS, AS, OS> {
-    fn check(&self) -> Result<(), Error> {
-        check_data_schema_subtype(&self.subtype)?;
-        check_one_of_schema(self.one_of.as_deref())?;
+    fn check_at(&self, path: &str) -> Result<(), Error> {
+        check_const_and_enum(
+            self.constant.as_ref(),
+            self.enumeration.as_deref(),
+            self.subtype.as_ref(),
+            path,
+        )?;
+        check_data_schema_subtype(&self.subtype, path)?;
+        check_one_of_schema(self.one_of.as_deref(), path)?;
         Ok(())
     }
 }
 
+/// Checks the `const` and the `enum` of the data schema whose JSON pointer is `path`.
+///
+/// They cannot be used together, because an enumeration would be either redundant or
+/// contradictory. The enumeration must have at least one value, and the values must match the
+/// `type` of the data schema, if any.
+pub(crate) fn check_const_and_enum(
+    constant: Option<&Value>,
+    enumeration: Option<&[Value]>,
+    subtype: Option<&impl HasType>,
+    path: &str,
+) -> Result<(), Error> {
+    match (constant, enumeration) {
+        (Some(_), Some(_)) => return Err(DataSchemaError::ConstAndEnum.into()),
+        (_, Some([])) => return Err(DataSchemaError::EmptyEnum.into()),
+        _ => {}
+    }
+
+    let Some(subtype) = subtype else {
+        return Ok(());
+    };
+    if constant.is_some_and(|constant| !subtype.has_type(constant)) {
+        return Err(DataSchemaError::ConstTypeMismatch.into());
+    }
+    match enumeration
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .find(|(_, value)| !subtype.has_type(value))
+    {
+        Some((index, value)) => Err(DataSchemaError::EnumTypeMismatch {
+            value: serde_json::to_string(value).unwrap_or_default(),
+            path: format!("{path}/enum/{index}"),
+        }
+        .into()),
+        None => Ok(()),
+    }
+}
+
+/// Checks that the raw fields do not override any of the members of the object they are
+/// serialized into.
+pub(super) fn check_raw_fields(
+    raw_fields: &Map<String, Value>,
+    is_member: impl Fn(&str) -> bool,
+) -> Result<(), Error> {
+    raw_fields
+        .keys()
+        .find(|key| DATA_SCHEMA_MEMBERS.contains(&key.as_str()) || is_member(key))
+        .map_or(Ok(()), |key| {
+            Err(DataSchemaError::ReservedKeyOverride(key.clone()).into())
+        })
+}
+
+/// Returns the names of the members serialized by an extension.
+///
+/// The extensions that are not serialized as JSON objects do not have any member.
+pub(super) fn serialized_members<T: Serialize>(extension: &T) -> Vec<String> {
+    match serde_json::to_value(extension) {
+        Ok(Value::Object(members)) => members.into_iter().map(|(key, _)| key).collect(),
+        _ => Vec::new(),
+    }
+}
+
 pub(super) fn check_data_schema_subtype<DS, AS, OS>(
     mut subtype: &Option<UncheckedDataSchemaSubtype<DS, AS, OS>>,
+    path: &str,
 ) -> Result<(), Error> {
     use UncheckedDataSchemaSubtype::*;
 
+    // The nested data schemas to check, along with their JSON pointers.
     let mut stack = Vec::new();
+    let mut path = path.to_owned();
 
     loop {
         if let Some(subtype) = subtype.as_ref() {
@@ -3830,75 +4698,106 @@ pub(super) fn check_data_schema_subtype<DS, AS, OS>(
                 Array(array) => {
                     match (array.min_items, array.max_items) {
                         (Some(min), Some(max))
-                            if matches!(min.partial_cmp(&max), None | Some(Ordering::Greater)) =>
+                            if min.partial_cmp(&max) == Some(Ordering::Greater) =>
                         {
-                            return Err(Error::InvalidMinMax)
+                            return Err(DataSchemaError::InvalidMinMax.into())
                         }
                         _ => {}
                     };
 
                     if let Some(items) = &array.items {
                         match items {
-                            BoxedElemOrVec::Elem(item) => stack.push(item.as_ref()),
-                            BoxedElemOrVec::Vec(items) => stack.extend(items.iter()),
+                            BoxedElemOrVec::Elem(item) => {
+                                stack.push((format!("{path}/items"), item.as_ref()))
+                            }
+                            BoxedElemOrVec::Vec(items) => stack.extend(
+                                items
+                                    .iter()
+                                    .enumerate()
+                                    .map(|(index, item)| (format!("{path}/items/{index}"), item)),
+                            ),
                         }
                     }
                 }
                 Number(number) => {
                     match (number.minimum, number.maximum) {
-                        (Some(x), _) if x.is_nan() => return Err(Error::NanMinMax),
-                        (_, Some(x)) if x.is_nan() => return Err(Error::NanMinMax),
+                        (Some(x), _) if x.is_nan() => return Err(DataSchemaError::NanMinMax.into()),
+                        (_, Some(x)) if x.is_nan() => return Err(DataSchemaError::NanMinMax.into()),
+                        (Some(x), _) if x.is_infinite() => {
+                            return Err(DataSchemaError::NonFiniteBound.into())
+                        }
+                        (_, Some(x)) if x.is_infinite() => {
+                            return Err(DataSchemaError::NonFiniteBound.into())
+                        }
                         (Some(min), Some(max))
-                            if matches!(min.partial_cmp(&max), None | Some(Ordering::Greater)) =>
+                            if min.partial_cmp(&max) == Some(Ordering::Greater) =>
                         {
-                            return Err(Error::InvalidMinMax)
+                            return Err(DataSchemaError::InvalidMinMax.into())
                         }
                         _ => {}
                     }
 
                     match number.multiple_of {
+                        Some(multiple_of) if !multiple_of.is_finite() => {
+                            return Err(DataSchemaError::NonFiniteBound.into())
+                        }
                         Some(multiple_of) if multiple_of <= 0. => {
-                            return Err(Error::InvalidMultipleOf)
+                            return Err(DataSchemaError::InvalidMultipleOf.into())
                         }
                         _ => {}
                     }
                 }
                 Integer(integer) => match (integer.minimum, integer.maximum) {
-                    (Some(min), Some(max))
-                        if matches!(min.partial_cmp(&max), None | Some(Ordering::Greater)) =>
-                    {
-                        return Err(Error::InvalidMinMax)
+                    (Some(min), Some(max)) if min.partial_cmp(&max) == Some(Ordering::Greater) => {
+                        return Err(DataSchemaError::InvalidMinMax.into())
                     }
                     _ => {}
                 },
                 Object(UncheckedObjectSchema {
                     properties: Some(properties),
                     ..
-                }) => stack.extend(properties.values()),
+                }) => stack.extend(properties.iter().map(|(name, property)| {
+                    let name = name.replace('~', "~0").replace('/', "~1");
+                    (format!("{path}/properties/{name}"), property)
+                })),
                 Object(_) | String(_) | Boolean | Null => {}
             }
         }
 
         match stack.pop() {
-            Some(new_data_schema) => {
+            Some((new_path, new_data_schema)) => {
+                check_const_and_enum(
+                    new_data_schema.constant.as_ref(),
+                    new_data_schema.enumeration.as_deref(),
+                    new_data_schema.subtype.as_ref(),
+                    &new_path,
+                )?;
                 if let Some(children) = new_data_schema.one_of.as_deref() {
-                    stack.extend(children.iter());
+                    stack.extend(
+                        children
+                            .iter()
+                            .enumerate()
+                            .map(|(index, child)| (format!("{new_path}/oneOf/{index}"), child)),
+                    );
                 }
 
-                subtype = &new_data_schema.subtype
+                subtype = &new_data_schema.subtype;
+                path = new_path;
             }
             None => break Ok(()),
         }
     }
 }
 
-fn check_one_of_schema<T>(one_of: Option<&[T]>) -> Result<(), Error>
+fn check_one_of_schema<T>(one_of: Option<&[T]>, path: &str) -> Result<(), Error>
 where
     T: CheckableDataSchema,
 {
     one_of
-        .map(|one_of| one_of.iter().try_for_each(|schema| schema.check()))
-        .unwrap_or(Ok(()))
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .try_for_each(|(index, schema)| schema.check_at(&format!("{path}/oneOf/{index}")))
 }
 
 impl<DS, AS, OS> TryFrom<UncheckedDataSchema<DS, AS, OS>> for DataSchema<DS, AS, OS> {
@@ -3911,6 +4810,8 @@ impl<DS, AS, OS> TryFrom<UncheckedDataSchema<DS, AS, OS>> for DataSchema<DS, AS,
             titles,
             description,
             descriptions,
+            deprecated,
+            deprecation_note,
             constant,
             default,
             unit,
@@ -3919,30 +4820,47 @@ impl<DS, AS, OS> TryFrom<UncheckedDataSchema<DS, AS, OS>> for DataSchema<DS, AS,
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
         } = data_schema;
 
-        let titles = titles.map(|titles| titles.build()).transpose()?;
+        let titles = titles
+            .map(|titles| titles.build().map_err(|error| error.at("titles")))
+            .transpose()?;
         let descriptions = descriptions
-            .map(|descriptions| descriptions.build())
+            .map(|descriptions| {
+                descriptions
+                    .build()
+                    .map_err(|error| error.at("descriptions"))
+            })
             .transpose()?;
+        check_raw_fields(&raw_fields, |_| false)?;
         let one_of = one_of
             .map(|one_of| {
                 one_of
                     .into_iter()
-                    .map(|data_schema| data_schema.try_into())
+                    .enumerate()
+                    .map(|(index, data_schema)| {
+                        data_schema
+                            .try_into()
+                            .map_err(|error: Error| error.at(index).at("oneOf"))
+                    })
                     .collect()
             })
             .transpose()?;
         let subtype = subtype.map(|subtype| subtype.try_into()).transpose()?;
 
         Ok(Self {
-            attype,
+            attype: parse_at_types(attype),
             title,
             titles,
             description,
             descriptions,
+            deprecated,
+            deprecation_note,
             constant,
             default,
             unit,
@@ -3951,12 +4869,138 @@ impl<DS, AS, OS> TryFrom<UncheckedDataSchema<DS, AS, OS>> for DataSchema<DS, AS,
             read_only,
             write_only,
             format,
+            raw_fields,
             subtype,
             other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
         })
     }
 }
 
+impl<DS, AS, OS> UncheckedDataSchema<DS, AS, OS> {
+    /// Checks the data schema as the builders do, converting it into a [`DataSchema`].
+    pub(crate) fn try_build(self) -> Result<DataSchema<DS, AS, OS>, Error> {
+        self.check()?;
+        self.try_into()
+    }
+
+    /// Removes the unknown members that belong to the property affordance flattening the data
+    /// schema, either declared by it or claimed by the extensions `C`.
+    #[cfg(feature = "preserve-unknown")]
+    pub(crate) fn remove_property_members<C: crate::unknown::ClaimMembers>(&mut self) {
+        use crate::thing::{INTERACTION_AFFORDANCE_MEMBERS, PROPERTY_AFFORDANCE_MEMBERS};
+
+        crate::unknown::remove_known(
+            &mut self.unknown,
+            &[INTERACTION_AFFORDANCE_MEMBERS, PROPERTY_AFFORDANCE_MEMBERS],
+        );
+        C::remove_claimed(&mut self.unknown);
+    }
+}
+
+impl<DS, AS, OS> From<DataSchema<DS, AS, OS>> for UncheckedDataSchema<DS, AS, OS> {
+    fn from(data_schema: DataSchema<DS, AS, OS>) -> Self {
+        let DataSchema {
+            attype,
+            title,
+            titles,
+            description,
+            descriptions,
+            deprecated,
+            deprecation_note,
+            constant,
+            default,
+            unit,
+            one_of,
+            enumeration,
+            read_only,
+            write_only,
+            format,
+            subtype,
+            other,
+            raw_fields,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        } = data_schema;
+
+        Self {
+            attype: format_at_types(attype),
+            title,
+            titles: titles.map(MultiLanguageBuilder::from_built),
+            description,
+            descriptions: descriptions.map(MultiLanguageBuilder::from_built),
+            deprecated,
+            deprecation_note,
+            constant,
+            default,
+            unit,
+            one_of: one_of.map(|one_of| one_of.into_iter().map(Into::into).collect()),
+            enumeration,
+            read_only,
+            write_only,
+            format,
+            subtype: subtype.map(Into::into),
+            other,
+            raw_fields,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        }
+    }
+}
+
+impl<DS, AS, OS> From<DataSchemaSubtype<DS, AS, OS>> for UncheckedDataSchemaSubtype<DS, AS, OS> {
+    fn from(value: DataSchemaSubtype<DS, AS, OS>) -> Self {
+        match value {
+            DataSchemaSubtype::Array(array) => {
+                let ArraySchema {
+                    items,
+                    min_items,
+                    max_items,
+                    other,
+                } = array;
+                let items = items.map(|items| match items {
+                    BoxedElemOrVec::Elem(item) => BoxedElemOrVec::Elem(Box::new((*item).into())),
+                    BoxedElemOrVec::Vec(items) => {
+                        BoxedElemOrVec::Vec(items.into_iter().map(Into::into).collect())
+                    }
+                });
+
+                Self::Array(UncheckedArraySchema {
+                    items,
+                    min_items,
+                    max_items,
+                    other,
+                })
+            }
+            DataSchemaSubtype::Boolean => Self::Boolean,
+            DataSchemaSubtype::Number(number) => Self::Number(number),
+            DataSchemaSubtype::Integer(integer) => Self::Integer(integer),
+            DataSchemaSubtype::Object(object) => {
+                let ObjectSchema {
+                    properties,
+                    required,
+                    other,
+                } = object;
+                let properties = properties.map(|properties| {
+                    properties
+                        .into_iter()
+                        .map(|(name, data_schema)| (name, data_schema.into()))
+                        .collect()
+                });
+
+                Self::Object(UncheckedObjectSchema {
+                    properties,
+                    required,
+                    other,
+                })
+            }
+            DataSchemaSubtype::String(string) => Self::String(string),
+            DataSchemaSubtype::Null => Self::Null,
+        }
+    }
+}
+
 pub(crate) fn uri_variables_contains_arrays_objects<Other>(
     uri_variables: &UncheckedDataSchemaMap<Other>,
 ) -> bool
@@ -4005,10 +5049,15 @@ impl<DS, AS, OS> TryFrom<UncheckedArraySchema<DS, AS, OS>> for ArraySchema<DS, A
             .map(|items| match items {
                 BoxedElemOrVec::Elem(item) => (*item)
                     .try_into()
-                    .map(|item| BoxedElemOrVec::Elem(Box::new(item))),
+                    .map(|item| BoxedElemOrVec::Elem(Box::new(item)))
+                    .map_err(|error: Error| error.at("items")),
                 BoxedElemOrVec::Vec(items) => items
                     .into_iter()
-                    .map(TryInto::try_into)
+                    .enumerate()
+                    .map(|(index, item)| {
+                        item.try_into()
+                            .map_err(|error: Error| error.at(index).at("items"))
+                    })
                     .collect::<Result<_, _>>()
                     .map(BoxedElemOrVec::Vec),
             })
@@ -4034,12 +5083,21 @@ impl<DS, AS, OS> TryFrom<UncheckedObjectSchema<DS, AS, OS>> for ObjectSchema<DS,
         } = value;
         let properties = properties
             .map(|properties| {
-                properties
-                    .into_iter()
-                    .map(|(k, v)| v.try_into().map(|v| (k, v)))
-                    .collect()
+                let mut map = HashMap::with_capacity(properties.len());
+                for (k, v) in properties {
+                    check_name(&k, NameKind::ObjectProperty)?;
+                    let v = v
+                        .try_into()
+                        .map_err(|error: Error| error.at(&k).at("properties"))?;
+                    map.insert(k, v);
+                }
+                Ok::<_, Error>(map)
             })
             .transpose()?;
+        required
+            .iter()
+            .flatten()
+            .try_for_each(|name| check_name(name, NameKind::Required))?;
 
         Ok(Self {
             properties,
@@ -4051,11 +5109,12 @@ impl<DS, AS, OS> TryFrom<UncheckedObjectSchema<DS, AS, OS>> for ObjectSchema<DS,
 
 #[cfg(test)]
 mod tests {
-    use alloc::{boxed::Box, string::*, vec};
+    use alloc::{boxed::Box, format, string::*, vec};
     use serde::{Deserialize, Serialize};
     use serde_json::json;
 
     use crate::{
+        builder::DocumentError,
         extend::ExtendableThing,
         hlist::{Cons, Nil},
         thing::{ArraySchema, BoxedElemOrVec, DataSchemaFromOther, ObjectSchema},
@@ -4075,6 +5134,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -4083,8 +5144,11 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Null),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -4104,6 +5168,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(UncheckedDataSchemaSubtype::Null),
                 other: Nil,
             }
@@ -4122,6 +5187,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -4130,8 +5197,11 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Boolean),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -4151,6 +5221,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(UncheckedDataSchemaSubtype::Boolean),
                 other: Nil,
             }
@@ -4169,6 +5240,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -4177,6 +5250,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::String(StringSchema {
                     max_length: None,
                     min_length: None,
@@ -4185,6 +5259,8 @@ mod tests {
                     content_media_type: None,
                 })),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -4204,6 +5280,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(UncheckedDataSchemaSubtype::String(StringSchema {
                     min_length: None,
                     max_length: None,
@@ -4228,6 +5305,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -4236,6 +5315,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Array(ArraySchema {
                     items: None,
                     min_items: None,
@@ -4243,6 +5323,8 @@ mod tests {
                     other: Nil,
                 })),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -4262,6 +5344,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(UncheckedDataSchemaSubtype::Array(UncheckedArraySchema {
                     items: None,
                     min_items: None,
@@ -4285,6 +5368,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -4293,6 +5378,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Array(ArraySchema {
                     items: Some(BoxedElemOrVec::Vec(vec![])),
                     min_items: None,
@@ -4300,6 +5386,8 @@ mod tests {
                     other: Nil,
                 })),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -4319,6 +5407,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(UncheckedDataSchemaSubtype::Array(UncheckedArraySchema {
                     items: Some(BoxedElemOrVec::Vec(vec![])),
                     min_items: None,
@@ -4342,6 +5431,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -4350,12 +5441,15 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Number(NumberSchema {
                     maximum: None,
                     minimum: None,
                     multiple_of: None,
                 })),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -4374,6 +5468,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(UncheckedDataSchemaSubtype::Number(NumberSchema {
                     maximum: None,
                     minimum: None,
@@ -4396,6 +5491,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -4404,12 +5501,15 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Integer(IntegerSchema {
                     maximum: None,
                     minimum: None,
                     multiple_of: None,
                 })),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -4429,6 +5529,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(UncheckedDataSchemaSubtype::Integer(IntegerSchema {
                     maximum: None,
                     minimum: None,
@@ -4451,6 +5552,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -4459,12 +5562,15 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Object(ObjectSchema {
                     properties: None,
                     required: None,
                     other: Nil,
                 })),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -4484,6 +5590,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(UncheckedDataSchemaSubtype::Object(UncheckedObjectSchema {
                     properties: None,
                     required: None,
@@ -4508,6 +5615,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: Some(json!({
                     "hello": 42,
                 })),
@@ -4518,8 +5627,11 @@ mod tests {
                 read_only: true,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: None,
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -4542,12 +5654,111 @@ mod tests {
                 read_only: true,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: None,
                 other: Nil,
             }
         );
     }
 
+    #[test]
+    fn typed_constants() {
+        fn to_value(builder: impl Into<UncheckedDataSchemaFromOther<Nil>>) -> Value {
+            let data_schema: DataSchemaFromOther<Nil> = builder.into().try_into().unwrap();
+            serde_json::to_value(data_schema).unwrap()
+        }
+
+        for (value, expected) in [
+            (
+                to_value(DataSchemaBuilder::default().constant_bool(false)),
+                json!({ "type": "boolean", "const": false }),
+            ),
+            (
+                to_value(DataSchemaBuilder::default().constant_int(42)),
+                json!({ "type": "integer", "const": 42 }),
+            ),
+            (
+                to_value(DataSchemaBuilder::default().constant_number(0.5)),
+                json!({ "type": "number", "const": 0.5 }),
+            ),
+            (
+                to_value(DataSchemaBuilder::default().constant_string("on")),
+                json!({ "type": "string", "const": "on" }),
+            ),
+        ] {
+            let mut expected = expected;
+            expected["readOnly"] = json!(true);
+            expected["writeOnly"] = json!(false);
+            assert_eq!(value, expected);
+        }
+
+        let data_schema: PartialDataSchema<Nil, Nil, Nil> =
+            PartialDataSchemaBuilder::default().constant_int(42).into();
+        assert_eq!(data_schema.constant, Some(json!(42)));
+        assert_eq!(
+            data_schema.subtype,
+            Some(UncheckedDataSchemaSubtype::Integer(IntegerSchema::default())),
+        );
+    }
+
+    #[test]
+    fn non_finite_constant_number() {
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let data_schema: UncheckedDataSchemaFromOther<Nil> =
+                DataSchemaBuilder::default().constant_number(value).into();
+            assert_eq!(
+                data_schema.check(),
+                Err(Error::DataSchema(DataSchemaError::ConstTypeMismatch)),
+            );
+        }
+
+        let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
+            .constant_number(f64::MAX)
+            .into();
+        assert_eq!(data_schema.check(), Ok(()));
+    }
+
+    #[test]
+    fn large_integer_and_negative_zero_values() {
+        let data_schema: DataSchemaFromOther<Nil> = DataSchemaBuilder::default()
+            .integer()
+            .minimum(0)
+            .default_value(u64::MAX)
+            .try_into()
+            .unwrap();
+        assert_eq!(data_schema.default, Some(json!(u64::MAX)));
+        assert_eq!(data_schema.validate_value(&json!(u64::MAX)), Ok(()));
+
+        let serialized = serde_json::to_string(&data_schema).unwrap();
+        assert!(serialized.contains(r#""default":18446744073709551615"#));
+        let deserialized: DataSchemaFromOther<Nil> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, data_schema);
+        assert_eq!(deserialized.default.unwrap().as_u64(), Some(u64::MAX));
+
+        let data_schema: DataSchemaFromOther<Nil> = DataSchemaBuilder::default()
+            .constant(-0.0)
+            .try_into()
+            .unwrap();
+        let serialized = serde_json::to_string(&data_schema).unwrap();
+        assert!(serialized.contains(r#""const":-0.0"#));
+        let deserialized: DataSchemaFromOther<Nil> = serde_json::from_str(&serialized).unwrap();
+        assert!(deserialized
+            .constant
+            .and_then(|constant| constant.as_f64())
+            .is_some_and(|constant| constant == 0. && constant.is_sign_negative()));
+
+        let data_schema: DataSchemaFromOther<Nil> = DataSchemaBuilder::default()
+            .enumeration(u64::MAX)
+            .enumeration(i64::MIN)
+            .enumeration(-0.0)
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&data_schema).unwrap()["enum"],
+            json!([u64::MAX, i64::MIN, -0.0]),
+        );
+    }
+
     #[test]
     fn enum_simple() {
         let data_schema: DataSchemaFromOther<Nil> = DataSchemaBuilder::default()
@@ -4564,6 +5775,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -4572,12 +5785,77 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: None,
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
 
+    #[test]
+    fn enum_from_iter() {
+        let variants = vec!["hello", "world"];
+        let data_schema: DataSchemaFromOther<Nil> = DataSchemaBuilder::default()
+            .enumeration("first")
+            .enum_from_iter(variants.clone())
+            .enum_from_iter([42])
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            data_schema.enumeration,
+            Some(vec![
+                "first".into(),
+                "hello".into(),
+                "world".into(),
+                42.into()
+            ]),
+        );
+
+        let data_schema: DataSchemaFromOther<Nil> = DataSchemaBuilder::default()
+            .enum_from_iter(variants)
+            .read_only()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            data_schema.enumeration,
+            Some(vec!["hello".into(), "world".into()]),
+        );
+        assert!(data_schema.read_only);
+
+        let data_schema: PartialDataSchema<Nil, Nil, Nil> = PartialDataSchemaBuilder::default()
+            .enum_from_iter(["a", "b"])
+            .into();
+        assert_eq!(data_schema.enumeration, Some(vec!["a".into(), "b".into()]));
+    }
+
+    #[test]
+    fn enum_from_empty_iter() {
+        let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
+            .enum_from_iter(Vec::<Value>::new())
+            .into();
+        assert_eq!(
+            data_schema.check(),
+            Err(Error::DataSchema(DataSchemaError::EmptyEnum)),
+        );
+
+        let data_schema: DataSchemaFromOther<Nil> = DataSchemaBuilder::default()
+            .enum_from_iter(Vec::<Value>::new())
+            .enumeration("first")
+            .try_into()
+            .unwrap();
+        assert_eq!(data_schema.enumeration, Some(vec!["first".into()]));
+
+        let data_schema: PartialDataSchema<Nil, Nil, Nil> = PartialDataSchemaBuilder::default()
+            .enum_from_iter(Vec::<Value>::new())
+            .into();
+        assert_eq!(
+            data_schema.check(),
+            Err(Error::DataSchema(DataSchemaError::EmptyEnum)),
+        );
+    }
+
     #[test]
     fn enum_partial() {
         let data_schema: PartialDataSchema<Nil, Nil, Nil> = PartialDataSchemaBuilder::default()
@@ -4596,6 +5874,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: None,
                 other: Nil,
             }
@@ -4617,6 +5896,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -4625,8 +5906,11 @@ mod tests {
                 read_only: true,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Boolean),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -4648,6 +5932,7 @@ mod tests {
                 read_only: true,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(UncheckedDataSchemaSubtype::Boolean),
                 other: Nil,
             }
@@ -4672,6 +5957,7 @@ mod tests {
                     read_only: true,
                     write_only: false,
                     format: None,
+                    raw_fields: Default::default(),
                     other: Nil,
                     _marker: PhantomData,
                 },
@@ -4697,6 +5983,7 @@ mod tests {
                 read_only: true,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 other: Nil,
                 _marker: PhantomData,
             },
@@ -4718,6 +6005,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -4726,8 +6015,11 @@ mod tests {
                 read_only: false,
                 write_only: true,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Boolean),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -4749,6 +6041,7 @@ mod tests {
                 read_only: false,
                 write_only: true,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(UncheckedDataSchemaSubtype::Boolean),
                 other: Nil,
             }
@@ -4773,6 +6066,7 @@ mod tests {
                     read_only: false,
                     write_only: true,
                     format: None,
+                    raw_fields: Default::default(),
                     other: Nil,
                     _marker: PhantomData,
                 },
@@ -4798,6 +6092,7 @@ mod tests {
                 read_only: false,
                 write_only: true,
                 format: None,
+                raw_fields: Default::default(),
                 other: Nil,
                 _marker: PhantomData,
             },
@@ -4822,7 +6117,9 @@ mod tests {
         assert_eq!(
             data_schema,
             DataSchema {
-                attype: Some(vec!["attype1".to_string(), "attype2".to_string()]),
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
+                attype: Some(vec!["attype1".into(), "attype2".into()]),
                 title: Some("title".to_string()),
                 titles: Some(
                     [("en", "title_en"), ("it", "title_it")]
@@ -4845,8 +6142,11 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: Some("format".to_string()),
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Null),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -4870,7 +6170,9 @@ mod tests {
         assert_eq!(
             data_schema,
             DataSchema {
-                attype: Some(vec!["attype".to_string()]),
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
+                attype: Some(vec!["attype".into()]),
                 title: Some("title".to_string()),
                 titles: Some(
                     [("en", "title_en"), ("it", "title_it")]
@@ -4893,8 +6195,11 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: Some("format".to_string()),
+                raw_fields: Default::default(),
                 subtype: None,
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -4919,6 +6224,8 @@ mod tests {
                 titles: None,
                 description: Some("description".to_string()),
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: Some(json! { ["hello", "world"]}),
                 unit: None,
@@ -4927,8 +6234,11 @@ mod tests {
                 read_only: true,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: None,
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -4949,6 +6259,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -4957,6 +6269,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Array(ArraySchema {
                     items: Some(BoxedElemOrVec::Vec(vec![
                         DataSchema {
@@ -4965,6 +6278,8 @@ mod tests {
                             titles: None,
                             description: None,
                             descriptions: None,
+                            deprecated: Default::default(),
+                            deprecation_note: Default::default(),
                             constant: Some("hello".into()),
                             default: None,
                             unit: None,
@@ -4973,8 +6288,11 @@ mod tests {
                             read_only: true,
                             write_only: false,
                             format: None,
+                            raw_fields: Default::default(),
                             subtype: None,
                             other: Nil,
+                            #[cfg(feature = "preserve-unknown")]
+                            unknown: Default::default(),
                         },
                         DataSchema {
                             attype: None,
@@ -4982,6 +6300,8 @@ mod tests {
                             titles: None,
                             description: None,
                             descriptions: None,
+                            deprecated: Default::default(),
+                            deprecation_note: Default::default(),
                             constant: None,
                             default: None,
                             unit: None,
@@ -4990,8 +6310,11 @@ mod tests {
                             read_only: false,
                             write_only: false,
                             format: None,
+                            raw_fields: Default::default(),
                             subtype: Some(DataSchemaSubtype::Boolean),
                             other: Nil,
+                            #[cfg(feature = "preserve-unknown")]
+                            unknown: Default::default(),
                         },
                     ])),
                     min_items: None,
@@ -4999,6 +6322,8 @@ mod tests {
                     other: Nil,
                 })),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -5020,6 +6345,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -5028,6 +6355,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Array(ArraySchema {
                     items: Some(BoxedElemOrVec::Elem(Box::new(DataSchema {
                         attype: None,
@@ -5035,6 +6363,8 @@ mod tests {
                         titles: None,
                         description: None,
                         descriptions: None,
+                        deprecated: Default::default(),
+                        deprecation_note: Default::default(),
                         constant: Some("hello".into()),
                         default: None,
                         unit: None,
@@ -5043,19 +6373,27 @@ mod tests {
                         read_only: true,
                         write_only: false,
                         format: None,
+                        raw_fields: Default::default(),
                         subtype: None,
                         other: Nil,
+                        #[cfg(feature = "preserve-unknown")]
+                        unknown: Default::default(),
                     },))),
                     min_items: Some(0),
                     max_items: Some(5),
                     other: Nil,
                 })),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
 
+    // The fallible conversion checks that the extension type is still inferred through
+    // `try_into`, as in the downstream code.
     #[test]
+    #[allow(clippy::unnecessary_fallible_conversions)]
     fn tuple_partial_with_content() {
         let data_schema: PartialDataSchema<Nil, Nil, Nil> = PartialDataSchemaBuilder::default()
             .tuple()
@@ -5074,6 +6412,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(UncheckedDataSchemaSubtype::Array(UncheckedArraySchema {
                     items: Some(BoxedElemOrVec::Vec(vec![
                         UncheckedDataSchema {
@@ -5082,6 +6421,8 @@ mod tests {
                             titles: None,
                             description: None,
                             descriptions: None,
+                            deprecated: Default::default(),
+                            deprecation_note: Default::default(),
                             constant: Some("hello".into()),
                             default: None,
                             unit: None,
@@ -5090,8 +6431,11 @@ mod tests {
                             read_only: true,
                             write_only: false,
                             format: None,
+                            raw_fields: Default::default(),
                             subtype: None,
                             other: Nil,
+                            #[cfg(feature = "preserve-unknown")]
+                            unknown: Default::default(),
                         },
                         UncheckedDataSchema {
                             attype: None,
@@ -5099,6 +6443,8 @@ mod tests {
                             titles: None,
                             description: None,
                             descriptions: None,
+                            deprecated: Default::default(),
+                            deprecation_note: Default::default(),
                             constant: None,
                             default: None,
                             unit: None,
@@ -5107,8 +6453,11 @@ mod tests {
                             read_only: false,
                             write_only: false,
                             format: None,
+                            raw_fields: Default::default(),
                             subtype: Some(UncheckedDataSchemaSubtype::Boolean),
                             other: Nil,
+                            #[cfg(feature = "preserve-unknown")]
+                            unknown: Default::default(),
                         },
                     ])),
                     min_items: None,
@@ -5120,7 +6469,10 @@ mod tests {
         );
     }
 
+    // The fallible conversion checks that the extension type is still inferred through
+    // `try_into`, as in the downstream code.
     #[test]
+    #[allow(clippy::unnecessary_fallible_conversions)]
     fn vec_partial_with_content() {
         let data_schema: PartialDataSchema<Nil, Nil, Nil> = PartialDataSchemaBuilder::default()
             .vec()
@@ -5140,6 +6492,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(UncheckedDataSchemaSubtype::Array(UncheckedArraySchema {
                     items: Some(BoxedElemOrVec::Elem(Box::new(UncheckedDataSchema {
                         attype: None,
@@ -5147,6 +6500,8 @@ mod tests {
                         titles: None,
                         description: None,
                         descriptions: None,
+                        deprecated: Default::default(),
+                        deprecation_note: Default::default(),
                         constant: Some("hello".into()),
                         default: None,
                         unit: None,
@@ -5155,8 +6510,11 @@ mod tests {
                         read_only: true,
                         write_only: false,
                         format: None,
+                        raw_fields: Default::default(),
                         subtype: None,
                         other: Nil,
+                        #[cfg(feature = "preserve-unknown")]
+                        unknown: Default::default(),
                     },))),
                     min_items: Some(0),
                     max_items: Some(5),
@@ -5183,6 +6541,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -5191,6 +6551,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Object(ObjectSchema {
                     properties: Some(
                         [
@@ -5202,6 +6563,8 @@ mod tests {
                                     titles: None,
                                     description: None,
                                     descriptions: None,
+                                    deprecated: Default::default(),
+                                    deprecation_note: Default::default(),
                                     constant: None,
                                     default: None,
                                     unit: None,
@@ -5210,8 +6573,11 @@ mod tests {
                                     read_only: false,
                                     write_only: false,
                                     format: None,
+                                    raw_fields: Default::default(),
                                     subtype: Some(DataSchemaSubtype::Boolean),
                                     other: Nil,
+                                    #[cfg(feature = "preserve-unknown")]
+                                    unknown: Default::default(),
                                 }
                             ),
                             (
@@ -5222,6 +6588,8 @@ mod tests {
                                     titles: None,
                                     description: None,
                                     descriptions: None,
+                                    deprecated: Default::default(),
+                                    deprecation_note: Default::default(),
                                     constant: None,
                                     default: None,
                                     unit: None,
@@ -5230,12 +6598,15 @@ mod tests {
                                     read_only: false,
                                     write_only: false,
                                     format: None,
+                                    raw_fields: Default::default(),
                                     subtype: Some(DataSchemaSubtype::Number(NumberSchema {
                                         maximum: None,
                                         minimum: None,
                                         multiple_of: None,
                                     })),
                                     other: Nil,
+                                    #[cfg(feature = "preserve-unknown")]
+                                    unknown: Default::default(),
                                 }
                             )
                         ]
@@ -5246,11 +6617,62 @@ mod tests {
                     other: Nil,
                 })),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
 
     #[test]
+    fn object_required_all_and_none() {
+        let required = |data_schema: DataSchemaFromOther<Nil>| match data_schema.subtype {
+            Some(DataSchemaSubtype::Object(object)) => object.required,
+            _ => panic!("expected an object data schema"),
+        };
+
+        let data_schema = DataSchemaBuilder::default()
+            .object()
+            .property("a", false, |b| b.finish_extend().bool())
+            .property("b", true, |b| b.finish_extend().number())
+            .property("c", false, |b| b.finish_extend().null())
+            .required_all()
+            .property("d", false, |b| b.finish_extend().integer())
+            .property("e", true, |b| b.finish_extend().string())
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            required(data_schema),
+            Some(vec![
+                "a".to_string(),
+                "b".to_string(),
+                "c".to_string(),
+                "e".to_string(),
+            ]),
+        );
+
+        let data_schema = DataSchemaBuilder::default()
+            .object()
+            .property("a", true, |b| b.finish_extend().bool())
+            .read_only()
+            .required_none()
+            .property("b", true, |b| b.finish_extend().number())
+            .try_into()
+            .unwrap();
+        assert_eq!(required(data_schema), Some(vec!["b".to_string()]));
+
+        let data_schema = DataSchemaBuilder::default()
+            .object()
+            .property("a", true, |b| b.finish_extend().bool())
+            .required_none()
+            .try_into()
+            .unwrap();
+        assert_eq!(required(data_schema), None);
+    }
+
+    // The fallible conversion checks that the extension type is still inferred through
+    // `try_into`, as in the downstream code.
+    #[test]
+    #[allow(clippy::unnecessary_fallible_conversions)]
     fn object_partial_with_content() {
         let data_schema: PartialDataSchema<Nil, Nil, Nil> = PartialDataSchemaBuilder::default()
             .object()
@@ -5269,6 +6691,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(UncheckedDataSchemaSubtype::Object(UncheckedObjectSchema {
                     properties: Some(
                         [
@@ -5280,6 +6703,8 @@ mod tests {
                                     titles: None,
                                     description: None,
                                     descriptions: None,
+                                    deprecated: Default::default(),
+                                    deprecation_note: Default::default(),
                                     constant: None,
                                     default: None,
                                     unit: None,
@@ -5288,8 +6713,11 @@ mod tests {
                                     read_only: false,
                                     write_only: false,
                                     format: None,
+                                    raw_fields: Default::default(),
                                     subtype: Some(UncheckedDataSchemaSubtype::Boolean),
                                     other: Nil,
+                                    #[cfg(feature = "preserve-unknown")]
+                                    unknown: Default::default(),
                                 }
                             ),
                             (
@@ -5300,6 +6728,8 @@ mod tests {
                                     titles: None,
                                     description: None,
                                     descriptions: None,
+                                    deprecated: Default::default(),
+                                    deprecation_note: Default::default(),
                                     constant: None,
                                     default: None,
                                     unit: None,
@@ -5308,6 +6738,7 @@ mod tests {
                                     read_only: false,
                                     write_only: false,
                                     format: None,
+                                    raw_fields: Default::default(),
                                     subtype: Some(UncheckedDataSchemaSubtype::Number(
                                         NumberSchema {
                                             maximum: None,
@@ -5316,6 +6747,8 @@ mod tests {
                                         }
                                     )),
                                     other: Nil,
+                                    #[cfg(feature = "preserve-unknown")]
+                                    unknown: Default::default(),
                                 }
                             )
                         ]
@@ -5347,6 +6780,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -5355,12 +6790,15 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Integer(IntegerSchema {
                     maximum: Some(Maximum::Inclusive(5)),
                     minimum: Some(Minimum::Exclusive(10)),
                     multiple_of: Some(NonZeroU64::new(2).unwrap()),
                 })),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             },
         );
 
@@ -5378,6 +6816,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -5386,12 +6826,15 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Integer(IntegerSchema {
                     maximum: Some(Maximum::Exclusive(5)),
                     minimum: Some(Minimum::Inclusive(10)),
                     multiple_of: None,
                 })),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             },
         );
     }
@@ -5413,6 +6856,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -5421,12 +6866,15 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Number(NumberSchema {
                     maximum: Some(Maximum::Inclusive(5.)),
                     minimum: Some(Minimum::Exclusive(10.)),
                     multiple_of: Some(2.),
                 })),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             },
         );
 
@@ -5445,6 +6893,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -5453,12 +6903,15 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Number(NumberSchema {
                     maximum: Some(Maximum::Exclusive(5.)),
                     minimum: Some(Minimum::Inclusive(10.)),
                     multiple_of: Some(2.),
                 })),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             },
         );
     }
@@ -5482,6 +6935,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -5490,6 +6945,7 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::String(StringSchema {
                     min_length: Some(5),
                     max_length: Some(32),
@@ -5498,6 +6954,8 @@ mod tests {
                     content_media_type: Some("content media type".to_string()),
                 })),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             },
         );
     }
@@ -5513,11 +6971,14 @@ mod tests {
         assert_eq!(
             data_schema,
             DataSchema {
+                raw_fields: Default::default(),
                 attype: None,
                 title: None,
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -5528,6 +6989,8 @@ mod tests {
                         titles: None,
                         description: None,
                         descriptions: None,
+                        deprecated: Default::default(),
+                        deprecation_note: Default::default(),
                         constant: None,
                         default: None,
                         unit: None,
@@ -5536,12 +6999,15 @@ mod tests {
                         read_only: false,
                         write_only: false,
                         format: None,
+                        raw_fields: Default::default(),
                         subtype: Some(DataSchemaSubtype::Number(NumberSchema {
                             maximum: None,
                             minimum: None,
                             multiple_of: None,
                         })),
                         other: Nil,
+                        #[cfg(feature = "preserve-unknown")]
+                        unknown: Default::default(),
                     },
                     DataSchema {
                         attype: None,
@@ -5549,6 +7015,8 @@ mod tests {
                         titles: None,
                         description: None,
                         descriptions: None,
+                        deprecated: Default::default(),
+                        deprecation_note: Default::default(),
                         constant: None,
                         default: None,
                         unit: None,
@@ -5557,12 +7025,15 @@ mod tests {
                         read_only: false,
                         write_only: false,
                         format: None,
+                        raw_fields: Default::default(),
                         subtype: Some(DataSchemaSubtype::Integer(IntegerSchema {
                             maximum: None,
                             minimum: None,
                             multiple_of: None,
                         })),
                         other: Nil,
+                        #[cfg(feature = "preserve-unknown")]
+                        unknown: Default::default(),
                     },
                     DataSchema {
                         attype: None,
@@ -5570,6 +7041,8 @@ mod tests {
                         titles: None,
                         description: None,
                         descriptions: None,
+                        deprecated: Default::default(),
+                        deprecation_note: Default::default(),
                         constant: None,
                         default: None,
                         unit: None,
@@ -5578,6 +7051,7 @@ mod tests {
                         read_only: false,
                         write_only: false,
                         format: None,
+                        raw_fields: Default::default(),
                         subtype: Some(DataSchemaSubtype::String(StringSchema {
                             min_length: None,
                             max_length: None,
@@ -5586,6 +7060,8 @@ mod tests {
                             content_media_type: None,
                         })),
                         other: Nil,
+                        #[cfg(feature = "preserve-unknown")]
+                        unknown: Default::default(),
                     },
                 ]),
                 enumeration: None,
@@ -5594,10 +7070,109 @@ mod tests {
                 format: None,
                 subtype: None,
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             },
         );
     }
 
+    #[test]
+    fn one_of_call_orders() {
+        fn expected(
+            title: Option<&str>,
+            read_only: bool,
+            write_only: bool,
+        ) -> DataSchemaFromOther<Nil> {
+            let variant = |subtype| DataSchema {
+                attype: None,
+                title: None,
+                titles: None,
+                description: None,
+                descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
+                constant: None,
+                default: None,
+                unit: None,
+                one_of: None,
+                enumeration: None,
+                read_only: false,
+                write_only: false,
+                format: None,
+                raw_fields: Default::default(),
+                subtype: Some(subtype),
+                other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
+            };
+
+            DataSchema {
+                attype: None,
+                title: title.map(String::from),
+                titles: None,
+                description: None,
+                descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
+                constant: None,
+                default: None,
+                unit: None,
+                one_of: Some(vec![
+                    variant(DataSchemaSubtype::Boolean),
+                    variant(DataSchemaSubtype::Null),
+                ]),
+                enumeration: None,
+                read_only,
+                write_only,
+                format: None,
+                raw_fields: Default::default(),
+                subtype: None,
+                other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
+            }
+        }
+
+        macro_rules! check_orders {
+            ($($(.$call:ident($($arg:expr)?))+ => $expected:expr;)+) => {
+                $(
+                    let data_schema: DataSchemaFromOther<Nil> = DataSchemaBuilder::default()
+                        $(.$call($($arg)?))+
+                        .try_into()
+                        .unwrap();
+                    assert_eq!(
+                        data_schema,
+                        $expected,
+                        "{}",
+                        stringify!($(.$call($($arg)?))+),
+                    );
+
+                )+
+            };
+        }
+
+        let boolean = |b: DataSchemaBuilder<_, _, _, _>| b.finish_extend().bool();
+        let null = |b: DataSchemaBuilder<_, _, _, _>| b.finish_extend().null();
+
+        check_orders! {
+            .one_of(boolean).one_of(null) => expected(None, false, false);
+            .read_only().one_of(boolean).one_of(null) => expected(None, true, false);
+            .one_of(boolean).read_only().one_of(null) => expected(None, true, false);
+            .one_of(boolean).one_of(null).read_only() => expected(None, true, false);
+            .write_only().one_of(boolean).one_of(null) => expected(None, false, true);
+            .one_of(boolean).write_only().one_of(null) => expected(None, false, true);
+            .one_of(boolean).one_of(null).write_only() => expected(None, false, true);
+            .title("title").one_of(boolean).one_of(null) => expected(Some("title"), false, false);
+            .one_of(boolean).title("title").one_of(null) => expected(Some("title"), false, false);
+            .one_of(boolean).one_of(null).title("title") => expected(Some("title"), false, false);
+            .title("title").read_only().one_of(boolean).one_of(null) => expected(Some("title"), true, false);
+            .read_only().title("title").one_of(boolean).one_of(null) => expected(Some("title"), true, false);
+            .one_of(boolean).read_only().title("title").one_of(null) => expected(Some("title"), true, false);
+            .one_of(boolean).title("title").write_only().one_of(null) => expected(Some("title"), false, true);
+            .one_of(boolean).one_of(null).write_only().title("title") => expected(Some("title"), false, true);
+        }
+    }
+
     #[test]
     fn one_of_nested() {
         let data_schema: DataSchemaFromOther<Nil> = DataSchemaBuilder::default()
@@ -5617,6 +7192,8 @@ mod tests {
                 titles: None,
                 description: None,
                 descriptions: None,
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: None,
                 default: None,
                 unit: None,
@@ -5625,16 +7202,20 @@ mod tests {
                 read_only: false,
                 write_only: false,
                 format: None,
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Object(ObjectSchema {
                     properties: Some(
                         [(
                             "hello".to_string(),
                             DataSchema {
+                                raw_fields: Default::default(),
                                 attype: None,
                                 title: None,
                                 titles: None,
                                 description: None,
                                 descriptions: None,
+                                deprecated: Default::default(),
+                                deprecation_note: Default::default(),
                                 constant: None,
                                 default: None,
                                 unit: None,
@@ -5645,6 +7226,8 @@ mod tests {
                                         titles: None,
                                         description: None,
                                         descriptions: None,
+                                        deprecated: Default::default(),
+                                        deprecation_note: Default::default(),
                                         constant: None,
                                         default: None,
                                         unit: None,
@@ -5653,6 +7236,7 @@ mod tests {
                                         read_only: false,
                                         write_only: false,
                                         format: None,
+                                        raw_fields: Default::default(),
                                         subtype: Some(DataSchemaSubtype::String(StringSchema {
                                             min_length: None,
                                             max_length: None,
@@ -5661,6 +7245,8 @@ mod tests {
                                             content_media_type: None,
                                         })),
                                         other: Nil,
+                                        #[cfg(feature = "preserve-unknown")]
+                                        unknown: Default::default(),
                                     },
                                     DataSchema {
                                         attype: None,
@@ -5668,6 +7254,8 @@ mod tests {
                                         titles: None,
                                         description: None,
                                         descriptions: None,
+                                        deprecated: Default::default(),
+                                        deprecation_note: Default::default(),
                                         constant: None,
                                         default: None,
                                         unit: None,
@@ -5676,12 +7264,15 @@ mod tests {
                                         read_only: false,
                                         write_only: false,
                                         format: None,
+                                        raw_fields: Default::default(),
                                         subtype: Some(DataSchemaSubtype::Integer(IntegerSchema {
                                             maximum: None,
                                             minimum: None,
                                             multiple_of: None,
                                         })),
                                         other: Nil,
+                                        #[cfg(feature = "preserve-unknown")]
+                                        unknown: Default::default(),
                                     },
                                 ]),
                                 enumeration: None,
@@ -5690,6 +7281,8 @@ mod tests {
                                 format: None,
                                 subtype: None,
                                 other: Nil,
+                                #[cfg(feature = "preserve-unknown")]
+                                unknown: Default::default(),
                             }
                         ),]
                         .into_iter()
@@ -5699,6 +7292,8 @@ mod tests {
                     other: Nil,
                 })),
                 other: Nil,
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             },
         );
     }
@@ -5740,6 +7335,64 @@ mod tests {
         assert!(data_schema.check().is_ok());
     }
 
+    #[test]
+    fn check_const_enum_subtype_combinations() {
+        let with = |constant: Option<Value>, enumeration: Option<Vec<Value>>, subtype: bool| {
+            let mut data_schema: UncheckedDataSchemaFromOther<Nil> =
+                DataSchemaBuilder::default().string().into();
+            if !subtype {
+                data_schema.subtype = None;
+            }
+            data_schema.constant = constant;
+            data_schema.enumeration = enumeration;
+            data_schema
+        };
+
+        let cases = [
+            (Some(json!("a")), None, false, Ok(())),
+            (None, Some(vec![json!("a")]), false, Ok(())),
+            (Some(json!("a")), None, true, Ok(())),
+            (None, Some(vec![json!("a"), json!("b")]), true, Ok(())),
+            (
+                Some(json!("a")),
+                Some(vec![json!("a")]),
+                false,
+                Err(Error::DataSchema(DataSchemaError::ConstAndEnum)),
+            ),
+            (
+                Some(json!("a")),
+                Some(vec![json!("b")]),
+                true,
+                Err(Error::DataSchema(DataSchemaError::ConstAndEnum)),
+            ),
+        ];
+
+        for (constant, enumeration, subtype, expected) in cases {
+            let data_schema = with(constant, enumeration, subtype);
+            assert_eq!(data_schema.check(), expected);
+        }
+
+        let mut data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
+            .object()
+            .property("a", false, |b| b.finish_extend().constant("a"))
+            .into();
+        let Some(UncheckedDataSchemaSubtype::Object(object)) = &mut data_schema.subtype else {
+            unreachable!()
+        };
+        object
+            .properties
+            .as_mut()
+            .unwrap()
+            .get_mut("a")
+            .unwrap()
+            .enumeration = Some(vec![json!("a")]);
+
+        assert_eq!(
+            data_schema.check(),
+            Err(Error::DataSchema(DataSchemaError::ConstAndEnum))
+        );
+    }
+
     #[test]
     fn check_invalid_data_schema() {
         let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
@@ -5762,7 +7415,10 @@ mod tests {
             })
             .into();
 
-        assert_eq!(data_schema.check().unwrap_err(), Error::InvalidMinMax);
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::InvalidMinMax)
+        );
 
         let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
             .one_of(|b| {
@@ -5784,7 +7440,10 @@ mod tests {
             })
             .into();
 
-        assert_eq!(data_schema.check().unwrap_err(), Error::InvalidMinMax);
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::InvalidMinMax)
+        );
 
         let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
             .one_of(|b| {
@@ -5806,7 +7465,10 @@ mod tests {
             })
             .into();
 
-        assert_eq!(data_schema.check().unwrap_err(), Error::InvalidMinMax);
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::InvalidMinMax)
+        );
 
         let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
             .one_of(|b| {
@@ -5828,7 +7490,10 @@ mod tests {
             })
             .into();
 
-        assert_eq!(data_schema.check().unwrap_err(), Error::InvalidMinMax);
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::InvalidMinMax)
+        );
 
         let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
             .one_of(|b| {
@@ -5850,7 +7515,10 @@ mod tests {
             })
             .into();
 
-        assert_eq!(data_schema.check().unwrap_err(), Error::NanMinMax);
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::NanMinMax)
+        );
 
         let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
             .one_of(|b| {
@@ -5872,7 +7540,10 @@ mod tests {
             })
             .into();
 
-        assert_eq!(data_schema.check().unwrap_err(), Error::NanMinMax);
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::NanMinMax)
+        );
 
         let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
             .one_of(|b| {
@@ -5894,7 +7565,10 @@ mod tests {
             })
             .into();
 
-        assert_eq!(data_schema.check().unwrap_err(), Error::InvalidMinMax);
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::InvalidMinMax)
+        );
 
         let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
             .one_of(|b| {
@@ -5921,7 +7595,10 @@ mod tests {
             })
             .into();
 
-        assert_eq!(data_schema.check().unwrap_err(), Error::InvalidMinMax);
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::InvalidMinMax)
+        );
 
         let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
             .one_of(|b| {
@@ -5947,7 +7624,10 @@ mod tests {
             })
             .into();
 
-        assert_eq!(data_schema.check().unwrap_err(), Error::InvalidMinMax);
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::InvalidMinMax)
+        );
 
         let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
             .one_of(|b| {
@@ -5972,7 +7652,10 @@ mod tests {
             })
             .into();
 
-        assert_eq!(data_schema.check().unwrap_err(), Error::InvalidMinMax);
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::InvalidMinMax)
+        );
     }
 
     #[test]
@@ -5983,7 +7666,10 @@ mod tests {
             .maximum(2)
             .into();
 
-        assert_eq!(data_schema.check().unwrap_err(), Error::InvalidMinMax);
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::InvalidMinMax)
+        );
 
         let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
             .integer()
@@ -5991,7 +7677,10 @@ mod tests {
             .exclusive_maximum(2)
             .into();
 
-        assert_eq!(data_schema.check().unwrap_err(), Error::InvalidMinMax);
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::InvalidMinMax)
+        );
 
         let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
             .number()
@@ -5999,7 +7688,10 @@ mod tests {
             .maximum(2.)
             .into();
 
-        assert_eq!(data_schema.check().unwrap_err(), Error::InvalidMinMax);
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::InvalidMinMax)
+        );
 
         let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
             .number()
@@ -6007,7 +7699,26 @@ mod tests {
             .exclusive_maximum(2.)
             .into();
 
-        assert_eq!(data_schema.check().unwrap_err(), Error::InvalidMinMax);
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::InvalidMinMax)
+        );
+
+        // Mixed bounds delimiting a non-empty range are not comparable, but they are valid.
+        let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
+            .number()
+            .exclusive_minimum(1.)
+            .maximum(2.)
+            .into();
+
+        assert_eq!(data_schema.check(), Ok(()));
+        let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
+            .integer()
+            .minimum(1)
+            .exclusive_maximum(2)
+            .into();
+
+        assert_eq!(data_schema.check(), Ok(()));
     }
 
     #[test]
@@ -6017,14 +7728,68 @@ mod tests {
             .set_item(|b| b.finish_extend().number().multiple_of(0.))
             .into();
 
-        assert_eq!(data_schema.check().unwrap_err(), Error::InvalidMultipleOf);
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::InvalidMultipleOf)
+        );
 
         let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
             .vec()
             .set_item(|b| b.finish_extend().number().multiple_of(-2.))
             .into();
 
-        assert_eq!(data_schema.check().unwrap_err(), Error::InvalidMultipleOf);
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::InvalidMultipleOf)
+        );
+    }
+
+    #[test]
+    fn check_non_finite_data_schema_bounds() {
+        let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
+            .number()
+            .minimum(0.)
+            .maximum(f64::INFINITY)
+            .into();
+
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::NonFiniteBound)
+        );
+
+        let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
+            .vec()
+            .set_item(|b| {
+                b.finish_extend()
+                    .number()
+                    .exclusive_minimum(f64::NEG_INFINITY)
+            })
+            .into();
+
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::NonFiniteBound)
+        );
+
+        let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
+            .number()
+            .multiple_of(f64::INFINITY)
+            .into();
+
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::NonFiniteBound)
+        );
+
+        let data_schema: UncheckedDataSchemaFromOther<Nil> = DataSchemaBuilder::default()
+            .number()
+            .multiple_of(f64::NAN)
+            .into();
+
+        assert_eq!(
+            data_schema.check().unwrap_err(),
+            Error::DataSchema(DataSchemaError::NonFiniteBound)
+        );
     }
 
     #[test]
@@ -6154,6 +7919,8 @@ mod tests {
                 titles: Default::default(),
                 description: Default::default(),
                 descriptions: Default::default(),
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: Default::default(),
                 default: Default::default(),
                 unit: Default::default(),
@@ -6162,11 +7929,45 @@ mod tests {
                 read_only: Default::default(),
                 write_only: Default::default(),
                 format: Default::default(),
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Null),
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
 
+    #[test]
+    fn finish_extend_as_is_in_generic_fragment() {
+        fn fragment<DS, AS, OS>(
+            builder: DataSchemaBuilder<DS, AS, OS, ToExtend>,
+        ) -> NumberDataSchemaBuilder<DataSchemaBuilder<DS, AS, OS, Extended>> {
+            builder.finish_extend_as_is().title("fragment").number()
+        }
+
+        let data_schema: DataSchemaFromOther<Cons<ThingExtB, Cons<ThingExtA, Nil>>> = fragment(
+            DataSchemaBuilder::<Cons<ThingExtB, Cons<ThingExtA, Nil>>, _, _, _>::empty()
+                .ext(DataSchemaExtA { a: A(1) })
+                .ext(DataSchemaExtB {
+                    d: B("hello".to_string()),
+                }),
+        )
+        .try_into()
+        .unwrap();
+
+        assert_eq!(data_schema.title.as_deref(), Some("fragment"));
+        assert_eq!(
+            data_schema.other,
+            Nil::cons(DataSchemaExtA { a: A(1) }).cons(DataSchemaExtB {
+                d: B("hello".to_string())
+            }),
+        );
+        assert!(matches!(
+            data_schema.subtype,
+            Some(DataSchemaSubtype::Number(_))
+        ));
+    }
+
     #[test]
     fn extend_data_schema_with_vec() {
         let data_schema: DataSchemaFromOther<Cons<ThingExtB, Cons<ThingExtA, Nil>>> =
@@ -6203,6 +8004,8 @@ mod tests {
                 titles: Default::default(),
                 description: Default::default(),
                 descriptions: Default::default(),
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: Default::default(),
                 default: Default::default(),
                 unit: Default::default(),
@@ -6211,6 +8014,7 @@ mod tests {
                 read_only: Default::default(),
                 write_only: Default::default(),
                 format: Default::default(),
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Array(ArraySchema {
                     other: Nil::cons(ArraySchemaExtA { b: A(2) }).cons(ArraySchemaExtB {
                         e: B("world".to_string())
@@ -6219,6 +8023,8 @@ mod tests {
                     items: Default::default(),
                     min_items: Default::default(),
                 })),
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -6258,6 +8064,8 @@ mod tests {
                 titles: Default::default(),
                 description: Default::default(),
                 descriptions: Default::default(),
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
                 constant: Default::default(),
                 default: Default::default(),
                 unit: Default::default(),
@@ -6266,6 +8074,7 @@ mod tests {
                 read_only: Default::default(),
                 write_only: Default::default(),
                 format: Default::default(),
+                raw_fields: Default::default(),
                 subtype: Some(DataSchemaSubtype::Array(ArraySchema {
                     other: Nil::cons(ArraySchemaExtA { b: A(2) }).cons(ArraySchemaExtB {
                         e: B("world".to_string())
@@ -6274,6 +8083,8 @@ mod tests {
                     max_items: Default::default(),
                     min_items: Default::default(),
                 })),
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -6313,6 +8124,9 @@ mod tests {
         assert_eq!(
             data_schema,
             DataSchema {
+                deprecated: Default::default(),
+                deprecation_note: Default::default(),
+                raw_fields: Default::default(),
                 title: Some("title".to_string()),
                 other: Nil::cons(DataSchemaExtA { a: A(1) }).cons(DataSchemaExtB {
                     d: B("hello".to_string())
@@ -6334,6 +8148,8 @@ mod tests {
                                 titles: Default::default(),
                                 description: Default::default(),
                                 descriptions: Default::default(),
+                                deprecated: Default::default(),
+                                deprecation_note: Default::default(),
                                 constant: Default::default(),
                                 default: Default::default(),
                                 unit: Default::default(),
@@ -6342,6 +8158,9 @@ mod tests {
                                 read_only: Default::default(),
                                 write_only: Default::default(),
                                 format: Default::default(),
+                                raw_fields: Default::default(),
+                                #[cfg(feature = "preserve-unknown")]
+                                unknown: Default::default(),
                             }
                         )]
                         .into_iter()
@@ -6361,6 +8180,8 @@ mod tests {
                 read_only: Default::default(),
                 write_only: Default::default(),
                 format: Default::default(),
+                #[cfg(feature = "preserve-unknown")]
+                unknown: Default::default(),
             }
         );
     }
@@ -6491,7 +8312,12 @@ mod tests {
 
         assert_eq!(
             ArraySchema::try_from(data_schema).unwrap_err(),
-            Error::InvalidLanguageTag("e1n".to_string()),
+            Error::AtPath {
+                path: "/items/1/descriptions".to_string(),
+                source: Box::new(Error::Document(DocumentError::InvalidLanguageTag(
+                    "e1n".to_string()
+                ))),
+            },
         );
     }
 
@@ -6569,7 +8395,12 @@ mod tests {
 
         assert_eq!(
             ArraySchema::try_from(data_schema).unwrap_err(),
-            Error::InvalidLanguageTag("e1n".to_string()),
+            Error::AtPath {
+                path: "/items/descriptions".to_string(),
+                source: Box::new(Error::Document(DocumentError::InvalidLanguageTag(
+                    "e1n".to_string()
+                ))),
+            },
         );
     }
 
@@ -6679,6 +8510,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn nested_error_path() {
+        let item = UncheckedDataSchema::<Nil, Nil, Nil> {
+            titles: Some({
+                let mut multilang = MultiLanguageBuilder::default();
+                multilang.add("e!n", "title");
+                multilang
+            }),
+            subtype: Some(UncheckedDataSchemaSubtype::String(StringSchema::default())),
+            ..Default::default()
+        };
+        let foo = UncheckedDataSchema {
+            subtype: Some(UncheckedDataSchemaSubtype::Array(UncheckedArraySchema {
+                items: Some(BoxedElemOrVec::Elem(Box::new(item))),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        let data_schema = UncheckedDataSchema {
+            one_of: Some(vec![UncheckedDataSchema {
+                subtype: Some(UncheckedDataSchemaSubtype::Object(UncheckedObjectSchema {
+                    properties: Some([("foo".to_string(), foo)].into_iter().collect()),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        let error = DataSchema::try_from(data_schema).unwrap_err();
+        assert!(error.to_string().contains("/properties/foo/items"));
+        assert_eq!(
+            error.to_string(),
+            "Invalid member at /oneOf/0/properties/foo/items/titles: \
+             Invalid language tag \"e!n\"",
+        );
+
+        let source = core::error::Error::source(&error).unwrap();
+        assert_eq!(source.to_string(), "Invalid language tag \"e!n\"");
+    }
+
     #[test]
     fn invalid_unchecked_object_data_schema() {
         let data_schema = UncheckedObjectSchema::<Nil, Nil, Nil> {
@@ -6729,7 +8601,12 @@ mod tests {
 
         assert_eq!(
             ObjectSchema::try_from(data_schema).unwrap_err(),
-            Error::InvalidLanguageTag("i1t".to_string()),
+            Error::AtPath {
+                path: "/properties/data2/descriptions".to_string(),
+                source: Box::new(Error::Document(DocumentError::InvalidLanguageTag(
+                    "i1t".to_string()
+                ))),
+            },
         )
     }
 
@@ -6765,7 +8642,7 @@ mod tests {
         assert_eq!(
             DataSchema::try_from(data_schema).unwrap(),
             DataSchema {
-                attype: Some(vec!["attype1".to_string(), "attype2".to_string()]),
+                attype: Some(vec!["attype1".into(), "attype2".into()]),
                 title: Some("title".to_string()),
                 titles: Some(
                     [
@@ -6828,7 +8705,102 @@ mod tests {
 
         assert_eq!(
             DataSchema::try_from(data_schema).unwrap_err(),
-            Error::InvalidLanguageTag("i1t".to_string()),
+            Error::AtPath {
+                path: "/descriptions".to_string(),
+                source: Box::new(Error::Document(DocumentError::InvalidLanguageTag(
+                    "i1t".to_string()
+                ))),
+            },
+        );
+    }
+
+    #[test]
+    fn shallow_debug() {
+        let builder = DataSchemaBuilder::<Nil, Nil, Nil, _>::default()
+            .object()
+            .property("level1", false, |b| {
+                b.finish_extend().one_of(|b| {
+                    b.finish_extend().object().property("level3", false, |b| {
+                        b.finish_extend().vec().set_item(|b| {
+                            b.finish_extend()
+                                .title("level5 {\"quoted\" [brackets]}")
+                                .integer()
+                        })
+                    })
+                })
+            });
+
+        let full = format!("{builder:?}");
+        let shallow = format!("{:?}", builder.debug_shallow(2));
+        assert!(full.contains("level5"));
+        assert!(!shallow.contains("level5"));
+        assert!(shallow.contains('…'));
+        assert!(shallow.len() * 2 < full.len());
+        assert!(shallow.starts_with(
+            "ObjectDataSchemaBuilder { inner: DataSchemaBuilder { partial: \
+             PartialDataSchemaBuilder {…}, "
+        ));
+
+        let pretty_full = format!("{builder:#?}");
+        let pretty_shallow = format!("{:#?}", builder.debug_shallow(2));
+        assert!(pretty_shallow.len() * 2 < pretty_full.len());
+
+        assert_eq!(
+            format!("{:?}", builder.debug_shallow(0)),
+            "ObjectDataSchemaBuilder {…}",
+        );
+        assert_eq!(format!("{:?}", builder.debug_shallow(usize::MAX)), full);
+        assert_eq!(
+            format!("{:?}", ShallowDebug::new(&[[1], [2]], 1)),
+            "[[…], […]]",
+        );
+        assert_eq!(
+            format!("{:?}", ShallowDebug::new(&Vec::<i32>::new(), 0)),
+            "[]"
+        );
+    }
+
+    #[test]
+    fn deserialize_unchecked_data_schema() {
+        let mut unchecked: UncheckedDataSchema<Nil, Nil, Nil> = serde_json::from_value(json!({
+            "type": "integer",
+            "minimum": 5,
+            "maximum": 1,
+        }))
+        .unwrap();
+        assert_eq!(
+            unchecked.check(),
+            Err(Error::DataSchema(DataSchemaError::InvalidMinMax))
         );
+
+        let Some(UncheckedDataSchemaSubtype::Integer(integer)) = &mut unchecked.subtype else {
+            panic!("expected an integer subtype");
+        };
+        integer.maximum = Some(Maximum::Inclusive(10));
+        assert_eq!(unchecked.check(), Ok(()));
+        let data_schema = DataSchema::try_from(unchecked).unwrap();
+        assert_eq!(
+            serde_json::to_value(data_schema).unwrap(),
+            json!({
+                "type": "integer",
+                "minimum": 5,
+                "maximum": 10,
+                "readOnly": false,
+                "writeOnly": false,
+            }),
+        );
+    }
+
+    #[cfg(feature = "preserve-unknown")]
+    #[test]
+    fn unchecked_data_schema_keeps_unknown_members() {
+        let data_schema: DataSchema<Nil, Nil, Nil> = serde_json::from_value(json!({
+            "type": "integer",
+            "vendor:range": [0, 100],
+        }))
+        .unwrap();
+
+        let data_schema = UncheckedDataSchema::from(data_schema).try_build().unwrap();
+        assert_eq!(data_schema.unknown["vendor:range"], json!([0, 100]));
     }
 }
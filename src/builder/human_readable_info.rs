@@ -5,29 +5,47 @@
 
 use alloc::{string::*, vec::Vec};
 
-use super::MultiLanguageBuilder;
+use super::{push_unique, MultiLanguageBuilder};
 
 /// Human readable informations and semantic tagging
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct HumanReadableInfo {
     /// JSON-LD @type
     /// The meaning of each @type is given by the current JSON-LD @context.
-    pub(super) attype: Option<Vec<String>>,
+    pub(crate) attype: Option<Vec<String>>,
     /// Human readable title in the default language
-    pub(super) title: Option<String>,
+    pub(crate) title: Option<String>,
     /// Human redable title, multilanguage
-    pub(super) titles: Option<MultiLanguageBuilder<String>>,
+    pub(crate) titles: Option<MultiLanguageBuilder<String>>,
     /// Human readable description in the default language
-    pub(super) description: Option<String>,
+    pub(crate) description: Option<String>,
     /// Human readable description, multilanguage
-    pub(super) descriptions: Option<MultiLanguageBuilder<String>>,
+    pub(crate) descriptions: Option<MultiLanguageBuilder<String>>,
+    /// Deprecation marker
+    pub(crate) deprecated: Option<bool>,
+    /// Human readable information about the deprecation
+    pub(crate) deprecation_note: Option<String>,
+}
+
+/// How the human readable information is merged into an existing one.
+///
+/// See [`DataSchema::merge_human_readable`](crate::thing::DataSchema::merge_human_readable).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MergePolicy {
+    /// The merged values replace the existing ones.
+    #[default]
+    Overwrite,
+
+    /// The existing values are kept, only the missing ones are added.
+    KeepExisting,
 }
 
 /// Trait shared across builders dealing with the same information
 pub trait BuildableHumanReadableInfo {
     /// Set JSON-LD @type
     ///
-    /// It can be called as many times as needed to add multiple @types.
+    /// It can be called as many times as needed to add multiple @types, adding the same @type
+    /// more than once has no effect.
     fn attype(self, value: impl Into<String>) -> Self;
 
     /// Set the title
@@ -61,13 +79,29 @@ pub trait BuildableHumanReadableInfo {
     fn descriptions<F>(self, f: F) -> Self
     where
         F: FnOnce(&mut MultiLanguageBuilder<String>) -> &mut MultiLanguageBuilder<String>;
+
+    /// Mark as deprecated
+    ///
+    /// The marker is serialized as `wot-td:deprecated`, because it is not part of the TD
+    /// vocabulary. The Thing built with it has the [`WOT_TD_PREFIX`] bound to the
+    /// [`WOT_TD_NAMESPACE`] in its `@context`, unless the prefix is already defined.
+    ///
+    /// [`WOT_TD_PREFIX`]: crate::thing::WOT_TD_PREFIX
+    /// [`WOT_TD_NAMESPACE`]: crate::thing::WOT_TD_NAMESPACE
+    fn deprecated(self) -> Self;
+
+    /// Set the deprecation note
+    ///
+    /// It should tell what to use instead. Calling it multiple times overwrites the field.
+    fn deprecation_note(self, value: impl Into<String>) -> Self;
 }
 
 impl BuildableHumanReadableInfo for HumanReadableInfo {
     fn attype(mut self, value: impl Into<String>) -> Self {
-        self.attype
-            .get_or_insert_with(Default::default)
-            .push(value.into());
+        push_unique(
+            self.attype.get_or_insert_with(Default::default),
+            value.into(),
+        );
         self
     }
 
@@ -100,6 +134,16 @@ impl BuildableHumanReadableInfo for HumanReadableInfo {
         self.descriptions = Some(builder);
         self
     }
+
+    fn deprecated(mut self) -> Self {
+        self.deprecated = Some(true);
+        self
+    }
+
+    fn deprecation_note(mut self, value: impl Into<String>) -> Self {
+        self.deprecation_note = Some(value.into());
+        self
+    }
 }
 
 macro_rules! impl_delegate_buildable_hr_info {
@@ -142,9 +186,21 @@ macro_rules! impl_delegate_buildable_hr_info {
                     self. $($inner_path).+ = self. $($inner_path).+ .descriptions(f);
                     self
                 }
+
+                #[inline]
+                fn deprecated(mut self) -> Self {
+                    self. $($inner_path).+ = self. $($inner_path).+ .deprecated();
+                    self
+                }
+
+                #[inline]
+                fn deprecation_note(mut self, value: impl Into<String>) -> Self {
+                    self. $($inner_path).+ = self. $($inner_path).+ .deprecation_note(value);
+                    self
+                }
             }
         )+
     };
 }
 
-pub(super) use impl_delegate_buildable_hr_info;
+pub(crate) use impl_delegate_buildable_hr_info;
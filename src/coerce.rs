@@ -0,0 +1,445 @@
+//! Coercion of the values transported as strings
+//!
+//! The `uriVariables` are expanded in the URI templates of the forms, therefore their values are
+//! always transported as strings, even when they are described by _integer_, _number_ or
+//! _boolean_ data schemas. [`DataSchema::coerce_from_str`] parses a raw, already percent-decoded,
+//! string according to the data schema, and [`DataSchema::to_uri_component`] returns the canonical
+//! string of a value, percent-encoded like the simple string expansion of RFC 6570.
+//!
+//! Only the primitive subtypes can be coerced, as the `uriVariables` cannot use _array_ or
+//! _object_ data schemas. The values of `enum` and `const` are matched by comparing their string
+//! representation, while the `oneOf` alternatives are tried in order.
+//!
+//! # Example
+//!
+//! ```
+//! # use serde_json::json;
+//! # use wot_td::{coerce::CoerceError, thing::DataSchema};
+//! #
+//! let data_schema: DataSchema<(), (), ()> =
+//!     serde_json::from_value(json!({ "type": "integer" })).unwrap();
+//!
+//! assert_eq!(data_schema.coerce_from_str("42"), Ok(json!(42)));
+//! assert_eq!(
+//!     data_schema.coerce_from_str("4.2"),
+//!     Err(CoerceError::InvalidInteger("4.2".to_string())),
+//! );
+//! assert_eq!(data_schema.to_uri_component(&json!(42)).unwrap(), "42");
+//! ```
+//!
+//! [`DataSchema::coerce_from_str`]: crate::thing::DataSchema::coerce_from_str
+//! [`DataSchema::to_uri_component`]: crate::thing::DataSchema::to_uri_component
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use serde_json::{Number, Value};
+
+use crate::thing::{DataSchema, DataSchemaSubtype, HasType};
+
+/// An error coercing a value transported as a string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum CoerceError {
+    /// The string is not an integer that fits an `i64` or an `u64`.
+    #[error("\"{0}\" is not a valid integer")]
+    InvalidInteger(String),
+
+    /// The string is not a finite number.
+    #[error("\"{0}\" is not a valid number")]
+    InvalidNumber(String),
+
+    /// The string is neither `true` nor `false`.
+    #[error("\"{0}\" is not a valid boolean, expected \"true\" or \"false\"")]
+    InvalidBoolean(String),
+
+    /// The string is not `null`.
+    #[error("\"{0}\" is not null")]
+    InvalidNull(String),
+
+    /// The string does not match any value of `enum` or the `const` value.
+    #[error("\"{0}\" is not one of the allowed values")]
+    NotAllowed(String),
+
+    /// The string does not match any of the `oneOf` alternatives.
+    #[error("\"{0}\" does not match any alternative")]
+    NoMatchingAlternative(String),
+
+    /// The value does not have the type of the data schema.
+    #[error("The value is not of type {0}")]
+    TypeMismatch(&'static str),
+
+    /// The data schema is an _array_ or an _object_, which cannot be used by the `uriVariables`.
+    #[error(
+        "{0} data schemas cannot be transported as strings, uriVariables only allow the \
+         primitive types"
+    )]
+    UnsupportedSubtype(&'static str),
+}
+
+pub(crate) fn coerce_from_str<DS, AS, OS>(
+    data_schema: &DataSchema<DS, AS, OS>,
+    raw: &str,
+) -> Result<Value, CoerceError> {
+    check_supported_subtype(data_schema)?;
+    if let Some(allowed) = allowed_values(data_schema) {
+        return allowed
+            .into_iter()
+            .find(|value| to_plain_string(value).is_some_and(|value| value == raw))
+            .cloned()
+            .ok_or_else(|| CoerceError::NotAllowed(raw.to_string()));
+    }
+
+    match &data_schema.subtype {
+        Some(subtype) => coerce_subtype(subtype, raw),
+        None => match &data_schema.one_of {
+            Some(one_of) => one_of
+                .iter()
+                .find_map(|alternative| coerce_from_str(alternative, raw).ok())
+                .ok_or_else(|| CoerceError::NoMatchingAlternative(raw.to_string())),
+            None => Ok(Value::String(raw.to_string())),
+        },
+    }
+}
+
+fn coerce_subtype<DS, AS, OS>(
+    subtype: &DataSchemaSubtype<DS, AS, OS>,
+    raw: &str,
+) -> Result<Value, CoerceError> {
+    match subtype {
+        DataSchemaSubtype::Integer(_) => {
+            parse_integer(raw).ok_or_else(|| CoerceError::InvalidInteger(raw.to_string()))
+        }
+        DataSchemaSubtype::Number(_) => parse_integer(raw)
+            .or_else(|| {
+                raw.parse::<f64>()
+                    .ok()
+                    .and_then(Number::from_f64)
+                    .map(Value::Number)
+            })
+            .ok_or_else(|| CoerceError::InvalidNumber(raw.to_string())),
+        DataSchemaSubtype::Boolean => match raw {
+            "true" => Ok(Value::Bool(true)),
+            "false" => Ok(Value::Bool(false)),
+            _ => Err(CoerceError::InvalidBoolean(raw.to_string())),
+        },
+        DataSchemaSubtype::String(_) => Ok(Value::String(raw.to_string())),
+        DataSchemaSubtype::Null => match raw {
+            "null" => Ok(Value::Null),
+            _ => Err(CoerceError::InvalidNull(raw.to_string())),
+        },
+        DataSchemaSubtype::Array(_) => Err(CoerceError::UnsupportedSubtype("array")),
+        DataSchemaSubtype::Object(_) => Err(CoerceError::UnsupportedSubtype("object")),
+    }
+}
+
+/// Checks that the subtype, if any, can be transported as a string.
+///
+/// This is checked before the `enum` and `const` values, which cannot be compared with a string
+/// for the _array_ and _object_ data schemas.
+fn check_supported_subtype<DS, AS, OS>(
+    data_schema: &DataSchema<DS, AS, OS>,
+) -> Result<(), CoerceError> {
+    match &data_schema.subtype {
+        Some(DataSchemaSubtype::Array(_)) => Err(CoerceError::UnsupportedSubtype("array")),
+        Some(DataSchemaSubtype::Object(_)) => Err(CoerceError::UnsupportedSubtype("object")),
+        _ => Ok(()),
+    }
+}
+
+fn parse_integer(raw: &str) -> Option<Value> {
+    raw.parse::<i64>()
+        .map(Number::from)
+        .or_else(|_| raw.parse::<u64>().map(Number::from))
+        .ok()
+        .map(Value::Number)
+}
+
+pub(crate) fn to_uri_component<DS, AS, OS>(
+    data_schema: &DataSchema<DS, AS, OS>,
+    value: &Value,
+) -> Result<String, CoerceError> {
+    canonical_string(data_schema, value).map(|string| percent_encode(&string))
+}
+
+/// The canonical string of a value, before being percent-encoded.
+fn canonical_string<DS, AS, OS>(
+    data_schema: &DataSchema<DS, AS, OS>,
+    value: &Value,
+) -> Result<String, CoerceError> {
+    check_supported_subtype(data_schema)?;
+    match &data_schema.subtype {
+        Some(DataSchemaSubtype::Integer(_)) => integer_component(value),
+        Some(subtype) => {
+            let expected = match subtype {
+                DataSchemaSubtype::Number(_) => "number",
+                DataSchemaSubtype::Boolean => "boolean",
+                DataSchemaSubtype::String(_) => "string",
+                _ => "null",
+            };
+            if !subtype.has_type(value) {
+                return Err(CoerceError::TypeMismatch(expected));
+            }
+            to_plain_string(value).ok_or(CoerceError::TypeMismatch(expected))
+        }
+        None => match &data_schema.one_of {
+            Some(one_of) => one_of
+                .iter()
+                .find_map(|alternative| canonical_string(alternative, value).ok())
+                .ok_or(CoerceError::TypeMismatch("oneOf")),
+            None => to_plain_string(value).ok_or(CoerceError::TypeMismatch("primitive")),
+        },
+    }
+}
+
+/// Returns the integer without fractional part, also when it is written like `5.0`.
+fn integer_component(value: &Value) -> Result<String, CoerceError> {
+    let Value::Number(number) = value else {
+        return Err(CoerceError::TypeMismatch("integer"));
+    };
+
+    if number.is_i64() || number.is_u64() {
+        return Ok(number.to_string());
+    }
+    number
+        .as_f64()
+        .filter(|number| number.is_finite() && number % 1. == 0.)
+        .filter(|number| (-9_007_199_254_740_992.0..9_007_199_254_740_992.0).contains(number))
+        .map(|number| (number as i64).to_string())
+        .ok_or(CoerceError::TypeMismatch("integer"))
+}
+
+/// Percent-encodes all the characters but the unreserved ones of RFC 3986.
+fn percent_encode(string: &str) -> String {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+    let mut encoded = String::with_capacity(string.len());
+    for byte in string.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push('%');
+            encoded.push(char::from(HEX_DIGITS[usize::from(byte >> 4)]));
+            encoded.push(char::from(HEX_DIGITS[usize::from(byte & 0xF)]));
+        }
+    }
+    encoded
+}
+
+/// The values allowed by `enum` or `const`, if any.
+fn allowed_values<DS, AS, OS>(data_schema: &DataSchema<DS, AS, OS>) -> Option<Vec<&Value>> {
+    match (&data_schema.enumeration, &data_schema.constant) {
+        (Some(enumeration), _) => Some(enumeration.iter().collect()),
+        (None, Some(constant)) => Some(Vec::from([constant])),
+        (None, None) => None,
+    }
+}
+
+/// The string representation of a primitive value, strings are not quoted.
+fn to_plain_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Null => Some("null".to_string()),
+        Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::hlist::Nil;
+
+    use super::*;
+
+    fn data_schema(value: Value) -> DataSchema<Nil, Nil, Nil> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn coerce_primitives() {
+        let integer = data_schema(json!({ "type": "integer" }));
+        assert_eq!(integer.coerce_from_str("42"), Ok(json!(42)));
+        assert_eq!(integer.coerce_from_str("-7"), Ok(json!(-7)));
+        assert_eq!(
+            integer.coerce_from_str("-9223372036854775808"),
+            Ok(json!(i64::MIN))
+        );
+        assert_eq!(
+            integer.coerce_from_str("18446744073709551615"),
+            Ok(json!(u64::MAX))
+        );
+        for raw in ["18446744073709551616", "4.0", "1e3", "", " 4", "four"] {
+            assert_eq!(
+                integer.coerce_from_str(raw),
+                Err(CoerceError::InvalidInteger(raw.to_string())),
+            );
+        }
+
+        let number = data_schema(json!({ "type": "number" }));
+        assert_eq!(number.coerce_from_str("42"), Ok(json!(42)));
+        assert_eq!(number.coerce_from_str("-0.5"), Ok(json!(-0.5)));
+        assert_eq!(number.coerce_from_str("1e3"), Ok(json!(1000.)));
+        for raw in ["NaN", "inf", "-infinity", "", "0x10"] {
+            assert_eq!(
+                number.coerce_from_str(raw),
+                Err(CoerceError::InvalidNumber(raw.to_string())),
+            );
+        }
+
+        let boolean = data_schema(json!({ "type": "boolean" }));
+        assert_eq!(boolean.coerce_from_str("true"), Ok(json!(true)));
+        assert_eq!(boolean.coerce_from_str("false"), Ok(json!(false)));
+        assert_eq!(
+            boolean.coerce_from_str("True"),
+            Err(CoerceError::InvalidBoolean("True".to_string())),
+        );
+        assert_eq!(
+            boolean.coerce_from_str("1"),
+            Err(CoerceError::InvalidBoolean("1".to_string())),
+        );
+
+        let string = data_schema(json!({ "type": "string" }));
+        assert_eq!(string.coerce_from_str("42"), Ok(json!("42")));
+        assert_eq!(string.coerce_from_str(""), Ok(json!("")));
+
+        let null = data_schema(json!({ "type": "null" }));
+        assert_eq!(null.coerce_from_str("null"), Ok(Value::Null));
+        assert_eq!(
+            null.coerce_from_str(""),
+            Err(CoerceError::InvalidNull(String::new())),
+        );
+
+        assert_eq!(
+            data_schema(json!({})).coerce_from_str("42"),
+            Ok(json!("42"))
+        );
+    }
+
+    #[test]
+    fn coerce_allowed_values() {
+        let levels = data_schema(json!({ "type": "integer", "enum": [1, 2, 3] }));
+        assert_eq!(levels.coerce_from_str("2"), Ok(json!(2)));
+        assert_eq!(
+            levels.coerce_from_str("4"),
+            Err(CoerceError::NotAllowed("4".to_string())),
+        );
+
+        let mixed = data_schema(json!({ "enum": ["auto", 0.5, true, null] }));
+        assert_eq!(mixed.coerce_from_str("auto"), Ok(json!("auto")));
+        assert_eq!(mixed.coerce_from_str("0.5"), Ok(json!(0.5)));
+        assert_eq!(mixed.coerce_from_str("true"), Ok(json!(true)));
+        assert_eq!(mixed.coerce_from_str("null"), Ok(Value::Null));
+        assert_eq!(
+            mixed.coerce_from_str("\"auto\""),
+            Err(CoerceError::NotAllowed("\"auto\"".to_string())),
+        );
+
+        let constant = data_schema(json!({ "type": "string", "const": "on" }));
+        assert_eq!(constant.coerce_from_str("on"), Ok(json!("on")));
+        assert_eq!(
+            constant.coerce_from_str("off"),
+            Err(CoerceError::NotAllowed("off".to_string())),
+        );
+
+        let one_of = data_schema(json!({
+            "oneOf": [{ "type": "integer" }, { "type": "boolean" }],
+        }));
+        assert_eq!(one_of.coerce_from_str("3"), Ok(json!(3)));
+        assert_eq!(one_of.coerce_from_str("false"), Ok(json!(false)));
+        assert_eq!(
+            one_of.coerce_from_str("x"),
+            Err(CoerceError::NoMatchingAlternative("x".to_string())),
+        );
+    }
+
+    #[test]
+    fn unsupported_subtypes() {
+        let array = data_schema(json!({ "type": "array" }));
+        assert_eq!(
+            array.coerce_from_str("[1]"),
+            Err(CoerceError::UnsupportedSubtype("array")),
+        );
+        assert_eq!(
+            array.to_uri_component(&json!([1])),
+            Err(CoerceError::UnsupportedSubtype("array")),
+        );
+
+        let array_enum = data_schema(json!({ "type": "array", "enum": [[1]] }));
+        assert_eq!(
+            array_enum.coerce_from_str("[1]"),
+            Err(CoerceError::UnsupportedSubtype("array")),
+        );
+
+        let object = data_schema(json!({ "type": "object" }));
+        assert_eq!(
+            object.coerce_from_str("{}"),
+            Err(CoerceError::UnsupportedSubtype("object")),
+        );
+        assert_eq!(
+            object.to_uri_component(&json!({})),
+            Err(CoerceError::UnsupportedSubtype("object")),
+        );
+    }
+
+    #[test]
+    fn uri_components() {
+        let integer = data_schema(json!({ "type": "integer" }));
+        assert_eq!(integer.to_uri_component(&json!(42)).unwrap(), "42");
+        assert_eq!(integer.to_uri_component(&json!(-3.0)).unwrap(), "-3");
+        assert_eq!(
+            integer.to_uri_component(&json!(u64::MAX)).unwrap(),
+            "18446744073709551615",
+        );
+        for value in [json!(4.5), json!(1e300), json!("4"), json!(true)] {
+            assert_eq!(
+                integer.to_uri_component(&value),
+                Err(CoerceError::TypeMismatch("integer")),
+            );
+        }
+
+        let number = data_schema(json!({ "type": "number" }));
+        assert_eq!(number.to_uri_component(&json!(0.25)).unwrap(), "0.25");
+        assert_eq!(number.to_uri_component(&json!(7)).unwrap(), "7");
+        assert_eq!(
+            number.to_uri_component(&json!("7")),
+            Err(CoerceError::TypeMismatch("number")),
+        );
+
+        let boolean = data_schema(json!({ "type": "boolean" }));
+        assert_eq!(boolean.to_uri_component(&json!(false)).unwrap(), "false");
+        assert_eq!(
+            boolean.to_uri_component(&json!(0)),
+            Err(CoerceError::TypeMismatch("boolean")),
+        );
+
+        let string = data_schema(json!({ "type": "string" }));
+        assert_eq!(
+            string.to_uri_component(&json!("a b/c")).unwrap(),
+            "a%20b%2Fc"
+        );
+        assert_eq!(
+            string.to_uri_component(&json!("~é?x=1&y")).unwrap(),
+            "~%C3%A9%3Fx%3D1%26y"
+        );
+        assert_eq!(
+            string.to_uri_component(&json!("A-z_0.9")).unwrap(),
+            "A-z_0.9"
+        );
+
+        let untyped = data_schema(json!({}));
+        assert_eq!(untyped.to_uri_component(&json!(1.5)).unwrap(), "1.5");
+        assert_eq!(
+            untyped.to_uri_component(&json!([1])),
+            Err(CoerceError::TypeMismatch("primitive")),
+        );
+
+        for (value, raw) in [(json!(12), "12"), (json!(-0.5), "-0.5")] {
+            assert_eq!(number.to_uri_component(&value).unwrap(), raw);
+            assert_eq!(number.coerce_from_str(raw), Ok(value));
+        }
+    }
+}
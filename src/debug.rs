@@ -0,0 +1,904 @@
+//! Compact `Debug` representations
+//!
+//! The derived representations of the Things are dominated by the unset members and the empty
+//! extensions, which makes them hard to read in the test failures. The [`Thing`], its affordances
+//! and the [`DataSchema`]s are formatted in a compact way instead:
+//!
+//! - the members that are unset or have their default value are omitted, like the `Nil` and `()`
+//!   extensions;
+//! - the maps are ordered by key, and the ones with many entries are summarized by their keys,
+//!   like `{12 properties: ["humidity", "pressure", "temperature", ...]}`;
+//! - the data schemas are printed on a single line, like `integer[0..=100] unit=%`;
+//! - the multi-language translations only list their languages.
+//!
+//! The derived representations are used with the `verbose-debug` feature.
+
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{self, Write};
+
+use serde::Serialize;
+
+use crate::{
+    extend::ExtendableThing,
+    thing::{
+        ActionAffordance, ArraySchema, BoxedElemOrVec, DataSchema, DataSchemaFromOther,
+        DataSchemaSubtype, DefaultedFormOperations, EventAffordance, Form, InteractionAffordance,
+        Maximum, Minimum, MultiLanguage, ObjectSchema, PropertyAffordance, Thing,
+    },
+    Map,
+};
+
+/// The maps with more entries than this are summarized by their keys.
+const MAP_THRESHOLD: usize = 8;
+
+/// The number of keys listed by the summary of a map.
+const MAP_PREVIEW: usize = 3;
+
+impl<Other> fmt::Debug for Thing<Other>
+where
+    Other: ExtendableThing + fmt::Debug,
+    PropertyAffordance<Other>: fmt::Debug,
+    ActionAffordance<Other>: fmt::Debug,
+    EventAffordance<Other>: fmt::Debug,
+    Other::Form: fmt::Debug,
+    Other::ExpectedResponse: fmt::Debug,
+    DataSchemaFromOther<Other>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = CompactStruct::new(f, "Thing");
+        debug
+            .field("context", &Json(&self.context))
+            .option("id", &self.id)
+            .option("attype", &self.attype)
+            .field("title", &self.title)
+            .option("titles", &self.titles.as_ref().map(Languages))
+            .option("description", &self.description)
+            .option("descriptions", &self.descriptions.as_ref().map(Languages))
+            .option("version", &self.version)
+            .option("created", &self.created)
+            .option("modified", &self.modified)
+            .option("support", &self.support)
+            .option("base", &self.base)
+            .option(
+                "properties",
+                &self
+                    .properties
+                    .as_ref()
+                    .map(|map| Entries::new(map.iter(), "properties")),
+            )
+            .option(
+                "actions",
+                &self
+                    .actions
+                    .as_ref()
+                    .map(|map| Entries::new(map.iter(), "actions")),
+            )
+            .option(
+                "events",
+                &self
+                    .events
+                    .as_ref()
+                    .map(|map| Entries::new(map.iter(), "events")),
+            )
+            .option("links", &self.links.as_ref().map(|links| List(links, Json)))
+            .option(
+                "forms",
+                &self.forms.as_ref().map(|forms| List(forms, CompactForm)),
+            )
+            .nonempty("security", &self.security, self.security.is_empty())
+            .nonempty(
+                "security_definitions",
+                &Entries::new(
+                    self.security_definitions
+                        .iter()
+                        .map(|(name, scheme)| (name, Json(scheme))),
+                    "security definitions",
+                ),
+                self.security_definitions.is_empty(),
+            )
+            .option(
+                "uri_variables",
+                &self
+                    .uri_variables
+                    .as_ref()
+                    .map(|map| Entries::new(map.iter(), "URI variables")),
+            )
+            .option("profile", &self.profile)
+            .option(
+                "schema_definitions",
+                &self
+                    .schema_definitions
+                    .as_ref()
+                    .map(|map| Entries::new(map.iter(), "schema definitions")),
+            )
+            .option("default_content_type", &self.default_content_type)
+            .extension("other", &self.other);
+        #[cfg(feature = "preserve-unknown")]
+        debug.members("unknown", &self.unknown);
+        debug.finish()
+    }
+}
+
+impl<Other> fmt::Debug for InteractionAffordance<Other>
+where
+    Other: ExtendableThing,
+    Other::Form: fmt::Debug,
+    Other::ExpectedResponse: fmt::Debug,
+    DataSchemaFromOther<Other>: fmt::Debug,
+    Other::InteractionAffordance: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = CompactStruct::new(f, "InteractionAffordance");
+        interaction_fields(&mut debug, self);
+        debug.finish()
+    }
+}
+
+impl<Other> fmt::Debug for PropertyAffordance<Other>
+where
+    Other: ExtendableThing,
+    Other::Form: fmt::Debug,
+    Other::ExpectedResponse: fmt::Debug,
+    DataSchemaFromOther<Other>: fmt::Debug,
+    Other::InteractionAffordance: fmt::Debug,
+    Other::PropertyAffordance: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = CompactStruct::new(f, "PropertyAffordance");
+        interaction_fields(&mut debug, &self.interaction);
+        debug
+            .field("data_schema", &self.data_schema)
+            .option("observable", &self.observable)
+            .extension("other", &self.other)
+            .finish()
+    }
+}
+
+impl<Other> fmt::Debug for ActionAffordance<Other>
+where
+    Other: ExtendableThing,
+    Other::Form: fmt::Debug,
+    Other::ExpectedResponse: fmt::Debug,
+    DataSchemaFromOther<Other>: fmt::Debug,
+    Other::InteractionAffordance: fmt::Debug,
+    Other::ActionAffordance: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = CompactStruct::new(f, "ActionAffordance");
+        interaction_fields(&mut debug, &self.interaction);
+        debug
+            .option("input", &self.input)
+            .option("output", &self.output)
+            .flag("safe", self.safe)
+            .flag("idempotent", self.idempotent)
+            .option("synchronous", &self.synchronous)
+            .extension("other", &self.other);
+        #[cfg(feature = "preserve-unknown")]
+        debug.members("unknown", &self.unknown);
+        debug.finish()
+    }
+}
+
+impl<Other> fmt::Debug for EventAffordance<Other>
+where
+    Other: ExtendableThing,
+    Other::Form: fmt::Debug,
+    Other::ExpectedResponse: fmt::Debug,
+    DataSchemaFromOther<Other>: fmt::Debug,
+    Other::InteractionAffordance: fmt::Debug,
+    Other::EventAffordance: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = CompactStruct::new(f, "EventAffordance");
+        interaction_fields(&mut debug, &self.interaction);
+        debug
+            .option("subscription", &self.subscription)
+            .option("data", &self.data)
+            .option("data_response", &self.data_response)
+            .option("cancellation", &self.cancellation)
+            .extension("other", &self.other);
+        #[cfg(feature = "preserve-unknown")]
+        debug.members("unknown", &self.unknown);
+        debug.finish()
+    }
+}
+
+/// Adds the fields of the interaction affordance, flattened in the debug struct of an affordance.
+fn interaction_fields<Other>(
+    debug: &mut CompactStruct<'_, '_>,
+    interaction: &InteractionAffordance<Other>,
+) where
+    Other: ExtendableThing,
+    Other::Form: fmt::Debug,
+    Other::ExpectedResponse: fmt::Debug,
+    DataSchemaFromOther<Other>: fmt::Debug,
+    Other::InteractionAffordance: fmt::Debug,
+{
+    debug
+        .option("attype", &interaction.attype)
+        .option("title", &interaction.title)
+        .option("titles", &interaction.titles.as_ref().map(Languages))
+        .option("description", &interaction.description)
+        .option(
+            "descriptions",
+            &interaction.descriptions.as_ref().map(Languages),
+        )
+        .option("deprecated", &interaction.deprecated)
+        .option("deprecation_note", &interaction.deprecation_note)
+        .nonempty(
+            "forms",
+            &List(&interaction.forms, CompactForm),
+            interaction.forms.is_empty(),
+        )
+        .option(
+            "uri_variables",
+            &interaction
+                .uri_variables
+                .as_ref()
+                .map(|map| Entries::new(map.iter(), "URI variables")),
+        )
+        .extension("other", &interaction.other);
+}
+
+/// A single line representation of the data schema.
+///
+/// It starts with the type and the bounds, like `integer[0..=100]` or `string[len ..=16]`, which
+/// is followed by the other members that are set, like `unit=% read_only`.
+impl<DS, AS, OS> fmt::Debug for DataSchema<DS, AS, OS>
+where
+    DS: fmt::Debug,
+    AS: fmt::Debug,
+    OS: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.subtype, &self.one_of) {
+            (Some(subtype), _) => write_subtype(f, subtype)?,
+            (None, Some(_)) => f.write_str("one_of")?,
+            (None, None) => f.write_str("any")?,
+        }
+        if let Some(one_of) = &self.one_of {
+            f.write_char('[')?;
+            write_separated(f, one_of, ", ", |f, schema| write!(f, "{schema:?}"))?;
+            f.write_char(']')?;
+        }
+
+        if let Some(attype) = &self.attype {
+            f.write_str(" @type=[")?;
+            write_separated(f, attype, ", ", |f, attype| write!(f, "{attype}"))?;
+            f.write_char(']')?;
+        }
+        if let Some(unit) = &self.unit {
+            write!(f, " unit={unit}")?;
+        }
+        if let Some(format) = &self.format {
+            write!(f, " format={format}")?;
+        }
+        if let Some(constant) = &self.constant {
+            write!(f, " const={constant}")?;
+        }
+        if let Some(default) = &self.default {
+            write!(f, " default={default}")?;
+        }
+        if let Some(enumeration) = &self.enumeration {
+            f.write_str(" enum=[")?;
+            write_separated(f, enumeration, ", ", |f, value| write!(f, "{value}"))?;
+            f.write_char(']')?;
+        }
+        if self.read_only {
+            f.write_str(" read_only")?;
+        }
+        if self.write_only {
+            f.write_str(" write_only")?;
+        }
+        if let Some(deprecated) = self.deprecated {
+            write!(f, " deprecated={deprecated}")?;
+        }
+        if let Some(deprecation_note) = &self.deprecation_note {
+            write!(f, " deprecation_note={deprecation_note:?}")?;
+        }
+        if let Some(title) = &self.title {
+            write!(f, " title={title:?}")?;
+        }
+        if let Some(titles) = &self.titles {
+            write!(f, " titles={:?}", Languages(titles))?;
+        }
+        if let Some(description) = &self.description {
+            write!(f, " description={description:?}")?;
+        }
+        if let Some(descriptions) = &self.descriptions {
+            write!(f, " descriptions={:?}", Languages(descriptions))?;
+        }
+        write_extension(f, &self.other)?;
+        if !self.raw_fields.is_empty() {
+            write!(f, " raw_fields={:?}", Json(&self.raw_fields))?;
+        }
+        #[cfg(feature = "preserve-unknown")]
+        if !self.unknown.is_empty() {
+            write!(f, " unknown={:?}", Json(&self.unknown))?;
+        }
+        Ok(())
+    }
+}
+
+fn write_subtype<DS, AS, OS>(
+    f: &mut fmt::Formatter<'_>,
+    subtype: &DataSchemaSubtype<DS, AS, OS>,
+) -> fmt::Result
+where
+    DS: fmt::Debug,
+    AS: fmt::Debug,
+    OS: fmt::Debug,
+{
+    match subtype {
+        DataSchemaSubtype::Array(array) => write_array(f, array),
+        DataSchemaSubtype::Boolean => f.write_str("boolean"),
+        DataSchemaSubtype::Number(number) => {
+            f.write_str("number")?;
+            write_bounds(f, "", number.minimum, number.maximum)?;
+            if let Some(multiple_of) = number.multiple_of {
+                write!(f, " multiple_of={multiple_of}")?;
+            }
+            Ok(())
+        }
+        DataSchemaSubtype::Integer(integer) => {
+            f.write_str("integer")?;
+            write_bounds(f, "", integer.minimum, integer.maximum)?;
+            if let Some(multiple_of) = integer.multiple_of {
+                write!(f, " multiple_of={multiple_of}")?;
+            }
+            Ok(())
+        }
+        DataSchemaSubtype::Object(object) => write_object(f, object),
+        DataSchemaSubtype::String(string) => {
+            f.write_str("string")?;
+            write_bounds(
+                f,
+                "len ",
+                string.min_length.map(Minimum::Inclusive),
+                string.max_length.map(Maximum::Inclusive),
+            )?;
+            if let Some(pattern) = &string.pattern {
+                write!(f, " pattern={pattern:?}")?;
+            }
+            if let Some(content_encoding) = &string.content_encoding {
+                write!(f, " content_encoding={content_encoding}")?;
+            }
+            if let Some(content_media_type) = &string.content_media_type {
+                write!(f, " content_media_type={content_media_type}")?;
+            }
+            Ok(())
+        }
+        DataSchemaSubtype::Null => f.write_str("null"),
+    }
+}
+
+fn write_array<DS, AS, OS>(
+    f: &mut fmt::Formatter<'_>,
+    array: &ArraySchema<DS, AS, OS>,
+) -> fmt::Result
+where
+    DS: fmt::Debug,
+    AS: fmt::Debug,
+    OS: fmt::Debug,
+{
+    f.write_str("array")?;
+    match &array.items {
+        Some(BoxedElemOrVec::Elem(item)) => write!(f, "<{item:?}>")?,
+        Some(BoxedElemOrVec::Vec(items)) => {
+            f.write_str("<(")?;
+            write_separated(f, items, ", ", |f, item| write!(f, "{item:?}"))?;
+            if items.len() == 1 {
+                f.write_char(',')?;
+            }
+            f.write_str(")>")?;
+        }
+        None => {}
+    }
+    write_bounds(
+        f,
+        "len ",
+        array.min_items.map(Minimum::Inclusive),
+        array.max_items.map(Maximum::Inclusive),
+    )?;
+    write_extension(f, &array.other)
+}
+
+fn write_object<DS, AS, OS>(
+    f: &mut fmt::Formatter<'_>,
+    object: &ObjectSchema<DS, AS, OS>,
+) -> fmt::Result
+where
+    DS: fmt::Debug,
+    AS: fmt::Debug,
+    OS: fmt::Debug,
+{
+    f.write_str("object")?;
+    if let Some(properties) = &object.properties {
+        let properties = sorted(properties);
+        if properties.len() > MAP_THRESHOLD {
+            write_summary(f, properties.iter().map(|&(name, _)| name), "properties")?;
+        } else {
+            f.write_char('{')?;
+            write_separated(f, &properties, ", ", |f, (name, schema)| {
+                write!(f, "{name:?}: {schema:?}")
+            })?;
+            f.write_char('}')?;
+        }
+    }
+    if let Some(required) = &object.required {
+        write!(f, " required={required:?}")?;
+    }
+    write_extension(f, &object.other)
+}
+
+/// Writes the bounds like a range, with a `>` before an exclusive minimum.
+fn write_bounds<T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    prefix: &str,
+    minimum: Option<Minimum<T>>,
+    maximum: Option<Maximum<T>>,
+) -> fmt::Result {
+    if minimum.is_none() && maximum.is_none() {
+        return Ok(());
+    }
+
+    write!(f, "[{prefix}")?;
+    match minimum {
+        Some(Minimum::Inclusive(minimum)) => write!(f, "{minimum}")?,
+        Some(Minimum::Exclusive(minimum)) => write!(f, ">{minimum}")?,
+        None => {}
+    }
+    match maximum {
+        Some(Maximum::Inclusive(maximum)) => write!(f, "..={maximum}]"),
+        Some(Maximum::Exclusive(maximum)) => write!(f, "..{maximum}]"),
+        None => f.write_str("..]"),
+    }
+}
+
+fn write_extension(f: &mut fmt::Formatter<'_>, extension: &dyn fmt::Debug) -> fmt::Result {
+    if is_empty_extension(extension) {
+        Ok(())
+    } else {
+        write!(f, " other={extension:?}")
+    }
+}
+
+fn write_separated<T>(
+    f: &mut fmt::Formatter<'_>,
+    items: &[T],
+    separator: &str,
+    mut write_item: impl FnMut(&mut fmt::Formatter<'_>, &T) -> fmt::Result,
+) -> fmt::Result {
+    items.iter().enumerate().try_for_each(|(index, item)| {
+        if index > 0 {
+            f.write_str(separator)?;
+        }
+        write_item(f, item)
+    })
+}
+
+/// Writes the summary of a map with too many entries, like `{12 properties: ["a", "b", ...]}`.
+fn write_summary<'a>(
+    f: &mut fmt::Formatter<'_>,
+    keys: impl ExactSizeIterator<Item = &'a String>,
+    noun: &str,
+) -> fmt::Result {
+    write!(f, "{{{} {noun}: [", keys.len())?;
+    keys.take(MAP_PREVIEW)
+        .try_for_each(|key| write!(f, "{key:?}, "))?;
+    f.write_str("...]}")
+}
+
+/// Whether the `Debug` representation of an extension is empty, like `Nil` or `()`.
+fn is_empty_extension(extension: &dyn fmt::Debug) -> bool {
+    matches!(format!("{extension:?}").as_str(), "Nil" | "()")
+}
+
+fn sorted<V>(map: &Map<String, V>) -> Vec<(&String, &V)> {
+    let mut entries = map.iter().collect::<Vec<_>>();
+    entries.sort_unstable_by_key(|&(key, _)| key);
+    entries
+}
+
+/// A `debug_struct` that skips the unset members.
+struct CompactStruct<'a, 'b> {
+    debug: fmt::DebugStruct<'a, 'b>,
+}
+
+impl<'a, 'b> CompactStruct<'a, 'b> {
+    fn new(f: &'a mut fmt::Formatter<'b>, name: &str) -> Self {
+        Self {
+            debug: f.debug_struct(name),
+        }
+    }
+
+    fn field(&mut self, name: &str, value: &dyn fmt::Debug) -> &mut Self {
+        self.debug.field(name, value);
+        self
+    }
+
+    /// Adds the content of the value, if any.
+    fn option<T: fmt::Debug>(&mut self, name: &str, value: &Option<T>) -> &mut Self {
+        if let Some(value) = value {
+            self.debug.field(name, value);
+        }
+        self
+    }
+
+    fn nonempty(&mut self, name: &str, value: &dyn fmt::Debug, is_empty: bool) -> &mut Self {
+        if !is_empty {
+            self.debug.field(name, value);
+        }
+        self
+    }
+
+    fn flag(&mut self, name: &str, value: bool) -> &mut Self {
+        self.nonempty(name, &value, !value)
+    }
+
+    fn extension(&mut self, name: &str, value: &dyn fmt::Debug) -> &mut Self {
+        self.nonempty(name, value, is_empty_extension(value))
+    }
+
+    #[cfg(feature = "preserve-unknown")]
+    fn members(
+        &mut self,
+        name: &str,
+        members: &serde_json::Map<String, serde_json::Value>,
+    ) -> &mut Self {
+        self.nonempty(name, &Json(members), members.is_empty())
+    }
+
+    fn finish(&mut self) -> fmt::Result {
+        self.debug.finish()
+    }
+}
+
+/// The entries of a map ordered by key, or its summary when it has too many entries.
+struct Entries<'a, V> {
+    entries: Vec<(&'a String, V)>,
+    noun: &'static str,
+}
+
+impl<'a, V> Entries<'a, V> {
+    fn new(entries: impl Iterator<Item = (&'a String, V)>, noun: &'static str) -> Self {
+        let mut entries = entries.collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|&(key, _)| key);
+        Self { entries, noun }
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for Entries<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.entries.len() > MAP_THRESHOLD {
+            write_summary(f, self.entries.iter().map(|&(key, _)| key), self.noun)
+        } else {
+            f.debug_map()
+                .entries(self.entries.iter().map(|(key, value)| (key, value)))
+                .finish()
+        }
+    }
+}
+
+/// A list formatted through a wrapper of its elements.
+struct List<'a, T, W>(&'a [T], fn(&'a T) -> W);
+
+impl<'a, T, W: fmt::Debug> fmt::Debug for List<'a, T, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.iter().map(self.1)).finish()
+    }
+}
+
+/// The languages of the translations.
+struct Languages<'a>(&'a MultiLanguage);
+
+impl fmt::Debug for Languages<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut languages = self.0.keys().map(|tag| tag.as_str()).collect::<Vec<_>>();
+        languages.sort_unstable();
+        f.write_char('[')?;
+        write_separated(f, &languages, ", ", |f, language| f.write_str(language))?;
+        f.write_char(']')
+    }
+}
+
+/// The compact JSON representation of a value.
+struct Json<'a, T: ?Sized>(&'a T);
+
+impl<T: Serialize + ?Sized> fmt::Debug for Json<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let json = serde_json::to_string(self.0).map_err(|_| fmt::Error)?;
+        f.write_str(&json)
+    }
+}
+
+/// A form without its unset members.
+struct CompactForm<'a, F, ER>(&'a Form<F, ER>);
+
+impl<F, ER> fmt::Debug for CompactForm<'_, F, ER>
+where
+    F: fmt::Debug,
+    ER: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let form = self.0;
+        let op = match &form.op {
+            DefaultedFormOperations::Default => None,
+            DefaultedFormOperations::Custom(op) => Some(op),
+        };
+
+        let mut debug = CompactStruct::new(f, "Form");
+        debug
+            .option("op", &op)
+            .field("href", &form.href)
+            .option("content_type", &form.content_type)
+            .option("content_coding", &form.content_coding)
+            .option("subprotocol", &form.subprotocol)
+            .option("security", &form.security)
+            .option("scopes", &form.scopes)
+            .option("response", &form.response)
+            .option("additional_responses", &form.additional_responses)
+            .extension("other", &form.other);
+        #[cfg(feature = "preserve-unknown")]
+        debug.members("unknown", &form.unknown);
+        debug.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format, vec};
+
+    use serde_json::json;
+
+    use crate::{
+        hlist::Nil,
+        thing::{ArraySchema, BoxedElemOrVec, DataSchema, DataSchemaSubtype, Thing, TD_CONTEXT_11},
+    };
+
+    fn data_schema(value: serde_json::Value) -> DataSchema<Nil, Nil, Nil> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn compact_thing() {
+        let thing: Thing = serde_json::from_value(json!({
+            "@context": TD_CONTEXT_11,
+            "id": "urn:dev:ops:lamp",
+            "title": "Lamp",
+            "titles": { "it": "Lampada", "en": "Lamp" },
+            "security": ["nosec"],
+            "securityDefinitions": { "nosec": { "scheme": "nosec" } },
+            "properties": {
+                "brightness": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "maximum": 100,
+                    "unit": "%",
+                    "readOnly": true,
+                    "forms": [{ "href": "/brightness" }],
+                },
+            },
+            "actions": {
+                "fade": {
+                    "input": {
+                        "type": "object",
+                        "properties": {
+                            "level": { "type": "number", "exclusiveMinimum": 0, "maximum": 1 },
+                            "duration": { "type": "integer", "unit": "ms" },
+                        },
+                        "required": ["level"],
+                    },
+                    "idempotent": true,
+                    "forms": [{
+                        "href": "/fade",
+                        "op": "invokeaction",
+                        "contentType": "application/json",
+                    }],
+                },
+            },
+            "events": {
+                "overheated": {
+                    "data": { "type": "array", "items": [{ "type": "string", "maxLength": 8 }] },
+                    "forms": [{ "href": "/overheated", "subprotocol": "sse" }],
+                },
+            },
+        }))
+        .unwrap();
+
+        assert_eq!(
+            format!("{thing:?}"),
+            "Thing { \
+                context: \"https://www.w3.org/2022/wot/td/v1.1\", \
+                id: \"urn:dev:ops:lamp\", \
+                title: \"Lamp\", \
+                titles: [en, it], \
+                properties: {\"brightness\": PropertyAffordance { \
+                    forms: [Form { href: \"/brightness\" }], \
+                    data_schema: integer[0..=100] unit=% read_only \
+                }}, \
+                actions: {\"fade\": ActionAffordance { \
+                    forms: [Form { \
+                        op: [InvokeAction], \
+                        href: \"/fade\", \
+                        content_type: \"application/json\" \
+                    }], \
+                    input: object{\"duration\": integer unit=ms, \"level\": number[>0..=1]} \
+                        required=[\"level\"], \
+                    idempotent: true \
+                }}, \
+                events: {\"overheated\": EventAffordance { \
+                    forms: [Form { href: \"/overheated\", subprotocol: \"sse\" }], \
+                    data: array<(string[len ..=8],)> \
+                }}, \
+                security: [\"nosec\"], \
+                security_definitions: {\"nosec\": {\"scheme\":\"nosec\"}} \
+            }",
+        );
+    }
+
+    #[test]
+    fn compact_pretty_thing() {
+        let thing: Thing = serde_json::from_value(json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Sensor",
+            "security": [],
+            "securityDefinitions": {},
+            "properties": {
+                "temperature": {
+                    "type": "number",
+                    "unit": "Cel",
+                    "forms": [{ "href": "/temperature" }],
+                },
+            },
+        }))
+        .unwrap();
+
+        assert_eq!(
+            format!("{thing:#?}"),
+            r#"Thing {
+    context: "https://www.w3.org/2022/wot/td/v1.1",
+    title: "Sensor",
+    properties: {
+        "temperature": PropertyAffordance {
+            forms: [
+                Form {
+                    href: "/temperature",
+                },
+            ],
+            data_schema: number unit=Cel,
+        },
+    },
+}"#,
+        );
+    }
+
+    #[test]
+    fn summarized_maps() {
+        let properties = (0..12)
+            .map(|index| {
+                (
+                    format!("p{index:02}"),
+                    json!({ "type": "boolean", "forms": [{ "href": "/p" }] }),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>();
+        let thing: Thing = serde_json::from_value(json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Crowded",
+            "security": [],
+            "securityDefinitions": {},
+            "properties": properties,
+            "schemaDefinitions": {
+                "crowded": {
+                    "type": "object",
+                    "properties": properties,
+                },
+            },
+        }))
+        .unwrap();
+
+        assert_eq!(
+            format!("{thing:?}"),
+            "Thing { \
+                context: \"https://www.w3.org/2022/wot/td/v1.1\", \
+                title: \"Crowded\", \
+                properties: {12 properties: [\"p00\", \"p01\", \"p02\", ...]}, \
+                schema_definitions: {\"crowded\": \
+                    object{12 properties: [\"p00\", \"p01\", \"p02\", ...]}} \
+            }",
+        );
+    }
+
+    #[test]
+    fn one_line_data_schemas() {
+        for (value, expected) in [
+            (json!({}), "any"),
+            (json!({ "type": "boolean", "default": false }), "boolean default=false"),
+            (
+                json!({ "type": "integer", "minimum": 1, "multipleOf": 2, "writeOnly": true }),
+                "integer[1..] multiple_of=2 write_only",
+            ),
+            (
+                json!({ "type": "number", "exclusiveMaximum": 0.5, "@type": "saref:Level" }),
+                "number[..0.5] @type=[saref:Level]",
+            ),
+            (
+                json!({
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 16,
+                    "pattern": "^[a-z]+$",
+                    "contentEncoding": "base64",
+                    "enum": ["a", "b"],
+                }),
+                "string[len 1..=16] pattern=\"^[a-z]+$\" content_encoding=base64 enum=[\"a\", \"b\"]",
+            ),
+            (
+                json!({
+                    "oneOf": [{ "type": "null" }, { "const": 5 }],
+                    "format": "date-time",
+                    "title": "Either",
+                    "descriptions": { "it": "Uno dei due", "en": "One of them" },
+                    "wot-td:deprecated": true,
+                }),
+                "one_of[null, any const=5] format=date-time deprecated=true title=\"Either\" \
+                 descriptions=[en, it]",
+            ),
+            (
+                json!({ "type": "object", "properties": {}, "required": [] }),
+                "object{} required=[]",
+            ),
+        ] {
+            assert_eq!(format!("{:?}", data_schema(value)), expected);
+        }
+
+        let array = DataSchema::<Nil, Nil, Nil> {
+            subtype: Some(DataSchemaSubtype::Array(ArraySchema {
+                items: Some(BoxedElemOrVec::Elem(Box::new(data_schema(
+                    json!({ "type": "integer", "maximum": 10 }),
+                )))),
+                min_items: Some(1),
+                max_items: None,
+                other: Nil,
+            })),
+            ..Default::default()
+        };
+        assert_eq!(format!("{array:?}"), "array<integer[..=10]>[len 1..]");
+
+        let tuple = DataSchema::<Nil, Nil, Nil> {
+            subtype: Some(DataSchemaSubtype::Array(ArraySchema {
+                items: Some(BoxedElemOrVec::Vec(vec![
+                    data_schema(json!({ "type": "string" })),
+                    data_schema(json!({ "type": "null" })),
+                ])),
+                min_items: None,
+                max_items: Some(2),
+                other: Nil,
+            })),
+            ..Default::default()
+        };
+        assert_eq!(format!("{tuple:?}"), "array<(string, null)>[len ..=2]");
+    }
+
+    #[test]
+    fn non_empty_extension() {
+        let data_schema = DataSchema::<u8, (), ()> {
+            unit: Some("ms".into()),
+            other: 3,
+            ..Default::default()
+        };
+        assert_eq!(format!("{data_schema:?}"), "any unit=ms other=3");
+
+        let data_schema = DataSchema::<(), (), ()> {
+            raw_fields: [("x-vendor".into(), json!({ "a": 1 }))]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+        assert_eq!(
+            format!("{data_schema:?}"),
+            "any raw_fields={\"x-vendor\":{\"a\":1}}"
+        );
+    }
+}
@@ -0,0 +1,460 @@
+//! Detection of duplicated Thing Descriptions
+//!
+//! Directories receive Thing Descriptions from many sources, and the same Thing can be submitted
+//! more than once. [`Thing::canonical_id`] returns a [`CanonicalId`] that can be compared and
+//! hashed to detect the duplicates, and [`dedup_things`] partitions a collection of Things into
+//! the unique ones and the duplicates.
+//!
+//! The canonical identifier is chosen with the following precedence:
+//!
+//! 1. the `id` of the Thing, compared exactly;
+//! 2. the `base` together with the `title`, when the Thing has no `id` but it has a `base`;
+//! 3. the whole content of the Thing Description otherwise.
+//!
+//! The content is compared through its canonical JSON representation, in which the members of
+//! every object are sorted in lexicographic order: it does not depend on the order of the members
+//! in the original document, or on the iteration order of the maps of the [`Thing`]. The order of
+//! the elements of the arrays is significant instead. A hash of the canonical JSON is only used to
+//! find the candidates quickly, therefore two distinct Things are never considered the same
+//! because of a hash collision.
+//!
+//! # Example
+//!
+//! ```
+//! # use serde_json::json;
+//! # use wot_td::{dedup::dedup_things, thing::Thing};
+//! #
+//! let lamp = json!({
+//!     "@context": "https://www.w3.org/2022/wot/td/v1.1",
+//!     "id": "urn:dev:ops:32473-WoTLamp-1234",
+//!     "title": "Lamp",
+//!     "security": [],
+//!     "securityDefinitions": {},
+//! });
+//! let mut renamed = lamp.clone();
+//! renamed["title"] = json!("Living room lamp");
+//!
+//! let things: Vec<Thing> = [lamp, renamed]
+//!     .into_iter()
+//!     .map(serde_json::from_value)
+//!     .collect::<Result<_, _>>()
+//!     .unwrap();
+//!
+//! let (unique, duplicates) = dedup_things(things).unwrap();
+//! assert_eq!(unique.len(), 1);
+//! assert_eq!(duplicates[0].index, 1);
+//! assert_eq!(duplicates[0].duplicate_of, 0);
+//! assert_eq!(duplicates[0].thing.title, "Living room lamp");
+//! ```
+//!
+//! [`Thing::canonical_id`]: crate::thing::Thing::canonical_id
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use hashbrown::{hash_map::Entry, HashMap};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{extend::ExtendableThing, thing::Thing};
+
+/// A key identifying a Thing, used to detect the duplicated Thing Descriptions.
+///
+/// The variants are listed in order of precedence, see the [module documentation](self). Two
+/// Things identified by different variants are never considered the same.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CanonicalId {
+    /// The `id` of the Thing.
+    Id(String),
+
+    /// The `base` and the `title` of a Thing without `id`.
+    BaseTitle(String, String),
+
+    /// The content of a Thing without `id` and `base`.
+    Content(CanonicalContent),
+}
+
+/// The canonical JSON representation of a Thing, along with its hash.
+///
+/// Two contents are equal when their canonical JSON representations are equal, the hash is only
+/// used by the [`Hash`] implementation and to speed up the comparisons.
+#[derive(Clone, Debug)]
+pub struct CanonicalContent {
+    hash: u64,
+    json: String,
+}
+
+impl CanonicalContent {
+    fn new(value: &Value) -> Self {
+        let mut json = String::new();
+        write_canonical(value, &mut json);
+        let mut hasher = Fnv1a::new();
+        hasher.write(json.as_bytes());
+
+        Self {
+            hash: hasher.finish(),
+            json,
+        }
+    }
+
+    /// Returns the 64-bit FNV-1a hash of the canonical JSON, which is stable across platforms and
+    /// executions.
+    #[inline]
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Returns the canonical JSON representation of the Thing.
+    #[inline]
+    pub fn as_json(&self) -> &str {
+        &self.json
+    }
+}
+
+impl PartialEq for CanonicalContent {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.json == other.json
+    }
+}
+
+impl Eq for CanonicalContent {}
+
+impl PartialOrd for CanonicalContent {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CanonicalContent {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.hash
+            .cmp(&other.hash)
+            .then_with(|| self.json.cmp(&other.json))
+    }
+}
+
+impl Hash for CanonicalContent {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+/// A Thing that has been recognized as a duplicate by [`dedup_things`].
+pub struct DuplicateInfo<Other: ExtendableThing> {
+    /// The position of the duplicate in the original collection.
+    pub index: usize,
+
+    /// The position in the original collection of the first Thing with the same identifier.
+    pub duplicate_of: usize,
+
+    /// The identifier shared by the two Things.
+    pub id: CanonicalId,
+
+    /// The duplicated Thing.
+    pub thing: Thing<Other>,
+}
+
+impl<Other> fmt::Debug for DuplicateInfo<Other>
+where
+    Other: ExtendableThing,
+    Thing<Other>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DuplicateInfo")
+            .field("index", &self.index)
+            .field("duplicate_of", &self.duplicate_of)
+            .field("id", &self.id)
+            .field("thing", &self.thing)
+            .finish()
+    }
+}
+
+/// The unique Things and the duplicates returned by [`dedup_things`].
+pub type Partition<Other> = (Vec<Thing<Other>>, Vec<DuplicateInfo<Other>>);
+
+/// Partitions a collection of Things into the unique ones and the duplicates.
+///
+/// The first occurrence of each [`CanonicalId`] is kept, in the original order, while the
+/// following ones are returned as [`DuplicateInfo`]s.
+///
+/// # Errors
+///
+/// It fails if one of the Things without `id` and `base` cannot be serialized, because its
+/// content cannot be compared.
+pub fn dedup_things<Other, I>(things: I) -> Result<Partition<Other>, serde_json::Error>
+where
+    Other: ExtendableThing,
+    Thing<Other>: Serialize,
+    I: IntoIterator<Item = Thing<Other>>,
+{
+    let mut seen = HashMap::new();
+    let mut unique = Vec::new();
+    let mut duplicates = Vec::new();
+
+    for (index, thing) in things.into_iter().enumerate() {
+        let id = canonical_id(&thing)?;
+        match seen.entry(id) {
+            Entry::Occupied(entry) => duplicates.push(DuplicateInfo {
+                index,
+                duplicate_of: *entry.get(),
+                id: entry.key().clone(),
+                thing,
+            }),
+            Entry::Vacant(entry) => {
+                entry.insert(index);
+                unique.push(thing);
+            }
+        }
+    }
+
+    Ok((unique, duplicates))
+}
+
+pub(crate) fn canonical_id<Other>(thing: &Thing<Other>) -> Result<CanonicalId, serde_json::Error>
+where
+    Other: ExtendableThing,
+    Thing<Other>: Serialize,
+{
+    if let Some(id) = &thing.id {
+        return Ok(CanonicalId::Id(id.clone()));
+    }
+
+    if let Some(base) = &thing.base {
+        return Ok(CanonicalId::BaseTitle(base.clone(), thing.title.clone()));
+    }
+
+    let value = serde_json::to_value(thing)?;
+    Ok(CanonicalId::Content(CanonicalContent::new(&value)))
+}
+
+/// Writes the JSON representation of a value, with the members of the objects sorted by name.
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Array(values) => {
+            out.push('[');
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                write_canonical(value, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut members = map.iter().collect::<Vec<_>>();
+            members.sort_unstable_by_key(|&(key, _)| key);
+
+            out.push('{');
+            for (index, (key, value)) in members.into_iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::from(key.as_str()).to_string());
+                out.push(':');
+                write_canonical(value, out);
+            }
+            out.push('}');
+        }
+        value => out.push_str(&value.to_string()),
+    }
+}
+
+/// The 64-bit FNV-1a hash, which is stable across platforms and executions.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(Self::PRIME);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::thing::TD_CONTEXT_11;
+
+    use super::*;
+
+    fn thing(value: Value) -> Thing {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn id_takes_precedence() {
+        let lamp = thing(json!({
+            "@context": TD_CONTEXT_11,
+            "id": "urn:dev:ops:lamp",
+            "base": "https://lamp.example.com/",
+            "title": "Lamp",
+            "security": [],
+            "securityDefinitions": {},
+        }));
+
+        assert_eq!(
+            lamp.canonical_id().unwrap(),
+            CanonicalId::Id("urn:dev:ops:lamp".into()),
+        );
+    }
+
+    #[test]
+    fn base_and_title() {
+        let lamp = |base: &str, title: &str, description: &str| {
+            thing(json!({
+                "@context": TD_CONTEXT_11,
+                "base": base,
+                "title": title,
+                "description": description,
+                "security": [],
+                "securityDefinitions": {},
+            }))
+            .canonical_id()
+            .unwrap()
+        };
+
+        let id = lamp("https://lamp.example.com/", "Lamp", "A lamp");
+        assert_eq!(
+            id,
+            CanonicalId::BaseTitle("https://lamp.example.com/".into(), "Lamp".into()),
+        );
+        assert_eq!(
+            lamp("https://lamp.example.com/", "Lamp", "Another lamp"),
+            id
+        );
+        assert_ne!(lamp("https://lamp.example.com/", "Lamp 2", "A lamp"), id);
+        assert_ne!(lamp("https://lamp.example.com/Lam", "p", "A lamp"), id);
+    }
+
+    #[test]
+    fn content_hash_ignores_member_order() {
+        let first: Thing = serde_json::from_str(
+            r#"{
+                "@context": "https://www.w3.org/2022/wot/td/v1.1",
+                "title": "Lamp",
+                "security": ["nosec_sc"],
+                "securityDefinitions": { "nosec_sc": { "scheme": "nosec" } },
+                "properties": {
+                    "on": { "type": "boolean", "forms": [{ "href": "/on" }] },
+                    "brightness": {
+                        "type": "integer",
+                        "minimum": 0,
+                        "maximum": 100,
+                        "forms": [{ "href": "/brightness", "contentType": "application/json" }]
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let second: Thing = serde_json::from_str(
+            r#"{
+                "properties": {
+                    "brightness": {
+                        "forms": [{ "contentType": "application/json", "href": "/brightness" }],
+                        "maximum": 100,
+                        "minimum": 0,
+                        "type": "integer"
+                    },
+                    "on": { "forms": [{ "href": "/on" }], "type": "boolean" }
+                },
+                "securityDefinitions": { "nosec_sc": { "scheme": "nosec" } },
+                "security": "nosec_sc",
+                "title": "Lamp",
+                "@context": "https://www.w3.org/2022/wot/td/v1.1"
+            }"#,
+        )
+        .unwrap();
+
+        let id = first.canonical_id().unwrap();
+        let CanonicalId::Content(content) = &id else {
+            unreachable!()
+        };
+        assert!(content.as_json().starts_with(r#"{"@context":"https://www.w3.org/2022/wot/td/v1.1","properties":{"brightness":{"forms":[{"contentType":"application/json","href":"/brightness"}],"#));
+        assert_eq!(second.canonical_id().unwrap(), id);
+
+        let mut third = second;
+        third.properties.as_mut().unwrap().remove("on");
+        assert_ne!(third.canonical_id().unwrap(), id);
+    }
+
+    #[test]
+    fn hash_collisions_are_not_duplicates() {
+        let content = |json: &str| CanonicalContent {
+            hash: 42,
+            json: json.to_string(),
+        };
+        let first = CanonicalId::Content(content(r#"{"title":"Lamp"}"#));
+        let second = CanonicalId::Content(content(r#"{"title":"Fan"}"#));
+        assert_ne!(first, second);
+
+        let mut seen = HashMap::new();
+        seen.insert(first.clone(), 0);
+        seen.insert(second.clone(), 1);
+        assert_eq!(seen.len(), 2);
+        assert_eq!(seen[&first], 0);
+        assert_eq!(seen[&second], 1);
+        assert_eq!(CanonicalId::Content(content(r#"{"title":"Lamp"}"#)), first);
+    }
+
+    #[test]
+    fn partition_duplicates() {
+        let lamp = |id: Option<&str>, title: &str| {
+            let mut value = json!({
+                "@context": TD_CONTEXT_11,
+                "title": title,
+                "security": [],
+                "securityDefinitions": {},
+            });
+            if let Some(id) = id {
+                value["id"] = json!(id);
+            }
+            thing(value)
+        };
+
+        let (unique, duplicates) = dedup_things([
+            lamp(Some("urn:lamp:1"), "Lamp"),
+            lamp(None, "Lamp"),
+            lamp(Some("urn:lamp:2"), "Lamp"),
+            lamp(Some("urn:lamp:1"), "Renamed lamp"),
+            lamp(None, "Lamp"),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            unique
+                .iter()
+                .map(|thing| thing.id.as_deref())
+                .collect::<Vec<_>>(),
+            [Some("urn:lamp:1"), None, Some("urn:lamp:2")],
+        );
+        assert_eq!(
+            duplicates
+                .iter()
+                .map(|duplicate| (duplicate.index, duplicate.duplicate_of))
+                .collect::<Vec<_>>(),
+            [(3, 0), (4, 1)],
+        );
+        assert_eq!(duplicates[0].id, CanonicalId::Id("urn:lamp:1".into()));
+        assert_eq!(duplicates[0].thing.title, "Renamed lamp");
+        assert!(matches!(duplicates[1].id, CanonicalId::Content(_)));
+    }
+}
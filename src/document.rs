@@ -0,0 +1,200 @@
+//! Parsing of Thing Descriptions and Thing Models
+//!
+//! A [Thing Model](https://www.w3.org/TR/wot-thing-description11/#thing-model) is a template for
+//! Thing Descriptions: it can omit the mandatory members, like `security`, and it can use
+//! placeholders like `"maximum": "{{MAX}}"` where a value is expected. A model is recognized by
+//! the `tm:ThingModel` term in its root `@type`, and [`parse_document`] uses it to avoid parsing
+//! a model as a [`Thing`].
+//!
+//! The crate has no type for the Thing Models, therefore they are returned as the raw JSON object.
+//!
+//! # Example
+//!
+//! ```
+//! # use wot_td::document::{parse_document, ThingDocument};
+//! #
+//! let document = parse_document::<wot_td::hlist::Nil>(
+//!     r#"{
+//!         "@context": "https://www.w3.org/2022/wot/td/v1.1",
+//!         "@type": "tm:ThingModel",
+//!         "title": "Lamp",
+//!         "properties": {
+//!             "brightness": { "type": "integer", "maximum": "{{MAX_BRIGHTNESS}}" }
+//!         }
+//!     }"#,
+//! )
+//! .unwrap();
+//!
+//! assert!(matches!(document, ThingDocument::ThingModel(_)));
+//! ```
+
+use alloc::{boxed::Box, string::String};
+use core::fmt;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{
+    extend::ExtendableThing,
+    thing::{AtType, Thing},
+};
+
+/// The namespace of the Thing Model vocabulary, used by the expanded `@type` terms.
+const TM_NAMESPACE: &str = "https://www.w3.org/2019/wot/tm#";
+
+/// A parsed document, either a Thing Description or a Thing Model.
+pub enum ThingDocument<Other: ExtendableThing> {
+    /// A Thing Description.
+    Thing(Box<Thing<Other>>),
+
+    /// A Thing Model, as the JSON object of the document.
+    ThingModel(serde_json::Map<String, Value>),
+}
+
+impl<Other> ThingDocument<Other>
+where
+    Other: ExtendableThing,
+{
+    /// Returns the Thing Description, if the document is not a Thing Model.
+    #[inline]
+    pub fn into_thing(self) -> Option<Thing<Other>> {
+        match self {
+            Self::Thing(thing) => Some(*thing),
+            Self::ThingModel(_) => None,
+        }
+    }
+
+    /// Returns `true` if the document is a Thing Model.
+    #[inline]
+    pub fn is_thing_model(&self) -> bool {
+        matches!(self, Self::ThingModel(_))
+    }
+}
+
+impl<Other> fmt::Debug for ThingDocument<Other>
+where
+    Other: ExtendableThing,
+    Thing<Other>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Thing(thing) => f.debug_tuple("Thing").field(thing).finish(),
+            Self::ThingModel(model) => f.debug_tuple("ThingModel").field(model).finish(),
+        }
+    }
+}
+
+/// Parses a JSON document as a Thing Model or as a Thing Description.
+///
+/// The document is a Thing Model when its root `@type` contains `tm:ThingModel`, or the expanded
+/// `https://www.w3.org/2019/wot/tm#ThingModel`. Otherwise it is deserialized as a [`Thing`].
+///
+/// # Errors
+///
+/// It fails if the document is not a JSON object, or if a Thing Description cannot be
+/// deserialized.
+pub fn parse_document<Other>(s: &str) -> Result<ThingDocument<Other>, serde_json::Error>
+where
+    Other: ExtendableThing,
+    Thing<Other>: DeserializeOwned,
+{
+    let object: serde_json::Map<String, Value> = serde_json::from_str(s)?;
+    if is_thing_model(&object) {
+        Ok(ThingDocument::ThingModel(object))
+    } else {
+        serde_json::from_value(Value::Object(object))
+            .map(|thing| ThingDocument::Thing(Box::new(thing)))
+    }
+}
+
+fn is_thing_model(object: &serde_json::Map<String, Value>) -> bool {
+    let is_tm_type = |value: &Value| {
+        value
+            .as_str()
+            .is_some_and(|term| match AtType::parse(term) {
+                AtType::Prefixed { prefix, local } => prefix == "tm" && local == "ThingModel",
+                AtType::AbsoluteIri(iri) => iri
+                    .strip_prefix(TM_NAMESPACE)
+                    .is_some_and(|local| local == "ThingModel"),
+                AtType::Term(_) => false,
+            })
+    };
+
+    match object.get("@type") {
+        Some(Value::Array(types)) => types.iter().any(is_tm_type),
+        Some(value) => is_tm_type(value),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use serde_json::json;
+
+    use crate::{hlist::Nil, thing::TD_CONTEXT_11};
+
+    use super::*;
+
+    fn parse(value: Value) -> Result<ThingDocument<Nil>, serde_json::Error> {
+        parse_document(&value.to_string())
+    }
+
+    #[test]
+    fn thing_description() {
+        let document = parse(json!({
+            "@context": TD_CONTEXT_11,
+            "@type": ["saref:LightSwitch"],
+            "title": "Lamp",
+            "security": ["nosec"],
+            "securityDefinitions": { "nosec": { "scheme": "nosec" } },
+            "properties": {
+                "brightness": {
+                    "type": "integer",
+                    "maximum": 100,
+                    "forms": [{ "href": "/brightness" }],
+                },
+            },
+        }))
+        .unwrap();
+
+        assert!(!document.is_thing_model());
+        let thing = document.into_thing().unwrap();
+        assert_eq!(thing.title, "Lamp");
+        assert_eq!(thing.validate(), Ok(()));
+    }
+
+    #[test]
+    fn thing_model() {
+        let model = json!({
+            "@context": [TD_CONTEXT_11, { "saref": "https://w3id.org/saref#" }],
+            "@type": ["saref:LightSwitch", "tm:ThingModel"],
+            "title": "Lamp",
+            "properties": {
+                "brightness": { "type": "integer", "maximum": "{{MAX_BRIGHTNESS}}" },
+            },
+        });
+        assert!(serde_json::from_value::<Thing>(model.clone()).is_err());
+
+        let document = parse(model.clone()).unwrap();
+        assert!(document.is_thing_model());
+        let ThingDocument::ThingModel(object) = document else {
+            unreachable!()
+        };
+        assert_eq!(Value::Object(object), model);
+
+        let expanded = parse(json!({
+            "@type": "https://www.w3.org/2019/wot/tm#ThingModel",
+            "title": "Lamp",
+        }))
+        .unwrap();
+        assert!(expanded.is_thing_model());
+    }
+
+    #[test]
+    fn invalid_documents() {
+        assert!(parse(json!(["tm:ThingModel"])).is_err());
+        assert!(parse(json!({ "@type": "ThingModel", "title": "Lamp" })).is_err());
+    }
+}
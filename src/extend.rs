@@ -5,7 +5,14 @@
 //! This module provides a trait, [ExtendableThing], to define extensions for each of the standard
 //! elements of a description.
 
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{any::type_name, marker::PhantomData};
+
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::hlist::{Cons, Nil};
 
@@ -160,3 +167,129 @@ impl<T, U, V> Extend<T> for Cons<U, V> {
         self.cons(t)
     }
 }
+
+/// A trait to automatically fill the missing _unit_ extensions of an heterogeneous list.
+///
+/// Extensions are added by _prepending_ elements to a list, therefore an extension that is still
+/// being built is always a suffix of the complete one. If all the missing elements are `()`,
+/// `Target` can be obtained without calling [`Extend::ext`] for each of them.
+///
+/// The `Index` type is only used to disambiguate the implementations, and it is always inferred by
+/// the compiler.
+pub trait AutoExtend<Target, Index> {
+    /// Prepends the missing `()` elements to obtain `Target`.
+    fn auto_extend(self) -> Target;
+}
+
+/// An [`AutoExtend`] index for a list that does not need further extensions.
+#[derive(Debug)]
+pub struct Here;
+
+/// An [`AutoExtend`] index for a list that needs to be extended with a `()` element.
+#[derive(Debug)]
+pub struct There<Index>(PhantomData<Index>);
+
+impl<T> AutoExtend<T, Here> for T {
+    #[inline]
+    fn auto_extend(self) -> T {
+        self
+    }
+}
+
+impl<T, U, Index> AutoExtend<Cons<(), U>, There<Index>> for T
+where
+    T: AutoExtend<U, Index>,
+{
+    #[inline]
+    fn auto_extend(self) -> Cons<(), U> {
+        Cons {
+            head: (),
+            tail: self.auto_extend(),
+        }
+    }
+}
+
+/// A list of extensions whose serialized members can be inspected.
+///
+/// It is implemented for every heterogeneous list of serializable extensions, and it is used by
+/// [`ThingBuilder::check_extension_collisions`].
+///
+/// [`ThingBuilder::check_extension_collisions`]: crate::builder::ThingBuilder::check_extension_collisions
+pub trait ExtensionFields {
+    /// Appends the type name of every extension of the list, along with the names of the members
+    /// it serializes.
+    ///
+    /// The extensions that are not serialized as JSON objects do not have any member.
+    fn extension_fields(&self, fields: &mut Vec<(&'static str, Vec<String>)>);
+}
+
+impl ExtensionFields for Nil {
+    #[inline]
+    fn extension_fields(&self, _fields: &mut Vec<(&'static str, Vec<String>)>) {}
+}
+
+impl<T, U> ExtensionFields for Cons<T, U>
+where
+    T: Serialize,
+    U: ExtensionFields,
+{
+    fn extension_fields(&self, fields: &mut Vec<(&'static str, Vec<String>)>) {
+        if let Ok(Value::Object(members)) = serde_json::to_value(&self.head) {
+            fields.push((
+                type_name::<T>(),
+                members.keys().map(ToString::to_string).collect(),
+            ));
+        }
+        self.tail.extension_fields(fields);
+    }
+}
+
+/// An [`ExtendableThing`] whose extensions can all be inspected with [`ExtensionFields`].
+///
+/// It is implemented for every heterogeneous list of extensions.
+pub trait ExtendableThingFields:
+    ExtendableThing<
+        InteractionAffordance: ExtensionFields,
+        PropertyAffordance: ExtensionFields,
+        ActionAffordance: ExtensionFields,
+        EventAffordance: ExtensionFields,
+        Form: ExtensionFields,
+        ExpectedResponse: ExtensionFields,
+        DataSchema: ExtensionFields,
+        ObjectSchema: ExtensionFields,
+        ArraySchema: ExtensionFields,
+    > + ExtensionFields
+{
+}
+
+impl<T> ExtendableThingFields for T where
+    T: ExtendableThing<
+            InteractionAffordance: ExtensionFields,
+            PropertyAffordance: ExtensionFields,
+            ActionAffordance: ExtensionFields,
+            EventAffordance: ExtensionFields,
+            Form: ExtensionFields,
+            ExpectedResponse: ExtensionFields,
+            DataSchema: ExtensionFields,
+            ObjectSchema: ExtensionFields,
+            ArraySchema: ExtensionFields,
+        > + ExtensionFields
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_extend_units() {
+        let list: Cons<(), Cons<(), Cons<u32, Nil>>> = Nil::cons(42u32).auto_extend();
+        assert_eq!(list, Nil::cons(42).cons(()).cons(()));
+
+        let list: Nil = Nil.auto_extend();
+        assert_eq!(list, Nil);
+
+        let list: Cons<(), Nil> = Nil.auto_extend();
+        assert_eq!(list, Nil::cons(()));
+    }
+}
@@ -1,5 +1,8 @@
 use alloc::vec::Vec;
-use core::fmt::{self, Display};
+use core::{
+    any::type_name,
+    fmt::{self, Display},
+};
 use serde::ser::{self, Impossible, Serialize, SerializeMap, Serializer};
 
 macro_rules! tri {
@@ -816,6 +819,26 @@ where
     }
 }
 
+/// Serializes a flattened value into a map, all at once.
+///
+/// The value is buffered before being forwarded to the map, therefore no entries are emitted when
+/// its serialization fails midway. The error is reported along with the type name of the value.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn serialize_flattened<M, T>(map: &mut M, value: &T) -> Result<(), M::Error>
+where
+    M: SerializeMap,
+    T: ?Sized + Serialize,
+{
+    let content = tri!(value
+        .serialize(ContentSerializer::<M::Error>::new())
+        .map_err(|err| ser::Error::custom(format_args!(
+            "cannot serialize extension {}: {}",
+            type_name::<T>(),
+            err
+        ))));
+    content.serialize(FlatMapSerializer(map))
+}
+
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub struct FlatMapSerializeMap<'a, M: 'a>(&'a mut M);
 
@@ -969,6 +992,7 @@ where
     }
 }
 
+#[allow(dead_code)]
 pub struct AdjacentlyTaggedEnumVariant {
     pub enum_name: &'static str,
     pub variant_index: u32,
@@ -986,6 +1010,7 @@ impl Serialize for AdjacentlyTaggedEnumVariant {
 
 // Error when Serialize for a non_exhaustive remote enum encounters a variant
 // that is not recognized.
+#[allow(dead_code)]
 pub struct CannotSerializeVariant<T>(pub T);
 
 impl<T> Display for CannotSerializeVariant<T>
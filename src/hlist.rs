@@ -2,14 +2,23 @@
 //!
 //! It is used for the internals of the extension system.
 
-use serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize};
+use serde::{
+    ser::{SerializeMap, SerializeStruct},
+    Deserialize, Deserializer, Serialize,
+};
+
+use crate::flat_map_serialize::{serialize_flattened, FlatMapSerializer};
 
 /// Empty type.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct Nil;
 
 /// List type.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// When serialized, the fields of every element are flattened into the same map. Each element is
+/// serialized as a whole before its fields are emitted, so that a failing element does not leave
+/// a partial map behind, and the error names the type of the element.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
 pub struct Cons<T, U = Nil> {
     /// The _head_ of the list.
     ///
@@ -24,6 +33,22 @@ pub struct Cons<T, U = Nil> {
     pub tail: U,
 }
 
+impl<T, U> Serialize for Cons<T, U>
+where
+    T: Serialize,
+    U: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        serialize_flattened(&mut map, &self.head)?;
+        self.tail.serialize(FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
 impl Nil {
     /// Prepend a new `head` the heterogeneous list.
     #[inline]
@@ -78,7 +103,7 @@ where
     }
 }
 
-impl<'a> HListRef for &'a Nil {
+impl HListRef for &Nil {
     type Target = Nil;
 
     #[inline]
@@ -126,7 +151,7 @@ where
     }
 }
 
-impl<'a> HListMut for &'a mut Nil {
+impl HListMut for &mut Nil {
     type Target = Nil;
 
     #[inline]
@@ -234,7 +259,7 @@ impl From<Nil> for () {
 
 #[cfg(test)]
 mod tests {
-    use alloc::string::*;
+    use alloc::{format, string::*, vec::Vec};
     use serde_json::{json, Value};
 
     use super::*;
@@ -433,4 +458,49 @@ mod tests {
             Nil::cons(C("hello".to_string())).cons(B(1.234)).cons(A(42)),
         )
     }
+
+    #[test]
+    fn serialize_failing_element() {
+        #[derive(Debug, Serialize)]
+        struct Valid {
+            valid: i32,
+        }
+
+        struct Failing {
+            fail: bool,
+        }
+
+        impl Serialize for Failing {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let mut map = serializer.serialize_map(None)?;
+                map.serialize_entry("partial", &1)?;
+                if self.fail {
+                    return Err(serde::ser::Error::custom("failing on purpose"));
+                }
+                map.serialize_entry("complete", &2)?;
+                map.end()
+            }
+        }
+
+        let list = Nil::cons(Valid { valid: 0 }).cons(Failing { fail: false });
+        assert_eq!(
+            serde_json::to_value(&list).unwrap(),
+            json!({ "valid": 0, "partial": 1, "complete": 2 }),
+        );
+
+        let list = Nil::cons(Failing { fail: true }).cons(Valid { valid: 0 });
+        let mut buffer = Vec::new();
+        let error = serde_json::to_writer(&mut buffer, &list).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "cannot serialize extension {}: failing on purpose",
+                core::any::type_name::<Failing>(),
+            ),
+        );
+        assert_eq!(buffer, br#"{"valid":0"#);
+    }
 }
@@ -0,0 +1,607 @@
+//! Conversion of JSON Schemas generated by [schemars](https://docs.rs/schemars)
+//!
+//! The payloads of the affordances are often Rust types, and deriving
+//! [`JsonSchema`](schemars::JsonSchema) for them avoids describing them by hand.
+//! [`DataSchema::from_json_schema`] translates the generated schema into a data schema, supporting
+//! the subset of JSON Schema that data schemas can express:
+//!
+//! - `type`, with the multiple types becoming a `oneOf` of the single types;
+//! - the metadata (`title`, `description`, `default`, `deprecated`, `readOnly` and `writeOnly`),
+//!   `format`, `enum` and `const`;
+//! - the bounds of numbers and integers, the lengths and the `pattern` of strings;
+//! - `items`, `minItems` and `maxItems` of arrays, `properties` and `required` of objects;
+//! - `oneOf`, an `anyOf` made of a schema and a `null` one, and an `allOf` of a single schema;
+//! - the references to the `definitions` of the root schema, which are inlined.
+//!
+//! The other constructs, like `patternProperties` or recursive references, cannot be represented
+//! and they are reported as [`ConvertError`]s.
+//!
+//! # Example
+//!
+//! ```
+//! # use schemars::{schema_for, JsonSchema};
+//! # use serde_json::json;
+//! # use wot_td::{hlist::Nil, thing::DataSchema};
+//! #
+//! #[derive(JsonSchema)]
+//! struct Fade {
+//!     #[schemars(range(max = 100))]
+//!     brightness: u8,
+//!     duration: Option<u32>,
+//! }
+//!
+//! let data_schema = DataSchema::<Nil, Nil, Nil>::from_json_schema(&schema_for!(Fade)).unwrap();
+//!
+//! assert_eq!(
+//!     data_schema.validate_value(&json!({ "brightness": 50, "duration": null })),
+//!     Ok(()),
+//! );
+//! assert!(data_schema
+//!     .validate_value(&json!({ "brightness": 150 }))
+//!     .is_err());
+//! ```
+
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::num::NonZeroU64;
+
+use hashbrown::HashMap;
+use schemars::{
+    schema::{
+        ArrayValidation, InstanceType, NumberValidation, ObjectValidation, RootSchema, Schema,
+        SchemaObject, SingleOrVec, StringValidation, SubschemaValidation,
+    },
+    Map,
+};
+
+use crate::{
+    hlist::Nil,
+    thing::{
+        ArraySchema, BoxedElemOrVec, DataSchema, DataSchemaSubtype, IntegerSchema, Maximum,
+        Minimum, NumberSchema, ObjectSchema, StringSchema,
+    },
+};
+
+type NilDataSchema = DataSchema<Nil, Nil, Nil>;
+
+/// A JSON Schema that cannot be converted into a data schema.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ConvertError {
+    /// A keyword, or a use of a keyword, that data schemas cannot express.
+    #[error("The JSON Schema keyword \"{0}\" is not supported")]
+    UnsupportedKeyword(&'static str),
+
+    /// The `false` schema, which does not accept any value.
+    #[error("The false schema is not supported")]
+    FalseSchema,
+
+    /// A reference that is not one of the `definitions` of the root schema.
+    #[error("The reference \"{0}\" cannot be resolved")]
+    UnresolvedReference(String),
+
+    /// A reference to a definition that is being converted, like the ones of recursive types.
+    #[error("The reference \"{0}\" is recursive")]
+    ReferenceCycle(String),
+
+    /// A bound of an integer schema that is not an integer.
+    #[error("The bound {0} of an integer schema is not an integer")]
+    InvalidIntegerBound(f64),
+
+    /// Both the inclusive and the exclusive version of a bound.
+    #[error("The JSON Schema has both {0} and its exclusive version")]
+    InclusiveAndExclusiveBound(&'static str),
+}
+
+impl DataSchema<Nil, Nil, Nil> {
+    /// Converts a JSON Schema generated by [schemars](https://docs.rs/schemars) into a data
+    /// schema.
+    ///
+    /// See the [module documentation](self) for the supported subset of JSON Schema. The
+    /// extensions of the JSON Schema (the unknown keywords) are ignored.
+    pub fn from_json_schema(schema: &RootSchema) -> Result<Self, ConvertError> {
+        Converter {
+            definitions: &schema.definitions,
+            resolving: Vec::new(),
+        }
+        .schema_object(&schema.schema)
+    }
+}
+
+struct Converter<'a> {
+    definitions: &'a Map<String, Schema>,
+    resolving: Vec<&'a str>,
+}
+
+impl<'a> Converter<'a> {
+    fn schema(&mut self, schema: &'a Schema) -> Result<NilDataSchema, ConvertError> {
+        match schema {
+            Schema::Bool(true) => Ok(DataSchema::default()),
+            Schema::Bool(false) => Err(ConvertError::FalseSchema),
+            Schema::Object(object) => self.schema_object(object),
+        }
+    }
+
+    fn schema_object(&mut self, object: &'a SchemaObject) -> Result<NilDataSchema, ConvertError> {
+        let subschemas = object.subschemas.as_deref();
+        let mut data_schema = match (&object.reference, subschemas) {
+            (Some(reference), _) => self.reference(reference)?,
+            (
+                None,
+                Some(SubschemaValidation {
+                    all_of: Some(all_of),
+                    ..
+                }),
+            ) => {
+                let [schema] = all_of.as_slice() else {
+                    return Err(ConvertError::UnsupportedKeyword("allOf"));
+                };
+                self.schema(schema)?
+            }
+            (None, _) => DataSchema::default(),
+        };
+
+        if let Some(metadata) = object.metadata.as_deref() {
+            if let Some(title) = &metadata.title {
+                data_schema.title = Some(title.clone());
+            }
+            if let Some(description) = &metadata.description {
+                data_schema.description = Some(description.clone());
+            }
+            if let Some(default) = &metadata.default {
+                data_schema.default = Some(default.clone());
+            }
+            if metadata.deprecated {
+                data_schema.deprecated = Some(true);
+            }
+            data_schema.read_only |= metadata.read_only;
+            data_schema.write_only |= metadata.write_only;
+        }
+        if let Some(format) = &object.format {
+            data_schema.format = Some(format.clone());
+        }
+        if let Some(enumeration) = &object.enum_values {
+            data_schema.enumeration = Some(enumeration.clone());
+        }
+        if let Some(constant) = &object.const_value {
+            data_schema.constant = Some(constant.clone());
+        }
+
+        match &object.instance_type {
+            Some(SingleOrVec::Single(ty)) => {
+                data_schema.subtype = Some(self.subtype(**ty, object)?);
+            }
+            Some(SingleOrVec::Vec(types)) => {
+                let one_of = types
+                    .iter()
+                    .map(|&ty| {
+                        Ok(DataSchema {
+                            subtype: Some(self.subtype(ty, object)?),
+                            ..Default::default()
+                        })
+                    })
+                    .collect::<Result<_, ConvertError>>()?;
+                data_schema.one_of = Some(one_of);
+            }
+            None => {}
+        }
+
+        if let Some(subschemas) = subschemas {
+            self.subschemas(subschemas, &mut data_schema)?;
+        }
+
+        Ok(data_schema)
+    }
+
+    fn reference(&mut self, reference: &'a str) -> Result<NilDataSchema, ConvertError> {
+        let name = reference
+            .strip_prefix("#/definitions/")
+            .or_else(|| reference.strip_prefix("#/$defs/"))
+            .ok_or_else(|| ConvertError::UnresolvedReference(reference.to_owned()))?;
+        let schema = self
+            .definitions
+            .get(name)
+            .ok_or_else(|| ConvertError::UnresolvedReference(reference.to_owned()))?;
+
+        if self.resolving.contains(&name) {
+            return Err(ConvertError::ReferenceCycle(reference.to_owned()));
+        }
+        self.resolving.push(name);
+        let data_schema = self.schema(schema);
+        self.resolving.pop();
+
+        data_schema
+    }
+
+    fn subschemas(
+        &mut self,
+        subschemas: &'a SubschemaValidation,
+        data_schema: &mut NilDataSchema,
+    ) -> Result<(), ConvertError> {
+        let SubschemaValidation {
+            all_of: _,
+            any_of,
+            one_of,
+            not,
+            if_schema,
+            then_schema,
+            else_schema,
+        } = subschemas;
+
+        if not.is_some() {
+            return Err(ConvertError::UnsupportedKeyword("not"));
+        }
+        if if_schema.is_some() || then_schema.is_some() || else_schema.is_some() {
+            return Err(ConvertError::UnsupportedKeyword("if"));
+        }
+
+        let one_of = match (one_of, any_of) {
+            (Some(_), Some(_)) => return Err(ConvertError::UnsupportedKeyword("anyOf")),
+            (Some(one_of), None) => one_of,
+            // `anyOf` is equivalent to `oneOf` only when the alternatives are disjoint, like the
+            // ones generated for `Option`.
+            (None, Some(any_of)) if is_nullable(any_of) => any_of,
+            (None, Some(_)) => return Err(ConvertError::UnsupportedKeyword("anyOf")),
+            (None, None) => return Ok(()),
+        };
+        if data_schema.one_of.is_some() {
+            return Err(ConvertError::UnsupportedKeyword("oneOf"));
+        }
+
+        let one_of = one_of
+            .iter()
+            .map(|schema| self.schema(schema))
+            .collect::<Result<_, _>>()?;
+        data_schema.one_of = Some(one_of);
+        Ok(())
+    }
+
+    fn subtype(
+        &mut self,
+        ty: InstanceType,
+        object: &'a SchemaObject,
+    ) -> Result<DataSchemaSubtype<Nil, Nil, Nil>, ConvertError> {
+        let subtype = match ty {
+            InstanceType::Null => DataSchemaSubtype::Null,
+            InstanceType::Boolean => DataSchemaSubtype::Boolean,
+            InstanceType::Integer => {
+                DataSchemaSubtype::Integer(integer_schema(object.number.as_deref())?)
+            }
+            InstanceType::Number => {
+                DataSchemaSubtype::Number(number_schema(object.number.as_deref())?)
+            }
+            InstanceType::String => {
+                DataSchemaSubtype::String(string_schema(object.string.as_deref()))
+            }
+            InstanceType::Array => {
+                DataSchemaSubtype::Array(self.array_schema(object.array.as_deref())?)
+            }
+            InstanceType::Object => {
+                DataSchemaSubtype::Object(self.object_schema(object.object.as_deref())?)
+            }
+        };
+
+        Ok(subtype)
+    }
+
+    fn array_schema(
+        &mut self,
+        array: Option<&'a ArrayValidation>,
+    ) -> Result<ArraySchema<Nil, Nil, Nil>, ConvertError> {
+        let Some(array) = array else {
+            return Ok(ArraySchema::default());
+        };
+
+        if array.additional_items.is_some() {
+            return Err(ConvertError::UnsupportedKeyword("additionalItems"));
+        }
+        if array.unique_items == Some(true) {
+            return Err(ConvertError::UnsupportedKeyword("uniqueItems"));
+        }
+        if array.contains.is_some() {
+            return Err(ConvertError::UnsupportedKeyword("contains"));
+        }
+
+        let items = match &array.items {
+            Some(SingleOrVec::Single(item)) => {
+                Some(BoxedElemOrVec::Elem(Box::new(self.schema(item)?)))
+            }
+            Some(SingleOrVec::Vec(items)) => Some(BoxedElemOrVec::Vec(
+                items
+                    .iter()
+                    .map(|item| self.schema(item))
+                    .collect::<Result<_, _>>()?,
+            )),
+            None => None,
+        };
+
+        Ok(ArraySchema {
+            items,
+            min_items: array.min_items,
+            max_items: array.max_items,
+            other: Nil,
+        })
+    }
+
+    fn object_schema(
+        &mut self,
+        object: Option<&'a ObjectValidation>,
+    ) -> Result<ObjectSchema<Nil, Nil, Nil>, ConvertError> {
+        let Some(object) = object else {
+            return Ok(ObjectSchema::default());
+        };
+
+        if !object.pattern_properties.is_empty() {
+            return Err(ConvertError::UnsupportedKeyword("patternProperties"));
+        }
+        if object
+            .additional_properties
+            .as_deref()
+            .is_some_and(|schema| *schema != Schema::Bool(true))
+        {
+            return Err(ConvertError::UnsupportedKeyword("additionalProperties"));
+        }
+        if object.property_names.is_some() {
+            return Err(ConvertError::UnsupportedKeyword("propertyNames"));
+        }
+        if object.min_properties.is_some() || object.max_properties.is_some() {
+            return Err(ConvertError::UnsupportedKeyword("minProperties"));
+        }
+
+        let properties = object
+            .properties
+            .iter()
+            .map(|(name, schema)| Ok((name.clone(), self.schema(schema)?)))
+            .collect::<Result<HashMap<_, _>, ConvertError>>()?;
+        let required = object
+            .required
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        Ok(ObjectSchema {
+            properties: (!properties.is_empty()).then_some(properties),
+            required: (!required.is_empty()).then_some(required),
+            other: Nil,
+        })
+    }
+}
+
+fn is_nullable(any_of: &[Schema]) -> bool {
+    let is_null = |schema: &Schema| {
+        matches!(
+            schema,
+            Schema::Object(SchemaObject {
+                instance_type: Some(SingleOrVec::Single(ty)),
+                ..
+            }) if **ty == InstanceType::Null
+        )
+    };
+
+    any_of.len() == 2 && any_of.iter().filter(|schema| is_null(schema)).count() == 1
+}
+
+fn integer_schema(number: Option<&NumberValidation>) -> Result<IntegerSchema, ConvertError> {
+    let Some(number) = number else {
+        return Ok(IntegerSchema::default());
+    };
+
+    let minimum = match (number.minimum, number.exclusive_minimum) {
+        (Some(_), Some(_)) => return Err(ConvertError::InclusiveAndExclusiveBound("minimum")),
+        (Some(minimum), None) => Some(Minimum::Inclusive(integer_bound(minimum)?)),
+        (None, Some(minimum)) => Some(Minimum::Exclusive(integer_bound(minimum)?)),
+        (None, None) => None,
+    };
+    let maximum = match (number.maximum, number.exclusive_maximum) {
+        (Some(_), Some(_)) => return Err(ConvertError::InclusiveAndExclusiveBound("maximum")),
+        (Some(maximum), None) => Some(Maximum::Inclusive(integer_bound(maximum)?)),
+        (None, Some(maximum)) => Some(Maximum::Exclusive(integer_bound(maximum)?)),
+        (None, None) => None,
+    };
+    let multiple_of = number
+        .multiple_of
+        .map(|multiple_of| {
+            integer_bound(multiple_of)
+                .ok()
+                .and_then(|multiple_of| u64::try_from(multiple_of).ok())
+                .and_then(NonZeroU64::new)
+                .ok_or(ConvertError::InvalidIntegerBound(multiple_of))
+        })
+        .transpose()?;
+
+    Ok(IntegerSchema {
+        minimum,
+        maximum,
+        multiple_of,
+    })
+}
+
+fn integer_bound(value: f64) -> Result<i64, ConvertError> {
+    // The cast saturates, therefore only `i64::MAX` can be the result of a value out of range.
+    let integer = value as i64;
+    if integer as f64 == value && integer != i64::MAX {
+        Ok(integer)
+    } else {
+        Err(ConvertError::InvalidIntegerBound(value))
+    }
+}
+
+fn number_schema(number: Option<&NumberValidation>) -> Result<NumberSchema, ConvertError> {
+    let Some(number) = number else {
+        return Ok(NumberSchema::default());
+    };
+
+    let minimum = match (number.minimum, number.exclusive_minimum) {
+        (Some(_), Some(_)) => return Err(ConvertError::InclusiveAndExclusiveBound("minimum")),
+        (Some(minimum), None) => Some(Minimum::Inclusive(minimum)),
+        (None, Some(minimum)) => Some(Minimum::Exclusive(minimum)),
+        (None, None) => None,
+    };
+    let maximum = match (number.maximum, number.exclusive_maximum) {
+        (Some(_), Some(_)) => return Err(ConvertError::InclusiveAndExclusiveBound("maximum")),
+        (Some(maximum), None) => Some(Maximum::Inclusive(maximum)),
+        (None, Some(maximum)) => Some(Maximum::Exclusive(maximum)),
+        (None, None) => None,
+    };
+
+    Ok(NumberSchema {
+        minimum,
+        maximum,
+        multiple_of: number.multiple_of,
+    })
+}
+
+fn string_schema(string: Option<&StringValidation>) -> StringSchema {
+    let Some(string) = string else {
+        return StringSchema::default();
+    };
+
+    StringSchema {
+        min_length: string.min_length,
+        max_length: string.max_length,
+        pattern: string.pattern.clone(),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use schemars::{schema_for, JsonSchema};
+    use serde::Serialize;
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(JsonSchema, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Fade {
+        /// The target brightness.
+        #[schemars(range(max = 100))]
+        brightness: u8,
+        duration: Option<u32>,
+        /// The shape of the transition.
+        curve: Curve,
+        steps: Vec<Step>,
+        last_step: Option<Step>,
+    }
+
+    #[derive(JsonSchema, Serialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Curve {
+        Linear,
+        Ease,
+    }
+
+    #[derive(JsonSchema, Serialize)]
+    struct Step {
+        at: f64,
+        #[schemars(length(min = 1))]
+        label: String,
+    }
+
+    #[test]
+    fn round_trip_derived_schema() {
+        let data_schema = DataSchema::from_json_schema(&schema_for!(Fade)).unwrap();
+
+        let Some(DataSchemaSubtype::Object(object)) = &data_schema.subtype else {
+            panic!("expected an object schema");
+        };
+        let mut required = object.required.clone().unwrap();
+        required.sort_unstable();
+        assert_eq!(required, ["brightness", "curve", "steps"]);
+
+        let properties = object.properties.as_ref().unwrap();
+        assert_eq!(
+            properties["brightness"].description.as_deref(),
+            Some("The target brightness."),
+        );
+        assert_eq!(
+            properties["brightness"].subtype,
+            Some(DataSchemaSubtype::Integer(IntegerSchema {
+                minimum: Some(Minimum::Inclusive(0)),
+                maximum: Some(Maximum::Inclusive(100)),
+                multiple_of: None,
+            })),
+        );
+        assert_eq!(
+            properties["curve"].description.as_deref(),
+            Some("The shape of the transition."),
+        );
+        assert_eq!(
+            properties["curve"].enumeration,
+            Some(vec![json!("linear"), json!("ease")]),
+        );
+        assert_eq!(properties["lastStep"].one_of.as_ref().unwrap().len(), 2);
+
+        let fade = Fade {
+            brightness: 40,
+            duration: None,
+            curve: Curve::Ease,
+            steps: vec![Step {
+                at: 0.5,
+                label: "half".to_string(),
+            }],
+            last_step: Some(Step {
+                at: 1.,
+                label: "end".to_string(),
+            }),
+        };
+        let mut value = serde_json::to_value(&fade).unwrap();
+        assert_eq!(data_schema.validate_value(&value), Ok(()));
+
+        value["brightness"] = json!(101);
+        assert!(data_schema.validate_value(&value).is_err());
+        value["brightness"] = json!(40);
+        value["curve"] = json!("bounce");
+        assert!(data_schema.validate_value(&value).is_err());
+        value["curve"] = serde_json::to_value(Curve::Linear).unwrap();
+        value["lastStep"]["label"] = json!("");
+        assert!(data_schema.validate_value(&value).is_err());
+    }
+
+    #[test]
+    fn unsupported_constructs() {
+        #[derive(JsonSchema)]
+        #[allow(dead_code)]
+        struct Tree {
+            children: Vec<Tree>,
+        }
+
+        assert_eq!(
+            DataSchema::from_json_schema(&schema_for!(Tree)),
+            Err(ConvertError::ReferenceCycle(
+                "#/definitions/Tree".to_string()
+            )),
+        );
+
+        let schema = |value| serde_json::from_value::<RootSchema>(value).unwrap();
+        assert_eq!(
+            DataSchema::from_json_schema(&schema(json!({
+                "type": "object",
+                "patternProperties": { "^x-": { "type": "string" } },
+            }))),
+            Err(ConvertError::UnsupportedKeyword("patternProperties")),
+        );
+        assert_eq!(
+            DataSchema::from_json_schema(&schema(json!({ "$ref": "#/definitions/Missing" }))),
+            Err(ConvertError::UnresolvedReference(
+                "#/definitions/Missing".to_string()
+            )),
+        );
+        assert_eq!(
+            DataSchema::from_json_schema(&schema(json!({ "type": "integer", "minimum": 0.5 }))),
+            Err(ConvertError::InvalidIntegerBound(0.5)),
+        );
+        assert_eq!(
+            DataSchema::from_json_schema(&schema(json!({
+                "anyOf": [{ "type": "string" }, { "type": "integer" }],
+            }))),
+            Err(ConvertError::UnsupportedKeyword("anyOf")),
+        );
+    }
+}
@@ -0,0 +1,192 @@
+//! Expansion of the compact IRIs of a Thing Description
+//!
+//! The TD vocabulary terms are defined by the TD `@context`, while the semantic annotations and
+//! the extensions usually rely on prefixes declared by additional `@context` entries, like
+//! `"saref": "https://w3id.org/saref#"`. The expansion performed here replaces the compact IRIs
+//! (`prefix:suffix`) used for the `@type` values and for the member names with the fully
+//! qualified IRIs, so that semantic reasoners do not need to process the `@context`.
+//!
+//! Besides the prefixes declared in the `@context`, the ones defined by the TD context (`td`,
+//! `jsonschema`, `wotsec`, `hctl`, `dct`, `schema` and `tm`) are always available. The names of
+//! the affordances, of the data schemas and of the security definitions, as well as the language
+//! keys of the multi-language maps, are indexes and they are never expanded.
+
+use alloc::{string::String, vec, vec::Vec};
+
+use serde_json::{Map, Value};
+
+const TD_PREFIXES: &[(&str, &str)] = &[
+    ("td", "https://www.w3.org/2019/wot/td#"),
+    ("jsonschema", "https://www.w3.org/2019/wot/json-schema#"),
+    ("wotsec", "https://www.w3.org/2019/wot/security#"),
+    ("hctl", "https://www.w3.org/2019/wot/hypermedia#"),
+    ("dct", "http://purl.org/dc/terms/"),
+    ("schema", "http://schema.org/"),
+    ("tm", "https://www.w3.org/2019/wot/tm#"),
+];
+
+const INDEX_CONTAINERS: &[&str] = &[
+    "properties",
+    "actions",
+    "events",
+    "uriVariables",
+    "schemaDefinitions",
+    "securityDefinitions",
+    "titles",
+    "descriptions",
+];
+
+/// The prefixes declared by a JSON-LD `@context`.
+struct Prefixes(Vec<(String, String)>);
+
+impl Prefixes {
+    fn from_context(context: &Value) -> Self {
+        let mut prefixes: Vec<(String, String)> = TD_PREFIXES
+            .iter()
+            .map(|&(prefix, iri)| (prefix.into(), iri.into()))
+            .collect();
+
+        let definitions = match context {
+            Value::Array(contexts) => contexts.iter().filter_map(Value::as_object).collect(),
+            Value::Object(definitions) => vec![definitions],
+            _ => Vec::new(),
+        };
+
+        for (prefix, definition) in definitions.into_iter().flatten() {
+            let iri = match definition {
+                Value::String(iri) => iri,
+                Value::Object(definition) => match definition.get("@id") {
+                    Some(Value::String(iri)) => iri,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            if prefix.starts_with('@') {
+                continue;
+            }
+
+            match prefixes.iter_mut().find(|(name, _)| name == prefix) {
+                Some((_, old_iri)) => old_iri.clone_from(iri),
+                None => prefixes.push((prefix.clone(), iri.clone())),
+            }
+        }
+
+        Self(prefixes)
+    }
+
+    fn expand(&self, term: &str) -> Option<String> {
+        let (prefix, suffix) = term.split_once(':')?;
+        if suffix.starts_with("//") {
+            return None;
+        }
+
+        self.0
+            .iter()
+            .find(|(name, _)| name == prefix)
+            .map(|(_, iri)| [iri.as_str(), suffix].concat())
+    }
+
+    fn expand_object(&self, object: Map<String, Value>) -> Map<String, Value> {
+        object
+            .into_iter()
+            .map(|(key, value)| {
+                let value = match key.as_str() {
+                    "@type" => self.expand_types(value),
+                    key if INDEX_CONTAINERS.contains(&key) => self.expand_index(value),
+                    _ => self.expand_value(value),
+                };
+                let key = self.expand(&key).unwrap_or(key);
+                (key, value)
+            })
+            .collect()
+    }
+
+    fn expand_index(&self, value: Value) -> Value {
+        match value {
+            Value::Object(index) => Value::Object(
+                index
+                    .into_iter()
+                    .map(|(name, value)| (name, self.expand_value(value)))
+                    .collect(),
+            ),
+            value => self.expand_value(value),
+        }
+    }
+
+    fn expand_value(&self, value: Value) -> Value {
+        match value {
+            Value::Object(object) => Value::Object(self.expand_object(object)),
+            Value::Array(values) => {
+                Value::Array(values.into_iter().map(|x| self.expand_value(x)).collect())
+            }
+            value => value,
+        }
+    }
+
+    fn expand_types(&self, value: Value) -> Value {
+        let types = match value {
+            Value::Array(types) => types,
+            value => vec![value],
+        };
+
+        Value::Array(
+            types
+                .into_iter()
+                .map(|ty| match ty {
+                    Value::String(ty) => Value::String(self.expand(&ty).unwrap_or(ty)),
+                    ty => ty,
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Expands the compact IRIs of a serialized Thing Description, removing its `@context`.
+pub(crate) fn expand(value: Value) -> Value {
+    let Value::Object(mut object) = value else {
+        return value;
+    };
+
+    let context = object.remove("@context").unwrap_or_default();
+    Value::Object(Prefixes::from_context(&context).expand_object(object))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn expand_member_names() {
+        let value = json!({
+            "@context": [
+                "https://www.w3.org/2022/wot/td/v1.1",
+                { "mqv": "http://www.example.org/mqtt-binding#", "@language": "en" },
+            ],
+            "actions": {
+                "mqv:toggle": {
+                    "forms": [{ "href": "mqtt://broker/toggle", "mqv:retain": true }],
+                },
+            },
+            "dct:creator": "someone",
+            "ex:unknown": "http://example.com",
+        });
+
+        assert_eq!(
+            expand(value),
+            json!({
+                "actions": {
+                    "mqv:toggle": {
+                        "forms": [{
+                            "href": "mqtt://broker/toggle",
+                            "http://www.example.org/mqtt-binding#retain": true,
+                        }],
+                    },
+                },
+                "http://purl.org/dc/terms/creator": "someone",
+                "ex:unknown": "http://example.com",
+            }),
+        );
+    }
+}
@@ -0,0 +1,367 @@
+//! Lenient parsing of Thing Descriptions
+//!
+//! Real-world Thing Descriptions often contain values that are technically valid but not written
+//! in their canonical form, like `"contentType": "Application/JSON "` or `"format": "Date-Time"`.
+//! The functions in this module normalize these values before deserializing, returning a
+//! [`NormalizationReport`] that lists every change that has been performed.
+//!
+//! The following members are normalized when they contain a string:
+//!
+//! - `contentType` and `contentMediaType`: the value is trimmed and the media type (the part
+//!   before the parameters) is lowercased;
+//! - `format`: the value is trimmed and lowercased if it matches one of the [`KNOWN_FORMATS`]
+//!   ignoring the case;
+//! - `unit`: the value is trimmed, the case is preserved because units are case sensitive.
+//!
+//! The values of the `const`, `default` and `enum` members of the data schemas are never modified.
+//! The data schemas are located following the structure of a Thing Description, therefore the
+//! members with the same names of the forms, the links or the extensions are normalized as usual.
+//!
+//! # Example
+//!
+//! ```
+//! # use serde_json::json;
+//! # use wot_td::{lenient, thing::Thing};
+//! #
+//! let (thing, report) = lenient::from_value::<Thing>(json!({
+//!     "@context": "https://www.w3.org/2022/wot/td/v1.1",
+//!     "title": "Thing name",
+//!     "forms": [{
+//!         "href": "/things",
+//!         "op": "readallproperties",
+//!         "contentType": "Application/JSON ",
+//!     }],
+//!     "security": [],
+//!     "securityDefinitions": {},
+//! }))
+//! .unwrap();
+//!
+//! assert_eq!(
+//!     thing.forms.unwrap()[0].content_type.as_deref(),
+//!     Some("application/json"),
+//! );
+//! assert_eq!(report.normalizations().len(), 1);
+//! assert_eq!(report.normalizations()[0].pointer, "/forms/0/contentType");
+//! ```
+
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::thing::walk_data_schema_values;
+
+/// The names of the formats defined by JSON Schema.
+///
+/// The lenient parser uses this table to decide whether a `format` value can be safely
+/// lowercased.
+pub const KNOWN_FORMATS: &[&str] = &[
+    "date-time",
+    "date",
+    "time",
+    "duration",
+    "email",
+    "idn-email",
+    "hostname",
+    "idn-hostname",
+    "ipv4",
+    "ipv6",
+    "uri",
+    "uri-reference",
+    "iri",
+    "iri-reference",
+    "uuid",
+    "uri-template",
+    "json-pointer",
+    "relative-json-pointer",
+    "regex",
+];
+
+/// A single normalization performed by the lenient parser.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Normalization {
+    /// The [JSON pointer](https://www.rfc-editor.org/rfc/rfc6901) of the normalized value.
+    pub pointer: String,
+
+    /// The value before the normalization.
+    pub original: String,
+
+    /// The value after the normalization.
+    pub normalized: String,
+}
+
+/// The list of normalizations performed by the lenient parser.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NormalizationReport {
+    normalizations: Vec<Normalization>,
+}
+
+impl NormalizationReport {
+    /// Returns the normalizations in the order they have been performed.
+    #[inline]
+    pub fn normalizations(&self) -> &[Normalization] {
+        &self.normalizations
+    }
+
+    /// Returns `true` if the input did not need any normalization.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.normalizations.is_empty()
+    }
+}
+
+/// Normalizes a JSON value in place, returning the report of the performed changes.
+///
+/// See the [module documentation](self) for the list of the normalized members.
+pub fn normalize(value: &mut Value) -> NormalizationReport {
+    let mut data_schemas = Vec::new();
+    walk_data_schema_values(value, &mut |pointer, _| {
+        data_schemas.push(pointer.to_owned())
+    });
+
+    let mut report = NormalizationReport::default();
+    normalize_value(value, &mut String::new(), &data_schemas, &mut report);
+    report
+}
+
+/// Deserializes an instance of `T` after normalizing the JSON value.
+///
+/// See the [module documentation](self) for the list of the normalized members.
+pub fn from_value<T>(mut value: Value) -> Result<(T, NormalizationReport), serde_json::Error>
+where
+    T: DeserializeOwned,
+{
+    let report = normalize(&mut value);
+    serde_json::from_value(value).map(|out| (out, report))
+}
+
+/// Deserializes an instance of `T` from a JSON string, normalizing it before.
+///
+/// See the [module documentation](self) for the list of the normalized members.
+pub fn from_str<T>(s: &str) -> Result<(T, NormalizationReport), serde_json::Error>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_str(s).and_then(from_value)
+}
+
+/// Returns the canonical form of a media type.
+///
+/// Whitespace is trimmed and the media type is lowercased, while the parameters are kept as they
+/// are because their values can be case sensitive.
+pub fn normalize_media_type(media_type: &str) -> String {
+    let media_type = media_type.trim();
+    match media_type.split_once(';') {
+        Some((essence, parameters)) => {
+            format!("{};{}", essence.trim_end().to_ascii_lowercase(), parameters)
+        }
+        None => media_type.to_ascii_lowercase(),
+    }
+}
+
+/// Returns the canonical form of a format name.
+///
+/// Whitespace is trimmed and, if the format is one of the [`KNOWN_FORMATS`], it is lowercased.
+pub fn normalize_format(format: &str) -> String {
+    let format = format.trim();
+    KNOWN_FORMATS
+        .iter()
+        .find(|known| known.eq_ignore_ascii_case(format))
+        .map_or_else(|| format.to_owned(), |&known| known.to_owned())
+}
+
+fn normalize_value(
+    value: &mut Value,
+    pointer: &mut String,
+    data_schemas: &[String],
+    report: &mut NormalizationReport,
+) {
+    match value {
+        Value::Object(map) => {
+            let is_data_schema = data_schemas.contains(pointer);
+            for (key, value) in map.iter_mut() {
+                let len = pointer.len();
+                pointer.push('/');
+                pointer.push_str(&key.replace('~', "~0").replace('/', "~1"));
+
+                match (key.as_str(), &mut *value) {
+                    ("const" | "default" | "enum", _) if is_data_schema => {}
+                    ("contentType" | "contentMediaType", Value::String(s)) => {
+                        replace_string(s, normalize_media_type, pointer, report)
+                    }
+                    ("format", Value::String(s)) => {
+                        replace_string(s, normalize_format, pointer, report)
+                    }
+                    ("unit", Value::String(s)) => {
+                        replace_string(s, |s| s.trim().to_owned(), pointer, report)
+                    }
+                    _ => normalize_value(value, pointer, data_schemas, report),
+                }
+
+                pointer.truncate(len);
+            }
+        }
+        Value::Array(values) => {
+            for (index, value) in values.iter_mut().enumerate() {
+                let len = pointer.len();
+                pointer.push_str(&format!("/{index}"));
+                normalize_value(value, pointer, data_schemas, report);
+                pointer.truncate(len);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn replace_string(
+    s: &mut String,
+    f: impl FnOnce(&str) -> String,
+    pointer: &str,
+    report: &mut NormalizationReport,
+) {
+    let normalized = f(s);
+    if normalized != *s {
+        let original = core::mem::replace(s, normalized.clone());
+        report.normalizations.push(Normalization {
+            pointer: pointer.to_owned(),
+            original,
+            normalized,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec};
+
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use crate::thing::{DataSchemaSubtype, Thing};
+
+    use super::*;
+
+    #[test]
+    fn normalize_messy_thing() {
+        let (thing, report) = from_value::<Thing>(json!({
+            "@context": "https://www.w3.org/2022/wot/td/v1.1",
+            "title": "Thing name",
+            "properties": {
+                "format": {
+                    "type": "string",
+                    "format": "Date-Time",
+                    "contentMediaType": " Text/Plain;charset=UTF-8",
+                    "unit": " mV ",
+                    "default": "Date-Time",
+                    "forms": [{
+                        "href": "/format",
+                        "contentType": "Application/JSON ",
+                    }],
+                },
+            },
+            "security": [],
+            "securityDefinitions": {},
+        }))
+        .unwrap();
+
+        let property = &thing.properties.as_ref().unwrap()["format"];
+        assert_eq!(property.data_schema.format.as_deref(), Some("date-time"));
+        assert_eq!(property.data_schema.unit.as_deref(), Some("mV"));
+        assert_eq!(property.data_schema.default, Some(json!("Date-Time")));
+        match property.data_schema.subtype.as_ref().unwrap() {
+            DataSchemaSubtype::String(string) => assert_eq!(
+                string.content_media_type.as_deref(),
+                Some("text/plain;charset=UTF-8"),
+            ),
+            _ => panic!("expected a string subtype"),
+        }
+        assert_eq!(
+            property.interaction.forms[0].content_type.as_deref(),
+            Some("application/json"),
+        );
+
+        let mut normalizations = report.normalizations().to_vec();
+        normalizations.sort_by(|a, b| a.pointer.cmp(&b.pointer));
+        assert_eq!(
+            normalizations,
+            vec![
+                Normalization {
+                    pointer: "/properties/format/contentMediaType".to_string(),
+                    original: " Text/Plain;charset=UTF-8".to_string(),
+                    normalized: "text/plain;charset=UTF-8".to_string(),
+                },
+                Normalization {
+                    pointer: "/properties/format/format".to_string(),
+                    original: "Date-Time".to_string(),
+                    normalized: "date-time".to_string(),
+                },
+                Normalization {
+                    pointer: "/properties/format/forms/0/contentType".to_string(),
+                    original: "Application/JSON ".to_string(),
+                    normalized: "application/json".to_string(),
+                },
+                Normalization {
+                    pointer: "/properties/format/unit".to_string(),
+                    original: " mV ".to_string(),
+                    normalized: "mV".to_string(),
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn skip_only_data_schema_values() {
+        let mut value = json!({
+            "properties": {
+                "enum": {
+                    "type": "object",
+                    "format": "URI",
+                    "properties": {
+                        "default": { "type": "string", "format": "Date-Time" },
+                    },
+                    "default": { "default": { "format": "Date-Time" } },
+                    "forms": [{ "href": "/enum", "default": { "contentType": "Text/Plain" } }],
+                },
+            },
+        });
+
+        let report = normalize(&mut value);
+        let mut pointers = report
+            .normalizations()
+            .iter()
+            .map(|normalization| normalization.pointer.as_str())
+            .collect::<Vec<_>>();
+        pointers.sort_unstable();
+        assert_eq!(
+            pointers,
+            [
+                "/properties/enum/format",
+                "/properties/enum/forms/0/default/contentType",
+                "/properties/enum/properties/default/format",
+            ],
+        );
+        assert_eq!(
+            value["properties"]["enum"]["default"],
+            json!({ "default": { "format": "Date-Time" } }),
+        );
+    }
+
+    #[test]
+    fn unknown_formats_are_only_trimmed() {
+        assert_eq!(normalize_format(" My-Format "), "My-Format");
+        assert_eq!(normalize_format("IPv4"), "ipv4");
+    }
+
+    #[test]
+    fn clean_input_has_empty_report() {
+        let mut value = json!({
+            "contentType": "application/json",
+            "format": "uri",
+            "unit": "m/s",
+        });
+        let expected = value.clone();
+
+        assert!(normalize(&mut value).is_empty());
+        assert_eq!(value, expected);
+    }
+}
@@ -81,11 +81,49 @@ extern crate std;
 extern crate alloc;
 
 pub mod builder;
+pub mod coerce;
+pub mod dedup;
+pub mod document;
 pub mod extend;
 pub mod hlist;
+#[cfg(feature = "schemars")]
+pub mod json_schema;
+pub mod lenient;
+pub mod limits;
+pub mod lint;
+#[cfg(feature = "profiles")]
+pub mod profiles;
 pub mod protocol;
+pub mod redact;
+pub mod serde_helpers;
+pub mod size;
+pub mod template;
 pub mod thing;
+pub mod unchecked;
+#[cfg(feature = "units")]
+pub mod units;
+pub mod validate;
 
 pub use crate::thing::Thing;
 
+/// The map used by the public types of the crate, like [`Thing::properties`].
+///
+/// It is the `HashMap` of [hashbrown](https://docs.rs/hashbrown), which is available without
+/// `std`. Downstream crates exposing the types of the crate in their own API can name the maps
+/// through this alias, without depending on a matching version of `hashbrown`.
+///
+/// Being an alias, the types of the crate are unchanged: the code naming
+/// `hashbrown::HashMap` directly keeps compiling.
+pub type Map<K, V> = hashbrown::HashMap<K, V>;
+
+#[cfg(not(feature = "verbose-debug"))]
+mod debug;
 mod flat_map_serialize;
+#[cfg(feature = "jsonld")]
+mod jsonld;
+mod macros;
+mod openapi;
+mod serialized_size;
+#[cfg(feature = "preserve-unknown")]
+mod unknown;
+mod uri;
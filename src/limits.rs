@@ -0,0 +1,431 @@
+//! Limits for the Thing Descriptions received from untrusted peers
+//!
+//! A hostile Thing Description can exhaust the stack with deeply nested data schemas or the
+//! memory with huge collections. [`thing::from_slice_with_limits`] deserializes a Thing
+//! enforcing the [`DeserializeLimits`]:
+//!
+//! - the nesting depth and the length of the strings are checked on the raw document, without
+//!   recursion, before anything is parsed, therefore the recursive deserialization cannot
+//!   exhaust the stack and an oversized string is never allocated;
+//! - the number of affordances is checked on the parsed JSON document;
+//! - the number of properties of the object schemas and of `oneOf` alternatives are checked on
+//!   the deserialized data schemas.
+//!
+//! The last two limits are checked after the document has been parsed, therefore they bound the
+//! size of the returned Thing but not the memory used to parse it, which grows with the length of
+//! the document. The length of untrusted documents should be limited before calling
+//! [`thing::from_slice_with_limits`].
+//!
+//! # Example
+//!
+//! ```
+//! # use wot_td::{
+//! #     limits::{DeserializeLimits, Error, Limit},
+//! #     thing::{from_slice_with_limits, Thing},
+//! # };
+//! #
+//! let document = br#"{
+//!     "@context": "https://www.w3.org/2022/wot/td/v1.1",
+//!     "title": "Thing name",
+//!     "security": [],
+//!     "securityDefinitions": {},
+//!     "schemaDefinitions": { "nested": { "type": "array", "items": { "type": "array" } } }
+//! }"#;
+//!
+//! let limits = DeserializeLimits {
+//!     max_depth: 3,
+//!     ..Default::default()
+//! };
+//! let error = from_slice_with_limits::<wot_td::hlist::Nil>(document, &limits).unwrap_err();
+//! assert!(matches!(
+//!     error,
+//!     Error::LimitExceeded {
+//!         which: Limit::Depth,
+//!         limit: 3,
+//!         actual: 4,
+//!     },
+//! ));
+//!
+//! let thing: Thing = from_slice_with_limits(document, &DeserializeLimits::default()).unwrap();
+//! # drop(thing);
+//! ```
+//!
+//! [`thing::from_slice_with_limits`]: crate::thing::from_slice_with_limits
+
+use core::fmt;
+
+/// The limits enforced by [`from_slice_with_limits`](crate::thing::from_slice_with_limits).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DeserializeLimits {
+    /// The maximum nesting depth of the arrays and objects of the document.
+    ///
+    /// The Thing Description itself is at depth 1. It defaults to 64.
+    pub max_depth: usize,
+
+    /// The maximum number of affordances, counting the properties, the actions and the events.
+    ///
+    /// It defaults to 1024.
+    pub max_affordances: usize,
+
+    /// The maximum number of `properties` of an object data schema.
+    ///
+    /// It defaults to 256.
+    pub max_object_properties: usize,
+
+    /// The maximum length in bytes of the strings, including the names of the members.
+    ///
+    /// It defaults to 65536.
+    pub max_string_length: usize,
+
+    /// The maximum number of alternatives of a `oneOf` data schema.
+    ///
+    /// It defaults to 64.
+    pub max_one_of: usize,
+}
+
+impl Default for DeserializeLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 64,
+            max_affordances: 1024,
+            max_object_properties: 256,
+            max_string_length: 65536,
+            max_one_of: 64,
+        }
+    }
+}
+
+/// One of the [`DeserializeLimits`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Limit {
+    /// [`DeserializeLimits::max_depth`].
+    Depth,
+
+    /// [`DeserializeLimits::max_affordances`].
+    Affordances,
+
+    /// [`DeserializeLimits::max_object_properties`].
+    ObjectProperties,
+
+    /// [`DeserializeLimits::max_string_length`].
+    StringLength,
+
+    /// [`DeserializeLimits::max_one_of`].
+    OneOf,
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Depth => "nesting depth",
+            Self::Affordances => "number of affordances",
+            Self::ObjectProperties => "number of object properties",
+            Self::StringLength => "string length",
+            Self::OneOf => "number of oneOf alternatives",
+        };
+
+        f.write_str(s)
+    }
+}
+
+/// An error deserializing a Thing Description with limits.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The document is not a valid Thing Description.
+    #[error("Invalid Thing Description: {0}")]
+    Json(serde_json::Error),
+
+    /// The document exceeds one of the limits.
+    #[error("The {which} is {actual}, exceeding the limit of {limit}")]
+    LimitExceeded {
+        /// The exceeded limit.
+        which: Limit,
+
+        /// The value of the limit.
+        limit: usize,
+
+        /// The value found in the document.
+        actual: usize,
+    },
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
+/// Checks that `actual` does not exceed `limit`.
+pub(crate) fn check_limit(which: Limit, limit: usize, actual: usize) -> Result<(), Error> {
+    if actual > limit {
+        Err(Error::LimitExceeded {
+            which,
+            limit,
+            actual,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// The metrics of a JSON document collected by [`scan`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Scan {
+    /// The maximum nesting depth of the arrays and the objects.
+    pub(crate) depth: usize,
+
+    /// The maximum length in bytes of the decoded strings, including the names of the members.
+    pub(crate) string_length: usize,
+}
+
+/// Scans a JSON document without parsing it.
+///
+/// Nothing is allocated, therefore the limits can be checked on the result before the document
+/// is parsed. The length of a string is the one it has once its escape sequences are decoded.
+/// The result is meaningless if the document is not valid JSON.
+pub(crate) fn scan(bytes: &[u8]) -> Scan {
+    let mut scan = Scan::default();
+    let mut depth = 0usize;
+    let mut string_length = None;
+    let mut escaped = false;
+    let mut unicode_escape: Option<(u8, u32)> = None;
+
+    for &byte in bytes {
+        let Some(length) = &mut string_length else {
+            match byte {
+                b'"' => string_length = Some(0),
+                b'[' | b'{' => {
+                    depth += 1;
+                    scan.depth = scan.depth.max(depth);
+                }
+                b']' | b'}' => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            continue;
+        };
+
+        if let Some((digits, code)) = &mut unicode_escape {
+            let digit = char::from(byte).to_digit(16).unwrap_or(0);
+            *code = *code * 16 + digit;
+            *digits += 1;
+            if *digits == 4 {
+                // Each half of a surrogate pair counts for half of the 4 bytes of the character.
+                *length += match *code {
+                    0..=0x7f => 1,
+                    0x80..=0x7ff | 0xd800..=0xdfff => 2,
+                    _ => 3,
+                };
+                unicode_escape = None;
+            }
+            continue;
+        }
+
+        match byte {
+            b'u' if escaped => {
+                escaped = false;
+                unicode_escape = Some((0, 0));
+            }
+            _ if escaped => {
+                escaped = false;
+                *length += 1;
+            }
+            b'\\' => escaped = true,
+            b'"' => {
+                scan.string_length = scan.string_length.max(*length);
+                string_length = None;
+            }
+            _ => *length += 1,
+        }
+    }
+
+    scan
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString, vec::Vec};
+
+    use serde_json::{json, Value};
+
+    use crate::{
+        hlist::Nil,
+        thing::{from_slice_with_limits, Thing, TD_CONTEXT_11},
+    };
+
+    use super::*;
+
+    fn document(members: Value) -> Vec<u8> {
+        let mut thing = json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Thing name",
+            "security": [],
+            "securityDefinitions": {},
+        });
+        thing
+            .as_object_mut()
+            .unwrap()
+            .extend(members.as_object().unwrap().clone());
+        serde_json::to_vec(&thing).unwrap()
+    }
+
+    fn limit_exceeded(bytes: &[u8], limits: &DeserializeLimits) -> (Limit, usize, usize) {
+        match from_slice_with_limits::<Nil>(bytes, limits) {
+            Err(Error::LimitExceeded {
+                which,
+                limit,
+                actual,
+            }) => (which, limit, actual),
+            Err(error) => panic!("unexpected error: {error}"),
+            Ok(_) => panic!("the limits have not been enforced"),
+        }
+    }
+
+    #[test]
+    fn deeply_nested_document() {
+        let depth = 100_000;
+        let schema = format!(
+            "{}{}",
+            r#"{"type":"array","items":"#.repeat(depth),
+            "}".repeat(depth)
+        );
+        let bytes = format!(
+            r#"{{"@context":"{TD_CONTEXT_11}","title":"Thing name","security":[],"securityDefinitions":{{}},"schemaDefinitions":{{"deep":{schema}}}}}"#
+        );
+
+        assert_eq!(
+            limit_exceeded(bytes.as_bytes(), &DeserializeLimits::default()),
+            (Limit::Depth, 64, depth + 2),
+        );
+    }
+
+    #[test]
+    fn too_many_affordances() {
+        let properties = (0..5)
+            .map(|index| {
+                (
+                    format!("property{index}"),
+                    json!({ "type": "integer", "forms": [{ "href": "/" }] }),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>();
+        let bytes = document(json!({
+            "properties": properties,
+            "actions": { "fade": { "forms": [{ "href": "/fade" }] } },
+        }));
+        let limits = DeserializeLimits {
+            max_affordances: 5,
+            ..Default::default()
+        };
+
+        assert_eq!(limit_exceeded(&bytes, &limits), (Limit::Affordances, 5, 6));
+        let thing: Thing = from_slice_with_limits(&bytes, &DeserializeLimits::default()).unwrap();
+        assert_eq!(thing.properties.unwrap().len(), 5);
+    }
+
+    #[test]
+    fn too_many_object_properties() {
+        let properties = (0..300)
+            .map(|index| (format!("member{index}"), json!({ "type": "string" })))
+            .collect::<serde_json::Map<_, _>>();
+        let bytes = document(json!({
+            "actions": {
+                "configure": {
+                    "input": {
+                        "type": "object",
+                        "properties": {
+                            "options": { "type": "object", "properties": properties },
+                        },
+                    },
+                    "forms": [{ "href": "/configure" }],
+                },
+            },
+        }));
+
+        assert_eq!(
+            limit_exceeded(&bytes, &DeserializeLimits::default()),
+            (Limit::ObjectProperties, 256, 300),
+        );
+    }
+
+    #[test]
+    fn too_long_string() {
+        let description = "a".repeat(100);
+        let bytes = document(json!({ "description": description }));
+        let limits = DeserializeLimits {
+            max_string_length: 64,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            limit_exceeded(&bytes, &limits),
+            (Limit::StringLength, 64, 100)
+        );
+
+        let bytes = document(json!({ "schemaDefinitions": { description: {} } }));
+        assert_eq!(
+            limit_exceeded(&bytes, &limits),
+            (Limit::StringLength, 64, 100)
+        );
+    }
+
+    #[test]
+    fn too_long_string_before_parsing() {
+        // The document is truncated after the string, therefore parsing it would fail: the
+        // string is rejected before the document is parsed.
+        let bytes = format!(r#"{{"title": "{}", "#, "a".repeat(100));
+        let limits = DeserializeLimits {
+            max_string_length: 64,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            limit_exceeded(bytes.as_bytes(), &limits),
+            (Limit::StringLength, 64, 100)
+        );
+    }
+
+    #[test]
+    fn too_many_one_of_alternatives() {
+        let one_of = (0..65)
+            .map(|index| json!({ "const": index }))
+            .collect::<Vec<_>>();
+        let bytes = document(json!({
+            "uriVariables": { "mode": { "oneOf": one_of } },
+        }));
+
+        assert_eq!(
+            limit_exceeded(&bytes, &DeserializeLimits::default()),
+            (Limit::OneOf, 64, 65),
+        );
+    }
+
+    #[test]
+    fn invalid_document() {
+        let error = from_slice_with_limits::<Nil>(b"{", &DeserializeLimits::default()).unwrap_err();
+        assert!(matches!(error, Error::Json(_)));
+        assert!(error.to_string().starts_with("Invalid Thing Description"));
+    }
+
+    #[test]
+    fn scan_depth_ignores_strings() {
+        assert_eq!(scan(b"42").depth, 0);
+        assert_eq!(scan(br#"{"a": [1, {"b": []}], "c": {}}"#).depth, 4);
+        assert_eq!(scan(br#"{"a": "[[[{{{", "b": "\"[[["}"#).depth, 1);
+        assert_eq!(scan(br#"["\\", [["\\\"]"]]]"#).depth, 3);
+    }
+
+    #[test]
+    fn scan_decoded_string_length() {
+        assert_eq!(scan(b"42").string_length, 0);
+        assert_eq!(scan(br#"{"abc": "de"}"#).string_length, 3);
+        assert_eq!(scan(br#"["\n\"\\", "\u0041"]"#).string_length, 3);
+        assert_eq!(scan(r#"["\u00e8", "è"]"#.as_bytes()).string_length, 2);
+        assert_eq!(
+            scan(br#"["\u20ac", "\ud83d\ude00"]"#),
+            Scan {
+                depth: 1,
+                string_length: 4,
+            },
+        );
+    }
+}
@@ -0,0 +1,472 @@
+//! Non-fatal checks of Thing Descriptions
+//!
+//! Some constructs are valid according to the specification, but they are likely to be mistakes
+//! or they are handled poorly by many consumers. [`Thing::lint`] reports them as
+//! [`LintWarning`]s, each one including the path of the offending member, using the TD member
+//! names separated by dots (e.g. `properties.status.oneOf.1`).
+//!
+//! [`ThingBuilder::build_with_report`](crate::builder::ThingBuilder::build_with_report) builds a
+//! Thing and lints it at once.
+//!
+//! A [`LintConfig`] enables the checks with configurable thresholds, which are meant to keep the
+//! Thing Descriptions within the budget of constrained transports. They are disabled by default,
+//! and they are enabled by [`Thing::lint_with`] and
+//! [`ThingBuilder::build_with_report_with`](crate::builder::ThingBuilder::build_with_report_with).
+//!
+//! [`Thing::lint`]: crate::thing::Thing::lint
+//! [`Thing::lint_with`]: crate::thing::Thing::lint_with
+
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt::{self, Write},
+    mem,
+};
+
+use hashbrown::HashMap;
+
+use crate::{
+    extend::ExtendableThing,
+    thing::{
+        DataSchema, DataSchemaSubtype, DefaultedFormOperations, Form, FormOperation,
+        InteractionAffordance, MultiLanguage, Thing, DEFAULT_CONTENT_TYPE,
+    },
+};
+
+/// A construct that is valid, but likely to be a mistake.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintWarning {
+    /// A data schema has both `oneOf` and a `type`.
+    ///
+    /// The value must satisfy both, which is rarely the intent: the `type` is usually meant to be
+    /// declared by each alternative of `oneOf`.
+    OneOfWithSubtype(String),
+
+    /// A string schema has a `contentEncoding` without a `contentMediaType`.
+    ///
+    /// The decoded contents cannot be interpreted, and several consumers reject the schema.
+    ContentEncodingWithoutMediaType(String),
+
+    /// A form of a property has an operation that contradicts the property schema.
+    ///
+    /// It is either a `readproperty` operation on a `writeOnly` property or a `writeproperty`
+    /// operation on a `readOnly` property, which consumers are not expected to perform.
+    ContradictoryFormOperation(String),
+
+    /// A URI variable of an affordance has the same name of one of the Thing.
+    ///
+    /// The affordance variable shadows the Thing one, and consumers disagree on which one is used
+    /// to expand the `href` templates.
+    ShadowedUriVariable(String),
+
+    /// The `unit` of a data schema is not a known unit.
+    ///
+    /// It is only reported with the `units` feature, see the `units` module it enables for the
+    /// known units. The unit can still be correct, but consumers are unlikely to convert it.
+    UnrecognizedUnit(String),
+
+    /// A successful additional response of a form overlaps with the main response.
+    ///
+    /// It has `success` and the same content type of the main response, as does another
+    /// additional response before it. Consumers cannot tell which one describes a successful
+    /// response, therefore at most one of them should be declared.
+    OverlappingSuccessResponse(String),
+
+    /// The values of the `enum` of a data schema have different JSON types.
+    ///
+    /// It is valid JSON Schema, but it is usually an authoring mistake and some consumers reject
+    /// it. A data schema with a `type` is required to list values of that type instead.
+    MixedEnumTypes(String),
+
+    /// The estimated size of the serialized Thing Description exceeds
+    /// [`LintConfig::max_serialized_size`].
+    ///
+    /// It contains the number of bytes counted when the limit was exceeded, which is a lower bound
+    /// of the estimated size, because the estimation stops there. See
+    /// [`Thing::serialized_size_estimate`](crate::thing::Thing::serialized_size_estimate) for the
+    /// whole size.
+    SerializedSizeExceeded(usize),
+
+    /// A description is longer than [`LintConfig::max_description_length`].
+    DescriptionTooLong(String),
+
+    /// The `titles` or the `descriptions` have more translations than
+    /// [`LintConfig::max_translations`].
+    TooManyTranslations(String),
+}
+
+impl fmt::Display for LintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OneOfWithSubtype(path) => {
+                write!(f, "The data schema at {path} has both oneOf and type")
+            }
+            Self::ContentEncodingWithoutMediaType(path) => write!(
+                f,
+                "The string schema at {path} has contentEncoding without contentMediaType"
+            ),
+            Self::ContradictoryFormOperation(path) => write!(
+                f,
+                "The form operation at {path} contradicts readOnly or writeOnly of the property"
+            ),
+            Self::ShadowedUriVariable(path) => write!(
+                f,
+                "The URI variable at {path} shadows a URI variable of the Thing"
+            ),
+            Self::UnrecognizedUnit(path) => {
+                write!(f, "The unit at {path} is not a known unit")
+            }
+            Self::OverlappingSuccessResponse(path) => write!(
+                f,
+                "The successful additional response at {path} overlaps with the main response"
+            ),
+            Self::MixedEnumTypes(path) => write!(
+                f,
+                "The enum of the data schema at {path} has values of different types"
+            ),
+            Self::SerializedSizeExceeded(size) => write!(
+                f,
+                "The estimated size of the Thing Description (at least {size} bytes) exceeds the limit"
+            ),
+            Self::DescriptionTooLong(path) => {
+                write!(f, "The description at {path} is too long")
+            }
+            Self::TooManyTranslations(path) => {
+                write!(f, "The translations at {path} are too many")
+            }
+        }
+    }
+}
+
+/// The thresholds of the configurable checks.
+///
+/// Each check is disabled when its threshold is `None`, which is the default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct LintConfig {
+    /// The maximum estimated size in bytes of the serialized Thing Description.
+    ///
+    /// The size is estimated walking the Thing like a serializer, without producing the JSON
+    /// document, and the walk stops as soon as the limit is exceeded. Therefore the check of a
+    /// Thing within the limit costs about as much as serializing it, while the check of a larger
+    /// one only costs as much as serializing the first `max_serialized_size` bytes.
+    pub max_serialized_size: Option<usize>,
+
+    /// The maximum number of characters of a description or of one of its translations.
+    pub max_description_length: Option<usize>,
+
+    /// The maximum number of translations of the `titles` and of the `descriptions`.
+    pub max_translations: Option<usize>,
+}
+
+/// Lints a Thing Description, returning the warnings sorted by kind and path.
+///
+/// The serialized size is not checked here, because it requires the Thing to be serializable.
+pub(crate) fn lint<Other: ExtendableThing>(
+    thing: &Thing<Other>,
+    config: &LintConfig,
+) -> Vec<LintWarning> {
+    let mut linter = Linter {
+        config: *config,
+        thing_uri_variables: thing
+            .uri_variables
+            .iter()
+            .flat_map(|uri_variables| uri_variables.keys().map(String::as_str))
+            .collect(),
+        default_content_type: thing
+            .default_content_type
+            .as_deref()
+            .unwrap_or(DEFAULT_CONTENT_TYPE),
+        ..Default::default()
+    };
+
+    linter.human_readable(
+        thing.description.as_ref(),
+        thing.titles.as_ref(),
+        thing.descriptions.as_ref(),
+    );
+    if let Some(forms) = &thing.forms {
+        linter.with_path(format_args!("forms"), |linter| linter.forms(forms));
+    }
+    for (name, security) in &thing.security_definitions {
+        linter.with_path(format_args!("securityDefinitions.{name}"), |linter| {
+            linter.human_readable(
+                security.description.as_ref(),
+                None,
+                security.descriptions.as_ref(),
+            )
+        });
+    }
+
+    for (name, property) in thing.properties.iter().flatten() {
+        linter.with_path(format_args!("properties.{name}"), |linter| {
+            linter.interaction(&property.interaction);
+            linter.property_forms(&property.interaction, &property.data_schema);
+            linter.data_schema(&property.data_schema);
+        });
+    }
+
+    for (name, action) in thing.actions.iter().flatten() {
+        linter.with_path(format_args!("actions.{name}"), |linter| {
+            linter.interaction(&action.interaction);
+            linter.optional_data_schema("input", action.input.as_ref());
+            linter.optional_data_schema("output", action.output.as_ref());
+        });
+    }
+
+    for (name, event) in thing.events.iter().flatten() {
+        linter.with_path(format_args!("events.{name}"), |linter| {
+            linter.interaction(&event.interaction);
+            linter.optional_data_schema("subscription", event.subscription.as_ref());
+            linter.optional_data_schema("data", event.data.as_ref());
+            linter.optional_data_schema("dataResponse", event.data_response.as_ref());
+            linter.optional_data_schema("cancellation", event.cancellation.as_ref());
+        });
+    }
+
+    if let Some(uri_variables) = &thing.uri_variables {
+        linter.data_schema_map("uriVariables", uri_variables);
+    }
+    if let Some(schema_definitions) = &thing.schema_definitions {
+        linter.data_schema_map("schemaDefinitions", schema_definitions);
+    }
+
+    linter.warnings.sort_unstable();
+    linter.warnings
+}
+
+#[derive(Default)]
+struct Linter<'a> {
+    config: LintConfig,
+    thing_uri_variables: Vec<&'a str>,
+    default_content_type: &'a str,
+    path: String,
+    warnings: Vec<LintWarning>,
+}
+
+impl Linter<'_> {
+    fn with_path(&mut self, segment: fmt::Arguments<'_>, f: impl FnOnce(&mut Self)) {
+        let len = self.path.len();
+        if !self.path.is_empty() {
+            self.path.push('.');
+        }
+        // Writing into a `String` cannot fail.
+        let _ = self.path.write_fmt(segment);
+
+        f(self);
+        self.path.truncate(len);
+    }
+
+    fn human_readable(
+        &mut self,
+        description: Option<&String>,
+        titles: Option<&MultiLanguage>,
+        descriptions: Option<&MultiLanguage>,
+    ) {
+        if let Some(max_length) = self.config.max_description_length {
+            let too_long = |description: &String| description.chars().count() > max_length;
+
+            if description.is_some_and(too_long) {
+                self.with_path(format_args!("description"), |linter| {
+                    linter
+                        .warnings
+                        .push(LintWarning::DescriptionTooLong(linter.path.clone()))
+                });
+            }
+            for (language, description) in descriptions.into_iter().flatten() {
+                if too_long(description) {
+                    self.with_path(format_args!("descriptions.{language}"), |linter| {
+                        linter
+                            .warnings
+                            .push(LintWarning::DescriptionTooLong(linter.path.clone()))
+                    });
+                }
+            }
+        }
+
+        if let Some(max_translations) = self.config.max_translations {
+            for (name, translations) in [("titles", titles), ("descriptions", descriptions)] {
+                if translations.is_some_and(|translations| translations.len() > max_translations) {
+                    self.with_path(format_args!("{name}"), |linter| {
+                        linter
+                            .warnings
+                            .push(LintWarning::TooManyTranslations(linter.path.clone()))
+                    });
+                }
+            }
+        }
+    }
+
+    fn interaction<Other: ExtendableThing>(&mut self, interaction: &InteractionAffordance<Other>) {
+        self.human_readable(
+            interaction.description.as_ref(),
+            interaction.titles.as_ref(),
+            interaction.descriptions.as_ref(),
+        );
+
+        if let Some(uri_variables) = &interaction.uri_variables {
+            for name in uri_variables.keys() {
+                if self.thing_uri_variables.contains(&name.as_str()) {
+                    self.with_path(format_args!("uriVariables.{name}"), |linter| {
+                        linter
+                            .warnings
+                            .push(LintWarning::ShadowedUriVariable(linter.path.clone()))
+                    });
+                }
+            }
+
+            self.data_schema_map("uriVariables", uri_variables);
+        }
+
+        self.with_path(format_args!("forms"), |linter| {
+            linter.forms(&interaction.forms)
+        });
+    }
+
+    fn forms<F, ER>(&mut self, forms: &[Form<F, ER>]) {
+        for (form_index, form) in forms.iter().enumerate() {
+            let form_content_type = form
+                .content_type
+                .as_deref()
+                .unwrap_or(self.default_content_type);
+            let main_content_type = form
+                .response
+                .as_ref()
+                .map_or(form_content_type, |response| &response.content_type);
+
+            let mut overlapping = form
+                .additional_responses
+                .iter()
+                .flatten()
+                .enumerate()
+                .filter(|(_, response)| {
+                    response.success
+                        && response
+                            .content_type
+                            .as_deref()
+                            .unwrap_or(form_content_type)
+                            == main_content_type
+                })
+                .map(|(index, _)| index);
+
+            // The first overlapping response is allowed.
+            overlapping.next();
+            for response_index in overlapping {
+                self.with_path(
+                    format_args!("{form_index}.additionalResponses.{response_index}"),
+                    |linter| {
+                        linter
+                            .warnings
+                            .push(LintWarning::OverlappingSuccessResponse(linter.path.clone()))
+                    },
+                );
+            }
+        }
+    }
+
+    fn property_forms<Other: ExtendableThing, DS, AS, OS>(
+        &mut self,
+        interaction: &InteractionAffordance<Other>,
+        data_schema: &DataSchema<DS, AS, OS>,
+    ) {
+        for (form_index, form) in interaction.forms.iter().enumerate() {
+            let DefaultedFormOperations::Custom(ops) = &form.op else {
+                continue;
+            };
+
+            for (op_index, op) in ops.iter().enumerate() {
+                let contradictory = match op {
+                    FormOperation::ReadProperty => data_schema.write_only,
+                    FormOperation::WriteProperty => data_schema.read_only,
+                    _ => false,
+                };
+
+                if contradictory {
+                    self.with_path(format_args!("forms.{form_index}.op.{op_index}"), |linter| {
+                        linter
+                            .warnings
+                            .push(LintWarning::ContradictoryFormOperation(linter.path.clone()))
+                    });
+                }
+            }
+        }
+    }
+
+    fn optional_data_schema<DS, AS, OS>(
+        &mut self,
+        name: &str,
+        data_schema: Option<&DataSchema<DS, AS, OS>>,
+    ) {
+        if let Some(data_schema) = data_schema {
+            self.with_path(format_args!("{name}"), |linter| {
+                linter.data_schema(data_schema)
+            });
+        }
+    }
+
+    fn data_schema_map<DS, AS, OS>(
+        &mut self,
+        name: &str,
+        map: &HashMap<String, DataSchema<DS, AS, OS>>,
+    ) {
+        for (key, data_schema) in map {
+            self.with_path(format_args!("{name}.{key}"), |linter| {
+                linter.data_schema(data_schema)
+            });
+        }
+    }
+
+    fn data_schema<DS, AS, OS>(&mut self, data_schema: &DataSchema<DS, AS, OS>) {
+        data_schema.walk_with_path(&mut |path, data_schema| {
+            let len = self.path.len();
+            for segment in path {
+                self.path.push('.');
+                self.path.push_str(segment);
+            }
+            self.data_schema_members(data_schema);
+            self.path.truncate(len);
+        });
+    }
+
+    fn data_schema_members<DS, AS, OS>(&mut self, data_schema: &DataSchema<DS, AS, OS>) {
+        self.human_readable(
+            data_schema.description.as_ref(),
+            data_schema.titles.as_ref(),
+            data_schema.descriptions.as_ref(),
+        );
+
+        #[cfg(feature = "units")]
+        if data_schema.unit.is_some() && data_schema.normalized_unit().is_none() {
+            self.warnings
+                .push(LintWarning::UnrecognizedUnit(self.path.clone()));
+        }
+
+        if let Some((first, rest)) = data_schema
+            .enumeration
+            .as_deref()
+            .and_then(<[_]>::split_first)
+        {
+            // All the numbers share the same discriminant.
+            if rest
+                .iter()
+                .any(|value| mem::discriminant(value) != mem::discriminant(first))
+            {
+                self.warnings
+                    .push(LintWarning::MixedEnumTypes(self.path.clone()));
+            }
+        }
+
+        if data_schema.one_of.is_some() && data_schema.subtype.is_some() {
+            self.warnings
+                .push(LintWarning::OneOfWithSubtype(self.path.clone()));
+        }
+
+        if let Some(DataSchemaSubtype::String(string)) = &data_schema.subtype {
+            if string.content_encoding.is_some() && string.content_media_type.is_none() {
+                self.warnings
+                    .push(LintWarning::ContentEncodingWithoutMediaType(
+                        self.path.clone(),
+                    ));
+            }
+        }
+    }
+}
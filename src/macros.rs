@@ -0,0 +1,575 @@
+//! Declarative definition of Thing Descriptions
+
+/// Defines a Thing Description using a compact, JSON-like syntax.
+///
+/// The macro expands to the equivalent chain of [`ThingBuilder`] calls on a non-extended
+/// ([`Nil`]) builder, ending with [`ThingBuilder::build`]. The structure is therefore checked at
+/// compile time, like with the builder, and the result is a
+/// `Result<Thing, `[`builder::Error`]`>`.
+///
+/// ```
+/// let thing = wot_td::thing! {
+///     title: "Lamp",
+///     description: "A web connected lamp",
+///     security: nosec,
+///     properties: {
+///         on: { type: bool, observable: true, forms: [{ href: "/on" }] },
+///         brightness: {
+///             type: integer,
+///             minimum: 0,
+///             maximum: 100,
+///             unit: "percent",
+///             forms: [{ href: "/brightness", op: [readproperty, writeproperty] }],
+///         },
+///     },
+///     actions: {
+///         fade: {
+///             input: { type: integer, minimum: 1, unit: "milliseconds" },
+///             forms: [{ href: "/fade" }],
+///         },
+///     },
+///     events: {
+///         overheated: { data: { type: number }, forms: [{ href: "/overheated" }] },
+///     },
+/// }
+/// .unwrap();
+///
+/// assert_eq!(thing.title, "Lamp");
+/// assert_eq!(thing.properties.unwrap().len(), 2);
+/// ```
+///
+/// # Supported keys
+///
+/// Only a subset of the Thing Description can be expressed, any other key is rejected at compile
+/// time. Keys can be written in any order, separated by commas.
+///
+/// - Thing: `title` (required), `id`, `description`, `base`, `security` (only `nosec`, which
+///   defines and requires the `nosec_sc` scheme), `properties`, `actions` and `events`.
+/// - Affordances are maps from names, either identifiers or string literals, to their members:
+///   - all affordances: `title`, `description` and `forms`;
+///   - properties: `observable` and the data schema keys;
+///   - actions: `input`, `output`, `safe` and `idempotent`;
+///   - events: `data`.
+/// - Data schemas: `type` (required, one of `bool`, `integer`, `number`, `string` and `null`),
+///   `title`, `description`, `unit`, `minimum`, `maximum`, `min_length`, `max_length`,
+///   `read_only` and `write_only`. Objects and arrays are not supported, and `min_length` and
+///   `max_length` are only available for the data schemas of actions and events.
+/// - Forms: `href` (required), `op` (a [`FormOperation`] in the TD casing, or a list of them),
+///   `content_type` and `subprotocol`.
+///
+/// ```compile_fail
+/// let thing = wot_td::thing! {
+///     title: "Lamp",
+///     colour: "red",
+/// };
+/// ```
+///
+/// [`ThingBuilder`]: crate::builder::ThingBuilder
+/// [`ThingBuilder::build`]: crate::builder::ThingBuilder::build
+/// [`Nil`]: crate::hlist::Nil
+/// [`builder::Error`]: crate::builder::Error
+/// [`FormOperation`]: crate::thing::FormOperation
+#[macro_export]
+macro_rules! thing {
+    ($($body:tt)*) => {{
+        #[allow(unused_imports)]
+        use $crate::builder::{
+            BuildableDataSchema as _, BuildableHumanReadableInfo as _,
+            BuildableInteractionAffordance as _, IntegerDataSchemaBuilderLike as _,
+            NumberDataSchemaBuilderLike as _, ReadableWriteableDataSchema as _,
+            SpecializableDataSchema as _, StringDataSchemaBuilderLike as _,
+        };
+
+        $crate::__thing!(@find_title [] $($body)*)
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __thing {
+    // Thing
+    (@find_title [$($seen:tt)*] title : $title:expr $(, $($rest:tt)*)?) => {
+        $crate::__thing!(
+            @thing
+            $crate::builder::ThingBuilder::<$crate::hlist::Nil, _>::new($title).finish_extend() ;
+            $($seen)* $($($rest)*)?
+        )
+    };
+    (@find_title [$($seen:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__thing!(@find_title [$($seen)* $next] $($rest)*)
+    };
+    (@find_title [$($seen:tt)*]) => {
+        ::core::compile_error!("missing `title` in `thing!`")
+    };
+
+    (@thing $b:expr ;) => {
+        $b.build()
+    };
+    (@thing $b:expr ; id : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@thing $b.id($value) ; $($($rest)*)?)
+    };
+    (@thing $b:expr ; description : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@thing $b.description($value) ; $($($rest)*)?)
+    };
+    (@thing $b:expr ; base : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@thing $b.base($value) ; $($($rest)*)?)
+    };
+    (@thing $b:expr ; security : nosec $(, $($rest:tt)*)?) => {
+        $crate::__thing!(
+            @thing $b.security(|b| b.no_sec().with_key("nosec_sc").required()) ;
+            $($($rest)*)?
+        )
+    };
+    (@thing $b:expr ; properties : { $($affordances:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@properties $b ; [$($($rest)*)?] $($affordances)*)
+    };
+    (@thing $b:expr ; actions : { $($affordances:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@actions $b ; [$($($rest)*)?] $($affordances)*)
+    };
+    (@thing $b:expr ; events : { $($affordances:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@events $b ; [$($($rest)*)?] $($affordances)*)
+    };
+    (@thing $b:expr ; $key:ident $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown key `",
+            ::core::stringify!($key),
+            "` in `thing!`",
+        ))
+    };
+
+    // Affordances
+    (@name $name:ident) => {
+        ::core::stringify!($name)
+    };
+    (@name $name:literal) => {
+        $name
+    };
+
+    (@properties $b:expr ; [$($next:tt)*]) => {
+        $crate::__thing!(@thing $b ; $($next)*)
+    };
+    (@properties $b:expr ; [$($next:tt)*] $name:tt : { $($body:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::__thing!(
+            @properties
+            $b.property($crate::__thing!(@name $name), |b| {
+                $crate::__thing!(@find_type property b.finish_extend_data_schema() ; [] $($body)*)
+            }) ;
+            [$($next)*] $($($rest)*)?
+        )
+    };
+
+    (@actions $b:expr ; [$($next:tt)*]) => {
+        $crate::__thing!(@thing $b ; $($next)*)
+    };
+    (@actions $b:expr ; [$($next:tt)*] $name:tt : { $($body:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::__thing!(
+            @actions
+            $b.action($crate::__thing!(@name $name), |b| $crate::__thing!(@action b ; $($body)*)) ;
+            [$($next)*] $($($rest)*)?
+        )
+    };
+
+    (@events $b:expr ; [$($next:tt)*]) => {
+        $crate::__thing!(@thing $b ; $($next)*)
+    };
+    (@events $b:expr ; [$($next:tt)*] $name:tt : { $($body:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::__thing!(
+            @events
+            $b.event($crate::__thing!(@name $name), |b| $crate::__thing!(@event b ; $($body)*)) ;
+            [$($next)*] $($($rest)*)?
+        )
+    };
+
+    (@property $b:expr ;) => {
+        $b
+    };
+    (@property $b:expr ; observable : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@property $b.observable($value) ; $($($rest)*)?)
+    };
+    (@property $b:expr ; $($rest:tt)*) => {
+        $crate::__thing!(@interaction property $b ; $($rest)*)
+    };
+
+    (@action $b:expr ;) => {
+        $b
+    };
+    (@action $b:expr ; input : { $($body:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::__thing!(
+            @action
+            $b.input(|b| $crate::__thing!(@find_type schema b.finish_extend() ; [] $($body)*)) ;
+            $($($rest)*)?
+        )
+    };
+    (@action $b:expr ; output : { $($body:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::__thing!(
+            @action
+            $b.output(|b| $crate::__thing!(@find_type schema b.finish_extend() ; [] $($body)*)) ;
+            $($($rest)*)?
+        )
+    };
+    (@action $b:expr ; safe : true $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@action $b.safe() ; $($($rest)*)?)
+    };
+    (@action $b:expr ; safe : false $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@action $b ; $($($rest)*)?)
+    };
+    (@action $b:expr ; idempotent : true $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@action $b.idempotent() ; $($($rest)*)?)
+    };
+    (@action $b:expr ; idempotent : false $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@action $b ; $($($rest)*)?)
+    };
+    (@action $b:expr ; $($rest:tt)*) => {
+        $crate::__thing!(@interaction action $b ; $($rest)*)
+    };
+
+    (@event $b:expr ;) => {
+        $b
+    };
+    (@event $b:expr ; data : { $($body:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::__thing!(
+            @event
+            $b.data(|b| $crate::__thing!(@find_type schema b.finish_extend() ; [] $($body)*)) ;
+            $($($rest)*)?
+        )
+    };
+    (@event $b:expr ; $($rest:tt)*) => {
+        $crate::__thing!(@interaction event $b ; $($rest)*)
+    };
+
+    (@interaction $kind:ident $b:expr ; title : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@ $kind $b.title($value) ; $($($rest)*)?)
+    };
+    (@interaction $kind:ident $b:expr ; description : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@ $kind $b.description($value) ; $($($rest)*)?)
+    };
+    (
+        @interaction $kind:ident $b:expr ;
+        forms : [$({ $($form:tt)* }),* $(,)?] $(, $($rest:tt)*)?
+    ) => {
+        $crate::__thing!(
+            @ $kind
+            $b $(.form(|b| $crate::__thing!(@find_href b ; [] $($form)*)))* ;
+            $($($rest)*)?
+        )
+    };
+    (@interaction property $b:expr ; $($rest:tt)*) => {
+        $crate::__thing!(@schema_key property $b ; $($rest)*)
+    };
+    (@interaction $kind:ident $b:expr ; $key:ident $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown ",
+            ::core::stringify!($kind),
+            " key `",
+            ::core::stringify!($key),
+            "` in `thing!`",
+        ))
+    };
+
+    // Data schemas
+    (@find_type $kind:ident $b:expr ; [$($seen:tt)*] type : $ty:ident $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@ $kind $crate::__thing!(@type $b ; $ty) ; $($seen)* $($($rest)*)?)
+    };
+    (@find_type $kind:ident $b:expr ; [$($seen:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__thing!(@find_type $kind $b ; [$($seen)* $next] $($rest)*)
+    };
+    (@find_type $kind:ident $b:expr ; [$($seen:tt)*]) => {
+        ::core::compile_error!(::core::concat!(
+            "missing `type` in a ",
+            ::core::stringify!($kind),
+            " of `thing!`",
+        ))
+    };
+
+    (@type $b:expr ; bool) => {
+        $b.bool()
+    };
+    (@type $b:expr ; integer) => {
+        $b.integer()
+    };
+    (@type $b:expr ; number) => {
+        $b.number()
+    };
+    (@type $b:expr ; string) => {
+        $b.string()
+    };
+    (@type $b:expr ; null) => {
+        $b.null()
+    };
+    (@type $b:expr ; $ty:ident) => {
+        ::core::compile_error!(::core::concat!(
+            "unsupported data schema type `",
+            ::core::stringify!($ty),
+            "` in `thing!`",
+        ))
+    };
+
+    (@schema $b:expr ;) => {
+        $b
+    };
+    (@schema $b:expr ; $($rest:tt)*) => {
+        $crate::__thing!(@schema_key schema $b ; $($rest)*)
+    };
+
+    (@schema_key $kind:ident $b:expr ; title : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@ $kind $b.title($value) ; $($($rest)*)?)
+    };
+    (@schema_key $kind:ident $b:expr ; description : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@ $kind $b.description($value) ; $($($rest)*)?)
+    };
+    (@schema_key $kind:ident $b:expr ; unit : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@ $kind $b.unit($value) ; $($($rest)*)?)
+    };
+    (@schema_key $kind:ident $b:expr ; minimum : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@ $kind $b.minimum($value) ; $($($rest)*)?)
+    };
+    (@schema_key $kind:ident $b:expr ; maximum : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@ $kind $b.maximum($value) ; $($($rest)*)?)
+    };
+    (@schema_key $kind:ident $b:expr ; min_length : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@ $kind $b.min_length($value) ; $($($rest)*)?)
+    };
+    (@schema_key $kind:ident $b:expr ; max_length : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@ $kind $b.max_length($value) ; $($($rest)*)?)
+    };
+    (@schema_key $kind:ident $b:expr ; read_only : true $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@ $kind $b.read_only() ; $($($rest)*)?)
+    };
+    (@schema_key $kind:ident $b:expr ; read_only : false $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@ $kind $b ; $($($rest)*)?)
+    };
+    (@schema_key $kind:ident $b:expr ; write_only : true $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@ $kind $b.write_only() ; $($($rest)*)?)
+    };
+    (@schema_key $kind:ident $b:expr ; write_only : false $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@ $kind $b ; $($($rest)*)?)
+    };
+    (@schema_key $kind:ident $b:expr ; $key:ident $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown ",
+            ::core::stringify!($kind),
+            " key `",
+            ::core::stringify!($key),
+            "` in `thing!`",
+        ))
+    };
+
+    // Forms
+    (@find_href $b:expr ; [$($seen:tt)*] href : $href:expr $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@form $b.href($href) ; $($seen)* $($($rest)*)?)
+    };
+    (@find_href $b:expr ; [$($seen:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__thing!(@find_href $b ; [$($seen)* $next] $($rest)*)
+    };
+    (@find_href $b:expr ; [$($seen:tt)*]) => {
+        ::core::compile_error!("missing `href` in a form of `thing!`")
+    };
+
+    (@form $b:expr ;) => {
+        $b
+    };
+    (@form $b:expr ; op : [$($op:ident),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::__thing!(
+            @form $b $(.op($crate::__thing!(@op $op)))* ;
+            $($($rest)*)?
+        )
+    };
+    (@form $b:expr ; op : $op:ident $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@form $b.op($crate::__thing!(@op $op)) ; $($($rest)*)?)
+    };
+    (@form $b:expr ; content_type : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@form $b.content_type($value) ; $($($rest)*)?)
+    };
+    (@form $b:expr ; subprotocol : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__thing!(@form $b.subprotocol($value) ; $($($rest)*)?)
+    };
+    (@form $b:expr ; $key:ident $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown form key `",
+            ::core::stringify!($key),
+            "` in `thing!`",
+        ))
+    };
+
+    (@op readproperty) => {
+        $crate::thing::FormOperation::ReadProperty
+    };
+    (@op writeproperty) => {
+        $crate::thing::FormOperation::WriteProperty
+    };
+    (@op observeproperty) => {
+        $crate::thing::FormOperation::ObserveProperty
+    };
+    (@op unobserveproperty) => {
+        $crate::thing::FormOperation::UnobserveProperty
+    };
+    (@op invokeaction) => {
+        $crate::thing::FormOperation::InvokeAction
+    };
+    (@op queryaction) => {
+        $crate::thing::FormOperation::QueryAction
+    };
+    (@op cancelaction) => {
+        $crate::thing::FormOperation::CancelAction
+    };
+    (@op subscribeevent) => {
+        $crate::thing::FormOperation::SubscribeEvent
+    };
+    (@op unsubscribeevent) => {
+        $crate::thing::FormOperation::UnsubscribeEvent
+    };
+    (@op readallproperties) => {
+        $crate::thing::FormOperation::ReadAllProperties
+    };
+    (@op writeallproperties) => {
+        $crate::thing::FormOperation::WriteAllProperties
+    };
+    (@op readmultipleproperties) => {
+        $crate::thing::FormOperation::ReadMultipleProperties
+    };
+    (@op writemultipleproperties) => {
+        $crate::thing::FormOperation::WriteMultipleProperties
+    };
+    (@op observeallproperties) => {
+        $crate::thing::FormOperation::ObserveAllProperties
+    };
+    (@op unobserveallproperties) => {
+        $crate::thing::FormOperation::UnobserveAllProperties
+    };
+    (@op subscribeallevents) => {
+        $crate::thing::FormOperation::SubscribeAllEvents
+    };
+    (@op unsubscribeallevents) => {
+        $crate::thing::FormOperation::UnsubscribeAllEvents
+    };
+    (@op queryallactions) => {
+        $crate::thing::FormOperation::QueryAllActions
+    };
+    (@op $op:ident) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown form operation `",
+            ::core::stringify!($op),
+            "` in `thing!`",
+        ))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        builder::{
+            BuildableDataSchema, BuildableHumanReadableInfo, BuildableInteractionAffordance,
+            IntegerDataSchemaBuilderLike, NumberDataSchemaBuilderLike, ReadableWriteableDataSchema,
+            SpecializableDataSchema, StringDataSchemaBuilderLike, ThingBuilder,
+        },
+        hlist::Nil,
+        thing::FormOperation,
+    };
+
+    #[test]
+    fn thing_matches_builder() {
+        let from_macro = crate::thing! {
+            id: "urn:dev:ops:lamp",
+            title: "Lamp",
+            base: "http://lamp.local/",
+            description: "A web connected lamp",
+            security: nosec,
+            properties: {
+                on: {
+                    forms: [{ href: "/on" }],
+                    type: bool,
+                    observable: true,
+                    title: "On/Off",
+                },
+                "brightness-level": {
+                    type: integer,
+                    minimum: 0,
+                    maximum: 100,
+                    unit: "percent",
+                    forms: [
+                        { op: [readproperty, writeproperty], href: "/brightness" },
+                        { href: "/brightness/observe", op: observeproperty, subprotocol: "sse" },
+                    ],
+                },
+                name: { type: string, read_only: true, forms: [{ href: "/name" }] },
+            },
+            actions: {
+                fade: {
+                    description: "Fade the lamp",
+                    input: { type: number, minimum: 0.5, title: "Duration" },
+                    output: { type: string, min_length: 1, max_length: 16 },
+                    safe: false,
+                    idempotent: true,
+                    forms: [{ href: "/fade", content_type: "application/cbor" }],
+                },
+            },
+            events: {
+                overheated: { data: { type: number, unit: "degree celsius" }, forms: [{ href: "/hot" }] },
+            },
+        }
+        .unwrap();
+
+        let from_builder = ThingBuilder::<Nil, _>::new("Lamp")
+            .finish_extend()
+            .id("urn:dev:ops:lamp")
+            .base("http://lamp.local/")
+            .description("A web connected lamp")
+            .security(|b| b.no_sec().with_key("nosec_sc").required())
+            .property("on", |b| {
+                b.finish_extend_data_schema()
+                    .form(|b| b.href("/on"))
+                    .bool()
+                    .observable(true)
+                    .title("On/Off")
+            })
+            .property("brightness-level", |b| {
+                b.finish_extend_data_schema()
+                    .integer()
+                    .minimum(0)
+                    .maximum(100)
+                    .unit("percent")
+                    .form(|b| {
+                        b.href("/brightness")
+                            .op(FormOperation::ReadProperty)
+                            .op(FormOperation::WriteProperty)
+                    })
+                    .form(|b| {
+                        b.href("/brightness/observe")
+                            .op(FormOperation::ObserveProperty)
+                            .subprotocol("sse")
+                    })
+            })
+            .property("name", |b| {
+                b.finish_extend_data_schema()
+                    .string()
+                    .read_only()
+                    .form(|b| b.href("/name"))
+            })
+            .action("fade", |b| {
+                b.description("Fade the lamp")
+                    .input(|b| b.finish_extend().number().minimum(0.5).title("Duration"))
+                    .output(|b| b.finish_extend().string().min_length(1).max_length(16))
+                    .idempotent()
+                    .form(|b| b.href("/fade").content_type("application/cbor"))
+            })
+            .event("overheated", |b| {
+                b.data(|b| b.finish_extend().number().unit("degree celsius"))
+                    .form(|b| b.href("/hot"))
+            })
+            .build()
+            .unwrap();
+
+        assert_eq!(from_macro, from_builder);
+    }
+
+    #[test]
+    fn minimal_thing() {
+        let from_macro = crate::thing! { title: "Empty" }.unwrap();
+        let from_builder = ThingBuilder::<Nil, _>::new("Empty")
+            .finish_extend()
+            .build()
+            .unwrap();
+
+        assert_eq!(from_macro, from_builder);
+    }
+}
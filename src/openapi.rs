@@ -0,0 +1,112 @@
+//! Conversion of data schemas into OpenAPI schema objects
+//!
+//! The schema object of [OpenAPI 3.1](https://spec.openapis.org/oas/v3.1.0#schema-object) is a
+//! superset of JSON Schema 2020-12, therefore most of the members of a serialized
+//! [`DataSchema`](crate::thing::DataSchema) can be kept as they are. The differences handled here
+//! are the following:
+//!
+//! - the JSON-LD and multi-language members (`@type`, `titles` and `descriptions`) are dropped;
+//! - `unit` becomes the `x-unit` specification extension;
+//! - `readOnly` and `writeOnly` are only emitted when they are `true`;
+//! - tuple `items` become `prefixItems`;
+//! - nullable values use a `type` array instead of the `nullable` keyword of OpenAPI 3.0: a
+//!   `oneOf` made of a `null` schema and a single typed schema is collapsed, and an `enum`
+//!   including `null` adds `"null"` to the `type`.
+
+use alloc::{string::String, vec};
+
+use serde_json::{Map, Value};
+
+const DROPPED_MEMBERS: &[&str] = &["@type", "titles", "descriptions"];
+
+/// Converts a serialized data schema into an OpenAPI 3.1 schema object in place.
+pub(crate) fn to_openapi_schema(value: &mut Value) {
+    let Value::Object(map) = value else {
+        return;
+    };
+
+    map.retain(|key, value| {
+        let unset_flag =
+            matches!(key.as_str(), "readOnly" | "writeOnly") && value.as_bool() == Some(false);
+        !(unset_flag || DROPPED_MEMBERS.contains(&key.as_str()))
+    });
+    if let Some(unit) = map.remove("unit") {
+        map.insert("x-unit".into(), unit);
+    }
+
+    if let Some(items) = map.remove("items") {
+        let key = if items.is_array() {
+            "prefixItems"
+        } else {
+            "items"
+        };
+        map.insert(key.into(), items);
+    }
+
+    for key in ["items", "prefixItems", "oneOf"] {
+        match map.get_mut(key) {
+            Some(Value::Array(schemas)) => schemas.iter_mut().for_each(to_openapi_schema),
+            Some(schema) => to_openapi_schema(schema),
+            None => {}
+        }
+    }
+    if let Some(Value::Object(properties)) = map.get_mut("properties") {
+        properties.values_mut().for_each(to_openapi_schema);
+    }
+
+    collapse_nullable_one_of(map);
+
+    let nullable_enum = map
+        .get("enum")
+        .and_then(Value::as_array)
+        .is_some_and(|values| values.contains(&Value::Null));
+    if nullable_enum {
+        if let Some(Value::String(ty)) = map.get("type") {
+            if ty != "null" {
+                let ty = Value::String(ty.clone());
+                map.insert("type".into(), Value::Array(vec![ty, "null".into()]));
+            }
+        }
+    }
+}
+
+fn collapse_nullable_one_of(map: &mut Map<String, Value>) {
+    let Some(Value::Array(one_of)) = map.get("oneOf") else {
+        return;
+    };
+    let [first, second] = one_of.as_slice() else {
+        return;
+    };
+
+    let is_null = |schema: &Value| schema.as_object().is_some_and(is_null_schema);
+    let typed = match (is_null(first), is_null(second)) {
+        (true, false) => second,
+        (false, true) => first,
+        _ => return,
+    };
+    let Some(typed) = typed.as_object() else {
+        return;
+    };
+    let Some(Value::String(ty)) = typed.get("type") else {
+        return;
+    };
+    if typed
+        .keys()
+        .any(|key| key != "type" && map.contains_key(key))
+    {
+        return;
+    }
+
+    let ty = Value::Array(vec![ty.as_str().into(), "null".into()]);
+    let mut typed = typed.clone();
+    typed.insert("type".into(), ty);
+    map.remove("oneOf");
+    map.extend(typed);
+}
+
+fn is_null_schema(schema: &Map<String, Value>) -> bool {
+    schema.get("type").and_then(Value::as_str) == Some("null")
+        && schema
+            .keys()
+            .all(|key| matches!(key.as_str(), "type" | "title" | "description"))
+}
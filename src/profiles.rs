@@ -0,0 +1,11 @@
+//! WoT Profiles
+//!
+//! A [WoT Profile](https://www.w3.org/TR/wot-profile/) restricts the Thing Descriptions to a
+//! subset that a consumer can interact with out of the box. The submodules check the constraints
+//! of a profile that can be verified from the Thing Description alone, reporting the violations
+//! with the path of the offending member, using the TD member names separated by dots (e.g.
+//! `properties.status.forms.0.contentType`).
+//!
+//! The module is only available with the `profiles` feature.
+
+pub mod http_basic;
@@ -0,0 +1,416 @@
+//! HTTP Basic Profile
+//!
+//! The [HTTP Basic Profile](https://www.w3.org/TR/wot-profile/#http-basic-profile) restricts the
+//! interactions to plain HTTP requests with JSON payloads. [`check`] verifies the constraints that
+//! a consumer can check on the Thing Description:
+//!
+//! - the profile is declared in the `profile` member;
+//! - every form uses the `http` or `https` scheme, once resolved against `base`;
+//! - every form uses the `application/json` content type, explicitly or by default;
+//! - every property that is not `writeOnly` has a form to read it;
+//! - the forms invoking an action use the `POST` method, when `htv:methodName` is known;
+//! - the forms subscribing to an event use the `sse` subprotocol.
+//!
+//! The `htv:methodName` of a form is only known when it is serialized, either by the
+//! [`HttpProtocol`](crate::protocol::http::HttpProtocol) extension or by the `preserve-unknown`
+//! feature.
+//!
+//! # Example
+//!
+//! ```
+//! # use serde_json::json;
+//! # use wot_td::{
+//! #     profiles::http_basic::{check, Violation, HTTP_BASIC_PROFILE},
+//! #     thing::Thing,
+//! # };
+//! #
+//! let thing: Thing = serde_json::from_value(json!({
+//!     "@context": "https://www.w3.org/2022/wot/td/v1.1",
+//!     "title": "Lamp",
+//!     "profile": HTTP_BASIC_PROFILE,
+//!     "base": "coap://lamp.example.com/",
+//!     "security": [],
+//!     "securityDefinitions": {},
+//!     "properties": {
+//!         "on": { "type": "boolean", "forms": [{ "href": "on" }] },
+//!     },
+//! }))
+//! .unwrap();
+//!
+//! let report = check(&thing);
+//! assert!(!report.conforms());
+//! assert_eq!(
+//!     report.violations,
+//!     [Violation::UnsupportedScheme("properties.on.forms.0.href".to_string())],
+//! );
+//! ```
+
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+
+use serde::Serialize;
+
+use crate::{
+    builder::AffordanceType,
+    extend::ExtendableThing,
+    thing::{default_ops_for, DefaultedFormOperations, Form, FormFromOther, FormOperation, Thing},
+};
+
+/// The IRI identifying the HTTP Basic Profile in the `profile` member of a Thing.
+pub const HTTP_BASIC_PROFILE: &str = "https://www.w3.org/2022/wot/profile/http-basic/v1";
+
+/// A constraint of the HTTP Basic Profile that is not satisfied.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Violation {
+    /// The profile is not declared in the `profile` member of the Thing.
+    MissingProfile,
+
+    /// The `href` of a form does not use the `http` or `https` scheme.
+    UnsupportedScheme(String),
+
+    /// A form does not use the `application/json` content type.
+    UnsupportedContentType(String),
+
+    /// A property that is not `writeOnly` does not have a form to read it.
+    MissingReadForm(String),
+
+    /// A form invoking an action uses a method other than `POST`.
+    UnsupportedActionMethod(String),
+
+    /// A form subscribing to an event does not use the `sse` subprotocol.
+    MissingSseSubprotocol(String),
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingProfile => write!(f, "The profile {HTTP_BASIC_PROFILE} is not declared"),
+            Self::UnsupportedScheme(path) => {
+                write!(f, "The href at {path} does not use http or https")
+            }
+            Self::UnsupportedContentType(path) => {
+                write!(f, "The content type at {path} is not application/json")
+            }
+            Self::MissingReadForm(path) => {
+                write!(f, "The forms at {path} do not include a readproperty form")
+            }
+            Self::UnsupportedActionMethod(path) => {
+                write!(f, "The method at {path} is not POST")
+            }
+            Self::MissingSseSubprotocol(path) => {
+                write!(f, "The subprotocol at {path} is not sse")
+            }
+        }
+    }
+}
+
+/// The result of checking a Thing against the HTTP Basic Profile.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ProfileReport {
+    /// The violations, sorted by kind and path.
+    pub violations: Vec<Violation>,
+}
+
+impl ProfileReport {
+    /// Returns `true` if the Thing satisfies all the checked constraints.
+    #[inline]
+    pub fn conforms(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Checks a Thing against the HTTP Basic Profile.
+///
+/// See the [module documentation](self) for the checked constraints.
+pub fn check<Other>(thing: &Thing<Other>) -> ProfileReport
+where
+    Other: ExtendableThing,
+    FormFromOther<Other>: Serialize,
+{
+    let mut violations = Vec::new();
+
+    let declared = thing
+        .profile
+        .iter()
+        .flatten()
+        .any(|profile| profile == HTTP_BASIC_PROFILE);
+    if !declared {
+        violations.push(Violation::MissingProfile);
+    }
+
+    for (index, form) in thing.forms.iter().flatten().enumerate() {
+        check_form(thing, form, &format!("forms.{index}"), &mut violations);
+    }
+
+    for (name, property) in thing.properties.iter().flatten() {
+        let path = format!("properties.{name}");
+        let default_ops = default_ops_for(
+            AffordanceType::Property,
+            property.data_schema.read_only,
+            property.data_schema.write_only,
+        );
+
+        let mut readable = false;
+        for (index, form) in property.interaction.forms.iter().enumerate() {
+            check_form(
+                thing,
+                form,
+                &format!("{path}.forms.{index}"),
+                &mut violations,
+            );
+            readable |= supports(form, &default_ops, FormOperation::ReadProperty);
+        }
+        if !property.data_schema.write_only && !readable {
+            violations.push(Violation::MissingReadForm(format!("{path}.forms")));
+        }
+    }
+
+    let default_ops = default_ops_for(AffordanceType::Action, false, false);
+    for (name, action) in thing.actions.iter().flatten() {
+        for (index, form) in action.interaction.forms.iter().enumerate() {
+            let path = format!("actions.{name}.forms.{index}");
+            check_form(thing, form, &path, &mut violations);
+
+            let invokes = supports(form, &default_ops, FormOperation::InvokeAction);
+            if invokes && method_name(form).is_some_and(|method| method != "POST") {
+                violations.push(Violation::UnsupportedActionMethod(format!(
+                    "{path}.htv:methodName"
+                )));
+            }
+        }
+    }
+
+    let default_ops = default_ops_for(AffordanceType::Event, false, false);
+    for (name, event) in thing.events.iter().flatten() {
+        for (index, form) in event.interaction.forms.iter().enumerate() {
+            let path = format!("events.{name}.forms.{index}");
+            check_form(thing, form, &path, &mut violations);
+
+            let subscribes = supports(form, &default_ops, FormOperation::SubscribeEvent);
+            if subscribes && form.subprotocol.as_deref() != Some("sse") {
+                violations.push(Violation::MissingSseSubprotocol(format!(
+                    "{path}.subprotocol"
+                )));
+            }
+        }
+    }
+
+    violations.sort_unstable();
+    ProfileReport { violations }
+}
+
+fn check_form<Other: ExtendableThing>(
+    thing: &Thing<Other>,
+    form: &FormFromOther<Other>,
+    path: &str,
+    violations: &mut Vec<Violation>,
+) {
+    let is_http = thing
+        .form_scheme(form)
+        .is_some_and(|scheme| scheme == "http" || scheme == "https");
+    if !is_http {
+        violations.push(Violation::UnsupportedScheme(format!("{path}.href")));
+    }
+
+    let content_type = form.content_type_or(thing);
+    let essence = content_type.split(';').next().unwrap_or_default().trim();
+    if !essence.eq_ignore_ascii_case("application/json") {
+        violations.push(Violation::UnsupportedContentType(format!(
+            "{path}.contentType"
+        )));
+    }
+}
+
+/// Checks whether a form supports an operation, given the default operations of its affordance.
+fn supports<F, ER>(form: &Form<F, ER>, default_ops: &[FormOperation], op: FormOperation) -> bool {
+    match &form.op {
+        DefaultedFormOperations::Default => default_ops.contains(&op),
+        DefaultedFormOperations::Custom(ops) => ops.contains(&op),
+    }
+}
+
+fn method_name<F, ER>(form: &Form<F, ER>) -> Option<String>
+where
+    Form<F, ER>: Serialize,
+{
+    let form = serde_json::to_value(form).ok()?;
+    form.get("htv:methodName")?.as_str().map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use serde_json::{json, Value};
+
+    use crate::{
+        hlist::{Cons, Nil},
+        protocol::http::HttpProtocol,
+        thing::TD_CONTEXT_11,
+    };
+
+    use super::*;
+
+    fn thing<Other>(members: Value) -> Thing<Other>
+    where
+        Other: ExtendableThing,
+        Thing<Other>: serde::de::DeserializeOwned,
+    {
+        let mut thing = json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Lamp",
+            "security": ["nosec_sc"],
+            "securityDefinitions": { "nosec_sc": { "scheme": "nosec" } },
+        });
+        thing
+            .as_object_mut()
+            .unwrap()
+            .extend(members.as_object().unwrap().clone());
+        serde_json::from_value(thing).unwrap()
+    }
+
+    #[test]
+    fn conforming_thing() {
+        let thing: Thing<Cons<HttpProtocol, Nil>> = thing(json!({
+            "profile": [HTTP_BASIC_PROFILE],
+            "base": "https://lamp.example.com/",
+            "forms": [{ "href": "properties", "op": "readallproperties" }],
+            "properties": {
+                "on": {
+                    "type": "boolean",
+                    "forms": [{ "href": "properties/on", "contentType": "application/json" }],
+                },
+                "brightness": {
+                    "type": "integer",
+                    "forms": [
+                        { "href": "properties/brightness", "op": "writeproperty" },
+                        {
+                            "href": "properties/brightness",
+                            "op": "readproperty",
+                            "htv:methodName": "GET",
+                        },
+                    ],
+                },
+                "secret": {
+                    "type": "string",
+                    "writeOnly": true,
+                    "forms": [{ "href": "properties/secret", "op": "writeproperty" }],
+                },
+            },
+            "actions": {
+                "fade": {
+                    "forms": [{ "href": "actions/fade", "htv:methodName": "POST" }],
+                },
+            },
+            "events": {
+                "overheated": {
+                    "forms": [{ "href": "events/overheated", "subprotocol": "sse" }],
+                },
+            },
+        }));
+
+        let report = check(&thing);
+        assert_eq!(report.violations, []);
+        assert!(report.conforms());
+    }
+
+    #[test]
+    fn non_conforming_thing() {
+        let thing: Thing<Cons<HttpProtocol, Nil>> = thing(json!({
+            "profile": "https://example.com/another-profile",
+            "base": "http://lamp.example.com/",
+            "forms": [{
+                "href": "properties",
+                "op": "readallproperties",
+                "contentType": "application/json; charset=utf-8",
+            }],
+            "properties": {
+                "on": {
+                    "type": "boolean",
+                    "forms": [{
+                        "href": "mqtt://broker.example.com/lamp/on",
+                        "contentType": "application/cbor",
+                    }],
+                },
+                "brightness": {
+                    "type": "integer",
+                    "forms": [{
+                        "href": "properties/brightness",
+                        "op": "writeproperty",
+                        "contentType": "application/json",
+                    }],
+                },
+            },
+            "actions": {
+                "fade": {
+                    "forms": [
+                        {
+                            "href": "actions/fade",
+                            "contentType": "application/json",
+                            "htv:methodName": "PUT",
+                        },
+                        {
+                            "href": "actions/fade",
+                            "op": "queryaction",
+                            "contentType": "application/json",
+                            "htv:methodName": "GET",
+                        },
+                    ],
+                },
+            },
+            "events": {
+                "overheated": {
+                    "forms": [{
+                        "href": "events/overheated",
+                        "contentType": "application/json",
+                        "subprotocol": "longpoll",
+                    }],
+                },
+            },
+        }));
+
+        let report = check(&thing);
+        assert!(!report.conforms());
+        assert_eq!(
+            report.violations,
+            [
+                Violation::MissingProfile,
+                Violation::UnsupportedScheme("properties.on.forms.0.href".to_string()),
+                Violation::UnsupportedContentType("properties.on.forms.0.contentType".to_string()),
+                Violation::MissingReadForm("properties.brightness.forms".to_string()),
+                Violation::UnsupportedActionMethod(
+                    "actions.fade.forms.0.htv:methodName".to_string()
+                ),
+                Violation::MissingSseSubprotocol(
+                    "events.overheated.forms.0.subprotocol".to_string()
+                ),
+            ],
+        );
+        assert_eq!(
+            report.violations[3].to_string(),
+            "The forms at properties.brightness.forms do not include a readproperty form",
+        );
+    }
+
+    #[test]
+    fn unknown_method_without_extension() {
+        let thing: Thing = thing(json!({
+            "profile": HTTP_BASIC_PROFILE,
+            "actions": {
+                "fade": {
+                    "forms": [{ "href": "http://lamp.example.com/fade", "htv:methodName": "PUT" }],
+                },
+            },
+        }));
+
+        let expected = if cfg!(feature = "preserve-unknown") {
+            [Violation::UnsupportedActionMethod(
+                "actions.fade.forms.0.htv:methodName".to_string(),
+            )]
+            .to_vec()
+        } else {
+            Vec::new()
+        };
+        assert_eq!(check(&thing).violations, expected);
+    }
+}
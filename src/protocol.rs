@@ -11,6 +11,8 @@
 //! can be described in a Thing Description.
 //!
 
+#![allow(clippy::doc_lazy_continuation)]
+
 pub mod coap;
 pub mod http;
 pub mod mqtt;
@@ -98,9 +98,9 @@ mod test {
     use alloc::vec;
 
     use super::{BlockSize, CoapProtocol};
-    use crate::thing::{ExpectedResponse, Form};
-    fn deserialize_form(s: &str, r: Form<CoapProtocol>) {
-        let f: crate::thing::Form<CoapProtocol> = serde_json::from_str(s).unwrap();
+    use crate::thing::{ExpectedResponse, Form, FormFromOther};
+    fn deserialize_form(s: &str, r: FormFromOther<CoapProtocol>) {
+        let f: FormFromOther<CoapProtocol> = serde_json::from_str(s).unwrap();
 
         assert_eq!(f, r);
     }
@@ -70,10 +70,10 @@ mod test {
     use alloc::vec;
 
     use super::HttpProtocol;
-    use crate::thing::{ExpectedResponse, Form};
+    use crate::thing::{ExpectedResponse, Form, FormFromOther};
 
-    fn deserialize_form(s: &str, r: Form<HttpProtocol>) {
-        let f: Form<HttpProtocol> = serde_json::from_str(s).unwrap();
+    fn deserialize_form(s: &str, r: FormFromOther<HttpProtocol>) {
+        let f: FormFromOther<HttpProtocol> = serde_json::from_str(s).unwrap();
         assert_eq!(f, r);
     }
 
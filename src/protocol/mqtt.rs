@@ -71,11 +71,11 @@ mod test {
     use alloc::vec;
 
     use super::MqttProtocol;
-    use crate::thing::Form;
     use crate::thing::{DefaultedFormOperations::Custom, FormOperation::*};
+    use crate::thing::{Form, FormFromOther};
 
-    fn deserialize_form(s: &str, r: Form<MqttProtocol>) {
-        let f: crate::thing::Form<MqttProtocol> = serde_json::from_str(s).unwrap();
+    fn deserialize_form(s: &str, r: FormFromOther<MqttProtocol>) {
+        let f: FormFromOther<MqttProtocol> = serde_json::from_str(s).unwrap();
         assert_eq!(f, r);
     }
 
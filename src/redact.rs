@@ -0,0 +1,343 @@
+//! Redaction of sensitive data from Thing Descriptions
+//!
+//! Thing Descriptions can contain data that should not end up in logs, like vendor extensions
+//! carrying tokens or passwords. The [`redact`] function replaces these values with [`REDACTED`],
+//! preserving the structure of the description.
+//!
+//! Extensions are flattened into the objects they extend, therefore the redaction works on the
+//! serialized JSON value: every member that is not part of the TD vocabulary for the object that
+//! contains it is considered extension content. An extension member is redacted when its name
+//! matches one of the [`Redaction::extension_patterns`]; all the members nested inside an
+//! extension member are considered extension content as well.
+//!
+//! The members of the TD vocabulary of the security schemes, like the `name` of the header
+//! carrying an API key or the `token` endpoint of OAuth2, describe how the credentials are
+//! exchanged and never contain the credentials themselves, therefore they are kept by default.
+//! They can be redacted anyway by listing them in [`Redaction::security_members`].
+//!
+//! The names of the entries of the maps (like the names of the properties or of the security
+//! definitions) and the data values inside `const`, `default` and `enum` are never redacted.
+//!
+//! # Example
+//!
+//! ```
+//! # use serde_json::json;
+//! # use wot_td::redact::{redact, Redaction};
+//! #
+//! let mut td = json!({
+//!     "title": "Thing name",
+//!     "securityDefinitions": {
+//!         "apikey": {
+//!             "scheme": "apikey",
+//!             "in": "header",
+//!             "name": "X-Api-Key",
+//!             "vendorApiKey": "abcdef",
+//!         },
+//!     },
+//!     "vendorToken": "abcdef",
+//! });
+//!
+//! redact(&mut td, &Redaction::default());
+//! assert_eq!(td["securityDefinitions"]["apikey"]["name"], "X-Api-Key");
+//! assert_eq!(td["securityDefinitions"]["apikey"]["vendorApiKey"], "***");
+//! assert_eq!(td["vendorToken"], "***");
+//!
+//! let redaction = Redaction {
+//!     security_members: vec!["name".to_string()],
+//!     ..Default::default()
+//! };
+//! redact(&mut td, &redaction);
+//! assert_eq!(td["securityDefinitions"]["apikey"]["name"], "***");
+//! assert_eq!(td["securityDefinitions"]["apikey"]["in"], "header");
+//! ```
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use serde_json::Value;
+
+use crate::thing::TD_MEMBERS;
+
+/// The value used to replace the redacted data.
+pub const REDACTED: &str = "***";
+
+/// The members of the TD vocabulary that can be used inside a security scheme.
+const SECURITY_SCHEME_MEMBERS: &[&str] = &[
+    "@type",
+    "description",
+    "descriptions",
+    "proxy",
+    "scheme",
+    "oneOf",
+    "allOf",
+    "in",
+    "name",
+    "qop",
+    "authorization",
+    "alg",
+    "format",
+    "identity",
+    "token",
+    "refresh",
+    "scopes",
+    "flow",
+];
+
+/// The members whose values are maps of user-defined names.
+const NAME_MAPS: &[&str] = &[
+    "properties",
+    "actions",
+    "events",
+    "securityDefinitions",
+    "schemaDefinitions",
+    "uriVariables",
+    "titles",
+    "descriptions",
+];
+
+/// The members whose values are data instead of TD vocabulary.
+const DATA_MEMBERS: &[&str] = &["const", "default", "enum"];
+
+/// The configuration of the redaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Redaction {
+    /// The members of the TD vocabulary of the security schemes that are redacted.
+    ///
+    /// These members, like `name`, `identity`, `authorization`, `token` and `refresh`, hold the
+    /// names of the headers and the URIs of the endpoints used to exchange the credentials, not
+    /// the credentials, therefore none of them is redacted by default. They can be listed here to
+    /// hide the authentication setup of a Thing as well.
+    pub security_members: Vec<String>,
+
+    /// The patterns used to redact extension members.
+    ///
+    /// An extension member is redacted when its name contains one of the patterns, ignoring the
+    /// case. Defaults to `token`, `secret`, `password`, `passphrase`, `credential` and `apikey`.
+    pub extension_patterns: Vec<String>,
+}
+
+impl Default for Redaction {
+    fn default() -> Self {
+        let to_strings = |s: &[&str]| s.iter().map(ToString::to_string).collect();
+
+        Self {
+            security_members: Vec::new(),
+            extension_patterns: to_strings(&[
+                "token",
+                "secret",
+                "password",
+                "passphrase",
+                "credential",
+                "apikey",
+            ]),
+        }
+    }
+}
+
+impl Redaction {
+    fn is_sensitive_extension(&self, name: &str) -> bool {
+        let name = name.to_ascii_lowercase();
+        self.extension_patterns
+            .iter()
+            .any(|pattern| name.contains(&pattern.to_ascii_lowercase()))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Context {
+    Td,
+    SecurityScheme,
+    Extension,
+}
+
+/// Redacts a serialized Thing Description in place.
+///
+/// See the [module documentation](self) for the redaction rules.
+pub fn redact(value: &mut Value, redaction: &Redaction) {
+    redact_value(value, Context::Td, redaction);
+}
+
+fn redact_value(value: &mut Value, context: Context, redaction: &Redaction) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                redact_member(key, value, context, redaction);
+            }
+        }
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| redact_value(value, context, redaction)),
+        _ => {}
+    }
+}
+
+fn redact_member(key: &str, value: &mut Value, context: Context, redaction: &Redaction) {
+    let known = match context {
+        Context::Td => TD_MEMBERS.contains(&key),
+        Context::SecurityScheme => SECURITY_SCHEME_MEMBERS.contains(&key),
+        Context::Extension => false,
+    };
+
+    if !known {
+        if redaction.is_sensitive_extension(key) {
+            *value = Value::String(REDACTED.into());
+        } else {
+            redact_value(value, Context::Extension, redaction);
+        }
+        return;
+    }
+
+    match context {
+        Context::SecurityScheme if redaction.security_members.iter().any(|m| m == key) => {
+            *value = Value::String(REDACTED.into());
+        }
+        _ if DATA_MEMBERS.contains(&key) => {}
+        _ if NAME_MAPS.contains(&key) => {
+            let inner_context = if key == "securityDefinitions" {
+                Context::SecurityScheme
+            } else {
+                Context::Td
+            };
+
+            if let Value::Object(map) = value {
+                map.values_mut()
+                    .for_each(|value| redact_value(value, inner_context, redaction));
+            }
+        }
+        _ => redact_value(value, context, redaction),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec};
+
+    use pretty_assertions::assert_eq;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::{
+        builder::{data_schema::SpecializableDataSchema, BuildableInteractionAffordance},
+        extend::ExtendableThing,
+        thing::Thing,
+    };
+
+    use super::*;
+
+    #[test]
+    fn redact_extension_token() {
+        #[derive(Debug, Default, Serialize, Deserialize)]
+        struct ThingExtension {
+            token: String,
+            vendor: String,
+        }
+
+        impl ExtendableThing for ThingExtension {
+            type InteractionAffordance = ();
+            type PropertyAffordance = ();
+            type ActionAffordance = ();
+            type EventAffordance = ();
+            type Form = ();
+            type ExpectedResponse = ();
+            type DataSchema = ();
+            type ObjectSchema = ();
+            type ArraySchema = ();
+        }
+
+        let thing = Thing::builder("Thing name")
+            .ext(ThingExtension {
+                token: "secret".to_string(),
+                vendor: "acme".to_string(),
+            })
+            .finish_extend()
+            .security(|b| b.oauth2("code").token("https://example.com/token"))
+            .property("token", |b| {
+                b.ext_interaction(())
+                    .ext(())
+                    .finish_extend_data_schema()
+                    .form(|b| b.ext(()).href("/token"))
+                    .string()
+            })
+            .build()
+            .unwrap();
+
+        let value = thing.redacted().unwrap();
+        assert_eq!(value["token"], "***");
+        assert_eq!(value["vendor"], "acme");
+        assert_eq!(value["properties"]["token"]["type"], "string");
+        assert_eq!(
+            value["securityDefinitions"]["oauth2"]["token"],
+            "https://example.com/token"
+        );
+        assert_eq!(value["securityDefinitions"]["oauth2"]["flow"], "code");
+
+        let value = thing
+            .redacted_with(&Redaction {
+                security_members: vec!["token".to_string()],
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(value["securityDefinitions"]["oauth2"]["token"], "***");
+        assert_eq!(value["securityDefinitions"]["oauth2"]["flow"], "code");
+    }
+
+    #[test]
+    fn redact_security_scheme_extension() {
+        let mut value = json!({
+            "securityDefinitions": {
+                "psk": {
+                    "scheme": "psk",
+                    "identity": "device-1",
+                    "vendorPassphrase": "abc",
+                    "vendorCredentials": { "key": "abc" },
+                },
+            },
+        });
+
+        redact(&mut value, &Redaction::default());
+        assert_eq!(
+            value,
+            json!({
+                "securityDefinitions": {
+                    "psk": {
+                        "scheme": "psk",
+                        "identity": "device-1",
+                        "vendorPassphrase": "***",
+                        "vendorCredentials": "***",
+                    },
+                },
+            }),
+        );
+    }
+
+    #[test]
+    fn preserve_names_and_data() {
+        let mut value = json!({
+            "properties": {
+                "password": {
+                    "type": "string",
+                    "default": { "token": "default" },
+                    "forms": [{ "href": "/password", "authToken": "abc" }],
+                },
+            },
+            "vendor": { "nested": { "apiSecret": "abc", "other": 1 } },
+        });
+
+        redact(&mut value, &Redaction::default());
+        assert_eq!(
+            value,
+            json!({
+                "properties": {
+                    "password": {
+                        "type": "string",
+                        "default": { "token": "default" },
+                        "forms": [{ "href": "/password", "authToken": "***" }],
+                    },
+                },
+                "vendor": { "nested": { "apiSecret": "***", "other": 1 } },
+            }),
+        );
+    }
+}
@@ -0,0 +1,252 @@
+//! Serde adapters to embed data schemas in other structures
+//!
+//! Applications often store [`DataSchema`]s in their own configuration files, either inline or
+//! referring to one of the `schemaDefinitions` of a Thing by name. The [`InlineOrRef`] type
+//! represents both forms, and the modules of this crate can be used with `#[serde(with = "...")]`:
+//!
+//! - [`data_schema_string_or_object`] for `InlineOrRef` fields;
+//! - [`option_data_schema`] for `Option<InlineOrRef>` fields, which also need `#[serde(default)]`
+//!   to be omitted.
+//!
+//! The references are not resolved while deserializing, because the schema definitions are
+//! usually not known at that time. [`InlineOrRef::resolve`] looks them up later.
+//!
+//! # Example
+//!
+//! ```
+//! # use serde::Deserialize;
+//! # use serde_json::json;
+//! # use wot_td::{hlist::Nil, serde_helpers::InlineOrRef, thing::DataSchema, Thing};
+//! #
+//! type Schema = DataSchema<Nil, Nil, Nil>;
+//!
+//! #[derive(Deserialize)]
+//! struct Config {
+//!     #[serde(with = "wot_td::serde_helpers::data_schema_string_or_object")]
+//!     input: InlineOrRef<Schema>,
+//!     #[serde(default, with = "wot_td::serde_helpers::option_data_schema")]
+//!     output: Option<InlineOrRef<Schema>>,
+//! }
+//!
+//! let thing: Thing = serde_json::from_value(json!({
+//!     "@context": "https://www.w3.org/2022/wot/td/v1.1",
+//!     "title": "Thing name",
+//!     "security": [],
+//!     "securityDefinitions": {},
+//!     "schemaDefinitions": {
+//!         "level": { "type": "integer" },
+//!     },
+//! }))
+//! .unwrap();
+//!
+//! let config: Config = serde_json::from_value(json!({ "input": "level" })).unwrap();
+//! assert!(config.output.is_none());
+//!
+//! let input = config.input.resolve(thing.schema_definitions.as_ref()).unwrap();
+//! assert!(input.subtype.is_some());
+//! ```
+//!
+//! [`DataSchema`]: crate::thing::DataSchema
+
+use alloc::string::String;
+
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::Map;
+
+/// A value given either inline or as the name of a definition.
+///
+/// It is serialized as the inline value or as a string.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InlineOrRef<T> {
+    /// The value itself.
+    Inline(T),
+
+    /// The name of a definition.
+    Ref(String),
+}
+
+impl<T> InlineOrRef<T> {
+    /// Returns the inline value or the referenced definition.
+    ///
+    /// It returns `None` if the referenced definition does not exist.
+    pub fn resolve<'a>(&'a self, definitions: Option<&'a Map<String, T>>) -> Option<&'a T> {
+        match self {
+            Self::Inline(value) => Some(value),
+            Self::Ref(name) => definitions?.get(name),
+        }
+    }
+}
+
+impl<T> Serialize for InlineOrRef<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Inline(value) => value.serialize(serializer),
+            Self::Ref(name) => serializer.serialize_str(name),
+        }
+    }
+}
+
+impl<'de, T> Deserialize<'de> for InlineOrRef<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Value::deserialize(deserializer)? {
+            Value::String(name) => Ok(Self::Ref(name)),
+            value => T::deserialize(value)
+                .map(Self::Inline)
+                .map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+/// Serializes and deserializes an [`InlineOrRef`] data schema.
+///
+/// An object is deserialized as an inline data schema, a string as a reference.
+pub mod data_schema_string_or_object {
+    use super::*;
+
+    /// Serializes the inline data schema or the name of the referenced one.
+    pub fn serialize<T, S>(value: &InlineOrRef<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    /// Deserializes an inline data schema or the name of a schema definition.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<InlineOrRef<T>, D::Error>
+    where
+        T: DeserializeOwned,
+        D: Deserializer<'de>,
+    {
+        InlineOrRef::deserialize(deserializer)
+    }
+}
+
+/// Serializes and deserializes an optional [`InlineOrRef`] data schema.
+///
+/// The field needs `#[serde(default)]` in order to be omitted, and `null` is deserialized as
+/// `None`.
+pub mod option_data_schema {
+    use super::*;
+
+    /// Serializes the inline data schema, the name of the referenced one or `null`.
+    pub fn serialize<T, S>(value: &Option<InlineOrRef<T>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    /// Deserializes an inline data schema, the name of a schema definition or `null`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<InlineOrRef<T>>, D::Error>
+    where
+        T: DeserializeOwned,
+        D: Deserializer<'de>,
+    {
+        Option::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use serde_json::json;
+
+    use crate::{
+        hlist::Nil,
+        thing::{DataSchema, DataSchemaSubtype, StringSchema},
+    };
+
+    use super::*;
+
+    type Schema = DataSchema<Nil, Nil, Nil>;
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Config {
+        #[serde(with = "data_schema_string_or_object")]
+        input: InlineOrRef<Schema>,
+
+        #[serde(
+            default,
+            with = "option_data_schema",
+            skip_serializing_if = "Option::is_none"
+        )]
+        output: Option<InlineOrRef<Schema>>,
+    }
+
+    #[test]
+    fn inline_and_ref_data_schemas() {
+        let config: Config = serde_json::from_value(json!({
+            "input": { "type": "string" },
+            "output": "level",
+        }))
+        .unwrap();
+
+        let string = Schema {
+            subtype: Some(DataSchemaSubtype::String(StringSchema::default())),
+            ..Default::default()
+        };
+        assert_eq!(
+            config,
+            Config {
+                input: InlineOrRef::Inline(string.clone()),
+                output: Some(InlineOrRef::Ref("level".to_string())),
+            },
+        );
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            json!({
+                "input": {
+                    "type": "string",
+                    "readOnly": false,
+                    "writeOnly": false,
+                },
+                "output": "level",
+            }),
+        );
+
+        let definitions = [("level".to_string(), string.clone())]
+            .into_iter()
+            .collect::<Map<_, _>>();
+        assert_eq!(config.input.resolve(None), Some(&string));
+        let output = config.output.as_ref().unwrap();
+        assert_eq!(output.resolve(Some(&definitions)), Some(&string));
+        assert_eq!(output.resolve(None), None);
+        assert_eq!(
+            InlineOrRef::<Schema>::Ref("missing".to_string()).resolve(Some(&definitions)),
+            None,
+        );
+    }
+
+    #[test]
+    fn optional_data_schema() {
+        for value in [
+            json!({ "input": "level" }),
+            json!({ "input": "level", "output": null }),
+        ] {
+            let config: Config = serde_json::from_value(value).unwrap();
+            assert_eq!(config.output, None);
+        }
+
+        assert!(serde_json::from_value::<Config>(json!({ "input": 42 })).is_err());
+        assert!(
+            serde_json::from_value::<Config>(json!({ "input": "level", "output": [] })).is_err()
+        );
+    }
+}
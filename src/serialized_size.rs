@@ -0,0 +1,513 @@
+//! Estimation of the size of the serialized JSON
+//!
+//! [`SizeEstimator`] is a serializer that counts the bytes of the compact JSON representation
+//! of a value without producing it. Strings are measured by their length and the characters to
+//! escape, while numbers are measured through their `Display` representation, which can differ
+//! from the one of `serde_json` for floating point numbers with a large or small magnitude.
+
+use core::fmt::{self, Display, Write};
+
+use serde::ser::{self, Serialize};
+
+/// Returns the estimated number of bytes of the compact JSON representation of the value.
+///
+/// If the value fails to serialize, the bytes counted before the failure are returned.
+pub(crate) fn estimate_serialized_size<T: Serialize + ?Sized>(value: &T) -> usize {
+    let mut estimator = SizeEstimator {
+        size: 0,
+        limit: usize::MAX,
+    };
+    // The error can only be raised by the `Serialize` implementations, the partial count is
+    // still a lower bound.
+    let _ = value.serialize(&mut estimator);
+    estimator.size
+}
+
+/// Returns the number of bytes counted when the estimated size exceeds `limit`.
+///
+/// The walk stops at the first element of a sequence, a map or a struct that takes the count
+/// over the limit, therefore the returned value is a lower bound of the estimated size.
+pub(crate) fn serialized_size_exceeding<T: Serialize + ?Sized>(
+    value: &T,
+    limit: usize,
+) -> Option<usize> {
+    let mut estimator = SizeEstimator { size: 0, limit };
+    // The error is either raised when the limit is exceeded or by the `Serialize`
+    // implementations, the partial count is checked in both cases.
+    let _ = value.serialize(&mut estimator);
+    (estimator.size > limit).then_some(estimator.size)
+}
+
+pub(crate) struct SizeEstimator {
+    size: usize,
+    limit: usize,
+}
+
+impl SizeEstimator {
+    fn check_limit(&self) -> Result<(), serde_json::Error> {
+        if self.size > self.limit {
+            Err(ser::Error::custom("the size limit has been exceeded"))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn add(&mut self, len: usize) {
+        self.size = self.size.saturating_add(len);
+    }
+
+    fn add_str(&mut self, s: &str) {
+        let escapes: usize = s
+            .bytes()
+            .map(|byte| match byte {
+                b'"' | b'\\' | b'\n' | b'\r' | b'\t' | 0x08 | 0x0c => 1,
+                0..=0x1f => 5,
+                _ => 0,
+            })
+            .sum();
+        self.add(s.len() + escapes + 2);
+    }
+
+    fn add_display(&mut self, value: impl Display) {
+        struct Counter(usize);
+
+        impl Write for Counter {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0 += s.len();
+                Ok(())
+            }
+        }
+
+        let mut counter = Counter(0);
+        // Writing into the counter cannot fail.
+        let _ = write!(counter, "{value}");
+        self.add(counter.0);
+    }
+
+    fn add_float(&mut self, value: f64) {
+        if !value.is_finite() {
+            self.add("null".len());
+        } else if value % 1. == 0. {
+            // `serde_json` writes the integral floats with a trailing `.0`.
+            self.add_display(value);
+            self.add(".0".len());
+        } else {
+            self.add_display(value);
+        }
+    }
+
+    fn add_variant_key(&mut self, variant: &str) {
+        // `{"variant":` and the closing brace.
+        self.add_str(variant);
+        self.add(3);
+    }
+}
+
+/// The serializer of a sequence or a map, adding the separators between the elements.
+pub(crate) struct Compound<'a> {
+    estimator: &'a mut SizeEstimator,
+    first: bool,
+}
+
+impl<'a> Compound<'a> {
+    fn new(estimator: &'a mut SizeEstimator) -> Self {
+        // The opening and the closing brackets.
+        estimator.add(2);
+        Self {
+            estimator,
+            first: true,
+        }
+    }
+
+    fn separator(&mut self) {
+        if !self.first {
+            self.estimator.add(1);
+        }
+        self.first = false;
+    }
+
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), serde_json::Error> {
+        self.separator();
+        value.serialize(&mut *self.estimator)?;
+        self.estimator.check_limit()
+    }
+
+    fn field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &str,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        self.separator();
+        self.estimator.add_str(key);
+        self.estimator.add(1);
+        value.serialize(&mut *self.estimator)?;
+        self.estimator.check_limit()
+    }
+}
+
+impl<'a> ser::Serializer for &'a mut SizeEstimator {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), Self::Error> {
+        self.add(if v { 4 } else { 5 });
+        Ok(())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), Self::Error> {
+        self.add_display(v);
+        Ok(())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<(), Self::Error> {
+        self.add_display(v);
+        Ok(())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), Self::Error> {
+        self.add_display(v);
+        Ok(())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<(), Self::Error> {
+        self.add_display(v);
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), Self::Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), Self::Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), Self::Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), Self::Error> {
+        self.add_float(v.into());
+        Ok(())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), Self::Error> {
+        self.add_float(v);
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), Self::Error> {
+        self.add_str(v.encode_utf8(&mut [0; 4]));
+        Ok(())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), Self::Error> {
+        self.add_str(v);
+        Ok(())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), Self::Error> {
+        let mut seq = Compound::new(self);
+        v.iter().try_for_each(|byte| seq.element(byte))
+    }
+
+    fn serialize_none(self) -> Result<(), Self::Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), Self::Error> {
+        self.add("null".len());
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Self::Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<(), Self::Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.add_variant_key(variant);
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(Compound::new(self))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(Compound::new(self))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(Compound::new(self))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        self.add_variant_key(variant);
+        Ok(Compound::new(self))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(Compound::new(self))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(Compound::new(self))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        self.add_variant_key(variant);
+        Ok(Compound::new(self))
+    }
+
+    fn collect_str<T: Display + ?Sized>(self, value: &T) -> Result<(), Self::Error> {
+        // The escapes are not counted, the strings collected this way are usually identifiers.
+        self.add_display(value);
+        self.add(2);
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        // The keys of the maps of the model are strings, the quotes of the other keys are not
+        // counted.
+        self.separator();
+        key.serialize(&mut *self.estimator)?;
+        self.estimator.add(1);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        value.serialize(&mut *self.estimator)?;
+        self.estimator.check_limit()
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    #[test]
+    fn matches_compact_json() {
+        let values = [
+            json!(null),
+            json!(true),
+            json!(false),
+            json!(-42),
+            json!(u64::MAX),
+            json!(3.0),
+            json!(-0.25),
+            json!("quote \" backslash \\ newline \n bell \u{7} ünïcode"),
+            json!([]),
+            json!({}),
+            json!([1, "two", [3.5], { "four": null }]),
+            json!({ "a": { "b": [true, false] }, "c": "d" }),
+        ];
+
+        for value in values {
+            assert_eq!(
+                estimate_serialized_size(&value),
+                serde_json::to_string(&value).unwrap().len(),
+                "{value}",
+            );
+        }
+    }
+
+    #[test]
+    fn stop_past_the_limit() {
+        let value: Value = (0..1000u32).map(|index| index.to_string()).collect();
+        let size = estimate_serialized_size(&value);
+
+        assert_eq!(serialized_size_exceeding(&value, size), None);
+        assert_eq!(serialized_size_exceeding(&value, size - 1), Some(size));
+        // `["0",` exceeds the limit, the other elements are not visited.
+        assert_eq!(serialized_size_exceeding(&value, 4), Some(5));
+    }
+
+    #[test]
+    fn enum_variants() {
+        #[derive(serde::Serialize)]
+        enum Variant {
+            Unit,
+            Newtype(u8),
+            Tuple(u8, u8),
+            Struct { a: u8 },
+        }
+
+        for value in [
+            Variant::Unit,
+            Variant::Newtype(1),
+            Variant::Tuple(1, 2),
+            Variant::Struct { a: 1 },
+        ] {
+            assert_eq!(
+                estimate_serialized_size(&value),
+                serde_json::to_string(&value).unwrap().len(),
+            );
+        }
+    }
+}
@@ -0,0 +1,816 @@
+//! Estimation of the memory footprint of a Thing
+//!
+//! The [`EstimateSize`] trait is implemented for all the types of the [`thing`](mod@crate::thing)
+//! module, and it can be used to account the size of the Thing Descriptions kept in a cache.
+//!
+//! The estimation counts the inline size of the values and the heap blocks of strings, vectors,
+//! hash maps and JSON values. The size of the allocations is based on the capacity of the
+//! collections, but the bookkeeping data of the allocator and the spare nodes of B-trees are not
+//! taken into account.
+//!
+//! Extensions need to implement the trait as well. The default implementation assumes that the
+//! extension does not own any heap data, therefore it only contributes `size_of::<T>()`:
+//!
+//! ```
+//! # use serde::{Deserialize, Serialize};
+//! # use serde_json::json;
+//! # use wot_td::{extend::ExtendableThing, hlist::Cons, size::EstimateSize, Thing};
+//! #
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct ThingExtension {
+//!     vendor: String,
+//! }
+//!
+//! impl EstimateSize for ThingExtension {
+//!     fn heap_size(&self) -> usize {
+//!         self.vendor.heap_size()
+//!     }
+//! }
+//!
+//! impl ExtendableThing for ThingExtension {
+//! #   type InteractionAffordance = ();
+//! #   type PropertyAffordance = ();
+//! #   type ActionAffordance = ();
+//! #   type EventAffordance = ();
+//! #   type Form = ();
+//! #   type ExpectedResponse = ();
+//! #   type DataSchema = ();
+//! #   type ObjectSchema = ();
+//! #   type ArraySchema = ();
+//!     /* The other types are set to `()` */
+//! }
+//!
+//! let thing: Thing<Cons<ThingExtension>> = serde_json::from_value(json!({
+//!     "@context": "https://www.w3.org/2022/wot/td/v1.1",
+//!     "title": "Thing name",
+//!     "vendor": "ACME",
+//!     "security": [],
+//!     "securityDefinitions": {},
+//! }))
+//! .unwrap();
+//!
+//! assert!(thing.estimated_size() > core::mem::size_of_val(&thing));
+//! ```
+
+use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
+use core::{
+    mem::{size_of, size_of_val},
+    num::NonZeroU64,
+};
+
+use hashbrown::HashMap;
+use oxilangtag::LanguageTag;
+use serde_json::{Map, Value};
+use time::OffsetDateTime;
+
+use crate::{
+    extend::ExtendableThing,
+    hlist::{Cons, Nil},
+    thing::{
+        ActionAffordance, AdditionalExpectedResponse, ApiKeySecurityScheme, ArraySchema, AtType,
+        BasicSecurityScheme, BearerSecurityScheme, BoxedElemOrVec, ComboSecurityScheme, DataSchema,
+        DataSchemaSubtype, DefaultedFormOperations, DigestSecurityScheme, EventAffordance,
+        ExpectedResponse, Form, FormOperation, IntegerSchema, InteractionAffordance,
+        KnownSecuritySchemeSubtype, Link, NumberSchema, OAuth2SecurityScheme, ObjectSchema,
+        PropertyAffordance, PskSecurityScheme, QualityOfProtection, SecurityAuthenticationLocation,
+        SecurityScheme, SecuritySchemeSubtype, StringSchema, Thing, UnknownSecuritySchemeSubtype,
+        VersionInfo,
+    },
+};
+
+/// A value whose memory footprint can be estimated.
+pub trait EstimateSize {
+    /// Returns the estimated number of bytes allocated on the heap and owned by the value.
+    ///
+    /// The default implementation returns 0, which is correct for the types that do not own any
+    /// heap data.
+    #[inline]
+    fn heap_size(&self) -> usize {
+        0
+    }
+
+    /// Returns the estimated memory footprint of the value, including its inline size.
+    #[inline]
+    fn estimated_size(&self) -> usize {
+        size_of_val(self) + self.heap_size()
+    }
+}
+
+/// The extensions whose memory footprint can be estimated.
+///
+/// It is implemented for every [`ExtendableThing`] with all the extension types implementing
+/// [`EstimateSize`].
+pub trait EstimateSizeExtension:
+    ExtendableThing<
+        InteractionAffordance: EstimateSize,
+        PropertyAffordance: EstimateSize,
+        ActionAffordance: EstimateSize,
+        EventAffordance: EstimateSize,
+        Form: EstimateSize,
+        ExpectedResponse: EstimateSize,
+        DataSchema: EstimateSize,
+        ObjectSchema: EstimateSize,
+        ArraySchema: EstimateSize,
+    > + EstimateSize
+{
+}
+
+impl<T> EstimateSizeExtension for T where
+    T: ExtendableThing<
+            InteractionAffordance: EstimateSize,
+            PropertyAffordance: EstimateSize,
+            ActionAffordance: EstimateSize,
+            EventAffordance: EstimateSize,
+            Form: EstimateSize,
+            ExpectedResponse: EstimateSize,
+            DataSchema: EstimateSize,
+            ObjectSchema: EstimateSize,
+            ArraySchema: EstimateSize,
+        > + EstimateSize
+{
+}
+
+macro_rules! impl_inline_only {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl EstimateSize for $ty {}
+        )+
+    };
+}
+
+impl_inline_only!(
+    (),
+    bool,
+    u32,
+    i64,
+    f64,
+    NonZeroU64,
+    OffsetDateTime,
+    Nil,
+    FormOperation,
+    QualityOfProtection,
+    SecurityAuthenticationLocation,
+    NumberSchema,
+    IntegerSchema,
+);
+
+impl<T, U> EstimateSize for Cons<T, U>
+where
+    T: EstimateSize,
+    U: EstimateSize,
+{
+    fn heap_size(&self) -> usize {
+        self.head.heap_size() + self.tail.heap_size()
+    }
+}
+
+impl EstimateSize for String {
+    #[inline]
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl EstimateSize for Cow<'static, str> {
+    fn heap_size(&self) -> usize {
+        match self {
+            Cow::Borrowed(_) => 0,
+            Cow::Owned(s) => s.heap_size(),
+        }
+    }
+}
+
+impl EstimateSize for LanguageTag<String> {
+    #[inline]
+    fn heap_size(&self) -> usize {
+        self.as_str().len()
+    }
+}
+
+impl<T: EstimateSize> EstimateSize for Option<T> {
+    #[inline]
+    fn heap_size(&self) -> usize {
+        self.as_ref().map_or(0, T::heap_size)
+    }
+}
+
+impl<T: EstimateSize> EstimateSize for Box<T> {
+    #[inline]
+    fn heap_size(&self) -> usize {
+        T::estimated_size(self)
+    }
+}
+
+impl<T: EstimateSize> EstimateSize for Vec<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * size_of::<T>() + self.iter().map(T::heap_size).sum::<usize>()
+    }
+}
+
+impl<K, V, S> EstimateSize for HashMap<K, V, S>
+where
+    K: EstimateSize,
+    V: EstimateSize,
+{
+    fn heap_size(&self) -> usize {
+        // Every bucket is made of the entry and a control byte.
+        self.capacity() * (size_of::<(K, V)>() + 1)
+            + self
+                .iter()
+                .map(|(key, value)| key.heap_size() + value.heap_size())
+                .sum::<usize>()
+    }
+}
+
+impl EstimateSize for Map<String, Value> {
+    fn heap_size(&self) -> usize {
+        self.iter()
+            .map(|(key, value)| size_of::<(String, Value)>() + key.heap_size() + value.heap_size())
+            .sum()
+    }
+}
+
+impl EstimateSize for Value {
+    fn heap_size(&self) -> usize {
+        match self {
+            Value::Null | Value::Bool(_) | Value::Number(_) => 0,
+            Value::String(s) => s.heap_size(),
+            Value::Array(values) => values.heap_size(),
+            Value::Object(map) => map.heap_size(),
+        }
+    }
+}
+
+impl<Other: EstimateSizeExtension> EstimateSize for Thing<Other> {
+    fn heap_size(&self) -> usize {
+        let Self {
+            context,
+            id,
+            attype,
+            title,
+            titles,
+            description,
+            descriptions,
+            version,
+            created,
+            modified,
+            support,
+            base,
+            properties,
+            actions,
+            events,
+            links,
+            forms,
+            security,
+            security_definitions,
+            uri_variables,
+            profile,
+            schema_definitions,
+            default_content_type,
+            other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        } = self;
+
+        let size = context.heap_size()
+            + id.heap_size()
+            + attype.heap_size()
+            + title.heap_size()
+            + titles.heap_size()
+            + description.heap_size()
+            + descriptions.heap_size()
+            + version.heap_size()
+            + created.heap_size()
+            + modified.heap_size()
+            + support.heap_size()
+            + base.heap_size()
+            + properties.heap_size()
+            + actions.heap_size()
+            + events.heap_size()
+            + links.heap_size()
+            + forms.heap_size()
+            + security.heap_size()
+            + security_definitions.heap_size()
+            + uri_variables.heap_size()
+            + profile.heap_size()
+            + schema_definitions.heap_size()
+            + default_content_type.heap_size()
+            + other.heap_size();
+        #[cfg(feature = "preserve-unknown")]
+        let size = size + unknown.heap_size();
+        size
+    }
+}
+
+impl<Other: EstimateSizeExtension> EstimateSize for InteractionAffordance<Other> {
+    fn heap_size(&self) -> usize {
+        let Self {
+            attype,
+            title,
+            titles,
+            description,
+            descriptions,
+            deprecated,
+            deprecation_note,
+            forms,
+            uri_variables,
+            other,
+        } = self;
+
+        attype.heap_size()
+            + title.heap_size()
+            + titles.heap_size()
+            + description.heap_size()
+            + descriptions.heap_size()
+            + deprecated.heap_size()
+            + deprecation_note.heap_size()
+            + forms.heap_size()
+            + uri_variables.heap_size()
+            + other.heap_size()
+    }
+}
+
+impl<Other: EstimateSizeExtension> EstimateSize for PropertyAffordance<Other> {
+    fn heap_size(&self) -> usize {
+        let Self {
+            interaction,
+            data_schema,
+            observable,
+            other,
+        } = self;
+
+        interaction.heap_size()
+            + data_schema.heap_size()
+            + observable.heap_size()
+            + other.heap_size()
+    }
+}
+
+impl<Other: EstimateSizeExtension> EstimateSize for ActionAffordance<Other> {
+    fn heap_size(&self) -> usize {
+        let Self {
+            interaction,
+            input,
+            output,
+            safe: _,
+            idempotent: _,
+            synchronous: _,
+            other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        } = self;
+
+        let size =
+            interaction.heap_size() + input.heap_size() + output.heap_size() + other.heap_size();
+        #[cfg(feature = "preserve-unknown")]
+        let size = size + unknown.heap_size();
+        size
+    }
+}
+
+impl<Other: EstimateSizeExtension> EstimateSize for EventAffordance<Other> {
+    fn heap_size(&self) -> usize {
+        let Self {
+            interaction,
+            subscription,
+            data,
+            data_response,
+            cancellation,
+            other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        } = self;
+
+        let size = interaction.heap_size()
+            + subscription.heap_size()
+            + data.heap_size()
+            + data_response.heap_size()
+            + cancellation.heap_size()
+            + other.heap_size();
+        #[cfg(feature = "preserve-unknown")]
+        let size = size + unknown.heap_size();
+        size
+    }
+}
+
+impl EstimateSize for VersionInfo {
+    fn heap_size(&self) -> usize {
+        let Self { instance, model } = self;
+        instance.heap_size() + model.heap_size()
+    }
+}
+
+impl<DS, AS, OS> EstimateSize for DataSchema<DS, AS, OS>
+where
+    DS: EstimateSize,
+    AS: EstimateSize,
+    OS: EstimateSize,
+{
+    fn heap_size(&self) -> usize {
+        let Self {
+            attype,
+            title,
+            titles,
+            description,
+            descriptions,
+            deprecated: _,
+            deprecation_note,
+            constant,
+            default,
+            unit,
+            one_of,
+            enumeration,
+            read_only: _,
+            write_only: _,
+            format,
+            subtype,
+            other,
+            raw_fields,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        } = self;
+
+        let size = attype.heap_size()
+            + title.heap_size()
+            + titles.heap_size()
+            + description.heap_size()
+            + descriptions.heap_size()
+            + deprecation_note.heap_size()
+            + constant.heap_size()
+            + default.heap_size()
+            + unit.heap_size()
+            + one_of.heap_size()
+            + enumeration.heap_size()
+            + format.heap_size()
+            + subtype.heap_size()
+            + other.heap_size()
+            + raw_fields.heap_size();
+        #[cfg(feature = "preserve-unknown")]
+        let size = size + unknown.heap_size();
+        size
+    }
+}
+
+impl<DS, AS, OS> EstimateSize for DataSchemaSubtype<DS, AS, OS>
+where
+    DS: EstimateSize,
+    AS: EstimateSize,
+    OS: EstimateSize,
+{
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::Array(array) => array.heap_size(),
+            Self::Object(object) => object.heap_size(),
+            Self::String(string) => string.heap_size(),
+            Self::Boolean | Self::Number(_) | Self::Integer(_) | Self::Null => 0,
+        }
+    }
+}
+
+impl<DS, AS, OS> EstimateSize for ArraySchema<DS, AS, OS>
+where
+    DS: EstimateSize,
+    AS: EstimateSize,
+    OS: EstimateSize,
+{
+    fn heap_size(&self) -> usize {
+        let Self {
+            items,
+            min_items: _,
+            max_items: _,
+            other,
+        } = self;
+        items.heap_size() + other.heap_size()
+    }
+}
+
+impl<T: EstimateSize> EstimateSize for BoxedElemOrVec<T> {
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::Elem(elem) => elem.heap_size(),
+            Self::Vec(elems) => elems.heap_size(),
+        }
+    }
+}
+
+impl<DS, AS, OS> EstimateSize for ObjectSchema<DS, AS, OS>
+where
+    DS: EstimateSize,
+    AS: EstimateSize,
+    OS: EstimateSize,
+{
+    fn heap_size(&self) -> usize {
+        let Self {
+            properties,
+            required,
+            other,
+        } = self;
+        properties.heap_size() + required.heap_size() + other.heap_size()
+    }
+}
+
+impl EstimateSize for StringSchema {
+    fn heap_size(&self) -> usize {
+        let Self {
+            min_length: _,
+            max_length: _,
+            pattern,
+            content_encoding,
+            content_media_type,
+        } = self;
+        pattern.heap_size() + content_encoding.heap_size() + content_media_type.heap_size()
+    }
+}
+
+impl EstimateSize for SecurityScheme {
+    fn heap_size(&self) -> usize {
+        let Self {
+            attype,
+            description,
+            descriptions,
+            proxy,
+            subtype,
+        } = self;
+        attype.heap_size()
+            + description.heap_size()
+            + descriptions.heap_size()
+            + proxy.heap_size()
+            + subtype.heap_size()
+    }
+}
+
+impl EstimateSize for SecuritySchemeSubtype {
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::Known(known) => known.heap_size(),
+            Self::Unknown(unknown) => unknown.heap_size(),
+        }
+    }
+}
+
+impl EstimateSize for KnownSecuritySchemeSubtype {
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::NoSec | Self::Auto => 0,
+            Self::Combo(combo) => combo.heap_size(),
+            Self::Basic(basic) => basic.heap_size(),
+            Self::Digest(digest) => digest.heap_size(),
+            Self::Bearer(bearer) => bearer.heap_size(),
+            Self::Psk(psk) => psk.heap_size(),
+            Self::OAuth2(oauth2) => oauth2.heap_size(),
+            Self::ApiKey(apikey) => apikey.heap_size(),
+        }
+    }
+}
+
+impl EstimateSize for UnknownSecuritySchemeSubtype {
+    fn heap_size(&self) -> usize {
+        let Self { scheme, data } = self;
+        scheme.heap_size() + data.heap_size()
+    }
+}
+
+impl EstimateSize for ComboSecurityScheme {
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::OneOf(names) | Self::AllOf(names) => names.heap_size(),
+        }
+    }
+}
+
+impl EstimateSize for BasicSecurityScheme {
+    fn heap_size(&self) -> usize {
+        self.name.heap_size()
+    }
+}
+
+impl EstimateSize for DigestSecurityScheme {
+    fn heap_size(&self) -> usize {
+        self.name.heap_size()
+    }
+}
+
+impl EstimateSize for ApiKeySecurityScheme {
+    fn heap_size(&self) -> usize {
+        self.name.heap_size()
+    }
+}
+
+impl EstimateSize for BearerSecurityScheme {
+    fn heap_size(&self) -> usize {
+        let Self {
+            authorization,
+            alg,
+            format,
+            location: _,
+            name,
+        } = self;
+        authorization.heap_size() + alg.heap_size() + format.heap_size() + name.heap_size()
+    }
+}
+
+impl EstimateSize for PskSecurityScheme {
+    fn heap_size(&self) -> usize {
+        self.identity.heap_size()
+    }
+}
+
+impl EstimateSize for OAuth2SecurityScheme {
+    fn heap_size(&self) -> usize {
+        let Self {
+            authorization,
+            token,
+            refresh,
+            scopes,
+            flow,
+        } = self;
+        authorization.heap_size()
+            + token.heap_size()
+            + refresh.heap_size()
+            + scopes.heap_size()
+            + flow.heap_size()
+    }
+}
+
+impl EstimateSize for AtType {
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::Prefixed { prefix, local } => prefix.heap_size() + local.heap_size(),
+            Self::AbsoluteIri(value) | Self::Term(value) => value.heap_size(),
+        }
+    }
+}
+
+impl EstimateSize for Link {
+    fn heap_size(&self) -> usize {
+        let Self {
+            href,
+            ty,
+            rel,
+            anchor,
+            sizes,
+            hreflang,
+        } = self;
+        href.heap_size()
+            + ty.heap_size()
+            + rel.heap_size()
+            + anchor.heap_size()
+            + sizes.heap_size()
+            + hreflang.heap_size()
+    }
+}
+
+impl<F: EstimateSize, ER: EstimateSize> EstimateSize for Form<F, ER> {
+    fn heap_size(&self) -> usize {
+        let Self {
+            op,
+            href,
+            content_type,
+            content_coding,
+            subprotocol,
+            security,
+            scopes,
+            response,
+            additional_responses,
+            other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        } = self;
+
+        let size = op.heap_size()
+            + href.heap_size()
+            + content_type.heap_size()
+            + content_coding.heap_size()
+            + subprotocol.heap_size()
+            + security.heap_size()
+            + scopes.heap_size()
+            + response.heap_size()
+            + additional_responses.heap_size()
+            + other.heap_size();
+        #[cfg(feature = "preserve-unknown")]
+        let size = size + unknown.heap_size();
+        size
+    }
+}
+
+impl EstimateSize for DefaultedFormOperations {
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::Default => 0,
+            Self::Custom(ops) => ops.heap_size(),
+        }
+    }
+}
+
+impl<Other: EstimateSize> EstimateSize for ExpectedResponse<Other> {
+    fn heap_size(&self) -> usize {
+        let Self {
+            content_type,
+            other,
+        } = self;
+        content_type.heap_size() + other.heap_size()
+    }
+}
+
+impl EstimateSize for AdditionalExpectedResponse {
+    fn heap_size(&self) -> usize {
+        let Self {
+            success: _,
+            content_type,
+            schema,
+        } = self;
+        content_type.heap_size() + schema.heap_size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn estimated_size_lower_bound() {
+        let value = json!({
+            "@context": "https://www.w3.org/2022/wot/td/v1.1",
+            "title": "Lamp",
+            "titles": { "en": "Lamp", "it": "Lampada" },
+            "base": "http://lamp.example.com/",
+            "security": ["basic"],
+            "securityDefinitions": {
+                "basic": { "scheme": "basic", "name": "Authorization" },
+            },
+            "properties": {
+                "status": {
+                    "type": "object",
+                    "properties": {
+                        "on": { "type": "boolean" },
+                        "brightness": { "type": "integer", "minimum": 0, "maximum": 100 },
+                        "color": { "type": "string", "enum": ["red", "green", "blue"] },
+                    },
+                    "required": ["on"],
+                    "forms": [{ "href": "status", "contentType": "application/json" }],
+                },
+            },
+            "actions": {
+                "fade": {
+                    "input": { "type": "array", "items": { "type": "number" } },
+                    "forms": [{ "href": "fade", "op": ["invokeaction", "queryaction"] }],
+                },
+            },
+            "events": {
+                "overheating": {
+                    "data": { "type": "string" },
+                    "forms": [{ "href": "overheating", "subprotocol": "longpoll" }],
+                },
+            },
+            "links": [{ "href": "/manual", "hreflang": ["en", "it"] }],
+        });
+        let thing: Thing = serde_json::from_value(value).unwrap();
+
+        // Every string value of the serialized Thing is stored in a heap block, except for the
+        // members that are deserialized into enums.
+        fn stored_strings_len(value: &Value) -> usize {
+            match value {
+                Value::String(s) => s.len(),
+                Value::Array(values) => values.iter().map(stored_strings_len).sum(),
+                Value::Object(map) => map
+                    .iter()
+                    .filter(|(key, _)| !matches!(key.as_str(), "type" | "scheme" | "op" | "in"))
+                    .map(|(_, value)| stored_strings_len(value))
+                    .sum(),
+                Value::Null | Value::Bool(_) | Value::Number(_) => 0,
+            }
+        }
+
+        let serialized = serde_json::to_value(&thing).unwrap();
+        let lower_bound = stored_strings_len(&serialized);
+        assert!(lower_bound > 0);
+        assert!(thing.heap_size() >= lower_bound);
+        assert_eq!(
+            thing.estimated_size(),
+            size_of::<Thing>() + thing.heap_size()
+        );
+    }
+
+    #[test]
+    fn heap_size_of_containers() {
+        let s = String::with_capacity(32);
+        assert_eq!(s.heap_size(), 32);
+        assert_eq!(Some(s).heap_size(), 32);
+        assert_eq!(None::<String>.heap_size(), 0);
+
+        let v: Vec<String> = Vec::with_capacity(4);
+        assert_eq!(v.heap_size(), 4 * size_of::<String>());
+
+        let b = Box::new(String::from("abc"));
+        assert_eq!(b.heap_size(), size_of::<String>() + 3);
+
+        let mut map = HashMap::new();
+        map.insert(String::from("key"), String::from("value"));
+        assert!(map.heap_size() >= 2 * size_of::<String>() + 8);
+
+        assert_eq!(Cow::Borrowed("static").heap_size(), 0);
+        assert_eq!(json!(["a", "bc"]).heap_size(), 2 * size_of::<Value>() + 3);
+    }
+}
@@ -0,0 +1,230 @@
+//! URI template handling
+//!
+//! The `href` of a [`Form`](crate::thing::Form) can be a [RFC 6570](https://www.rfc-editor.org/rfc/rfc6570)
+//! URI template, whose variables are described by the `uriVariables` of the affordance or of the
+//! Thing. This module contains a small parser for templates up to level 3, which only extracts
+//! the names of the variables without expanding the template. The prefix (`:n`) and explode (`*`)
+//! modifiers of level 4 are recognized and ignored.
+//!
+//! # Example
+//!
+//! ```
+//! # use wot_td::template::template_variables;
+//! #
+//! assert_eq!(
+//!     template_variables("/items/{id}{?page,size}").unwrap(),
+//!     ["id", "page", "size"],
+//! );
+//! ```
+
+use alloc::{string::String, vec::Vec};
+
+/// An error in the syntax of a URI template.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum TemplateError {
+    /// An expression is opened but never closed.
+    #[error("Unclosed expression starting at position {0}")]
+    UnclosedExpression(usize),
+
+    /// A closing brace is used outside an expression.
+    #[error("Unexpected closing brace at position {0}")]
+    UnexpectedClosingBrace(usize),
+
+    /// An expression does not contain any variable.
+    #[error("Empty expression at position {0}")]
+    EmptyExpression(usize),
+
+    /// An expression uses one of the operators reserved for future extensions.
+    #[error("Reserved operator '{0}'")]
+    ReservedOperator(char),
+
+    /// A variable name contains invalid characters.
+    #[error("Invalid variable name \"{0}\"")]
+    InvalidVariableName(String),
+}
+
+/// Extracts the names of the variables of a URI template.
+///
+/// The names are returned in the order they first appear in the template, without duplicates.
+pub fn template_variables(template: &str) -> Result<Vec<String>, TemplateError> {
+    let mut variables: Vec<String> = Vec::new();
+    let mut rest = template;
+    let mut offset = 0;
+
+    while let Some(index) = rest.find(['{', '}']) {
+        let start = offset + index;
+        if rest.as_bytes()[index] == b'}' {
+            return Err(TemplateError::UnexpectedClosingBrace(start));
+        }
+
+        let expression = &rest[index + 1..];
+        let end = expression
+            .find(['{', '}'])
+            .filter(|&end| expression.as_bytes()[end] == b'}')
+            .ok_or(TemplateError::UnclosedExpression(start))?;
+
+        for name in expression_variables(&expression[..end], start)? {
+            if variables.iter().all(|variable| variable != name) {
+                variables.push(name.into());
+            }
+        }
+
+        rest = &expression[end + 1..];
+        offset = start + end + 2;
+    }
+
+    Ok(variables)
+}
+
+fn expression_variables(expression: &str, start: usize) -> Result<Vec<&str>, TemplateError> {
+    let variable_list = match expression.chars().next() {
+        Some('+' | '#' | '.' | '/' | ';' | '?' | '&') => &expression[1..],
+        Some(operator @ ('=' | ',' | '!' | '@' | '|')) => {
+            return Err(TemplateError::ReservedOperator(operator))
+        }
+        Some(_) => expression,
+        None => return Err(TemplateError::EmptyExpression(start)),
+    };
+
+    if variable_list.is_empty() {
+        return Err(TemplateError::EmptyExpression(start));
+    }
+
+    variable_list
+        .split(',')
+        .map(|spec| {
+            let name = match spec.split_once(':') {
+                Some((name, max_length))
+                    // RFC 6570 defines the max length as `%x31-39 0*3DIGIT`, a number between 1
+                    // and 9999 without leading zeros.
+                    if !max_length.is_empty()
+                        && max_length.len() <= 4
+                        && !max_length.starts_with('0')
+                        && max_length.bytes().all(|c| c.is_ascii_digit()) =>
+                {
+                    name
+                }
+                Some(_) => spec,
+                None => spec.strip_suffix('*').unwrap_or(spec),
+            };
+
+            if is_variable_name(name) {
+                Ok(name)
+            } else {
+                Err(TemplateError::InvalidVariableName(spec.into()))
+            }
+        })
+        .collect()
+}
+
+fn is_variable_name(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    if bytes.is_empty() || bytes[0] == b'.' || bytes[bytes.len() - 1] == b'.' {
+        return false;
+    }
+
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'%' => {
+                let is_encoded = bytes
+                    .get(index + 1..index + 3)
+                    .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit));
+                if !is_encoded {
+                    return false;
+                }
+                index += 3;
+                continue;
+            }
+            b'.' if bytes[index - 1] == b'.' => return false,
+            c if c.is_ascii_alphanumeric() || c == b'_' || c == b'.' => {}
+            _ => return false,
+        }
+        index += 1;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_variables() {
+        assert_eq!(template_variables("/items/{id}").unwrap(), ["id"]);
+        assert_eq!(
+            template_variables("/{group}/items/{id}/{group}").unwrap(),
+            ["group", "id"],
+        );
+        assert_eq!(
+            template_variables("{+base}/{a.b}{/c%20d}").unwrap(),
+            ["base", "a.b", "c%20d"],
+        );
+        assert!(template_variables("/items").unwrap().is_empty());
+    }
+
+    #[test]
+    fn query_variables() {
+        assert_eq!(
+            template_variables("/items/{id}{?page,size}").unwrap(),
+            ["id", "page", "size"],
+        );
+        assert_eq!(
+            template_variables("/items{?page}{&size,sort*}{#frag:3}").unwrap(),
+            ["page", "size", "sort", "frag"],
+        );
+    }
+
+    #[test]
+    fn malformed_templates() {
+        assert_eq!(
+            template_variables("/items/{id").unwrap_err(),
+            TemplateError::UnclosedExpression(7),
+        );
+        assert_eq!(
+            template_variables("/items/{id{page}}").unwrap_err(),
+            TemplateError::UnclosedExpression(7),
+        );
+        assert_eq!(
+            template_variables("/items/id}").unwrap_err(),
+            TemplateError::UnexpectedClosingBrace(9),
+        );
+        assert_eq!(
+            template_variables("/items/{id}/{}").unwrap_err(),
+            TemplateError::EmptyExpression(12),
+        );
+        assert_eq!(
+            template_variables("/items{?}").unwrap_err(),
+            TemplateError::EmptyExpression(6),
+        );
+        assert_eq!(
+            template_variables("/items{|id}").unwrap_err(),
+            TemplateError::ReservedOperator('|'),
+        );
+        assert_eq!(
+            template_variables("/items{?page,,size}").unwrap_err(),
+            TemplateError::InvalidVariableName("".into()),
+        );
+        assert_eq!(
+            template_variables("/items/{item id}").unwrap_err(),
+            TemplateError::InvalidVariableName("item id".into()),
+        );
+        assert_eq!(
+            template_variables("/items/{id:abc}").unwrap_err(),
+            TemplateError::InvalidVariableName("id:abc".into()),
+        );
+        assert_eq!(
+            template_variables("/items/{var:0}").unwrap_err(),
+            TemplateError::InvalidVariableName("var:0".into()),
+        );
+        assert_eq!(
+            template_variables("/items/{var:01}").unwrap_err(),
+            TemplateError::InvalidVariableName("var:01".into()),
+        );
+        assert_eq!(
+            template_variables("/items/{a..b}").unwrap_err(),
+            TemplateError::InvalidVariableName("a..b".into()),
+        );
+    }
+}
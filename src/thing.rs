@@ -7,28 +7,61 @@
 //!
 //! [Interaction Affordance]: https://www.w3.org/TR/wot-thing-description/#interactionaffordance
 
-use alloc::{borrow::Cow, boxed::Box, string::*, vec::Vec};
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::*,
+    vec,
+    vec::Vec,
+};
 use core::{
     cmp::{self, Ordering},
     fmt,
     num::NonZeroU64,
 };
 
-use hashbrown::HashMap;
 use oxilangtag::LanguageTag;
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
-use serde_with::{serde_as, skip_serializing_none, DeserializeAs, OneOrMany, Same};
+use serde_with::{
+    formats::PreferMany, serde_as, skip_serializing_none, DeserializeAs, OneOrMany, Same,
+    SerializeAs,
+};
 use time::OffsetDateTime;
 
 use crate::{
-    builder::{data_schema::UncheckedDataSchema, ThingBuilder, ToExtend},
+    builder::{
+        affordance::check_href_template,
+        check_name,
+        data_schema::{check_const_and_enum, UncheckedDataSchema},
+        parse_language_tag, AffordanceType, DataSchemaError, DocumentError, Error,
+        HumanReadableInfo, MergePolicy, MissingTitle, NameKind, ThingBuilder, ToExtend,
+    },
+    coerce::{self, CoerceError},
+    dedup::{self, CanonicalId},
     extend::ExtendableThing,
     hlist::Nil,
+    limits::{self, check_limit, scan, DeserializeLimits, Limit},
+    lint::{lint, LintConfig, LintWarning},
+    openapi::to_openapi_schema,
+    redact::{redact, Redaction},
+    serialized_size::{estimate_serialized_size, serialized_size_exceeding},
+    template::{template_variables, TemplateError},
+    uri,
+    validate::{is_multiple_of, SchemaContext, ValidationError, ValidationOptions},
+    Map,
 };
 
-pub(crate) type MultiLanguage = HashMap<LanguageTag<String>, String>;
-pub(crate) type DataSchemaMap<Other> = HashMap<
+/// The serialization helper for the members that can be either a single value or an array.
+///
+/// A single value is deserialized as a vector with one element, and vectors are always
+/// serialized as arrays. In this way the array form round-trips in the same way for every member.
+pub(crate) type StringOrArray = OneOrMany<Same, PreferMany>;
+
+pub(crate) type MultiLanguage = Map<LanguageTag<String>, String>;
+pub(crate) type DataSchemaMap<Other> = Map<
     String,
     DataSchema<
         <Other as ExtendableThing>::DataSchema,
@@ -45,7 +78,202 @@ pub const TD_CONTEXT_10: &str = "https://www.w3.org/2019/wot/td/v1";
 /// description](https://www.w3.org/TR/wot-thing-description11/)
 pub const TD_CONTEXT_11: &str = "https://www.w3.org/2022/wot/td/v1.1";
 
-mod rfc3339_option {
+/// The media type of a [Thing Model](https://www.w3.org/TR/wot-thing-description11/#thing-model).
+pub const TM_MEDIA_TYPE: &str = "application/tm+json";
+
+/// The content type of the forms that do not declare one, as specified by the [Thing
+/// description](https://www.w3.org/TR/wot-thing-description11/#form).
+pub const DEFAULT_CONTENT_TYPE: &str = "application/json";
+
+/// The prefix of the members that are defined by this crate and are not part of the TD
+/// vocabulary, like `wot-td:deprecated` and `wot-td:deprecationNote`.
+pub const WOT_TD_PREFIX: &str = "wot-td";
+
+/// The namespace bound to [`WOT_TD_PREFIX`] in the `@context` of the Things using its members.
+///
+/// It expands `wot-td:deprecated` and `wot-td:deprecationNote` to IRIs resolving to the
+/// repository of this crate, where they are documented.
+pub const WOT_TD_NAMESPACE: &str = "https://github.com/wot-rust/wot-td#";
+
+/// The members of the TD vocabulary that are not specific to security schemes.
+pub(crate) const TD_MEMBERS: &[&str] = &[
+    "@context",
+    "@type",
+    "id",
+    "title",
+    "titles",
+    "description",
+    "descriptions",
+    "version",
+    "instance",
+    "model",
+    "created",
+    "modified",
+    "support",
+    "base",
+    "properties",
+    "actions",
+    "events",
+    "links",
+    "forms",
+    "security",
+    "securityDefinitions",
+    "uriVariables",
+    "profile",
+    "schemaDefinitions",
+    "observable",
+    "input",
+    "output",
+    "safe",
+    "idempotent",
+    "synchronous",
+    "subscription",
+    "data",
+    "dataResponse",
+    "cancellation",
+    "wot-td:deprecated",
+    "wot-td:deprecationNote",
+    "const",
+    "default",
+    "unit",
+    "oneOf",
+    "enum",
+    "readOnly",
+    "writeOnly",
+    "format",
+    "type",
+    "items",
+    "minItems",
+    "maxItems",
+    "minimum",
+    "maximum",
+    "exclusiveMinimum",
+    "exclusiveMaximum",
+    "multipleOf",
+    "required",
+    "minLength",
+    "maxLength",
+    "pattern",
+    "contentEncoding",
+    "contentMediaType",
+    "href",
+    "rel",
+    "anchor",
+    "sizes",
+    "hreflang",
+    "op",
+    "contentType",
+    "contentCoding",
+    "subprotocol",
+    "scopes",
+    "response",
+    "additionalResponses",
+    "success",
+    "schema",
+];
+
+/// The members of a data schema, including the ones of its subtypes.
+pub(crate) const DATA_SCHEMA_MEMBERS: &[&str] = &[
+    "@type",
+    "title",
+    "titles",
+    "description",
+    "descriptions",
+    "wot-td:deprecated",
+    "wot-td:deprecationNote",
+    "const",
+    "default",
+    "unit",
+    "oneOf",
+    "enum",
+    "readOnly",
+    "writeOnly",
+    "format",
+    "type",
+    "items",
+    "minItems",
+    "maxItems",
+    "minimum",
+    "exclusiveMinimum",
+    "maximum",
+    "exclusiveMaximum",
+    "multipleOf",
+    "properties",
+    "required",
+    "minLength",
+    "maxLength",
+    "pattern",
+    "contentEncoding",
+    "contentMediaType",
+];
+
+/// The members shared by all the interaction affordances.
+pub(crate) const INTERACTION_AFFORDANCE_MEMBERS: &[&str] = &[
+    "@type",
+    "title",
+    "titles",
+    "description",
+    "descriptions",
+    "wot-td:deprecated",
+    "wot-td:deprecationNote",
+    "forms",
+    "uriVariables",
+];
+
+/// The members specific to the property affordances.
+pub(crate) const PROPERTY_AFFORDANCE_MEMBERS: &[&str] = &["observable"];
+
+/// The members of a Thing.
+pub(crate) const THING_MEMBERS: &[&str] = &[
+    "@context",
+    "@type",
+    "id",
+    "title",
+    "titles",
+    "description",
+    "descriptions",
+    "version",
+    "created",
+    "modified",
+    "support",
+    "base",
+    "properties",
+    "actions",
+    "events",
+    "links",
+    "forms",
+    "security",
+    "securityDefinitions",
+    "uriVariables",
+    "profile",
+    "schemaDefinitions",
+];
+
+/// The members specific to the action affordances.
+pub(crate) const ACTION_AFFORDANCE_MEMBERS: &[&str] =
+    &["input", "output", "safe", "idempotent", "synchronous"];
+
+/// The members specific to the event affordances.
+pub(crate) const EVENT_AFFORDANCE_MEMBERS: &[&str] =
+    &["subscription", "data", "dataResponse", "cancellation"];
+
+/// The members of a form.
+pub(crate) const FORM_MEMBERS: &[&str] = &[
+    "op",
+    "href",
+    "contentType",
+    "contentCoding",
+    "subprotocol",
+    "security",
+    "scopes",
+    "response",
+    "additionalResponses",
+];
+
+/// The members of an expected response.
+pub(crate) const EXPECTED_RESPONSE_MEMBERS: &[&str] = &["contentType"];
+
+pub(crate) mod rfc3339_option {
     use core::fmt;
 
     use alloc::format;
@@ -136,10 +364,17 @@ mod rfc3339_option {
 /// An abstraction of a physical or a virtual entity
 ///
 /// It contains metadata and a description of its interfaces.
+///
+/// The `Debug` representation omits the unset members and summarizes the large maps, unless the
+/// `verbose-debug` feature is enabled.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "preserve-unknown",
+    serde(bound(deserialize = "Other: Serialize + serde::de::DeserializeOwned"))
+)]
 pub struct Thing<Other: ExtendableThing = Nil> {
     // The context can be arbitrarily complex
     // https://www.w3.org/TR/json-ld11/#the-context
@@ -153,8 +388,8 @@ pub struct Thing<Other: ExtendableThing = Nil> {
 
     /// JSON-LD semantic keywords
     #[serde(rename = "@type", default)]
-    #[serde_as(as = "Option<OneOrMany<_>>")]
-    pub attype: Option<Vec<String>>,
+    #[serde_as(as = "Option<StringOrArray>")]
+    pub attype: Option<Vec<AtType>>,
 
     /// Human-readable title to be displayed
     pub title: String,
@@ -196,13 +431,13 @@ pub struct Thing<Other: ExtendableThing = Nil> {
     pub base: Option<String>,
 
     /// Property-based [Interaction Affordances]
-    pub properties: Option<HashMap<String, PropertyAffordance<Other>>>,
+    pub properties: Option<Map<String, PropertyAffordance<Other>>>,
 
     /// Action-based [Interaction Affordances]
-    pub actions: Option<HashMap<String, ActionAffordance<Other>>>,
+    pub actions: Option<Map<String, ActionAffordance<Other>>>,
 
     /// Event-based [Interaction Affordances]
-    pub events: Option<HashMap<String, EventAffordance<Other>>>,
+    pub events: Option<Map<String, EventAffordance<Other>>>,
 
     /// Arbitrary resources that relate to the current Thing
     ///
@@ -210,13 +445,13 @@ pub struct Thing<Other: ExtendableThing = Nil> {
     pub links: Option<Vec<Link>>,
 
     /// Bulk-operations over the Thing properties
-    pub forms: Option<Vec<Form<Other>>>,
+    pub forms: Option<Vec<FormFromOther<Other>>>,
 
     /// Thing-wide Security constraints
     ///
     /// It is a list of names matching the Security Schemes defined in [Thing::security_definitions].
     /// They must be all satisfied in order to access the Thing resources.
-    #[serde_as(as = "OneOrMany<_>")]
+    #[serde_as(as = "StringOrArray")]
     pub security: Vec<String>,
 
     /// Security definitions
@@ -224,7 +459,7 @@ pub struct Thing<Other: ExtendableThing = Nil> {
     /// A Map of Security Schemes, the name keys are used in [Form::security] and [Thing::security]
     /// to express all the security constraints that must be satisfied in order to access the
     /// resources.
-    pub security_definitions: HashMap<String, SecurityScheme>,
+    pub security_definitions: Map<String, SecurityScheme>,
 
     /// URI template variables
     ///
@@ -241,7 +476,7 @@ pub struct Thing<Other: ExtendableThing = Nil> {
     /// Indicates the WoT Profile mechanisms followed by this Thing Description and the
     /// corresponding Thing implementation.
     #[serde(default)]
-    #[serde_as(as = "Option<OneOrMany<_>>")]
+    #[serde_as(as = "Option<StringOrArray>")]
     pub profile: Option<Vec<String>>,
 
     /// A Map of named data schemas
@@ -249,22 +484,47 @@ pub struct Thing<Other: ExtendableThing = Nil> {
     /// To be used in a schema name-value pair inside an [`AdditionalExpectedResponse`] object.
     pub schema_definitions: Option<DataSchemaMap<Other>>,
 
+    /// The content type of the forms that do not declare one.
+    ///
+    /// This is not part of the Thing Description, therefore it is neither serialized nor
+    /// deserialized, and it is ignored when two Things are compared. It is set by
+    /// [`ThingBuilder::default_content_type`] and it is consulted by [`Form::content_type_or`] and
+    /// by [`SerializeOptions::omit_default_content_type`]. The forms are not modified, therefore
+    /// the consumers of a serialized Thing must know the default in advance.
+    ///
+    /// [`ThingBuilder::default_content_type`]: crate::builder::ThingBuilder::default_content_type
+    #[serde(skip)]
+    pub default_content_type: Option<String>,
+
     /// Thing extension
     #[serde(flatten)]
     pub other: Other,
+
+    /// The members that are neither part of the TD vocabulary nor claimed by an extension.
+    ///
+    /// They are emitted again on serialization after the extension fields.
+    #[cfg(feature = "preserve-unknown")]
+    #[doc(hidden)]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::unknown::deserialize::<_, Thing<Other>, (Other,)>"
+    )]
+    pub unknown: serde_json::Map<String, Value>,
 }
 
+#[cfg(feature = "verbose-debug")]
 impl<Other> fmt::Debug for Thing<Other>
 where
     Other: ExtendableThing + fmt::Debug,
     PropertyAffordance<Other>: fmt::Debug,
     ActionAffordance<Other>: fmt::Debug,
     EventAffordance<Other>: fmt::Debug,
-    Form<Other>: fmt::Debug,
+    FormFromOther<Other>: fmt::Debug,
     DataSchemaFromOther<Other>: fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Thing")
+        let mut debug = f.debug_struct("Thing");
+        debug
             .field("context", &self.context)
             .field("id", &self.id)
             .field("attype", &self.attype)
@@ -287,8 +547,11 @@ where
             .field("uri_variables", &self.uri_variables)
             .field("profile", &self.profile)
             .field("schema_definitions", &self.schema_definitions)
-            .field("other", &self.other)
-            .finish()
+            .field("default_content_type", &self.default_content_type)
+            .field("other", &self.other);
+        #[cfg(feature = "preserve-unknown")]
+        debug.field("unknown", &self.unknown);
+        debug.finish()
     }
 }
 
@@ -320,7 +583,10 @@ where
             uri_variables: Default::default(),
             profile: Default::default(),
             schema_definitions: Default::default(),
+            default_content_type: Default::default(),
             other: Default::default(),
+            #[cfg(feature = "preserve-unknown")]
+            unknown: Default::default(),
         }
     }
 }
@@ -328,14 +594,14 @@ where
 impl<Other> PartialEq for Thing<Other>
 where
     Other: ExtendableThing + PartialEq,
-    Form<Other>: PartialEq,
+    FormFromOther<Other>: PartialEq,
     PropertyAffordance<Other>: PartialEq,
     ActionAffordance<Other>: PartialEq,
     EventAffordance<Other>: PartialEq,
     DataSchemaFromOther<Other>: PartialEq,
 {
     fn eq(&self, other: &Self) -> bool {
-        self.context == other.context
+        let eq = self.context == other.context
             && self.id == other.id
             && self.attype == other.attype
             && self.title == other.title
@@ -357,3048 +623,10372 @@ where
             && self.uri_variables == other.uri_variables
             && self.profile == other.profile
             && self.schema_definitions == other.schema_definitions
-            && self.other == other.other
+            && self.other == other.other;
+        #[cfg(feature = "preserve-unknown")]
+        let eq = eq && self.unknown == other.unknown;
+        eq
     }
 }
 
-fn default_context() -> Value {
+pub(crate) fn default_context() -> Value {
     TD_CONTEXT_11.into()
 }
 
-impl Thing<Nil> {
-    /// Shorthand for [ThingBuilder::new].
-    #[inline]
-    pub fn builder(title: impl Into<String>) -> ThingBuilder<Nil, ToExtend> {
-        ThingBuilder::new(title)
-    }
-}
+impl<Other> Thing<Other>
+where
+    Other: ExtendableThing,
+{
+    /// Merges a JSON-LD `@context` into the one of the Thing.
+    ///
+    /// The entries of `context` are appended to the ones of the Thing, skipping the duplicates:
+    /// a string is dropped if it is already present, and a member of a map is dropped if an
+    /// earlier map already has the same member with the same value. The maps left empty are
+    /// removed. The first-seen order is kept, except for the WoT context, which is moved to the
+    /// first position.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::thing::{Thing, TD_CONTEXT_11};
+    /// #
+    /// let mut thing: Thing = serde_json::from_value(json!({
+    ///     "@context": [{ "htv": "http://www.w3.org/2011/http#" }, TD_CONTEXT_11],
+    ///     "title": "Thing name",
+    ///     "security": [],
+    ///     "securityDefinitions": {},
+    /// }))
+    /// .unwrap();
+    ///
+    /// thing.merge_context(&json!([
+    ///     TD_CONTEXT_11,
+    ///     { "htv": "http://www.w3.org/2011/http#", "saref": "https://w3id.org/saref#" },
+    /// ]));
+    ///
+    /// assert_eq!(
+    ///     thing.context,
+    ///     json!([
+    ///         TD_CONTEXT_11,
+    ///         { "htv": "http://www.w3.org/2011/http#" },
+    ///         { "saref": "https://w3id.org/saref#" },
+    ///     ]),
+    /// );
+    /// ```
+    pub fn merge_context(&mut self, context: &Value) {
+        fn entries(context: &Value) -> &[Value] {
+            match context {
+                Value::Array(entries) => entries,
+                context => core::slice::from_ref(context),
+            }
+        }
 
-/// Thing description Interaction Affordance
-///
-/// Metadata of a Thing that shows the possible choices to Consumers, thereby suggesting how
-/// Consumers may interact with the Thing. See [w3c
-/// documentation](https://www.w3.org/TR/wot-thing-description11/#interactionaffordance) for
-/// further details.
-#[serde_as]
-#[skip_serializing_none]
-#[derive(Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct InteractionAffordance<Other: ExtendableThing> {
-    /// JSON-LD keyword to label the object with semantic tags or types.
-    #[serde(rename = "@type", default)]
-    #[serde_as(as = "Option<OneOrMany<_>>")]
-    pub attype: Option<Vec<String>>,
+        let mut merged: Vec<Value> = Vec::new();
+        for entry in entries(&self.context).iter().chain(entries(context)) {
+            match entry {
+                Value::Object(map) => {
+                    let mut map = map.clone();
+                    map.retain(|key, value| {
+                        merged.iter().all(|merged| {
+                            merged
+                                .as_object()
+                                .and_then(|merged| merged.get(key))
+                                .is_none_or(|merged| merged != value)
+                        })
+                    });
+                    if !map.is_empty() {
+                        merged.push(Value::Object(map));
+                    }
+                }
+                entry => {
+                    if !merged.contains(entry) {
+                        merged.push(entry.clone());
+                    }
+                }
+            }
+        }
 
-    /// A human-readable title based on a default language.
-    pub title: Option<String>,
+        if let Some(index) = merged
+            .iter()
+            .position(|entry| entry == TD_CONTEXT_11 || entry == TD_CONTEXT_10)
+        {
+            merged[..=index].rotate_right(1);
+        }
 
-    /// Multi-language human-readable titles.
-    pub titles: Option<MultiLanguage>,
+        self.context = if merged.len() == 1 {
+            merged.pop().unwrap()
+        } else {
+            Value::Array(merged)
+        };
+    }
 
-    /// Additional human-readable information based on a default language.
-    pub description: Option<String>,
+    /// Returns the link to the Thing Model the Thing is an instance of, if any.
+    ///
+    /// See [`Link::is_thing_model`] for more information.
+    pub fn thing_model_link(&self) -> Option<&Link> {
+        self.links
+            .as_ref()?
+            .iter()
+            .find(|link| link.is_thing_model())
+    }
 
-    /// Additional human-readable information in different languages.
-    pub descriptions: Option<MultiLanguage>,
+    /// Returns the key identifying the Thing, used to detect the duplicated Thing Descriptions.
+    ///
+    /// It is the `id` if present, otherwise the `base` and the `title` if the `base` is present,
+    /// otherwise a canonical representation of the whole content that does not depend on the order
+    /// of the members. See the [`dedup`] module for more information.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{dedup::CanonicalId, thing::Thing};
+    /// #
+    /// let thing = Thing::builder("Lamp")
+    ///     .id("urn:dev:ops:32473-WoTLamp-1234")
+    ///     .finish_extend()
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     thing.canonical_id().unwrap(),
+    ///     CanonicalId::Id("urn:dev:ops:32473-WoTLamp-1234".to_string()),
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// It fails if the content must be compared and the Thing cannot be serialized.
+    ///
+    /// [`dedup`]: crate::dedup
+    pub fn canonical_id(&self) -> Result<CanonicalId, serde_json::Error>
+    where
+        Self: Serialize,
+    {
+        dedup::canonical_id(self)
+    }
 
-    /// Set of form hypermedia controls that describe how an operation can be performed.
-    pub forms: Vec<Form<Other>>,
+    /// Serializes the Thing into a JSON value, using the specified options.
+    ///
+    /// With the default options the output is the same obtained with [`serde_json::to_value`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     builder::data_schema::{NumberDataSchemaBuilderLike, SpecializableDataSchema},
+    /// #     thing::{SerializeOptions, Thing},
+    /// # };
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .schema_definition("test", |b| b.finish_extend().number().maximum(5.))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mut options = SerializeOptions::default();
+    /// options.compact_floats = true;
+    /// let value = thing.to_value_with(options).unwrap();
+    /// assert_eq!(value["schemaDefinitions"]["test"]["maximum"].to_string(), "5");
+    /// ```
+    pub fn to_value_with(&self, options: SerializeOptions) -> Result<Value, serde_json::Error>
+    where
+        Self: Serialize,
+    {
+        let mut value = serde_json::to_value(self)?;
+        if options.compact_floats {
+            compact_number_bounds(&mut value);
+        }
+        if options.omit_default_flags {
+            omit_default_flags(&mut value);
+        }
+        if options.omit_default_content_type {
+            let content_type = self
+                .default_content_type
+                .as_deref()
+                .unwrap_or(DEFAULT_CONTENT_TYPE);
+            omit_default_content_type(&mut value, content_type);
+        }
+        Ok(value)
+    }
 
-    /// URI template variables
+    /// Serializes the Thing as JSON into a writer, returning the number of bytes written.
     ///
-    /// A Map of URI template variables that can be used inside `Forms`. The individual variables
-    /// `DataSchema` cannot be an [`ObjectSchema`] or an [`ArraySchema`]. If the same variable is
-    /// both declared in [`Thing`]-level `uri_variables` and in `InteractionAffordance` level, the
-    /// `InteractionAffordance` level variable takes precedence.
-    pub uri_variables: Option<DataSchemaMap<Other>>,
+    /// The Thing is serialized directly into the writer, without building an intermediate
+    /// `String`. The writer is not flushed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::thing::Thing;
+    /// #
+    /// let thing = Thing::builder("Thing name").finish_extend().build().unwrap();
+    ///
+    /// let mut buffer = Vec::new();
+    /// let written = thing.write_json(&mut buffer).unwrap();
+    /// assert_eq!(written, buffer.len());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// It fails if the writer fails or if the Thing cannot be serialized.
+    #[cfg(feature = "std")]
+    pub fn write_json<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<usize>
+    where
+        Self: Serialize,
+    {
+        let mut writer = CountingWriter::new(w);
+        serde_json::to_writer(&mut writer, self)?;
+        Ok(writer.count)
+    }
 
-    /// Interaction affordance extension
-    #[serde(flatten)]
-    pub other: Other::InteractionAffordance,
-}
+    /// Serializes the Thing as JSON into a writer using the specified options, returning the
+    /// number of bytes written.
+    ///
+    /// The options are applied as in [`Thing::to_value_with`], then the resulting JSON value is
+    /// written without building an intermediate `String`. Combined with
+    /// [`SerializeOptions::omit_default_flags`] and [`SerializeOptions::compact_floats`], it
+    /// produces the minimal output.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     builder::{affordance::BuildableInteractionAffordance, data_schema::SpecializableDataSchema},
+    /// #     thing::{SerializeOptions, Thing},
+    /// # };
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .property("on", |b| {
+    ///         b.finish_extend_data_schema()
+    ///             .bool()
+    ///             .form(|b| b.href("/on"))
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mut default = Vec::new();
+    /// thing.write_json(&mut default).unwrap();
+    ///
+    /// let mut options = SerializeOptions::default();
+    /// options.omit_default_flags = true;
+    /// let mut minimal = Vec::new();
+    /// let written = thing.write_json_with(&mut minimal, options).unwrap();
+    /// assert_eq!(written, minimal.len());
+    /// assert!(minimal.len() < default.len());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// It fails if the writer fails or if the Thing cannot be serialized.
+    #[cfg(feature = "std")]
+    pub fn write_json_with<W: std::io::Write>(
+        &self,
+        w: &mut W,
+        options: SerializeOptions,
+    ) -> std::io::Result<usize>
+    where
+        Self: Serialize,
+    {
+        let value = self.to_value_with(options)?;
+        let mut writer = CountingWriter::new(w);
+        serde_json::to_writer(&mut writer, &value)?;
+        Ok(writer.count)
+    }
 
-impl<Other> fmt::Debug for InteractionAffordance<Other>
-where
-    Other: ExtendableThing,
-    Form<Other>: fmt::Debug,
-    DataSchemaFromOther<Other>: fmt::Debug,
-    Other::InteractionAffordance: fmt::Debug,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("InteractionAffordance")
-            .field("attype", &self.attype)
-            .field("title", &self.title)
-            .field("titles", &self.titles)
-            .field("description", &self.description)
-            .field("descriptions", &self.descriptions)
-            .field("forms", &self.forms)
-            .field("uri_variables", &self.uri_variables)
-            .field("other", &self.other)
-            .finish()
+    /// Serializes the Thing into a JSON value, redacting the sensitive data with the default
+    /// [`Redaction`].
+    ///
+    /// This is meant to be used to log Thing Descriptions safely, see the [`redact`] module for
+    /// the redaction rules.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{redact::Redaction, thing::Thing};
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .security(|b| b.apikey().name("X-Api-Key"))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let value = thing.redacted().unwrap();
+    /// assert_eq!(value["securityDefinitions"]["apikey"]["name"], "X-Api-Key");
+    ///
+    /// let redaction = Redaction {
+    ///     security_members: vec!["name".to_string()],
+    ///     ..Default::default()
+    /// };
+    /// let value = thing.redacted_with(&redaction).unwrap();
+    /// assert_eq!(value["securityDefinitions"]["apikey"]["name"], "***");
+    /// ```
+    ///
+    /// [`redact`]: crate::redact
+    #[inline]
+    pub fn redacted(&self) -> Result<Value, serde_json::Error>
+    where
+        Self: Serialize,
+    {
+        self.redacted_with(&Redaction::default())
     }
-}
 
-impl<Other> Default for InteractionAffordance<Other>
-where
-    Other: ExtendableThing,
-    Form<Other>: Default,
-    DataSchemaFromOther<Other>: Default,
-    Other::InteractionAffordance: Default,
-{
-    fn default() -> Self {
-        Self {
-            attype: Default::default(),
-            title: Default::default(),
-            titles: Default::default(),
-            description: Default::default(),
-            descriptions: Default::default(),
-            forms: Default::default(),
-            uri_variables: Default::default(),
-            other: Default::default(),
-        }
+    /// Serializes the Thing into a JSON value, redacting the sensitive data with the specified
+    /// [`Redaction`].
+    pub fn redacted_with(&self, redaction: &Redaction) -> Result<Value, serde_json::Error>
+    where
+        Self: Serialize,
+    {
+        let mut value = serde_json::to_value(self)?;
+        redact(&mut value, redaction);
+        Ok(value)
     }
-}
 
-impl<Other> PartialEq for InteractionAffordance<Other>
-where
-    Other: ExtendableThing,
-    Form<Other>: PartialEq,
-    DataSchemaFromOther<Other>: PartialEq,
-    Other::InteractionAffordance: PartialEq,
-{
-    fn eq(&self, other: &Self) -> bool {
-        self.attype == other.attype
-            && self.title == other.title
-            && self.titles == other.titles
-            && self.description == other.description
-            && self.descriptions == other.descriptions
-            && self.forms == other.forms
-            && self.uri_variables == other.uri_variables
-            && self.other == other.other
+    /// Serializes the Thing into a JSON value in which the compact IRIs are expanded.
+    ///
+    /// The `@type` values and the member names using a prefix declared in the `@context`, or one
+    /// of the prefixes of the TD context, are replaced with fully qualified IRIs and the
+    /// `@context` is removed. The `@type` values are always emitted as arrays.
+    ///
+    /// # Errors
+    ///
+    /// It fails with [`DocumentError::Serialization`] when the Thing cannot be serialized, which
+    /// can only be caused by the `Serialize` implementation of an extension.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::{builder::data_schema::SpecializableDataSchema, thing::Thing};
+    /// #
+    /// let thing = Thing::builder("Lamp")
+    ///     .context_map(|b| b.context("saref", "https://w3id.org/saref#"))
+    ///     .attype("saref:LightSwitch")
+    ///     .finish_extend()
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let value = thing.to_json_ld_expanded().unwrap();
+    /// assert_eq!(value["@type"], json!(["https://w3id.org/saref#LightSwitch"]));
+    /// ```
+    #[cfg(feature = "jsonld")]
+    pub fn to_json_ld_expanded(&self) -> Result<Value, Error>
+    where
+        Self: Serialize,
+    {
+        serde_json::to_value(self)
+            .map(crate::jsonld::expand)
+            .map_err(|error| DocumentError::Serialization(error.to_string()).into())
     }
-}
 
-// Do not serialize the fields that are shared with DataSchema
-fn omit_common<S, O>(i: &InteractionAffordance<O>, ser: S) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-    O: ExtendableThing,
-{
-    use serde::ser::SerializeMap;
+    /// Returns the names of the declared `uriVariables` that are not used by any `href` template
+    /// in their scope, sorted and without duplicates.
+    ///
+    /// The variables declared at Thing level are in scope for every form of the Thing, the ones
+    /// declared by an affordance only for the forms of the affordance. The templates that cannot
+    /// be parsed are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{builder::data_schema::SpecializableDataSchema, thing::Thing};
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .form(|b| b.href("/{id}").op(wot_td::thing::FormOperation::ReadAllProperties))
+    ///     .uri_variable("id", |b| b.finish_extend().integer())
+    ///     .uri_variable("page", |b| b.finish_extend().integer())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(thing.unused_uri_variables(), ["page"]);
+    /// ```
+    pub fn unused_uri_variables(&self) -> Vec<&str> {
+        fn form_variables<F, ER>(forms: &[Form<F, ER>]) -> Vec<String> {
+            forms
+                .iter()
+                .filter_map(|form| form.href_template_variables().ok())
+                .flatten()
+                .collect()
+        }
 
-    let mut s = ser.serialize_map(Some(3))?;
-    s.serialize_entry("forms", &i.forms)?;
-    if i.uri_variables.is_some() {
-        s.serialize_entry("uriVariables", &i.uri_variables)?;
-    }
+        let interactions = self
+            .properties
+            .iter()
+            .flat_map(|properties| properties.values().map(|property| &property.interaction))
+            .chain(
+                self.actions
+                    .iter()
+                    .flat_map(|actions| actions.values().map(|action| &action.interaction)),
+            )
+            .chain(
+                self.events
+                    .iter()
+                    .flat_map(|events| events.values().map(|event| &event.interaction)),
+            );
+
+        let mut used = form_variables(self.forms.as_deref().unwrap_or_default());
+        let mut unused = Vec::new();
+        for interaction in interactions {
+            let variables = form_variables(&interaction.forms);
+            unused.extend(
+                interaction
+                    .uri_variables
+                    .iter()
+                    .flat_map(|uri_variables| uri_variables.keys())
+                    .filter(|name| !variables.contains(name))
+                    .map(String::as_str),
+            );
+            used.extend(variables);
+        }
+        unused.extend(
+            self.uri_variables
+                .iter()
+                .flat_map(|uri_variables| uri_variables.keys())
+                .filter(|name| !used.contains(name))
+                .map(String::as_str),
+        );
 
-    Serialize::serialize(
-        &&i.other,
-        crate::flat_map_serialize::FlatMapSerializer(&mut s),
-    )?;
+        unused.sort_unstable();
+        unused.dedup();
+        unused
+    }
 
-    s.end()
-}
+    /// Returns the affordances marked as deprecated, along with their deprecation notes.
+    ///
+    /// The properties are considered deprecated when either the interaction or the data schema
+    /// carries the marker. The order of the affordances is unspecified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     builder::{
+    /// #         affordance::BuildableInteractionAffordance, data_schema::SpecializableDataSchema,
+    /// #         AffordanceType, BuildableHumanReadableInfo,
+    /// #     },
+    /// #     thing::Thing,
+    /// # };
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .property("level", |b| {
+    ///         b.finish_extend_data_schema()
+    ///             .integer()
+    ///             .deprecated()
+    ///             .deprecation_note("Use brightness instead")
+    ///             .form(|b| b.href("/level"))
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     thing.deprecated_affordances().collect::<Vec<_>>(),
+    ///     [(AffordanceType::Property, "level", Some("Use brightness instead"))],
+    /// );
+    /// ```
+    pub fn deprecated_affordances(
+        &self,
+    ) -> impl Iterator<Item = (AffordanceType, &str, Option<&str>)> {
+        fn deprecation(
+            interaction: &InteractionAffordance<impl ExtendableThing>,
+        ) -> Option<Option<&str>> {
+            (interaction.deprecated == Some(true))
+                .then_some(interaction.deprecation_note.as_deref())
+        }
 
-/// An affordance that exposes the state of a `Thing`
-///
-/// The fields `title`, `titles`, `description`, `descriptions`,
-/// are serialized from `PropertyAffordance::data_schema`.
-#[skip_serializing_none]
-#[derive(Deserialize, Serialize)]
-pub struct PropertyAffordance<Other: ExtendableThing> {
-    /// The interaction affordance.
-    #[serde(flatten)]
-    #[serde(serialize_with = "omit_common")]
-    pub interaction: InteractionAffordance<Other>,
+        let properties = self
+            .properties
+            .iter()
+            .flatten()
+            .filter_map(|(name, property)| {
+                let note = property
+                    .interaction
+                    .deprecation_note
+                    .as_deref()
+                    .or(property.data_schema.deprecation_note.as_deref());
+                (property.interaction.deprecated == Some(true)
+                    || property.data_schema.deprecated == Some(true))
+                .then_some((AffordanceType::Property, name.as_str(), note))
+            });
+        let actions = self.actions.iter().flatten().filter_map(|(name, action)| {
+            deprecation(&action.interaction)
+                .map(|note| (AffordanceType::Action, name.as_str(), note))
+        });
+        let events = self.events.iter().flatten().filter_map(|(name, event)| {
+            deprecation(&event.interaction).map(|note| (AffordanceType::Event, name.as_str(), note))
+        });
 
-    /// The data schema representing the property.
-    #[serde(flatten)]
-    pub data_schema: DataSchemaFromOther<Other>,
+        properties.chain(actions).chain(events)
+    }
 
-    /// A hint that indicates whether Servients hosting the Thing and Intermediaries should provide
-    /// a Protocol Binding that supports the [`ObserveProperty`] and [`UnobserveProperty`]
-    /// operations for this property.
+    /// Returns the properties that can be observed, with their names.
     ///
-    /// [`ObserveProperty`]: FormOperation::ObserveProperty
-    /// [`UnobserveProperty`]: FormOperation::UnobserveProperty
-    pub observable: Option<bool>,
+    /// A property without the `observable` flag is not observable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     builder::{affordance::BuildableInteractionAffordance, data_schema::SpecializableDataSchema},
+    /// #     thing::Thing,
+    /// # };
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .property("temperature", |b| {
+    ///         b.finish_extend_data_schema()
+    ///             .number()
+    ///             .observable(true)
+    ///             .form(|b| b.href("/temperature"))
+    ///     })
+    ///     .property("unit", |b| {
+    ///         b.finish_extend_data_schema()
+    ///             .string()
+    ///             .form(|b| b.href("/unit"))
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     thing
+    ///         .observable_properties()
+    ///         .map(|(name, _)| name)
+    ///         .collect::<Vec<_>>(),
+    ///     ["temperature"],
+    /// );
+    /// ```
+    pub fn observable_properties(
+        &self,
+    ) -> impl Iterator<Item = (&str, &PropertyAffordance<Other>)> {
+        self.properties
+            .iter()
+            .flatten()
+            .filter(|(_, property)| property.observable == Some(true))
+            .map(|(name, property)| (name.as_str(), property))
+    }
 
-    /// Property affordance extension.
-    #[serde(flatten)]
-    pub other: Other::PropertyAffordance,
-}
+    /// Checks the Thing for valid constructs that are likely to be mistakes.
+    ///
+    /// The warnings are sorted by kind and path, see the [`lint`](crate::lint) module for more
+    /// details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::{lint::LintWarning, thing::Thing};
+    /// #
+    /// let thing: Thing = serde_json::from_value(json!({
+    ///     "@context": "https://www.w3.org/2022/wot/td/v1.1",
+    ///     "title": "Thing name",
+    ///     "security": [],
+    ///     "securityDefinitions": {},
+    ///     "schemaDefinitions": {
+    ///         "level": {
+    ///             "type": "number",
+    ///             "oneOf": [{ "minimum": 0 }, { "const": -1 }],
+    ///         },
+    ///     },
+    /// }))
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     thing.lint(),
+    ///     [LintWarning::OneOfWithSubtype("schemaDefinitions.level".to_string())],
+    /// );
+    /// ```
+    pub fn lint(&self) -> Vec<LintWarning> {
+        lint(self, &LintConfig::default())
+    }
 
-impl<Other> fmt::Debug for PropertyAffordance<Other>
-where
-    Other: ExtendableThing,
-    InteractionAffordance<Other>: fmt::Debug,
-    DataSchemaFromOther<Other>: fmt::Debug,
-    Other::PropertyAffordance: fmt::Debug,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("PropertyAffordance")
-            .field("interaction", &self.interaction)
-            .field("data_schema", &self.data_schema)
-            .field("observable", &self.observable)
-            .field("other", &self.other)
-            .finish()
+    /// Checks the Thing for valid constructs that are likely to be mistakes, including the
+    /// checks enabled by the specified [`LintConfig`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     lint::{LintConfig, LintWarning},
+    /// #     thing::Thing,
+    /// # };
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .description("A Thing with a long description")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mut config = LintConfig::default();
+    /// config.max_description_length = Some(16);
+    /// config.max_serialized_size = Some(1024);
+    /// assert_eq!(
+    ///     thing.lint_with(&config),
+    ///     [LintWarning::DescriptionTooLong("description".to_string())],
+    /// );
+    /// ```
+    pub fn lint_with(&self, config: &LintConfig) -> Vec<LintWarning>
+    where
+        Self: Serialize,
+    {
+        let mut warnings = lint(self, config);
+        if let Some(size) = config
+            .max_serialized_size
+            .and_then(|max_size| serialized_size_exceeding(self, max_size))
+        {
+            warnings.push(LintWarning::SerializedSizeExceeded(size));
+            warnings.sort_unstable();
+        }
+        warnings
     }
-}
 
-impl<Other> Default for PropertyAffordance<Other>
-where
-    Other: ExtendableThing,
-    InteractionAffordance<Other>: Default,
-    DataSchemaFromOther<Other>: Default,
-    Other::PropertyAffordance: Default,
-{
-    fn default() -> Self {
-        Self {
-            interaction: Default::default(),
-            data_schema: Default::default(),
-            observable: Default::default(),
-            other: Default::default(),
-        }
+    /// Estimates the size in bytes of the Thing serialized as compact JSON.
+    ///
+    /// The Thing is walked with a serializer that only sums the lengths of the members, without
+    /// producing the JSON document. The estimate is exact, except for the floating point numbers
+    /// that `serde_json` writes in exponential notation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::thing::Thing;
+    /// #
+    /// let thing = Thing::builder("Thing name").finish_extend().build().unwrap();
+    ///
+    /// assert_eq!(
+    ///     thing.serialized_size_estimate(),
+    ///     serde_json::to_string(&thing).unwrap().len(),
+    /// );
+    /// ```
+    pub fn serialized_size_estimate(&self) -> usize
+    where
+        Self: Serialize,
+    {
+        estimate_serialized_size(self)
     }
-}
 
-impl<Other> PartialEq for PropertyAffordance<Other>
-where
-    Other: ExtendableThing,
-    InteractionAffordance<Other>: PartialEq,
-    DataSchemaFromOther<Other>: PartialEq,
-    Other::PropertyAffordance: PartialEq,
-{
-    fn eq(&self, other: &Self) -> bool {
-        self.interaction == other.interaction
-            && self.data_schema == other.data_schema
-            && self.observable == other.observable
-            && self.other == other.other
+    /// Checks the Thing for the issues that the [`ThingBuilder`] rejects while building.
+    ///
+    /// This is useful for the Thing Descriptions that have been deserialized. The following
+    /// names must be neither empty nor made only of whitespace:
+    ///
+    /// - the names of the affordances, of the `uriVariables`, of the `schemaDefinitions` and of
+    ///   the `securityDefinitions`;
+    /// - the `security` references of the Thing and of the forms;
+    /// - the `properties` and the `required` entries of the object data schemas.
+    ///
+    /// Moreover, the data schemas must not have both `const` and `enum` and the URIs of the
+    /// `@context`, `id`, `base` and the `href` of the links and of the forms must be valid for the
+    /// backend selected by the cargo features, like in [`ThingBuilder::build`]. At most one link
+    /// can refer to the Thing Model (see [`Link::is_thing_model`]).
+    ///
+    /// See [`Thing::validate_with`] to enable the optional checks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::{
+    /// #     builder::{DocumentError, Error, NameKind},
+    /// #     thing::Thing,
+    /// # };
+    /// #
+    /// let thing: Thing = serde_json::from_value(json!({
+    ///     "@context": "https://www.w3.org/2022/wot/td/v1.1",
+    ///     "title": "Thing name",
+    ///     "security": [],
+    ///     "securityDefinitions": {},
+    ///     "schemaDefinitions": {
+    ///         " ": { "type": "string" },
+    ///     },
+    /// }))
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     thing.validate(),
+    ///     Err(Error::Document(DocumentError::EmptyName(NameKind::SchemaDefinition))),
+    /// );
+    /// ```
+    #[inline]
+    pub fn validate(&self) -> Result<(), Error> {
+        self.validate_with(ValidateOptions::default())
     }
-}
 
-/// An affordance that allows to inkvoke a function of the `Thing`.
-#[skip_serializing_none]
-#[derive(Deserialize, Serialize)]
-pub struct ActionAffordance<Other: ExtendableThing> {
-    /// The interaction affordance.
-    #[serde(flatten)]
-    pub interaction: InteractionAffordance<Other>,
+    /// Checks the Thing like [`Thing::validate`], with the optional checks enabled by `options`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::{
+    /// #     builder::DataSchemaError,
+    /// #     thing::{Thing, ValidateOptions},
+    /// # };
+    /// #
+    /// let thing: Thing = serde_json::from_value(json!({
+    ///     "@context": "https://www.w3.org/2022/wot/td/v1.1",
+    ///     "title": "Thing name",
+    ///     "security": [],
+    ///     "securityDefinitions": {},
+    ///     "schemaDefinitions": {
+    ///         "archive": { "type": "string", "contentEncoding": "gzip" },
+    ///     },
+    /// }))
+    /// .unwrap();
+    /// assert_eq!(thing.validate(), Ok(()));
+    ///
+    /// let mut options = ValidateOptions::default();
+    /// options.strict_content_encoding = true;
+    /// assert_eq!(
+    ///     thing.validate_with(options),
+    ///     Err(DataSchemaError::UnknownContentEncoding("gzip".to_string()).into()),
+    /// );
+    /// ```
+    pub fn validate_with(&self, options: ValidateOptions) -> Result<(), Error> {
+        check_names(&self.security, NameKind::Security)?;
+        check_names(
+            self.security_definitions.keys(),
+            NameKind::SecurityDefinition,
+        )?;
+        if let Some(schema_definitions) = &self.schema_definitions {
+            check_data_schema_map(schema_definitions, NameKind::SchemaDefinition)?;
+        }
+        if let Some(uri_variables) = &self.uri_variables {
+            check_data_schema_map(uri_variables, NameKind::UriVariable)?;
+        }
+        check_form_names(self.forms.as_deref().unwrap_or_default())?;
+        if self
+            .links
+            .iter()
+            .flatten()
+            .filter(|link| link.is_thing_model())
+            .count()
+            > 1
+        {
+            return Err(DocumentError::MultipleThingModelLinks.into());
+        }
 
-    /// The input data schema of the action.
-    pub input: Option<DataSchemaFromOther<Other>>,
+        for (name, property) in self.properties.iter().flatten() {
+            check_name(name, NameKind::Property)?;
+            check_interaction_names(&property.interaction)?;
+            check_data_schema(&property.data_schema)?;
+        }
+        for (name, action) in self.actions.iter().flatten() {
+            check_name(name, NameKind::Action)?;
+            check_interaction_names(&action.interaction)?;
+            [&action.input, &action.output]
+                .into_iter()
+                .flatten()
+                .try_for_each(check_data_schema)?;
+        }
+        for (name, event) in self.events.iter().flatten() {
+            check_name(name, NameKind::Event)?;
+            check_interaction_names(&event.interaction)?;
+            [
+                &event.subscription,
+                &event.data,
+                &event.data_response,
+                &event.cancellation,
+            ]
+            .into_iter()
+            .flatten()
+            .try_for_each(check_data_schema)?;
+        }
+        if options.strict_content_encoding {
+            check_content_encodings(self)?;
+        }
 
-    /// The output data schema of the action.
-    pub output: Option<DataSchemaFromOther<Other>>,
+        self.check_uris()
+    }
 
-    /// Whether the action is safe or not.
+    /// Returns the summary of the Thing, without the interaction affordances.
     ///
-    /// In case it is `true`, when the action is invoked there is no internal state that is being
-    /// changed.
-    #[serde(default)]
-    pub safe: bool,
+    /// The summary contains the members used by TD Directories for listings and keeps the
+    /// Thing-level extension data. See [`ThingSummary`] for the list of the members.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::thing::Thing;
+    /// #
+    /// let thing = Thing::builder("Lamp")
+    ///     .id("urn:example:lamp")
+    ///     .finish_extend()
+    ///     .security(|b| b.basic().required())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(thing.summary()).unwrap(),
+    ///     json!({
+    ///         "@context": "https://www.w3.org/2022/wot/td/v1.1",
+    ///         "id": "urn:example:lamp",
+    ///         "title": "Lamp",
+    ///         "security": ["basic"],
+    ///     }),
+    /// );
+    /// ```
+    #[inline]
+    pub fn summary(&self) -> ThingSummary<'_, Other> {
+        self.into()
+    }
 
-    /// Whether the action is idempotent or not.
+    /// Drops the interaction affordances and the Thing-level forms that operate on them.
     ///
-    /// In case it is `true`, the action can be called repeatedly with the same result based on the
-    /// same input.
-    #[serde(default)]
-    pub idempotent: bool,
+    /// Unlike [`Thing::summary`], the result is still a complete Thing Description, which keeps
+    /// all the other members, like the security definitions and the schema definitions.
+    pub fn strip_to_summary(mut self) -> Self {
+        self.properties = None;
+        self.actions = None;
+        self.events = None;
+        self.forms = None;
+        self
+    }
 
-    /// Whether the action is synchronous or not.
+    /// Returns the security schemes that apply to `form`, with their names.
     ///
-    /// A synchronous action means that the response of action contains all the information about
-    /// the result of the action and no further querying about the status of the action is needed.
+    /// The `security` of the form overrides the one of the Thing. The names are resolved in order
+    /// using the `securityDefinitions`, skipping the ones that are not defined, and the _combo_
+    /// schemes are replaced by the schemes they combine, without expanding nested combos.
     ///
-    /// If this is `None`, no claim on the synchronicity of the action can be made.
-    pub synchronous: Option<bool>,
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::thing::{Form, Thing};
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .security(|b| b.no_sec().required())
+    ///     .security(|b| b.basic())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let form = Form {
+    ///     security: Some(vec!["basic".to_string()]),
+    ///     ..Default::default()
+    /// };
+    /// let schemes = thing.security_for_form(&form);
+    /// assert_eq!(schemes.len(), 1);
+    /// assert_eq!(schemes[0].0, "basic");
+    ///
+    /// assert!(!thing.requires_auth(&Form::default()));
+    /// assert!(thing.requires_auth(&form));
+    /// ```
+    pub fn security_for_form(&self, form: &FormFromOther<Other>) -> Vec<(&str, &SecurityScheme)> {
+        let resolve = |name: &String| {
+            self.security_definitions
+                .get_key_value(name)
+                .map(|(name, scheme)| (name.as_str(), scheme))
+        };
 
-    /// Action affordance extension
-    #[serde(flatten)]
-    pub other: Other::ActionAffordance,
-}
+        let mut schemes = Vec::new();
+        for (name, scheme) in form
+            .security
+            .as_ref()
+            .unwrap_or(&self.security)
+            .iter()
+            .filter_map(resolve)
+        {
+            match &scheme.subtype {
+                SecuritySchemeSubtype::Known(KnownSecuritySchemeSubtype::Combo(
+                    ComboSecurityScheme::OneOf(names) | ComboSecurityScheme::AllOf(names),
+                )) => schemes.extend(names.iter().filter_map(resolve)),
+                _ => schemes.push((name, scheme)),
+            }
+        }
+        schemes
+    }
 
-impl<Other> fmt::Debug for ActionAffordance<Other>
-where
-    Other: ExtendableThing,
-    InteractionAffordance<Other>: fmt::Debug,
-    DataSchemaFromOther<Other>: fmt::Debug,
-    Other::ActionAffordance: fmt::Debug,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("ActionAffordance")
-            .field("interaction", &self.interaction)
-            .field("input", &self.input)
-            .field("output", &self.output)
-            .field("safe", &self.safe)
-            .field("idempotent", &self.idempotent)
-            .field("synchronous", &self.synchronous)
-            .field("other", &self.other)
-            .finish()
+    /// Checks whether accessing the resource described by `form` requires any authentication.
+    ///
+    /// It returns `false` only if all the security schemes resolved by
+    /// [`Thing::security_for_form`] are _nosec_ schemes, therefore it returns `true` when no
+    /// scheme can be resolved.
+    pub fn requires_auth(&self, form: &FormFromOther<Other>) -> bool {
+        let schemes = self.security_for_form(form);
+        schemes.is_empty()
+            || schemes.iter().any(|(_, scheme)| {
+                scheme.subtype != SecuritySchemeSubtype::Known(KnownSecuritySchemeSubtype::NoSec)
+            })
     }
-}
 
-impl<Other> Default for ActionAffordance<Other>
-where
-    Other: ExtendableThing,
-    InteractionAffordance<Other>: Default,
-    DataSchemaFromOther<Other>: Default,
-    Other::ActionAffordance: Default,
-{
-    fn default() -> Self {
-        Self {
-            interaction: Default::default(),
-            input: Default::default(),
-            output: Default::default(),
-            safe: Default::default(),
-            idempotent: Default::default(),
-            synchronous: Default::default(),
-            other: Default::default(),
+    /// Returns the `uriVariables` in scope for the forms of an affordance of the Thing.
+    ///
+    /// The variables of the Thing are merged with the ones of the affordance, and the latter take
+    /// precedence when both declare a variable with the same name. The variables are borrowed when
+    /// only one of them declares any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     builder::{affordance::BuildableInteractionAffordance, data_schema::SpecializableDataSchema},
+    /// #     thing::{DataSchemaSubtype, Thing},
+    /// # };
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .uri_variable("id", |b| b.finish_extend().integer())
+    ///     .uri_variable("page", |b| b.finish_extend().integer())
+    ///     .property("item", |b| {
+    ///         b.finish_extend_data_schema()
+    ///             .uri_variable("id", |b| b.finish_extend().string())
+    ///             .form(|b| b.href("/items/{id}{?page}"))
+    ///             .bool()
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let item = &thing.properties.as_ref().unwrap()["item"];
+    /// let uri_variables = thing.resolve_uri_variables(&item.interaction);
+    /// assert_eq!(uri_variables.len(), 2);
+    /// assert!(matches!(
+    ///     uri_variables["id"].subtype,
+    ///     Some(DataSchemaSubtype::String(_)),
+    /// ));
+    /// ```
+    pub fn resolve_uri_variables<'a>(
+        &'a self,
+        affordance: &'a InteractionAffordance<Other>,
+    ) -> Cow<'a, DataSchemaMap<Other>>
+    where
+        DataSchemaFromOther<Other>: Clone,
+    {
+        match (&self.uri_variables, &affordance.uri_variables) {
+            (Some(thing_variables), Some(affordance_variables)) => {
+                let mut uri_variables = thing_variables.clone();
+                uri_variables.extend(
+                    affordance_variables
+                        .iter()
+                        .map(|(name, data_schema)| (name.clone(), data_schema.clone())),
+                );
+                Cow::Owned(uri_variables)
+            }
+            (Some(uri_variables), None) | (None, Some(uri_variables)) => {
+                Cow::Borrowed(uri_variables)
+            }
+            (None, None) => Cow::Owned(Map::new()),
         }
     }
-}
 
-impl<Other> PartialEq for ActionAffordance<Other>
-where
-    Other: ExtendableThing,
-    InteractionAffordance<Other>: PartialEq,
-    DataSchemaFromOther<Other>: PartialEq,
-    Other::ActionAffordance: PartialEq,
-{
-    fn eq(&self, other: &Self) -> bool {
-        self.interaction == other.interaction
-            && self.input == other.input
-            && self.output == other.output
-            && self.safe == other.safe
-            && self.idempotent == other.idempotent
-            && self.synchronous == other.synchronous
-            && self.other == other.other
+    /// Returns the data schema of the URI variable `name` in scope for a form.
+    ///
+    /// The forms of an affordance use the variables returned by
+    /// [`Thing::resolve_uri_variables`], the Thing-level forms use the variables of the Thing.
+    /// The Thing-level forms with an operation on multiple properties, like
+    /// `readmultipleproperties`, also imply a variable for each property, whose data schema is
+    /// the one of the property. A variable declared by the Thing takes precedence over an implied
+    /// one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     builder::{affordance::BuildableInteractionAffordance, data_schema::SpecializableDataSchema},
+    /// #     thing::{DataSchemaSubtype, FormOperation, Thing},
+    /// # };
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .property("on", |b| {
+    ///         b.finish_extend_data_schema()
+    ///             .form(|b| b.href("/properties/on"))
+    ///             .bool()
+    ///     })
+    ///     .form(|b| {
+    ///         b.href("/properties{?on}")
+    ///             .op(FormOperation::WriteMultipleProperties)
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let form = &thing.forms.as_ref().unwrap()[0];
+    /// let on = thing.resolve_uri_variable(None, form, "on").unwrap();
+    /// assert_eq!(on.subtype, Some(DataSchemaSubtype::Boolean));
+    /// ```
+    pub fn resolve_uri_variable<'a>(
+        &'a self,
+        affordance: Option<&'a InteractionAffordance<Other>>,
+        form: &FormFromOther<Other>,
+        name: &str,
+    ) -> Option<&'a DataSchemaFromOther<Other>> {
+        let thing_variable = || {
+            self.uri_variables
+                .as_ref()
+                .and_then(|uri_variables| uri_variables.get(name))
+        };
+
+        match affordance {
+            Some(affordance) => affordance
+                .uri_variables
+                .as_ref()
+                .and_then(|uri_variables| uri_variables.get(name))
+                .or_else(thing_variable),
+            None => thing_variable().or_else(|| {
+                let is_bulk_property_op = |op: &FormOperation| {
+                    matches!(
+                        op,
+                        FormOperation::ReadAllProperties
+                            | FormOperation::WriteAllProperties
+                            | FormOperation::ReadMultipleProperties
+                            | FormOperation::WriteMultipleProperties
+                            | FormOperation::ObserveAllProperties
+                            | FormOperation::UnobserveAllProperties
+                    )
+                };
+                match &form.op {
+                    DefaultedFormOperations::Custom(ops) if ops.iter().any(is_bulk_property_op) => {
+                        self.properties
+                            .as_ref()
+                            .and_then(|properties| properties.get(name))
+                            .map(|property| &property.data_schema)
+                    }
+                    _ => None,
+                }
+            }),
+        }
     }
-}
 
-/// An affordance that describes an event source.
-#[skip_serializing_none]
-#[derive(Deserialize, Serialize)]
-pub struct EventAffordance<Other: ExtendableThing> {
-    /// The interaction affordance.
-    #[serde(flatten)]
-    pub interaction: InteractionAffordance<Other>,
+    /// Checks that every variable of the `href` templates is declared in the `uriVariables` in
+    /// scope for the form, as resolved by [`Thing::resolve_uri_variable`].
+    ///
+    /// It fails with [`AffordanceError::InvalidHrefTemplate`] if a template cannot be parsed.
+    ///
+    /// [`AffordanceError::InvalidHrefTemplate`]: crate::builder::AffordanceError::InvalidHrefTemplate
+    pub fn check_href_templates(&self) -> Result<(), Error> {
+        let check_forms = |affordance: Option<&InteractionAffordance<Other>>,
+                           forms: &[FormFromOther<Other>]| {
+            forms.iter().try_for_each(|form| {
+                check_href_template(&form.href, |name| {
+                    self.resolve_uri_variable(affordance, form, name).is_some()
+                })
+            })
+        };
 
-    /// Data that needs to be passed upon subscription.
-    pub subscription: Option<DataSchemaFromOther<Other>>,
+        check_forms(None, self.forms.as_deref().unwrap_or_default())?;
+
+        let mut interactions = self
+            .properties
+            .iter()
+            .flat_map(|properties| properties.values().map(|property| &property.interaction))
+            .chain(
+                self.actions
+                    .iter()
+                    .flat_map(|actions| actions.values().map(|action| &action.interaction)),
+            )
+            .chain(
+                self.events
+                    .iter()
+                    .flat_map(|events| events.values().map(|event| &event.interaction)),
+            );
+        interactions.try_for_each(|interaction| check_forms(Some(interaction), &interaction.forms))
+    }
 
-    /// Data schema of the messages pushed by the `Thing`.
-    pub data: Option<DataSchemaFromOther<Other>>,
+    /// Checks the URI references of the Thing with the validation backend selected by the cargo
+    /// features.
+    ///
+    /// The URIs of the `@context`, `id`, `base` and the `href` of the links and of the forms are
+    /// checked. The expressions of the `href` templates are not part of the check.
+    pub(crate) fn check_uris(&self) -> Result<(), Error> {
+        fn check(uri: &str, is_valid: fn(&str) -> bool) -> Result<(), Error> {
+            if is_valid(uri) {
+                Ok(())
+            } else {
+                Err(DocumentError::InvalidUri(uri.to_string()).into())
+            }
+        }
 
-    /// Data schema of the responsed messages sent by the consumer in a response to a data message.
-    pub data_response: Option<DataSchemaFromOther<Other>>,
+        fn check_forms<F, ER>(forms: &[Form<F, ER>]) -> Result<(), Error> {
+            forms.iter().enumerate().try_for_each(|(index, form)| {
+                check(&form.href, uri::is_valid_template_reference)
+                    .map_err(|error| error.at("href").at(index))
+            })
+        }
 
-    /// Data that needs to be passed to cancel a subscription.
-    pub cancellation: Option<DataSchemaFromOther<Other>>,
+        let check_context_entry = |entry: &Value| match entry {
+            Value::String(uri) => check(uri, uri::is_valid_reference),
+            Value::Object(definitions) => definitions
+                .iter()
+                .filter(|(term, _)| !term.starts_with('@'))
+                .try_for_each(|(term, definition)| match definition {
+                    Value::String(uri) => {
+                        check(uri, uri::is_valid_reference).map_err(|error| error.at(term))
+                    }
+                    _ => Ok(()),
+                }),
+            _ => Ok(()),
+        };
+        match &self.context {
+            Value::Array(entries) => entries.iter().enumerate().try_for_each(|(index, entry)| {
+                check_context_entry(entry).map_err(|error| error.at(index))
+            }),
+            entry => check_context_entry(entry),
+        }
+        .map_err(|error| error.at("@context"))?;
 
-    /// Event affordance extension.
-    #[serde(flatten)]
-    pub other: Other::EventAffordance,
-}
+        for (name, uri) in [("id", &self.id), ("base", &self.base)] {
+            if let Some(uri) = uri {
+                check(uri, uri::is_valid_reference).map_err(|error| error.at(name))?;
+            }
+        }
+        for (index, link) in self.links.iter().flatten().enumerate() {
+            check(&link.href, uri::is_valid_reference)
+                .map_err(|error| error.at("href").at(index).at("links"))?;
+        }
+        check_forms(self.forms.as_deref().unwrap_or_default())
+            .map_err(|error| error.at("forms"))?;
+
+        let interactions = self
+            .properties
+            .iter()
+            .flatten()
+            .map(|(name, property)| ("properties", name, &property.interaction))
+            .chain(
+                self.actions
+                    .iter()
+                    .flatten()
+                    .map(|(name, action)| ("actions", name, &action.interaction)),
+            )
+            .chain(
+                self.events
+                    .iter()
+                    .flatten()
+                    .map(|(name, event)| ("events", name, &event.interaction)),
+            );
+        for (kind, name, interaction) in interactions {
+            check_forms(&interaction.forms).map_err(|error| error.at("forms").at(name).at(kind))?;
+        }
 
-impl<Other> fmt::Debug for EventAffordance<Other>
-where
-    Other: ExtendableThing,
-    InteractionAffordance<Other>: fmt::Debug,
-    DataSchemaFromOther<Other>: fmt::Debug,
-    Other::EventAffordance: fmt::Debug,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("EventAffordance")
-            .field("interaction", &self.interaction)
-            .field("subscription", &self.subscription)
-            .field("data", &self.data)
-            .field("data_response", &self.data_response)
-            .field("cancellation", &self.cancellation)
-            .field("other", &self.other)
-            .finish()
+        Ok(())
     }
-}
 
-impl<Other> Default for EventAffordance<Other>
-where
-    Other: ExtendableThing,
-    InteractionAffordance<Other>: Default,
-    DataSchemaFromOther<Other>: Default,
-    Other::EventAffordance: Default,
-{
-    fn default() -> Self {
-        Self {
-            interaction: Default::default(),
-            subscription: Default::default(),
-            data: Default::default(),
-            data_response: Default::default(),
-            cancellation: Default::default(),
-            other: Default::default(),
-        }
+    /// Calls `f` on every data schema of the Thing, stopping at the first error.
+    ///
+    /// The URI variables and the schema definitions of the Thing are visited first, followed by
+    /// the URI variables and the data schemas of the properties, the actions and the events. Each
+    /// data schema is followed by the nested ones, as in [`DataSchema::walk`].
+    pub(crate) fn try_walk_data_schemas<'a, E, F>(&'a self, f: &mut F) -> Result<(), E>
+    where
+        F: FnMut(&'a DataSchemaFromOther<Other>) -> Result<(), E>,
+    {
+        let interactions = self
+            .properties
+            .iter()
+            .flat_map(|properties| properties.values().map(|property| &property.interaction))
+            .chain(
+                self.actions
+                    .iter()
+                    .flat_map(|actions| actions.values().map(|action| &action.interaction)),
+            )
+            .chain(
+                self.events
+                    .iter()
+                    .flat_map(|events| events.values().map(|event| &event.interaction)),
+            );
+        let affordance_data_schemas = self
+            .properties
+            .iter()
+            .flat_map(|properties| properties.values().map(|property| &property.data_schema))
+            .chain(self.actions.iter().flat_map(|actions| {
+                actions
+                    .values()
+                    .flat_map(|action| [&action.input, &action.output].into_iter().flatten())
+            }))
+            .chain(self.events.iter().flat_map(|events| {
+                events.values().flat_map(|event| {
+                    [
+                        &event.subscription,
+                        &event.data,
+                        &event.data_response,
+                        &event.cancellation,
+                    ]
+                    .into_iter()
+                    .flatten()
+                })
+            }));
+
+        [&self.uri_variables, &self.schema_definitions]
+            .into_iter()
+            .chain(interactions.map(|interaction| &interaction.uri_variables))
+            .flatten()
+            .flat_map(|map| map.values())
+            .chain(affordance_data_schemas)
+            .try_for_each(|data_schema| {
+                data_schema.try_walk_with_path(&mut |_, data_schema| f(data_schema))
+            })
     }
-}
 
-impl<Other> PartialEq for EventAffordance<Other>
-where
-    Other: ExtendableThing,
-    InteractionAffordance<Other>: PartialEq,
-    DataSchemaFromOther<Other>: PartialEq,
-    Other::EventAffordance: PartialEq,
-{
-    fn eq(&self, other: &Self) -> bool {
-        self.interaction == other.interaction
-            && self.subscription == other.subscription
-            && self.data == other.data
-            && self.data_response == other.data_response
-            && self.cancellation == other.cancellation
-            && self.other == other.other
+    /// Adds the [`WOT_TD_PREFIX`] to the `@context` if any affordance or data schema has one of
+    /// its members and the prefix is not already defined.
+    pub(crate) fn declare_wot_td_prefix(&mut self) {
+        fn uses_prefix(deprecated: Option<bool>, deprecation_note: Option<&String>) -> bool {
+            deprecated.is_some() || deprecation_note.is_some()
+        }
+
+        let mut interactions = self
+            .properties
+            .iter()
+            .flat_map(|properties| properties.values().map(|property| &property.interaction))
+            .chain(
+                self.actions
+                    .iter()
+                    .flat_map(|actions| actions.values().map(|action| &action.interaction)),
+            )
+            .chain(
+                self.events
+                    .iter()
+                    .flat_map(|events| events.values().map(|event| &event.interaction)),
+            );
+        let used = interactions.any(|interaction| {
+            uses_prefix(
+                interaction.deprecated,
+                interaction.deprecation_note.as_ref(),
+            )
+        }) || self
+            .try_walk_data_schemas(&mut |data_schema| {
+                if uses_prefix(
+                    data_schema.deprecated,
+                    data_schema.deprecation_note.as_ref(),
+                ) {
+                    Err(())
+                } else {
+                    Ok(())
+                }
+            })
+            .is_err();
+        if !used {
+            return;
+        }
+
+        let declared = match &self.context {
+            Value::Array(contexts) => contexts
+                .iter()
+                .any(|context| context.get(WOT_TD_PREFIX).is_some()),
+            context => context.get(WOT_TD_PREFIX).is_some(),
+        };
+        if declared {
+            return;
+        }
+
+        let mut entry = serde_json::Map::new();
+        entry.insert(WOT_TD_PREFIX.to_string(), WOT_TD_NAMESPACE.into());
+        match &mut self.context {
+            Value::Array(contexts) => contexts.push(entry.into()),
+            context => *context = Value::Array(vec![core::mem::take(context), entry.into()]),
+        }
     }
-}
 
-/// Metadata of a `Thing` that provides version information about the _Thing Description_ document.
-#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
-pub struct VersionInfo {
-    /// The version indicator of this _Thing Description_ instance.
-    pub instance: String,
+    /// Returns all the forms of the Thing, along with the affordances they belong to.
+    ///
+    /// The Thing-level forms come first, followed by the forms of the properties, the actions and
+    /// the events. The order of the affordances is unspecified.
+    pub fn forms(&self) -> impl Iterator<Item = FormRef<'_, Other>> {
+        fn affordance_forms<'a, Other: ExtendableThing>(
+            ty: AffordanceType,
+            name: &'a str,
+            interaction: &'a InteractionAffordance<Other>,
+        ) -> impl Iterator<Item = FormRef<'a, Other>> {
+            interaction.forms.iter().map(move |form| FormRef {
+                affordance: Some((ty, name)),
+                form,
+            })
+        }
 
-    /// The version indicator of the underlying _Thing Model_.
-    pub model: Option<String>,
-}
+        let thing_forms = self.forms.iter().flatten().map(|form| FormRef {
+            affordance: None,
+            form,
+        });
+        let properties = self
+            .properties
+            .iter()
+            .flatten()
+            .flat_map(|(name, property)| {
+                affordance_forms(AffordanceType::Property, name, &property.interaction)
+            });
+        let actions = self.actions.iter().flatten().flat_map(|(name, action)| {
+            affordance_forms(AffordanceType::Action, name, &action.interaction)
+        });
+        let events = self.events.iter().flatten().flat_map(|(name, event)| {
+            affordance_forms(AffordanceType::Event, name, &event.interaction)
+        });
 
-impl<S> From<S> for VersionInfo
-where
-    S: Into<String>,
-{
-    fn from(instance: S) -> Self {
-        let instance = instance.into();
-        Self {
-            instance,
-            model: None,
+        thing_forms.chain(properties).chain(actions).chain(events)
+    }
+
+    /// Resolves the `href` of a form against the `base` of the Thing.
+    ///
+    /// The resolution follows [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-5.2), and
+    /// the `href` is returned unchanged when the Thing has no `base`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::thing::{Form, Thing};
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .base("http://example.com/lamp/")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let form = Form {
+    ///     href: "status".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     thing.resolve_form_href(&form),
+    ///     "http://example.com/lamp/status"
+    /// );
+    /// ```
+    pub fn resolve_form_href<'a>(&self, form: &'a FormFromOther<Other>) -> Cow<'a, str> {
+        match &self.base {
+            Some(base) => Cow::Owned(uri::resolve(base, &form.href)),
+            None => Cow::Borrowed(&form.href),
         }
     }
-}
 
-/// Metadata that describes the data format used.
-#[serde_as]
-#[skip_serializing_none]
-#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct DataSchema<DS, AS, OS> {
-    /// JSON-LD keyword to label the object with semantic tags or types.
-    #[serde(rename = "@type", default)]
-    #[serde_as(as = "Option<OneOrMany<_>>")]
-    pub attype: Option<Vec<String>>,
+    /// Resolves the relative `href` of all the forms against `base`, then removes `base`.
+    ///
+    /// It is useful for the consumers that cannot resolve relative references. The `href` of the
+    /// links and the URIs of the security schemes, like the `proxy` or the `token` endpoint of
+    /// OAuth 2.0, are resolved as well, because `base` applies to them too. The URIs that are
+    /// already absolute are left unchanged, and the Thing is returned unchanged when it has no
+    /// `base`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     builder::{affordance::BuildableInteractionAffordance, data_schema::SpecializableDataSchema},
+    /// #     thing::Thing,
+    /// # };
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .base("http://example.com/lamp/")
+    ///     .property("on", |b| {
+    ///         b.finish_extend_data_schema()
+    ///             .bool()
+    ///             .form(|b| b.href("on"))
+    ///             .form(|b| b.href("coap://example.com/on"))
+    ///     })
+    ///     .build()
+    ///     .unwrap()
+    ///     .apply_base();
+    ///
+    /// assert_eq!(thing.base, None);
+    /// let forms = &thing.properties.unwrap()["on"].interaction.forms;
+    /// assert_eq!(forms[0].href, "http://example.com/lamp/on");
+    /// assert_eq!(forms[1].href, "coap://example.com/on");
+    /// ```
+    pub fn apply_base(mut self) -> Self {
+        let Some(base) = self.base.take() else {
+            return self;
+        };
 
-    /// Human-readable title to be displayed
-    pub title: Option<String>,
+        let resolve = |href: &mut String| {
+            if uri::Components::parse(href).scheme.is_none() {
+                *href = uri::resolve(&base, href);
+            }
+        };
 
-    /// Multi-language translations of the title
-    pub titles: Option<MultiLanguage>,
+        let interactions = self
+            .properties
+            .iter_mut()
+            .flat_map(|properties| {
+                properties
+                    .values_mut()
+                    .map(|property| &mut property.interaction)
+            })
+            .chain(
+                self.actions
+                    .iter_mut()
+                    .flat_map(|actions| actions.values_mut().map(|action| &mut action.interaction)),
+            )
+            .chain(
+                self.events
+                    .iter_mut()
+                    .flat_map(|events| events.values_mut().map(|event| &mut event.interaction)),
+            );
+        self.forms
+            .iter_mut()
+            .flatten()
+            .chain(interactions.flat_map(|interaction| &mut interaction.forms))
+            .for_each(|form| resolve(&mut form.href));
+        self.links
+            .iter_mut()
+            .flatten()
+            .for_each(|link| resolve(&mut link.href));
+        for scheme in self.security_definitions.values_mut() {
+            let subtype_uris = match &mut scheme.subtype {
+                SecuritySchemeSubtype::Known(KnownSecuritySchemeSubtype::Bearer(bearer)) => {
+                    vec![&mut bearer.authorization]
+                }
+                SecuritySchemeSubtype::Known(KnownSecuritySchemeSubtype::OAuth2(oauth2)) => vec![
+                    &mut oauth2.authorization,
+                    &mut oauth2.token,
+                    &mut oauth2.refresh,
+                ],
+                _ => Vec::new(),
+            };
+            [&mut scheme.proxy]
+                .into_iter()
+                .chain(subtype_uris)
+                .flatten()
+                .for_each(resolve);
+        }
 
-    /// Human-readable additional information
-    pub description: Option<String>,
+        self
+    }
 
-    /// Multi-language translations of the description
-    pub descriptions: Option<MultiLanguage>,
+    /// Groups the forms of the Thing by the `scheme://authority` of their resolved `href`.
+    ///
+    /// The keys are lowercased, and the forms whose `href` cannot be resolved to a URI with an
+    /// authority (like a relative `href` without `base` or a URN) are not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     builder::{affordance::BuildableInteractionAffordance, data_schema::SpecializableDataSchema},
+    /// #     thing::Thing,
+    /// # };
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .base("http://example.com/")
+    ///     .property("on", |b| {
+    ///         b.finish_extend_data_schema()
+    ///             .bool()
+    ///             .form(|b| b.href("/on"))
+    ///             .form(|b| b.href("coap://example.com:5683/on"))
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let endpoints = thing.endpoints();
+    /// assert_eq!(endpoints.len(), 2);
+    /// assert_eq!(endpoints["http://example.com"].len(), 1);
+    /// assert_eq!(endpoints["coap://example.com:5683"].len(), 1);
+    /// ```
+    pub fn endpoints(&self) -> Map<String, Vec<FormRef<'_, Other>>> {
+        let mut endpoints: Map<_, Vec<_>> = Map::new();
+        for form_ref in self.forms() {
+            let href = self.resolve_form_href(form_ref.form);
+            if let Some(origin) = uri::Components::parse(&href).origin() {
+                endpoints.entry(origin).or_default().push(form_ref);
+            }
+        }
+        endpoints
+    }
 
-    /// A constant value for the data schema.
-    #[serde(rename = "const")]
-    pub constant: Option<Value>,
+    /// Returns the lowercased URI schemes of the forms of the Thing, resolved against `base`.
+    ///
+    /// The forms whose `href` does not have a scheme once resolved are ignored, see
+    /// [`Thing::forms_without_scheme`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     builder::{affordance::BuildableInteractionAffordance, data_schema::SpecializableDataSchema},
+    /// #     thing::Thing,
+    /// # };
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .base("https://example.com/")
+    ///     .property("on", |b| {
+    ///         b.finish_extend_data_schema()
+    ///             .bool()
+    ///             .form(|b| b.href("/on"))
+    ///             .form(|b| b.href("mqtt://broker.example.com/on"))
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     thing.protocol_schemes().into_iter().collect::<Vec<_>>(),
+    ///     ["https", "mqtt"],
+    /// );
+    /// ```
+    pub fn protocol_schemes(&self) -> BTreeSet<String> {
+        self.forms_by_scheme().into_keys().collect()
+    }
 
-    /// A default value for the data schema.
-    pub default: Option<Value>,
+    /// Groups the forms of the Thing by the lowercased URI scheme of their `href`, resolved
+    /// against `base`.
+    ///
+    /// The forms whose `href` does not have a scheme once resolved are not included, they are
+    /// returned by [`Thing::forms_without_scheme`].
+    pub fn forms_by_scheme(&self) -> BTreeMap<String, Vec<FormRef<'_, Other>>> {
+        let mut forms: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for form_ref in self.forms() {
+            if let Some(scheme) = self.form_scheme(form_ref.form) {
+                forms.entry(scheme).or_default().push(form_ref);
+            }
+        }
+        forms
+    }
 
-    /// Unit information used for the data schema (e.g. Km, g, m/s^2)
-    pub unit: Option<String>,
+    /// Returns the forms whose `href` does not have a URI scheme once resolved against `base`.
+    ///
+    /// They are either relative references in a Thing without `base` or malformed URIs (like
+    /// `coap tcp://example.com`), and their protocol cannot be determined.
+    pub fn forms_without_scheme(&self) -> Vec<FormRef<'_, Other>> {
+        self.forms()
+            .filter(|form_ref| self.form_scheme(form_ref.form).is_none())
+            .collect()
+    }
 
-    /// Used to ensure that the data is valid against one of the specified schemas.
-    pub one_of: Option<Vec<Self>>,
+    pub(crate) fn form_scheme(&self, form: &FormFromOther<Other>) -> Option<String> {
+        // The first segment of a relative reference cannot contain a colon, therefore an `href`
+        // like `coap tcp://example.com` is malformed and it must not be resolved against `base`.
+        let first_segment = form.href.split(['/', '?', '#']).next().unwrap_or_default();
+        if first_segment.contains(':') && uri::Components::parse(&form.href).scheme.is_none() {
+            return None;
+        }
 
-    /// A restricted set of values.
-    #[serde(rename = "enum")]
-    pub enumeration: Option<Vec<Value>>,
+        let href = self.resolve_form_href(form);
+        uri::Components::parse(&href)
+            .scheme
+            .map(str::to_ascii_lowercase)
+    }
 
-    /// Indicates if the property interaction value is read only.
-    #[serde(default)]
-    pub read_only: bool,
+    /// Selects the form of an affordance to perform an operation, negotiating the content type.
+    ///
+    /// The candidates are the forms of the affordance named `affordance` supporting `op`, and the
+    /// forms without `op` support the [default operations](default_ops_for) of the affordance.
+    /// The operations that do not target a single affordance, like
+    /// [`ReadAllProperties`](FormOperation::ReadAllProperties), select one of the forms of the
+    /// Thing, and `affordance` is ignored.
+    ///
+    /// `accept` lists the content types accepted by the consumer, in order of preference. It can
+    /// contain media ranges like `application/*` and `*/*`, and the parameters are ignored. The
+    /// content type of a form falls back to the default of the Thing, see
+    /// [`Form::content_type_or`]. The first form matching the most preferred content type is
+    /// returned, and an empty `accept` matches any form.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     builder::{affordance::BuildableInteractionAffordance, data_schema::SpecializableDataSchema},
+    /// #     thing::{FormOperation, Thing},
+    /// # };
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .property("temperature", |b| {
+    ///         b.finish_extend_data_schema()
+    ///             .number()
+    ///             .form(|b| b.href("/temperature"))
+    ///             .form(|b| b.href("/temperature.cbor").content_type("application/cbor"))
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let form = thing
+    ///     .select_form("temperature", FormOperation::ReadProperty, &["application/cbor"])
+    ///     .unwrap();
+    /// assert_eq!(form.form.href, "/temperature.cbor");
+    /// ```
+    pub fn select_form(
+        &self,
+        affordance: &str,
+        op: FormOperation,
+        accept: &[&str],
+    ) -> Option<FormRef<'_, Other>> {
+        let affordance_type = match op {
+            FormOperation::ReadProperty
+            | FormOperation::WriteProperty
+            | FormOperation::ObserveProperty
+            | FormOperation::UnobserveProperty => Some(AffordanceType::Property),
+            FormOperation::InvokeAction
+            | FormOperation::QueryAction
+            | FormOperation::CancelAction => Some(AffordanceType::Action),
+            FormOperation::SubscribeEvent | FormOperation::UnsubscribeEvent => {
+                Some(AffordanceType::Event)
+            }
+            _ => None,
+        };
 
-    /// Indicates if the property interaction value is write only.
-    #[serde(default)]
-    pub write_only: bool,
+        let (name, forms, default_ops) = match affordance_type {
+            Some(ty @ AffordanceType::Property) => {
+                let (name, property) = self.properties.as_ref()?.get_key_value(affordance)?;
+                let default_ops = default_ops_for(
+                    ty,
+                    property.data_schema.read_only,
+                    property.data_schema.write_only,
+                );
+                (Some(name), &property.interaction.forms, default_ops)
+            }
+            Some(ty @ AffordanceType::Action) => {
+                let (name, action) = self.actions.as_ref()?.get_key_value(affordance)?;
+                let default_ops = default_ops_for(ty, false, false);
+                (Some(name), &action.interaction.forms, default_ops)
+            }
+            Some(ty @ AffordanceType::Event) => {
+                let (name, event) = self.events.as_ref()?.get_key_value(affordance)?;
+                let default_ops = default_ops_for(ty, false, false);
+                (Some(name), &event.interaction.forms, default_ops)
+            }
+            None => (None, self.forms.as_ref()?, Vec::new()),
+        };
 
-    /// Allows validation based on a format pattern such as "date-time", "email", "uri".
-    pub format: Option<String>,
+        let candidates = forms
+            .iter()
+            .filter(|form| match &form.op {
+                DefaultedFormOperations::Default => default_ops.contains(&op),
+                DefaultedFormOperations::Custom(ops) => ops.contains(&op),
+            })
+            .collect::<Vec<_>>();
+        let form = if accept.is_empty() {
+            candidates.first().copied()
+        } else {
+            accept.iter().find_map(|range| {
+                candidates
+                    .iter()
+                    .copied()
+                    .find(|form| media_range_matches(range, form.content_type_or(self)))
+            })
+        }?;
 
-    /// The JSON-based subtype of the data schema.
-    #[serde(flatten)]
-    pub subtype: Option<DataSchemaSubtype<DS, AS, OS>>,
+        Some(FormRef {
+            affordance: affordance_type.zip(name.map(String::as_str)),
+            form,
+        })
+    }
+}
 
-    /// Data schema extension.
-    #[serde(flatten)]
-    pub other: DS,
+/// Checks whether a media type matches a media range, like `application/*`, ignoring the
+/// parameters.
+fn media_range_matches(range: &str, media_type: &str) -> bool {
+    fn essence(s: &str) -> &str {
+        s.split(';').next().unwrap_or_default().trim()
+    }
+
+    let range = essence(range);
+    let media_type = essence(media_type);
+    match range.split_once('/') {
+        Some(("*", "*")) => true,
+        Some((ty, "*")) => media_type
+            .split_once('/')
+            .is_some_and(|(media_ty, _)| media_ty.eq_ignore_ascii_case(ty)),
+        _ => range.eq_ignore_ascii_case(media_type),
+    }
 }
 
-pub(crate) type DataSchemaFromOther<Other> = DataSchema<
-    <Other as ExtendableThing>::DataSchema,
-    <Other as ExtendableThing>::ArraySchema,
-    <Other as ExtendableThing>::ObjectSchema,
->;
+/// Deserializes a Thing Description from untrusted bytes, enforcing the given limits.
+///
+/// The nesting depth and the length of the strings are checked before parsing anything, while
+/// the number of affordances, of object properties and of `oneOf` alternatives are checked after
+/// the document has been parsed. See the [`limits`] module for more details.
+///
+/// [`limits`]: crate::limits
+pub fn from_slice_with_limits<Other>(
+    bytes: &[u8],
+    limits: &DeserializeLimits,
+) -> Result<Thing<Other>, limits::Error>
+where
+    Other: ExtendableThing,
+    Thing<Other>: DeserializeOwned,
+{
+    let scan = scan(bytes);
+    check_limit(Limit::Depth, limits.max_depth, scan.depth)?;
+    check_limit(
+        Limit::StringLength,
+        limits.max_string_length,
+        scan.string_length,
+    )?;
 
-/// A JSON-based data schema subtype.
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
-#[serde(tag = "type", rename_all = "lowercase")]
-pub enum DataSchemaSubtype<DS, AS, OS> {
-    /// A JSON array metadata.
-    Array(ArraySchema<DS, AS, OS>),
+    let value: Value = serde_json::from_slice(bytes)?;
+    let affordances = ["properties", "actions", "events"]
+        .into_iter()
+        .filter_map(|key| value.get(key)?.as_object())
+        .map(serde_json::Map::len)
+        .sum();
+    check_limit(Limit::Affordances, limits.max_affordances, affordances)?;
 
-    /// A boolean.
-    Boolean,
+    let thing: Thing<Other> = serde_json::from_value(value)?;
 
-    /// A number metadata.
-    Number(NumberSchema),
+    thing.try_walk_data_schemas(&mut |data_schema| {
+        let properties = match &data_schema.subtype {
+            Some(DataSchemaSubtype::Object(object)) => {
+                object.properties.as_ref().map_or(0, Map::len)
+            }
+            _ => 0,
+        };
+        let one_of = data_schema.one_of.as_ref().map_or(0, Vec::len);
+        check_limit(
+            Limit::ObjectProperties,
+            limits.max_object_properties,
+            properties,
+        )
+        .and_then(|()| check_limit(Limit::OneOf, limits.max_one_of, one_of))
+    })?;
 
-    /// An integer metadata.
-    Integer(IntegerSchema),
+    Ok(thing)
+}
 
-    /// A JSON object metadata.
-    Object(ObjectSchema<DS, AS, OS>),
+fn check_names<'a>(
+    names: impl IntoIterator<Item = &'a String>,
+    kind: NameKind,
+) -> Result<(), Error> {
+    names
+        .into_iter()
+        .try_for_each(|name| check_name(name, kind))
+}
 
-    /// A string metadata.
-    String(StringSchema),
+fn check_form_names<F, ER>(forms: &[Form<F, ER>]) -> Result<(), Error> {
+    forms
+        .iter()
+        .filter_map(|form| form.security.as_ref())
+        .try_for_each(|security| check_names(security, NameKind::Security))
+}
 
-    /// A JSON null.
-    Null,
+fn check_interaction_names<Other: ExtendableThing>(
+    interaction: &InteractionAffordance<Other>,
+) -> Result<(), Error> {
+    if let Some(uri_variables) = &interaction.uri_variables {
+        check_data_schema_map(uri_variables, NameKind::UriVariable)?;
+    }
+    check_form_names(&interaction.forms)
 }
 
-#[derive(Clone, Debug, PartialEq)]
-pub(crate) enum UncheckedDataSchemaSubtype<DS, AS, OS> {
-    Array(UncheckedArraySchema<DS, AS, OS>),
-    Boolean,
-    Number(NumberSchema),
-    Integer(IntegerSchema),
-    Object(UncheckedObjectSchema<DS, AS, OS>),
-    String(StringSchema),
-    Null,
+fn check_data_schema_map<DS, AS, OS>(
+    map: &Map<String, DataSchema<DS, AS, OS>>,
+    kind: NameKind,
+) -> Result<(), Error> {
+    map.iter().try_for_each(|(name, data_schema)| {
+        check_name(name, kind)?;
+        check_data_schema(data_schema)
+    })
 }
 
-impl<DS, AS, OS> Default for DataSchemaSubtype<DS, AS, OS> {
-    fn default() -> Self {
-        Self::Null
+fn check_data_schema<DS, AS, OS>(data_schema: &DataSchema<DS, AS, OS>) -> Result<(), Error> {
+    data_schema.try_walk_with_path(&mut |path, data_schema| {
+        check_const_and_enum(
+            data_schema.constant.as_ref(),
+            data_schema.enumeration.as_deref(),
+            data_schema.subtype.as_ref(),
+            &json_pointer(path),
+        )?;
+        match &data_schema.subtype {
+            Some(DataSchemaSubtype::Object(object)) => {
+                check_names(
+                    object
+                        .properties
+                        .iter()
+                        .flat_map(|properties| properties.keys()),
+                    NameKind::ObjectProperty,
+                )?;
+                check_names(object.required.iter().flatten(), NameKind::Required)
+            }
+            _ => Ok(()),
+        }
+    })
+}
+
+fn check_content_encoding<DS, AS, OS>(data_schema: &DataSchema<DS, AS, OS>) -> Result<(), Error> {
+    match &data_schema.subtype {
+        Some(DataSchemaSubtype::String(string)) => match string.encoding_known() {
+            Some(ContentEncoding::Other(encoding)) => {
+                Err(DataSchemaError::UnknownContentEncoding(encoding).into())
+            }
+            _ => Ok(()),
+        },
+        _ => Ok(()),
     }
 }
 
-/// A JSON array metadata.
+/// Checks the content encodings of all the data schemas of a Thing, recursively.
+pub(crate) fn check_content_encodings<Other: ExtendableThing>(
+    thing: &Thing<Other>,
+) -> Result<(), Error> {
+    thing.try_walk_data_schemas(&mut check_content_encoding)
+}
+
+/// A summary of a [`Thing`], used to register Thing Descriptions in directories.
+///
+/// It contains the identification members, the security requirements and the links of a Thing,
+/// but not the interaction affordances. The Thing-level extension data is kept.
 #[serde_as]
-#[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
-#[serde(bound(
-    deserialize = "DS: Deserialize<'de>, AS: Deserialize<'de>, OS: Deserialize<'de>",
-    serialize = "DS: Serialize, AS: Serialize, OS: Serialize"
-))]
-pub struct ArraySchema<DS, AS, OS> {
-    /// The characteristics of the JSON array.
-    ///
-    /// An item has a different semantic than a `Vec` of one item:
-    ///
-    /// - a _single_ item `T` represents an array of element where every element must follow the
-    /// schema defined by `T`;
-    /// - a `Vec` of one element `T` represent a tuple of one single element, which must follow the
-    /// schema defined by `T`.
-    ///
-    /// In general, using a `Vec` of data schemas expresses a tuple of elements with a 1:1
-    /// correspondence.
+#[derive(Debug, Serialize)]
+pub struct ThingSummary<'a, Other> {
+    /// A [JSON-LD @context](https://www.w3.org/TR/json-ld11/#the-context)
+    #[serde(rename = "@context")]
+    pub context: &'a Value,
+
+    /// A unique identifier
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub items: Option<BoxedElemOrVec<DataSchema<DS, AS, OS>>>,
+    pub id: Option<&'a str>,
 
-    /// The minimum number of items that have to be in the JSON array.
-    pub min_items: Option<u32>,
+    /// JSON-LD semantic keywords
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<&StringOrArray>")]
+    pub attype: Option<&'a Vec<AtType>>,
 
-    /// The maximum number of items that have to be in the JSON array.
-    pub max_items: Option<u32>,
+    /// Human-readable title to be displayed
+    pub title: &'a str,
 
-    /// Array schema extension.
+    /// Base URI to be used to resolve all the other relative URIs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<&'a str>,
+
+    /// Thing-wide Security constraints
+    #[serde_as(as = "&StringOrArray")]
+    pub security: &'a Vec<String>,
+
+    /// Arbitrary resources that relate to the Thing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub links: Option<&'a [Link]>,
+
+    /// Thing extension
     #[serde(flatten)]
-    pub other: AS,
+    pub other: &'a Other,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum BoxedElemOrVec<T> {
-    Elem(Box<T>),
-    Vec(Vec<T>),
+impl<'a, Other> From<&'a Thing<Other>> for ThingSummary<'a, Other>
+where
+    Other: ExtendableThing,
+{
+    fn from(thing: &'a Thing<Other>) -> Self {
+        Self {
+            context: &thing.context,
+            id: thing.id.as_deref(),
+            attype: thing.attype.as_ref(),
+            title: &thing.title,
+            base: thing.base.as_deref(),
+            security: &thing.security,
+            links: thing.links.as_deref(),
+            other: &thing.other,
+        }
+    }
 }
 
-#[derive(Clone, Debug, Default, PartialEq)]
-pub(crate) struct UncheckedArraySchema<DS, AS, OS> {
-    pub(crate) items: Option<BoxedElemOrVec<UncheckedDataSchema<DS, AS, OS>>>,
-    pub(crate) min_items: Option<u32>,
-    pub(crate) max_items: Option<u32>,
-    pub(crate) other: AS,
+/// A reference to a form of a [`Thing`].
+///
+/// See [`Thing::forms`].
+pub struct FormRef<'a, Other: ExtendableThing = Nil> {
+    /// The type and the name of the affordance of the form, `None` for the Thing-level forms.
+    pub affordance: Option<(AffordanceType, &'a str)>,
+
+    /// The form.
+    pub form: &'a FormFromOther<Other>,
 }
 
-impl<DS, AS, OS> Default for ArraySchema<DS, AS, OS>
+impl<Other> fmt::Debug for FormRef<'_, Other>
 where
-    AS: Default,
+    Other: ExtendableThing,
+    FormFromOther<Other>: fmt::Debug,
 {
-    fn default() -> Self {
-        Self {
-            items: Default::default(),
-            min_items: Default::default(),
-            max_items: Default::default(),
-            other: Default::default(),
-        }
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FormRef")
+            .field("affordance", &self.affordance)
+            .field("form", &self.form)
+            .finish()
     }
 }
 
-/// A helper enum to represent an inclusive or exclusive maximum value.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
-pub enum Maximum<T> {
-    /// An inclusive maximum value.
-    #[serde(rename = "maximum")]
-    Inclusive(T),
+impl<Other: ExtendableThing> Clone for FormRef<'_, Other> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
 
-    /// An exclusive maximum value.
-    #[serde(rename = "exclusiveMaximum")]
-    Exclusive(T),
+impl<Other: ExtendableThing> Copy for FormRef<'_, Other> {}
+
+/// The optional checks of [`Thing::validate_with`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ValidateOptions {
+    /// Rejects the `contentEncoding` values outside the registered set (see [`ContentEncoding`])
+    /// with [`DataSchemaError::UnknownContentEncoding`].
+    ///
+    /// Any encoding is accepted by default, in order to support the vendor ones. It is the same
+    /// check of [`ThingBuilder::strict_content_encoding`].
+    pub strict_content_encoding: bool,
 }
 
-/// A helper enum to represent an inclusive or exclusive minimum value.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
-pub enum Minimum<T> {
-    /// An inclusive minimum value.
-    #[serde(rename = "minimum")]
-    Inclusive(T),
+/// Options to customize the serialization of a [`Thing`].
+///
+/// See [`Thing::to_value_with`] and [`Thing::write_json_with`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct SerializeOptions {
+    /// Serializes the integral bounds and `multipleOf` of number schemas as JSON integers.
+    ///
+    /// For instance, `"maximum": 5.0` becomes `"maximum": 5`. Values that do not fit an `i64` are
+    /// left unchanged.
+    pub compact_floats: bool,
 
-    /// An exclusive minimum value.
-    #[serde(rename = "exclusiveMinimum")]
-    Exclusive(T),
+    /// Omits the `readOnly`, `writeOnly`, `safe` and `idempotent` members when they are `false`.
+    ///
+    /// They are always serialized by default, even if `false` is their default value. Only the
+    /// `readOnly` and `writeOnly` members of the data schemas and the `safe` and `idempotent`
+    /// members of the actions are omitted, the values of `const`, `default` and `enum` are left
+    /// as they are.
+    pub omit_default_flags: bool,
+
+    /// Omits the `contentType` of the forms when it is equal to the default content type.
+    ///
+    /// The default is [`Thing::default_content_type`] when it is set, otherwise it is
+    /// `application/json`, the default of the specification. The forms of the Thing and of its
+    /// affordances are considered, the content types of the additional responses are left as
+    /// they are.
+    pub omit_default_content_type: bool,
 }
 
-impl<T> PartialOrd for Minimum<T>
-where
-    T: PartialOrd,
-{
-    #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        match (self, other) {
-            (Minimum::Inclusive(a), Minimum::Inclusive(b))
-            | (Minimum::Exclusive(a), Minimum::Exclusive(b)) => a.partial_cmp(b),
-            (Minimum::Inclusive(a), Minimum::Exclusive(b)) => {
-                a.partial_cmp(b).and_then(|ord| match ord {
-                    Ordering::Less | Ordering::Equal => Some(Ordering::Less),
-                    Ordering::Greater => None,
-                })
-            }
-            (Minimum::Exclusive(a), Minimum::Inclusive(b)) => {
-                a.partial_cmp(b).and_then(|ord| match ord {
-                    Ordering::Less => None,
-                    Ordering::Equal | Ordering::Greater => Some(Ordering::Greater),
-                })
-            }
-        }
+fn omit_default_flags(value: &mut Value) {
+    fn omit(map: &mut serde_json::Map<String, Value>, flags: [&str; 2]) {
+        map.retain(|key, value| !(flags.contains(&key.as_str()) && *value == Value::Bool(false)));
     }
-}
 
-impl<T> PartialOrd for Maximum<T>
-where
-    T: PartialOrd,
-{
-    #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match (self, other) {
-            (Maximum::Inclusive(a), Maximum::Inclusive(b))
-            | (Maximum::Exclusive(a), Maximum::Exclusive(b)) => a.partial_cmp(b),
+    walk_data_schema_values(value, &mut |_, data_schema| {
+        omit(data_schema, ["readOnly", "writeOnly"])
+    });
+    if let Some(Value::Object(actions)) = value.get_mut("actions") {
+        actions
+            .values_mut()
+            .filter_map(Value::as_object_mut)
+            .for_each(|action| omit(action, ["safe", "idempotent"]));
+    }
+}
 
-            (Maximum::Inclusive(a), Maximum::Exclusive(b)) => {
-                a.partial_cmp(b).and_then(|ord| match ord {
-                    Ordering::Less => None,
-                    Ordering::Equal | Ordering::Greater => Some(Ordering::Greater),
+fn omit_default_content_type(value: &mut Value, content_type: &str) {
+    fn omit(forms: Option<&mut Value>, content_type: &str) {
+        if let Some(Value::Array(forms)) = forms {
+            forms
+                .iter_mut()
+                .filter_map(Value::as_object_mut)
+                .filter(|form| {
+                    form.get("contentType").and_then(Value::as_str) == Some(content_type)
                 })
-            }
+                .for_each(|form| {
+                    form.remove("contentType");
+                });
+        }
+    }
 
-            (Maximum::Exclusive(a), Maximum::Inclusive(b)) => {
-                a.partial_cmp(b).and_then(|ord| match ord {
-                    Ordering::Less | Ordering::Equal => Some(Ordering::Less),
-                    Ordering::Greater => None,
-                })
-            }
+    omit(value.get_mut("forms"), content_type);
+    for affordances in ["properties", "actions", "events"] {
+        if let Some(Value::Object(affordances)) = value.get_mut(affordances) {
+            affordances
+                .values_mut()
+                .for_each(|affordance| omit(affordance.get_mut("forms"), content_type));
         }
     }
 }
 
-impl<T> PartialEq<Maximum<T>> for Minimum<T>
-where
-    T: PartialEq,
-{
-    #[inline]
-    fn eq(&self, other: &Maximum<T>) -> bool {
-        match (self, other) {
-            (Minimum::Inclusive(a), Maximum::Inclusive(b))
-            | (Minimum::Exclusive(a), Maximum::Exclusive(b)) => a == b,
-            _ => false,
-        }
+/// A writer counting the bytes written into the inner writer.
+#[cfg(feature = "std")]
+struct CountingWriter<'a, W> {
+    inner: &'a mut W,
+    count: usize,
+}
+
+#[cfg(feature = "std")]
+impl<'a, W> CountingWriter<'a, W> {
+    fn new(inner: &'a mut W) -> Self {
+        Self { inner, count: 0 }
     }
 }
 
-impl<T> PartialEq<Minimum<T>> for Maximum<T>
-where
-    T: PartialEq,
-{
-    #[inline]
-    fn eq(&self, other: &Minimum<T>) -> bool {
-        other == self
+#[cfg(feature = "std")]
+impl<W: std::io::Write> std::io::Write for CountingWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.count += written;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
     }
 }
 
-impl<T> PartialOrd<Maximum<T>> for Minimum<T>
-where
-    T: PartialOrd,
-{
-    #[inline]
-    fn partial_cmp(&self, other: &Maximum<T>) -> Option<cmp::Ordering> {
-        match (self, other) {
-            (Minimum::Inclusive(a), Maximum::Inclusive(b))
-            | (Minimum::Exclusive(a), Maximum::Exclusive(b)) => a.partial_cmp(b),
+fn compact_number_bounds(value: &mut Value) {
+    walk_data_schema_values(value, &mut |_, data_schema| {
+        if data_schema.get("type").and_then(Value::as_str) != Some("number") {
+            return;
+        }
 
-            (Minimum::Exclusive(a), Maximum::Inclusive(b))
-            | (Minimum::Inclusive(a), Maximum::Exclusive(b)) => {
-                a.partial_cmp(b).and_then(|ord| match ord {
-                    Ordering::Less => None,
-                    Ordering::Equal | Ordering::Greater => Some(Ordering::Greater),
-                })
+        for key in [
+            "minimum",
+            "maximum",
+            "exclusiveMinimum",
+            "exclusiveMaximum",
+            "multipleOf",
+        ] {
+            if let Some(bound) = data_schema.get_mut(key) {
+                if let Some(integer) = bound.as_f64().and_then(f64_to_i64_exact) {
+                    *bound = integer.into();
+                }
             }
         }
-    }
+    });
 }
 
-impl<T> PartialOrd<Minimum<T>> for Maximum<T>
+/// Calls `f` on every data schema of a Thing Description in JSON form, along with its JSON
+/// pointer.
+///
+/// The data schemas are the URI variables, the schema definitions, the properties, the inputs and
+/// outputs of the actions and the data schemas of the events. Each one is followed by its nested
+/// data schemas, visited in the same order of [`DataSchema::walk`]. Members that are not JSON
+/// objects are skipped.
+pub(crate) fn walk_data_schema_values<F>(thing: &mut Value, f: &mut F)
 where
-    T: PartialOrd,
+    F: FnMut(&str, &mut serde_json::Map<String, Value>),
 {
-    #[inline]
-    fn partial_cmp(&self, other: &Minimum<T>) -> Option<Ordering> {
-        other.partial_cmp(self).map(Ordering::reverse)
-    }
-}
+    fn walk<F>(value: &mut Value, pointer: &mut String, f: &mut F)
+    where
+        F: FnMut(&str, &mut serde_json::Map<String, Value>),
+    {
+        let Value::Object(data_schema) = value else {
+            return;
+        };
 
-macro_rules! impl_minmax_float {
-    (@ $ty:ident $float_type:ty) => {
-        impl $ty<$float_type> {
-            /// Returns `true` if value is `NaN`.
-            pub fn is_nan(&self) -> bool {
-                match self {
-                    Self::Inclusive(x) => x.is_nan(),
-                    Self::Exclusive(x) => x.is_nan(),
+        f(pointer, data_schema);
+        if let Some(Value::Array(one_of)) = data_schema.get_mut("oneOf") {
+            for (index, one_of) in one_of.iter_mut().enumerate() {
+                descend(one_of, &["oneOf", &index.to_string()], pointer, f);
+            }
+        }
+
+        match data_schema.get("type").and_then(Value::as_str) {
+            Some("array") => match data_schema.get_mut("items") {
+                Some(Value::Array(items)) => {
+                    for (index, item) in items.iter_mut().enumerate() {
+                        descend(item, &["items", &index.to_string()], pointer, f);
+                    }
+                }
+                Some(item) => descend(item, &["items"], pointer, f),
+                None => {}
+            },
+            Some("object") => {
+                if let Some(Value::Object(properties)) = data_schema.get_mut("properties") {
+                    for (name, property) in properties.iter_mut() {
+                        descend(property, &["properties", name], pointer, f);
+                    }
                 }
             }
+            _ => {}
         }
-    };
+    }
 
-    ($($float_type:ty),*) => {
-        $(
-            impl_minmax_float!(@ Minimum $float_type);
-            impl_minmax_float!(@ Maximum $float_type);
-        )*
+    fn descend<F>(value: &mut Value, segments: &[&str], pointer: &mut String, f: &mut F)
+    where
+        F: FnMut(&str, &mut serde_json::Map<String, Value>),
+    {
+        let len = pointer.len();
+        for segment in segments {
+            pointer.push('/');
+            pointer.push_str(&segment.replace('~', "~0").replace('/', "~1"));
+        }
+        walk(value, pointer, f);
+        pointer.truncate(len);
+    }
+
+    fn descend_values<F>(
+        value: Option<&mut Value>,
+        segments: &[&str],
+        pointer: &mut String,
+        f: &mut F,
+    ) where
+        F: FnMut(&str, &mut serde_json::Map<String, Value>),
+    {
+        if let Some(Value::Object(map)) = value {
+            for (name, value) in map.iter_mut() {
+                let segments = [segments, &[name.as_str()]].concat();
+                descend(value, &segments, pointer, f);
+            }
+        }
+    }
+
+    let Value::Object(thing) = thing else {
+        return;
     };
-}
 
-impl_minmax_float!(f32, f64);
+    let pointer = &mut String::new();
+    for member in ["uriVariables", "schemaDefinitions"] {
+        descend_values(thing.get_mut(member), &[member], pointer, f);
+    }
 
-/// A number metadata.
-#[skip_serializing_none]
-#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct NumberSchema {
-    /// The higher limit of the value.
-    #[serde(flatten)]
-    pub maximum: Option<Maximum<f64>>,
+    for (kind, members) in [
+        ("properties", &[][..]),
+        ("actions", &["input", "output"][..]),
+        (
+            "events",
+            &["subscription", "data", "dataResponse", "cancellation"][..],
+        ),
+    ] {
+        let Some(Value::Object(affordances)) = thing.get_mut(kind) else {
+            continue;
+        };
 
-    /// The lower limit of the value.
-    #[serde(flatten)]
-    pub minimum: Option<Minimum<f64>>,
+        for (name, affordance) in affordances.iter_mut() {
+            if kind == "properties" {
+                descend(affordance, &[kind, name], pointer, f);
+            }
 
-    /// It adds the requirement that the numeric value must be a multiple of this.
-    pub multiple_of: Option<f64>,
+            let Value::Object(affordance) = affordance else {
+                continue;
+            };
+            for member in members {
+                if let Some(value) = affordance.get_mut(*member) {
+                    descend(value, &[kind, name, member], pointer, f);
+                }
+            }
+            descend_values(
+                affordance.get_mut("uriVariables"),
+                &[kind, name, "uriVariables"],
+                pointer,
+                f,
+            );
+        }
+    }
 }
 
-/// An integer metadata.
-#[skip_serializing_none]
-#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-// FIXME: we should probably use a Decimal type
-pub struct IntegerSchema {
-    /// The higher limit of the value.
-    #[serde(flatten)]
-    pub maximum: Option<Maximum<i64>>,
+/// Returns the JSON pointer made of the path segments, like the ones of
+/// [`DataSchema::walk_with_path`].
+fn json_pointer(segments: &[Cow<'_, str>]) -> String {
+    segments
+        .iter()
+        .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
 
-    /// The lower limit of the value.
-    #[serde(flatten)]
-    pub minimum: Option<Minimum<i64>>,
+impl Thing<Nil> {
+    /// Shorthand for [ThingBuilder::new].
+    #[inline]
+    pub fn builder(title: impl Into<String>) -> ThingBuilder<Nil, ToExtend> {
+        ThingBuilder::new(title)
+    }
 
-    /// It adds the requirement that the numeric value must be a multiple of this.
-    pub multiple_of: Option<NonZeroU64>,
+    /// Shorthand for [ThingBuilder::new_untitled].
+    ///
+    /// The returned builder cannot be built until the title is set:
+    ///
+    /// ```compile_fail
+    /// # use wot_td::thing::Thing;
+    /// #
+    /// let thing = Thing::builder_untitled().finish_extend().build();
+    /// ```
+    #[inline]
+    pub fn builder_untitled() -> ThingBuilder<Nil, ToExtend, MissingTitle> {
+        ThingBuilder::new_untitled()
+    }
 }
 
-/// A JSON object metadata.
+/// Thing description Interaction Affordance
+///
+/// Metadata of a Thing that shows the possible choices to Consumers, thereby suggesting how
+/// Consumers may interact with the Thing. See [w3c
+/// documentation](https://www.w3.org/TR/wot-thing-description11/#interactionaffordance) for
+/// further details.
+#[serde_as]
 #[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
-pub struct ObjectSchema<DS, AS, OS> {
-    /// Data schema nested definitions.
-    pub properties: Option<HashMap<String, DataSchema<DS, AS, OS>>>,
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InteractionAffordance<Other: ExtendableThing> {
+    /// JSON-LD keyword to label the object with semantic tags or types.
+    #[serde(rename = "@type", default)]
+    #[serde_as(as = "Option<StringOrArray>")]
+    pub attype: Option<Vec<AtType>>,
 
-    /// Defines which members of the object type are mandatory.
-    pub required: Option<Vec<String>>,
+    /// A human-readable title based on a default language.
+    pub title: Option<String>,
 
-    /// Object schema extension.
-    #[serde(flatten)]
-    pub other: OS,
-}
+    /// Multi-language human-readable titles.
+    pub titles: Option<MultiLanguage>,
 
-#[derive(Clone, Debug, PartialEq)]
-pub(crate) struct UncheckedObjectSchema<DS, AS, OS> {
-    pub(crate) properties: Option<HashMap<String, UncheckedDataSchema<DS, AS, OS>>>,
-    pub(crate) required: Option<Vec<String>>,
-    pub(crate) other: OS,
+    /// Additional human-readable information based on a default language.
+    pub description: Option<String>,
+
+    /// Additional human-readable information in different languages.
+    pub descriptions: Option<MultiLanguage>,
+
+    /// Indicates that the affordance is deprecated and it is going to be removed.
+    ///
+    /// It is not part of the TD vocabulary, therefore it is serialized as `wot-td:deprecated`,
+    /// and [`ThingBuilder::build`] binds the [`WOT_TD_PREFIX`] in the `@context`.
+    #[serde(rename = "wot-td:deprecated")]
+    pub deprecated: Option<bool>,
+
+    /// Human-readable information about the deprecation, like the affordance to use instead.
+    ///
+    /// It is serialized as `wot-td:deprecationNote`.
+    #[serde(rename = "wot-td:deprecationNote")]
+    pub deprecation_note: Option<String>,
+
+    /// Set of form hypermedia controls that describe how an operation can be performed.
+    pub forms: Vec<FormFromOther<Other>>,
+
+    /// URI template variables
+    ///
+    /// A Map of URI template variables that can be used inside `Forms`. The individual variables
+    /// `DataSchema` cannot be an [`ObjectSchema`] or an [`ArraySchema`]. If the same variable is
+    /// both declared in [`Thing`]-level `uri_variables` and in `InteractionAffordance` level, the
+    /// `InteractionAffordance` level variable takes precedence.
+    pub uri_variables: Option<DataSchemaMap<Other>>,
+
+    /// Interaction affordance extension
+    #[serde(flatten)]
+    pub other: Other::InteractionAffordance,
 }
 
-impl<DS, AS, OS> Default for ObjectSchema<DS, AS, OS>
+#[cfg(feature = "verbose-debug")]
+impl<Other> fmt::Debug for InteractionAffordance<Other>
 where
-    OS: Default,
+    Other: ExtendableThing,
+    FormFromOther<Other>: fmt::Debug,
+    DataSchemaFromOther<Other>: fmt::Debug,
+    Other::InteractionAffordance: fmt::Debug,
 {
-    fn default() -> Self {
-        Self {
-            properties: Default::default(),
-            required: Default::default(),
-            other: Default::default(),
-        }
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InteractionAffordance")
+            .field("attype", &self.attype)
+            .field("title", &self.title)
+            .field("titles", &self.titles)
+            .field("description", &self.description)
+            .field("descriptions", &self.descriptions)
+            .field("deprecated", &self.deprecated)
+            .field("deprecation_note", &self.deprecation_note)
+            .field("forms", &self.forms)
+            .field("uri_variables", &self.uri_variables)
+            .field("other", &self.other)
+            .finish()
     }
 }
 
-impl<DS, AS, OS> Default for UncheckedObjectSchema<DS, AS, OS>
+impl<Other> Default for InteractionAffordance<Other>
 where
-    OS: Default,
+    Other: ExtendableThing,
+    FormFromOther<Other>: Default,
+    DataSchemaFromOther<Other>: Default,
+    Other::InteractionAffordance: Default,
 {
     fn default() -> Self {
         Self {
-            properties: Default::default(),
-            required: Default::default(),
+            attype: Default::default(),
+            title: Default::default(),
+            titles: Default::default(),
+            description: Default::default(),
+            descriptions: Default::default(),
+            deprecated: Default::default(),
+            deprecation_note: Default::default(),
+            forms: Default::default(),
+            uri_variables: Default::default(),
             other: Default::default(),
         }
     }
 }
 
-/// A string metadata
-#[skip_serializing_none]
-#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct StringSchema {
-    /// The minimum length of a string.
-    pub min_length: Option<u32>,
+impl<Other> PartialEq for InteractionAffordance<Other>
+where
+    Other: ExtendableThing,
+    FormFromOther<Other>: PartialEq,
+    DataSchemaFromOther<Other>: PartialEq,
+    Other::InteractionAffordance: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.attype == other.attype
+            && self.title == other.title
+            && self.titles == other.titles
+            && self.description == other.description
+            && self.descriptions == other.descriptions
+            && self.deprecated == other.deprecated
+            && self.deprecation_note == other.deprecation_note
+            && self.forms == other.forms
+            && self.uri_variables == other.uri_variables
+            && self.other == other.other
+    }
+}
 
-    /// The maximum length of a string.
-    pub max_length: Option<u32>,
+// Do not serialize the fields that are shared with DataSchema
+fn omit_common<S, O>(i: &InteractionAffordance<O>, ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    O: ExtendableThing,
+{
+    use serde::ser::SerializeMap;
 
-    /// A regular expression to express constraints of the string value. The regular expression
-    /// must follow the [ECMA-262](https://www.w3.org/TR/wot-thing-description11/#bib-ecma-262)
-    /// dialect.
-    // TODO: this should be a validated against EcmaScript dialect of regexes
-    pub pattern: Option<String>,
+    let mut s = ser.serialize_map(Some(3))?;
+    s.serialize_entry("forms", &i.forms)?;
+    if i.uri_variables.is_some() {
+        s.serialize_entry("uriVariables", &i.uri_variables)?;
+    }
 
-    /// The encoding used to store the contents, as specified in [RFC
-    /// 2045](https://www.rfc-editor.org/rfc/rfc2045).
-    // TODO: this should be validated against RFC 2045
-    pub content_encoding: Option<String>,
+    Serialize::serialize(
+        &&i.other,
+        crate::flat_map_serialize::FlatMapSerializer(&mut s),
+    )?;
 
-    /// the MIME type of the contents of a string value, as described in [RFC
-    /// 2046](https://www.rfc-editor.org/rfc/rfc2046).
-    // TODO: this should be validated against RFC 2046
-    pub content_media_type: Option<String>,
+    s.end()
 }
 
-/// The configuration of a security mechanism.
-#[serde_as]
+/// An affordance that exposes the state of a `Thing`
+///
+/// The fields `title`, `titles`, `description`, `descriptions`, `deprecated` and
+/// `deprecation_note` are serialized from `PropertyAffordance::data_schema`.
 #[skip_serializing_none]
-#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
-pub struct SecurityScheme {
-    /// JSON-LD keyword to label the object with semantic tags or types.
-    #[serde(rename = "@type", default)]
-    #[serde_as(as = "Option<OneOrMany<_>>")]
-    pub attype: Option<Vec<String>>,
-
-    /// Human-readable additional information
-    pub description: Option<String>,
-
-    /// Multi-language translations of the description
-    pub descriptions: Option<MultiLanguage>,
-
-    /// URI of the proxy server this security configuration provides access to. If `None`, the
-    /// corresponding security configuration is for the endpoint.
-    // FIXME: use AnyURI
-    pub proxy: Option<String>,
-
-    /// The security scheme subtype.
+#[derive(Deserialize, Serialize)]
+pub struct PropertyAffordance<Other: ExtendableThing> {
+    /// The interaction affordance.
     #[serde(flatten)]
-    pub subtype: SecuritySchemeSubtype,
-}
-
-/// A pre-defined security scheme subtype.
-#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
-#[serde(tag = "scheme", rename_all = "lowercase")]
-pub enum KnownSecuritySchemeSubtype {
-    /// No authentication or other mechanism required to access the resource.
-    #[default]
-    NoSec,
-
-    /// The security parameters are going to be negotiated by the underlying protocols at runtime.
-    Auto,
-
-    /// A combination of security schemes.
-    Combo(ComboSecurityScheme),
-
-    /// Basic Authentication ([RFC7617](https://httpwg.org/specs/rfc7617.html)) security
-    /// configuration
-    Basic(BasicSecurityScheme),
-
-    /// Digest Access Authentication ([RFC7616](https://httpwg.org/specs/rfc7616.html)) security
-    /// configuration
-    Digest(DigestSecurityScheme),
-
-    /// Bearer Token ([RFC6750](https://www.rfc-editor.org/rfc/rfc6750)) security configuration
-    Bearer(BearerSecurityScheme),
-
-    /// Pre-shared key authentication security configuration.
-    Psk(PskSecurityScheme),
-
-    /// OAuth 2.0 authentication security configuration for systems conformant with
-    /// [RFC6749](https://www.rfc-editor.org/rfc/rfc6749),
-    /// [RFC8252](https://www.rfc-editor.org/rfc/rfc8252) and (for the device flow)
-    /// [RFC8628](https://www.rfc-editor.org/rfc/rfc8628)
-    OAuth2(OAuth2SecurityScheme),
+    #[serde(serialize_with = "omit_common")]
+    pub interaction: InteractionAffordance<Other>,
 
-    /// API key authentication security configuration.
-    ApiKey(ApiKeySecurityScheme),
-}
+    /// The data schema representing the property.
+    #[serde(flatten)]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(deserialize_with = "deserialize_property_data_schema::<_, Other>")
+    )]
+    pub data_schema: DataSchemaFromOther<Other>,
 
-/// Custom security scheme subtype
-#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
-pub struct UnknownSecuritySchemeSubtype {
-    /// The name of the security scheme.
-    pub scheme: String,
+    /// A hint that indicates whether Servients hosting the Thing and Intermediaries should provide
+    /// a Protocol Binding that supports the [`ObserveProperty`] and [`UnobserveProperty`]
+    /// operations for this property.
+    ///
+    /// [`ObserveProperty`]: FormOperation::ObserveProperty
+    /// [`UnobserveProperty`]: FormOperation::UnobserveProperty
+    pub observable: Option<bool>,
 
-    /// The inner data of the security scheme.
+    /// Property affordance extension.
     #[serde(flatten)]
-    pub data: Value,
+    pub other: Other::PropertyAffordance,
 }
 
-/// A security scheme subtype.
-#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
-#[serde(untagged)]
-pub enum SecuritySchemeSubtype {
-    /// Pre-defined security scheme subtype.
-    Known(KnownSecuritySchemeSubtype),
+// The members of a property that are not understood are collected by its data schema, but the
+// interaction and property extensions must still take the precedence over them.
+#[cfg(feature = "preserve-unknown")]
+fn deserialize_property_data_schema<'de, D, Other>(
+    deserializer: D,
+) -> Result<DataSchemaFromOther<Other>, D::Error>
+where
+    D: Deserializer<'de>,
+    Other: ExtendableThing,
+{
+    use crate::unknown::{remove_known, ClaimMembers};
+
+    let mut data_schema = DataSchemaFromOther::<Other>::deserialize(deserializer)?;
+    remove_known(
+        &mut data_schema.unknown,
+        &[INTERACTION_AFFORDANCE_MEMBERS, PROPERTY_AFFORDANCE_MEMBERS],
+    );
+    <(Other::InteractionAffordance, Other::PropertyAffordance)>::remove_claimed(
+        &mut data_schema.unknown,
+    );
+    Ok(data_schema)
+}
 
-    /// Custom security scheme subtype
-    Unknown(UnknownSecuritySchemeSubtype),
+#[cfg(feature = "verbose-debug")]
+impl<Other> fmt::Debug for PropertyAffordance<Other>
+where
+    Other: ExtendableThing,
+    InteractionAffordance<Other>: fmt::Debug,
+    DataSchemaFromOther<Other>: fmt::Debug,
+    Other::PropertyAffordance: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PropertyAffordance")
+            .field("interaction", &self.interaction)
+            .field("data_schema", &self.data_schema)
+            .field("observable", &self.observable)
+            .field("other", &self.other)
+            .finish()
+    }
 }
 
-impl Default for SecuritySchemeSubtype {
+impl<Other> Default for PropertyAffordance<Other>
+where
+    Other: ExtendableThing,
+    InteractionAffordance<Other>: Default,
+    DataSchemaFromOther<Other>: Default,
+    Other::PropertyAffordance: Default,
+{
     fn default() -> Self {
-        Self::Known(KnownSecuritySchemeSubtype::default())
+        Self {
+            interaction: Default::default(),
+            data_schema: Default::default(),
+            observable: Default::default(),
+            other: Default::default(),
+        }
     }
 }
 
-/// A combination of security schemes.
-#[serde_as]
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum ComboSecurityScheme {
-    /// Two or more strings identifying other named security scheme definitions, any one of which,
-    /// when satisfied, will allow access.
-    OneOf(#[serde_as(as = "OneOrMany<_>")] Vec<String>),
-
-    /// Two or more strings identifying other named security scheme definitions, all of which must
-    /// be satisfied for access.
-    AllOf(#[serde_as(as = "OneOrMany<_>")] Vec<String>),
+impl<Other> PartialEq for PropertyAffordance<Other>
+where
+    Other: ExtendableThing,
+    InteractionAffordance<Other>: PartialEq,
+    DataSchemaFromOther<Other>: PartialEq,
+    Other::PropertyAffordance: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.interaction == other.interaction
+            && self.data_schema == other.data_schema
+            && self.observable == other.observable
+            && self.other == other.other
+    }
 }
 
-/// Basic Authentication ([RFC7617](https://httpwg.org/specs/rfc7617.html)) security configuration
+/// An affordance that allows to inkvoke a function of the `Thing`.
 #[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
-pub struct BasicSecurityScheme {
-    /// The location of security authentication information.
-    #[serde(rename = "in", default = "SecurityAuthenticationLocation::header")]
-    pub location: SecurityAuthenticationLocation,
+#[derive(Deserialize, Serialize)]
+pub struct ActionAffordance<Other: ExtendableThing> {
+    /// The interaction affordance.
+    #[serde(flatten)]
+    pub interaction: InteractionAffordance<Other>,
 
-    /// Name for query, header, cookie, or uri parameters.
-    pub name: Option<String>,
-}
+    /// The input data schema of the action.
+    pub input: Option<DataSchemaFromOther<Other>>,
 
-impl Default for BasicSecurityScheme {
-    fn default() -> Self {
-        Self {
-            location: SecurityAuthenticationLocation::Header,
-            name: Default::default(),
-        }
-    }
-}
+    /// The output data schema of the action.
+    pub output: Option<DataSchemaFromOther<Other>>,
 
-/// The location of security authentication information.
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
-#[serde(rename_all = "lowercase")]
-pub enum SecurityAuthenticationLocation {
-    /// The parameter will be given in a header provided by the protocol, with the name of the
-    /// header provided by the value of `name`.
-    Header,
+    /// Whether the action is safe or not.
+    ///
+    /// In case it is `true`, when the action is invoked there is no internal state that is being
+    /// changed.
+    #[serde(default)]
+    pub safe: bool,
 
-    /// The parameter will be appended to the URI as a query parameter, with the name of the query
-    /// parameter provided by `name`.
-    Query,
+    /// Whether the action is idempotent or not.
+    ///
+    /// In case it is `true`, the action can be called repeatedly with the same result based on the
+    /// same input.
+    #[serde(default)]
+    pub idempotent: bool,
 
-    /// The parameter will be provided in the body of the request payload, with the data schema
-    /// element used provided by `name`.
-    Body,
+    /// Whether the action is synchronous or not.
+    ///
+    /// A synchronous action means that the response of action contains all the information about
+    /// the result of the action and no further querying about the status of the action is needed.
+    ///
+    /// If this is `None`, no claim on the synchronicity of the action can be made.
+    pub synchronous: Option<bool>,
 
-    /// The parameter is stored in a cookie identified by the value of `name`.
-    Cookie,
+    /// Action affordance extension
+    #[serde(flatten)]
+    pub other: Other::ActionAffordance,
 
-    /// The parameter is embedded in the URI itself, which is encoded in the relevant interaction
-    /// using a URI template variable defined by the value of `name`.
-    Uri,
+    /// The members that are neither part of the TD vocabulary nor claimed by an extension.
+    ///
+    /// They are emitted again on serialization after the extension fields.
+    #[cfg(feature = "preserve-unknown")]
+    #[doc(hidden)]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::unknown::deserialize::<_, ActionAffordance<Other>, (Other::InteractionAffordance, Other::ActionAffordance)>"
+    )]
+    pub unknown: serde_json::Map<String, Value>,
 }
 
-impl SecurityAuthenticationLocation {
-    const fn header() -> Self {
-        Self::Header
-    }
-
-    const fn query() -> Self {
-        Self::Query
+#[cfg(feature = "verbose-debug")]
+impl<Other> fmt::Debug for ActionAffordance<Other>
+where
+    Other: ExtendableThing,
+    InteractionAffordance<Other>: fmt::Debug,
+    DataSchemaFromOther<Other>: fmt::Debug,
+    Other::ActionAffordance: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("ActionAffordance");
+        debug
+            .field("interaction", &self.interaction)
+            .field("input", &self.input)
+            .field("output", &self.output)
+            .field("safe", &self.safe)
+            .field("idempotent", &self.idempotent)
+            .field("synchronous", &self.synchronous)
+            .field("other", &self.other);
+        #[cfg(feature = "preserve-unknown")]
+        debug.field("unknown", &self.unknown);
+        debug.finish()
     }
 }
 
-/// Digest Access Authentication ([RFC7616](https://httpwg.org/specs/rfc7616.html)) security configuration
-#[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
-pub struct DigestSecurityScheme {
-    /// Quality of protection.
-    pub qop: QualityOfProtection,
-
-    /// The location of security authentication information.
-    #[serde(rename = "in", default = "SecurityAuthenticationLocation::header")]
-    pub location: SecurityAuthenticationLocation,
-
-    /// Name for query, header, cookie, or uri parameters.
-    pub name: Option<String>,
-}
-
-impl Default for DigestSecurityScheme {
+impl<Other> Default for ActionAffordance<Other>
+where
+    Other: ExtendableThing,
+    InteractionAffordance<Other>: Default,
+    DataSchemaFromOther<Other>: Default,
+    Other::ActionAffordance: Default,
+{
     fn default() -> Self {
         Self {
-            qop: Default::default(),
-            location: SecurityAuthenticationLocation::Header,
-            name: Default::default(),
+            interaction: Default::default(),
+            input: Default::default(),
+            output: Default::default(),
+            safe: Default::default(),
+            idempotent: Default::default(),
+            synchronous: Default::default(),
+            other: Default::default(),
+            #[cfg(feature = "preserve-unknown")]
+            unknown: Default::default(),
         }
     }
 }
 
-/// Quality of protection, as defined in [RFC2617](https://www.rfc-editor.org/rfc/rfc2617).
-#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum QualityOfProtection {
-    /// Protection by authentication.
-    #[default]
-    Auth,
-
-    /// Protection by authentication with integrity protection.
-    AuthInt,
+impl<Other> PartialEq for ActionAffordance<Other>
+where
+    Other: ExtendableThing,
+    InteractionAffordance<Other>: PartialEq,
+    DataSchemaFromOther<Other>: PartialEq,
+    Other::ActionAffordance: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        let eq = self.interaction == other.interaction
+            && self.input == other.input
+            && self.output == other.output
+            && self.safe == other.safe
+            && self.idempotent == other.idempotent
+            && self.synchronous == other.synchronous
+            && self.other == other.other;
+        #[cfg(feature = "preserve-unknown")]
+        let eq = eq && self.unknown == other.unknown;
+        eq
+    }
 }
 
-/// API key authentication security configuration.
-#[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
-pub struct ApiKeySecurityScheme {
-    /// The location of security authentication information.
-    #[serde(rename = "in", default = "SecurityAuthenticationLocation::query")]
-    pub location: SecurityAuthenticationLocation,
-
-    /// Name for query, header, cookie, or uri parameters.
-    pub name: Option<String>,
-}
+impl<Other> ActionAffordance<Other>
+where
+    Other: ExtendableThing,
+{
+    /// Returns the data schema of the input of the action, if any.
+    #[inline]
+    pub fn input_schema(&self) -> Option<&DataSchemaFromOther<Other>> {
+        self.input.as_ref()
+    }
 
-impl Default for ApiKeySecurityScheme {
-    fn default() -> Self {
-        Self {
-            location: SecurityAuthenticationLocation::Query,
-            name: Default::default(),
-        }
+    /// Returns the data schema of the output of the action, if any.
+    #[inline]
+    pub fn output_schema(&self) -> Option<&DataSchemaFromOther<Other>> {
+        self.output.as_ref()
     }
 }
 
-/// Pre-shared key authentication security configuration.
+/// An affordance that describes an event source.
 #[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
-pub struct BearerSecurityScheme {
-    /// URI of the authorization server.
-    // FIXME: use AnyURI
-    pub authorization: Option<String>,
+#[derive(Deserialize, Serialize)]
+pub struct EventAffordance<Other: ExtendableThing> {
+    /// The interaction affordance.
+    #[serde(flatten)]
+    pub interaction: InteractionAffordance<Other>,
 
-    /// Encoding, encryption, or digest algorithm.
-    #[serde(default = "BearerSecurityScheme::default_alg")]
-    pub alg: Cow<'static, str>,
+    /// Data that needs to be passed upon subscription.
+    pub subscription: Option<DataSchemaFromOther<Other>>,
 
-    /// Format of security authentication information.
-    #[serde(default = "BearerSecurityScheme::default_format")]
-    pub format: Cow<'static, str>,
+    /// Data schema of the messages pushed by the `Thing`.
+    pub data: Option<DataSchemaFromOther<Other>>,
 
-    /// The location of security authentication information.
-    #[serde(rename = "in", default = "SecurityAuthenticationLocation::header")]
-    pub location: SecurityAuthenticationLocation,
+    /// Data schema of the responsed messages sent by the consumer in a response to a data message.
+    pub data_response: Option<DataSchemaFromOther<Other>>,
 
-    /// Name for query, header, cookie, or uri parameters.
-    pub name: Option<String>,
-}
+    /// Data that needs to be passed to cancel a subscription.
+    pub cancellation: Option<DataSchemaFromOther<Other>>,
 
-impl Default for BearerSecurityScheme {
-    fn default() -> Self {
-        Self {
-            authorization: Default::default(),
-            alg: BearerSecurityScheme::default_alg(),
-            format: BearerSecurityScheme::default_format(),
-            location: SecurityAuthenticationLocation::Header,
-            name: Default::default(),
-        }
-    }
-}
+    /// Event affordance extension.
+    #[serde(flatten)]
+    pub other: Other::EventAffordance,
 
-impl BearerSecurityScheme {
-    const fn default_alg() -> Cow<'static, str> {
-        Cow::Borrowed("ES256")
-    }
+    /// The members that are neither part of the TD vocabulary nor claimed by an extension.
+    ///
+    /// They are emitted again on serialization after the extension fields.
+    #[cfg(feature = "preserve-unknown")]
+    #[doc(hidden)]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::unknown::deserialize::<_, EventAffordance<Other>, (Other::InteractionAffordance, Other::EventAffordance)>"
+    )]
+    pub unknown: serde_json::Map<String, Value>,
+}
 
-    const fn default_format() -> Cow<'static, str> {
-        Cow::Borrowed("jwt")
+#[cfg(feature = "verbose-debug")]
+impl<Other> fmt::Debug for EventAffordance<Other>
+where
+    Other: ExtendableThing,
+    InteractionAffordance<Other>: fmt::Debug,
+    DataSchemaFromOther<Other>: fmt::Debug,
+    Other::EventAffordance: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("EventAffordance");
+        debug
+            .field("interaction", &self.interaction)
+            .field("subscription", &self.subscription)
+            .field("data", &self.data)
+            .field("data_response", &self.data_response)
+            .field("cancellation", &self.cancellation)
+            .field("other", &self.other);
+        #[cfg(feature = "preserve-unknown")]
+        debug.field("unknown", &self.unknown);
+        debug.finish()
     }
 }
 
-/// Pre-shared key authentication security configuration.
-#[skip_serializing_none]
-#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
-pub struct PskSecurityScheme {
-    /// Identifier providing information useful for selection or confirmation.
-    pub identity: Option<String>,
+impl<Other> Default for EventAffordance<Other>
+where
+    Other: ExtendableThing,
+    InteractionAffordance<Other>: Default,
+    DataSchemaFromOther<Other>: Default,
+    Other::EventAffordance: Default,
+{
+    fn default() -> Self {
+        Self {
+            interaction: Default::default(),
+            subscription: Default::default(),
+            data: Default::default(),
+            data_response: Default::default(),
+            cancellation: Default::default(),
+            other: Default::default(),
+            #[cfg(feature = "preserve-unknown")]
+            unknown: Default::default(),
+        }
+    }
 }
 
-/// OAuth 2.0 authentication security configuration for systems conformant with
-/// [RFC6749](https://www.rfc-editor.org/rfc/rfc6749),
-/// [RFC8252](https://www.rfc-editor.org/rfc/rfc8252) and (for the device flow)
-/// [RFC8628](https://www.rfc-editor.org/rfc/rfc8628)
-#[serde_as]
-#[skip_serializing_none]
-#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
-pub struct OAuth2SecurityScheme {
-    /// URI of the authorization server. In the case of the device flow, the URI provided for the
-    /// authorization value refers to the device authorization endpoint
-    /// ([RFC8628](https://www.rfc-editor.org/rfc/rfc8628)).
-    // FIXME: use AnyURI
-    pub authorization: Option<String>,
+impl<Other> PartialEq for EventAffordance<Other>
+where
+    Other: ExtendableThing,
+    InteractionAffordance<Other>: PartialEq,
+    DataSchemaFromOther<Other>: PartialEq,
+    Other::EventAffordance: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        let eq = self.interaction == other.interaction
+            && self.subscription == other.subscription
+            && self.data == other.data
+            && self.data_response == other.data_response
+            && self.cancellation == other.cancellation
+            && self.other == other.other;
+        #[cfg(feature = "preserve-unknown")]
+        let eq = eq && self.unknown == other.unknown;
+        eq
+    }
+}
 
-    /// URI of the token server.
-    // FIXME: use AnyURI
-    pub token: Option<String>,
+impl<Other> EventAffordance<Other>
+where
+    Other: ExtendableThing,
+{
+    /// Returns the data schema of the messages pushed by the `Thing`, if any.
+    #[inline]
+    pub fn data_schema(&self) -> Option<&DataSchemaFromOther<Other>> {
+        self.data.as_ref()
+    }
 
-    /// URI of the refresh server.
-    // FIXME: use AnyURI
-    pub refresh: Option<String>,
+    /// Returns the data schema of the data passed upon subscription, if any.
+    #[inline]
+    pub fn subscription_schema(&self) -> Option<&DataSchemaFromOther<Other>> {
+        self.subscription.as_ref()
+    }
 
-    /// Set of authorization scope identifiers.
-    ///
-    /// These are provided in tokens returned by an authorization server and associated with forms
-    /// in order to identify what resources a client may access and how.
-    #[serde(default)]
-    #[serde_as(as = "Option<OneOrMany<_>>")]
-    pub scopes: Option<Vec<String>>,
+    /// Returns the data schema of the data passed to cancel a subscription, if any.
+    #[inline]
+    pub fn cancellation_schema(&self) -> Option<&DataSchemaFromOther<Other>> {
+        self.cancellation.as_ref()
+    }
 
-    /// Authorization flow.
-    pub flow: String,
+    /// Returns the data schema of the responses sent by the consumer to data messages, if any.
+    #[inline]
+    pub fn data_response_schema(&self) -> Option<&DataSchemaFromOther<Other>> {
+        self.data_response.as_ref()
+    }
 }
 
-impl OAuth2SecurityScheme {
-    /// Creates a new default value with the given `flow`.
-    pub fn new(flow: impl Into<String>) -> Self {
-        let flow = flow.into();
+/// Metadata of a `Thing` that provides version information about the _Thing Description_ document.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct VersionInfo {
+    /// The version indicator of this _Thing Description_ instance.
+    pub instance: String,
+
+    /// The version indicator of the underlying _Thing Model_.
+    pub model: Option<String>,
+}
+
+impl<S> From<S> for VersionInfo
+where
+    S: Into<String>,
+{
+    fn from(instance: S) -> Self {
+        let instance = instance.into();
         Self {
-            authorization: Default::default(),
-            token: Default::default(),
-            refresh: Default::default(),
-            scopes: Default::default(),
-            flow,
+            instance,
+            model: None,
         }
     }
 }
 
-/// A link to an arbitrary resource.
+/// Metadata that describes the data format used.
+///
+/// The `Debug` representation fits in a single line, like `integer[0..=100] unit=%`, unless the
+/// `verbose-debug` feature is enabled.
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
-pub struct Link {
-    /// Target IRI of a link or submission target of a form.
-    pub href: String,
+#[derive(Clone, Default, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "verbose-debug", derive(Debug))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "preserve-unknown",
+    serde(bound(deserialize = "DS: Serialize + serde::de::DeserializeOwned, \
+                               AS: Serialize + serde::de::DeserializeOwned, \
+                               OS: Serialize + serde::de::DeserializeOwned"))
+)]
+pub struct DataSchema<DS, AS, OS> {
+    /// JSON-LD keyword to label the object with semantic tags or types.
+    #[serde(rename = "@type", default)]
+    #[serde_as(as = "Option<StringOrArray>")]
+    pub attype: Option<Vec<AtType>>,
 
-    /// Target attribute providing a hint indicating what the media type
-    /// ([RFC2046](https://www.rfc-editor.org/rfc/rfc2046)) of the result of dereferencing the link
-    /// should be.
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    /// Human-readable title to be displayed
+    pub title: Option<String>,
 
-    /// A link relation type identifies the semantics of a link.
-    pub rel: Option<String>,
+    /// Multi-language translations of the title
+    pub titles: Option<MultiLanguage>,
 
-    /// Overrides the link context with the given URI or IRI.
-    ///
-    /// By default the link context is the Thing itself identified by its `id`.
-    // FIXME: use AnyURI
-    pub anchor: Option<String>,
+    /// Human-readable additional information
+    pub description: Option<String>,
 
-    /// One or more sizes for the referenced icon.
+    /// Multi-language translations of the description
+    pub descriptions: Option<MultiLanguage>,
+
+    /// Indicates that the data schema is deprecated and it is going to be removed.
     ///
-    /// This is only applicable for relation type "icon". The value pattern follows {Height}x{Width} (e.g., "16x16", "16x16 32x32").
-    pub sizes: Option<String>,
+    /// It is not part of the TD vocabulary, therefore it is serialized as `wot-td:deprecated`,
+    /// and [`ThingBuilder::build`] binds the [`WOT_TD_PREFIX`] in the `@context`.
+    #[serde(rename = "wot-td:deprecated")]
+    pub deprecated: Option<bool>,
 
-    /// The language of a linked document.
-    #[serde(default)]
-    #[serde_as(as = "Option<OneOrMany<_>>")]
-    pub hreflang: Option<Vec<LanguageTag<String>>>,
-}
+    /// Human-readable information about the deprecation.
+    ///
+    /// It is serialized as `wot-td:deprecationNote`.
+    #[serde(rename = "wot-td:deprecationNote")]
+    pub deprecation_note: Option<String>,
 
-/// The representation of an operation over a Thing.
-#[serde_as]
-#[skip_serializing_none]
-#[derive(Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Form<Other: ExtendableThing> {
-    /// The semantic intention of performing the operation(s) described by the form.
-    #[serde(default, skip_serializing_if = "DefaultedFormOperations::is_default")]
-    pub op: DefaultedFormOperations,
+    /// A constant value for the data schema.
+    #[serde(rename = "const")]
+    pub constant: Option<Value>,
 
-    /// Target IRI of a link or submission target of a form.
-    // FIXME: use AnyURI
-    pub href: String,
+    /// A default value for the data schema.
+    pub default: Option<Value>,
 
-    /// A content type.
-    ///
-    /// It is based on a media type (e.g., text/plain) and potential parameters (e.g.,
-    /// charset=utf-8) for the media type ([RFC2046](https://www.rfc-editor.org/rfc/rfc2046)).
-    pub content_type: Option<String>,
+    /// Unit information used for the data schema (e.g. Km, g, m/s^2)
+    pub unit: Option<String>,
 
-    /// Content coding values indicate an encoding transformation that has been or can be applied
-    /// to a representation.
-    ///
-    /// Content codings are primarily used to allow a representation to be compressed or otherwise
-    /// usefully transformed without losing the identity of its underlying media type and without
-    /// loss of information.
-    ///
-    /// Examples of content coding include "gzip", "deflate", etc. .
-    // TODO: check if the subset of possible values is limited by the [IANA HTTP content coding
-    // registry](https://www.iana.org/assignments/http-parameters/http-parameters.xhtml#content-coding).
-    pub content_coding: Option<String>,
+    /// Used to ensure that the data is valid against one of the specified schemas.
+    pub one_of: Option<Vec<Self>>,
 
-    /// The mechanism by which an interaction will be accomplished for a given protocol when there
-    /// are multiple options.
-    pub subprotocol: Option<String>,
+    /// A restricted set of values.
+    #[serde(rename = "enum")]
+    pub enumeration: Option<Vec<Value>>,
 
-    /// Set of security definition names, chosen from those defined in
-    /// [`security_definitions`](Thing::security_definitions). These must all be satisfied for
-    /// access to resources.
-    // FIXME: use variant names of KnownSecuritySchemeSubtype + "other" string variant
+    /// Indicates if the property interaction value is read only.
     #[serde(default)]
-    #[serde_as(as = "Option<OneOrMany<_>>")]
-    pub security: Option<Vec<String>>,
+    pub read_only: bool,
 
-    /// Set of authorization scope identifiers.
-    ///
-    /// The values associated with a form should be chosen from those defined in an
-    /// [`OAuth2SecurityScheme`] active on that form.
+    /// Indicates if the property interaction value is write only.
     #[serde(default)]
-    #[serde_as(as = "Option<OneOrMany<_>>")]
-    pub scopes: Option<Vec<String>>,
-
-    /// The expected response from the call to the resource.
-    ///
-    /// The response name contains metadata that is only valid for the primary response messages
-    pub response: Option<ExpectedResponse<Other::ExpectedResponse>>,
+    pub write_only: bool,
 
-    /// Additional expected responses.
-    #[serde(default)]
-    #[serde_as(as = "Option<OneOrMany<_>>")]
-    pub additional_responses: Option<Vec<AdditionalExpectedResponse>>,
+    /// Allows validation based on a format pattern such as "date-time", "email", "uri".
+    pub format: Option<String>,
 
-    /// Form extension.
+    /// The JSON-based subtype of the data schema.
     #[serde(flatten)]
-    pub other: Other::Form,
-}
-
-impl<Other> Clone for Form<Other>
-where
-    Other: ExtendableThing,
-    Other::ExpectedResponse: Clone,
-    Other::Form: Clone,
-{
-    fn clone(&self) -> Self {
-        Self {
-            op: self.op.clone(),
-            href: self.href.clone(),
-            content_type: self.content_type.clone(),
-            content_coding: self.content_coding.clone(),
-            subprotocol: self.subprotocol.clone(),
-            security: self.security.clone(),
-            scopes: self.scopes.clone(),
-            response: self.response.clone(),
-            additional_responses: self.additional_responses.clone(),
-            other: self.other.clone(),
-        }
-    }
-}
-
-/// The semantic intention of an operation.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
-#[serde(rename_all = "lowercase")]
-pub enum FormOperation {
-    /// Read a property.
-    ReadProperty,
+    pub subtype: Option<DataSchemaSubtype<DS, AS, OS>>,
 
-    /// Update a property.
-    WriteProperty,
+    /// Data schema extension.
+    #[serde(flatten)]
+    pub other: DS,
 
-    /// Observe a property.
+    /// Additional members, serialized after the extension fields.
     ///
-    /// Identifies an operation to be notified with new data when the property is updated.
-    ObserveProperty,
-
-    /// Unobserve a property.
+    /// They are set by [`BuildableDataSchema::raw_field`] and they are never deserialized: a
+    /// parsed data schema has them among the unknown members with the `preserve-unknown` feature,
+    /// otherwise they are dropped.
     ///
-    /// Stops the notification from a previously observed property.
-    UnobserveProperty,
-
-    /// Perform an action.
-    InvokeAction,
-
-    /// Get the status of an action.
-    QueryAction,
-
-    /// Cancel an ongoing action.
-    CancelAction,
+    /// [`BuildableDataSchema::raw_field`]: crate::builder::data_schema::BuildableDataSchema::raw_field
+    #[serde(flatten, skip_deserializing)]
+    pub raw_fields: serde_json::Map<String, Value>,
 
-    /// Subscribe to an event.
+    /// The members that are neither part of the TD vocabulary nor claimed by an extension.
     ///
-    /// Identifies an operation to be notified when an event occurs.
-    SubscribeEvent,
+    /// They are emitted again on serialization after the extension fields.
+    #[cfg(feature = "preserve-unknown")]
+    #[doc(hidden)]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::unknown::deserialize::<_, DataSchema<DS, AS, OS>, (DS, AS, OS)>"
+    )]
+    pub unknown: serde_json::Map<String, Value>,
+}
 
-    /// Unsubscribe from an event.
+impl<DS, AS, OS> DataSchema<DS, AS, OS> {
+    /// Returns the [UCUM](https://ucum.org/ucum) code of the `unit`, if it is a known unit.
     ///
-    /// Stops the notification from a previously subscribed event.
-    UnsubscribeEvent,
-
-    /// Read all the properties in a single interaction.
-    ReadAllProperties,
+    /// Common spellings like `celsius` and `°C` are mapped to the same code, see the
+    /// [`units`](crate::units) module. The `unit` itself is left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::thing::DataSchema;
+    /// #
+    /// let data_schema = DataSchema::<(), (), ()> {
+    ///     unit: Some("celsius".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(data_schema.normalized_unit(), Some("Cel"));
+    /// assert_eq!(data_schema.unit.as_deref(), Some("celsius"));
+    /// ```
+    #[cfg(feature = "units")]
+    pub fn normalized_unit(&self) -> Option<&str> {
+        self.unit.as_deref().and_then(crate::units::normalize_unit)
+    }
 
-    /// Update all the properties in a single interaction.
-    WriteAllProperties,
+    /// Merges human readable information into the data schema, using the specified policy.
+    ///
+    /// The translations of `titles` and `descriptions` are merged language by language, and the
+    /// `@type` values are added to the existing ones, skipping the duplicates. With
+    /// [`MergePolicy::Overwrite`] the merged values replace the existing ones, while with
+    /// [`MergePolicy::KeepExisting`] only the missing ones are set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     builder::{BuildableHumanReadableInfo, HumanReadableInfo, MergePolicy},
+    /// #     thing::DataSchema,
+    /// # };
+    /// #
+    /// let mut data_schema = DataSchema::<(), (), ()> {
+    ///     title: Some("Temperature".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let info = HumanReadableInfo::default()
+    ///     .title("Temp")
+    ///     .description("The measured temperature");
+    /// data_schema
+    ///     .merge_human_readable(&info, MergePolicy::KeepExisting)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(data_schema.title.as_deref(), Some("Temperature"));
+    /// assert_eq!(
+    ///     data_schema.description.as_deref(),
+    ///     Some("The measured temperature"),
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// It fails if a language tag of the merged translations is not valid, in which case the
+    /// data schema is left untouched.
+    pub fn merge_human_readable(
+        &mut self,
+        other: &HumanReadableInfo,
+        policy: MergePolicy,
+    ) -> Result<(), Error> {
+        fn merge<T>(current: &mut Option<T>, other: Option<T>, policy: MergePolicy) {
+            if other.is_some() && (policy == MergePolicy::Overwrite || current.is_none()) {
+                *current = other;
+            }
+        }
 
-    /// Read multiple selected properties in a single interaction.
-    ReadMultipleProperties,
+        fn merge_languages(
+            current: &mut Option<MultiLanguage>,
+            other: Option<MultiLanguage>,
+            policy: MergePolicy,
+        ) {
+            let Some(other) = other else { return };
+            let current = current.get_or_insert_with(Default::default);
+            for (language, value) in other {
+                match policy {
+                    MergePolicy::Overwrite => {
+                        current.insert(language, value);
+                    }
+                    MergePolicy::KeepExisting => {
+                        current.entry(language).or_insert(value);
+                    }
+                }
+            }
+        }
 
-    /// Update multiple selected properties in a single interaction.
-    WriteMultipleProperties,
+        let titles = other
+            .titles
+            .clone()
+            .map(|titles| titles.build().map_err(|error| error.at("titles")))
+            .transpose()?;
+        let descriptions = other
+            .descriptions
+            .clone()
+            .map(|descriptions| {
+                descriptions
+                    .build()
+                    .map_err(|error| error.at("descriptions"))
+            })
+            .transpose()?;
 
-    /// Observe all the properties in a single interaction.
-    ObserveAllProperties,
+        if let Some(attype) = &other.attype {
+            let current = self.attype.get_or_insert_with(Default::default);
+            for ty in attype {
+                if !current.iter().any(|current| current == ty.as_str()) {
+                    current.push(AtType::parse(ty));
+                }
+            }
+        }
+        merge(&mut self.title, other.title.clone(), policy);
+        merge_languages(&mut self.titles, titles, policy);
+        merge(&mut self.description, other.description.clone(), policy);
+        merge_languages(&mut self.descriptions, descriptions, policy);
+        merge(&mut self.deprecated, other.deprecated, policy);
+        merge(
+            &mut self.deprecation_note,
+            other.deprecation_note.clone(),
+            policy,
+        );
 
-    /// Unobserve all the properties in a single interaction.
-    UnobserveAllProperties,
+        Ok(())
+    }
 
-    /// Subscribe to all events in a single interaction.
-    SubscribeAllEvents,
+    /// Converts the data schema into an [OpenAPI 3.1 schema
+    /// object](https://spec.openapis.org/oas/v3.1.0#schema-object).
+    ///
+    /// OpenAPI 3.1 is aligned with JSON Schema 2020-12, but some TD members need to be adapted:
+    /// the JSON-LD and multi-language members are dropped, `unit` is emitted as `x-unit`,
+    /// `readOnly` and `writeOnly` are only emitted when set, tuple `items` become `prefixItems`
+    /// and nullable values use `type` arrays.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::thing::{DataSchema, DataSchemaSubtype};
+    /// #
+    /// let data_schema = DataSchema::<(), (), ()> {
+    ///     read_only: true,
+    ///     unit: Some("cm".to_string()),
+    ///     subtype: Some(DataSchemaSubtype::Number(Default::default())),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     data_schema.to_openapi().unwrap(),
+    ///     json!({
+    ///         "type": "number",
+    ///         "readOnly": true,
+    ///         "x-unit": "cm",
+    ///     }),
+    /// );
+    /// ```
+    pub fn to_openapi(&self) -> Result<Value, serde_json::Error>
+    where
+        Self: Serialize,
+    {
+        let mut value = serde_json::to_value(self)?;
+        to_openapi_schema(&mut value);
+        Ok(value)
+    }
 
-    /// Unsubscribe from all events in a single interaction.
-    UnsubscribeAllEvents,
+    /// Checks whether `value` satisfies the data schema.
+    ///
+    /// The default [`ValidationOptions`] are used, see [`validate_value_with`] and the
+    /// [`validate`](crate::validate) module for more details.
+    ///
+    /// [`validate_value_with`]: Self::validate_value_with
+    pub fn validate_value(&self, value: &Value) -> Result<(), ValidationError> {
+        self.validate_value_with(value, &ValidationOptions::default())
+    }
 
-    /// Get the status of all actions in a single interaction.
-    QueryAllActions,
-}
+    /// Checks whether `value` satisfies the data schema using the given validation options.
+    pub fn validate_value_with(
+        &self,
+        value: &Value,
+        options: &ValidationOptions,
+    ) -> Result<(), ValidationError> {
+        SchemaContext::new(options).validate(self, value)
+    }
 
-impl fmt::Display for FormOperation {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let s = match self {
-            Self::ReadProperty => "readproperty",
-            Self::WriteProperty => "writeproperty",
-            Self::ObserveProperty => "observeproperty",
-            Self::UnobserveProperty => "unobserveproperty",
-            Self::InvokeAction => "invokeaction",
-            Self::QueryAction => "queryaction",
-            Self::CancelAction => "cancelaction",
-            Self::SubscribeEvent => "subscribeevent",
-            Self::UnsubscribeEvent => "unsubscribeevent",
-            Self::ReadAllProperties => "readallproperties",
-            Self::WriteAllProperties => "writeallproperties",
-            Self::ReadMultipleProperties => "readmultipleproperties",
-            Self::WriteMultipleProperties => "writemultipleproperties",
-            Self::ObserveAllProperties => "observeallproperties",
-            Self::UnobserveAllProperties => "unobserveallproperties",
-            Self::SubscribeAllEvents => "subscribeallevents",
-            Self::UnsubscribeAllEvents => "unsubscribeallevents",
-            Self::QueryAllActions => "queryallactions",
-        };
+    /// Parses a value transported as a string, like the value of an URI variable.
+    ///
+    /// The string is expected to be already percent-decoded.
+    ///
+    /// See the [`coerce`] module for more details.
+    pub fn coerce_from_str(&self, raw: &str) -> Result<Value, CoerceError> {
+        coerce::coerce_from_str(self, raw)
+    }
 
-        f.write_str(s)
+    /// Returns the canonical string of a value transported as a string, like the value of an URI
+    /// variable.
+    ///
+    /// All the characters but the unreserved ones of RFC 3986 are percent-encoded, therefore the
+    /// string can be directly used as a component of the URI.
+    /// See the [`coerce`] module for more details.
+    pub fn to_uri_component(&self, value: &Value) -> Result<String, CoerceError> {
+        coerce::to_uri_component(self, value)
     }
-}
 
-/// A default or custom set of Form operations.
-///
-/// A `Form` has a different default `op` field depending on its context. With this, it is possible
-/// to specify a _default_ operation independently from the context.
-///
-/// Note: an instance of this enum should not be serialized if it is a `Default` value.
-#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
-pub enum DefaultedFormOperations {
-    /// The default operation depending on the context.
-    #[default]
-    Default,
+    /// Converts _number_ subtypes that can only represent integers into _integer_ subtypes.
+    ///
+    /// A [`NumberSchema`] is converted into an [`IntegerSchema`] when it has an integer
+    /// `multipleOf` and all its bounds are integer values (see [`NumberSchema::to_integer`]). The
+    /// conversion is recursively applied to `oneOf`, array items and object properties.
+    ///
+    /// This is an opt-in normalization, which is never performed while parsing or building.
+    pub fn tighten_integers(mut self) -> Self {
+        self.one_of = self.one_of.map(|one_of| {
+            one_of
+                .into_iter()
+                .map(DataSchema::tighten_integers)
+                .collect()
+        });
 
-    /// A custom set of operations.
-    Custom(Vec<FormOperation>),
-}
+        self.subtype = self.subtype.map(|subtype| match subtype {
+            DataSchemaSubtype::Number(number) => match number.to_integer() {
+                Some(integer) => DataSchemaSubtype::Integer(integer),
+                None => DataSchemaSubtype::Number(number),
+            },
+            DataSchemaSubtype::Array(mut array) => {
+                array.items = array.items.map(|items| match items {
+                    BoxedElemOrVec::Elem(item) => {
+                        BoxedElemOrVec::Elem(Box::new(item.tighten_integers()))
+                    }
+                    BoxedElemOrVec::Vec(items) => BoxedElemOrVec::Vec(
+                        items
+                            .into_iter()
+                            .map(DataSchema::tighten_integers)
+                            .collect(),
+                    ),
+                });
+                DataSchemaSubtype::Array(array)
+            }
+            DataSchemaSubtype::Object(mut object) => {
+                object.properties = object.properties.map(|properties| {
+                    properties
+                        .into_iter()
+                        .map(|(name, schema)| (name, schema.tighten_integers()))
+                        .collect()
+                });
+                DataSchemaSubtype::Object(object)
+            }
+            subtype => subtype,
+        });
 
-impl DefaultedFormOperations {
-    /// Returns `true` if the operation is a [`Default`](DefaultedFormOperations::Default) value.
-    #[inline]
-    pub const fn is_default(&self) -> bool {
-        matches!(self, Self::Default)
+        self
     }
-}
 
-impl Serialize for DefaultedFormOperations {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    /// Transforms the bounds of all the numeric subtypes using `f`.
+    ///
+    /// [`NumberSchema::map_bounds`] and [`IntegerSchema::map_bounds`] are recursively applied to
+    /// `oneOf`, array items and object properties, therefore the _integer_ subtypes become
+    /// _number_ subtypes. The values of `const`, `default` and `enum` are not transformed.
+    pub fn map_numeric_bounds<F>(self, f: F) -> Self
     where
-        S: Serializer,
+        F: Fn(f64) -> f64,
     {
-        match self {
-            Self::Default => serializer.serialize_none(),
-            Self::Custom(ops) if ops.is_empty() => serializer.serialize_none(),
-            Self::Custom(ops) => ops.serialize(serializer),
-        }
+        self.map_numeric_bounds_with(&f)
     }
-}
 
-impl<'de> Deserialize<'de> for DefaultedFormOperations
-where
-    OneOrMany<Same>: DeserializeAs<'de, Vec<FormOperation>>,
-{
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    fn map_numeric_bounds_with<F>(mut self, f: &F) -> Self
     where
-        D: Deserializer<'de>,
+        F: Fn(f64) -> f64,
     {
-        let ops = Option::<OneOrMany<_>>::deserialize_as(deserializer)?;
-        Ok(ops.map(Self::Custom).unwrap_or(Self::Default))
+        self.one_of = self.one_of.map(|one_of| {
+            one_of
+                .into_iter()
+                .map(|schema| schema.map_numeric_bounds_with(f))
+                .collect()
+        });
+
+        self.subtype = self.subtype.map(|subtype| match subtype {
+            DataSchemaSubtype::Number(number) => DataSchemaSubtype::Number(number.map_bounds(f)),
+            DataSchemaSubtype::Integer(integer) => DataSchemaSubtype::Number(integer.map_bounds(f)),
+            DataSchemaSubtype::Array(mut array) => {
+                array.items = array.items.map(|items| match items {
+                    BoxedElemOrVec::Elem(item) => {
+                        BoxedElemOrVec::Elem(Box::new(item.map_numeric_bounds_with(f)))
+                    }
+                    BoxedElemOrVec::Vec(items) => BoxedElemOrVec::Vec(
+                        items
+                            .into_iter()
+                            .map(|item| item.map_numeric_bounds_with(f))
+                            .collect(),
+                    ),
+                });
+                DataSchemaSubtype::Array(array)
+            }
+            DataSchemaSubtype::Object(mut object) => {
+                object.properties = object.properties.map(|properties| {
+                    properties
+                        .into_iter()
+                        .map(|(name, schema)| (name, schema.map_numeric_bounds_with(f)))
+                        .collect()
+                });
+                DataSchemaSubtype::Object(object)
+            }
+            subtype => subtype,
+        });
+
+        self
     }
-}
 
-/// The expected response message for the primary response.
-#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ExpectedResponse<Other> {
-    /// A content type.
+    /// Removes the constraints that do not restrict the accepted values.
     ///
-    /// It is based on a media type (e.g., text/plain) and potential parameters (e.g.,
-    /// charset=utf-8) for the media type ([RFC2046](https://www.rfc-editor.org/rfc/rfc2046)).
-    pub content_type: String,
+    /// This is an opt-in size optimization, the minified data schema accepts exactly the same
+    /// values. The following constraints are removed:
+    ///
+    /// - `minItems` and `minLength` equal to `0`;
+    /// - the inclusive `minimum` and `maximum` of an _integer_ equal to [`i64::MIN`] and
+    ///   [`i64::MAX`], the range of values representable by an [`IntegerSchema`];
+    /// - the inclusive `minimum` and `maximum` of a _number_ that are infinite or equal to
+    ///   [`f64::MIN`] and [`f64::MAX`];
+    /// - a `multipleOf` equal to `1` on an _integer_;
+    /// - an empty `properties` map and an empty `required` list.
+    ///
+    /// The minification is recursively applied to `oneOf`, array items and object properties.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::thing::{ArraySchema, DataSchema, DataSchemaSubtype};
+    /// #
+    /// let data_schema = DataSchema::<(), (), ()> {
+    ///     subtype: Some(DataSchemaSubtype::Array(ArraySchema {
+    ///         min_items: Some(0),
+    ///         max_items: Some(4),
+    ///         ..Default::default()
+    ///     })),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let Some(DataSchemaSubtype::Array(array)) = data_schema.minify().subtype else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!(array.min_items, None);
+    /// assert_eq!(array.max_items, Some(4));
+    /// ```
+    pub fn minify(mut self) -> Self {
+        self.one_of = self
+            .one_of
+            .map(|one_of| one_of.into_iter().map(DataSchema::minify).collect());
+
+        self.subtype = self.subtype.map(|subtype| match subtype {
+            DataSchemaSubtype::Array(mut array) => {
+                array.min_items = array.min_items.filter(|&min_items| min_items != 0);
+                array.items = array.items.map(|items| match items {
+                    BoxedElemOrVec::Elem(item) => BoxedElemOrVec::Elem(Box::new(item.minify())),
+                    BoxedElemOrVec::Vec(items) => {
+                        BoxedElemOrVec::Vec(items.into_iter().map(DataSchema::minify).collect())
+                    }
+                });
+                DataSchemaSubtype::Array(array)
+            }
+            DataSchemaSubtype::Number(mut number) => {
+                let is_natural = |x: f64| x.is_infinite() || x == f64::MIN || x == f64::MAX;
+                number.minimum = number
+                    .minimum
+                    .filter(|&minimum| !matches!(minimum, Minimum::Inclusive(x) if is_natural(x)));
+                number.maximum = number
+                    .maximum
+                    .filter(|&maximum| !matches!(maximum, Maximum::Inclusive(x) if is_natural(x)));
+                DataSchemaSubtype::Number(number)
+            }
+            DataSchemaSubtype::Integer(mut integer) => {
+                integer.minimum = integer
+                    .minimum
+                    .filter(|&minimum| minimum != Minimum::Inclusive(i64::MIN));
+                integer.maximum = integer
+                    .maximum
+                    .filter(|&maximum| maximum != Maximum::Inclusive(i64::MAX));
+                integer.multiple_of = integer
+                    .multiple_of
+                    .filter(|multiple_of| multiple_of.get() != 1);
+                DataSchemaSubtype::Integer(integer)
+            }
+            DataSchemaSubtype::Object(mut object) => {
+                object.properties = object
+                    .properties
+                    .filter(|properties| !properties.is_empty())
+                    .map(|properties| {
+                        properties
+                            .into_iter()
+                            .map(|(name, schema)| (name, schema.minify()))
+                            .collect()
+                    });
+                object.required = object.required.filter(|required| !required.is_empty());
+                DataSchemaSubtype::Object(object)
+            }
+            DataSchemaSubtype::String(mut string) => {
+                string.min_length = string.min_length.filter(|&min_length| min_length != 0);
+                DataSchemaSubtype::String(string)
+            }
+            subtype => subtype,
+        });
 
-    /// Expected response extension.
-    #[serde(flatten)]
-    pub other: Other,
-}
+        self
+    }
 
-/// The expected response message for additional responses.
-#[skip_serializing_none]
-#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct AdditionalExpectedResponse {
-    /// It is `true` if an additional response should not be considered an error.
-    #[serde(default = "bool_false", skip_serializing_if = "is_false")]
-    pub success: bool,
+    /// Returns the enumerated schemas of the tree along with their values, sorted by path.
+    ///
+    /// Each path is a [JSON pointer](https://www.rfc-editor.org/rfc/rfc6901) relative to `self`,
+    /// the empty string being `self` itself. The `oneOf` alternatives, the array items and the
+    /// object properties are recursively visited.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::thing::DataSchema;
+    /// #
+    /// let data_schema: DataSchema<(), (), ()> = serde_json::from_value(json!({
+    ///     "type": "array",
+    ///     "items": { "type": "string", "enum": ["on", "off"] },
+    /// }))
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     data_schema.collect_enums(),
+    ///     [("/items".to_string(), &[json!("on"), json!("off")][..])],
+    /// );
+    /// ```
+    pub fn collect_enums(&self) -> Vec<(String, &[Value])> {
+        let mut enums = Vec::new();
+        self.walk_with_path(&mut |path, data_schema| {
+            if let Some(enumeration) = &data_schema.enumeration {
+                enums.push((json_pointer(path), &enumeration[..]));
+            }
+        });
+        enums.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        enums
+    }
 
-    /// A content type.
+    /// Calls `f` on the data schema and on every nested one.
     ///
-    /// It is based on a media type (e.g., text/plain) and potential parameters (e.g.,
-    /// charset=utf-8) for the media type ([RFC2046](https://www.rfc-editor.org/rfc/rfc2046)).
-    pub content_type: Option<String>,
+    /// The data schemas are visited in pre-order: a data schema is visited before its `oneOf`
+    /// alternatives, then its array items and its object properties.
+    pub fn walk<'a, F>(&'a self, f: &mut F)
+    where
+        F: FnMut(&'a DataSchema<DS, AS, OS>),
+    {
+        self.walk_with_path(&mut |_, data_schema| f(data_schema));
+    }
 
-    /// The output data schema for an additional response if it differs from the default output data schema.
+    /// Like [`walk`](Self::walk), but `f` also receives the path of the nested data schema.
     ///
-    /// It is the name of a previous definition given in the
-    /// [`schema_definitions`](Thing::schema_definitions).
-    pub schema: Option<String>,
-}
+    /// The path contains the names of the members from this data schema to the nested one, like
+    /// `["properties", "position", "items", "0"]`, and it is empty for this data schema.
+    pub(crate) fn walk_with_path<'a, F>(&'a self, f: &mut F)
+    where
+        F: FnMut(&[Cow<'a, str>], &'a DataSchema<DS, AS, OS>),
+    {
+        self.try_walk_with_path(&mut |path, data_schema| {
+            f(path, data_schema);
+            Ok::<_, core::convert::Infallible>(())
+        })
+        .unwrap_or_else(|never| match never {});
+    }
 
-const fn bool_false() -> bool {
-    false
-}
+    /// Like [`walk_with_path`](Self::walk_with_path), but it stops at the first error returned
+    /// by `f`.
+    pub(crate) fn try_walk_with_path<'a, E, F>(&'a self, f: &mut F) -> Result<(), E>
+    where
+        F: FnMut(&[Cow<'a, str>], &'a DataSchema<DS, AS, OS>) -> Result<(), E>,
+    {
+        fn visit<'a, DS, AS, OS, E, F>(
+            data_schema: &'a DataSchema<DS, AS, OS>,
+            path: &mut Vec<Cow<'a, str>>,
+            f: &mut F,
+        ) -> Result<(), E>
+        where
+            F: FnMut(&[Cow<'a, str>], &'a DataSchema<DS, AS, OS>) -> Result<(), E>,
+        {
+            f(path, data_schema)?;
+            for (index, one_of) in data_schema.one_of.iter().flatten().enumerate() {
+                descend(one_of, ["oneOf".into(), index.to_string().into()], path, f)?;
+            }
 
-const fn is_false(b: &bool) -> bool {
-    !*b
-}
+            match &data_schema.subtype {
+                Some(DataSchemaSubtype::Array(array)) => match &array.items {
+                    Some(BoxedElemOrVec::Elem(item)) => descend(item, ["items".into()], path, f),
+                    Some(BoxedElemOrVec::Vec(items)) => {
+                        items.iter().enumerate().try_for_each(|(index, item)| {
+                            descend(item, ["items".into(), index.to_string().into()], path, f)
+                        })
+                    }
+                    None => Ok(()),
+                },
+                Some(DataSchemaSubtype::Object(object)) => object
+                    .properties
+                    .iter()
+                    .flatten()
+                    .try_for_each(|(name, property)| {
+                        descend(property, ["properties".into(), name.into()], path, f)
+                    }),
+                _ => Ok(()),
+            }
+        }
 
-#[cfg(test)]
-mod test {
-    use alloc::vec;
+        fn descend<'a, DS, AS, OS, E, F, const N: usize>(
+            data_schema: &'a DataSchema<DS, AS, OS>,
+            segments: [Cow<'a, str>; N],
+            path: &mut Vec<Cow<'a, str>>,
+            f: &mut F,
+        ) -> Result<(), E>
+        where
+            F: FnMut(&[Cow<'a, str>], &'a DataSchema<DS, AS, OS>) -> Result<(), E>,
+        {
+            let len = path.len();
+            path.extend(segments);
+            let result = visit(data_schema, path, f);
+            path.truncate(len);
+            result
+        }
 
-    use serde_json::json;
-    use time::macros::datetime;
+        visit(self, &mut Vec::new(), f)
+    }
 
-    use crate::hlist::Cons;
+    /// Calls `f` on the data schema and on every nested one, allowing to modify them in place.
+    ///
+    /// The data schemas are visited in the same order of [`walk`](Self::walk). The nested data
+    /// schemas are visited after `f` is called on their parent, therefore the changes to the
+    /// `oneOf` alternatives, the array items and the object properties made by `f` are visited as
+    /// well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::thing::DataSchema;
+    /// #
+    /// let mut data_schema: DataSchema<(), (), ()> = serde_json::from_value(json!({
+    ///     "type": "object",
+    ///     "description": "A position",
+    ///     "properties": {
+    ///         "x": { "type": "number", "description": "The abscissa" },
+    ///         "y": { "type": "number", "description": "The ordinate" },
+    ///     },
+    /// }))
+    /// .unwrap();
+    ///
+    /// data_schema.walk_mut(&mut |data_schema| data_schema.description = None);
+    ///
+    /// let mut descriptions = 0;
+    /// data_schema.walk(&mut |data_schema| {
+    ///     descriptions += usize::from(data_schema.description.is_some())
+    /// });
+    /// assert_eq!(descriptions, 0);
+    /// ```
+    pub fn walk_mut<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(&mut DataSchema<DS, AS, OS>),
+    {
+        f(self);
+        self.one_of
+            .iter_mut()
+            .flatten()
+            .for_each(|one_of| one_of.walk_mut(f));
+
+        match &mut self.subtype {
+            Some(DataSchemaSubtype::Array(array)) => match &mut array.items {
+                Some(BoxedElemOrVec::Elem(item)) => item.walk_mut(f),
+                Some(BoxedElemOrVec::Vec(items)) => {
+                    items.iter_mut().for_each(|item| item.walk_mut(f))
+                }
+                None => {}
+            },
+            Some(DataSchemaSubtype::Object(object)) => object
+                .properties
+                .iter_mut()
+                .flat_map(|properties| properties.values_mut())
+                .for_each(|property| property.walk_mut(f)),
+            _ => {}
+        }
+    }
 
-    use super::*;
+    /// Returns `true` if the data schemas accept the same values in the same way.
+    ///
+    /// The human-readable members (`title`, `description` and their translations), `@type`,
+    /// `default` and the deprecation are ignored, while the type, the bounds, the properties, the
+    /// items and the other constraints must be the same. The values of `enum` and the `required`
+    /// properties are compared as sets, the `oneOf` alternatives are compared in order. The nested
+    /// data schemas are compared with the same rules.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// # use wot_td::thing::DataSchema;
+    /// #
+    /// let data_schema = |value| serde_json::from_value::<DataSchema<(), (), ()>>(value).unwrap();
+    ///
+    /// let level = data_schema(json!({ "title": "Level", "type": "integer", "maximum": 100 }));
+    /// let brightness = data_schema(json!({
+    ///     "title": "Brightness",
+    ///     "type": "integer",
+    ///     "maximum": 100,
+    ///     "default": 50,
+    /// }));
+    /// assert!(level.same_shape(&brightness));
+    ///
+    /// let volume = data_schema(json!({ "title": "Level", "type": "integer", "maximum": 11 }));
+    /// assert!(!level.same_shape(&volume));
+    /// ```
+    pub fn same_shape(&self, other: &Self) -> bool
+    where
+        DS: PartialEq,
+        AS: PartialEq,
+        OS: PartialEq,
+    {
+        fn same_set<T: PartialEq>(a: Option<&[T]>, b: Option<&[T]>) -> bool {
+            match (a, b) {
+                (Some(a), Some(b)) => {
+                    a.iter().all(|x| b.contains(x)) && b.iter().all(|x| a.contains(x))
+                }
+                (None, None) => true,
+                _ => false,
+            }
+        }
 
-    use pretty_assertions::assert_eq;
+        let same_one_of = match (&self.one_of, &other.one_of) {
+            (Some(a), Some(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.same_shape(b))
+            }
+            (None, None) => true,
+            _ => false,
+        };
 
-    #[test]
-    fn minimal_thing() {
-        const RAW: &str = r#"
-        {
-            "@context": "https://www.w3.org/2022/wot/td/v1.1",
-            "id": "urn:dev:ops:32473-WoTLamp-1234",
-            "title": "MyLampThing",
-            "securityDefinitions": {
-                "nosec": {"scheme": "nosec"}
-            },
-            "security": ["nosec"]
-        }"#;
+        let same_subtype = match (&self.subtype, &other.subtype) {
+            (Some(DataSchemaSubtype::Array(a)), Some(DataSchemaSubtype::Array(b))) => {
+                let same_items = match (&a.items, &b.items) {
+                    (Some(BoxedElemOrVec::Elem(a)), Some(BoxedElemOrVec::Elem(b))) => {
+                        a.same_shape(b)
+                    }
+                    (Some(BoxedElemOrVec::Vec(a)), Some(BoxedElemOrVec::Vec(b))) => {
+                        a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.same_shape(b))
+                    }
+                    (None, None) => true,
+                    _ => false,
+                };
 
-        let expected_thing = Thing {
-            context: TD_CONTEXT_11.into(),
-            id: Some("urn:dev:ops:32473-WoTLamp-1234".to_string()),
-            title: "MyLampThing".to_string(),
-            security_definitions: [("nosec".to_string(), SecurityScheme::default())]
-                .into_iter()
-                .collect(),
-            security: vec!["nosec".to_string()],
-            ..Default::default()
+                same_items
+                    && a.min_items == b.min_items
+                    && a.max_items == b.max_items
+                    && a.other == b.other
+            }
+            (Some(DataSchemaSubtype::Object(a)), Some(DataSchemaSubtype::Object(b))) => {
+                let same_properties = match (&a.properties, &b.properties) {
+                    (Some(a), Some(b)) => {
+                        a.len() == b.len()
+                            && a.iter()
+                                .all(|(name, a)| b.get(name).is_some_and(|b| a.same_shape(b)))
+                    }
+                    (None, None) => true,
+                    _ => false,
+                };
+
+                same_properties
+                    && same_set(a.required.as_deref(), b.required.as_deref())
+                    && a.other == b.other
+            }
+            (a, b) => a == b,
         };
 
-        let thing: Thing = serde_json::from_str(RAW).unwrap();
-        assert_eq!(thing, expected_thing);
+        same_subtype
+            && same_one_of
+            && same_set(self.enumeration.as_deref(), other.enumeration.as_deref())
+            && self.constant == other.constant
+            && self.unit == other.unit
+            && self.format == other.format
+            && self.read_only == other.read_only
+            && self.write_only == other.write_only
+            && self.other == other.other
+            && self.raw_fields == other.raw_fields
+    }
+}
 
-        let thing: Thing = serde_json::from_value(serde_json::to_value(thing).unwrap()).unwrap();
-        assert_eq!(thing, expected_thing);
+/// Converts a data schema without extensions into its JSON representation.
+///
+/// It is a shortcut for [`serde_json::to_value`]; the data schemas with extensions need to use
+/// it directly.
+///
+/// # Example
+///
+/// ```
+/// # use serde_json::{json, Value};
+/// # use wot_td::{hlist::Nil, thing::DataSchema};
+/// #
+/// let data_schema: DataSchema<Nil, Nil, Nil> = json!({ "type": "boolean" }).try_into().unwrap();
+/// let value = Value::from(data_schema);
+///
+/// assert_eq!(value["type"], "boolean");
+/// ```
+impl From<DataSchema<Nil, Nil, Nil>> for Value {
+    fn from(data_schema: DataSchema<Nil, Nil, Nil>) -> Self {
+        serde_json::to_value(data_schema)
+            .expect("a data schema without extensions can always be serialized")
     }
+}
 
-    #[test]
-    fn complete_thing() {
-        const RAW: &str = r#"
-        {
-          "@context": "https://www.w3.org/2022/wot/td/v1.1",
-          "id": "urn:dev:ops:32473-WoTLamp-1234",
-          "@type": [
-            "Thing",
-            "LampThing"
-          ],
-          "title": "MyLampThing",
-          "titles": {
-            "en": "MyLampThing",
-            "it": "La mia lampada intelligente"
-          },
-          "description": "A simple smart lamp",
-          "descriptions": {
-            "en": "A simple smart lamp",
-            "it": "Una semplice lampada intelligente"
-          },
-          "version": {
-            "instance": "0.1.0",
-            "model": "model"
-          },
-          "created": "2022-05-01T10:20:42.123Z",
-          "modified": "2022-05-10T12:30:00.000+01:00",
-          "support": "mailto:mail@test.com",
-          "base": "https://mylamp.example.com/",
-          "properties": {
-            "status": {
-              "type": "string",
-              "forms": [
-                {
-                  "href": "https://mylamp.example.com/status"
-                }
-              ]
-            }
-          },
-          "actions": {
-            "toggle": {
-              "forms": [
-                {
-                  "href": "https://mylamp.example.com/toggle"
-                }
-              ],
-              "synchronous": false
-            }
-          },
-          "events": {
-            "overheating": {
-              "data": {
-                "type": "string"
-              },
-              "forms": [
-                {
-                  "href": "https://mylamp.example.com/oh",
-                  "subprotocol": "longpoll"
+/// Parses a data schema without extensions from its JSON representation.
+///
+/// It is a shortcut for [`serde_json::from_value`]; the data schemas with extensions need to use
+/// it directly.
+impl TryFrom<Value> for DataSchema<Nil, Nil, Nil> {
+    type Error = serde_json::Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        serde_json::from_value(value)
+    }
+}
+
+pub(crate) type DataSchemaFromOther<Other> = DataSchema<
+    <Other as ExtendableThing>::DataSchema,
+    <Other as ExtendableThing>::ArraySchema,
+    <Other as ExtendableThing>::ObjectSchema,
+>;
+
+/// A JSON-based data schema subtype.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+#[cfg_attr(
+    feature = "preserve-unknown",
+    serde(bound(deserialize = "DS: Serialize + serde::de::DeserializeOwned, \
+                               AS: Serialize + serde::de::DeserializeOwned, \
+                               OS: Serialize + serde::de::DeserializeOwned"))
+)]
+pub enum DataSchemaSubtype<DS, AS, OS> {
+    /// A JSON array metadata.
+    Array(ArraySchema<DS, AS, OS>),
+
+    /// A boolean.
+    Boolean,
+
+    /// A number metadata.
+    Number(NumberSchema),
+
+    /// An integer metadata.
+    Integer(IntegerSchema),
+
+    /// A JSON object metadata.
+    Object(ObjectSchema<DS, AS, OS>),
+
+    /// A string metadata.
+    String(StringSchema),
+
+    /// A JSON null.
+    #[default]
+    Null,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+#[cfg_attr(
+    feature = "preserve-unknown",
+    serde(bound(deserialize = "DS: Serialize + serde::de::DeserializeOwned, \
+                               AS: Serialize + serde::de::DeserializeOwned, \
+                               OS: Serialize + serde::de::DeserializeOwned"))
+)]
+pub(crate) enum UncheckedDataSchemaSubtype<DS, AS, OS> {
+    Array(UncheckedArraySchema<DS, AS, OS>),
+    Boolean,
+    Number(NumberSchema),
+    Integer(IntegerSchema),
+    Object(UncheckedObjectSchema<DS, AS, OS>),
+    String(StringSchema),
+    Null,
+}
+
+/// A data schema subtype, which defines the JSON `type` of the values.
+pub(crate) trait HasType {
+    /// Returns whether a JSON value has the `type` of the subtype.
+    fn has_type(&self, value: &Value) -> bool;
+}
+
+macro_rules! impl_has_type {
+    ($($ty:ident),+ $(,)?) => {
+        $(
+            impl<DS, AS, OS> HasType for $ty<DS, AS, OS> {
+                fn has_type(&self, value: &Value) -> bool {
+                    match self {
+                        Self::Array(_) => value.is_array(),
+                        Self::Boolean => value.is_boolean(),
+                        Self::Number(_) => value.is_number(),
+                        Self::Integer(_) => is_integer(value),
+                        Self::Object(_) => value.is_object(),
+                        Self::String(_) => value.is_string(),
+                        Self::Null => value.is_null(),
+                    }
                 }
-              ]
-            }
-          },
-          "links": [
-            {
-              "href": "https://myswitch.example.com/"
-            }
-          ],
-          "forms": [
-            {
-              "href": "https://mylamp.example.com/enumerate",
-              "op": "readallproperties"
-            }
-          ],
-          "schemaDefinitions": {
-              "schema": {
-                  "type": "null"
-              }
-          },
-          "securityDefinitions": {
-            "nosec": {
-              "scheme": "nosec"
-            }
-          },
-          "security": [
-            "nosec"
-          ],
-          "profile": [
-              "profile1",
-              "profile2"
-          ],
-          "uriVariables": {
-            "uriVariable1": {
-              "type": "string"
-            },
-            "uriVariable2": {
-              "type": "number"
             }
-          }
-        }"#;
+        )+
+    };
+}
 
-        let expected_thing = Thing {
-            context: TD_CONTEXT_11.into(),
-            id: Some("urn:dev:ops:32473-WoTLamp-1234".to_string()),
-            attype: Some(vec!["Thing".to_string(), "LampThing".to_string()]),
-            title: "MyLampThing".to_string(),
-            titles: Some(
-                [
-                    ("en".parse().unwrap(), "MyLampThing".to_string()),
-                    (
-                        "it".parse().unwrap(),
-                        "La mia lampada intelligente".to_string(),
-                    ),
-                ]
-                .into_iter()
-                .collect(),
-            ),
-            description: Some("A simple smart lamp".to_string()),
-            descriptions: Some(
-                [
-                    ("en".parse().unwrap(), "A simple smart lamp".to_string()),
-                    (
-                        "it".parse().unwrap(),
-                        "Una semplice lampada intelligente".to_string(),
-                    ),
-                ]
-                .into_iter()
-                .collect(),
-            ),
-            version: Some(VersionInfo {
-                instance: "0.1.0".to_string(),
-                model: Some("model".to_string()),
-            }),
-            created: Some(datetime!(2022-05-01 10:20:42.123 UTC)),
-            modified: Some(datetime!(2022-05-10 12:30 +1)),
-            support: Some("mailto:mail@test.com".to_string()),
-            base: Some("https://mylamp.example.com/".to_string()),
-            properties: Some(
-                [(
-                    "status".to_string(),
-                    PropertyAffordance {
-                        interaction: InteractionAffordance {
-                            forms: vec![Form {
-                                href: "https://mylamp.example.com/status".to_string(),
-                                ..Form::default()
-                            }],
-                            ..Default::default()
-                        },
-                        data_schema: DataSchema {
-                            subtype: Some(DataSchemaSubtype::String(Default::default())),
-                            ..Default::default()
-                        },
-                        ..Default::default()
-                    },
-                )]
-                .into_iter()
-                .collect(),
-            ),
-            actions: Some(
-                [(
-                    "toggle".to_string(),
-                    ActionAffordance {
-                        interaction: InteractionAffordance {
-                            forms: vec![Form {
-                                href: "https://mylamp.example.com/toggle".to_string(),
-                                ..Default::default()
-                            }],
-                            ..Default::default()
-                        },
-                        synchronous: Some(false),
-                        ..Default::default()
-                    },
-                )]
-                .into_iter()
-                .collect(),
-            ),
-            events: Some(
-                [(
-                    "overheating".to_string(),
-                    EventAffordance {
-                        interaction: InteractionAffordance {
-                            forms: vec![Form {
-                                href: "https://mylamp.example.com/oh".to_string(),
-                                subprotocol: Some("longpoll".to_string()),
-                                ..Default::default()
-                            }],
-                            ..Default::default()
-                        },
-                        data: Some(DataSchema {
-                            subtype: Some(DataSchemaSubtype::String(StringSchema::default())),
-                            ..Default::default()
-                        }),
-                        ..Default::default()
-                    },
-                )]
-                .into_iter()
-                .collect(),
-            ),
-            links: Some(vec![Link {
-                href: "https://myswitch.example.com/".to_string(),
-                ..Default::default()
-            }]),
-            forms: Some(vec![Form {
-                op: DefaultedFormOperations::Custom(vec![FormOperation::ReadAllProperties]),
-                href: "https://mylamp.example.com/enumerate".to_string(),
-                ..Default::default()
-            }]),
-            schema_definitions: Some(
-                [(
-                    "schema".to_string(),
-                    DataSchema {
-                        subtype: Some(DataSchemaSubtype::Null),
-                        ..Default::default()
-                    },
-                )]
-                .into_iter()
-                .collect(),
-            ),
-            security_definitions: [("nosec".to_string(), SecurityScheme::default())]
-                .into_iter()
-                .collect(),
-            security: vec!["nosec".to_string()],
-            profile: Some(vec!["profile1".to_string(), "profile2".to_string()]),
-            uri_variables: Some(
-                [
-                    (
-                        "uriVariable1".to_string(),
-                        DataSchema {
-                            subtype: Some(DataSchemaSubtype::String(Default::default())),
-                            ..Default::default()
-                        },
-                    ),
-                    (
-                        "uriVariable2".to_string(),
-                        DataSchema {
-                            subtype: Some(DataSchemaSubtype::Number(Default::default())),
-                            ..Default::default()
-                        },
-                    ),
-                ]
-                .into_iter()
-                .collect(),
-            ),
-            ..Default::default()
+impl_has_type!(DataSchemaSubtype, UncheckedDataSchemaSubtype);
+
+/// A JSON Schema integer is any number without a fractional part, including `1.0`.
+fn is_integer(value: &Value) -> bool {
+    value
+        .as_f64()
+        .is_some_and(|number| number.is_finite() && number % 1. == 0.)
+}
+
+/// A JSON array metadata.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(bound(serialize = "DS: Serialize, AS: Serialize, OS: Serialize"))]
+#[cfg_attr(
+    not(feature = "preserve-unknown"),
+    serde(bound(
+        deserialize = "DS: Deserialize<'de>, AS: Deserialize<'de>, OS: Deserialize<'de>"
+    ))
+)]
+#[cfg_attr(
+    feature = "preserve-unknown",
+    serde(bound(deserialize = "DS: Serialize + serde::de::DeserializeOwned, \
+                               AS: Serialize + serde::de::DeserializeOwned, \
+                               OS: Serialize + serde::de::DeserializeOwned"))
+)]
+pub struct ArraySchema<DS, AS, OS> {
+    /// The characteristics of the JSON array.
+    ///
+    /// An item has a different semantic than a `Vec` of one item:
+    ///
+    /// - a _single_ item `T` represents an array of element where every element must follow the
+    /// schema defined by `T`;
+    /// - a `Vec` of one element `T` represent a tuple of one single element, which must follow the
+    /// schema defined by `T`.
+    ///
+    /// In general, using a `Vec` of data schemas expresses a tuple of elements with a 1:1
+    /// correspondence.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<BoxedElemOrVec<DataSchema<DS, AS, OS>>>,
+
+    /// The minimum number of items that have to be in the JSON array.
+    pub min_items: Option<u32>,
+
+    /// The maximum number of items that have to be in the JSON array.
+    pub max_items: Option<u32>,
+
+    /// Array schema extension.
+    #[serde(flatten)]
+    pub other: AS,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BoxedElemOrVec<T> {
+    Elem(Box<T>),
+    Vec(Vec<T>),
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(bound(serialize = "DS: Serialize, AS: Serialize, OS: Serialize"))]
+#[cfg_attr(
+    not(feature = "preserve-unknown"),
+    serde(bound(
+        deserialize = "DS: Deserialize<'de>, AS: Deserialize<'de>, OS: Deserialize<'de>"
+    ))
+)]
+#[cfg_attr(
+    feature = "preserve-unknown",
+    serde(bound(deserialize = "DS: Serialize + serde::de::DeserializeOwned, \
+                               AS: Serialize + serde::de::DeserializeOwned, \
+                               OS: Serialize + serde::de::DeserializeOwned"))
+)]
+pub(crate) struct UncheckedArraySchema<DS, AS, OS> {
+    pub(crate) items: Option<BoxedElemOrVec<UncheckedDataSchema<DS, AS, OS>>>,
+    pub(crate) min_items: Option<u32>,
+    pub(crate) max_items: Option<u32>,
+    #[serde(flatten)]
+    pub(crate) other: AS,
+}
+
+impl<DS, AS, OS> Default for ArraySchema<DS, AS, OS>
+where
+    AS: Default,
+{
+    fn default() -> Self {
+        Self {
+            items: Default::default(),
+            min_items: Default::default(),
+            max_items: Default::default(),
+            other: Default::default(),
+        }
+    }
+}
+
+impl<DS, AS, OS> ArraySchema<DS, AS, OS> {
+    /// Returns whether the array is a tuple, in which every item has its own data schema.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     builder::data_schema::{SpecializableDataSchema, TupleDataSchemaBuilderLike},
+    /// #     thing::{DataSchemaSubtype, Thing},
+    /// # };
+    /// #
+    /// let thing = Thing::builder("Thing name")
+    ///     .finish_extend()
+    ///     .schema_definition("point", |b| {
+    ///         b.finish_extend()
+    ///             .tuple()
+    ///             .append(|b| b.finish_extend().number())
+    ///             .append(|b| b.finish_extend().number())
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let point = &thing.schema_definitions.as_ref().unwrap()["point"];
+    /// let Some(DataSchemaSubtype::Array(array)) = &point.subtype else {
+    ///     unreachable!()
+    /// };
+    /// assert!(array.is_tuple());
+    /// assert_eq!(array.item_schemas().count(), 2);
+    /// ```
+    #[inline]
+    pub fn is_tuple(&self) -> bool {
+        matches!(self.items, Some(BoxedElemOrVec::Vec(_)))
+    }
+
+    /// Returns the data schemas of the items.
+    ///
+    /// It yields the only data schema of a vec, the data schemas of a tuple in order, or nothing
+    /// if the items are not specified.
+    pub fn item_schemas(&self) -> impl Iterator<Item = &DataSchema<DS, AS, OS>> {
+        let items = match &self.items {
+            Some(BoxedElemOrVec::Elem(item)) => core::slice::from_ref(&**item),
+            Some(BoxedElemOrVec::Vec(items)) => items.as_slice(),
+            None => &[],
+        };
+        items.iter()
+    }
+
+    /// Turns a tuple into a homogeneous list, collapsing the data schemas of its items.
+    ///
+    /// The array is returned unchanged if it is not a tuple, and an empty tuple becomes an array
+    /// without `items`. The items following the ones of the tuple are not constrained by a tuple,
+    /// while they must follow the data schema of a list.
+    ///
+    /// # Errors
+    ///
+    /// It fails with [`DataSchemaError::HeterogeneousTuple`] if the items of the tuple do not have the
+    /// same data schema.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     hlist::Nil,
+    /// #     thing::{ArraySchema, BoxedElemOrVec, DataSchema},
+    /// # };
+    /// #
+    /// let tuple = ArraySchema::<Nil, Nil, Nil> {
+    ///     items: Some(BoxedElemOrVec::Vec(vec![DataSchema::default(); 3])),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let list = tuple.into_vec().unwrap();
+    /// assert!(matches!(list.items, Some(BoxedElemOrVec::Elem(_))));
+    /// ```
+    pub fn into_vec(self) -> Result<Self, Error>
+    where
+        DataSchema<DS, AS, OS>: PartialEq,
+    {
+        let Some(BoxedElemOrVec::Vec(mut items)) = self.items else {
+            return Ok(self);
         };
 
-        let thing: Thing = serde_json::from_str(RAW).unwrap();
-        assert_eq!(thing, expected_thing);
+        if items.windows(2).any(|pair| pair[0] != pair[1]) {
+            return Err(DataSchemaError::HeterogeneousTuple.into());
+        }
+
+        items.truncate(1);
+        Ok(Self {
+            items: items.pop().map(|item| BoxedElemOrVec::Elem(Box::new(item))),
+            ..self
+        })
+    }
+
+    /// Turns a homogeneous list into a tuple of `len` items, repeating the data schema of the
+    /// items.
+    ///
+    /// The array is returned unchanged if it is already a tuple, or if it does not specify the
+    /// data schema of its items.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{
+    /// #     hlist::Nil,
+    /// #     thing::{ArraySchema, BoxedElemOrVec, DataSchema},
+    /// # };
+    /// #
+    /// let list = ArraySchema::<Nil, Nil, Nil> {
+    ///     items: Some(BoxedElemOrVec::Elem(Box::default())),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let tuple = list.into_tuple(2);
+    /// assert!(tuple.is_tuple());
+    /// assert_eq!(tuple.item_schemas().count(), 2);
+    /// ```
+    pub fn into_tuple(self, len: usize) -> Self
+    where
+        DataSchema<DS, AS, OS>: Clone,
+    {
+        match self.items {
+            Some(BoxedElemOrVec::Elem(item)) => Self {
+                items: Some(BoxedElemOrVec::Vec(vec![*item; len])),
+                ..self
+            },
+            _ => self,
+        }
+    }
+}
+
+/// A helper enum to represent an inclusive or exclusive maximum value.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum Maximum<T> {
+    /// An inclusive maximum value.
+    #[serde(rename = "maximum")]
+    Inclusive(T),
+
+    /// An exclusive maximum value.
+    #[serde(rename = "exclusiveMaximum")]
+    Exclusive(T),
+}
+
+/// A helper enum to represent an inclusive or exclusive minimum value.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum Minimum<T> {
+    /// An inclusive minimum value.
+    #[serde(rename = "minimum")]
+    Inclusive(T),
+
+    /// An exclusive minimum value.
+    #[serde(rename = "exclusiveMinimum")]
+    Exclusive(T),
+}
+
+/// The exclusive sibling of a bound, in the forms used by the different JSON Schema versions.
+///
+/// TD 1.1 and JSON Schema 2020-12 use the exclusive bound value, JSON Schema draft 4 uses a
+/// boolean that makes the inclusive sibling exclusive.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ExclusiveBound<T> {
+    Value(T),
+    Modifier(bool),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawMinimum<T> {
+    minimum: Option<T>,
+    exclusive_minimum: Option<ExclusiveBound<T>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawMaximum<T> {
+    maximum: Option<T>,
+    exclusive_maximum: Option<ExclusiveBound<T>>,
+}
+
+/// Resolves a bound given in both the inclusive and the exclusive forms.
+///
+/// The tighter bound is kept, which is the one ordered as `tighter` with respect to the other. On
+/// equal values the exclusive bound is tighter. Only incomparable values, like a NaN produced by a
+/// non-JSON deserializer, are a conflict.
+fn resolve_bound<T, B, E>(
+    name: &str,
+    inclusive: Option<T>,
+    exclusive: Option<ExclusiveBound<T>>,
+    to_inclusive: fn(T) -> B,
+    to_exclusive: fn(T) -> B,
+    tighter: Ordering,
+) -> Result<Option<B>, E>
+where
+    T: PartialOrd,
+    E: serde::de::Error,
+{
+    use ExclusiveBound::*;
+
+    match (inclusive, exclusive) {
+        (Some(inclusive), Some(Value(exclusive))) => match exclusive.partial_cmp(&inclusive) {
+            Some(Ordering::Equal) => Ok(Some(to_exclusive(exclusive))),
+            Some(ordering) if ordering == tighter => Ok(Some(to_exclusive(exclusive))),
+            Some(_) => Ok(Some(to_inclusive(inclusive))),
+            None => Err(E::custom(Error::DataSchema(
+                DataSchemaError::AmbiguousBound(name.to_string()),
+            ))),
+        },
+        (Some(x), Some(Modifier(true))) | (None, Some(Value(x))) => Ok(Some(to_exclusive(x))),
+        (Some(x), None | Some(Modifier(false))) => Ok(Some(to_inclusive(x))),
+        (None, None | Some(Modifier(_))) => Ok(None),
+    }
+}
+
+fn deserialize_minimum<'de, D, T>(deserializer: D) -> Result<Option<Minimum<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + PartialOrd,
+{
+    let RawMinimum {
+        minimum,
+        exclusive_minimum,
+    } = RawMinimum::deserialize(deserializer)?;
+    resolve_bound(
+        "minimum",
+        minimum,
+        exclusive_minimum,
+        Minimum::Inclusive,
+        Minimum::Exclusive,
+        Ordering::Greater,
+    )
+}
+
+fn deserialize_maximum<'de, D, T>(deserializer: D) -> Result<Option<Maximum<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + PartialOrd,
+{
+    let RawMaximum {
+        maximum,
+        exclusive_maximum,
+    } = RawMaximum::deserialize(deserializer)?;
+    resolve_bound(
+        "maximum",
+        maximum,
+        exclusive_maximum,
+        Maximum::Inclusive,
+        Maximum::Exclusive,
+        Ordering::Less,
+    )
+}
+
+impl<T> PartialOrd for Minimum<T>
+where
+    T: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        match (self, other) {
+            (Minimum::Inclusive(a), Minimum::Inclusive(b))
+            | (Minimum::Exclusive(a), Minimum::Exclusive(b)) => a.partial_cmp(b),
+            (Minimum::Inclusive(a), Minimum::Exclusive(b)) => {
+                a.partial_cmp(b).and_then(|ord| match ord {
+                    Ordering::Less | Ordering::Equal => Some(Ordering::Less),
+                    Ordering::Greater => None,
+                })
+            }
+            (Minimum::Exclusive(a), Minimum::Inclusive(b)) => {
+                a.partial_cmp(b).and_then(|ord| match ord {
+                    Ordering::Less => None,
+                    Ordering::Equal | Ordering::Greater => Some(Ordering::Greater),
+                })
+            }
+        }
+    }
+}
+
+impl<T> PartialOrd for Maximum<T>
+where
+    T: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Maximum::Inclusive(a), Maximum::Inclusive(b))
+            | (Maximum::Exclusive(a), Maximum::Exclusive(b)) => a.partial_cmp(b),
+
+            (Maximum::Inclusive(a), Maximum::Exclusive(b)) => {
+                a.partial_cmp(b).and_then(|ord| match ord {
+                    Ordering::Less => None,
+                    Ordering::Equal | Ordering::Greater => Some(Ordering::Greater),
+                })
+            }
+
+            (Maximum::Exclusive(a), Maximum::Inclusive(b)) => {
+                a.partial_cmp(b).and_then(|ord| match ord {
+                    Ordering::Less | Ordering::Equal => Some(Ordering::Less),
+                    Ordering::Greater => None,
+                })
+            }
+        }
+    }
+}
+
+impl<T> PartialEq<Maximum<T>> for Minimum<T>
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Maximum<T>) -> bool {
+        match (self, other) {
+            (Minimum::Inclusive(a), Maximum::Inclusive(b))
+            | (Minimum::Exclusive(a), Maximum::Exclusive(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<T> PartialEq<Minimum<T>> for Maximum<T>
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Minimum<T>) -> bool {
+        other == self
+    }
+}
+
+impl<T> PartialOrd<Maximum<T>> for Minimum<T>
+where
+    T: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Maximum<T>) -> Option<cmp::Ordering> {
+        match (self, other) {
+            (Minimum::Inclusive(a), Maximum::Inclusive(b))
+            | (Minimum::Exclusive(a), Maximum::Exclusive(b)) => a.partial_cmp(b),
+
+            (Minimum::Exclusive(a), Maximum::Inclusive(b))
+            | (Minimum::Inclusive(a), Maximum::Exclusive(b)) => {
+                a.partial_cmp(b).and_then(|ord| match ord {
+                    Ordering::Less => None,
+                    Ordering::Equal | Ordering::Greater => Some(Ordering::Greater),
+                })
+            }
+        }
+    }
+}
+
+impl<T> PartialOrd<Minimum<T>> for Maximum<T>
+where
+    T: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Minimum<T>) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+macro_rules! impl_minmax_float {
+    (@ $ty:ident $float_type:ty) => {
+        impl $ty<$float_type> {
+            /// Returns `true` if value is `NaN`.
+            pub fn is_nan(&self) -> bool {
+                match self {
+                    Self::Inclusive(x) => x.is_nan(),
+                    Self::Exclusive(x) => x.is_nan(),
+                }
+            }
+
+            /// Returns `true` if value is positive or negative infinity.
+            pub fn is_infinite(&self) -> bool {
+                match self {
+                    Self::Inclusive(x) => x.is_infinite(),
+                    Self::Exclusive(x) => x.is_infinite(),
+                }
+            }
+        }
+    };
+
+    ($($float_type:ty),*) => {
+        $(
+            impl_minmax_float!(@ Minimum $float_type);
+            impl_minmax_float!(@ Maximum $float_type);
+        )*
+    };
+}
+
+impl_minmax_float!(f32, f64);
+
+/// A number metadata.
+///
+/// Besides the TD form, the bounds are deserialized from the JSON Schema draft 4 form, where
+/// `exclusiveMinimum` and `exclusiveMaximum` are booleans modifying `minimum` and `maximum`.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NumberSchema {
+    /// The higher limit of the value.
+    #[serde(flatten, deserialize_with = "deserialize_maximum")]
+    pub maximum: Option<Maximum<f64>>,
+
+    /// The lower limit of the value.
+    #[serde(flatten, deserialize_with = "deserialize_minimum")]
+    pub minimum: Option<Minimum<f64>>,
+
+    /// It adds the requirement that the numeric value must be a multiple of this.
+    pub multiple_of: Option<f64>,
+}
+
+impl NumberSchema {
+    /// Returns the equivalent [`IntegerSchema`], if any.
+    ///
+    /// A number schema is equivalent to an integer schema only when `multiple_of` is a positive
+    /// integer value and the bounds, when present, are integer values that fit an `i64`.
+    pub fn to_integer(&self) -> Option<IntegerSchema> {
+        let multiple_of = self
+            .multiple_of
+            .and_then(f64_to_i64_exact)
+            .and_then(|multiple_of| u64::try_from(multiple_of).ok())
+            .and_then(NonZeroU64::new)?;
+
+        let minimum = self
+            .minimum
+            .map(|minimum| match minimum {
+                Minimum::Inclusive(x) => f64_to_i64_exact(x).map(Minimum::Inclusive),
+                Minimum::Exclusive(x) => f64_to_i64_exact(x).map(Minimum::Exclusive),
+            })
+            .map_or(Some(None), |minimum| minimum.map(Some))?;
+
+        let maximum = self
+            .maximum
+            .map(|maximum| match maximum {
+                Maximum::Inclusive(x) => f64_to_i64_exact(x).map(Maximum::Inclusive),
+                Maximum::Exclusive(x) => f64_to_i64_exact(x).map(Maximum::Exclusive),
+            })
+            .map_or(Some(None), |maximum| maximum.map(Some))?;
+
+        Some(IntegerSchema {
+            maximum,
+            minimum,
+            multiple_of: Some(multiple_of),
+        })
+    }
+
+    /// Checks whether `value` is a multiple of `multiple_of`, returning `None` when it is not set.
+    ///
+    /// A naive `value % multiple_of == 0.` is wrong for most decimal steps, because values like
+    /// `0.1` cannot be represented exactly: `0.3 % 0.1` is about `0.1`. Therefore:
+    ///
+    /// 1. when the shortest decimal representation of `multiple_of` has at most 15 significant
+    ///    digits, both numbers are converted to their shortest decimal representation, scaled to
+    ///    integers by the same power of ten and divided exactly;
+    /// 2. otherwise the quotient `value / multiple_of` must be within a relative epsilon
+    ///    (`4 * f64::EPSILON`) of an integer.
+    ///
+    /// Non-finite values are never multiples, while zero is a multiple of anything.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::thing::NumberSchema;
+    /// #
+    /// let number = NumberSchema {
+    ///     multiple_of: Some(0.1),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(number.is_multiple(0.3), Some(true));
+    /// assert_eq!(number.is_multiple(0.35), Some(false));
+    /// assert_eq!(NumberSchema::default().is_multiple(0.3), None);
+    /// ```
+    pub fn is_multiple(&self, value: f64) -> Option<bool> {
+        self.multiple_of
+            .map(|multiple_of| is_multiple_of(value, multiple_of))
+    }
+}
+
+impl NumberSchema {
+    /// Returns a number schema with the bounds transformed by `f`.
+    ///
+    /// This is meant to convert the range of values between units of measurement. The function
+    /// must be monotonic: when it is decreasing, the minimum and the maximum are swapped,
+    /// preserving their exclusiveness.
+    ///
+    /// `multiple_of` can only be transformed when `f` is a pure scaling. The mapping is probed
+    /// evaluating `f(0)` and `f(1)`: if `f(0)` is not zero (as for affine conversions like Celsius
+    /// to Fahrenheit), the returned `multiple_of` is `None`; otherwise it is scaled by `|f(1)|`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::thing::{Maximum, Minimum, NumberSchema};
+    /// #
+    /// let celsius = NumberSchema {
+    ///     minimum: Some(Minimum::Inclusive(0.)),
+    ///     maximum: Some(Maximum::Exclusive(100.)),
+    ///     multiple_of: Some(0.5),
+    /// };
+    ///
+    /// let fahrenheit = celsius.map_bounds(|x| x * 1.8 + 32.);
+    /// assert_eq!(fahrenheit.minimum, Some(Minimum::Inclusive(32.)));
+    /// assert_eq!(fahrenheit.maximum, Some(Maximum::Exclusive(212.)));
+    /// assert_eq!(fahrenheit.multiple_of, None);
+    /// ```
+    pub fn map_bounds(&self, f: impl Fn(f64) -> f64) -> NumberSchema {
+        let origin = f(0.);
+        let scale = f(1.) - origin;
+
+        let map_minimum = |minimum| match minimum {
+            Minimum::Inclusive(x) => Minimum::Inclusive(f(x)),
+            Minimum::Exclusive(x) => Minimum::Exclusive(f(x)),
+        };
+        let map_maximum = |maximum| match maximum {
+            Maximum::Inclusive(x) => Maximum::Inclusive(f(x)),
+            Maximum::Exclusive(x) => Maximum::Exclusive(f(x)),
+        };
+
+        let (minimum, maximum) = if scale < 0. {
+            let minimum = self.maximum.map(|maximum| match map_maximum(maximum) {
+                Maximum::Inclusive(x) => Minimum::Inclusive(x),
+                Maximum::Exclusive(x) => Minimum::Exclusive(x),
+            });
+            let maximum = self.minimum.map(|minimum| match map_minimum(minimum) {
+                Minimum::Inclusive(x) => Maximum::Inclusive(x),
+                Minimum::Exclusive(x) => Maximum::Exclusive(x),
+            });
+            (minimum, maximum)
+        } else {
+            (self.minimum.map(map_minimum), self.maximum.map(map_maximum))
+        };
+
+        let multiple_of = self
+            .multiple_of
+            .filter(|_| origin == 0.)
+            .map(|multiple_of| multiple_of * scale.abs())
+            .filter(|&multiple_of| multiple_of > 0.);
+
+        NumberSchema {
+            maximum,
+            minimum,
+            multiple_of,
+        }
+    }
+}
+
+impl IntegerSchema {
+    /// Returns a number schema with the bounds transformed by `f`.
+    ///
+    /// The result is a [`NumberSchema`] because the transformed bounds are not necessarily
+    /// integers. See [`NumberSchema::map_bounds`] for the details.
+    pub fn map_bounds(&self, f: impl Fn(f64) -> f64) -> NumberSchema {
+        let number = NumberSchema {
+            maximum: self.maximum.map(|maximum| match maximum {
+                Maximum::Inclusive(x) => Maximum::Inclusive(x as f64),
+                Maximum::Exclusive(x) => Maximum::Exclusive(x as f64),
+            }),
+            minimum: self.minimum.map(|minimum| match minimum {
+                Minimum::Inclusive(x) => Minimum::Inclusive(x as f64),
+                Minimum::Exclusive(x) => Minimum::Exclusive(x as f64),
+            }),
+            multiple_of: self.multiple_of.map(|multiple_of| multiple_of.get() as f64),
+        };
+
+        number.map_bounds(f)
+    }
+}
+
+fn f64_to_i64_exact(x: f64) -> Option<i64> {
+    // `i64::MAX as f64` is rounded up to 2^63, which is already out of range.
+    if (i64::MIN as f64..i64::MAX as f64).contains(&x) {
+        let truncated = x as i64;
+        (truncated as f64 == x).then_some(truncated)
+    } else {
+        None
+    }
+}
+
+/// An integer metadata.
+///
+/// Besides the TD form, the bounds are deserialized from the JSON Schema draft 4 form, where
+/// `exclusiveMinimum` and `exclusiveMaximum` are booleans modifying `minimum` and `maximum`.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+// FIXME: we should probably use a Decimal type
+pub struct IntegerSchema {
+    /// The higher limit of the value.
+    #[serde(flatten, deserialize_with = "deserialize_maximum")]
+    pub maximum: Option<Maximum<i64>>,
+
+    /// The lower limit of the value.
+    #[serde(flatten, deserialize_with = "deserialize_minimum")]
+    pub minimum: Option<Minimum<i64>>,
+
+    /// It adds the requirement that the numeric value must be a multiple of this.
+    pub multiple_of: Option<NonZeroU64>,
+}
+
+/// A JSON object metadata.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "preserve-unknown",
+    serde(bound(deserialize = "DS: Serialize + serde::de::DeserializeOwned, \
+                               AS: Serialize + serde::de::DeserializeOwned, \
+                               OS: Serialize + serde::de::DeserializeOwned"))
+)]
+pub struct ObjectSchema<DS, AS, OS> {
+    /// Data schema nested definitions.
+    pub properties: Option<Map<String, DataSchema<DS, AS, OS>>>,
+
+    /// Defines which members of the object type are mandatory.
+    pub required: Option<Vec<String>>,
+
+    /// Object schema extension.
+    #[serde(flatten)]
+    pub other: OS,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "preserve-unknown",
+    serde(bound(deserialize = "DS: Serialize + serde::de::DeserializeOwned, \
+                               AS: Serialize + serde::de::DeserializeOwned, \
+                               OS: Serialize + serde::de::DeserializeOwned"))
+)]
+pub(crate) struct UncheckedObjectSchema<DS, AS, OS> {
+    pub(crate) properties: Option<Map<String, UncheckedDataSchema<DS, AS, OS>>>,
+    pub(crate) required: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub(crate) other: OS,
+}
+
+impl<DS, AS, OS> Default for ObjectSchema<DS, AS, OS>
+where
+    OS: Default,
+{
+    fn default() -> Self {
+        Self {
+            properties: Default::default(),
+            required: Default::default(),
+            other: Default::default(),
+        }
+    }
+}
+
+impl<DS, AS, OS> Default for UncheckedObjectSchema<DS, AS, OS>
+where
+    OS: Default,
+{
+    fn default() -> Self {
+        Self {
+            properties: Default::default(),
+            required: Default::default(),
+            other: Default::default(),
+        }
+    }
+}
+
+/// A string metadata
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StringSchema {
+    /// The minimum length of a string.
+    pub min_length: Option<u32>,
+
+    /// The maximum length of a string.
+    pub max_length: Option<u32>,
+
+    /// A regular expression to express constraints of the string value. The regular expression
+    /// must follow the [ECMA-262](https://www.w3.org/TR/wot-thing-description11/#bib-ecma-262)
+    /// dialect.
+    // TODO: this should be a validated against EcmaScript dialect of regexes
+    pub pattern: Option<String>,
+
+    /// The encoding used to store the contents, as specified in [RFC
+    /// 2045](https://www.rfc-editor.org/rfc/rfc2045).
+    // TODO: this should be validated against RFC 2045
+    pub content_encoding: Option<String>,
+
+    /// the MIME type of the contents of a string value, as described in [RFC
+    /// 2046](https://www.rfc-editor.org/rfc/rfc2046).
+    // TODO: this should be validated against RFC 2046
+    pub content_media_type: Option<String>,
+}
+
+impl StringSchema {
+    /// Returns the typed `content_encoding`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::thing::{ContentEncoding, StringSchema};
+    /// #
+    /// let string = StringSchema {
+    ///     content_encoding: Some("BASE64".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(string.encoding_known(), Some(ContentEncoding::Base64));
+    /// ```
+    pub fn encoding_known(&self) -> Option<ContentEncoding> {
+        self.content_encoding.as_deref().map(ContentEncoding::from)
+    }
+}
+
+/// The encoding of the contents of a string value.
+///
+/// The known variants are the ones registered by [RFC
+/// 2045](https://www.rfc-editor.org/rfc/rfc2045) and [RFC
+/// 4648](https://www.rfc-editor.org/rfc/rfc4648). They are parsed ignoring the case.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContentEncoding {
+    /// `7bit`
+    SevenBit,
+
+    /// `8bit`
+    EightBit,
+
+    /// `binary`
+    Binary,
+
+    /// `quoted-printable`
+    QuotedPrintable,
+
+    /// `base16`
+    Base16,
+
+    /// `base32`
+    Base32,
+
+    /// `base64`
+    Base64,
+
+    /// An encoding outside the registered set.
+    Other(String),
+}
+
+impl ContentEncoding {
+    /// Returns the name of the encoding.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::SevenBit => "7bit",
+            Self::EightBit => "8bit",
+            Self::Binary => "binary",
+            Self::QuotedPrintable => "quoted-printable",
+            Self::Base16 => "base16",
+            Self::Base32 => "base32",
+            Self::Base64 => "base64",
+            Self::Other(encoding) => encoding,
+        }
+    }
+}
+
+impl From<&str> for ContentEncoding {
+    fn from(value: &str) -> Self {
+        [
+            Self::SevenBit,
+            Self::EightBit,
+            Self::Binary,
+            Self::QuotedPrintable,
+            Self::Base16,
+            Self::Base32,
+            Self::Base64,
+        ]
+        .into_iter()
+        .find(|encoding| encoding.as_str().eq_ignore_ascii_case(value))
+        .unwrap_or_else(|| Self::Other(value.to_string()))
+    }
+}
+
+impl fmt::Display for ContentEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The configuration of a security mechanism.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SecurityScheme {
+    /// JSON-LD keyword to label the object with semantic tags or types.
+    #[serde(rename = "@type", default)]
+    #[serde_as(as = "Option<StringOrArray>")]
+    pub attype: Option<Vec<AtType>>,
+
+    /// Human-readable additional information
+    pub description: Option<String>,
+
+    /// Multi-language translations of the description
+    pub descriptions: Option<MultiLanguage>,
+
+    /// URI of the proxy server this security configuration provides access to. If `None`, the
+    /// corresponding security configuration is for the endpoint.
+    // FIXME: use AnyURI
+    pub proxy: Option<String>,
+
+    /// The security scheme subtype.
+    #[serde(flatten)]
+    pub subtype: SecuritySchemeSubtype,
+}
+
+impl SecurityScheme {
+    /// Returns the URI of the proxy server this security configuration provides access to.
+    ///
+    /// It is `None` when the security configuration is for the endpoint, whatever the scheme.
+    #[inline]
+    pub fn proxy(&self) -> Option<&str> {
+        self.proxy.as_deref()
+    }
+}
+
+/// A pre-defined security scheme subtype.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(tag = "scheme", rename_all = "lowercase")]
+pub enum KnownSecuritySchemeSubtype {
+    /// No authentication or other mechanism required to access the resource.
+    #[default]
+    NoSec,
+
+    /// The security parameters are going to be negotiated by the underlying protocols at runtime.
+    Auto,
+
+    /// A combination of security schemes.
+    Combo(ComboSecurityScheme),
+
+    /// Basic Authentication ([RFC7617](https://httpwg.org/specs/rfc7617.html)) security
+    /// configuration
+    Basic(BasicSecurityScheme),
+
+    /// Digest Access Authentication ([RFC7616](https://httpwg.org/specs/rfc7616.html)) security
+    /// configuration
+    Digest(DigestSecurityScheme),
+
+    /// Bearer Token ([RFC6750](https://www.rfc-editor.org/rfc/rfc6750)) security configuration
+    Bearer(BearerSecurityScheme),
+
+    /// Pre-shared key authentication security configuration.
+    Psk(PskSecurityScheme),
+
+    /// OAuth 2.0 authentication security configuration for systems conformant with
+    /// [RFC6749](https://www.rfc-editor.org/rfc/rfc6749),
+    /// [RFC8252](https://www.rfc-editor.org/rfc/rfc8252) and (for the device flow)
+    /// [RFC8628](https://www.rfc-editor.org/rfc/rfc8628)
+    OAuth2(OAuth2SecurityScheme),
+
+    /// API key authentication security configuration.
+    ApiKey(ApiKeySecurityScheme),
+}
+
+/// Custom security scheme subtype
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct UnknownSecuritySchemeSubtype {
+    /// The name of the security scheme.
+    pub scheme: String,
+
+    /// The inner data of the security scheme.
+    #[serde(flatten)]
+    pub data: Value,
+}
+
+/// A security scheme subtype.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum SecuritySchemeSubtype {
+    /// Pre-defined security scheme subtype.
+    Known(KnownSecuritySchemeSubtype),
+
+    /// Custom security scheme subtype
+    Unknown(UnknownSecuritySchemeSubtype),
+}
+
+impl Default for SecuritySchemeSubtype {
+    fn default() -> Self {
+        Self::Known(KnownSecuritySchemeSubtype::default())
+    }
+}
+
+/// A combination of security schemes.
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ComboSecurityScheme {
+    /// Two or more strings identifying other named security scheme definitions, any one of which,
+    /// when satisfied, will allow access.
+    OneOf(#[serde_as(as = "StringOrArray")] Vec<String>),
+
+    /// Two or more strings identifying other named security scheme definitions, all of which must
+    /// be satisfied for access.
+    AllOf(#[serde_as(as = "StringOrArray")] Vec<String>),
+}
+
+/// Basic Authentication ([RFC7617](https://httpwg.org/specs/rfc7617.html)) security configuration
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct BasicSecurityScheme {
+    /// The location of security authentication information.
+    #[serde(rename = "in", default = "SecurityAuthenticationLocation::header")]
+    pub location: SecurityAuthenticationLocation,
+
+    /// Name for query, header, cookie, or uri parameters.
+    pub name: Option<String>,
+}
+
+impl Default for BasicSecurityScheme {
+    fn default() -> Self {
+        Self {
+            location: SecurityAuthenticationLocation::Header,
+            name: Default::default(),
+        }
+    }
+}
+
+/// The location of security authentication information.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SecurityAuthenticationLocation {
+    /// The parameter will be given in a header provided by the protocol, with the name of the
+    /// header provided by the value of `name`.
+    Header,
+
+    /// The parameter will be appended to the URI as a query parameter, with the name of the query
+    /// parameter provided by `name`.
+    Query,
+
+    /// The parameter will be provided in the body of the request payload, with the data schema
+    /// element used provided by `name`.
+    Body,
+
+    /// The parameter is stored in a cookie identified by the value of `name`.
+    Cookie,
+
+    /// The parameter is embedded in the URI itself, which is encoded in the relevant interaction
+    /// using a URI template variable defined by the value of `name`.
+    Uri,
+}
+
+impl SecurityAuthenticationLocation {
+    const fn header() -> Self {
+        Self::Header
+    }
+
+    const fn query() -> Self {
+        Self::Query
+    }
+}
+
+/// Digest Access Authentication ([RFC7616](https://httpwg.org/specs/rfc7616.html)) security configuration
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct DigestSecurityScheme {
+    /// Quality of protection.
+    pub qop: QualityOfProtection,
+
+    /// The location of security authentication information.
+    #[serde(rename = "in", default = "SecurityAuthenticationLocation::header")]
+    pub location: SecurityAuthenticationLocation,
+
+    /// Name for query, header, cookie, or uri parameters.
+    pub name: Option<String>,
+}
+
+impl Default for DigestSecurityScheme {
+    fn default() -> Self {
+        Self {
+            qop: Default::default(),
+            location: SecurityAuthenticationLocation::Header,
+            name: Default::default(),
+        }
+    }
+}
+
+/// Quality of protection, as defined in [RFC2617](https://www.rfc-editor.org/rfc/rfc2617).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum QualityOfProtection {
+    /// Protection by authentication.
+    #[default]
+    Auth,
+
+    /// Protection by authentication with integrity protection.
+    AuthInt,
+}
+
+/// API key authentication security configuration.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ApiKeySecurityScheme {
+    /// The location of security authentication information.
+    #[serde(rename = "in", default = "SecurityAuthenticationLocation::query")]
+    pub location: SecurityAuthenticationLocation,
+
+    /// Name for query, header, cookie, or uri parameters.
+    pub name: Option<String>,
+}
+
+impl Default for ApiKeySecurityScheme {
+    fn default() -> Self {
+        Self {
+            location: SecurityAuthenticationLocation::Query,
+            name: Default::default(),
+        }
+    }
+}
+
+/// Pre-shared key authentication security configuration.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct BearerSecurityScheme {
+    /// URI of the authorization server.
+    // FIXME: use AnyURI
+    pub authorization: Option<String>,
+
+    /// Encoding, encryption, or digest algorithm.
+    #[serde(default = "BearerSecurityScheme::default_alg")]
+    pub alg: Cow<'static, str>,
+
+    /// Format of security authentication information.
+    #[serde(default = "BearerSecurityScheme::default_format")]
+    pub format: Cow<'static, str>,
+
+    /// The location of security authentication information.
+    #[serde(rename = "in", default = "SecurityAuthenticationLocation::header")]
+    pub location: SecurityAuthenticationLocation,
+
+    /// Name for query, header, cookie, or uri parameters.
+    pub name: Option<String>,
+}
+
+impl Default for BearerSecurityScheme {
+    fn default() -> Self {
+        Self {
+            authorization: Default::default(),
+            alg: BearerSecurityScheme::default_alg(),
+            format: BearerSecurityScheme::default_format(),
+            location: SecurityAuthenticationLocation::Header,
+            name: Default::default(),
+        }
+    }
+}
+
+impl BearerSecurityScheme {
+    const fn default_alg() -> Cow<'static, str> {
+        Cow::Borrowed("ES256")
+    }
+
+    const fn default_format() -> Cow<'static, str> {
+        Cow::Borrowed("jwt")
+    }
+}
+
+/// Pre-shared key authentication security configuration.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct PskSecurityScheme {
+    /// Identifier providing information useful for selection or confirmation.
+    pub identity: Option<String>,
+}
+
+/// OAuth 2.0 authentication security configuration for systems conformant with
+/// [RFC6749](https://www.rfc-editor.org/rfc/rfc6749),
+/// [RFC8252](https://www.rfc-editor.org/rfc/rfc8252) and (for the device flow)
+/// [RFC8628](https://www.rfc-editor.org/rfc/rfc8628)
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct OAuth2SecurityScheme {
+    /// URI of the authorization server. In the case of the device flow, the URI provided for the
+    /// authorization value refers to the device authorization endpoint
+    /// ([RFC8628](https://www.rfc-editor.org/rfc/rfc8628)).
+    // FIXME: use AnyURI
+    pub authorization: Option<String>,
+
+    /// URI of the token server.
+    // FIXME: use AnyURI
+    pub token: Option<String>,
+
+    /// URI of the refresh server.
+    // FIXME: use AnyURI
+    pub refresh: Option<String>,
+
+    /// Set of authorization scope identifiers.
+    ///
+    /// These are provided in tokens returned by an authorization server and associated with forms
+    /// in order to identify what resources a client may access and how.
+    #[serde(default)]
+    #[serde_as(as = "Option<StringOrArray>")]
+    pub scopes: Option<Vec<String>>,
+
+    /// Authorization flow.
+    pub flow: String,
+}
+
+impl OAuth2SecurityScheme {
+    /// Creates a new default value with the given `flow`.
+    pub fn new(flow: impl Into<String>) -> Self {
+        let flow = flow.into();
+        Self {
+            authorization: Default::default(),
+            token: Default::default(),
+            refresh: Default::default(),
+            scopes: Default::default(),
+            flow,
+        }
+    }
+}
+
+/// A link to an arbitrary resource.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct Link {
+    /// Target IRI of a link or submission target of a form.
+    pub href: String,
+
+    /// Target attribute providing a hint indicating what the media type
+    /// ([RFC2046](https://www.rfc-editor.org/rfc/rfc2046)) of the result of dereferencing the link
+    /// should be.
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+
+    /// A link relation type identifies the semantics of a link.
+    ///
+    /// The Thing Description defines `rel` as a single string, therefore an array is rejected
+    /// when deserializing. Multiple relation types can be expressed as a space-separated list,
+    /// like in [RFC 8288](https://www.rfc-editor.org/rfc/rfc8288#section-3.3).
+    pub rel: Option<String>,
+
+    /// Overrides the link context with the given URI or IRI.
+    ///
+    /// By default the link context is the Thing itself identified by its `id`.
+    // FIXME: use AnyURI
+    pub anchor: Option<String>,
+
+    /// One or more sizes for the referenced icon.
+    ///
+    /// This is only applicable for relation type "icon". The value pattern follows {Height}x{Width} (e.g., "16x16", "16x16 32x32").
+    pub sizes: Option<String>,
+
+    /// The language of a linked document.
+    #[serde(default)]
+    #[serde_as(as = "Option<StringOrArray>")]
+    pub hreflang: Option<Vec<LanguageTag<String>>>,
+}
+
+impl Link {
+    /// Returns `true` if the link refers to the Thing Model of the Thing.
+    ///
+    /// A Thing Model link has `"type"` among its `rel` relation types and `type` set to
+    /// [`TM_MEDIA_TYPE`].
+    pub fn is_thing_model(&self) -> bool {
+        self.rel
+            .as_deref()
+            .is_some_and(|rel| rel.split_ascii_whitespace().any(|rel| rel == "type"))
+            && self.ty.as_deref() == Some(TM_MEDIA_TYPE)
+    }
+}
+
+/// A term of a JSON-LD `@type`.
+///
+/// The terms are classified by their syntax, without resolving the prefixes against the
+/// `@context`:
+///
+/// - an absolute IRI has a scheme followed by `//` (like `https://w3id.org/saref#Sensor`), or a
+///   well-known scheme that is not used as a prefix (like `urn:dev:ops:1234`);
+/// - a prefixed term, or compact IRI, has a prefix and a local name separated by a colon (like
+///   `saref:Sensor`);
+/// - any other value is a plain term (like `Thing`).
+///
+/// It is serialized as a plain string, and [`Display`](fmt::Display) writes the original value.
+///
+/// # Example
+///
+/// ```
+/// # use wot_td::thing::AtType;
+/// #
+/// let ty = AtType::parse("saref:Sensor");
+/// assert_eq!(ty.prefix(), Some("saref"));
+/// assert_eq!(ty.local_name(), "Sensor");
+///
+/// let ty = AtType::parse("urn:dev:ops:32473-WoTLamp-1234");
+/// assert!(ty.is_absolute_iri());
+/// assert_eq!(ty.prefix(), None);
+/// assert_eq!(ty.to_string(), "urn:dev:ops:32473-WoTLamp-1234");
+/// ```
+///
+/// # Migration
+///
+/// The `attype` members of the Thing Description structures used to be `Vec<String>`. An
+/// `AtType` can be created from a string with [`From`] or [`str::parse`], it can be compared
+/// directly with a `&str` and it is turned back into a string with [`ToString`] or [`From`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AtType {
+    /// A compact IRI, like `saref:Sensor`.
+    Prefixed {
+        /// The prefix, which should be declared in the `@context`.
+        prefix: String,
+
+        /// The local name following the prefix.
+        local: String,
+    },
+
+    /// An absolute IRI, like `https://w3id.org/saref#Sensor`.
+    AbsoluteIri(String),
+
+    /// A term without prefix, like `Thing`.
+    Term(String),
+}
+
+/// The schemes classified as absolute IRIs even when they are not followed by `//`.
+const OPAQUE_IRI_SCHEMES: &[&str] = &[
+    "data", "did", "file", "http", "https", "mailto", "tag", "tel", "urn",
+];
+
+impl AtType {
+    /// Classifies a `@type` term.
+    pub fn parse(value: &str) -> Self {
+        let Some((prefix, local)) = value.split_once(':') else {
+            return Self::Term(value.to_string());
+        };
+
+        let is_prefix = !prefix.is_empty()
+            && prefix
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'));
+
+        if local.starts_with("//")
+            || OPAQUE_IRI_SCHEMES
+                .iter()
+                .any(|scheme| scheme.eq_ignore_ascii_case(prefix))
+        {
+            Self::AbsoluteIri(value.to_string())
+        } else if is_prefix {
+            Self::Prefixed {
+                prefix: prefix.to_string(),
+                local: local.to_string(),
+            }
+        } else {
+            Self::Term(value.to_string())
+        }
+    }
+
+    /// Returns the prefix of a compact IRI.
+    pub fn prefix(&self) -> Option<&str> {
+        match self {
+            Self::Prefixed { prefix, .. } => Some(prefix),
+            Self::AbsoluteIri(_) | Self::Term(_) => None,
+        }
+    }
+
+    /// Returns the local name of the term.
+    ///
+    /// It is the part following the prefix of a compact IRI, the last segment of an absolute IRI
+    /// (after the last `#`, `/` or `:`) and the whole value of a plain term.
+    pub fn local_name(&self) -> &str {
+        match self {
+            Self::Prefixed { local, .. } => local,
+            Self::AbsoluteIri(iri) => iri.rsplit(['#', '/', ':']).next().unwrap_or(iri),
+            Self::Term(term) => term,
+        }
+    }
+
+    /// Returns `true` if the term is an absolute IRI.
+    pub fn is_absolute_iri(&self) -> bool {
+        matches!(self, Self::AbsoluteIri(_))
+    }
+}
+
+/// Classifies the `@type` terms collected by the builders.
+pub(crate) fn parse_at_types(attype: Option<Vec<String>>) -> Option<Vec<AtType>> {
+    attype.map(|attype| attype.into_iter().map(AtType::from).collect())
+}
+
+/// Turns the `@type` terms back into the strings used by the builders.
+pub(crate) fn format_at_types(attype: Option<Vec<AtType>>) -> Option<Vec<String>> {
+    attype.map(|attype| attype.into_iter().map(String::from).collect())
+}
+
+impl fmt::Display for AtType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Prefixed { prefix, local } => write!(f, "{prefix}:{local}"),
+            Self::AbsoluteIri(value) | Self::Term(value) => f.write_str(value),
+        }
+    }
+}
+
+impl core::str::FromStr for AtType {
+    type Err = core::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s))
+    }
+}
+
+impl From<&str> for AtType {
+    fn from(value: &str) -> Self {
+        Self::parse(value)
+    }
+}
+
+impl From<String> for AtType {
+    fn from(value: String) -> Self {
+        Self::parse(&value)
+    }
+}
+
+impl From<AtType> for String {
+    fn from(value: AtType) -> Self {
+        match value {
+            AtType::Prefixed { .. } => value.to_string(),
+            AtType::AbsoluteIri(value) | AtType::Term(value) => value,
+        }
+    }
+}
+
+impl PartialEq<str> for AtType {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Self::Prefixed { prefix, local } => {
+                other
+                    .strip_prefix(prefix.as_str())
+                    .and_then(|other| other.strip_prefix(':'))
+                    == Some(local.as_str())
+            }
+            Self::AbsoluteIri(value) | Self::Term(value) => value == other,
+        }
+    }
+}
+
+impl PartialEq<&str> for AtType {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl Serialize for AtType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AtType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// The representation of an operation over a Thing.
+///
+/// The form is generic over its own extensions: `F` is the form extension and `ER` is the
+/// extension of the [`ExpectedResponse`]. The form of a [`Thing`] extended with `Other` is
+/// spelled [`FormFromOther<Other>`], which makes it possible to write helpers taking a
+/// `Form<F, ER>` without naming the whole extension stack.
+///
+/// # Migration
+///
+/// `Form` used to take the [`ExtendableThing`] as its only parameter: `Form<Other>` is now
+/// spelled `FormFromOther<Other>`, while `Form<Nil>` becomes `Form<Nil, Nil>`.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "preserve-unknown",
+    serde(bound(deserialize = "F: Serialize + serde::de::DeserializeOwned, \
+                               ER: serde::de::DeserializeOwned"))
+)]
+pub struct Form<F, ER> {
+    /// The semantic intention of performing the operation(s) described by the form.
+    #[serde(default, skip_serializing_if = "DefaultedFormOperations::is_default")]
+    pub op: DefaultedFormOperations,
+
+    /// Target IRI of a link or submission target of a form.
+    // FIXME: use AnyURI
+    pub href: String,
+
+    /// A content type.
+    ///
+    /// It is based on a media type (e.g., text/plain) and potential parameters (e.g.,
+    /// charset=utf-8) for the media type ([RFC2046](https://www.rfc-editor.org/rfc/rfc2046)).
+    pub content_type: Option<String>,
+
+    /// Content coding values indicate an encoding transformation that has been or can be applied
+    /// to a representation.
+    ///
+    /// Content codings are primarily used to allow a representation to be compressed or otherwise
+    /// usefully transformed without losing the identity of its underlying media type and without
+    /// loss of information.
+    ///
+    /// Examples of content coding include "gzip", "deflate", etc. .
+    // TODO: check if the subset of possible values is limited by the [IANA HTTP content coding
+    // registry](https://www.iana.org/assignments/http-parameters/http-parameters.xhtml#content-coding).
+    pub content_coding: Option<String>,
+
+    /// The mechanism by which an interaction will be accomplished for a given protocol when there
+    /// are multiple options.
+    pub subprotocol: Option<String>,
+
+    /// Set of security definition names, chosen from those defined in
+    /// [`security_definitions`](Thing::security_definitions). These must all be satisfied for
+    /// access to resources.
+    // FIXME: use variant names of KnownSecuritySchemeSubtype + "other" string variant
+    #[serde(default)]
+    #[serde_as(as = "Option<StringOrArray>")]
+    pub security: Option<Vec<String>>,
+
+    /// Set of authorization scope identifiers.
+    ///
+    /// The values associated with a form should be chosen from those defined in an
+    /// [`OAuth2SecurityScheme`] active on that form.
+    #[serde(default)]
+    #[serde_as(as = "Option<StringOrArray>")]
+    pub scopes: Option<Vec<String>>,
+
+    /// The expected response from the call to the resource.
+    ///
+    /// The response name contains metadata that is only valid for the primary response messages
+    pub response: Option<ExpectedResponse<ER>>,
+
+    /// Additional expected responses.
+    #[serde(default)]
+    #[serde_as(as = "Option<OneOrMany<_>>")]
+    pub additional_responses: Option<Vec<AdditionalExpectedResponse>>,
+
+    /// Form extension.
+    #[serde(flatten)]
+    pub other: F,
+
+    /// The members that are neither part of the TD vocabulary nor claimed by an extension.
+    ///
+    /// They are emitted again on serialization after the extension fields.
+    #[cfg(feature = "preserve-unknown")]
+    #[doc(hidden)]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::unknown::deserialize::<_, Form<F, ER>, (F,)>"
+    )]
+    pub unknown: serde_json::Map<String, Value>,
+}
+
+/// The [`Form`] of a [`Thing`] extended with `Other`.
+pub type FormFromOther<Other> =
+    Form<<Other as ExtendableThing>::Form, <Other as ExtendableThing>::ExpectedResponse>;
+
+impl<F, ER> Form<F, ER> {
+    /// Returns the names of the variables of the `href`, when it is a URI template.
+    ///
+    /// The names are listed in order of appearance, without duplicates. See the [`template`]
+    /// module for the supported syntax.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{hlist::Nil, thing::Form};
+    /// #
+    /// let form = Form::<Nil, Nil> {
+    ///     href: "/items/{id}{?page,size}".to_string(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(form.href_template_variables().unwrap(), ["id", "page", "size"]);
+    /// ```
+    ///
+    /// [`template`]: crate::template
+    pub fn href_template_variables(&self) -> Result<Vec<String>, TemplateError> {
+        template_variables(&self.href)
+    }
+
+    /// Returns the content type of the form, falling back to the default of the Thing.
+    ///
+    /// The content type of the form takes the precedence over
+    /// [`Thing::default_content_type`], and `application/json` is returned when neither is set, as
+    /// specified by the Thing Description.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{hlist::Nil, thing::{Form, Thing}};
+    /// #
+    /// let mut thing = Thing::<Nil>::default();
+    /// let form = Form::<Nil, Nil>::default();
+    /// assert_eq!(form.content_type_or(&thing), "application/json");
+    ///
+    /// thing.default_content_type = Some("application/cbor".to_string());
+    /// assert_eq!(form.content_type_or(&thing), "application/cbor");
+    /// ```
+    pub fn content_type_or<'a, Other>(&'a self, thing: &'a Thing<Other>) -> &'a str
+    where
+        Other: ExtendableThing,
+    {
+        self.content_type
+            .as_deref()
+            .or(thing.default_content_type.as_deref())
+            .unwrap_or(DEFAULT_CONTENT_TYPE)
+    }
+}
+
+/// The semantic intention of an operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FormOperation {
+    /// Read a property.
+    ReadProperty,
+
+    /// Update a property.
+    WriteProperty,
+
+    /// Observe a property.
+    ///
+    /// Identifies an operation to be notified with new data when the property is updated.
+    ObserveProperty,
+
+    /// Unobserve a property.
+    ///
+    /// Stops the notification from a previously observed property.
+    UnobserveProperty,
+
+    /// Perform an action.
+    InvokeAction,
+
+    /// Get the status of an action.
+    QueryAction,
+
+    /// Cancel an ongoing action.
+    CancelAction,
+
+    /// Subscribe to an event.
+    ///
+    /// Identifies an operation to be notified when an event occurs.
+    SubscribeEvent,
+
+    /// Unsubscribe from an event.
+    ///
+    /// Stops the notification from a previously subscribed event.
+    UnsubscribeEvent,
+
+    /// Read all the properties in a single interaction.
+    ReadAllProperties,
+
+    /// Update all the properties in a single interaction.
+    WriteAllProperties,
+
+    /// Read multiple selected properties in a single interaction.
+    ReadMultipleProperties,
+
+    /// Update multiple selected properties in a single interaction.
+    WriteMultipleProperties,
+
+    /// Observe all the properties in a single interaction.
+    ObserveAllProperties,
+
+    /// Unobserve all the properties in a single interaction.
+    UnobserveAllProperties,
+
+    /// Subscribe to all events in a single interaction.
+    SubscribeAllEvents,
+
+    /// Unsubscribe from all events in a single interaction.
+    UnsubscribeAllEvents,
+
+    /// Get the status of all actions in a single interaction.
+    QueryAllActions,
+}
+
+impl fmt::Display for FormOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::ReadProperty => "readproperty",
+            Self::WriteProperty => "writeproperty",
+            Self::ObserveProperty => "observeproperty",
+            Self::UnobserveProperty => "unobserveproperty",
+            Self::InvokeAction => "invokeaction",
+            Self::QueryAction => "queryaction",
+            Self::CancelAction => "cancelaction",
+            Self::SubscribeEvent => "subscribeevent",
+            Self::UnsubscribeEvent => "unsubscribeevent",
+            Self::ReadAllProperties => "readallproperties",
+            Self::WriteAllProperties => "writeallproperties",
+            Self::ReadMultipleProperties => "readmultipleproperties",
+            Self::WriteMultipleProperties => "writemultipleproperties",
+            Self::ObserveAllProperties => "observeallproperties",
+            Self::UnobserveAllProperties => "unobserveallproperties",
+            Self::SubscribeAllEvents => "subscribeallevents",
+            Self::UnsubscribeAllEvents => "unsubscribeallevents",
+            Self::QueryAllActions => "queryallactions",
+        };
+
+        f.write_str(s)
+    }
+}
+
+/// A default or custom set of Form operations.
+///
+/// A `Form` has a different default `op` field depending on its context. With this, it is possible
+/// to specify a _default_ operation independently from the context.
+///
+/// Note: an instance of this enum should not be serialized if it is a `Default` value.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DefaultedFormOperations {
+    /// The default operation depending on the context.
+    #[default]
+    Default,
+
+    /// A custom set of operations.
+    Custom(Vec<FormOperation>),
+}
+
+impl DefaultedFormOperations {
+    /// Returns `true` if the operation is a [`Default`](DefaultedFormOperations::Default) value.
+    #[inline]
+    pub const fn is_default(&self) -> bool {
+        matches!(self, Self::Default)
+    }
+}
+
+/// Returns the default operations of a form of an affordance without an explicit `op`.
+///
+/// The operations follow the [default values](https://www.w3.org/TR/wot-thing-description11/#sec-default-values)
+/// of the specification:
+///
+/// - the forms of the properties default to `readproperty` and `writeproperty`, with the former
+///   omitted for `writeOnly` properties and the latter omitted for `readOnly` ones. A property
+///   that is both `readOnly` and `writeOnly` does not have any default operation;
+/// - the forms of the actions default to `invokeaction`;
+/// - the forms of the events default to `subscribeevent` and `unsubscribeevent`.
+///
+/// The observation of a property is never a default operation, even if the property is
+/// `observable`. The flags are only relevant for the properties.
+///
+/// The [`ThingBuilder`] sets these operations on the forms of the
+/// affordances that do not declare any.
+///
+/// # Example
+///
+/// ```
+/// # use wot_td::{builder::AffordanceType, thing::{default_ops_for, FormOperation}};
+/// #
+/// assert_eq!(
+///     default_ops_for(AffordanceType::Property, true, false),
+///     [FormOperation::ReadProperty],
+/// );
+/// ```
+pub fn default_ops_for(
+    affordance: AffordanceType,
+    read_only: bool,
+    write_only: bool,
+) -> Vec<FormOperation> {
+    match affordance {
+        AffordanceType::Property => {
+            let mut ops = Vec::with_capacity(2);
+            if !write_only {
+                ops.push(FormOperation::ReadProperty);
+            }
+            if !read_only {
+                ops.push(FormOperation::WriteProperty);
+            }
+            ops
+        }
+        AffordanceType::Action => vec![FormOperation::InvokeAction],
+        AffordanceType::Event => vec![
+            FormOperation::SubscribeEvent,
+            FormOperation::UnsubscribeEvent,
+        ],
+    }
+}
+
+impl Serialize for DefaultedFormOperations {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Default => serializer.serialize_none(),
+            Self::Custom(ops) if ops.is_empty() => serializer.serialize_none(),
+            Self::Custom(ops) => StringOrArray::serialize_as(ops, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DefaultedFormOperations
+where
+    StringOrArray: DeserializeAs<'de, Vec<FormOperation>>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ops = Option::<StringOrArray>::deserialize_as(deserializer)?;
+        Ok(ops.map(Self::Custom).unwrap_or(Self::Default))
+    }
+}
+
+/// The expected response message for the primary response.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpectedResponse<Other> {
+    /// A content type.
+    ///
+    /// It is based on a media type (e.g., text/plain) and potential parameters (e.g.,
+    /// charset=utf-8) for the media type ([RFC2046](https://www.rfc-editor.org/rfc/rfc2046)).
+    pub content_type: String,
+
+    /// Expected response extension.
+    #[serde(flatten)]
+    pub other: Other,
+}
+
+/// The expected response message for additional responses.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdditionalExpectedResponse {
+    /// It is `true` if an additional response should not be considered an error.
+    #[serde(default = "bool_false", skip_serializing_if = "is_false")]
+    pub success: bool,
+
+    /// A content type.
+    ///
+    /// It is based on a media type (e.g., text/plain) and potential parameters (e.g.,
+    /// charset=utf-8) for the media type ([RFC2046](https://www.rfc-editor.org/rfc/rfc2046)).
+    pub content_type: Option<String>,
+
+    /// The output data schema for an additional response if it differs from the default output data schema.
+    ///
+    /// It is the name of a previous definition given in the
+    /// [`schema_definitions`](Thing::schema_definitions).
+    pub schema: Option<String>,
+}
+
+impl AdditionalExpectedResponse {
+    /// Returns the content type of the response, falling back to the one of the form.
+    ///
+    /// As specified by the Thing Description, an additional response without `contentType` has
+    /// the content type of its form. The default is only applied when reading it: an omitted
+    /// `contentType` is still omitted when serialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wot_td::{hlist::Nil, thing::{AdditionalExpectedResponse, Form}};
+    /// #
+    /// let form = Form::<Nil, Nil> {
+    ///     content_type: Some("application/cbor".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let response = AdditionalExpectedResponse::default();
+    /// assert_eq!(response.content_type_or(&form), Some("application/cbor"));
+    ///
+    /// let response = AdditionalExpectedResponse {
+    ///     content_type: Some("text/plain".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(response.content_type_or(&form), Some("text/plain"));
+    /// ```
+    pub fn content_type_or<'a, F, ER>(&'a self, form: &'a Form<F, ER>) -> Option<&'a str> {
+        self.content_type
+            .as_deref()
+            .or(form.content_type.as_deref())
+    }
+
+    /// Returns the content type of the response, following the whole defaulting chain.
+    ///
+    /// The content type of the response takes the precedence, then the one of the form as
+    /// returned by [`Form::content_type_or`].
+    pub fn resolved_content_type<'a, F, ER, Other>(
+        &'a self,
+        form: &'a Form<F, ER>,
+        thing: &'a Thing<Other>,
+    ) -> &'a str
+    where
+        Other: ExtendableThing,
+    {
+        self.content_type
+            .as_deref()
+            .unwrap_or_else(|| form.content_type_or(thing))
+    }
+}
+
+/// Mutation of the human-readable information of checked types.
+///
+/// The translations are validated in the same way as the builders do, using
+/// [`parse_language_tag`]. Removing the last translation of a
+/// field unsets the field.
+///
+/// # Example
+///
+/// ```
+/// # use wot_td::{
+/// #     builder::{parse_language_tag, DocumentError, Error},
+/// #     thing::HumanReadableMut,
+/// #     Thing,
+/// # };
+/// #
+/// let mut thing = Thing::builder("Lamp").finish_extend().build().unwrap();
+///
+/// thing.set_title("Smart lamp");
+/// thing.add_title_translation("it", "Lampada").unwrap();
+/// thing.add_title_translation("it", "Lampada intelligente").unwrap();
+/// assert_eq!(
+///     thing.add_title_translation("i1t", "Lampada"),
+///     Err(Error::Document(DocumentError::InvalidLanguageTag("i1t".to_string()))),
+/// );
+///
+/// let titles = thing.titles.as_ref().unwrap();
+/// assert_eq!(titles.len(), 1);
+/// assert_eq!(titles[&parse_language_tag("it").unwrap()], "Lampada intelligente");
+///
+/// assert_eq!(
+///     thing.remove_title_translation("it").as_deref(),
+///     Some("Lampada intelligente"),
+/// );
+/// assert!(thing.titles.is_none());
+/// ```
+pub trait HumanReadableMut {
+    /// Sets the title.
+    fn set_title(&mut self, value: impl Into<String>);
+
+    /// Sets the description.
+    fn set_description(&mut self, value: impl Into<String>);
+
+    /// Applies a function to the translations of the title.
+    fn update_titles<R>(&mut self, f: impl FnOnce(&mut Option<MultiLanguage>) -> R) -> R;
+
+    /// Applies a function to the translations of the description.
+    fn update_descriptions<R>(&mut self, f: impl FnOnce(&mut Option<MultiLanguage>) -> R) -> R;
+
+    /// Adds a translation of the title, overwriting the existing one for the same language.
+    ///
+    /// It fails if `tag` is not a valid language tag.
+    fn add_title_translation(&mut self, tag: &str, value: &str) -> Result<(), Error> {
+        let tag = parse_language_tag(tag)?;
+        self.update_titles(|titles| add_translation(titles, tag, value));
+        Ok(())
+    }
+
+    /// Removes the translation of the title for a language, returning it.
+    fn remove_title_translation(&mut self, tag: &str) -> Option<String> {
+        self.update_titles(|titles| remove_translation(titles, tag))
+    }
+
+    /// Replaces all the translations of the title.
+    ///
+    /// It fails without changing the translations if any tag is not a valid language tag.
+    fn set_title_translations<I, K, V>(&mut self, translations: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let translations = collect_translations(translations)?;
+        self.update_titles(|titles| *titles = translations);
+        Ok(())
+    }
+
+    /// Adds a translation of the description, overwriting the existing one for the same language.
+    ///
+    /// It fails if `tag` is not a valid language tag.
+    fn add_description_translation(&mut self, tag: &str, value: &str) -> Result<(), Error> {
+        let tag = parse_language_tag(tag)?;
+        self.update_descriptions(|descriptions| add_translation(descriptions, tag, value));
+        Ok(())
+    }
+
+    /// Removes the translation of the description for a language, returning it.
+    fn remove_description_translation(&mut self, tag: &str) -> Option<String> {
+        self.update_descriptions(|descriptions| remove_translation(descriptions, tag))
+    }
+
+    /// Replaces all the translations of the description.
+    ///
+    /// It fails without changing the translations if any tag is not a valid language tag.
+    fn set_description_translations<I, K, V>(&mut self, translations: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let translations = collect_translations(translations)?;
+        self.update_descriptions(|descriptions| *descriptions = translations);
+        Ok(())
+    }
+}
+
+fn add_translation(
+    translations: &mut Option<MultiLanguage>,
+    tag: LanguageTag<String>,
+    value: &str,
+) {
+    translations
+        .get_or_insert_with(Default::default)
+        .insert(tag, value.to_string());
+}
+
+fn remove_translation(translations: &mut Option<MultiLanguage>, tag: &str) -> Option<String> {
+    let tag = LanguageTag::parse(tag.to_string()).ok()?;
+    let map = translations.as_mut()?;
+    let value = map.remove(&tag);
+    if map.is_empty() {
+        *translations = None;
+    }
+    value
+}
+
+fn collect_translations<I, K, V>(translations: I) -> Result<Option<MultiLanguage>, Error>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<String>,
+    V: Into<String>,
+{
+    let translations = translations
+        .into_iter()
+        .map(|(tag, value)| Ok((parse_language_tag(tag)?, value.into())))
+        .collect::<Result<MultiLanguage, Error>>()?;
+    Ok((!translations.is_empty()).then_some(translations))
+}
+
+impl<Other: ExtendableThing> HumanReadableMut for Thing<Other> {
+    fn set_title(&mut self, value: impl Into<String>) {
+        self.title = value.into();
+    }
+
+    fn set_description(&mut self, value: impl Into<String>) {
+        self.description = Some(value.into());
+    }
+
+    fn update_titles<R>(&mut self, f: impl FnOnce(&mut Option<MultiLanguage>) -> R) -> R {
+        f(&mut self.titles)
+    }
+
+    fn update_descriptions<R>(&mut self, f: impl FnOnce(&mut Option<MultiLanguage>) -> R) -> R {
+        f(&mut self.descriptions)
+    }
+}
+
+impl<Other: ExtendableThing> HumanReadableMut for InteractionAffordance<Other> {
+    fn set_title(&mut self, value: impl Into<String>) {
+        self.title = Some(value.into());
+    }
+
+    fn set_description(&mut self, value: impl Into<String>) {
+        self.description = Some(value.into());
+    }
+
+    fn update_titles<R>(&mut self, f: impl FnOnce(&mut Option<MultiLanguage>) -> R) -> R {
+        f(&mut self.titles)
+    }
+
+    fn update_descriptions<R>(&mut self, f: impl FnOnce(&mut Option<MultiLanguage>) -> R) -> R {
+        f(&mut self.descriptions)
+    }
+}
+
+impl<DS, AS, OS> HumanReadableMut for DataSchema<DS, AS, OS> {
+    fn set_title(&mut self, value: impl Into<String>) {
+        self.title = Some(value.into());
+    }
+
+    fn set_description(&mut self, value: impl Into<String>) {
+        self.description = Some(value.into());
+    }
+
+    fn update_titles<R>(&mut self, f: impl FnOnce(&mut Option<MultiLanguage>) -> R) -> R {
+        f(&mut self.titles)
+    }
+
+    fn update_descriptions<R>(&mut self, f: impl FnOnce(&mut Option<MultiLanguage>) -> R) -> R {
+        f(&mut self.descriptions)
+    }
+}
+
+/// The information is serialized from the data schema, and it is kept in sync with the
+/// interaction affordance.
+impl<Other: ExtendableThing> HumanReadableMut for PropertyAffordance<Other> {
+    fn set_title(&mut self, value: impl Into<String>) {
+        let value = value.into();
+        self.interaction.title = Some(value.clone());
+        self.data_schema.title = Some(value);
+    }
+
+    fn set_description(&mut self, value: impl Into<String>) {
+        let value = value.into();
+        self.interaction.description = Some(value.clone());
+        self.data_schema.description = Some(value);
+    }
+
+    fn update_titles<R>(&mut self, f: impl FnOnce(&mut Option<MultiLanguage>) -> R) -> R {
+        let result = f(&mut self.data_schema.titles);
+        self.interaction.titles.clone_from(&self.data_schema.titles);
+        result
+    }
+
+    fn update_descriptions<R>(&mut self, f: impl FnOnce(&mut Option<MultiLanguage>) -> R) -> R {
+        let result = f(&mut self.data_schema.descriptions);
+        self.interaction
+            .descriptions
+            .clone_from(&self.data_schema.descriptions);
+        result
+    }
+}
+
+macro_rules! impl_human_readable_mut_for_affordance {
+    ($($ty:ident),+ $(,)?) => {
+        $(
+            impl<Other: ExtendableThing> HumanReadableMut for $ty<Other> {
+                fn set_title(&mut self, value: impl Into<String>) {
+                    self.interaction.set_title(value);
+                }
+
+                fn set_description(&mut self, value: impl Into<String>) {
+                    self.interaction.set_description(value);
+                }
+
+                fn update_titles<R>(
+                    &mut self,
+                    f: impl FnOnce(&mut Option<MultiLanguage>) -> R,
+                ) -> R {
+                    self.interaction.update_titles(f)
+                }
+
+                fn update_descriptions<R>(
+                    &mut self,
+                    f: impl FnOnce(&mut Option<MultiLanguage>) -> R,
+                ) -> R {
+                    self.interaction.update_descriptions(f)
+                }
+            }
+        )+
+    };
+}
+
+impl_human_readable_mut_for_affordance!(ActionAffordance, EventAffordance);
+
+const fn bool_false() -> bool {
+    false
+}
+
+const fn is_false(b: &bool) -> bool {
+    !*b
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{format, vec};
+
+    use serde_json::json;
+    use time::macros::datetime;
+
+    use crate::{
+        builder::{AffordanceError, DocumentError},
+        hlist::Cons,
+    };
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn minimal_thing() {
+        const RAW: &str = r#"
+        {
+            "@context": "https://www.w3.org/2022/wot/td/v1.1",
+            "id": "urn:dev:ops:32473-WoTLamp-1234",
+            "title": "MyLampThing",
+            "securityDefinitions": {
+                "nosec": {"scheme": "nosec"}
+            },
+            "security": ["nosec"]
+        }"#;
+
+        let expected_thing = Thing {
+            context: TD_CONTEXT_11.into(),
+            id: Some("urn:dev:ops:32473-WoTLamp-1234".to_string()),
+            title: "MyLampThing".to_string(),
+            security_definitions: [("nosec".to_string(), SecurityScheme::default())]
+                .into_iter()
+                .collect(),
+            security: vec!["nosec".to_string()],
+            ..Default::default()
+        };
+
+        let thing: Thing = serde_json::from_str(RAW).unwrap();
+        assert_eq!(thing, expected_thing);
+
+        let thing: Thing = serde_json::from_value(serde_json::to_value(thing).unwrap()).unwrap();
+        assert_eq!(thing, expected_thing);
+    }
+
+    #[test]
+    fn complete_thing() {
+        const RAW: &str = r#"
+        {
+          "@context": "https://www.w3.org/2022/wot/td/v1.1",
+          "id": "urn:dev:ops:32473-WoTLamp-1234",
+          "@type": [
+            "Thing",
+            "LampThing"
+          ],
+          "title": "MyLampThing",
+          "titles": {
+            "en": "MyLampThing",
+            "it": "La mia lampada intelligente"
+          },
+          "description": "A simple smart lamp",
+          "descriptions": {
+            "en": "A simple smart lamp",
+            "it": "Una semplice lampada intelligente"
+          },
+          "version": {
+            "instance": "0.1.0",
+            "model": "model"
+          },
+          "created": "2022-05-01T10:20:42.123Z",
+          "modified": "2022-05-10T12:30:00.000+01:00",
+          "support": "mailto:mail@test.com",
+          "base": "https://mylamp.example.com/",
+          "properties": {
+            "status": {
+              "type": "string",
+              "forms": [
+                {
+                  "href": "https://mylamp.example.com/status"
+                }
+              ]
+            }
+          },
+          "actions": {
+            "toggle": {
+              "forms": [
+                {
+                  "href": "https://mylamp.example.com/toggle"
+                }
+              ],
+              "synchronous": false
+            }
+          },
+          "events": {
+            "overheating": {
+              "data": {
+                "type": "string"
+              },
+              "forms": [
+                {
+                  "href": "https://mylamp.example.com/oh",
+                  "subprotocol": "longpoll"
+                }
+              ]
+            }
+          },
+          "links": [
+            {
+              "href": "https://myswitch.example.com/"
+            }
+          ],
+          "forms": [
+            {
+              "href": "https://mylamp.example.com/enumerate",
+              "op": "readallproperties"
+            }
+          ],
+          "schemaDefinitions": {
+              "schema": {
+                  "type": "null"
+              }
+          },
+          "securityDefinitions": {
+            "nosec": {
+              "scheme": "nosec"
+            }
+          },
+          "security": [
+            "nosec"
+          ],
+          "profile": [
+              "profile1",
+              "profile2"
+          ],
+          "uriVariables": {
+            "uriVariable1": {
+              "type": "string"
+            },
+            "uriVariable2": {
+              "type": "number"
+            }
+          }
+        }"#;
+
+        let expected_thing = Thing {
+            context: TD_CONTEXT_11.into(),
+            id: Some("urn:dev:ops:32473-WoTLamp-1234".to_string()),
+            attype: Some(vec!["Thing".into(), "LampThing".into()]),
+            title: "MyLampThing".to_string(),
+            titles: Some(
+                [
+                    ("en".parse().unwrap(), "MyLampThing".to_string()),
+                    (
+                        "it".parse().unwrap(),
+                        "La mia lampada intelligente".to_string(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            description: Some("A simple smart lamp".to_string()),
+            descriptions: Some(
+                [
+                    ("en".parse().unwrap(), "A simple smart lamp".to_string()),
+                    (
+                        "it".parse().unwrap(),
+                        "Una semplice lampada intelligente".to_string(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            version: Some(VersionInfo {
+                instance: "0.1.0".to_string(),
+                model: Some("model".to_string()),
+            }),
+            created: Some(datetime!(2022-05-01 10:20:42.123 UTC)),
+            modified: Some(datetime!(2022-05-10 12:30 +1)),
+            support: Some("mailto:mail@test.com".to_string()),
+            base: Some("https://mylamp.example.com/".to_string()),
+            properties: Some(
+                [(
+                    "status".to_string(),
+                    PropertyAffordance {
+                        interaction: InteractionAffordance {
+                            forms: vec![Form {
+                                href: "https://mylamp.example.com/status".to_string(),
+                                ..Form::default()
+                            }],
+                            ..Default::default()
+                        },
+                        data_schema: DataSchema {
+                            subtype: Some(DataSchemaSubtype::String(Default::default())),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            actions: Some(
+                [(
+                    "toggle".to_string(),
+                    ActionAffordance {
+                        interaction: InteractionAffordance {
+                            forms: vec![Form {
+                                href: "https://mylamp.example.com/toggle".to_string(),
+                                ..Default::default()
+                            }],
+                            ..Default::default()
+                        },
+                        synchronous: Some(false),
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            events: Some(
+                [(
+                    "overheating".to_string(),
+                    EventAffordance {
+                        interaction: InteractionAffordance {
+                            forms: vec![Form {
+                                href: "https://mylamp.example.com/oh".to_string(),
+                                subprotocol: Some("longpoll".to_string()),
+                                ..Default::default()
+                            }],
+                            ..Default::default()
+                        },
+                        data: Some(DataSchema {
+                            subtype: Some(DataSchemaSubtype::String(StringSchema::default())),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            links: Some(vec![Link {
+                href: "https://myswitch.example.com/".to_string(),
+                ..Default::default()
+            }]),
+            forms: Some(vec![Form {
+                op: DefaultedFormOperations::Custom(vec![FormOperation::ReadAllProperties]),
+                href: "https://mylamp.example.com/enumerate".to_string(),
+                ..Default::default()
+            }]),
+            schema_definitions: Some(
+                [(
+                    "schema".to_string(),
+                    DataSchema {
+                        subtype: Some(DataSchemaSubtype::Null),
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            security_definitions: [("nosec".to_string(), SecurityScheme::default())]
+                .into_iter()
+                .collect(),
+            security: vec!["nosec".to_string()],
+            profile: Some(vec!["profile1".to_string(), "profile2".to_string()]),
+            uri_variables: Some(
+                [
+                    (
+                        "uriVariable1".to_string(),
+                        DataSchema {
+                            subtype: Some(DataSchemaSubtype::String(Default::default())),
+                            ..Default::default()
+                        },
+                    ),
+                    (
+                        "uriVariable2".to_string(),
+                        DataSchema {
+                            subtype: Some(DataSchemaSubtype::Number(Default::default())),
+                            ..Default::default()
+                        },
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            ..Default::default()
+        };
+
+        let thing: Thing = serde_json::from_str(RAW).unwrap();
+        assert_eq!(thing, expected_thing);
+
+        let thing: Thing = serde_json::from_value(serde_json::to_value(thing).unwrap()).unwrap();
+        assert_eq!(thing, expected_thing);
+    }
+
+    #[test]
+    fn default_context() {
+        const RAW: &str = r#"
+        {
+          "title": "MyLampThing",
+          "securityDefinitions": {
+            "nosec": {
+              "scheme": "nosec"
+            }
+          },
+          "security": [
+            "nosec"
+          ]
+        }"#;
+
+        let expected_thing = Thing {
+            context: TD_CONTEXT_11.into(),
+            title: "MyLampThing".to_string(),
+            security_definitions: [("nosec".to_string(), SecurityScheme::default())]
+                .into_iter()
+                .collect(),
+            security: vec!["nosec".to_string()],
+            ..Default::default()
+        };
+
+        let thing: Thing = serde_json::from_str(RAW).unwrap();
+        assert_eq!(thing, expected_thing);
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct A(i32);
+
+    impl Default for A {
+        fn default() -> Self {
+            A(42)
+        }
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct ThingExtA {
+        a: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct IntAffExtA {
+        b: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct ActionAffExtA {
+        c: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct PropAffExtA {
+        d: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct EventAffExtA {
+        e: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct FormExtA {
+        f: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct RespExtA {
+        g: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct DataSchemaExtA {
+        h: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct ObjectSchemaExtA {
+        i: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct ArraySchemaExtA {
+        j: A,
+    }
+
+    impl ExtendableThing for ThingExtA {
+        type InteractionAffordance = IntAffExtA;
+        type PropertyAffordance = PropAffExtA;
+        type ActionAffordance = ActionAffExtA;
+        type EventAffordance = EventAffExtA;
+        type Form = FormExtA;
+        type ExpectedResponse = RespExtA;
+        type DataSchema = DataSchemaExtA;
+        type ObjectSchema = ObjectSchemaExtA;
+        type ArraySchema = ArraySchemaExtA;
+    }
+
+    #[test]
+    fn extend_single_thing() {
+        let thing = Thing::<ThingExtA> {
+            context: "test".into(),
+            properties: Some(
+                [(
+                    "prop".to_string(),
+                    PropertyAffordance {
+                        interaction: InteractionAffordance {
+                            other: IntAffExtA { b: A(1) },
+                            ..Default::default()
+                        },
+                        data_schema: DataSchema {
+                            subtype: Some(DataSchemaSubtype::Array(ArraySchema {
+                                other: ArraySchemaExtA { j: A(2) },
+                                ..Default::default()
+                            })),
+                            other: DataSchemaExtA { h: A(3) },
+                            ..Default::default()
+                        },
+                        other: PropAffExtA { d: A(4) },
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            actions: Some(
+                [(
+                    "action".to_string(),
+                    ActionAffordance {
+                        interaction: InteractionAffordance {
+                            other: IntAffExtA { b: A(5) },
+                            ..Default::default()
+                        },
+                        input: Some(DataSchema {
+                            subtype: Some(DataSchemaSubtype::Object(ObjectSchema {
+                                other: ObjectSchemaExtA { i: A(6) },
+                                ..Default::default()
+                            })),
+                            other: DataSchemaExtA { h: A(7) },
+                            ..Default::default()
+                        }),
+                        output: Some(DataSchema::default()),
+                        other: ActionAffExtA { c: A(8) },
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            events: Some(
+                [(
+                    "event".to_string(),
+                    EventAffordance {
+                        other: EventAffExtA { e: A(9) },
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            forms: Some(vec![Form {
+                response: Some(ExpectedResponse {
+                    other: RespExtA { g: A(10) },
+                    ..Default::default()
+                }),
+                other: FormExtA { f: A(11) },
+                ..Default::default()
+            }]),
+            schema_definitions: Some(
+                [(
+                    "schema".to_string(),
+                    DataSchema {
+                        subtype: Some(DataSchemaSubtype::Null),
+                        other: DataSchemaExtA { h: A(12) },
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            other: ThingExtA { a: A(13) },
+            ..Default::default()
+        };
+
+        let thing_json = serde_json::to_value(thing).unwrap();
+        assert_eq!(
+            thing_json,
+            json![{
+                "@context": "test",
+                "title": "",
+                "properties": {
+                    "prop": {
+                        "b": 1,
+                        "j": 2,
+                        "h": 3,
+                        "d": 4,
+                        "forms": [],
+                        "type": "array",
+                        "readOnly": false,
+                        "writeOnly": false,
+                    }
+                },
+                "actions": {
+                    "action": {
+                        "b": 5,
+                        "input": {
+                            "i": 6,
+                            "h": 7,
+                            "readOnly": false,
+                            "writeOnly": false,
+                            "type": "object",
+                        },
+                        "output": {
+                            "h": 42,
+                            "readOnly": false,
+                            "writeOnly": false,
+                        },
+                        "forms": [],
+                        "idempotent": false,
+                        "safe": false,
+                        "c": 8,
+                    }
+                },
+                "events": {
+                    "event": {
+                        "b": 42,
+                        "e": 9,
+                        "forms": [],
+                    }
+                },
+                "forms": [{
+                    "href": "",
+                    "response": {
+                        "contentType": "",
+                        "g": 10,
+                    },
+                    "f": 11,
+                }],
+                "schemaDefinitions": {
+                    "schema": {
+                        "type": "null",
+                        "readOnly": false,
+                        "writeOnly": false,
+                        "h": 12,
+                    }
+                },
+                "security": [],
+                "securityDefinitions": {},
+                "a": 13,
+            }],
+        );
+    }
+
+    #[test]
+    fn extend_single_thing_with_hlist() {
+        let thing = Thing::<Cons<ThingExtA, Nil>> {
+            context: "test".into(),
+            properties: Some(
+                [(
+                    "prop".to_string(),
+                    PropertyAffordance {
+                        interaction: InteractionAffordance {
+                            other: Nil::cons(IntAffExtA { b: A(1) }),
+                            ..Default::default()
+                        },
+                        data_schema: DataSchema {
+                            subtype: Some(DataSchemaSubtype::Array(ArraySchema {
+                                other: Nil::cons(ArraySchemaExtA { j: A(2) }),
+                                ..Default::default()
+                            })),
+                            other: Nil::cons(DataSchemaExtA { h: A(3) }),
+                            ..Default::default()
+                        },
+                        other: Nil::cons(PropAffExtA { d: A(4) }),
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            actions: Some(
+                [(
+                    "action".to_string(),
+                    ActionAffordance {
+                        interaction: InteractionAffordance {
+                            other: Nil::cons(IntAffExtA { b: A(5) }),
+                            ..Default::default()
+                        },
+                        input: Some(DataSchema {
+                            subtype: Some(DataSchemaSubtype::Object(ObjectSchema {
+                                other: Nil::cons(ObjectSchemaExtA { i: A(6) }),
+                                ..Default::default()
+                            })),
+                            other: Nil::cons(DataSchemaExtA { h: A(7) }),
+                            ..Default::default()
+                        }),
+                        output: Some(DataSchema::default()),
+                        other: Nil::cons(ActionAffExtA { c: A(8) }),
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            events: Some(
+                [(
+                    "event".to_string(),
+                    EventAffordance {
+                        other: Nil::cons(EventAffExtA { e: A(9) }),
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            forms: Some(vec![Form {
+                response: Some(ExpectedResponse {
+                    other: Nil::cons(RespExtA { g: A(10) }),
+                    ..Default::default()
+                }),
+                other: Nil::cons(FormExtA { f: A(11) }),
+                ..Default::default()
+            }]),
+            other: Nil::cons(ThingExtA { a: A(12) }),
+            ..Default::default()
+        };
+
+        let thing_json = serde_json::to_value(thing).unwrap();
+        assert_eq!(
+            thing_json,
+            json!({
+                "@context": "test",
+                "title": "",
+                "properties": {
+                    "prop": {
+                        "b": 1,
+                        "j": 2,
+                        "h": 3,
+                        "d": 4,
+                        "forms": [],
+                        "type": "array",
+                        "readOnly": false,
+                        "writeOnly": false,
+                    }
+                },
+                "actions": {
+                    "action": {
+                        "b": 5,
+                        "input": {
+                            "i": 6,
+                            "h": 7,
+                            "readOnly": false,
+                            "writeOnly": false,
+                            "type": "object",
+                        },
+                        "output": {
+                            "h": 42,
+                            "readOnly": false,
+                            "writeOnly": false,
+                        },
+                        "forms": [],
+                        "idempotent": false,
+                        "safe": false,
+                        "c": 8,
+                    }
+                },
+                "events": {
+                    "event": {
+                        "b": 42,
+                        "e": 9,
+                        "forms": [],
+                    }
+                },
+                "forms": [{
+                    "href": "",
+                    "response": {
+                        "contentType": "",
+                        "g": 10,
+                    },
+                    "f": 11,
+                }],
+                "security": [],
+                "securityDefinitions": {},
+                "a": 12,
+            }),
+        );
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct ThingExtB {
+        k: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct IntAffExtB {
+        l: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct ActionAffExtB {
+        m: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct PropAffExtB {
+        n: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct EventAffExtB {
+        o: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct FormExtB {
+        p: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct RespExtB {
+        q: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct DataSchemaExtB {
+        r: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct ObjectSchemaExtB {
+        s: A,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct ArraySchemaExtB {
+        t: A,
+    }
+
+    impl ExtendableThing for ThingExtB {
+        type InteractionAffordance = IntAffExtB;
+        type PropertyAffordance = PropAffExtB;
+        type ActionAffordance = ActionAffExtB;
+        type EventAffordance = EventAffExtB;
+        type Form = FormExtB;
+        type ExpectedResponse = RespExtB;
+        type DataSchema = DataSchemaExtB;
+        type ObjectSchema = ObjectSchemaExtB;
+        type ArraySchema = ArraySchemaExtB;
+    }
+
+    #[test]
+    fn extend_thing_with_two() {
+        let thing = Thing::<Cons<ThingExtB, Cons<ThingExtA, Nil>>> {
+            context: "test".into(),
+            properties: Some(
+                [(
+                    "prop".to_string(),
+                    PropertyAffordance {
+                        interaction: InteractionAffordance {
+                            other: Nil::cons(IntAffExtA { b: A(1) }).cons(IntAffExtB { l: A(2) }),
+                            ..Default::default()
+                        },
+                        data_schema: DataSchema {
+                            subtype: Some(DataSchemaSubtype::Array(ArraySchema {
+                                other: Nil::cons(ArraySchemaExtA { j: A(3) })
+                                    .cons(ArraySchemaExtB { t: A(4) }),
+                                ..Default::default()
+                            })),
+                            other: Nil::cons(DataSchemaExtA { h: A(5) })
+                                .cons(DataSchemaExtB { r: A(6) }),
+                            ..Default::default()
+                        },
+                        other: Nil::cons(PropAffExtA { d: A(7) }).cons(PropAffExtB { n: A(8) }),
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            actions: Some(
+                [(
+                    "action".to_string(),
+                    ActionAffordance {
+                        interaction: InteractionAffordance {
+                            other: Nil::cons(IntAffExtA { b: A(9) }).cons(IntAffExtB { l: A(10) }),
+                            ..Default::default()
+                        },
+                        input: Some(DataSchema {
+                            subtype: Some(DataSchemaSubtype::Object(ObjectSchema {
+                                other: Nil::cons(ObjectSchemaExtA { i: A(11) })
+                                    .cons(ObjectSchemaExtB { s: A(12) }),
+                                ..Default::default()
+                            })),
+                            other: Nil::cons(DataSchemaExtA { h: A(13) })
+                                .cons(DataSchemaExtB { r: A(14) }),
+                            ..Default::default()
+                        }),
+                        output: Some(DataSchema::default()),
+                        other: Nil::cons(ActionAffExtA { c: A(15) })
+                            .cons(ActionAffExtB { m: A(16) }),
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            events: Some(
+                [(
+                    "event".to_string(),
+                    EventAffordance {
+                        other: Nil::cons(EventAffExtA { e: A(17) }).cons(EventAffExtB { o: A(18) }),
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            forms: Some(vec![Form {
+                response: Some(ExpectedResponse {
+                    other: Nil::cons(RespExtA { g: A(19) }).cons(RespExtB { q: A(20) }),
+                    ..Default::default()
+                }),
+                other: Nil::cons(FormExtA { f: A(21) }).cons(FormExtB { p: A(22) }),
+                ..Default::default()
+            }]),
+            other: Nil::cons(ThingExtA { a: A(23) }).cons(ThingExtB { k: A(24) }),
+            ..Default::default()
+        };
+
+        let thing_json = serde_json::to_value(thing).unwrap();
+        assert_eq!(
+            thing_json,
+            json!({
+                "@context": "test",
+                "title": "",
+                "properties": {
+                    "prop": {
+                        "b": 1,
+                        "l": 2,
+                        "j": 3,
+                        "t": 4,
+                        "h": 5,
+                        "r": 6,
+                        "d": 7,
+                        "n": 8,
+                        "forms": [],
+                        "type": "array",
+                        "readOnly": false,
+                        "writeOnly": false,
+                    }
+                },
+                "actions": {
+                    "action": {
+                        "b": 9,
+                        "l": 10,
+                        "input": {
+                            "i": 11,
+                            "s": 12,
+                            "h": 13,
+                            "r": 14,
+                            "readOnly": false,
+                            "writeOnly": false,
+                            "type": "object",
+                        },
+                        "output": {
+                            "h": 42,
+                            "r": 42,
+                            "readOnly": false,
+                            "writeOnly": false,
+                        },
+                        "forms": [],
+                        "idempotent": false,
+                        "safe": false,
+                        "c": 15,
+                        "m": 16,
+                    }
+                },
+                "events": {
+                    "event": {
+                        "b": 42,
+                        "l": 42,
+                        "e": 17,
+                        "o": 18,
+                        "forms": [],
+                    }
+                },
+                "forms": [{
+                    "href": "",
+                    "response": {
+                        "contentType": "",
+                        "g": 19,
+                        "q": 20,
+                    },
+                    "f": 21,
+                    "p": 22,
+                }],
+                "security": [],
+                "securityDefinitions": {},
+                "a": 23,
+                "k": 24,
+            }),
+        );
+    }
+
+    #[test]
+    fn dummy_http() {
+        #[derive(Serialize, Deserialize, Default)]
+        struct HttpThing {}
+
+        #[derive(Deserialize, Serialize)]
+        #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+        enum HttpMethod {
+            Get,
+            Put,
+            Post,
+            Delete,
+            Patch,
+        }
+
+        #[derive(Deserialize, Serialize)]
+        struct HttpMessageHeader {
+            #[serde(rename = "htv:fieldName")]
+            field_name: Option<String>,
+            #[serde(rename = "htv:fieldValue")]
+            field_value: Option<String>,
+        }
+
+        #[derive(Deserialize, Serialize, Default)]
+        struct HttpResponse {
+            #[serde(rename = "htv:headers")]
+            headers: Vec<HttpMessageHeader>,
+            #[serde(rename = "htv:statusCodeValue")]
+            status_code_value: Option<usize>,
+        }
+
+        #[derive(Default, Deserialize, Serialize)]
+        struct HttpForm {
+            #[serde(rename = "htv:methodName")]
+            method_name: Option<HttpMethod>,
+        }
+
+        impl ExtendableThing for HttpThing {
+            type InteractionAffordance = ();
+            type PropertyAffordance = ();
+            type ActionAffordance = ();
+            type EventAffordance = ();
+            type Form = HttpForm;
+            type ExpectedResponse = HttpResponse;
+            type DataSchema = ();
+            type ObjectSchema = ();
+            type ArraySchema = ();
+        }
+
+        let thing = Thing::<Cons<ThingExtB, Cons<HttpThing, Cons<ThingExtA, Nil>>>> {
+            context: "test".into(),
+            properties: Some(
+                [(
+                    "prop".to_string(),
+                    PropertyAffordance {
+                        interaction: InteractionAffordance {
+                            other: Nil::cons(IntAffExtA { b: A(1) })
+                                .cons(())
+                                .cons(IntAffExtB { l: A(2) }),
+                            ..Default::default()
+                        },
+                        data_schema: DataSchema {
+                            subtype: Some(DataSchemaSubtype::Array(ArraySchema {
+                                other: Nil::cons(ArraySchemaExtA { j: A(3) })
+                                    .cons(())
+                                    .cons(ArraySchemaExtB { t: A(4) }),
+                                ..Default::default()
+                            })),
+                            other: Nil::cons(DataSchemaExtA { h: A(5) })
+                                .cons(())
+                                .cons(DataSchemaExtB { r: A(6) }),
+                            ..Default::default()
+                        },
+                        other: Nil::cons(PropAffExtA { d: A(7) })
+                            .cons(())
+                            .cons(PropAffExtB { n: A(8) }),
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            actions: Some(
+                [(
+                    "action".to_string(),
+                    ActionAffordance {
+                        interaction: InteractionAffordance {
+                            forms: vec![Form {
+                                other: Nil::cons(FormExtA::default())
+                                    .cons(HttpForm {
+                                        method_name: Some(HttpMethod::Put),
+                                    })
+                                    .cons(FormExtB::default()),
+                                ..Default::default()
+                            }],
+                            other: Nil::cons(IntAffExtA { b: A(9) })
+                                .cons(())
+                                .cons(IntAffExtB { l: A(10) }),
+                            ..Default::default()
+                        },
+                        input: Some(DataSchema {
+                            subtype: Some(DataSchemaSubtype::Object(ObjectSchema {
+                                other: Nil::cons(ObjectSchemaExtA { i: A(11) })
+                                    .cons(())
+                                    .cons(ObjectSchemaExtB { s: A(12) }),
+                                ..Default::default()
+                            })),
+                            other: Nil::cons(DataSchemaExtA { h: A(13) })
+                                .cons(())
+                                .cons(DataSchemaExtB { r: A(14) }),
+                            ..Default::default()
+                        }),
+                        output: Some(DataSchema::default()),
+                        other: Nil::cons(ActionAffExtA { c: A(15) })
+                            .cons(())
+                            .cons(ActionAffExtB { m: A(16) }),
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            events: Some(
+                [(
+                    "event".to_string(),
+                    EventAffordance {
+                        other: Nil::cons(EventAffExtA { e: A(17) })
+                            .cons(())
+                            .cons(EventAffExtB { o: A(18) }),
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            forms: Some(vec![Form {
+                response: Some(ExpectedResponse {
+                    other: Nil::cons(RespExtA { g: A(19) })
+                        .cons(HttpResponse {
+                            headers: vec![HttpMessageHeader {
+                                field_name: Some("hello".to_string()),
+                                field_value: Some("world".to_string()),
+                            }],
+                            status_code_value: Some(200),
+                        })
+                        .cons(RespExtB { q: A(20) }),
+                    ..Default::default()
+                }),
+                other: Nil::cons(FormExtA { f: A(21) })
+                    .cons(HttpForm {
+                        method_name: Some(HttpMethod::Get),
+                    })
+                    .cons(FormExtB { p: A(22) }),
+                ..Default::default()
+            }]),
+            other: Nil::cons(ThingExtA { a: A(23) })
+                .cons(HttpThing {})
+                .cons(ThingExtB { k: A(24) }),
+            ..Default::default()
+        };
+
+        let thing_json = serde_json::to_value(thing).unwrap();
+        assert_eq!(
+            thing_json,
+            json!({
+                "@context": "test",
+                "title": "",
+                "properties": {
+                    "prop": {
+                        "b": 1,
+                        "l": 2,
+                        "j": 3,
+                        "t": 4,
+                        "h": 5,
+                        "r": 6,
+                        "d": 7,
+                        "n": 8,
+                        "forms": [],
+                        "type": "array",
+                        "readOnly": false,
+                        "writeOnly": false,
+                    }
+                },
+                "actions": {
+                    "action": {
+                        "b": 9,
+                        "l": 10,
+                        "input": {
+                            "i": 11,
+                            "s": 12,
+                            "h": 13,
+                            "r": 14,
+                            "readOnly": false,
+                            "writeOnly": false,
+                            "type": "object",
+                        },
+                        "output": {
+                            "h": 42,
+                            "r": 42,
+                            "readOnly": false,
+                            "writeOnly": false,
+                        },
+                        "forms": [
+                            {
+                                "f": 42,
+                                "href": "",
+                                "htv:methodName": "PUT",
+                                "p": 42,
+                            }
+                        ],
+                        "idempotent": false,
+                        "safe": false,
+                        "c": 15,
+                        "m": 16,
+                    }
+                },
+                "events": {
+                    "event": {
+                        "b": 42,
+                        "l": 42,
+                        "e": 17,
+                        "o": 18,
+                        "forms": [],
+                    }
+                },
+                "forms": [{
+                    "href": "",
+                    "response": {
+                        "contentType": "",
+                        "g": 19,
+                        "q": 20,
+                        "htv:headers": [{
+                            "htv:fieldName": "hello",
+                            "htv:fieldValue": "world",
+                        }],
+                        "htv:statusCodeValue": 200,
+                    },
+                    "f": 21,
+                    "p": 22,
+                    "htv:methodName": "GET",
+                }],
+                "security": [],
+                "securityDefinitions": {},
+                "a": 23,
+                "k": 24,
+            }),
+        );
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct DataSchemaExt {}
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct ArraySchemaExt {}
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct ObjectSchemaExt {}
+
+    #[test]
+    fn default_array_schema() {
+        ArraySchema::<DataSchemaExt, (), ObjectSchemaExt>::default();
+    }
+
+    #[test]
+    fn default_object_schema() {
+        ObjectSchema::<DataSchemaExt, ArraySchemaExt, ()>::default();
+    }
+
+    #[test]
+    fn serde_empty_additional_expected_response() {
+        let response: AdditionalExpectedResponse = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(
+            response,
+            AdditionalExpectedResponse {
+                success: false,
+                content_type: None,
+                schema: None,
+            },
+        );
+
+        assert_eq!(serde_json::to_value(response).unwrap(), json!({}));
+    }
+
+    #[test]
+    fn serde_full_additional_expected_response() {
+        let raw_data = json!({
+            "success": true,
+            "contentType": "application/json",
+            "schema": "test",
+        });
+
+        let response: AdditionalExpectedResponse =
+            serde_json::from_value(raw_data.clone()).unwrap();
+
+        assert_eq!(
+            response,
+            AdditionalExpectedResponse {
+                success: true,
+                content_type: Some("application/json".to_string()),
+                schema: Some("test".to_string()),
+            },
+        );
+
+        assert_eq!(serde_json::to_value(response).unwrap(), raw_data);
+    }
+
+    #[test]
+    fn combo_security_scheme() {
+        let raw_data = json!({
+            "oneOf": "simple",
+        });
+        let combo: ComboSecurityScheme = serde_json::from_value(raw_data.clone()).unwrap();
+        assert_eq!(
+            combo,
+            ComboSecurityScheme::OneOf(vec!["simple".to_string()]),
+        );
+        assert_eq!(
+            serde_json::to_value(combo).unwrap(),
+            json!({
+                "oneOf": ["simple"],
+            }),
+        );
+
+        let raw_data = json!({
+            "oneOf": ["data1", "data2"],
+        });
+        let combo: ComboSecurityScheme = serde_json::from_value(raw_data.clone()).unwrap();
+        assert_eq!(
+            combo,
+            ComboSecurityScheme::OneOf(vec!["data1".to_string(), "data2".to_string()]),
+        );
+        assert_eq!(serde_json::to_value(combo).unwrap(), raw_data);
+
+        let raw_data = json!({
+            "allOf": "simple",
+        });
+        let combo: ComboSecurityScheme = serde_json::from_value(raw_data.clone()).unwrap();
+        assert_eq!(
+            combo,
+            ComboSecurityScheme::AllOf(vec!["simple".to_string()]),
+        );
+        assert_eq!(
+            serde_json::to_value(combo).unwrap(),
+            json!({
+                "allOf": ["simple"],
+            }),
+        );
+
+        let raw_data = json!({
+            "allOf": ["data1", "data2"],
+        });
+        let combo: ComboSecurityScheme = serde_json::from_value(raw_data.clone()).unwrap();
+        assert_eq!(
+            combo,
+            ComboSecurityScheme::AllOf(vec!["data1".to_string(), "data2".to_string()]),
+        );
+        assert_eq!(serde_json::to_value(combo).unwrap(), raw_data);
+    }
+
+    #[test]
+    fn minimum_partial_ord_trivial() {
+        assert_eq!(
+            Minimum::Inclusive(5).partial_cmp(&Minimum::Inclusive(5)),
+            Some(Ordering::Equal),
+        );
+        assert_eq!(
+            Minimum::Inclusive(5).partial_cmp(&Minimum::Inclusive(6)),
+            Some(Ordering::Less),
+        );
+        assert_eq!(
+            Minimum::Inclusive(6).partial_cmp(&Minimum::Inclusive(5)),
+            Some(Ordering::Greater),
+        );
+
+        assert_eq!(
+            Minimum::Exclusive(5).partial_cmp(&Minimum::Exclusive(5)),
+            Some(Ordering::Equal),
+        );
+        assert_eq!(
+            Minimum::Exclusive(5).partial_cmp(&Minimum::Exclusive(6)),
+            Some(Ordering::Less),
+        );
+        assert_eq!(
+            Minimum::Exclusive(6).partial_cmp(&Minimum::Exclusive(5)),
+            Some(Ordering::Greater),
+        );
+    }
+
+    #[test]
+    fn minimum_partial_ord_complex() {
+        assert_eq!(
+            Minimum::Inclusive(4).partial_cmp(&Minimum::Exclusive(5)),
+            Some(Ordering::Less),
+        );
+
+        assert_eq!(
+            Minimum::Inclusive(5).partial_cmp(&Minimum::Exclusive(5)),
+            Some(Ordering::Less),
+        );
+
+        assert_eq!(
+            Minimum::Inclusive(6).partial_cmp(&Minimum::Exclusive(5)),
+            None,
+        );
+
+        assert_eq!(
+            Minimum::Exclusive(4).partial_cmp(&Minimum::Inclusive(5)),
+            None,
+        );
+
+        assert_eq!(
+            Minimum::Exclusive(5).partial_cmp(&Minimum::Inclusive(5)),
+            Some(Ordering::Greater),
+        );
+
+        assert_eq!(
+            Minimum::Exclusive(6).partial_cmp(&Minimum::Inclusive(5)),
+            Some(Ordering::Greater),
+        );
+    }
+
+    #[test]
+    fn maximum_partial_ord_trivial() {
+        use core::cmp::Ordering;
+        assert_eq!(
+            Maximum::Inclusive(5).partial_cmp(&Maximum::Inclusive(5)),
+            Some(Ordering::Equal),
+        );
+        assert_eq!(
+            Maximum::Inclusive(5).partial_cmp(&Maximum::Inclusive(6)),
+            Some(Ordering::Less),
+        );
+        assert_eq!(
+            Maximum::Inclusive(6).partial_cmp(&Maximum::Inclusive(5)),
+            Some(Ordering::Greater),
+        );
+
+        assert_eq!(
+            Maximum::Exclusive(5).partial_cmp(&Maximum::Exclusive(5)),
+            Some(Ordering::Equal),
+        );
+        assert_eq!(
+            Maximum::Exclusive(5).partial_cmp(&Maximum::Exclusive(6)),
+            Some(Ordering::Less),
+        );
+        assert_eq!(
+            Maximum::Exclusive(6).partial_cmp(&Maximum::Exclusive(5)),
+            Some(Ordering::Greater),
+        );
+    }
+
+    #[test]
+    fn maximum_partial_ord_complex() {
+        assert_eq!(
+            Maximum::Inclusive(4).partial_cmp(&Maximum::Exclusive(5)),
+            None,
+        );
+
+        assert_eq!(
+            Maximum::Inclusive(5).partial_cmp(&Maximum::Exclusive(5)),
+            Some(Ordering::Greater),
+        );
+
+        assert_eq!(
+            Maximum::Inclusive(6).partial_cmp(&Maximum::Exclusive(5)),
+            Some(Ordering::Greater),
+        );
+
+        assert_eq!(
+            Maximum::Exclusive(4).partial_cmp(&Maximum::Inclusive(5)),
+            Some(Ordering::Less)
+        );
+
+        assert_eq!(
+            Maximum::Exclusive(5).partial_cmp(&Maximum::Inclusive(5)),
+            Some(Ordering::Less),
+        );
+
+        assert_eq!(
+            Maximum::Exclusive(6).partial_cmp(&Maximum::Inclusive(5)),
+            None
+        );
+    }
+
+    #[test]
+    fn minimum_maximum_mixed_partial_ord_trivial() {
+        assert_eq!(
+            Minimum::Inclusive(4).partial_cmp(&Maximum::Inclusive(5)),
+            Some(Ordering::Less),
+        );
+        assert_eq!(
+            Minimum::Inclusive(5).partial_cmp(&Maximum::Inclusive(5)),
+            Some(Ordering::Equal),
+        );
+        assert_eq!(
+            Minimum::Inclusive(6).partial_cmp(&Maximum::Inclusive(5)),
+            Some(Ordering::Greater),
+        );
+
+        assert_eq!(
+            Maximum::Inclusive(4).partial_cmp(&Minimum::Inclusive(5)),
+            Some(Ordering::Less),
+        );
+        assert_eq!(
+            Maximum::Inclusive(5).partial_cmp(&Minimum::Inclusive(5)),
+            Some(Ordering::Equal),
+        );
+        assert_eq!(
+            Maximum::Inclusive(6).partial_cmp(&Minimum::Inclusive(5)),
+            Some(Ordering::Greater),
+        );
+    }
+
+    #[test]
+    fn minimum_maximum_mixed_partial_ord_complex() {
+        assert_eq!(
+            Minimum::Inclusive(4).partial_cmp(&Maximum::Exclusive(5)),
+            None,
+        );
+        assert_eq!(
+            Minimum::Inclusive(5).partial_cmp(&Maximum::Exclusive(5)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            Minimum::Inclusive(6).partial_cmp(&Maximum::Exclusive(5)),
+            Some(Ordering::Greater)
+        );
+
+        assert_eq!(
+            Minimum::Exclusive(4).partial_cmp(&Maximum::Inclusive(5)),
+            None,
+        );
+        assert_eq!(
+            Minimum::Exclusive(5).partial_cmp(&Maximum::Inclusive(5)),
+            Some(Ordering::Greater),
+        );
+        assert_eq!(
+            Minimum::Exclusive(6).partial_cmp(&Maximum::Inclusive(5)),
+            Some(Ordering::Greater),
+        );
+
+        assert_eq!(
+            Maximum::Inclusive(4).partial_cmp(&Minimum::Exclusive(5)),
+            Some(Ordering::Less),
+        );
+        assert_eq!(
+            Maximum::Inclusive(5).partial_cmp(&Minimum::Exclusive(5)),
+            Some(Ordering::Less),
+        );
+        assert_eq!(
+            Maximum::Inclusive(6).partial_cmp(&Minimum::Exclusive(5)),
+            None,
+        );
 
-        let thing: Thing = serde_json::from_value(serde_json::to_value(thing).unwrap()).unwrap();
-        assert_eq!(thing, expected_thing);
+        assert_eq!(
+            Maximum::Exclusive(4).partial_cmp(&Minimum::Inclusive(5)),
+            Some(Ordering::Less),
+        );
+        assert_eq!(
+            Maximum::Exclusive(5).partial_cmp(&Minimum::Inclusive(5)),
+            Some(Ordering::Less),
+        );
+        assert_eq!(
+            Maximum::Exclusive(6).partial_cmp(&Minimum::Inclusive(5)),
+            None,
+        );
     }
 
     #[test]
-    fn default_context() {
-        const RAW: &str = r#"
-        {
-          "title": "MyLampThing",
-          "securityDefinitions": {
-            "nosec": {
-              "scheme": "nosec"
+    fn serde_number_schema() {
+        let data: NumberSchema = serde_json::from_value(json! {
+            {
+                "minimum": 0.5,
+                "maximum": 1.,
+                "multipleOf": 0.5,
             }
-          },
-          "security": [
-            "nosec"
-          ]
-        }"#;
-
-        let expected_thing = Thing {
-            context: TD_CONTEXT_11.into(),
-            title: "MyLampThing".to_string(),
-            security_definitions: [("nosec".to_string(), SecurityScheme::default())]
-                .into_iter()
-                .collect(),
-            security: vec!["nosec".to_string()],
-            ..Default::default()
-        };
-
-        let thing: Thing = serde_json::from_str(RAW).unwrap();
-        assert_eq!(thing, expected_thing);
-    }
-
-    #[derive(Serialize, Deserialize)]
-    struct A(i32);
+        })
+        .unwrap();
 
-    impl Default for A {
-        fn default() -> Self {
-            A(42)
-        }
-    }
+        assert_eq!(
+            data,
+            NumberSchema {
+                minimum: Some(Minimum::Inclusive(0.5)),
+                maximum: Some(Maximum::Inclusive(1.)),
+                multiple_of: Some(0.5),
+            },
+        );
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct ThingExtA {
-        a: A,
-    }
+        let data: NumberSchema = serde_json::from_value(json! {
+            {
+                "exclusiveMinimum": 0.5,
+                "exclusiveMaximum": 1.,
+                "multipleOf": 0.5,
+            }
+        })
+        .unwrap();
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct IntAffExtA {
-        b: A,
+        assert_eq!(
+            data,
+            NumberSchema {
+                minimum: Some(Minimum::Exclusive(0.5)),
+                maximum: Some(Maximum::Exclusive(1.)),
+                multiple_of: Some(0.5),
+            },
+        );
     }
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct ActionAffExtA {
-        c: A,
-    }
+    #[test]
+    fn serde_integer_schema() {
+        let data: IntegerSchema = serde_json::from_value(json! {
+            {
+                "minimum": 5,
+                "maximum": 10,
+                "multipleOf": 2,
+            }
+        })
+        .unwrap();
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct PropAffExtA {
-        d: A,
-    }
+        assert_eq!(
+            data,
+            IntegerSchema {
+                minimum: Some(Minimum::Inclusive(5)),
+                maximum: Some(Maximum::Inclusive(10)),
+                multiple_of: Some(NonZeroU64::new(2).unwrap()),
+            },
+        );
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct EventAffExtA {
-        e: A,
-    }
+        let data: IntegerSchema = serde_json::from_value(json! {
+            {
+                "exclusiveMinimum": 5,
+                "exclusiveMaximum": 10,
+            }
+        })
+        .unwrap();
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct FormExtA {
-        f: A,
+        assert_eq!(
+            data,
+            IntegerSchema {
+                minimum: Some(Minimum::Exclusive(5)),
+                maximum: Some(Maximum::Exclusive(10)),
+                multiple_of: None,
+            },
+        );
     }
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct RespExtA {
-        g: A,
-    }
+    #[test]
+    fn form_almost_default_serialization() {
+        let form: Form<Nil, Nil> = Form {
+            href: "href".to_string(),
+            ..Default::default()
+        };
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct DataSchemaExtA {
-        h: A,
+        let form_json = serde_json::to_value(form).unwrap();
+        assert_eq!(
+            form_json,
+            json!({
+                "href": "href",
+            }),
+        )
     }
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct ObjectSchemaExtA {
-        i: A,
-    }
+    #[test]
+    fn tighten_integers() {
+        use crate::builder::data_schema::{
+            DataSchemaBuilder, NumberDataSchemaBuilderLike, SpecializableDataSchema,
+        };
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct ArraySchemaExtA {
-        j: A,
-    }
+        let data_schema: DataSchema<Nil, Nil, Nil> = DataSchemaBuilder::default()
+            .number()
+            .minimum(0.)
+            .maximum(10.)
+            .multiple_of(1.)
+            .try_into()
+            .unwrap();
 
-    impl ExtendableThing for ThingExtA {
-        type InteractionAffordance = IntAffExtA;
-        type PropertyAffordance = PropAffExtA;
-        type ActionAffordance = ActionAffExtA;
-        type EventAffordance = EventAffExtA;
-        type Form = FormExtA;
-        type ExpectedResponse = RespExtA;
-        type DataSchema = DataSchemaExtA;
-        type ObjectSchema = ObjectSchemaExtA;
-        type ArraySchema = ArraySchemaExtA;
+        assert_eq!(
+            data_schema.tighten_integers(),
+            DataSchema {
+                subtype: Some(DataSchemaSubtype::Integer(IntegerSchema {
+                    minimum: Some(Minimum::Inclusive(0)),
+                    maximum: Some(Maximum::Inclusive(10)),
+                    multiple_of: Some(NonZeroU64::new(1).unwrap()),
+                })),
+                ..Default::default()
+            },
+        );
     }
 
     #[test]
-    fn extend_single_thing() {
-        let thing = Thing::<ThingExtA> {
-            context: "test".into(),
-            properties: Some(
-                [(
-                    "prop".to_string(),
-                    PropertyAffordance {
-                        interaction: InteractionAffordance {
-                            other: IntAffExtA { b: A(1) },
-                            ..Default::default()
-                        },
-                        data_schema: DataSchema {
-                            subtype: Some(DataSchemaSubtype::Array(ArraySchema {
-                                other: ArraySchemaExtA { j: A(2) },
-                                ..Default::default()
-                            })),
-                            other: DataSchemaExtA { h: A(3) },
-                            ..Default::default()
-                        },
-                        other: PropAffExtA { d: A(4) },
-                        ..Default::default()
-                    },
-                )]
-                .into_iter()
-                .collect(),
-            ),
-            actions: Some(
-                [(
-                    "action".to_string(),
-                    ActionAffordance {
-                        interaction: InteractionAffordance {
-                            other: IntAffExtA { b: A(5) },
-                            ..Default::default()
-                        },
-                        input: Some(DataSchema {
-                            subtype: Some(DataSchemaSubtype::Object(ObjectSchema {
-                                other: ObjectSchemaExtA { i: A(6) },
-                                ..Default::default()
-                            })),
-                            other: DataSchemaExtA { h: A(7) },
-                            ..Default::default()
-                        }),
-                        output: Some(DataSchema::default()),
-                        other: ActionAffExtA { c: A(8) },
-                        ..Default::default()
-                    },
-                )]
-                .into_iter()
-                .collect(),
-            ),
-            events: Some(
-                [(
-                    "event".to_string(),
-                    EventAffordance {
-                        other: EventAffExtA { e: A(9) },
-                        ..Default::default()
-                    },
-                )]
-                .into_iter()
-                .collect(),
-            ),
-            forms: Some(vec![Form {
-                response: Some(ExpectedResponse {
-                    other: RespExtA { g: A(10) },
+    fn tighten_integers_keeps_real_numbers() {
+        let number = NumberSchema {
+            minimum: Some(Minimum::Inclusive(0.5)),
+            maximum: None,
+            multiple_of: Some(1.),
+        };
+        assert_eq!(number.to_integer(), None);
+
+        let number = NumberSchema {
+            minimum: Some(Minimum::Exclusive(0.)),
+            maximum: None,
+            multiple_of: None,
+        };
+        assert_eq!(number.to_integer(), None);
+
+        let number = NumberSchema {
+            minimum: None,
+            maximum: Some(Maximum::Exclusive(1e20)),
+            multiple_of: Some(2.),
+        };
+        assert_eq!(number.to_integer(), None);
+
+        let data_schema = DataSchema::<Nil, Nil, Nil> {
+            one_of: Some(vec![
+                DataSchema {
+                    subtype: Some(DataSchemaSubtype::Number(NumberSchema {
+                        multiple_of: Some(2.),
+                        ..Default::default()
+                    })),
                     ..Default::default()
-                }),
-                other: FormExtA { f: A(11) },
-                ..Default::default()
-            }]),
-            schema_definitions: Some(
-                [(
-                    "schema".to_string(),
-                    DataSchema {
-                        subtype: Some(DataSchemaSubtype::Null),
-                        other: DataSchemaExtA { h: A(12) },
+                },
+                DataSchema {
+                    subtype: Some(DataSchemaSubtype::Number(NumberSchema {
+                        multiple_of: Some(0.5),
                         ..Default::default()
-                    },
-                )]
-                .into_iter()
-                .collect(),
-            ),
-            other: ThingExtA { a: A(13) },
+                    })),
+                    ..Default::default()
+                },
+            ]),
             ..Default::default()
         };
 
-        let thing_json = serde_json::to_value(thing).unwrap();
         assert_eq!(
-            thing_json,
-            json![{
-                "@context": "test",
-                "title": "",
-                "properties": {
-                    "prop": {
-                        "b": 1,
-                        "j": 2,
-                        "h": 3,
-                        "d": 4,
-                        "forms": [],
-                        "type": "array",
-                        "readOnly": false,
-                        "writeOnly": false,
-                    }
-                },
-                "actions": {
-                    "action": {
-                        "b": 5,
-                        "input": {
-                            "i": 6,
-                            "h": 7,
-                            "readOnly": false,
-                            "writeOnly": false,
-                            "type": "object",
-                        },
-                        "output": {
-                            "h": 42,
-                            "readOnly": false,
-                            "writeOnly": false,
-                        },
-                        "forms": [],
-                        "idempotent": false,
-                        "safe": false,
-                        "c": 8,
-                    }
-                },
-                "events": {
-                    "event": {
-                        "b": 42,
-                        "e": 9,
-                        "forms": [],
-                    }
+            data_schema.tighten_integers().one_of.unwrap(),
+            vec![
+                DataSchema {
+                    subtype: Some(DataSchemaSubtype::Integer(IntegerSchema {
+                        multiple_of: Some(NonZeroU64::new(2).unwrap()),
+                        ..Default::default()
+                    })),
+                    ..Default::default()
                 },
-                "forms": [{
-                    "href": "",
-                    "response": {
-                        "contentType": "",
-                        "g": 10,
-                    },
-                    "f": 11,
-                }],
-                "schemaDefinitions": {
-                    "schema": {
-                        "type": "null",
-                        "readOnly": false,
-                        "writeOnly": false,
-                        "h": 12,
-                    }
+                DataSchema {
+                    subtype: Some(DataSchemaSubtype::Number(NumberSchema {
+                        multiple_of: Some(0.5),
+                        ..Default::default()
+                    })),
+                    ..Default::default()
                 },
-                "security": [],
-                "securityDefinitions": {},
-                "a": 13,
-            }],
+            ],
         );
     }
 
     #[test]
-    fn extend_single_thing_with_hlist() {
-        let thing = Thing::<Cons<ThingExtA, Nil>> {
-            context: "test".into(),
-            properties: Some(
-                [(
-                    "prop".to_string(),
-                    PropertyAffordance {
-                        interaction: InteractionAffordance {
-                            other: Nil::cons(IntAffExtA { b: A(1) }),
-                            ..Default::default()
-                        },
-                        data_schema: DataSchema {
-                            subtype: Some(DataSchemaSubtype::Array(ArraySchema {
-                                other: Nil::cons(ArraySchemaExtA { j: A(2) }),
-                                ..Default::default()
-                            })),
-                            other: Nil::cons(DataSchemaExtA { h: A(3) }),
-                            ..Default::default()
-                        },
-                        other: Nil::cons(PropAffExtA { d: A(4) }),
-                        ..Default::default()
-                    },
-                )]
-                .into_iter()
-                .collect(),
-            ),
-            actions: Some(
-                [(
-                    "action".to_string(),
-                    ActionAffordance {
-                        interaction: InteractionAffordance {
-                            other: Nil::cons(IntAffExtA { b: A(5) }),
-                            ..Default::default()
-                        },
-                        input: Some(DataSchema {
-                            subtype: Some(DataSchemaSubtype::Object(ObjectSchema {
-                                other: Nil::cons(ObjectSchemaExtA { i: A(6) }),
-                                ..Default::default()
-                            })),
-                            other: Nil::cons(DataSchemaExtA { h: A(7) }),
-                            ..Default::default()
-                        }),
-                        output: Some(DataSchema::default()),
-                        other: Nil::cons(ActionAffExtA { c: A(8) }),
-                        ..Default::default()
-                    },
-                )]
-                .into_iter()
-                .collect(),
-            ),
-            events: Some(
-                [(
-                    "event".to_string(),
-                    EventAffordance {
-                        other: Nil::cons(EventAffExtA { e: A(9) }),
-                        ..Default::default()
-                    },
-                )]
-                .into_iter()
-                .collect(),
-            ),
-            forms: Some(vec![Form {
-                response: Some(ExpectedResponse {
-                    other: Nil::cons(RespExtA { g: A(10) }),
-                    ..Default::default()
-                }),
-                other: Nil::cons(FormExtA { f: A(11) }),
+    fn affordance_schema_accessors() {
+        use crate::builder::{
+            affordance::BuildableInteractionAffordance, data_schema::SpecializableDataSchema,
+        };
+
+        let thing = Thing::builder("Thing name")
+            .finish_extend()
+            .action("action", |b| {
+                b.input(|b| b.finish_extend().number())
+                    .output(|b| b.finish_extend().string())
+                    .form(|b| b.href("/action"))
+            })
+            .event("event", |b| {
+                b.data(|b| b.finish_extend().integer())
+                    .subscription(|b| b.finish_extend().bool())
+                    .form(|b| b.href("/event"))
+            })
+            .build()
+            .unwrap();
+
+        let action = &thing.actions.as_ref().unwrap()["action"];
+        assert!(matches!(
+            action.input_schema().unwrap().subtype,
+            Some(DataSchemaSubtype::Number(_)),
+        ));
+        assert!(matches!(
+            action.output_schema().unwrap().subtype,
+            Some(DataSchemaSubtype::String(_)),
+        ));
+
+        let event = &thing.events.as_ref().unwrap()["event"];
+        assert!(matches!(
+            event.data_schema().unwrap().subtype,
+            Some(DataSchemaSubtype::Integer(_)),
+        ));
+        assert!(matches!(
+            event.subscription_schema().unwrap().subtype,
+            Some(DataSchemaSubtype::Boolean),
+        ));
+        assert!(event.cancellation_schema().is_none());
+        assert!(event.data_response_schema().is_none());
+    }
+
+    #[test]
+    fn compact_floats_serialization() {
+        use crate::builder::data_schema::{
+            BuildableDataSchema, NumberDataSchemaBuilderLike, SpecializableDataSchema,
+            VecDataSchemaBuilderLike,
+        };
+
+        let thing = Thing::builder("Thing name")
+            .finish_extend()
+            .schema_definition("test", |b| {
+                b.finish_extend().vec().set_item(|b| {
+                    b.finish_extend()
+                        .number()
+                        .minimum(-2.)
+                        .exclusive_maximum(5.5)
+                        .multiple_of(0.5)
+                })
+            })
+            .schema_definition("other", |b| {
+                b.finish_extend()
+                    .number()
+                    .exclusive_minimum(1e20)
+                    .maximum(1e21)
+                    .multiple_of(2.)
+                    .default_value(3.0)
+            })
+            .build()
+            .unwrap();
+
+        let default = thing.to_value_with(SerializeOptions::default()).unwrap();
+        assert_eq!(default, serde_json::to_value(&thing).unwrap());
+        assert_eq!(
+            serde_json::to_string(&default["schemaDefinitions"]["test"]["items"]).unwrap(),
+            r#"{"exclusiveMaximum":5.5,"minimum":-2.0,"multipleOf":0.5,"readOnly":false,"type":"number","writeOnly":false}"#,
+        );
+
+        let compact = thing
+            .to_value_with(SerializeOptions {
+                compact_floats: true,
                 ..Default::default()
-            }]),
-            other: Nil::cons(ThingExtA { a: A(12) }),
+            })
+            .unwrap();
+        assert_eq!(
+            serde_json::to_string(&compact["schemaDefinitions"]["test"]["items"]).unwrap(),
+            r#"{"exclusiveMaximum":5.5,"minimum":-2,"multipleOf":0.5,"readOnly":false,"type":"number","writeOnly":false}"#,
+        );
+        assert_eq!(
+            serde_json::to_string(&compact["schemaDefinitions"]["other"]).unwrap(),
+            r#"{"default":3.0,"exclusiveMinimum":1e+20,"maximum":1e+21,"multipleOf":2,"readOnly":false,"type":"number","writeOnly":false}"#,
+        );
+
+        let deserialized: Thing = serde_json::from_value(compact).unwrap();
+        assert_eq!(deserialized, thing);
+        let Some(DataSchemaSubtype::Number(number)) =
+            &deserialized.schema_definitions.as_ref().unwrap()["other"].subtype
+        else {
+            unreachable!()
+        };
+        assert_eq!(number.multiple_of, Some(2.));
+        assert_eq!(number.maximum, Some(Maximum::Inclusive(1e21)));
+    }
+
+    #[test]
+    fn write_json_with_omitted_default_flags() {
+        use crate::builder::{
+            affordance::BuildableInteractionAffordance,
+            data_schema::{
+                BuildableDataSchema, ReadableWriteableDataSchema, SpecializableDataSchema,
+            },
+        };
+
+        let thing = Thing::builder("Thing name")
+            .finish_extend()
+            .property("on", |b| {
+                b.finish_extend_data_schema()
+                    .bool()
+                    .read_only()
+                    .form(|b| b.href("/on"))
+            })
+            .action("toggle", |b| b.idempotent().form(|b| b.href("/toggle")))
+            .schema_definition("level", |b| {
+                b.finish_extend()
+                    .integer()
+                    .default_value(json!({ "safe": false }))
+            })
+            .build()
+            .unwrap();
+
+        let mut buffer = Vec::new();
+        let written = thing.write_json(&mut buffer).unwrap();
+        assert_eq!(written, buffer.len());
+        assert_eq!(buffer, serde_json::to_vec(&thing).unwrap());
+
+        let options = SerializeOptions {
+            omit_default_flags: true,
             ..Default::default()
         };
+        let mut minimal = Vec::new();
+        let written = thing.write_json_with(&mut minimal, options).unwrap();
+        assert_eq!(written, minimal.len());
+        assert!(minimal.len() < buffer.len());
 
-        let thing_json = serde_json::to_value(thing).unwrap();
+        let value: Value = serde_json::from_slice(&minimal).unwrap();
+        assert_eq!(value, thing.to_value_with(options).unwrap());
         assert_eq!(
-            thing_json,
+            value["properties"]["on"],
             json!({
-                "@context": "test",
-                "title": "",
-                "properties": {
-                    "prop": {
-                        "b": 1,
-                        "j": 2,
-                        "h": 3,
-                        "d": 4,
-                        "forms": [],
-                        "type": "array",
-                        "readOnly": false,
-                        "writeOnly": false,
-                    }
-                },
-                "actions": {
-                    "action": {
-                        "b": 5,
-                        "input": {
-                            "i": 6,
-                            "h": 7,
-                            "readOnly": false,
-                            "writeOnly": false,
-                            "type": "object",
-                        },
-                        "output": {
-                            "h": 42,
-                            "readOnly": false,
-                            "writeOnly": false,
-                        },
-                        "forms": [],
-                        "idempotent": false,
-                        "safe": false,
-                        "c": 8,
-                    }
-                },
-                "events": {
-                    "event": {
-                        "b": 42,
-                        "e": 9,
-                        "forms": [],
-                    }
-                },
-                "forms": [{
-                    "href": "",
-                    "response": {
-                        "contentType": "",
-                        "g": 10,
-                    },
-                    "f": 11,
-                }],
-                "security": [],
-                "securityDefinitions": {},
-                "a": 12,
+                "type": "boolean",
+                "readOnly": true,
+                "forms": [{ "href": "/on", "op": ["readproperty"] }],
+            }),
+        );
+        assert_eq!(
+            value["actions"]["toggle"],
+            json!({
+                "idempotent": true,
+                "forms": [{ "href": "/toggle", "op": ["invokeaction"] }],
+            }),
+        );
+        assert_eq!(
+            value["schemaDefinitions"]["level"],
+            json!({
+                "type": "integer",
+                "default": { "safe": false },
             }),
         );
     }
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct ThingExtB {
-        k: A,
-    }
+    #[test]
+    fn omit_default_content_type() {
+        let mut thing: Thing = serde_json::from_value(json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Thing name",
+            "security": [],
+            "securityDefinitions": {},
+            "forms": [
+                { "href": "/all", "op": "readallproperties", "contentType": "application/json" },
+            ],
+            "properties": {
+                "on": {
+                    "type": "boolean",
+                    "forms": [
+                        { "href": "/on", "contentType": "application/cbor" },
+                        { "href": "/on.txt", "contentType": "text/plain" },
+                    ],
+                },
+            },
+        }))
+        .unwrap();
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct IntAffExtB {
-        l: A,
-    }
+        let options = SerializeOptions {
+            omit_default_content_type: true,
+            ..Default::default()
+        };
+        let value = thing.to_value_with(options).unwrap();
+        assert!(value["forms"][0].get("contentType").is_none());
+        assert_eq!(
+            value["properties"]["on"]["forms"][0]["contentType"],
+            "application/cbor"
+        );
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct ActionAffExtB {
-        m: A,
-    }
+        thing.default_content_type = Some("application/cbor".to_string());
+        let value = thing.to_value_with(options).unwrap();
+        assert_eq!(value["forms"][0]["contentType"], "application/json");
+        assert!(value["properties"]["on"]["forms"][0]
+            .get("contentType")
+            .is_none());
+        assert_eq!(
+            value["properties"]["on"]["forms"][1]["contentType"],
+            "text/plain"
+        );
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct PropAffExtB {
-        n: A,
+        let default = thing.to_value_with(SerializeOptions::default()).unwrap();
+        assert_eq!(
+            default["properties"]["on"]["forms"][0]["contentType"],
+            "application/cbor"
+        );
     }
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct EventAffExtB {
-        o: A,
-    }
+    #[test]
+    fn unused_uri_variables() {
+        let thing: Thing = serde_json::from_value(json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Thing name",
+            "uriVariables": {
+                "page": { "type": "integer" },
+                "unused": { "type": "integer" },
+            },
+            "properties": {
+                "items": {
+                    "type": "array",
+                    "uriVariables": {
+                        "id": { "type": "string" },
+                        "filter": { "type": "string" },
+                    },
+                    "forms": [
+                        { "href": "/items/{id}{?page}" },
+                        { "href": "/items/{malformed" },
+                    ],
+                },
+            },
+            "security": "nosec",
+            "securityDefinitions": {
+                "nosec": { "scheme": "nosec" },
+            },
+        }))
+        .unwrap();
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct FormExtB {
-        p: A,
+        assert_eq!(thing.unused_uri_variables(), ["filter", "unused"]);
+        assert_eq!(
+            thing.properties.unwrap()["items"].interaction.forms[1].href_template_variables(),
+            Err(TemplateError::UnclosedExpression(7)),
+        );
     }
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct RespExtB {
-        q: A,
-    }
+    #[test]
+    fn resolve_uri_variables_precedence() {
+        let mut thing: Thing = serde_json::from_value(json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Thing name",
+            "uriVariables": {
+                "id": { "type": "integer" },
+                "page": { "type": "integer" },
+            },
+            "properties": {
+                "item": {
+                    "type": "object",
+                    "uriVariables": {
+                        "id": { "type": "string" },
+                        "fields": { "type": "string" },
+                    },
+                    "forms": [{ "href": "/items/{id}{?page,fields}" }],
+                },
+                "count": {
+                    "type": "integer",
+                    "forms": [{ "href": "/items/count{?page}" }],
+                },
+            },
+            "security": "nosec",
+            "securityDefinitions": {
+                "nosec": { "scheme": "nosec" },
+            },
+        }))
+        .unwrap();
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct DataSchemaExtB {
-        r: A,
-    }
+        let properties = thing.properties.as_ref().unwrap();
+        let item = thing.resolve_uri_variables(&properties["item"].interaction);
+        assert!(matches!(item, Cow::Owned(_)));
+        let mut names = item.keys().map(String::as_str).collect::<Vec<_>>();
+        names.sort_unstable();
+        assert_eq!(names, ["fields", "id", "page"]);
+        assert_eq!(
+            item["id"].subtype,
+            Some(DataSchemaSubtype::String(Default::default())),
+        );
+        assert_eq!(
+            item["page"].subtype,
+            Some(DataSchemaSubtype::Integer(Default::default())),
+        );
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct ObjectSchemaExtB {
-        s: A,
-    }
+        let count = thing.resolve_uri_variables(&properties["count"].interaction);
+        assert!(matches!(&count, Cow::Borrowed(map) if map.len() == 2));
+        assert_eq!(thing.check_href_templates(), Ok(()));
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct ArraySchemaExtB {
-        t: A,
-    }
+        let uri_variables = thing.uri_variables.take();
+        let properties = thing.properties.as_ref().unwrap();
+        let item = thing.resolve_uri_variables(&properties["item"].interaction);
+        assert!(matches!(&item, Cow::Borrowed(map) if map.len() == 2));
+        let count = thing.resolve_uri_variables(&properties["count"].interaction);
+        assert!(matches!(&count, Cow::Owned(map) if map.is_empty()));
+        assert_eq!(
+            thing.check_href_templates(),
+            Err(AffordanceError::UndeclaredUriVariable("page".to_string()).into()),
+        );
 
-    impl ExtendableThing for ThingExtB {
-        type InteractionAffordance = IntAffExtB;
-        type PropertyAffordance = PropAffExtB;
-        type ActionAffordance = ActionAffExtB;
-        type EventAffordance = EventAffExtB;
-        type Form = FormExtB;
-        type ExpectedResponse = RespExtB;
-        type DataSchema = DataSchemaExtB;
-        type ObjectSchema = ObjectSchemaExtB;
-        type ArraySchema = ArraySchemaExtB;
+        thing.uri_variables = uri_variables;
+        thing.forms = Some(vec![Form {
+            href: "/items{?fields}".to_string(),
+            ..Default::default()
+        }]);
+        assert_eq!(
+            thing.check_href_templates(),
+            Err(AffordanceError::UndeclaredUriVariable("fields".to_string()).into()),
+        );
+
+        // The bulk operations on the properties imply a variable for each property.
+        thing.forms = Some(vec![Form {
+            op: DefaultedFormOperations::Custom(vec![FormOperation::ReadMultipleProperties]),
+            href: "/items{?item,count,page}".to_string(),
+            ..Default::default()
+        }]);
+        assert_eq!(thing.check_href_templates(), Ok(()));
+        let form = &thing.forms.as_ref().unwrap()[0];
+        let properties = thing.properties.as_ref().unwrap();
+        assert_eq!(
+            thing.resolve_uri_variable(None, form, "count"),
+            Some(&properties["count"].data_schema),
+        );
+        assert_eq!(
+            thing.resolve_uri_variable(None, form, "page"),
+            Some(&thing.uri_variables.as_ref().unwrap()["page"]),
+        );
+        assert_eq!(
+            thing.resolve_uri_variable(Some(&properties["item"].interaction), form, "count"),
+            None,
+        );
     }
 
     #[test]
-    fn extend_thing_with_two() {
-        let thing = Thing::<Cons<ThingExtB, Cons<ThingExtA, Nil>>> {
-            context: "test".into(),
-            properties: Some(
-                [(
-                    "prop".to_string(),
-                    PropertyAffordance {
-                        interaction: InteractionAffordance {
-                            other: Nil::cons(IntAffExtA { b: A(1) }).cons(IntAffExtB { l: A(2) }),
-                            ..Default::default()
-                        },
-                        data_schema: DataSchema {
-                            subtype: Some(DataSchemaSubtype::Array(ArraySchema {
-                                other: Nil::cons(ArraySchemaExtA { j: A(3) })
-                                    .cons(ArraySchemaExtB { t: A(4) }),
-                                ..Default::default()
-                            })),
-                            other: Nil::cons(DataSchemaExtA { h: A(5) })
-                                .cons(DataSchemaExtB { r: A(6) }),
-                            ..Default::default()
-                        },
-                        other: Nil::cons(PropAffExtA { d: A(7) }).cons(PropAffExtB { n: A(8) }),
-                        ..Default::default()
-                    },
-                )]
-                .into_iter()
-                .collect(),
+    fn validate_empty_names() {
+        let base = json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Thing name",
+            "security": ["nosec"],
+            "securityDefinitions": {
+                "nosec": { "scheme": "nosec" },
+            },
+        });
+        let thing: Thing = serde_json::from_value(base.clone()).unwrap();
+        assert_eq!(thing.validate(), Ok(()));
+
+        let object = |properties: Value, required: Value| {
+            json!({
+                "on": {
+                    "type": "object",
+                    "properties": properties,
+                    "required": required,
+                    "forms": [{ "href": "/on" }],
+                },
+            })
+        };
+        let cases = [
+            (
+                "properties",
+                json!({ " ": { "forms": [{ "href": "/on" }] } }),
+                NameKind::Property,
             ),
-            actions: Some(
-                [(
-                    "action".to_string(),
-                    ActionAffordance {
-                        interaction: InteractionAffordance {
-                            other: Nil::cons(IntAffExtA { b: A(9) }).cons(IntAffExtB { l: A(10) }),
-                            ..Default::default()
-                        },
-                        input: Some(DataSchema {
-                            subtype: Some(DataSchemaSubtype::Object(ObjectSchema {
-                                other: Nil::cons(ObjectSchemaExtA { i: A(11) })
-                                    .cons(ObjectSchemaExtB { s: A(12) }),
-                                ..Default::default()
-                            })),
-                            other: Nil::cons(DataSchemaExtA { h: A(13) })
-                                .cons(DataSchemaExtB { r: A(14) }),
-                            ..Default::default()
-                        }),
-                        output: Some(DataSchema::default()),
-                        other: Nil::cons(ActionAffExtA { c: A(15) })
-                            .cons(ActionAffExtB { m: A(16) }),
-                        ..Default::default()
-                    },
-                )]
-                .into_iter()
-                .collect(),
+            (
+                "actions",
+                json!({ "": { "forms": [{ "href": "/fade" }] } }),
+                NameKind::Action,
             ),
-            events: Some(
-                [(
-                    "event".to_string(),
-                    EventAffordance {
-                        other: Nil::cons(EventAffExtA { e: A(17) }).cons(EventAffExtB { o: A(18) }),
-                        ..Default::default()
-                    },
-                )]
-                .into_iter()
-                .collect(),
+            (
+                "events",
+                json!({ "\n": { "forms": [{ "href": "/overheat" }] } }),
+                NameKind::Event,
             ),
-            forms: Some(vec![Form {
-                response: Some(ExpectedResponse {
-                    other: Nil::cons(RespExtA { g: A(19) }).cons(RespExtB { q: A(20) }),
-                    ..Default::default()
-                }),
-                other: Nil::cons(FormExtA { f: A(21) }).cons(FormExtB { p: A(22) }),
-                ..Default::default()
-            }]),
-            other: Nil::cons(ThingExtA { a: A(23) }).cons(ThingExtB { k: A(24) }),
-            ..Default::default()
-        };
+            (
+                "properties",
+                object(json!({ "": { "type": "null" } }), json!([])),
+                NameKind::ObjectProperty,
+            ),
+            (
+                "properties",
+                object(json!({ "a": { "type": "null" } }), json!(["a", " "])),
+                NameKind::Required,
+            ),
+            (
+                "uriVariables",
+                json!({ " ": { "type": "integer" } }),
+                NameKind::UriVariable,
+            ),
+            (
+                "schemaDefinitions",
+                json!({ "": { "type": "null" } }),
+                NameKind::SchemaDefinition,
+            ),
+            (
+                "securityDefinitions",
+                json!({ "nosec": { "scheme": "nosec" }, " ": { "scheme": "nosec" } }),
+                NameKind::SecurityDefinition,
+            ),
+            ("security", json!(["nosec", ""]), NameKind::Security),
+            (
+                "forms",
+                json!([{ "href": "/all", "op": "readallproperties", "security": [" "] }]),
+                NameKind::Security,
+            ),
+        ];
+
+        for (member, value, kind) in cases {
+            let mut td = base.clone();
+            td[member] = value;
+            let thing: Thing = serde_json::from_value(td).unwrap();
+            assert_eq!(
+                thing.validate(),
+                Err(DocumentError::EmptyName(kind).into()),
+                "{member}"
+            );
+        }
+    }
+
+    #[cfg(feature = "jsonld")]
+    #[test]
+    fn json_ld_expanded_property_type() {
+        let thing: Thing = serde_json::from_value(json!({
+            "@context": [
+                TD_CONTEXT_11,
+                { "saref": "https://w3id.org/saref#" },
+                { "om": { "@id": "http://www.ontology-of-units-of-measure.org/resource/om-2/" } },
+            ],
+            "title": "Thing name",
+            "@type": ["saref:Sensor", "tm:ThingModel"],
+            "properties": {
+                "saref:temperature": {
+                    "@type": "saref:Temperature",
+                    "type": "number",
+                    "unit": "om:degreeCelsius",
+                    "titles": { "it": "Temperatura" },
+                    "forms": [{ "href": "http://example.com/temperature" }],
+                },
+            },
+            "security": ["nosec"],
+            "securityDefinitions": {
+                "nosec": { "scheme": "nosec" },
+            },
+        }))
+        .unwrap();
 
-        let thing_json = serde_json::to_value(thing).unwrap();
         assert_eq!(
-            thing_json,
+            thing.to_json_ld_expanded().unwrap(),
             json!({
-                "@context": "test",
-                "title": "",
+                "title": "Thing name",
+                "@type": [
+                    "https://w3id.org/saref#Sensor",
+                    "https://www.w3.org/2019/wot/tm#ThingModel",
+                ],
                 "properties": {
-                    "prop": {
-                        "b": 1,
-                        "l": 2,
-                        "j": 3,
-                        "t": 4,
-                        "h": 5,
-                        "r": 6,
-                        "d": 7,
-                        "n": 8,
-                        "forms": [],
-                        "type": "array",
-                        "readOnly": false,
-                        "writeOnly": false,
-                    }
-                },
-                "actions": {
-                    "action": {
-                        "b": 9,
-                        "l": 10,
-                        "input": {
-                            "i": 11,
-                            "s": 12,
-                            "h": 13,
-                            "r": 14,
-                            "readOnly": false,
-                            "writeOnly": false,
-                            "type": "object",
-                        },
-                        "output": {
-                            "h": 42,
-                            "r": 42,
-                            "readOnly": false,
-                            "writeOnly": false,
-                        },
-                        "forms": [],
-                        "idempotent": false,
-                        "safe": false,
-                        "c": 15,
-                        "m": 16,
-                    }
-                },
-                "events": {
-                    "event": {
-                        "b": 42,
-                        "l": 42,
-                        "e": 17,
-                        "o": 18,
-                        "forms": [],
-                    }
-                },
-                "forms": [{
-                    "href": "",
-                    "response": {
-                        "contentType": "",
-                        "g": 19,
-                        "q": 20,
+                    "saref:temperature": {
+                        "@type": ["https://w3id.org/saref#Temperature"],
+                        "type": "number",
+                        "unit": "om:degreeCelsius",
+                        "titles": { "it": "Temperatura" },
+                        "readOnly": false,
+                        "writeOnly": false,
+                        "forms": [{ "href": "http://example.com/temperature" }],
                     },
-                    "f": 21,
-                    "p": 22,
-                }],
+                },
+                "security": ["nosec"],
+                "securityDefinitions": {
+                    "nosec": { "scheme": "nosec" },
+                },
+            }),
+        );
+    }
+
+    #[test]
+    fn merge_context_of_things_sharing_http_binding() {
+        let http = json!({ "htv": "http://www.w3.org/2011/http#" });
+        let thing = |context: Value| -> Thing {
+            serde_json::from_value(json!({
+                "@context": context,
+                "title": "Thing name",
                 "security": [],
                 "securityDefinitions": {},
-                "a": 23,
-                "k": 24,
+            }))
+            .unwrap()
+        };
+
+        let mut lamp = thing(json!([TD_CONTEXT_11, http]));
+        let sensor = thing(json!([
+            "https://example.org/context",
+            http,
+            TD_CONTEXT_11,
+            { "htv": "http://www.w3.org/2011/http#", "ex": "https://example.org/" },
+        ]));
+
+        lamp.merge_context(&sensor.context);
+        assert_eq!(
+            lamp.context,
+            json!([
+                TD_CONTEXT_11,
+                http,
+                "https://example.org/context",
+                { "ex": "https://example.org/" },
+            ]),
+        );
+
+        let mut thing = thing(json!(TD_CONTEXT_11));
+        thing.merge_context(&json!([TD_CONTEXT_11]));
+        assert_eq!(thing.context, json!(TD_CONTEXT_11));
+    }
+
+    #[test]
+    fn content_type_resolution_order() {
+        let mut thing = Thing::<Nil>::default();
+        let mut form = Form::<Nil, Nil> {
+            href: "/on".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(form.content_type_or(&thing), DEFAULT_CONTENT_TYPE);
+
+        thing.default_content_type = Some("application/cbor".to_string());
+        assert_eq!(form.content_type_or(&thing), "application/cbor");
+
+        form.content_type = Some("text/plain".to_string());
+        assert_eq!(form.content_type_or(&thing), "text/plain");
+
+        thing.default_content_type = None;
+        assert_eq!(form.content_type_or(&thing), "text/plain");
+    }
+
+    #[test]
+    fn additional_response_content_type_chain() {
+        let mut thing = Thing::<Nil>::default();
+        let mut form = Form::<Nil, Nil> {
+            href: "/on".to_string(),
+            additional_responses: Some(vec![AdditionalExpectedResponse {
+                schema: Some("error".to_string()),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        let response =
+            |form: &Form<Nil, Nil>| form.additional_responses.as_ref().unwrap()[0].clone();
+
+        assert_eq!(response(&form).content_type_or(&form), None);
+        assert_eq!(
+            response(&form).resolved_content_type(&form, &thing),
+            DEFAULT_CONTENT_TYPE,
+        );
+
+        thing.default_content_type = Some("application/cbor".to_string());
+        assert_eq!(response(&form).content_type_or(&form), None);
+        assert_eq!(
+            response(&form).resolved_content_type(&form, &thing),
+            "application/cbor",
+        );
+
+        form.content_type = Some("text/plain".to_string());
+        assert_eq!(response(&form).content_type_or(&form), Some("text/plain"));
+        assert_eq!(
+            response(&form).resolved_content_type(&form, &thing),
+            "text/plain",
+        );
+
+        form.additional_responses.as_mut().unwrap()[0].content_type =
+            Some("application/problem+json".to_string());
+        assert_eq!(
+            response(&form).content_type_or(&form),
+            Some("application/problem+json"),
+        );
+        assert_eq!(
+            response(&form).resolved_content_type(&form, &thing),
+            "application/problem+json",
+        );
+
+        form.additional_responses.as_mut().unwrap()[0].content_type = None;
+        assert_eq!(
+            serde_json::to_value(&form).unwrap(),
+            json!({
+                "href": "/on",
+                "contentType": "text/plain",
+                "additionalResponses": { "schema": "error" },
             }),
         );
     }
 
     #[test]
-    fn dummy_http() {
-        #[derive(Serialize, Deserialize, Default)]
-        struct HttpThing {}
+    fn standalone_form_helpers() {
+        use crate::protocol::http::{self, HttpProtocol, Method};
 
-        #[derive(Deserialize, Serialize)]
-        #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-        enum HttpMethod {
-            Get,
-            Put,
-            Post,
-            Delete,
-            Patch,
+        fn methods<ER>(forms: &[Form<http::Form, ER>]) -> Vec<Option<Method>> {
+            forms.iter().map(|form| form.other.method_name).collect()
         }
 
-        #[derive(Deserialize, Serialize)]
-        struct HttpMessageHeader {
-            #[serde(rename = "htv:fieldName")]
-            field_name: Option<String>,
-            #[serde(rename = "htv:fieldValue")]
-            field_value: Option<String>,
-        }
+        let thing: Thing<HttpProtocol> = serde_json::from_value(json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Thing name",
+            "security": [],
+            "securityDefinitions": {},
+            "forms": [
+                { "href": "/all", "op": "readallproperties", "htv:methodName": "GET" },
+                { "href": "/all", "op": "writeallproperties" },
+            ],
+        }))
+        .unwrap();
 
-        #[derive(Deserialize, Serialize, Default)]
-        struct HttpResponse {
-            #[serde(rename = "htv:headers")]
-            headers: Vec<HttpMessageHeader>,
-            #[serde(rename = "htv:statusCodeValue")]
-            status_code_value: Option<usize>,
-        }
+        let forms: &[FormFromOther<HttpProtocol>] = thing.forms.as_deref().unwrap();
+        assert_eq!(methods(forms), [Some(Method::Get), None]);
+        assert_eq!(forms[1].content_type_or(&thing), DEFAULT_CONTENT_TYPE);
+    }
 
-        #[derive(Default, Deserialize, Serialize)]
-        struct HttpForm {
-            #[serde(rename = "htv:methodName")]
-            method_name: Option<HttpMethod>,
-        }
+    #[test]
+    fn default_content_type_is_not_serialized_nor_compared() {
+        let thing = Thing::<Nil> {
+            title: "Thing name".to_string(),
+            default_content_type: Some("application/cbor".to_string()),
+            ..Default::default()
+        };
 
-        impl ExtendableThing for HttpThing {
-            type InteractionAffordance = ();
-            type PropertyAffordance = ();
-            type ActionAffordance = ();
-            type EventAffordance = ();
-            type Form = HttpForm;
-            type ExpectedResponse = HttpResponse;
-            type DataSchema = ();
-            type ObjectSchema = ();
-            type ArraySchema = ();
-        }
+        let value = serde_json::to_value(&thing).unwrap();
+        assert!(value.get("defaultContentType").is_none());
 
-        let thing = Thing::<Cons<ThingExtB, Cons<HttpThing, Cons<ThingExtA, Nil>>>> {
-            context: "test".into(),
-            properties: Some(
-                [(
-                    "prop".to_string(),
-                    PropertyAffordance {
-                        interaction: InteractionAffordance {
-                            other: Nil::cons(IntAffExtA { b: A(1) })
-                                .cons(())
-                                .cons(IntAffExtB { l: A(2) }),
-                            ..Default::default()
-                        },
-                        data_schema: DataSchema {
-                            subtype: Some(DataSchemaSubtype::Array(ArraySchema {
-                                other: Nil::cons(ArraySchemaExtA { j: A(3) })
-                                    .cons(())
-                                    .cons(ArraySchemaExtB { t: A(4) }),
-                                ..Default::default()
-                            })),
-                            other: Nil::cons(DataSchemaExtA { h: A(5) })
-                                .cons(())
-                                .cons(DataSchemaExtB { r: A(6) }),
-                            ..Default::default()
-                        },
-                        other: Nil::cons(PropAffExtA { d: A(7) })
-                            .cons(())
-                            .cons(PropAffExtB { n: A(8) }),
-                        ..Default::default()
-                    },
-                )]
+        let deserialized: Thing = serde_json::from_value(value).unwrap();
+        assert_eq!(deserialized.default_content_type, None);
+        assert_eq!(deserialized, thing);
+    }
+
+    #[test]
+    fn security_for_form() {
+        let thing: Thing = serde_json::from_value(json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Thing name",
+            "properties": {
+                "on": {
+                    "type": "boolean",
+                    "forms": [
+                        { "href": "/on" },
+                        { "href": "/on/secure", "security": ["combo", "missing"] },
+                        { "href": "/on/basic", "security": "basic" },
+                    ],
+                },
+            },
+            "security": ["nosec"],
+            "securityDefinitions": {
+                "nosec": { "scheme": "nosec" },
+                "basic": { "scheme": "basic" },
+                "bearer": { "scheme": "bearer" },
+                "combo": { "scheme": "combo", "oneOf": ["basic", "bearer"] },
+            },
+        }))
+        .unwrap();
+
+        let forms = &thing.properties.as_ref().unwrap()["on"].interaction.forms;
+        let names = |form| {
+            thing
+                .security_for_form(form)
                 .into_iter()
-                .collect(),
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(&forms[0]), ["nosec"]);
+        assert!(!thing.requires_auth(&forms[0]));
+
+        assert_eq!(names(&forms[1]), ["basic", "bearer"]);
+        assert!(thing.requires_auth(&forms[1]));
+
+        let schemes = thing.security_for_form(&forms[2]);
+        assert_eq!(schemes.len(), 1);
+        assert_eq!(schemes[0].0, "basic");
+        assert_eq!(
+            schemes[0].1.subtype,
+            SecuritySchemeSubtype::Known(KnownSecuritySchemeSubtype::Basic(Default::default())),
+        );
+        assert!(thing.requires_auth(&forms[2]));
+
+        let unresolved = Form {
+            security: Some(vec!["missing".to_string()]),
+            ..Default::default()
+        };
+        assert!(thing.security_for_form(&unresolved).is_empty());
+        assert!(thing.requires_auth(&unresolved));
+    }
+
+    #[test]
+    fn validate_uris() {
+        let thing = |id: &str, href: &str| -> Thing {
+            serde_json::from_value(json!({
+                "@context": TD_CONTEXT_11,
+                "id": id,
+                "title": "Thing name",
+                "security": [],
+                "securityDefinitions": {},
+                "forms": [{ "href": href, "op": "readallproperties" }],
+            }))
+            .unwrap()
+        };
+
+        assert_eq!(thing("urn:my-lamp", "/all").validate(), Ok(()));
+
+        let err = thing("urn:my lamp", "/all").validate().unwrap_err();
+        assert_eq!(err.path(), Some("/id"));
+        assert_eq!(
+            err.without_path(),
+            &Error::Document(DocumentError::InvalidUri("urn:my lamp".to_string())),
+        );
+
+        let err = thing("urn:my-lamp", "/my all").validate().unwrap_err();
+        assert_eq!(err.path(), Some("/forms/0/href"));
+    }
+
+    #[test]
+    fn validate_thing_model_links() {
+        let thing = |links: Value| -> Thing {
+            serde_json::from_value(json!({
+                "@context": TD_CONTEXT_11,
+                "title": "Thing name",
+                "security": [],
+                "securityDefinitions": {},
+                "links": links,
+            }))
+            .unwrap()
+        };
+
+        let model = json!({ "href": "lamp.tm.jsonld", "rel": "type", "type": TM_MEDIA_TYPE });
+        let html = json!({ "href": "lamp.html", "rel": "type", "type": "text/html" });
+        assert_eq!(thing(json!([model, html])).validate(), Ok(()));
+        assert_eq!(
+            thing(json!([
+                model,
+                { "href": "other.tm.jsonld", "rel": "alternate type", "type": TM_MEDIA_TYPE },
+            ]))
+            .validate(),
+            Err(DocumentError::MultipleThingModelLinks.into()),
+        );
+
+        let links = json!([{ "href": "lamp.tm.jsonld", "rel": ["type"], "type": TM_MEDIA_TYPE }]);
+        assert!(serde_json::from_value::<Thing>(json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Thing name",
+            "security": [],
+            "securityDefinitions": {},
+            "links": links,
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn validate_const_and_enum() {
+        let thing = |data_schema: Value| -> Thing {
+            serde_json::from_value(json!({
+                "@context": TD_CONTEXT_11,
+                "title": "Thing name",
+                "security": [],
+                "securityDefinitions": {},
+                "schemaDefinitions": { "test": data_schema },
+            }))
+            .unwrap()
+        };
+
+        let cases = [
+            (json!({ "type": "string", "const": "a" }), Ok(())),
+            (json!({ "type": "string", "enum": ["a", "b"] }), Ok(())),
+            (
+                json!({ "const": "a", "enum": ["a"] }),
+                Err(DataSchemaError::ConstAndEnum.into()),
             ),
-            actions: Some(
-                [(
-                    "action".to_string(),
-                    ActionAffordance {
-                        interaction: InteractionAffordance {
-                            forms: vec![Form {
-                                other: Nil::cons(FormExtA::default())
-                                    .cons(HttpForm {
-                                        method_name: Some(HttpMethod::Put),
-                                    })
-                                    .cons(FormExtB::default()),
-                                ..Default::default()
-                            }],
-                            other: Nil::cons(IntAffExtA { b: A(9) })
-                                .cons(())
-                                .cons(IntAffExtB { l: A(10) }),
-                            ..Default::default()
-                        },
-                        input: Some(DataSchema {
-                            subtype: Some(DataSchemaSubtype::Object(ObjectSchema {
-                                other: Nil::cons(ObjectSchemaExtA { i: A(11) })
-                                    .cons(())
-                                    .cons(ObjectSchemaExtB { s: A(12) }),
-                                ..Default::default()
-                            })),
-                            other: Nil::cons(DataSchemaExtA { h: A(13) })
-                                .cons(())
-                                .cons(DataSchemaExtB { r: A(14) }),
-                            ..Default::default()
-                        }),
-                        output: Some(DataSchema::default()),
-                        other: Nil::cons(ActionAffExtA { c: A(15) })
-                            .cons(())
-                            .cons(ActionAffExtB { m: A(16) }),
-                        ..Default::default()
-                    },
-                )]
-                .into_iter()
-                .collect(),
+            (
+                json!({
+                    "type": "array",
+                    "items": { "type": "string", "const": "a", "enum": ["b"] },
+                }),
+                Err(DataSchemaError::ConstAndEnum.into()),
             ),
-            events: Some(
-                [(
-                    "event".to_string(),
-                    EventAffordance {
-                        other: Nil::cons(EventAffExtA { e: A(17) })
-                            .cons(())
-                            .cons(EventAffExtB { o: A(18) }),
-                        ..Default::default()
-                    },
-                )]
-                .into_iter()
-                .collect(),
+            (json!({ "enum": ["on", 1, true] }), Ok(())),
+            (
+                json!({ "enum": [] }),
+                Err(DataSchemaError::EmptyEnum.into()),
             ),
-            forms: Some(vec![Form {
-                response: Some(ExpectedResponse {
-                    other: Nil::cons(RespExtA { g: A(19) })
-                        .cons(HttpResponse {
-                            headers: vec![HttpMessageHeader {
-                                field_name: Some("hello".to_string()),
-                                field_value: Some("world".to_string()),
-                            }],
-                            status_code_value: Some(200),
-                        })
-                        .cons(RespExtB { q: A(20) }),
-                    ..Default::default()
+            (
+                json!({ "type": "number", "const": "1" }),
+                Err(DataSchemaError::ConstTypeMismatch.into()),
+            ),
+            (
+                json!({
+                    "type": "object",
+                    "properties": { "level": { "type": "string", "enum": [1, 2] } },
                 }),
-                other: Nil::cons(FormExtA { f: A(21) })
-                    .cons(HttpForm {
-                        method_name: Some(HttpMethod::Get),
-                    })
-                    .cons(FormExtB { p: A(22) }),
-                ..Default::default()
-            }]),
-            other: Nil::cons(ThingExtA { a: A(23) })
-                .cons(HttpThing {})
-                .cons(ThingExtB { k: A(24) }),
-            ..Default::default()
+                Err(DataSchemaError::EnumTypeMismatch {
+                    value: "1".to_string(),
+                    path: "/properties/level/enum/0".to_string(),
+                }
+                .into()),
+            ),
+        ];
+
+        for (data_schema, expected) in cases {
+            assert_eq!(thing(data_schema).validate(), expected);
+        }
+    }
+
+    #[test]
+    fn lint_overlapping_success_responses() {
+        let mut thing: Thing = serde_json::from_value(json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Thing name",
+            "security": [],
+            "securityDefinitions": {},
+            "forms": [{
+                "href": "/all",
+                "op": "readallproperties",
+                "additionalResponses": [
+                    { "success": true },
+                    { "success": true, "contentType": "application/cbor" },
+                    { "success": true, "contentType": "text/plain" },
+                ],
+            }],
+            "actions": {
+                "toggle": {
+                    "forms": [
+                        {
+                            "href": "/toggle",
+                            "response": { "contentType": "text/plain" },
+                            "additionalResponses": [
+                                { "success": true },
+                                { "success": true, "contentType": "text/plain" },
+                                { "success": true, "contentType": "text/plain" },
+                                { "contentType": "text/plain", "schema": "error" },
+                            ],
+                        },
+                        {
+                            "href": "/toggle",
+                            "contentType": "text/plain",
+                            "additionalResponses": [
+                                { "success": true },
+                                { "contentType": "text/plain" },
+                            ],
+                        },
+                    ],
+                },
+            },
+        }))
+        .unwrap();
+        thing.default_content_type = Some("application/cbor".to_string());
+
+        assert_eq!(
+            thing.lint(),
+            [
+                LintWarning::OverlappingSuccessResponse(
+                    "actions.toggle.forms.0.additionalResponses.2".to_string()
+                ),
+                LintWarning::OverlappingSuccessResponse(
+                    "forms.0.additionalResponses.1".to_string()
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn lint_with_size_thresholds() {
+        let long_description = "A deliberately verbose description. ".repeat(20);
+        let translations = |text: &str| {
+            ["en", "it", "de", "fr", "es"]
+                .into_iter()
+                .map(|language| (language.parse().unwrap(), format!("{text} ({language})")))
+                .collect::<MultiLanguage>()
         };
 
-        let thing_json = serde_json::to_value(thing).unwrap();
+        let mut thing: Thing = serde_json::from_value(json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Bloated Thing",
+            "description": long_description,
+            "security": ["nosec"],
+            "securityDefinitions": {
+                "nosec": { "scheme": "nosec", "description": long_description },
+            },
+            "properties": {
+                "level": {
+                    "type": "object",
+                    "properties": {
+                        "value": {
+                            "type": "number",
+                            "minimum": 0.5,
+                            "description": "Short",
+                        },
+                    },
+                    "forms": [{ "href": "/level" }],
+                },
+            },
+        }))
+        .unwrap();
+        let level = thing.properties.as_mut().unwrap().get_mut("level").unwrap();
+        level.interaction.titles = Some(translations("Level"));
+        let Some(DataSchemaSubtype::Object(object)) = &mut level.data_schema.subtype else {
+            unreachable!()
+        };
+        object
+            .properties
+            .as_mut()
+            .unwrap()
+            .get_mut("value")
+            .unwrap()
+            .descriptions = Some(translations(&long_description));
+
+        let size = thing.serialized_size_estimate();
+        assert_eq!(size, serde_json::to_string(&thing).unwrap().len());
+
+        assert!(thing.lint_with(&LintConfig::default()).is_empty());
+        assert_eq!(thing.lint_with(&LintConfig::default()), thing.lint());
+
+        let config = LintConfig {
+            max_serialized_size: Some(size - 1),
+            max_description_length: Some(100),
+            max_translations: Some(4),
+        };
         assert_eq!(
-            thing_json,
-            json!({
-                "@context": "test",
-                "title": "",
-                "properties": {
-                    "prop": {
-                        "b": 1,
-                        "l": 2,
-                        "j": 3,
-                        "t": 4,
-                        "h": 5,
-                        "r": 6,
-                        "d": 7,
-                        "n": 8,
-                        "forms": [],
-                        "type": "array",
-                        "readOnly": false,
-                        "writeOnly": false,
-                    }
+            thing.lint_with(&config),
+            [
+                LintWarning::SerializedSizeExceeded(size),
+                LintWarning::DescriptionTooLong("description".to_string()),
+                LintWarning::DescriptionTooLong(
+                    "properties.level.properties.value.descriptions.de".to_string()
+                ),
+                LintWarning::DescriptionTooLong(
+                    "properties.level.properties.value.descriptions.en".to_string()
+                ),
+                LintWarning::DescriptionTooLong(
+                    "properties.level.properties.value.descriptions.es".to_string()
+                ),
+                LintWarning::DescriptionTooLong(
+                    "properties.level.properties.value.descriptions.fr".to_string()
+                ),
+                LintWarning::DescriptionTooLong(
+                    "properties.level.properties.value.descriptions.it".to_string()
+                ),
+                LintWarning::DescriptionTooLong(
+                    "securityDefinitions.nosec.description".to_string()
+                ),
+                LintWarning::TooManyTranslations(
+                    "properties.level.properties.value.descriptions".to_string()
+                ),
+                LintWarning::TooManyTranslations("properties.level.titles".to_string()),
+            ],
+        );
+
+        let config = LintConfig {
+            max_serialized_size: Some(size),
+            max_description_length: Some(long_description.len() + 10),
+            max_translations: Some(5),
+        };
+        assert!(thing.lint_with(&config).is_empty());
+    }
+
+    #[test]
+    fn lint_one_of_with_subtype() {
+        let thing: Thing = serde_json::from_value(json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Thing name",
+            "properties": {
+                "level": {
+                    "oneOf": [{ "type": "integer" }, { "type": "null" }],
+                    "forms": [{ "href": "/level" }],
                 },
-                "actions": {
-                    "action": {
-                        "b": 9,
-                        "l": 10,
-                        "input": {
-                            "i": 11,
-                            "s": 12,
-                            "h": 13,
-                            "r": 14,
-                            "readOnly": false,
-                            "writeOnly": false,
-                            "type": "object",
-                        },
-                        "output": {
-                            "h": 42,
-                            "r": 42,
-                            "readOnly": false,
-                            "writeOnly": false,
+            },
+            "actions": {
+                "fade": {
+                    "input": {
+                        "type": "object",
+                        "properties": {
+                            "duration": {
+                                "type": "integer",
+                                "oneOf": [{ "minimum": 0 }, { "const": -1 }],
+                            },
                         },
-                        "forms": [
-                            {
-                                "f": 42,
-                                "href": "",
-                                "htv:methodName": "PUT",
-                                "p": 42,
-                            }
-                        ],
-                        "idempotent": false,
-                        "safe": false,
-                        "c": 15,
-                        "m": 16,
-                    }
+                    },
+                    "forms": [{ "href": "/fade" }],
                 },
-                "events": {
-                    "event": {
-                        "b": 42,
-                        "l": 42,
-                        "e": 17,
-                        "o": 18,
-                        "forms": [],
-                    }
+            },
+            "schemaDefinitions": {
+                "mode": {
+                    "type": "string",
+                    "oneOf": [{ "const": "auto" }, { "type": "string", "oneOf": [] }],
                 },
-                "forms": [{
-                    "href": "",
-                    "response": {
-                        "contentType": "",
-                        "g": 19,
-                        "q": 20,
-                        "htv:headers": [{
-                            "htv:fieldName": "hello",
-                            "htv:fieldValue": "world",
-                        }],
-                        "htv:statusCodeValue": 200,
+            },
+            "security": [],
+            "securityDefinitions": {},
+        }))
+        .unwrap();
+
+        assert_eq!(
+            thing.lint(),
+            [
+                LintWarning::OneOfWithSubtype("actions.fade.input.properties.duration".to_string()),
+                LintWarning::OneOfWithSubtype("schemaDefinitions.mode".to_string()),
+                LintWarning::OneOfWithSubtype("schemaDefinitions.mode.oneOf.1".to_string()),
+            ],
+        );
+    }
+
+    #[test]
+    fn content_encoding_validation() {
+        let strict = ValidateOptions {
+            strict_content_encoding: true,
+        };
+        let thing = |encoding: &str, media_type: Option<&str>| -> Thing {
+            serde_json::from_value(json!({
+                "@context": TD_CONTEXT_11,
+                "title": "Thing name",
+                "properties": {
+                    "image": {
+                        "type": "string",
+                        "contentEncoding": encoding,
+                        "contentMediaType": media_type,
+                        "forms": [{ "href": "/image" }],
                     },
-                    "f": 21,
-                    "p": 22,
-                    "htv:methodName": "GET",
-                }],
+                },
                 "security": [],
                 "securityDefinitions": {},
-                "a": 23,
-                "k": 24,
+            }))
+            .unwrap()
+        };
+
+        for (name, encoding) in [
+            ("7bit", ContentEncoding::SevenBit),
+            ("8bit", ContentEncoding::EightBit),
+            ("binary", ContentEncoding::Binary),
+            ("quoted-printable", ContentEncoding::QuotedPrintable),
+            ("base16", ContentEncoding::Base16),
+            ("base32", ContentEncoding::Base32),
+            ("Base64", ContentEncoding::Base64),
+        ] {
+            assert_eq!(ContentEncoding::from(name), encoding);
+            assert!(encoding.as_str().eq_ignore_ascii_case(name));
+
+            let thing = thing(name, Some("image/png"));
+            assert_eq!(thing.validate(), Ok(()));
+            assert_eq!(thing.validate_with(strict), Ok(()));
+            assert!(thing.lint().is_empty());
+        }
+
+        assert_eq!(
+            ContentEncoding::from("base85"),
+            ContentEncoding::Other("base85".to_string()),
+        );
+        assert_eq!(thing("base85", Some("image/png")).validate(), Ok(()));
+        assert_eq!(
+            thing("base85", Some("image/png")).validate_with(strict),
+            Err(DataSchemaError::UnknownContentEncoding("base85".to_string()).into()),
+        );
+        assert_eq!(
+            thing("base64", None).lint(),
+            [LintWarning::ContentEncodingWithoutMediaType(
+                "properties.image".to_string()
+            )],
+        );
+    }
+
+    #[cfg(feature = "units")]
+    #[test]
+    fn lint_unrecognized_unit() {
+        let thing: Thing = serde_json::from_value(json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Thing name",
+            "security": [],
+            "securityDefinitions": {},
+            "properties": {
+                "temperature": {
+                    "type": "number",
+                    "unit": "celsius",
+                    "forms": [{ "href": "/temperature" }],
+                },
+                "distance": {
+                    "type": "number",
+                    "unit": "furlong",
+                    "forms": [{ "href": "/distance" }],
+                },
+            },
+        }))
+        .unwrap();
+
+        let properties = thing.properties.as_ref().unwrap();
+        let temperature = &properties["temperature"].data_schema;
+        assert_eq!(temperature.normalized_unit(), Some("Cel"));
+        assert_eq!(temperature.unit.as_deref(), Some("celsius"));
+        assert_eq!(properties["distance"].data_schema.normalized_unit(), None);
+
+        assert_eq!(
+            thing.lint(),
+            [LintWarning::UnrecognizedUnit(
+                "properties.distance".to_string()
+            )],
+        );
+        assert_eq!(
+            serde_json::to_value(&thing).unwrap()["properties"]["temperature"]["unit"],
+            "celsius",
+        );
+    }
+
+    #[test]
+    fn openapi_object_with_read_only_field() {
+        let data_schema: DataSchemaFromOther<Nil> = serde_json::from_value(json!({
+            "@type": "saref:Sensor",
+            "title": "Reading",
+            "titles": { "it": "Lettura" },
+            "type": "object",
+            "properties": {
+                "id": {
+                    "type": "string",
+                    "readOnly": true,
+                },
+                "value": {
+                    "type": "number",
+                    "unit": "cm",
+                },
+                "note": {
+                    "oneOf": [
+                        { "type": "string", "maxLength": 16 },
+                        { "type": "null" },
+                    ],
+                    "description": "Optional note",
+                },
+                "state": {
+                    "type": "string",
+                    "enum": ["on", "off", null],
+                },
+                "pair": {
+                    "type": "array",
+                    "items": [{ "type": "integer" }, { "const": "cm" }],
+                },
+            },
+            "required": ["id"],
+        }))
+        .unwrap();
+
+        assert_eq!(
+            data_schema.to_openapi().unwrap(),
+            json!({
+                "title": "Reading",
+                "type": "object",
+                "properties": {
+                    "id": {
+                        "type": "string",
+                        "readOnly": true,
+                    },
+                    "value": {
+                        "type": "number",
+                        "x-unit": "cm",
+                    },
+                    "note": {
+                        "type": ["string", "null"],
+                        "maxLength": 16,
+                        "description": "Optional note",
+                    },
+                    "state": {
+                        "type": ["string", "null"],
+                        "enum": ["on", "off", null],
+                    },
+                    "pair": {
+                        "type": "array",
+                        "prefixItems": [{ "type": "integer" }, { "const": "cm" }],
+                    },
+                },
+                "required": ["id"],
             }),
         );
     }
 
-    #[derive(Debug, PartialEq, Serialize, Deserialize)]
-    struct DataSchemaExt {}
+    #[test]
+    fn directory_summary() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Registration {
+            registration: Value,
+        }
 
-    #[derive(Debug, PartialEq, Serialize, Deserialize)]
-    struct ArraySchemaExt {}
+        impl ExtendableThing for Registration {
+            type InteractionAffordance = ();
+            type PropertyAffordance = ();
+            type ActionAffordance = ();
+            type EventAffordance = ();
+            type Form = ();
+            type ExpectedResponse = ();
+            type DataSchema = ();
+            type ObjectSchema = ();
+            type ArraySchema = ();
+        }
 
-    #[derive(Debug, PartialEq, Serialize, Deserialize)]
-    struct ObjectSchemaExt {}
+        let td = json!({
+            "@context": [
+                "https://www.w3.org/2022/wot/td/v1.1",
+                "https://www.w3.org/2022/wot/discovery",
+            ],
+            "@type": ["Thing", "saref:LightSwitch"],
+            "id": "urn:example:simple-td",
+            "title": "Simple Example Thing",
+            "base": "https://example.com/things/simple/",
+            "securityDefinitions": {
+                "basic_sc": { "scheme": "basic", "in": "header" },
+            },
+            "security": ["basic_sc"],
+            "links": [{
+                "href": "https://example.com/models/simple.tm.jsonld",
+                "rel": "type",
+                "type": "application/tm+json",
+            }],
+            "properties": {
+                "status": {
+                    "type": "string",
+                    "readOnly": false,
+                    "writeOnly": false,
+                    "forms": [{ "href": "status" }],
+                },
+            },
+            "forms": [{ "href": "all", "op": "readallproperties" }],
+            "registration": {
+                "created": "2020-07-14T15:16:22.862Z",
+                "expires": "2020-07-14T16:16:22.862Z",
+            },
+        });
+        let thing: Thing<Cons<Registration, Nil>> = serde_json::from_value(td.clone()).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(thing.summary()).unwrap(),
+            json!({
+                "@context": [
+                    "https://www.w3.org/2022/wot/td/v1.1",
+                    "https://www.w3.org/2022/wot/discovery",
+                ],
+                "@type": ["Thing", "saref:LightSwitch"],
+                "id": "urn:example:simple-td",
+                "title": "Simple Example Thing",
+                "base": "https://example.com/things/simple/",
+                "security": ["basic_sc"],
+                "links": [{
+                    "href": "https://example.com/models/simple.tm.jsonld",
+                    "rel": "type",
+                    "type": "application/tm+json",
+                }],
+                "registration": {
+                    "created": "2020-07-14T15:16:22.862Z",
+                    "expires": "2020-07-14T16:16:22.862Z",
+                },
+            }),
+        );
+
+        let mut expected = td;
+        let expected_map = expected.as_object_mut().unwrap();
+        expected_map.remove("properties");
+        expected_map.remove("forms");
+        assert_eq!(
+            serde_json::to_value(thing.strip_to_summary()).unwrap(),
+            expected,
+        );
+    }
 
     #[test]
-    fn default_array_schema() {
-        ArraySchema::<DataSchemaExt, (), ObjectSchemaExt>::default();
+    fn map_number_bounds() {
+        let celsius = NumberSchema {
+            minimum: Some(Minimum::Exclusive(-10.)),
+            maximum: Some(Maximum::Inclusive(100.)),
+            multiple_of: Some(0.5),
+        };
+
+        assert_eq!(
+            celsius.map_bounds(|x| x * 1.8 + 32.),
+            NumberSchema {
+                minimum: Some(Minimum::Exclusive(14.)),
+                maximum: Some(Maximum::Inclusive(212.)),
+                multiple_of: None,
+            },
+        );
+        assert_eq!(
+            celsius.map_bounds(|x| x * 2.),
+            NumberSchema {
+                minimum: Some(Minimum::Exclusive(-20.)),
+                maximum: Some(Maximum::Inclusive(200.)),
+                multiple_of: Some(1.),
+            },
+        );
+        assert_eq!(
+            celsius.map_bounds(|x| -x),
+            NumberSchema {
+                minimum: Some(Minimum::Inclusive(-100.)),
+                maximum: Some(Maximum::Exclusive(10.)),
+                multiple_of: Some(0.5),
+            },
+        );
+
+        let fahrenheit = NumberSchema {
+            minimum: Some(Minimum::Inclusive(32.)),
+            maximum: None,
+            multiple_of: None,
+        };
+        assert_eq!(
+            fahrenheit.map_bounds(|x| (x - 32.) / 1.8),
+            NumberSchema {
+                minimum: Some(Minimum::Inclusive(0.)),
+                maximum: None,
+                multiple_of: None,
+            },
+        );
     }
 
     #[test]
-    fn default_object_schema() {
-        ObjectSchema::<DataSchemaExt, ArraySchemaExt, ()>::default();
+    fn map_integer_bounds() {
+        let celsius = IntegerSchema {
+            minimum: Some(Minimum::Inclusive(0)),
+            maximum: Some(Maximum::Exclusive(100)),
+            multiple_of: NonZeroU64::new(5),
+        };
+
+        assert_eq!(
+            celsius.map_bounds(|x| x * 1.8 + 32.),
+            NumberSchema {
+                minimum: Some(Minimum::Inclusive(32.)),
+                maximum: Some(Maximum::Exclusive(212.)),
+                multiple_of: None,
+            },
+        );
+        assert_eq!(
+            celsius.map_bounds(|x| x * 1.8),
+            NumberSchema {
+                minimum: Some(Minimum::Inclusive(0.)),
+                maximum: Some(Maximum::Exclusive(180.)),
+                multiple_of: Some(9.),
+            },
+        );
     }
 
     #[test]
-    fn serde_empty_additional_expected_response() {
-        let response: AdditionalExpectedResponse = serde_json::from_value(json!({})).unwrap();
+    fn data_schema_value_conversion() {
+        let value = json!({
+            "type": "array",
+            "title": "Levels",
+            "items": { "type": "integer", "minimum": 0 },
+            "readOnly": true,
+            "writeOnly": false,
+        });
+
+        let data_schema = DataSchemaFromOther::<Nil>::try_from(value.clone()).unwrap();
+        assert_eq!(data_schema.title.as_deref(), Some("Levels"));
+        assert!(data_schema.read_only);
+
+        let round_trip = Value::from(data_schema.clone());
+        assert_eq!(round_trip["items"]["minimum"], 0);
         assert_eq!(
-            response,
-            AdditionalExpectedResponse {
-                success: false,
-                content_type: None,
-                schema: None,
+            DataSchemaFromOther::<Nil>::try_from(round_trip).unwrap(),
+            data_schema
+        );
+
+        assert!(DataSchemaFromOther::<Nil>::try_from(json!({ "title": 42 })).is_err());
+    }
+
+    #[test]
+    fn array_schema_into_vec() {
+        let number = |minimum: f64| DataSchemaFromOther::<Nil> {
+            subtype: Some(DataSchemaSubtype::Number(NumberSchema {
+                minimum: Some(Minimum::Inclusive(minimum)),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        let tuple = |items: Vec<DataSchemaFromOther<Nil>>| ArraySchema::<Nil, Nil, Nil> {
+            items: Some(BoxedElemOrVec::Vec(items)),
+            min_items: Some(2),
+            ..Default::default()
+        };
+
+        let list = tuple(vec![number(0.), number(0.)]).into_vec().unwrap();
+        assert_eq!(
+            list,
+            ArraySchema {
+                items: Some(BoxedElemOrVec::Elem(Box::new(number(0.)))),
+                min_items: Some(2),
+                ..Default::default()
             },
         );
+        assert_eq!(list.clone().into_vec().unwrap(), list);
 
-        assert_eq!(serde_json::to_value(response).unwrap(), json!({}));
+        assert_eq!(
+            tuple(vec![number(0.), number(1.)]).into_vec(),
+            Err(DataSchemaError::HeterogeneousTuple.into()),
+        );
+        assert_eq!(tuple(vec![]).into_vec().unwrap().items, None);
+        assert_eq!(
+            ArraySchema::<Nil, Nil, Nil>::default().into_vec().unwrap(),
+            ArraySchema::default(),
+        );
     }
 
     #[test]
-    fn serde_full_additional_expected_response() {
-        let raw_data = json!({
-            "success": true,
-            "contentType": "application/json",
-            "schema": "test",
-        });
+    fn array_schema_into_tuple() {
+        let list = ArraySchema::<Nil, Nil, Nil> {
+            items: Some(BoxedElemOrVec::Elem(Box::new(DataSchema {
+                subtype: Some(DataSchemaSubtype::Boolean),
+                ..Default::default()
+            }))),
+            max_items: Some(3),
+            ..Default::default()
+        };
 
-        let response: AdditionalExpectedResponse =
-            serde_json::from_value(raw_data.clone()).unwrap();
+        let tuple = list.clone().into_tuple(3);
+        assert!(tuple.is_tuple());
+        assert_eq!(tuple.max_items, Some(3));
+        assert_eq!(tuple.item_schemas().count(), 3);
+        assert!(tuple
+            .item_schemas()
+            .all(|item| item.subtype == Some(DataSchemaSubtype::Boolean)));
+        assert_eq!(tuple.clone().into_tuple(5), tuple);
+        assert_eq!(tuple.into_vec().unwrap(), list);
 
         assert_eq!(
-            response,
-            AdditionalExpectedResponse {
-                success: true,
-                content_type: Some("application/json".to_string()),
-                schema: Some("test".to_string()),
-            },
+            ArraySchema::<Nil, Nil, Nil>::default().into_tuple(2),
+            ArraySchema::default(),
         );
-
-        assert_eq!(serde_json::to_value(response).unwrap(), raw_data);
     }
 
     #[test]
-    fn combo_security_scheme() {
-        let raw_data = json!({
-            "oneOf": "simple",
-        });
-        let combo: ComboSecurityScheme = serde_json::from_value(raw_data.clone()).unwrap();
+    fn at_type_classification() {
+        let prefixed = |prefix: &str, local: &str| AtType::Prefixed {
+            prefix: prefix.to_string(),
+            local: local.to_string(),
+        };
+
+        for (value, expected) in [
+            ("v4:temp", prefixed("v4", "temp")),
+            ("saref:LightSwitch", prefixed("saref", "LightSwitch")),
+            ("tm:ThingModel", prefixed("tm", "ThingModel")),
+            ("ex:a:b", prefixed("ex", "a:b")),
+            (
+                "urn:dev:ops:1234",
+                AtType::AbsoluteIri("urn:dev:ops:1234".to_string()),
+            ),
+            (
+                "URN:ISBN:0451450523",
+                AtType::AbsoluteIri("URN:ISBN:0451450523".to_string()),
+            ),
+            (
+                "http://example.com/ns#Lamp",
+                AtType::AbsoluteIri("http://example.com/ns#Lamp".to_string()),
+            ),
+            (
+                "coap://[::1]/lamp",
+                AtType::AbsoluteIri("coap://[::1]/lamp".to_string()),
+            ),
+            ("Thing", AtType::Term("Thing".to_string())),
+            (":Lamp", AtType::Term(":Lamp".to_string())),
+            ("my type:Lamp", AtType::Term("my type:Lamp".to_string())),
+        ] {
+            let ty = AtType::parse(value);
+            assert_eq!(ty, expected, "{value}");
+            assert_eq!(ty.to_string(), value);
+            assert_eq!(ty, value);
+            assert_eq!(String::from(ty), value);
+        }
+
+        assert_eq!(AtType::parse("v4:temp").prefix(), Some("v4"));
+        assert_eq!(AtType::parse("urn:dev:ops:1234").prefix(), None);
+        assert_eq!(AtType::parse("urn:dev:ops:1234").local_name(), "1234");
         assert_eq!(
-            combo,
-            ComboSecurityScheme::OneOf(vec!["simple".to_string()]),
+            AtType::parse("https://w3id.org/saref#Sensor").local_name(),
+            "Sensor"
         );
-        assert_eq!(serde_json::to_value(combo).unwrap(), raw_data);
+        assert_eq!(AtType::parse("Thing").local_name(), "Thing");
+        assert_ne!(AtType::parse("saref:Sensor"), "saref:Sensor2");
+        assert_ne!(AtType::parse("saref:Sensor"), "sarefSensor");
+    }
 
-        let raw_data = json!({
-            "oneOf": ["data1", "data2"],
-        });
-        let combo: ComboSecurityScheme = serde_json::from_value(raw_data.clone()).unwrap();
+    #[test]
+    fn at_type_serde() {
+        let thing: Thing = serde_json::from_value(json!({
+            "@context": TD_CONTEXT_11,
+            "@type": ["Thing", "v4:temp", "urn:dev:ops:1234"],
+            "title": "Thing name",
+            "security": [],
+            "securityDefinitions": {},
+        }))
+        .unwrap();
+
+        let attype = thing.attype.as_ref().unwrap();
+        assert_eq!(attype[0], AtType::Term("Thing".to_string()));
+        assert_eq!(attype[1].prefix(), Some("v4"));
+        assert!(attype[2].is_absolute_iri());
         assert_eq!(
-            combo,
-            ComboSecurityScheme::OneOf(vec!["data1".to_string(), "data2".to_string()]),
+            serde_json::to_value(&thing).unwrap()["@type"],
+            json!(["Thing", "v4:temp", "urn:dev:ops:1234"]),
         );
-        assert_eq!(serde_json::to_value(combo).unwrap(), raw_data);
+    }
 
-        let raw_data = json!({
-            "allOf": "simple",
-        });
-        let combo: ComboSecurityScheme = serde_json::from_value(raw_data.clone()).unwrap();
+    #[test]
+    fn merge_human_readable_translations() {
+        use crate::builder::BuildableHumanReadableInfo;
+
+        let english = |title: &str| {
+            [("en".parse().unwrap(), title.to_string())]
+                .into_iter()
+                .collect::<MultiLanguage>()
+        };
+        let data_schema = DataSchemaFromOther::<Nil> {
+            attype: Some(vec!["saref:Temperature".into()]),
+            title: Some("Temperature".to_string()),
+            titles: Some(english("Temperature")),
+            subtype: Some(DataSchemaSubtype::Number(Default::default())),
+            ..Default::default()
+        };
+        let info = HumanReadableInfo::default()
+            .attype("saref:Temperature")
+            .attype("saref:Property")
+            .title("Temperatura")
+            .titles(|b| b.add("it", "Temperatura").add("en", "Temp"))
+            .descriptions(|b| b.add("it", "La temperatura misurata"));
+
+        let mut kept = data_schema.clone();
+        kept.merge_human_readable(&info, MergePolicy::KeepExisting)
+            .unwrap();
         assert_eq!(
-            combo,
-            ComboSecurityScheme::AllOf(vec!["simple".to_string()]),
+            kept.attype,
+            Some(vec!["saref:Temperature".into(), "saref:Property".into()]),
         );
-        assert_eq!(serde_json::to_value(combo).unwrap(), raw_data);
-
-        let raw_data = json!({
-            "allOf": ["data1", "data2"],
-        });
-        let combo: ComboSecurityScheme = serde_json::from_value(raw_data.clone()).unwrap();
+        assert_eq!(kept.title.as_deref(), Some("Temperature"));
+        let titles = kept.titles.as_ref().unwrap();
+        assert_eq!(titles.len(), 2);
+        assert_eq!(titles["en"], "Temperature");
+        assert_eq!(titles["it"], "Temperatura");
         assert_eq!(
-            combo,
-            ComboSecurityScheme::AllOf(vec!["data1".to_string(), "data2".to_string()]),
+            kept.descriptions.as_ref().unwrap()["it"],
+            "La temperatura misurata",
         );
-        assert_eq!(serde_json::to_value(combo).unwrap(), raw_data);
+        assert_eq!(kept.subtype, data_schema.subtype);
+
+        let mut overwritten = data_schema.clone();
+        overwritten
+            .merge_human_readable(&info, MergePolicy::Overwrite)
+            .unwrap();
+        assert_eq!(overwritten.title.as_deref(), Some("Temperatura"));
+        let titles = overwritten.titles.as_ref().unwrap();
+        assert_eq!(titles["en"], "Temp");
+        assert_eq!(titles["it"], "Temperatura");
+
+        let invalid = HumanReadableInfo::default()
+            .title("Temperatura")
+            .titles(|b| b.add("not a language", "Temperatura"));
+        let mut untouched = data_schema.clone();
+        assert!(matches!(
+            untouched.merge_human_readable(&invalid, MergePolicy::Overwrite),
+            Err(Error::AtPath { .. }),
+        ));
+        assert_eq!(untouched, data_schema);
     }
 
     #[test]
-    fn minimum_partial_ord_trivial() {
+    fn data_schema_attype_order() {
+        let types = ["saref:Temperature", "om:Measure", "schema:PropertyValue"];
+        let data_schema = DataSchemaFromOther::<Nil> {
+            attype: Some(types.iter().map(|&ty| ty.into()).collect()),
+            ..Default::default()
+        };
+
+        let value = serde_json::to_value(&data_schema).unwrap();
+        assert_eq!(value["@type"], json!(types));
         assert_eq!(
-            Minimum::Inclusive(5).partial_cmp(&Minimum::Inclusive(5)),
-            Some(Ordering::Equal),
+            DataSchemaFromOther::<Nil>::try_from(value).unwrap(),
+            data_schema
         );
+
+        let reversed = json!(["schema:PropertyValue", "om:Measure", "saref:Temperature"]);
+        let data_schema =
+            DataSchemaFromOther::<Nil>::try_from(json!({ "@type": reversed.clone() })).unwrap();
         assert_eq!(
-            Minimum::Inclusive(5).partial_cmp(&Minimum::Inclusive(6)),
-            Some(Ordering::Less),
+            serde_json::to_value(&data_schema).unwrap()["@type"],
+            reversed
         );
+
+        let single =
+            DataSchemaFromOther::<Nil>::try_from(json!({ "@type": "om:Measure" })).unwrap();
+        assert_eq!(single.attype.as_ref().unwrap(), &["om:Measure"]);
         assert_eq!(
-            Minimum::Inclusive(6).partial_cmp(&Minimum::Inclusive(5)),
-            Some(Ordering::Greater),
+            serde_json::to_value(&single).unwrap()["@type"],
+            json!(["om:Measure"]),
         );
+    }
+
+    #[test]
+    fn default_ops_table() {
+        use FormOperation::*;
+
+        let property_rows = [
+            (false, false, vec![ReadProperty, WriteProperty]),
+            (true, false, vec![ReadProperty]),
+            (false, true, vec![WriteProperty]),
+            (true, true, vec![]),
+        ];
+        for (read_only, write_only, ops) in property_rows {
+            assert_eq!(
+                default_ops_for(AffordanceType::Property, read_only, write_only),
+                ops,
+                "readOnly: {read_only}, writeOnly: {write_only}",
+            );
+        }
+
+        for flags in [false, true] {
+            assert_eq!(
+                default_ops_for(AffordanceType::Action, flags, flags),
+                [InvokeAction],
+            );
+            assert_eq!(
+                default_ops_for(AffordanceType::Event, flags, flags),
+                [SubscribeEvent, UnsubscribeEvent],
+            );
+        }
+    }
+
+    #[test]
+    fn map_data_schema_numeric_bounds() {
+        let data_schema: DataSchemaFromOther<Nil> = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {
+                "current": { "type": "integer", "minimum": -40, "maximum": 120 },
+                "history": {
+                    "type": "array",
+                    "items": { "type": "number", "exclusiveMinimum": 0 },
+                },
+                "target": {
+                    "oneOf": [
+                        { "type": "number", "maximum": 30 },
+                        { "type": "string" },
+                    ],
+                },
+            },
+        }))
+        .unwrap();
 
+        let data_schema = data_schema.map_numeric_bounds(|x| x * 1.8 + 32.);
         assert_eq!(
-            Minimum::Exclusive(5).partial_cmp(&Minimum::Exclusive(5)),
-            Some(Ordering::Equal),
+            serde_json::to_value(data_schema).unwrap(),
+            json!({
+                "type": "object",
+                "properties": {
+                    "current": {
+                        "type": "number",
+                        "minimum": -40.0,
+                        "maximum": 248.0,
+                        "readOnly": false,
+                        "writeOnly": false,
+                    },
+                    "history": {
+                        "type": "array",
+                        "items": {
+                            "type": "number",
+                            "exclusiveMinimum": 32.0,
+                            "readOnly": false,
+                            "writeOnly": false,
+                        },
+                        "readOnly": false,
+                        "writeOnly": false,
+                    },
+                    "target": {
+                        "oneOf": [
+                            {
+                                "type": "number",
+                                "maximum": 86.0,
+                                "readOnly": false,
+                                "writeOnly": false,
+                            },
+                            { "type": "string", "readOnly": false, "writeOnly": false },
+                        ],
+                        "readOnly": false,
+                        "writeOnly": false,
+                    },
+                },
+                "readOnly": false,
+                "writeOnly": false,
+            }),
         );
+    }
+
+    #[test]
+    fn minify_data_schema() {
+        let mut data_schema: DataSchemaFromOther<Nil> = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {
+                "tags": {
+                    "type": "array",
+                    "items": { "type": "string", "minLength": 0, "maxLength": 8 },
+                },
+                "count": {
+                    "type": "integer",
+                    "minimum": i64::MIN,
+                    "maximum": i64::MAX,
+                    "multipleOf": 1,
+                },
+                "level": {
+                    "oneOf": [
+                        { "type": "number", "minimum": f64::MIN, "maximum": 100. },
+                        { "type": "number", "exclusiveMaximum": f64::MAX },
+                        { "type": "object", "properties": {}, "required": [] },
+                    ],
+                },
+            },
+            "required": [],
+        }))
+        .unwrap();
+        let Some(DataSchemaSubtype::Object(object)) = &mut data_schema.subtype else {
+            unreachable!()
+        };
+        let tags = object.properties.as_mut().unwrap().get_mut("tags").unwrap();
+        let Some(DataSchemaSubtype::Array(array)) = &mut tags.subtype else {
+            unreachable!()
+        };
+        array.min_items = Some(0);
+        array.max_items = Some(4);
+
+        let mut data_schema = data_schema.minify();
+        let Some(DataSchemaSubtype::Object(object)) = &mut data_schema.subtype else {
+            unreachable!()
+        };
+        let tags = object.properties.as_mut().unwrap().remove("tags").unwrap();
+        let Some(DataSchemaSubtype::Array(array)) = tags.subtype else {
+            unreachable!()
+        };
+        assert_eq!(array.min_items, None);
+        assert_eq!(array.max_items, Some(4));
         assert_eq!(
-            Minimum::Exclusive(5).partial_cmp(&Minimum::Exclusive(6)),
-            Some(Ordering::Less),
+            serde_json::to_value(array.items).unwrap(),
+            json!({
+                "type": "string",
+                "maxLength": 8,
+                "readOnly": false,
+                "writeOnly": false,
+            }),
         );
+
         assert_eq!(
-            Minimum::Exclusive(6).partial_cmp(&Minimum::Exclusive(5)),
-            Some(Ordering::Greater),
+            serde_json::to_value(data_schema).unwrap(),
+            json!({
+                "type": "object",
+                "properties": {
+                    "count": {
+                        "type": "integer",
+                        "readOnly": false,
+                        "writeOnly": false,
+                    },
+                    "level": {
+                        "oneOf": [
+                            {
+                                "type": "number",
+                                "maximum": 100.,
+                                "readOnly": false,
+                                "writeOnly": false,
+                            },
+                            {
+                                "type": "number",
+                                "exclusiveMaximum": f64::MAX,
+                                "readOnly": false,
+                                "writeOnly": false,
+                            },
+                            {
+                                "type": "object",
+                                "readOnly": false,
+                                "writeOnly": false,
+                            },
+                        ],
+                        "readOnly": false,
+                        "writeOnly": false,
+                    },
+                },
+                "readOnly": false,
+                "writeOnly": false,
+            }),
         );
     }
 
     #[test]
-    fn minimum_partial_ord_complex() {
+    fn deserialize_bound_forms() {
+        let number = |value: Value| -> Result<NumberSchema, _> { serde_json::from_value(value) };
+        let integer = |value: Value| -> Result<IntegerSchema, _> { serde_json::from_value(value) };
+
+        let inclusive = number(json!({ "minimum": 0.5, "maximum": 2.5 })).unwrap();
+        assert_eq!(inclusive.minimum, Some(Minimum::Inclusive(0.5)));
+        assert_eq!(inclusive.maximum, Some(Maximum::Inclusive(2.5)));
+
+        let exclusive =
+            number(json!({ "exclusiveMinimum": 0.5, "exclusiveMaximum": 2.5 })).unwrap();
+        assert_eq!(exclusive.minimum, Some(Minimum::Exclusive(0.5)));
+        assert_eq!(exclusive.maximum, Some(Maximum::Exclusive(2.5)));
+
+        let draft4 = integer(json!({
+            "minimum": 0,
+            "exclusiveMinimum": true,
+            "maximum": 10,
+            "exclusiveMaximum": false,
+        }))
+        .unwrap();
+        assert_eq!(draft4.minimum, Some(Minimum::Exclusive(0)));
+        assert_eq!(draft4.maximum, Some(Maximum::Inclusive(10)));
+
+        let modifier_only = integer(json!({ "exclusiveMaximum": true })).unwrap();
+        assert_eq!(modifier_only.maximum, None);
+
         assert_eq!(
-            Minimum::Inclusive(4).partial_cmp(&Minimum::Exclusive(5)),
-            Some(Ordering::Less),
+            serde_json::to_value(&draft4).unwrap(),
+            json!({ "exclusiveMinimum": 0, "maximum": 10 }),
         );
 
+        let both = number(json!({
+            "minimum": 0.5,
+            "exclusiveMinimum": 1.5,
+            "maximum": 2.5,
+            "exclusiveMaximum": 3.5,
+        }))
+        .unwrap();
+        assert_eq!(both.minimum, Some(Minimum::Exclusive(1.5)));
+        assert_eq!(both.maximum, Some(Maximum::Inclusive(2.5)));
+
+        let both = number(json!({
+            "minimum": 1.5,
+            "exclusiveMinimum": 0.5,
+            "maximum": 3.5,
+            "exclusiveMaximum": 2.5,
+        }))
+        .unwrap();
+        assert_eq!(both.minimum, Some(Minimum::Inclusive(1.5)));
+        assert_eq!(both.maximum, Some(Maximum::Exclusive(2.5)));
+
+        let both = integer(json!({
+            "minimum": 0,
+            "exclusiveMinimum": 0,
+            "maximum": 10,
+            "exclusiveMaximum": 10,
+        }))
+        .unwrap();
+        assert_eq!(both.minimum, Some(Minimum::Exclusive(0)));
+        assert_eq!(both.maximum, Some(Maximum::Exclusive(10)));
+
+        // JSON cannot represent NaN, but other deserializers can produce it.
+        let error = resolve_bound::<_, _, serde::de::value::Error>(
+            "minimum",
+            Some(f64::NAN),
+            Some(ExclusiveBound::Value(1.)),
+            Minimum::Inclusive,
+            Minimum::Exclusive,
+            Ordering::Greater,
+        )
+        .unwrap_err();
         assert_eq!(
-            Minimum::Inclusive(5).partial_cmp(&Minimum::Exclusive(5)),
-            Some(Ordering::Less),
+            error.to_string(),
+            Error::DataSchema(DataSchemaError::AmbiguousBound("minimum".to_string())).to_string()
         );
-
+        let error = resolve_bound::<_, _, serde::de::value::Error>(
+            "maximum",
+            Some(1.),
+            Some(ExclusiveBound::Value(f64::NAN)),
+            Maximum::Inclusive,
+            Maximum::Exclusive,
+            Ordering::Less,
+        )
+        .unwrap_err();
         assert_eq!(
-            Minimum::Inclusive(6).partial_cmp(&Minimum::Exclusive(5)),
-            None,
+            error.to_string(),
+            Error::DataSchema(DataSchemaError::AmbiguousBound("maximum".to_string())).to_string()
         );
 
+        let data_schema: DataSchemaFromOther<Nil> = serde_json::from_value(json!({
+            "type": "number",
+            "minimum": 1,
+            "exclusiveMinimum": true,
+        }))
+        .unwrap();
         assert_eq!(
-            Minimum::Exclusive(4).partial_cmp(&Minimum::Inclusive(5)),
-            None,
+            data_schema.subtype,
+            Some(DataSchemaSubtype::Number(NumberSchema {
+                minimum: Some(Minimum::Exclusive(1.)),
+                ..Default::default()
+            })),
         );
+    }
 
-        assert_eq!(
-            Minimum::Exclusive(5).partial_cmp(&Minimum::Inclusive(5)),
-            Some(Ordering::Greater),
+    #[test]
+    fn minify_keeps_restricting_constraints() {
+        let data_schema: DataSchemaFromOther<Nil> = serde_json::from_value(json!({
+            "type": "integer",
+            "exclusiveMinimum": i64::MIN,
+            "maximum": i64::MAX - 1,
+            "multipleOf": 2,
+        }))
+        .unwrap();
+
+        assert_eq!(data_schema.clone().minify(), data_schema);
+    }
+
+    #[test]
+    fn minify_preserves_validation() {
+        fn assert_same_validation(
+            data_schema: &DataSchemaFromOther<Nil>,
+            accepted: &[Value],
+            rejected: &[Value],
+        ) {
+            let minified = data_schema.clone().minify();
+            for value in accepted {
+                assert_eq!(data_schema.validate_value(value), Ok(()), "{value}");
+                assert_eq!(minified.validate_value(value), Ok(()), "{value}");
+            }
+            for value in rejected {
+                let error = data_schema
+                    .validate_value(value)
+                    .expect_err(&value.to_string());
+                assert_eq!(minified.validate_value(value), Err(error), "{value}");
+            }
+        }
+
+        let mut data_schema: DataSchemaFromOther<Nil> = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {
+                "tags": {
+                    "type": "array",
+                    "items": { "type": "string", "minLength": 0, "maxLength": 8 },
+                },
+                "count": {
+                    "type": "integer",
+                    "minimum": i64::MIN,
+                    "maximum": i64::MAX,
+                    "multipleOf": 1,
+                },
+                "level": {
+                    "oneOf": [
+                        { "type": "number", "minimum": f64::MIN, "maximum": 100. },
+                        { "type": "number", "exclusiveMaximum": f64::MAX },
+                        { "type": "object", "properties": {}, "required": [] },
+                    ],
+                },
+            },
+            "required": [],
+        }))
+        .unwrap();
+        let Some(DataSchemaSubtype::Object(object)) = &mut data_schema.subtype else {
+            unreachable!()
+        };
+        let tags = object.properties.as_mut().unwrap().get_mut("tags").unwrap();
+        let Some(DataSchemaSubtype::Array(array)) = &mut tags.subtype else {
+            unreachable!()
+        };
+        array.min_items = Some(0);
+        array.max_items = Some(2);
+        assert_ne!(data_schema.clone().minify(), data_schema);
+        assert_same_validation(
+            &data_schema,
+            &[
+                json!({}),
+                json!({ "tags": [] }),
+                json!({ "tags": ["", "lamp"] }),
+                json!({ "count": i64::MIN, "level": 150. }),
+                json!({ "count": i64::MAX, "level": {} }),
+            ],
+            &[
+                json!({ "tags": ["a", "b", "c"] }),
+                json!({ "tags": ["too long tag"] }),
+                json!({ "count": 1.5 }),
+                json!({ "count": "1" }),
+                json!({ "level": 50. }),
+                json!({ "level": f64::MIN }),
+                json!({ "level": f64::MAX }),
+                json!({ "level": "high" }),
+            ],
+        );
+
+        let data_schema: DataSchemaFromOther<Nil> = serde_json::from_value(json!({
+            "type": "integer",
+            "exclusiveMinimum": i64::MIN,
+            "maximum": i64::MAX - 1,
+            "multipleOf": 2,
+        }))
+        .unwrap();
+        assert_same_validation(
+            &data_schema,
+            &[json!(0), json!(i64::MIN + 2), json!(i64::MAX - 1)],
+            &[json!(i64::MIN), json!(i64::MAX), json!(1)],
         );
+    }
+
+    #[test]
+    fn collect_enums_of_object_properties() {
+        let data_schema: DataSchemaFromOther<Nil> = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {
+                "mode": {
+                    "type": "string",
+                    "enum": ["eco", "boost"],
+                },
+                "fan/speed": {
+                    "oneOf": [
+                        { "type": "integer", "enum": [1, 2, 3] },
+                        { "type": "string" },
+                    ],
+                },
+                "name": { "type": "string" },
+            },
+        }))
+        .unwrap();
 
         assert_eq!(
-            Minimum::Exclusive(6).partial_cmp(&Minimum::Inclusive(5)),
-            Some(Ordering::Greater),
+            data_schema.collect_enums(),
+            [
+                (
+                    "/properties/fan~1speed/oneOf/0".to_string(),
+                    &[json!(1), json!(2), json!(3)][..],
+                ),
+                (
+                    "/properties/mode".to_string(),
+                    &[json!("eco"), json!("boost")][..],
+                ),
+            ],
         );
     }
 
     #[test]
-    fn maximum_partial_ord_trivial() {
-        use core::cmp::Ordering;
+    fn string_or_array_members() {
+        let td = |attype: Value, security: Value, profile: Value, op: Value, scopes: Value| {
+            json!({
+                "@context": TD_CONTEXT_11,
+                "@type": attype,
+                "title": "Thing name",
+                "profile": profile,
+                "forms": [{
+                    "href": "/all",
+                    "op": op,
+                    "scopes": scopes,
+                }],
+                "security": security,
+                "securityDefinitions": {
+                    "nosec": { "scheme": "nosec" },
+                    "combo": { "scheme": "combo", "oneOf": security },
+                },
+            })
+        };
+
+        let single = td(
+            json!("Lamp"),
+            json!("nosec"),
+            json!("https://example.com/profile"),
+            json!("readallproperties"),
+            json!("read"),
+        );
+        let single_element_array = td(
+            json!(["Lamp"]),
+            json!(["nosec"]),
+            json!(["https://example.com/profile"]),
+            json!(["readallproperties"]),
+            json!(["read"]),
+        );
+        let multi = td(
+            json!(["Lamp", "Light"]),
+            json!(["nosec", "combo"]),
+            json!(["https://example.com/profile", "https://example.com/other"]),
+            json!(["readallproperties", "writeallproperties"]),
+            json!(["read", "write"]),
+        );
+
+        let from_single: Thing = serde_json::from_value(single).unwrap();
+        let from_array: Thing = serde_json::from_value(single_element_array.clone()).unwrap();
+        assert_eq!(from_single, from_array);
+        assert_eq!(from_single.attype.as_ref().unwrap(), &["Lamp"]);
+        assert_eq!(from_single.security, ["nosec"]);
         assert_eq!(
-            Maximum::Inclusive(5).partial_cmp(&Maximum::Inclusive(5)),
-            Some(Ordering::Equal),
+            from_single.forms.as_ref().unwrap()[0].op,
+            DefaultedFormOperations::Custom(vec![FormOperation::ReadAllProperties]),
         );
         assert_eq!(
-            Maximum::Inclusive(5).partial_cmp(&Maximum::Inclusive(6)),
-            Some(Ordering::Less),
+            serde_json::to_value(&from_single).unwrap(),
+            single_element_array,
         );
         assert_eq!(
-            Maximum::Inclusive(6).partial_cmp(&Maximum::Inclusive(5)),
-            Some(Ordering::Greater),
+            serde_json::to_value(&from_array).unwrap(),
+            single_element_array,
         );
 
+        let from_multi: Thing = serde_json::from_value(multi.clone()).unwrap();
         assert_eq!(
-            Maximum::Exclusive(5).partial_cmp(&Maximum::Exclusive(5)),
-            Some(Ordering::Equal),
+            from_multi.profile,
+            Some(vec![
+                "https://example.com/profile".to_string(),
+                "https://example.com/other".to_string(),
+            ]),
         );
+        assert_eq!(serde_json::to_value(&from_multi).unwrap(), multi);
+    }
+
+    #[test]
+    fn deprecated_property() {
+        use crate::builder::{
+            affordance::BuildableInteractionAffordance, data_schema::SpecializableDataSchema,
+            BuildableHumanReadableInfo,
+        };
+
+        let thing = Thing::builder("Thing name")
+            .finish_extend()
+            .property("level", |b| {
+                b.finish_extend_data_schema()
+                    .integer()
+                    .deprecated()
+                    .deprecation_note("Use brightness instead")
+                    .form(|b| b.href("/level"))
+            })
+            .property("brightness", |b| {
+                b.finish_extend_data_schema()
+                    .integer()
+                    .form(|b| b.href("/brightness"))
+            })
+            .action("reset", |b| b.deprecated().form(|b| b.href("/reset")))
+            .build()
+            .unwrap();
+
+        let mut deprecated = thing.deprecated_affordances().collect::<Vec<_>>();
+        deprecated.sort_unstable_by_key(|&(_, name, _)| name);
         assert_eq!(
-            Maximum::Exclusive(5).partial_cmp(&Maximum::Exclusive(6)),
-            Some(Ordering::Less),
+            deprecated,
+            [
+                (
+                    AffordanceType::Property,
+                    "level",
+                    Some("Use brightness instead")
+                ),
+                (AffordanceType::Action, "reset", None),
+            ],
         );
+
+        let value = serde_json::to_value(&thing).unwrap();
+        assert_eq!(
+            value["properties"]["level"],
+            json!({
+                "type": "integer",
+                "wot-td:deprecated": true,
+                "wot-td:deprecationNote": "Use brightness instead",
+                "readOnly": false,
+                "writeOnly": false,
+                "forms": [{ "href": "/level", "op": ["readproperty", "writeproperty"] }],
+            }),
+        );
+        assert_eq!(value["actions"]["reset"]["wot-td:deprecated"], json!(true));
+        assert!(value["properties"]["brightness"]
+            .get("wot-td:deprecated")
+            .is_none());
+
+        let deserialized: Thing = serde_json::from_value(value).unwrap();
+        assert_eq!(deserialized, thing);
+    }
+
+    #[test]
+    fn declare_wot_td_prefix() {
+        use crate::builder::{
+            affordance::BuildableInteractionAffordance, data_schema::SpecializableDataSchema,
+            BuildableHumanReadableInfo,
+        };
+
+        let thing = Thing::builder("Thing name")
+            .finish_extend()
+            .action("reset", |b| b.form(|b| b.href("/reset")))
+            .build()
+            .unwrap();
+        assert_eq!(thing.context, json!(TD_CONTEXT_11));
+
+        let thing = Thing::builder("Thing name")
+            .finish_extend()
+            .action("reset", |b| {
+                b.input(|b| {
+                    b.finish_extend()
+                        .string()
+                        .deprecation_note("Use the default mode")
+                })
+                .form(|b| b.href("/reset"))
+            })
+            .build()
+            .unwrap();
+        assert_eq!(
+            thing.context,
+            json!([TD_CONTEXT_11, { "wot-td": "https://github.com/wot-rust/wot-td#" }]),
+        );
+
+        let thing = Thing::builder("Thing name")
+            .finish_extend()
+            .context_map(|b| b.context("wot-td", "https://example.com/wot-td#"))
+            .action("reset", |b| b.deprecated().form(|b| b.href("/reset")))
+            .build()
+            .unwrap();
         assert_eq!(
-            Maximum::Exclusive(6).partial_cmp(&Maximum::Exclusive(5)),
-            Some(Ordering::Greater),
+            thing.context,
+            json!([TD_CONTEXT_11, { "wot-td": "https://example.com/wot-td#" }]),
         );
     }
 
     #[test]
-    fn maximum_partial_ord_complex() {
+    fn observable_properties() {
+        use crate::builder::{
+            affordance::BuildableInteractionAffordance, data_schema::SpecializableDataSchema,
+        };
+
+        let thing = Thing::builder("Thing name")
+            .finish_extend()
+            .property("temperature", |b| {
+                b.finish_extend_data_schema()
+                    .number()
+                    .observable(true)
+                    .form(|b| b.href("/temperature"))
+            })
+            .property("unit", |b| {
+                b.finish_extend_data_schema()
+                    .string()
+                    .observable(false)
+                    .form(|b| b.href("/unit"))
+            })
+            .property("name", |b| {
+                b.finish_extend_data_schema()
+                    .string()
+                    .form(|b| b.href("/name"))
+            })
+            .build()
+            .unwrap();
+
+        let observable = thing.observable_properties().collect::<Vec<_>>();
+        assert_eq!(observable.len(), 1);
+        assert_eq!(observable[0].0, "temperature");
         assert_eq!(
-            Maximum::Inclusive(4).partial_cmp(&Maximum::Exclusive(5)),
-            None,
+            observable[0].1,
+            &thing.properties.as_ref().unwrap()["temperature"]
         );
+        assert_eq!(Thing::<Nil>::default().observable_properties().count(), 0);
+    }
 
+    #[test]
+    fn apply_base_to_hrefs() {
+        let document = json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Thing name",
+            "base": "http://lamp.example.com/api/v1",
+            "forms": [
+                { "href": "properties", "op": "readallproperties" },
+                { "href": "HTTPS://other.example.com/all", "op": "writeallproperties" },
+            ],
+            "properties": {
+                "on": {
+                    "type": "boolean",
+                    "forms": [
+                        { "href": "../on{?brightness}" },
+                        { "href": "mqtt://broker.example.com:1883/lamp/on" },
+                    ],
+                },
+            },
+            "actions": {
+                "fade": { "forms": [{ "href": "//cdn.example.com/fade" }] },
+            },
+            "events": {
+                "overheating": { "forms": [{ "href": "/events/overheating?since=now" }] },
+            },
+            "links": [
+                { "href": "manual.pdf" },
+                { "href": "urn:dev:ops:32473-manual" },
+            ],
+            "security": "nosec",
+            "securityDefinitions": {
+                "nosec": { "scheme": "nosec" },
+                "bearer": {
+                    "scheme": "bearer",
+                    "authorization": "auth",
+                    "proxy": "/proxy",
+                },
+                "oauth2": {
+                    "scheme": "oauth2",
+                    "flow": "code",
+                    "authorization": "/oauth/authorize",
+                    "token": "oauth/token",
+                    "refresh": "urn:dev:ops:32473-refresh",
+                },
+            },
+        });
+        let thing: Thing = serde_json::from_value(document.clone()).unwrap();
+
+        let applied = serde_json::from_value::<Thing>(document.clone())
+            .unwrap()
+            .apply_base();
+        assert_eq!(applied.base, None);
+        let hrefs = |thing: &Thing| {
+            let mut hrefs = thing
+                .forms()
+                .map(|form_ref| form_ref.form.href.clone())
+                .chain(thing.links.iter().flatten().map(|link| link.href.clone()))
+                .collect::<Vec<_>>();
+            hrefs.sort_unstable();
+            hrefs
+        };
         assert_eq!(
-            Maximum::Inclusive(5).partial_cmp(&Maximum::Exclusive(5)),
-            Some(Ordering::Greater),
+            hrefs(&applied),
+            [
+                "HTTPS://other.example.com/all",
+                "http://cdn.example.com/fade",
+                "http://lamp.example.com/api/manual.pdf",
+                "http://lamp.example.com/api/properties",
+                "http://lamp.example.com/events/overheating?since=now",
+                "http://lamp.example.com/on{?brightness}",
+                "mqtt://broker.example.com:1883/lamp/on",
+                "urn:dev:ops:32473-manual",
+            ],
         );
-
+        let security_definitions = serde_json::to_value(&applied.security_definitions).unwrap();
         assert_eq!(
-            Maximum::Inclusive(6).partial_cmp(&Maximum::Exclusive(5)),
-            Some(Ordering::Greater),
+            security_definitions["bearer"],
+            json!({
+                "scheme": "bearer",
+                "authorization": "http://lamp.example.com/api/auth",
+                "proxy": "http://lamp.example.com/proxy",
+                "in": "header",
+                "alg": "ES256",
+                "format": "jwt",
+            }),
         );
-
         assert_eq!(
-            Maximum::Exclusive(4).partial_cmp(&Maximum::Inclusive(5)),
-            Some(Ordering::Less)
+            security_definitions["oauth2"],
+            json!({
+                "scheme": "oauth2",
+                "flow": "code",
+                "authorization": "http://lamp.example.com/oauth/authorize",
+                "token": "http://lamp.example.com/api/oauth/token",
+                "refresh": "urn:dev:ops:32473-refresh",
+            }),
+        );
+        assert!(applied.forms_without_scheme().is_empty());
+        assert_eq!(
+            applied
+                .forms()
+                .map(|form_ref| applied.resolve_form_href(form_ref.form).into_owned())
+                .collect::<Vec<_>>(),
+            thing
+                .forms()
+                .map(|form_ref| thing.resolve_form_href(form_ref.form).into_owned())
+                .collect::<Vec<_>>(),
         );
 
+        let mut without_base: Thing = serde_json::from_value(document).unwrap();
+        without_base.base = None;
         assert_eq!(
-            Maximum::Exclusive(5).partial_cmp(&Maximum::Inclusive(5)),
-            Some(Ordering::Less),
+            serde_json::to_value(without_base.apply_base()).unwrap()["forms"][0]["href"],
+            "properties",
         );
+    }
+
+    #[test]
+    fn endpoints_by_authority() {
+        use crate::builder::{
+            affordance::BuildableInteractionAffordance, data_schema::SpecializableDataSchema,
+        };
+
+        let thing = Thing::builder("Thing name")
+            .finish_extend()
+            .base("http://lamp.example.com/api/")
+            .form(|b| b.href("properties").op(FormOperation::ReadAllProperties))
+            .property("on", |b| {
+                b.finish_extend_data_schema()
+                    .bool()
+                    .form(|b| b.href("on"))
+                    .form(|b| b.href("mqtt://broker.example.com:1883/lamp/on"))
+            })
+            .action("fade", |b| {
+                b.form(|b| b.href("//LAMP.example.com/fade"))
+                    .form(|b| b.href("urn:dev:ops:32473-fade"))
+            })
+            .build()
+            .unwrap();
+
+        let mut endpoints = thing
+            .endpoints()
+            .into_iter()
+            .map(|(origin, forms)| {
+                let mut hrefs = forms
+                    .iter()
+                    .map(|form_ref| (form_ref.affordance, form_ref.form.href.as_str()))
+                    .collect::<Vec<_>>();
+                hrefs.sort_unstable_by_key(|&(_, href)| href);
+                (origin, hrefs)
+            })
+            .collect::<Vec<_>>();
+        endpoints.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
 
         assert_eq!(
-            Maximum::Exclusive(6).partial_cmp(&Maximum::Inclusive(5)),
-            None
+            endpoints,
+            [
+                (
+                    "http://lamp.example.com".to_string(),
+                    vec![
+                        (
+                            Some((AffordanceType::Action, "fade")),
+                            "//LAMP.example.com/fade"
+                        ),
+                        (Some((AffordanceType::Property, "on")), "on"),
+                        (None, "properties"),
+                    ],
+                ),
+                (
+                    "mqtt://broker.example.com:1883".to_string(),
+                    vec![(
+                        Some((AffordanceType::Property, "on")),
+                        "mqtt://broker.example.com:1883/lamp/on"
+                    )],
+                ),
+            ],
         );
+        assert_eq!(thing.forms().count(), 5);
     }
 
     #[test]
-    fn minimum_maximum_mixed_partial_ord_trivial() {
+    fn select_form_by_content_type() {
+        use crate::builder::{
+            affordance::BuildableInteractionAffordance,
+            data_schema::{ReadableWriteableDataSchema, SpecializableDataSchema},
+        };
+
+        let thing = Thing::builder("Thing name")
+            .finish_extend()
+            .form(|b| {
+                b.href("/properties")
+                    .op(FormOperation::ReadAllProperties)
+                    .content_type("application/cbor")
+            })
+            .property("temperature", |b| {
+                b.finish_extend_data_schema()
+                    .observable(true)
+                    .number()
+                    .read_only()
+                    .form(|b| b.href("/temperature"))
+                    .form(|b| {
+                        b.href("/temperature.cbor")
+                            .content_type("application/cbor; cose-type=cose-sign1")
+                    })
+            })
+            .action("fade", |b| {
+                b.form(|b| b.href("/fade").content_type("application/cbor"))
+                    .form(|b| b.href("/fade/status").op(FormOperation::QueryAction))
+            })
+            .build()
+            .unwrap();
+
+        let href = |affordance, op, accept: &[&str]| {
+            thing
+                .select_form(affordance, op, accept)
+                .map(|form_ref| form_ref.form.href.as_str())
+        };
+        let read = FormOperation::ReadProperty;
+
         assert_eq!(
-            Minimum::Inclusive(4).partial_cmp(&Maximum::Inclusive(5)),
-            Some(Ordering::Less),
+            href("temperature", read, &["application/cbor"]),
+            Some("/temperature.cbor"),
         );
         assert_eq!(
-            Minimum::Inclusive(5).partial_cmp(&Maximum::Inclusive(5)),
-            Some(Ordering::Equal),
+            href(
+                "temperature",
+                read,
+                &["application/json", "application/cbor"]
+            ),
+            Some("/temperature"),
         );
         assert_eq!(
-            Minimum::Inclusive(6).partial_cmp(&Maximum::Inclusive(5)),
-            Some(Ordering::Greater),
+            href("temperature", read, &["text/plain", "APPLICATION/*"]),
+            Some("/temperature"),
         );
-
+        assert_eq!(href("temperature", read, &["*/*"]), Some("/temperature"));
+        assert_eq!(href("temperature", read, &[]), Some("/temperature"));
+        assert_eq!(href("temperature", read, &["text/plain"]), None);
+        assert_eq!(href("temperature", FormOperation::WriteProperty, &[]), None);
         assert_eq!(
-            Maximum::Inclusive(4).partial_cmp(&Minimum::Inclusive(5)),
-            Some(Ordering::Less),
+            href("temperature", FormOperation::ObserveProperty, &[]),
+            None
         );
+        assert_eq!(href("humidity", read, &[]), None);
+
         assert_eq!(
-            Maximum::Inclusive(5).partial_cmp(&Minimum::Inclusive(5)),
-            Some(Ordering::Equal),
+            href("fade", FormOperation::InvokeAction, &["application/json"]),
+            None,
         );
         assert_eq!(
-            Maximum::Inclusive(6).partial_cmp(&Minimum::Inclusive(5)),
-            Some(Ordering::Greater),
+            href("fade", FormOperation::QueryAction, &["application/json"]),
+            Some("/fade/status"),
         );
+        let form_ref = thing
+            .select_form("fade", FormOperation::InvokeAction, &["application/cbor"])
+            .unwrap();
+        assert_eq!(form_ref.affordance, Some((AffordanceType::Action, "fade")));
+
+        let form_ref = thing
+            .select_form("", FormOperation::ReadAllProperties, &["application/cbor"])
+            .unwrap();
+        assert_eq!(form_ref.affordance, None);
+        assert_eq!(form_ref.form.href, "/properties");
     }
 
     #[test]
-    fn minimum_maximum_mixed_partial_ord_complex() {
-        assert_eq!(
-            Minimum::Inclusive(4).partial_cmp(&Maximum::Exclusive(5)),
-            None,
-        );
+    fn forms_by_protocol_scheme() {
+        let thing: Thing = serde_json::from_value(json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Thing name",
+            "base": "http://lamp.example.com/api/",
+            "security": [],
+            "securityDefinitions": {},
+            "forms": [{ "href": "properties", "op": "readallproperties" }],
+            "properties": {
+                "on": {
+                    "type": "boolean",
+                    "forms": [
+                        { "href": "COAP://lamp.example.com/on" },
+                        { "href": "mqtt://broker.example.com/lamp/on" },
+                    ],
+                },
+            },
+            "actions": {
+                "fade": {
+                    "forms": [
+                        { "href": "https://lamp.example.com/fade" },
+                        { "href": "coap tcp://lamp.example.com/fade" },
+                    ],
+                },
+            },
+        }))
+        .unwrap();
+
+        let forms = thing
+            .forms_by_scheme()
+            .into_iter()
+            .map(|(scheme, forms)| {
+                let mut hrefs = forms
+                    .iter()
+                    .map(|form_ref| form_ref.form.href.as_str())
+                    .collect::<Vec<_>>();
+                hrefs.sort_unstable();
+                (scheme, hrefs)
+            })
+            .collect::<Vec<_>>();
         assert_eq!(
-            Minimum::Inclusive(5).partial_cmp(&Maximum::Exclusive(5)),
-            Some(Ordering::Greater)
+            forms,
+            [
+                ("coap".to_string(), vec!["COAP://lamp.example.com/on"]),
+                ("http".to_string(), vec!["properties"]),
+                ("https".to_string(), vec!["https://lamp.example.com/fade"]),
+                (
+                    "mqtt".to_string(),
+                    vec!["mqtt://broker.example.com/lamp/on"]
+                ),
+            ],
         );
         assert_eq!(
-            Minimum::Inclusive(6).partial_cmp(&Maximum::Exclusive(5)),
-            Some(Ordering::Greater)
+            thing.protocol_schemes().into_iter().collect::<Vec<_>>(),
+            ["coap", "http", "https", "mqtt"],
         );
 
+        let without_scheme = thing.forms_without_scheme();
+        assert_eq!(without_scheme.len(), 1);
         assert_eq!(
-            Minimum::Exclusive(4).partial_cmp(&Maximum::Inclusive(5)),
-            None,
+            without_scheme[0].affordance,
+            Some((AffordanceType::Action, "fade"))
         );
         assert_eq!(
-            Minimum::Exclusive(5).partial_cmp(&Maximum::Inclusive(5)),
-            Some(Ordering::Greater),
+            without_scheme[0].form.href,
+            "coap tcp://lamp.example.com/fade"
         );
+
+        let thing = Thing {
+            base: None,
+            ..thing
+        };
         assert_eq!(
-            Minimum::Exclusive(6).partial_cmp(&Maximum::Inclusive(5)),
-            Some(Ordering::Greater),
+            thing.protocol_schemes().into_iter().collect::<Vec<_>>(),
+            ["coap", "https", "mqtt"],
         );
+        let mut hrefs = thing
+            .forms_without_scheme()
+            .into_iter()
+            .map(|form_ref| form_ref.form.href.as_str())
+            .collect::<Vec<_>>();
+        hrefs.sort_unstable();
+        assert_eq!(hrefs, ["coap tcp://lamp.example.com/fade", "properties"]);
+    }
+
+    #[test]
+    fn human_readable_mutation() {
+        let mut thing: Thing = serde_json::from_value(json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Lamp",
+            "titles": { "en": "Lamp", "it": "Lampada" },
+            "security": [],
+            "securityDefinitions": {},
+            "properties": {
+                "on": {
+                    "type": "boolean",
+                    "title": "On",
+                    "forms": [{ "href": "/on" }],
+                },
+            },
+            "actions": {
+                "fade": {
+                    "forms": [{ "href": "/fade" }],
+                },
+            },
+        }))
+        .unwrap();
 
+        thing.set_title("Smart lamp");
+        thing
+            .add_title_translation("it", "Lampada intelligente")
+            .unwrap();
         assert_eq!(
-            Maximum::Inclusive(4).partial_cmp(&Minimum::Exclusive(5)),
-            Some(Ordering::Less),
+            thing.add_title_translation("en_US", "Lamp"),
+            Err(DocumentError::InvalidLanguageTag("en_US".to_string()).into()),
         );
         assert_eq!(
-            Maximum::Inclusive(5).partial_cmp(&Minimum::Exclusive(5)),
-            Some(Ordering::Less),
+            thing.set_title_translations([("de", "Lampe"), ("e!n", "Lamp")]),
+            Err(DocumentError::InvalidLanguageTag("e!n".to_string()).into()),
         );
+        assert_eq!(thing.titles.as_ref().unwrap().len(), 2);
+        assert_eq!(thing.remove_title_translation("fr"), None);
+        assert_eq!(thing.remove_title_translation("e!n"), None);
+
+        let property = thing.properties.as_mut().unwrap().get_mut("on").unwrap();
+        property.set_title("Power");
+        property
+            .add_description_translation("it", "Accensione")
+            .unwrap();
+        assert_eq!(property.interaction.title, property.data_schema.title);
         assert_eq!(
-            Maximum::Inclusive(6).partial_cmp(&Minimum::Exclusive(5)),
-            None,
+            property.interaction.descriptions,
+            property.data_schema.descriptions,
         );
 
+        let action = thing.actions.as_mut().unwrap().get_mut("fade").unwrap();
+        action.set_description("Fades the light");
+        action
+            .set_description_translations([("it", "Dissolvenza")])
+            .unwrap();
         assert_eq!(
-            Maximum::Exclusive(4).partial_cmp(&Minimum::Inclusive(5)),
-            Some(Ordering::Less),
-        );
-        assert_eq!(
-            Maximum::Exclusive(5).partial_cmp(&Minimum::Inclusive(5)),
-            Some(Ordering::Less),
+            action.remove_description_translation("it").as_deref(),
+            Some("Dissolvenza"),
         );
+        assert_eq!(action.interaction.descriptions, None);
+
+        thing.set_title_translations([("de", "Lampe")]).unwrap();
         assert_eq!(
-            Maximum::Exclusive(6).partial_cmp(&Minimum::Inclusive(5)),
-            None,
+            serde_json::to_value(&thing).unwrap(),
+            json!({
+                "@context": TD_CONTEXT_11,
+                "title": "Smart lamp",
+                "titles": { "de": "Lampe" },
+                "security": [],
+                "securityDefinitions": {},
+                "properties": {
+                    "on": {
+                        "type": "boolean",
+                        "title": "Power",
+                        "descriptions": { "it": "Accensione" },
+                        "forms": [{ "href": "/on" }],
+                        "readOnly": false,
+                        "writeOnly": false,
+                    },
+                },
+                "actions": {
+                    "fade": {
+                        "description": "Fades the light",
+                        "forms": [{ "href": "/fade" }],
+                        "safe": false,
+                        "idempotent": false,
+                    },
+                },
+            }),
         );
     }
 
     #[test]
-    fn serde_number_schema() {
-        let data: NumberSchema = serde_json::from_value(json! {
-            {
-                "minimum": 0.5,
-                "maximum": 1.,
-                "multipleOf": 0.5,
-            }
-        })
-        .unwrap();
+    fn array_item_schemas() {
+        let number = || DataSchema::<Nil, Nil, Nil> {
+            subtype: Some(DataSchemaSubtype::Number(NumberSchema::default())),
+            ..Default::default()
+        };
+        let string = || DataSchema::<Nil, Nil, Nil> {
+            subtype: Some(DataSchemaSubtype::String(StringSchema::default())),
+            ..Default::default()
+        };
+
+        let vec = ArraySchema::<Nil, Nil, Nil> {
+            items: Some(BoxedElemOrVec::Elem(Box::new(number()))),
+            ..Default::default()
+        };
+        assert!(!vec.is_tuple());
+        assert_eq!(vec.item_schemas().collect::<Vec<_>>(), [&number()]);
 
+        let tuple = ArraySchema::<Nil, Nil, Nil> {
+            items: Some(BoxedElemOrVec::Vec(vec![string(), number()])),
+            ..Default::default()
+        };
+        assert!(tuple.is_tuple());
         assert_eq!(
-            data,
-            NumberSchema {
-                minimum: Some(Minimum::Inclusive(0.5)),
-                maximum: Some(Maximum::Inclusive(1.)),
-                multiple_of: Some(0.5),
-            },
+            tuple.item_schemas().collect::<Vec<_>>(),
+            [&string(), &number()],
         );
 
-        let data: NumberSchema = serde_json::from_value(json! {
-            {
-                "exclusiveMinimum": 0.5,
-                "exclusiveMaximum": 1.,
-                "multipleOf": 0.5,
-            }
-        })
-        .unwrap();
+        let single = ArraySchema::<Nil, Nil, Nil> {
+            items: Some(BoxedElemOrVec::Vec(vec![number()])),
+            ..Default::default()
+        };
+        assert!(single.is_tuple());
+        assert_eq!(single.item_schemas().count(), 1);
 
-        assert_eq!(
-            data,
-            NumberSchema {
-                minimum: Some(Minimum::Exclusive(0.5)),
-                maximum: Some(Maximum::Exclusive(1.)),
-                multiple_of: Some(0.5),
-            },
-        );
+        let empty = ArraySchema::<Nil, Nil, Nil>::default();
+        assert!(!empty.is_tuple());
+        assert_eq!(empty.item_schemas().next(), None);
     }
 
     #[test]
-    fn serde_integer_schema() {
-        let data: IntegerSchema = serde_json::from_value(json! {
-            {
-                "minimum": 5,
-                "maximum": 10,
-                "multipleOf": 2,
-            }
-        })
+    fn walk_mut_uppercase_units() {
+        let mut data_schema: DataSchema<Nil, Nil, Nil> = serde_json::from_value(json!({
+            "type": "object",
+            "unit": "root",
+            "properties": {
+                "speed": {
+                    "type": "array",
+                    "items": { "type": "number", "unit": "km/h" },
+                },
+                "position": {
+                    "type": "array",
+                    "items": [
+                        { "type": "number", "unit": "m" },
+                        {
+                            "oneOf": [
+                                { "type": "number", "unit": "cm" },
+                                { "type": "null" },
+                            ],
+                        },
+                    ],
+                },
+            },
+        }))
         .unwrap();
 
-        assert_eq!(
-            data,
-            IntegerSchema {
-                minimum: Some(Minimum::Inclusive(5)),
-                maximum: Some(Maximum::Inclusive(10)),
-                multiple_of: Some(NonZeroU64::new(2).unwrap()),
+        let mut visited = 0;
+        data_schema.walk_mut(&mut |data_schema| {
+            visited += 1;
+            if let Some(unit) = &mut data_schema.unit {
+                *unit = unit.to_uppercase();
+            }
+        });
+        assert_eq!(visited, 8);
+
+        let mut units = Vec::new();
+        data_schema.walk(&mut |data_schema| units.extend(data_schema.unit.as_deref()));
+        units.sort_unstable();
+        assert_eq!(units, ["CM", "KM/H", "M", "ROOT"]);
+    }
+
+    #[test]
+    fn walk_data_schema_values_like_walk() {
+        let nested = json!({
+            "type": "object",
+            "unit": "root",
+            "properties": {
+                "default": {
+                    "type": "array",
+                    "items": { "type": "number", "unit": "km/h" },
+                },
+                "enum": {
+                    "type": "array",
+                    "items": [
+                        { "type": "number", "unit": "m" },
+                        { "oneOf": [{ "type": "number", "unit": "cm" }, { "type": "null" }] },
+                    ],
+                },
+            },
+            "default": { "properties": { "unit": "default" } },
+        });
+        let mut thing = json!({
+            "schemaDefinitions": { "nested": nested },
+            "uriVariables": { "id": { "type": "integer", "unit": "id" } },
+            "properties": {
+                "status": {
+                    "type": "string",
+                    "unit": "status",
+                    "uriVariables": { "lang": { "type": "string", "unit": "lang" } },
+                    "forms": [{ "href": "/status", "additionalResponses": [{ "schema": "x" }] }],
+                },
             },
+            "actions": {
+                "fade": {
+                    "input": { "type": "number", "unit": "input" },
+                    "output": { "type": "null", "unit": "output" },
+                },
+            },
+            "events": { "overheat": { "data": { "type": "number", "unit": "data" } } },
+        });
+
+        let mut pointers = Vec::new();
+        let mut units = Vec::new();
+        walk_data_schema_values(&mut thing, &mut |pointer, data_schema| {
+            pointers.push(pointer.to_string());
+            units.extend(
+                data_schema
+                    .get("unit")
+                    .and_then(Value::as_str)
+                    .map(String::from),
+            );
+        });
+        pointers.sort_unstable();
+        assert_eq!(
+            pointers,
+            [
+                "/actions/fade/input",
+                "/actions/fade/output",
+                "/events/overheat/data",
+                "/properties/status",
+                "/properties/status/uriVariables/lang",
+                "/schemaDefinitions/nested",
+                "/schemaDefinitions/nested/properties/default",
+                "/schemaDefinitions/nested/properties/default/items",
+                "/schemaDefinitions/nested/properties/enum",
+                "/schemaDefinitions/nested/properties/enum/items/0",
+                "/schemaDefinitions/nested/properties/enum/items/1",
+                "/schemaDefinitions/nested/properties/enum/items/1/oneOf/0",
+                "/schemaDefinitions/nested/properties/enum/items/1/oneOf/1",
+                "/uriVariables/id",
+            ],
         );
 
-        let data: IntegerSchema = serde_json::from_value(json! {
-            {
-                "exclusiveMinimum": 5,
-                "exclusiveMaximum": 10,
-            }
-        })
+        let data_schema: DataSchema<Nil, Nil, Nil> = serde_json::from_value(nested).unwrap();
+        let mut walked_units = Vec::new();
+        data_schema.walk(&mut |data_schema| walked_units.extend(data_schema.unit.clone()));
+        walked_units.extend(["id", "status", "lang", "input", "output", "data"].map(String::from));
+        units.sort_unstable();
+        walked_units.sort_unstable();
+        assert_eq!(units, walked_units);
+    }
+
+    #[test]
+    fn serialize_options_skip_only_data_schema_values() {
+        let thing: Thing = serde_json::from_value(json!({
+            "@context": "https://www.w3.org/2022/wot/td/v1.1",
+            "title": "Thing name",
+            "schemaDefinitions": {
+                "limits": {
+                    "type": "object",
+                    "properties": {
+                        "default": { "type": "number", "maximum": 5.0 },
+                        "enum": { "type": "string", "readOnly": false },
+                    },
+                    "default": {
+                        "default": { "type": "number", "maximum": 5.0, "readOnly": false },
+                    },
+                },
+            },
+            "security": [],
+            "securityDefinitions": {},
+        }))
         .unwrap();
 
+        let value = thing
+            .to_value_with(SerializeOptions {
+                compact_floats: true,
+                omit_default_flags: true,
+                ..Default::default()
+            })
+            .unwrap();
+        let limits = &value["schemaDefinitions"]["limits"];
         assert_eq!(
-            data,
-            IntegerSchema {
-                minimum: Some(Minimum::Exclusive(5)),
-                maximum: Some(Maximum::Exclusive(10)),
-                multiple_of: None,
-            },
+            serde_json::to_string(&limits["properties"]["default"]).unwrap(),
+            r#"{"maximum":5,"type":"number"}"#,
+        );
+        assert_eq!(limits["properties"]["enum"], json!({ "type": "string" }));
+        assert_eq!(
+            serde_json::to_string(&limits["default"]).unwrap(),
+            r#"{"default":{"maximum":5.0,"readOnly":false,"type":"number"}}"#,
         );
     }
 
     #[test]
-    fn form_almost_default_serialization() {
-        let form: Form<Nil> = Form {
-            href: "href".to_string(),
-            ..Default::default()
-        };
-
-        let form_json = serde_json::to_value(form).unwrap();
-        assert_eq!(
-            form_json,
-            json!({
-                "href": "href",
-            }),
-        )
+    fn data_schema_same_shape() {
+        let data_schema =
+            |value: Value| serde_json::from_value::<DataSchema<Nil, Nil, Nil>>(value).unwrap();
+
+        let position = data_schema(json!({
+            "title": "Position",
+            "type": "object",
+            "properties": {
+                "x": { "type": "number", "minimum": 0, "description": "The abscissa" },
+                "mode": { "type": "string", "enum": ["absolute", "relative"] },
+            },
+            "required": ["x", "mode"],
+        }));
+        let relabeled = data_schema(json!({
+            "title": "Target",
+            "titles": { "it": "Obiettivo" },
+            "@type": "saref:Position",
+            "type": "object",
+            "properties": {
+                "x": { "type": "number", "minimum": 0, "default": 1 },
+                "mode": { "type": "string", "enum": ["relative", "absolute"] },
+            },
+            "required": ["mode", "x"],
+        }));
+        assert!(position.same_shape(&relabeled));
+        assert!(relabeled.same_shape(&position));
+        assert!(position.same_shape(&position));
+
+        let bounded = data_schema(json!({
+            "title": "Position",
+            "type": "object",
+            "properties": {
+                "x": { "type": "number", "minimum": 0, "maximum": 10 },
+                "mode": { "type": "string", "enum": ["absolute", "relative"] },
+            },
+            "required": ["x", "mode"],
+        }));
+        assert!(!position.same_shape(&bounded));
+
+        let fewer_modes = data_schema(json!({
+            "type": "object",
+            "properties": {
+                "x": { "type": "number", "minimum": 0 },
+                "mode": { "type": "string", "enum": ["absolute"] },
+            },
+            "required": ["x", "mode"],
+        }));
+        assert!(!position.same_shape(&fewer_modes));
+
+        let optional_mode = data_schema(json!({
+            "type": "object",
+            "properties": {
+                "x": { "type": "number", "minimum": 0 },
+                "mode": { "type": "string", "enum": ["absolute", "relative"] },
+            },
+            "required": ["x"],
+        }));
+        assert!(!position.same_shape(&optional_mode));
+
+        assert!(data_schema(json!({ "type": "integer", "title": "A" }))
+            .same_shape(&data_schema(json!({ "type": "integer", "title": "B" }))));
+        assert!(!data_schema(json!({ "type": "integer", "maximum": 5 }))
+            .same_shape(&data_schema(json!({ "type": "integer", "maximum": 6 }))));
+        assert!(!data_schema(json!({ "type": "integer" }))
+            .same_shape(&data_schema(json!({ "type": "number" }))));
+        assert!(!data_schema(json!({ "type": "number", "unit": "m" }))
+            .same_shape(&data_schema(json!({ "type": "number", "unit": "cm" }))));
+        assert!(
+            !data_schema(json!({ "type": "array", "items": [{ "type": "null" }] })).same_shape(
+                &data_schema(json!({ "type": "array", "items": { "type": "null" } }))
+            )
+        );
     }
 }
@@ -0,0 +1,810 @@
+//! Thing Descriptions that have not been checked yet
+//!
+//! A [`Thing`] obtained from the [`builder`](crate::builder) satisfies all the invariants of the
+//! Thing Description. [`UncheckedThing`] holds the same unchecked state of the
+//! [`ThingBuilder`](crate::builder::ThingBuilder) instead: it can be deserialized from a Thing
+//! Description that breaks the invariants, it can be modified, for instance with [JSON Merge
+//! Patch](https://www.rfc-editor.org/rfc/rfc7396) documents, and it is turned into a [`Thing`] by
+//! [`UncheckedThing::try_build`], which checks the data schemas as the builder does and then
+//! performs the checks of [`Thing::validate`].
+//!
+//! # Example
+//!
+//! ```
+//! # use serde_json::json;
+//! # use wot_td::{
+//! #     thing::{DataSchemaSubtype, Maximum},
+//! #     unchecked::UncheckedThing,
+//! # };
+//! #
+//! let mut unchecked: UncheckedThing = serde_json::from_value(json!({
+//!     "@context": "https://www.w3.org/2022/wot/td/v1.1",
+//!     "title": "Lamp",
+//!     "security": [],
+//!     "securityDefinitions": {},
+//!     "properties": {
+//!         "brightness": {
+//!             "type": "integer",
+//!             "minimum": 0,
+//!             "maximum": 100,
+//!             "forms": [{ "href": "/brightness" }],
+//!         },
+//!     },
+//! }))
+//! .unwrap();
+//!
+//! let patch = |minimum, maximum| {
+//!     json!({
+//!         "properties": {
+//!             "brightness": { "minimum": minimum, "maximum": maximum },
+//!         },
+//!     })
+//! };
+//!
+//! // The intermediate state can be invalid...
+//! unchecked.apply_merge_patch(&patch(300, 255)).unwrap();
+//! let invalid: UncheckedThing =
+//!     serde_json::from_value(serde_json::to_value(&unchecked).unwrap()).unwrap();
+//! assert!(invalid.try_build().is_err());
+//! // ...as long as the final one is valid.
+//! unchecked.apply_merge_patch(&patch(0, 255)).unwrap();
+//! let thing = unchecked.try_build().unwrap();
+//!
+//! let Some(DataSchemaSubtype::Integer(brightness)) =
+//!     &thing.properties.unwrap()["brightness"].data_schema.subtype
+//! else {
+//!     unreachable!()
+//! };
+//! assert_eq!(brightness.maximum, Some(Maximum::Inclusive(255)));
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use hashbrown::HashMap;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
+use serde_with::{serde_as, skip_serializing_none};
+use time::OffsetDateTime;
+
+use crate::{
+    builder::{
+        affordance::{omit_unchecked_common, UncheckedInteractionAffordance},
+        data_schema::{UncheckedDataSchemaFromOther, UncheckedDataSchemaMap},
+        Error, MultiLanguageBuilder, UncheckedLink, UncheckedSecurityScheme,
+    },
+    extend::ExtendableThing,
+    hlist::Nil,
+    thing::{
+        default_context, format_at_types, parse_at_types, rfc3339_option, ActionAffordance,
+        EventAffordance, FormFromOther, PropertyAffordance, StringOrArray, Thing, VersionInfo,
+    },
+};
+
+/// A [`Thing`] whose invariants have not been checked.
+///
+/// It mirrors the state of the [`ThingBuilder`](crate::builder::ThingBuilder): the data schemas,
+/// the translations, the links and the security schemes are kept in their unchecked form until
+/// [`try_build`](Self::try_build) is called. It is serialized and deserialized as a Thing
+/// Description.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "preserve-unknown",
+    serde(bound(deserialize = "Other: Serialize + DeserializeOwned"))
+)]
+pub struct UncheckedThing<Other: ExtendableThing = Nil> {
+    #[serde(rename = "@context", default = "default_context")]
+    context: Value,
+    id: Option<String>,
+    #[serde(rename = "@type", default)]
+    #[serde_as(as = "Option<StringOrArray>")]
+    attype: Option<Vec<String>>,
+    title: String,
+    titles: Option<MultiLanguageBuilder<String>>,
+    description: Option<String>,
+    descriptions: Option<MultiLanguageBuilder<String>>,
+    version: Option<VersionInfo>,
+    #[serde(with = "rfc3339_option", default)]
+    created: Option<OffsetDateTime>,
+    #[serde(with = "rfc3339_option", default)]
+    modified: Option<OffsetDateTime>,
+    support: Option<String>,
+    base: Option<String>,
+    properties: Option<HashMap<String, UncheckedPropertyAffordance<Other>>>,
+    actions: Option<HashMap<String, UncheckedActionAffordance<Other>>>,
+    events: Option<HashMap<String, UncheckedEventAffordance<Other>>>,
+    links: Option<Vec<UncheckedLink>>,
+    forms: Option<Vec<FormFromOther<Other>>>,
+    #[serde_as(as = "StringOrArray")]
+    security: Vec<String>,
+    security_definitions: HashMap<String, UncheckedSecurityScheme>,
+    uri_variables: Option<UncheckedDataSchemaMap<Other>>,
+    #[serde(default)]
+    #[serde_as(as = "Option<StringOrArray>")]
+    profile: Option<Vec<String>>,
+    schema_definitions: Option<UncheckedDataSchemaMap<Other>>,
+    #[serde(skip)]
+    default_content_type: Option<String>,
+    #[serde(flatten)]
+    other: Other,
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::unknown::deserialize::<_, crate::thing::Thing<Other>, (Other,)>"
+    )]
+    unknown: Map<String, Value>,
+}
+
+/// A [`PropertyAffordance`] whose invariants have not been checked.
+///
+/// It is serialized and deserialized as a property affordance.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize)]
+pub struct UncheckedPropertyAffordance<Other: ExtendableThing> {
+    #[serde(flatten, serialize_with = "omit_unchecked_common")]
+    interaction: UncheckedInteractionAffordance<Other>,
+    #[serde(flatten)]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(deserialize_with = "deserialize_property_data_schema::<_, Other>")
+    )]
+    data_schema: UncheckedDataSchemaFromOther<Other>,
+    observable: Option<bool>,
+    #[serde(flatten)]
+    other: Other::PropertyAffordance,
+}
+
+// The members claimed by the interaction and property extensions are not unknown, like for the
+// checked property affordances.
+#[cfg(feature = "preserve-unknown")]
+fn deserialize_property_data_schema<'de, D, Other>(
+    deserializer: D,
+) -> Result<UncheckedDataSchemaFromOther<Other>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    Other: ExtendableThing,
+{
+    let mut data_schema = UncheckedDataSchemaFromOther::<Other>::deserialize(deserializer)?;
+    data_schema
+        .remove_property_members::<(Other::InteractionAffordance, Other::PropertyAffordance)>();
+    Ok(data_schema)
+}
+
+/// An [`ActionAffordance`] whose invariants have not been checked.
+///
+/// It is serialized and deserialized as an action affordance.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize)]
+pub struct UncheckedActionAffordance<Other: ExtendableThing> {
+    #[serde(flatten)]
+    interaction: UncheckedInteractionAffordance<Other>,
+    input: Option<UncheckedDataSchemaFromOther<Other>>,
+    output: Option<UncheckedDataSchemaFromOther<Other>>,
+    #[serde(default)]
+    safe: bool,
+    #[serde(default)]
+    idempotent: bool,
+    synchronous: Option<bool>,
+    #[serde(flatten)]
+    other: Other::ActionAffordance,
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::unknown::deserialize::<_, crate::thing::ActionAffordance<Other>, (Other::InteractionAffordance, Other::ActionAffordance)>"
+    )]
+    unknown: Map<String, Value>,
+}
+
+/// An [`EventAffordance`] whose invariants have not been checked.
+///
+/// It is serialized and deserialized as an event affordance.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize)]
+pub struct UncheckedEventAffordance<Other: ExtendableThing> {
+    #[serde(flatten)]
+    interaction: UncheckedInteractionAffordance<Other>,
+    subscription: Option<UncheckedDataSchemaFromOther<Other>>,
+    data: Option<UncheckedDataSchemaFromOther<Other>>,
+    data_response: Option<UncheckedDataSchemaFromOther<Other>>,
+    cancellation: Option<UncheckedDataSchemaFromOther<Other>>,
+    #[serde(flatten)]
+    other: Other::EventAffordance,
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::unknown::deserialize::<_, crate::thing::EventAffordance<Other>, (Other::InteractionAffordance, Other::EventAffordance)>"
+    )]
+    unknown: Map<String, Value>,
+}
+
+impl<Other: ExtendableThing> UncheckedThing<Other> {
+    /// Checks the Thing, returning it if it is valid.
+    ///
+    /// Every data schema is checked in the same way as the [`builder`](crate::builder) does (e.g.
+    /// the minimum cannot be greater than the maximum), the translations must have valid language
+    /// tags, and then the checks of [`Thing::validate`] are performed.
+    pub fn try_build(self) -> Result<Thing<Other>, Error> {
+        let Self {
+            context,
+            id,
+            attype,
+            title,
+            titles,
+            description,
+            descriptions,
+            version,
+            created,
+            modified,
+            support,
+            base,
+            properties,
+            actions,
+            events,
+            links,
+            forms,
+            security,
+            security_definitions,
+            uri_variables,
+            profile,
+            schema_definitions,
+            default_content_type,
+            other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        } = self;
+
+        let titles = titles
+            .map(|titles| titles.build().map_err(|error| error.at("titles")))
+            .transpose()?;
+        let descriptions = descriptions
+            .map(|descriptions| {
+                descriptions
+                    .build()
+                    .map_err(|error| error.at("descriptions"))
+            })
+            .transpose()?;
+        let links = links
+            .map(|links| links.into_iter().map(TryInto::try_into).collect())
+            .transpose()?;
+        let security_definitions =
+            try_build_map(security_definitions, "securityDefinitions", |scheme| {
+                scheme.try_into()
+            })?;
+        let uri_variables = uri_variables
+            .map(|uri_variables| {
+                try_build_map(uri_variables, "uriVariables", |data_schema| {
+                    data_schema.try_build()
+                })
+            })
+            .transpose()?;
+        let schema_definitions = schema_definitions
+            .map(|schema_definitions| {
+                try_build_map(schema_definitions, "schemaDefinitions", |data_schema| {
+                    data_schema.try_build()
+                })
+            })
+            .transpose()?;
+        let properties = properties
+            .map(|properties| {
+                try_build_map(
+                    properties,
+                    "properties",
+                    UncheckedPropertyAffordance::try_build,
+                )
+            })
+            .transpose()?;
+        let actions = actions
+            .map(|actions| try_build_map(actions, "actions", UncheckedActionAffordance::try_build))
+            .transpose()?;
+        let events = events
+            .map(|events| try_build_map(events, "events", UncheckedEventAffordance::try_build))
+            .transpose()?;
+
+        let thing = Thing {
+            context,
+            id,
+            attype: parse_at_types(attype),
+            title,
+            titles,
+            description,
+            descriptions,
+            version,
+            created,
+            modified,
+            support,
+            base,
+            properties,
+            actions,
+            events,
+            links,
+            forms,
+            security,
+            security_definitions,
+            uri_variables,
+            profile,
+            schema_definitions,
+            default_content_type,
+            other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        };
+        thing.validate()?;
+
+        Ok(thing)
+    }
+
+    /// Applies a [JSON Merge Patch](https://www.rfc-editor.org/rfc/rfc7396) to the Thing.
+    ///
+    /// The patch is applied to the serialized Thing Description, which is then deserialized
+    /// again without being checked. The members that are not part of the Thing Description, like
+    /// the default content type set by
+    /// [`ThingBuilder::default_content_type`](crate::builder::ThingBuilder::default_content_type),
+    /// are kept. The Thing is left unchanged if the patched Thing Description cannot be
+    /// deserialized.
+    pub fn apply_merge_patch(&mut self, patch: &Value) -> Result<(), serde_json::Error>
+    where
+        Self: Serialize + DeserializeOwned,
+    {
+        let mut value = serde_json::to_value(&*self)?;
+        merge_patch(&mut value, patch);
+        let mut patched: Self = serde_json::from_value(value)?;
+        patched.default_content_type = self.default_content_type.take();
+        *self = patched;
+        Ok(())
+    }
+}
+
+impl<Other: ExtendableThing> UncheckedPropertyAffordance<Other> {
+    fn try_build(self) -> Result<PropertyAffordance<Other>, Error> {
+        let Self {
+            interaction,
+            data_schema,
+            observable,
+            other,
+        } = self;
+
+        Ok(PropertyAffordance {
+            interaction: interaction.try_into()?,
+            data_schema: data_schema.try_build()?,
+            observable,
+            other,
+        })
+    }
+}
+
+impl<Other: ExtendableThing> UncheckedActionAffordance<Other> {
+    fn try_build(self) -> Result<ActionAffordance<Other>, Error> {
+        let Self {
+            interaction,
+            input,
+            output,
+            safe,
+            idempotent,
+            synchronous,
+            other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        } = self;
+
+        Ok(ActionAffordance {
+            interaction: interaction.try_into()?,
+            input: input
+                .map(|input| input.try_build().map_err(|error| error.within("input")))
+                .transpose()?,
+            output: output
+                .map(|output| output.try_build().map_err(|error| error.within("output")))
+                .transpose()?,
+            safe,
+            idempotent,
+            synchronous,
+            other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        })
+    }
+}
+
+impl<Other: ExtendableThing> UncheckedEventAffordance<Other> {
+    fn try_build(self) -> Result<EventAffordance<Other>, Error> {
+        let Self {
+            interaction,
+            subscription,
+            data,
+            data_response,
+            cancellation,
+            other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        } = self;
+
+        let try_build = |data_schema: Option<UncheckedDataSchemaFromOther<Other>>, name| {
+            data_schema
+                .map(|data_schema| data_schema.try_build().map_err(|error| error.within(name)))
+                .transpose()
+        };
+
+        Ok(EventAffordance {
+            interaction: interaction.try_into()?,
+            subscription: try_build(subscription, "subscription")?,
+            data: try_build(data, "data")?,
+            data_response: try_build(data_response, "dataResponse")?,
+            cancellation: try_build(cancellation, "cancellation")?,
+            other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        })
+    }
+}
+
+fn try_build_map<T, U>(
+    map: HashMap<String, T>,
+    member: &str,
+    try_build: impl Fn(T) -> Result<U, Error>,
+) -> Result<HashMap<String, U>, Error> {
+    map.into_iter()
+        .map(|(name, value)| {
+            let value = try_build(value).map_err(|error| error.within(&name).within(member))?;
+            Ok((name, value))
+        })
+        .collect()
+}
+
+impl<Other: ExtendableThing> From<Thing<Other>> for UncheckedThing<Other> {
+    fn from(thing: Thing<Other>) -> Self {
+        let Thing {
+            context,
+            id,
+            attype,
+            title,
+            titles,
+            description,
+            descriptions,
+            version,
+            created,
+            modified,
+            support,
+            base,
+            properties,
+            actions,
+            events,
+            links,
+            forms,
+            security,
+            security_definitions,
+            uri_variables,
+            profile,
+            schema_definitions,
+            default_content_type,
+            other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        } = thing;
+
+        Self {
+            context,
+            id,
+            attype: format_at_types(attype),
+            title,
+            titles: titles.map(MultiLanguageBuilder::from_built),
+            description,
+            descriptions: descriptions.map(MultiLanguageBuilder::from_built),
+            version,
+            created,
+            modified,
+            support,
+            base,
+            properties: properties.map(convert_map),
+            actions: actions.map(convert_map),
+            events: events.map(convert_map),
+            links: links.map(|links| links.into_iter().map(Into::into).collect()),
+            forms,
+            security,
+            security_definitions: convert_map(security_definitions),
+            uri_variables: uri_variables.map(convert_map),
+            profile,
+            schema_definitions: schema_definitions.map(convert_map),
+            default_content_type,
+            other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        }
+    }
+}
+
+impl<Other: ExtendableThing> From<PropertyAffordance<Other>>
+    for UncheckedPropertyAffordance<Other>
+{
+    fn from(property: PropertyAffordance<Other>) -> Self {
+        let PropertyAffordance {
+            interaction,
+            data_schema,
+            observable,
+            other,
+        } = property;
+
+        Self {
+            interaction: interaction.into(),
+            data_schema: data_schema.into(),
+            observable,
+            other,
+        }
+    }
+}
+
+impl<Other: ExtendableThing> From<ActionAffordance<Other>> for UncheckedActionAffordance<Other> {
+    fn from(action: ActionAffordance<Other>) -> Self {
+        let ActionAffordance {
+            interaction,
+            input,
+            output,
+            safe,
+            idempotent,
+            synchronous,
+            other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        } = action;
+
+        Self {
+            interaction: interaction.into(),
+            input: input.map(Into::into),
+            output: output.map(Into::into),
+            safe,
+            idempotent,
+            synchronous,
+            other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        }
+    }
+}
+
+impl<Other: ExtendableThing> From<EventAffordance<Other>> for UncheckedEventAffordance<Other> {
+    fn from(event: EventAffordance<Other>) -> Self {
+        let EventAffordance {
+            interaction,
+            subscription,
+            data,
+            data_response,
+            cancellation,
+            other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        } = event;
+
+        Self {
+            interaction: interaction.into(),
+            subscription: subscription.map(Into::into),
+            data: data.map(Into::into),
+            data_response: data_response.map(Into::into),
+            cancellation: cancellation.map(Into::into),
+            other,
+            #[cfg(feature = "preserve-unknown")]
+            unknown,
+        }
+    }
+}
+
+fn convert_map<T, U: From<T>>(map: HashMap<String, T>) -> HashMap<String, U> {
+    map.into_iter()
+        .map(|(name, value)| (name, value.into()))
+        .collect()
+}
+
+impl<Other: ExtendableThing> fmt::Debug for UncheckedThing<Other> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UncheckedThing")
+            .field("id", &self.id)
+            .field("title", &self.title)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Applies a [JSON Merge Patch](https://www.rfc-editor.org/rfc/rfc7396) to a JSON value.
+///
+/// The members of `patch` replace the ones of `target` recursively, and `null` members remove
+/// them. A `patch` that is not an object replaces `target` entirely.
+///
+/// # Example
+///
+/// ```
+/// # use serde_json::json;
+/// # use wot_td::unchecked::merge_patch;
+/// #
+/// let mut value = json!({ "title": "Lamp", "description": "A lamp", "version": { "instance": "1" } });
+/// merge_patch(
+///     &mut value,
+///     &json!({ "description": null, "version": { "model": "2" } }),
+/// );
+///
+/// assert_eq!(
+///     value,
+///     json!({ "title": "Lamp", "version": { "instance": "1", "model": "2" } }),
+/// );
+/// ```
+pub fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    if let Value::Object(target) = target {
+        for (name, value) in patch {
+            if value.is_null() {
+                target.remove(name);
+            } else {
+                merge_patch(target.entry(name.clone()).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use serde_json::json;
+
+    use crate::{
+        builder::{DataSchemaError, DocumentError},
+        thing::{DataSchemaSubtype, Maximum, TD_CONTEXT_11},
+    };
+
+    use super::*;
+
+    #[test]
+    fn patch_property_maximum() {
+        let mut unchecked: UncheckedThing = serde_json::from_value(json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Lamp",
+            "security": [],
+            "securityDefinitions": {},
+            "properties": {
+                "temperature": {
+                    "type": "number",
+                    "minimum": 10.5,
+                    "maximum": 30.5,
+                    "unit": "celsius",
+                    "forms": [{ "href": "/temperature" }],
+                },
+            },
+        }))
+        .unwrap();
+
+        unchecked
+            .apply_merge_patch(&json!({
+                "description": "A lamp with a thermometer",
+                "properties": {
+                    "temperature": { "maximum": 5, "unit": null },
+                },
+            }))
+            .unwrap();
+        let invalid: UncheckedThing =
+            serde_json::from_value(serde_json::to_value(&unchecked).unwrap()).unwrap();
+        assert_eq!(
+            invalid.try_build().unwrap_err(),
+            Error::DataSchema(DataSchemaError::InvalidMinMax)
+        );
+
+        unchecked
+            .apply_merge_patch(&json!({
+                "properties": { "temperature": { "maximum": 40 } },
+            }))
+            .unwrap();
+        assert!(unchecked
+            .apply_merge_patch(&json!({ "title": 42 }))
+            .is_err());
+
+        let thing = unchecked.try_build().unwrap();
+        assert_eq!(
+            thing.description.as_deref(),
+            Some("A lamp with a thermometer")
+        );
+        assert_eq!(thing.title, "Lamp");
+
+        let temperature = &thing.properties.as_ref().unwrap()["temperature"].data_schema;
+        assert_eq!(temperature.unit, None);
+        let Some(DataSchemaSubtype::Number(number)) = &temperature.subtype else {
+            panic!("expected a number subtype");
+        };
+        assert_eq!(number.maximum, Some(Maximum::Inclusive(40.)));
+    }
+
+    #[test]
+    fn deserialize_invalid_translations() {
+        let td = json!({
+            "@context": TD_CONTEXT_11,
+            "title": "Lamp",
+            "titles": { "e!n": "Lamp" },
+            "security": [],
+            "securityDefinitions": {},
+            "properties": {
+                "on": {
+                    "type": "boolean",
+                    "descriptions": { "e!n": "Power" },
+                    "forms": [{ "href": "/on" }],
+                },
+            },
+        });
+        assert!(serde_json::from_value::<Thing>(td.clone()).is_err());
+
+        let mut unchecked: UncheckedThing = serde_json::from_value(td).unwrap();
+        assert_eq!(
+            serde_json::to_value(&unchecked).unwrap()["titles"],
+            json!({ "e!n": "Lamp" }),
+        );
+        unchecked
+            .apply_merge_patch(&json!({ "titles": { "e!n": null, "en": "Lamp" } }))
+            .unwrap();
+        assert_eq!(
+            serde_json::from_value::<UncheckedThing>(serde_json::to_value(&unchecked).unwrap())
+                .unwrap()
+                .try_build()
+                .unwrap_err(),
+            Error::Document(DocumentError::InvalidLanguageTag("e!n".to_string()))
+                .at("descriptions")
+                .at("on")
+                .at("properties"),
+        );
+
+        unchecked
+            .apply_merge_patch(&json!({
+                "properties": { "on": { "descriptions": { "e!n": null, "en": "Power" } } },
+            }))
+            .unwrap();
+        let thing = unchecked.try_build().unwrap();
+        assert_eq!(thing.titles.unwrap().len(), 1);
+        assert_eq!(
+            thing.properties.unwrap()["on"]
+                .data_schema
+                .descriptions
+                .as_ref()
+                .unwrap()
+                .len(),
+            1,
+        );
+    }
+
+    #[test]
+    fn merge_patch_keeps_default_content_type() {
+        use crate::builder::{
+            affordance::BuildableInteractionAffordance, data_schema::SpecializableDataSchema,
+        };
+
+        let thing = Thing::builder("Lamp")
+            .default_content_type("application/cbor")
+            .finish_extend()
+            .property("on", |b| {
+                b.finish_extend_data_schema().bool().form(|b| b.href("/on"))
+            })
+            .build()
+            .unwrap();
+
+        let mut unchecked = UncheckedThing::from(thing);
+        unchecked
+            .apply_merge_patch(&json!({ "description": "A lamp" }))
+            .unwrap();
+        let thing = unchecked.try_build().unwrap();
+        assert_eq!(thing.description.as_deref(), Some("A lamp"));
+        assert_eq!(
+            thing.default_content_type.as_deref(),
+            Some("application/cbor"),
+        );
+    }
+
+    #[test]
+    fn merge_patch_replaces_non_objects() {
+        let mut value = json!({ "a": [1, 2], "b": "c" });
+        merge_patch(&mut value, &json!({ "a": { "d": null, "e": 1 }, "b": [3] }));
+        assert_eq!(value, json!({ "a": { "e": 1 }, "b": [3] }));
+
+        merge_patch(&mut value, &json!("f"));
+        assert_eq!(value, json!("f"));
+    }
+}
@@ -0,0 +1,191 @@
+//! Normalization of the units of measurement
+//!
+//! The `unit` of a data schema is a free-form string, and the same unit is often written in
+//! several ways: `"Cel"`, `"°C"`, `"celsius"` and `"om:degreeCelsius"` all mean degrees Celsius.
+//! [`normalize_unit`] maps the common spellings to the case-sensitive code of the [Unified Code
+//! for Units of Measure](https://ucum.org/ucum) (UCUM), which can be used to compare the units of
+//! different Things.
+//!
+//! The raw `unit` is never changed, and it is serialized as is. The vocabulary only covers the
+//! units commonly found in Thing Descriptions; with the `units` feature enabled,
+//! [`Thing::lint`](crate::thing::Thing::lint) reports the units it does not recognize.
+//!
+//! # Example
+//!
+//! ```
+//! # use wot_td::units::normalize_unit;
+//! #
+//! assert_eq!(normalize_unit("celsius"), Some("Cel"));
+//! assert_eq!(normalize_unit("om:degreeCelsius"), Some("Cel"));
+//! assert_eq!(normalize_unit("m/s"), Some("m/s"));
+//! assert_eq!(normalize_unit("furlongs per fortnight"), None);
+//! ```
+
+/// The UCUM codes of the known units, each one followed by its aliases.
+///
+/// The codes are matched exactly, while the aliases are matched ignoring the ASCII case.
+const UNITS: &[(&str, &[&str])] = &[
+    // Temperature
+    (
+        "Cel",
+        &[
+            "°C",
+            "℃",
+            "celsius",
+            "degree celsius",
+            "degrees celsius",
+            "degreecelsius",
+            "degree_celsius",
+            "om:degreecelsius",
+        ],
+    ),
+    (
+        "[degF]",
+        &[
+            "°F",
+            "℉",
+            "fahrenheit",
+            "degree fahrenheit",
+            "degrees fahrenheit",
+            "degreefahrenheit",
+            "degree_fahrenheit",
+            "om:degreefahrenheit",
+        ],
+    ),
+    ("K", &["kelvin", "om:kelvin"]),
+    // Ratios
+    ("%", &["percent", "percentage", "om:percent"]),
+    ("[ppm]", &["ppm", "parts per million", "om:partspermillion"]),
+    // Length
+    ("m", &["metre", "meter", "metres", "meters", "om:metre"]),
+    (
+        "cm",
+        &[
+            "centimetre",
+            "centimeter",
+            "centimetres",
+            "centimeters",
+            "om:centimetre",
+        ],
+    ),
+    (
+        "mm",
+        &[
+            "millimetre",
+            "millimeter",
+            "millimetres",
+            "millimeters",
+            "om:millimetre",
+        ],
+    ),
+    (
+        "km",
+        &[
+            "kilometre",
+            "kilometer",
+            "kilometres",
+            "kilometers",
+            "om:kilometre",
+        ],
+    ),
+    // Mass
+    ("g", &["gram", "grams", "om:gram"]),
+    ("kg", &["kilogram", "kilograms", "om:kilogram"]),
+    // Time
+    ("s", &["sec", "second", "seconds", "om:second-time"]),
+    (
+        "ms",
+        &["millisecond", "milliseconds", "om:millisecond-time"],
+    ),
+    ("min", &["minute", "minutes", "om:minute-time"]),
+    ("h", &["hour", "hours", "om:hour"]),
+    ("d", &["day", "days", "om:day"]),
+    // Speed
+    (
+        "m/s",
+        &[
+            "m.s-1",
+            "metre per second",
+            "meter per second",
+            "metres per second",
+            "meters per second",
+            "om:metre-perSecond-time",
+        ],
+    ),
+    (
+        "km/h",
+        &["kmh", "km.h-1", "kilometre per hour", "kilometer per hour"],
+    ),
+    // Electricity
+    ("V", &["volt", "volts", "om:volt"]),
+    ("A", &["ampere", "amperes", "amp", "amps", "om:ampere"]),
+    ("mA", &["milliampere", "milliamperes", "om:milliampere"]),
+    ("W", &["watt", "watts", "om:watt"]),
+    ("kW", &["kilowatt", "kilowatts", "om:kilowatt"]),
+    (
+        "kW.h",
+        &["kWh", "kilowatt hour", "kilowatt-hour", "om:kilowatthour"],
+    ),
+    ("Wh", &["W.h", "watt hour", "watt-hour", "om:watthour"]),
+    // Pressure
+    ("Pa", &["pascal", "pascals", "om:pascal"]),
+    ("hPa", &["hectopascal", "hectopascals", "om:hectopascal"]),
+    ("bar", &["bars", "om:bar"]),
+    // Others
+    ("Hz", &["hertz", "om:hertz"]),
+    ("lx", &["lux", "om:lux"]),
+    ("dB", &["decibel", "decibels", "om:decibel"]),
+    (
+        "L",
+        &["l", "litre", "liter", "litres", "liters", "om:litre"],
+    ),
+    ("deg", &["°", "degree", "degrees", "om:degree"]),
+    ("rad", &["radian", "radians", "om:radian"]),
+];
+
+/// Returns the UCUM code of a unit, or `None` if it is not a known unit.
+///
+/// The UCUM codes are matched exactly, because they are case-sensitive (`mA` is not `MA`), while
+/// the other spellings are matched ignoring the ASCII case. Surrounding whitespace is ignored.
+pub fn normalize_unit(unit: &str) -> Option<&'static str> {
+    let unit = unit.trim();
+    UNITS
+        .iter()
+        .find(|&&(code, aliases)| {
+            code == unit || aliases.iter().any(|alias| alias.eq_ignore_ascii_case(unit))
+        })
+        .map(|&(code, _)| code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_units() {
+        assert_eq!(normalize_unit("celsius"), Some("Cel"));
+        assert_eq!(normalize_unit("Degree Celsius"), Some("Cel"));
+        assert_eq!(normalize_unit("°C"), Some("Cel"));
+        assert_eq!(normalize_unit(" Cel "), Some("Cel"));
+        assert_eq!(normalize_unit("cm"), Some("cm"));
+        assert_eq!(normalize_unit("kWh"), Some("kW.h"));
+        assert_eq!(normalize_unit("om:metre-perSecond-time"), Some("m/s"));
+    }
+
+    #[test]
+    fn codes_are_case_sensitive() {
+        assert_eq!(normalize_unit("mA"), Some("mA"));
+        assert_eq!(normalize_unit("MA"), None);
+        assert_eq!(normalize_unit("CEL"), None);
+        assert_eq!(normalize_unit(""), None);
+    }
+
+    #[test]
+    fn unambiguous_aliases() {
+        for &(code, aliases) in UNITS {
+            for alias in aliases {
+                assert_eq!(normalize_unit(alias), Some(code), "{alias}");
+            }
+        }
+    }
+}
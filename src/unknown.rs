@@ -0,0 +1,320 @@
+//! Preservation of the members that are not understood
+//!
+//! With the `preserve-unknown` feature the extensible structs collect the members that are
+//! neither declared by the struct nor claimed by a registered extension, in order to emit them
+//! again on serialization. A member of the TD vocabulary is unknown where the TD does not define
+//! it, like an `href` on a Thing.
+//!
+//! Serde does not remove the entries consumed by a flattened map-like field, therefore the
+//! catch-all map receives the members of the extensions as well. The typed extensions always take the
+//! precedence: after deserializing the catch-all, every extension is deserialized again from the
+//! collected members and the ones it serializes back are considered claimed and removed.
+
+use alloc::string::String;
+
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    extend::ExtendableThing,
+    thing::{
+        ActionAffordance, DataSchema, EventAffordance, Form, Thing, ACTION_AFFORDANCE_MEMBERS,
+        DATA_SCHEMA_MEMBERS, EVENT_AFFORDANCE_MEMBERS, FORM_MEMBERS,
+        INTERACTION_AFFORDANCE_MEMBERS, THING_MEMBERS,
+    },
+};
+
+/// The members of a JSON object that are not understood.
+pub(crate) type UnknownMembers = Map<String, Value>;
+
+/// A struct collecting the members that are not understood.
+pub(crate) trait KnownMembers {
+    /// The lists of the members of the TD vocabulary declared by the struct.
+    ///
+    /// Only these members are removed from the catch-all map, a member of the TD vocabulary
+    /// declared by another struct is not understood here and it is preserved.
+    const MEMBERS: &'static [&'static [&'static str]];
+}
+
+impl<Other: ExtendableThing> KnownMembers for Thing<Other> {
+    const MEMBERS: &'static [&'static [&'static str]] = &[THING_MEMBERS];
+}
+
+impl<Other: ExtendableThing> KnownMembers for ActionAffordance<Other> {
+    const MEMBERS: &'static [&'static [&'static str]] =
+        &[INTERACTION_AFFORDANCE_MEMBERS, ACTION_AFFORDANCE_MEMBERS];
+}
+
+impl<Other: ExtendableThing> KnownMembers for EventAffordance<Other> {
+    const MEMBERS: &'static [&'static [&'static str]] =
+        &[INTERACTION_AFFORDANCE_MEMBERS, EVENT_AFFORDANCE_MEMBERS];
+}
+
+impl<DS, AS, OS> KnownMembers for DataSchema<DS, AS, OS> {
+    const MEMBERS: &'static [&'static [&'static str]] = &[DATA_SCHEMA_MEMBERS];
+}
+
+impl<F, ER> KnownMembers for Form<F, ER> {
+    const MEMBERS: &'static [&'static [&'static str]] = &[FORM_MEMBERS];
+}
+
+/// A set of extensions that can claim some members of a JSON object.
+pub(crate) trait ClaimMembers {
+    /// Removes the members claimed by the extensions.
+    fn remove_claimed(members: &mut UnknownMembers);
+}
+
+macro_rules! impl_claim_members {
+    ($($ty:ident),+) => {
+        impl<$($ty),+> ClaimMembers for ($($ty,)+)
+        where
+            $($ty: Serialize + DeserializeOwned,)+
+        {
+            fn remove_claimed(members: &mut UnknownMembers) {
+                $(remove_claimed_by::<$ty>(members);)+
+            }
+        }
+    };
+}
+
+impl_claim_members!(A);
+impl_claim_members!(A, B);
+impl_claim_members!(A, B, C);
+
+fn remove_claimed_by<T>(members: &mut UnknownMembers)
+where
+    T: Serialize + DeserializeOwned,
+{
+    let claimed = serde_json::from_value::<T>(Value::Object(members.clone()))
+        .and_then(|extension| serde_json::to_value(extension));
+
+    if let Ok(Value::Object(claimed)) = claimed {
+        members.retain(|key, _| !claimed.contains_key(key));
+    }
+}
+
+/// Removes the members of the TD vocabulary contained in any of the lists.
+pub(crate) fn remove_known(members: &mut UnknownMembers, known: &[&[&str]]) {
+    members.retain(|key, _| !known.iter().any(|known| known.contains(&key.as_str())));
+}
+
+/// Deserializes the catch-all map of the extensible struct `T`.
+///
+/// The members of the TD vocabulary declared by `T` and the ones claimed by `C` are removed.
+pub(crate) fn deserialize<'de, D, T, C>(deserializer: D) -> Result<UnknownMembers, D::Error>
+where
+    D: Deserializer<'de>,
+    T: KnownMembers,
+    C: ClaimMembers,
+{
+    let mut members = UnknownMembers::deserialize(deserializer)?;
+    remove_known(&mut members, T::MEMBERS);
+    C::remove_claimed(&mut members);
+    Ok(members)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use pretty_assertions::assert_eq;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::{
+        extend::ExtendableThing,
+        hlist::{Cons, Nil},
+        thing::{DataSchemaSubtype, Thing},
+    };
+
+    use super::*;
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Retain {
+        #[serde(
+            rename = "mqv:retain",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        retain: Option<bool>,
+    }
+
+    impl ExtendableThing for Retain {
+        type InteractionAffordance = ();
+        type PropertyAffordance = ();
+        type ActionAffordance = ();
+        type EventAffordance = ();
+        type Form = Retain;
+        type ExpectedResponse = ();
+        type DataSchema = ();
+        type ObjectSchema = ();
+        type ArraySchema = ();
+    }
+
+    fn binding_td() -> Value {
+        json!({
+            "@context": "https://www.w3.org/2022/wot/td/v1.1",
+            "title": "Thing name",
+            "vendor:version": 3,
+            "properties": {
+                "on": {
+                    "type": "boolean",
+                    "readOnly": false,
+                    "writeOnly": false,
+                    "observable": true,
+                    "vendor:hint": "switch",
+                    "forms": [{
+                        "href": "mqtt://broker/on",
+                        "op": ["readproperty", "writeproperty"],
+                        "mqv:retain": true,
+                        "mqv:qos": "1",
+                    }],
+                },
+            },
+            "actions": {
+                "fade": {
+                    "vendor:slow": true,
+                    "safe": false,
+                    "idempotent": false,
+                    "input": {
+                        "type": "integer",
+                        "readOnly": false,
+                        "writeOnly": false,
+                        "vendor:range": [0, 100],
+                    },
+                    "forms": [{ "href": "/fade" }],
+                },
+            },
+            "events": {
+                "overheat": {
+                    "vendor:severity": "high",
+                    "forms": [{ "href": "/overheat" }],
+                },
+            },
+            "security": ["nosec"],
+            "securityDefinitions": {
+                "nosec": { "scheme": "nosec" },
+            },
+        })
+    }
+
+    #[test]
+    fn round_trip_unknown_members() {
+        let td = binding_td();
+        let thing: Thing = serde_json::from_value(td.clone()).unwrap();
+
+        assert_eq!(thing.unknown["vendor:version"], 3);
+        let property = &thing.properties.as_ref().unwrap()["on"];
+        assert_eq!(property.data_schema.unknown["vendor:hint"], "switch");
+        assert_eq!(
+            property.interaction.forms[0]
+                .unknown
+                .keys()
+                .collect::<Vec<_>>(),
+            ["mqv:qos", "mqv:retain"],
+        );
+        let action = &thing.actions.as_ref().unwrap()["fade"];
+        assert_eq!(action.unknown.keys().collect::<Vec<_>>(), ["vendor:slow"]);
+        assert_eq!(
+            action.input.as_ref().unwrap().unknown["vendor:range"],
+            json!([0, 100]),
+        );
+        assert!(matches!(
+            action.input.as_ref().unwrap().subtype,
+            Some(DataSchemaSubtype::Integer(_)),
+        ));
+
+        assert_eq!(serde_json::to_value(&thing).unwrap(), td);
+    }
+
+    #[test]
+    fn members_known_elsewhere_are_preserved() {
+        let td = json!({
+            "@context": "https://www.w3.org/2022/wot/td/v1.1",
+            "title": "Thing name",
+            "href": "/elsewhere",
+            "properties": {
+                "on": {
+                    "type": "boolean",
+                    "readOnly": false,
+                    "writeOnly": false,
+                    "observable": true,
+                    "input": "misplaced",
+                    "forms": [{ "href": "/on", "minimum": 1 }],
+                },
+            },
+            "actions": {
+                "fade": {
+                    "safe": false,
+                    "idempotent": false,
+                    "observable": true,
+                    "input": {
+                        "type": "integer",
+                        "readOnly": false,
+                        "writeOnly": false,
+                        "forms": [],
+                    },
+                    "forms": [{ "href": "/fade" }],
+                },
+            },
+            "security": ["nosec"],
+            "securityDefinitions": {
+                "nosec": { "scheme": "nosec" },
+            },
+        });
+        let thing: Thing = serde_json::from_value(td.clone()).unwrap();
+
+        assert_eq!(thing.unknown.keys().collect::<Vec<_>>(), ["href"]);
+        let property = &thing.properties.as_ref().unwrap()["on"];
+        assert_eq!(
+            property.data_schema.unknown.keys().collect::<Vec<_>>(),
+            ["input"],
+        );
+        assert_eq!(
+            property.interaction.forms[0]
+                .unknown
+                .keys()
+                .collect::<Vec<_>>(),
+            ["minimum"],
+        );
+        let action = &thing.actions.as_ref().unwrap()["fade"];
+        assert_eq!(action.unknown.keys().collect::<Vec<_>>(), ["observable"]);
+        assert_eq!(
+            action
+                .input
+                .as_ref()
+                .unwrap()
+                .unknown
+                .keys()
+                .collect::<Vec<_>>(),
+            ["forms"],
+        );
+
+        assert_eq!(serde_json::to_value(&thing).unwrap(), td);
+    }
+
+    #[test]
+    fn extensions_take_precedence() {
+        let td = binding_td();
+        let mut thing: Thing<Cons<Retain, Nil>> = serde_json::from_value(td.clone()).unwrap();
+
+        let form = &mut thing
+            .properties
+            .as_mut()
+            .unwrap()
+            .get_mut("on")
+            .unwrap()
+            .interaction
+            .forms[0];
+        assert_eq!(form.other.head, Retain { retain: Some(true) });
+        assert_eq!(form.unknown.keys().collect::<Vec<_>>(), ["mqv:qos"]);
+
+        form.other.head.retain = Some(false);
+        let mut expected = td;
+        expected["properties"]["on"]["forms"][0]["mqv:retain"] = false.into();
+        assert_eq!(serde_json::to_value(&thing).unwrap(), expected);
+
+        let serialized = serde_json::to_string(&thing).unwrap();
+        assert_eq!(serialized.matches("mqv:retain").count(), 1);
+    }
+}
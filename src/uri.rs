@@ -0,0 +1,387 @@
+//! URI syntax validation
+//!
+//! The checks follow the generic syntax of [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986).
+//! References can be resolved against a base URI, but they are not normalized.
+//!
+//! The URI references of the Thing Descriptions are validated by the backend selected with the
+//! cargo features: the `iri` feature accepts the internationalized references of [RFC
+//! 3987](https://www.rfc-editor.org/rfc/rfc3987), the default `uri-strict` feature only accepts
+//! the ASCII references of RFC 3986. Without any of them, the references are not validated.
+
+use alloc::{borrow::Cow, format, string::String, vec::Vec};
+
+/// Checks whether the string is a valid URI reference.
+///
+/// Both absolute URIs and relative references are accepted, because fields like `proxy` are
+/// defined as `anyURI` by the Thing Description specification. The function checks that:
+///
+/// - only the characters allowed by RFC 3986 are used;
+/// - percent-encoded octets are made of two hexadecimal digits;
+/// - the fragment does not contain a `#`;
+/// - the scheme, when present, starts with a letter and contains only letters, digits, `+`, `-`
+///   and `.`.
+#[cfg_attr(any(feature = "iri", not(feature = "uri-strict")), allow(dead_code))]
+pub(crate) fn is_uri_reference(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'%' => {
+                let is_encoded = bytes
+                    .get(index + 1..index + 3)
+                    .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit));
+                if !is_encoded {
+                    return false;
+                }
+                index += 3;
+                continue;
+            }
+            c if is_uri_char(c) => {}
+            _ => return false,
+        }
+        index += 1;
+    }
+
+    if s.matches('#').count() > 1 {
+        return false;
+    }
+
+    let path_start = s.find(['/', '?', '#']).unwrap_or(s.len());
+    match s[..path_start].split_once(':') {
+        Some((scheme, _)) => is_scheme(scheme),
+        None => true,
+    }
+}
+
+/// A backend validating the URI references.
+pub(crate) trait ReferenceValidator {
+    /// The name of the backend, reported by the errors.
+    const NAME: &'static str;
+
+    /// Checks whether the string is a valid reference.
+    fn is_valid(s: &str) -> bool;
+}
+
+/// The ASCII-only URI references of RFC 3986, checked by [`is_uri_reference`].
+#[cfg_attr(any(feature = "iri", not(feature = "uri-strict")), allow(dead_code))]
+pub(crate) struct UriStrict;
+
+impl ReferenceValidator for UriStrict {
+    const NAME: &'static str = "RFC 3986 URI";
+
+    fn is_valid(s: &str) -> bool {
+        is_uri_reference(s)
+    }
+}
+
+/// The internationalized IRI references of RFC 3987.
+#[cfg(feature = "iri")]
+pub(crate) struct Iri;
+
+#[cfg(feature = "iri")]
+impl ReferenceValidator for Iri {
+    const NAME: &'static str = "RFC 3987 IRI";
+
+    fn is_valid(s: &str) -> bool {
+        iri_string::types::IriReferenceStr::new(s).is_ok()
+    }
+}
+
+/// Any reference, used when neither `iri` nor `uri-strict` is enabled.
+#[cfg(not(any(feature = "iri", feature = "uri-strict")))]
+pub(crate) struct Unchecked;
+
+#[cfg(not(any(feature = "iri", feature = "uri-strict")))]
+impl ReferenceValidator for Unchecked {
+    const NAME: &'static str = "disabled";
+
+    fn is_valid(_s: &str) -> bool {
+        true
+    }
+}
+
+/// The backend selected by the cargo features, `iri` takes the precedence over `uri-strict`.
+#[cfg(feature = "iri")]
+pub(crate) type Backend = Iri;
+
+/// The backend selected by the cargo features, `iri` takes the precedence over `uri-strict`.
+#[cfg(all(feature = "uri-strict", not(feature = "iri")))]
+pub(crate) type Backend = UriStrict;
+
+/// The backend selected by the cargo features, `iri` takes the precedence over `uri-strict`.
+#[cfg(not(any(feature = "iri", feature = "uri-strict")))]
+pub(crate) type Backend = Unchecked;
+
+/// The name of the selected backend.
+pub(crate) const BACKEND_NAME: &str = Backend::NAME;
+
+/// Checks whether the string is a valid reference according to the selected backend.
+pub(crate) fn is_valid_reference(s: &str) -> bool {
+    Backend::is_valid(s)
+}
+
+/// Checks whether an `href` is a valid reference, ignoring the expressions of the URI template.
+///
+/// The expressions are removed before the check, because their braces are not allowed in a URI
+/// reference and their values are only known when the template is expanded.
+pub(crate) fn is_valid_template_reference(s: &str) -> bool {
+    let reference = if s.contains('{') {
+        let mut reference = String::with_capacity(s.len());
+        let mut rest = s;
+        while let Some((literal, expression)) = rest.split_once('{') {
+            reference.push_str(literal);
+            rest = expression.split_once('}').map_or("", |(_, rest)| rest);
+        }
+        reference.push_str(rest);
+        Cow::Owned(reference)
+    } else {
+        Cow::Borrowed(s)
+    };
+
+    is_valid_reference(&reference)
+}
+
+#[cfg_attr(any(feature = "iri", not(feature = "uri-strict")), allow(dead_code))]
+fn is_uri_char(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || b"-._~:/?#[]@!$&'()*+,;=".contains(&c)
+}
+
+fn is_scheme(scheme: &str) -> bool {
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+/// The components of a URI reference, as split by the regular expression of RFC 3986 appendix B.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Components<'a> {
+    pub(crate) scheme: Option<&'a str>,
+    pub(crate) authority: Option<&'a str>,
+    pub(crate) path: &'a str,
+    pub(crate) query: Option<&'a str>,
+    pub(crate) fragment: Option<&'a str>,
+}
+
+impl<'a> Components<'a> {
+    pub(crate) fn parse(s: &'a str) -> Self {
+        let (s, fragment) = match s.split_once('#') {
+            Some((s, fragment)) => (s, Some(fragment)),
+            None => (s, None),
+        };
+        let (s, query) = match s.split_once('?') {
+            Some((s, query)) => (s, Some(query)),
+            None => (s, None),
+        };
+        let (scheme, s) = match s.split_once(':') {
+            Some((scheme, rest)) if is_scheme(scheme) && !scheme.contains('/') => {
+                (Some(scheme), rest)
+            }
+            _ => (None, s),
+        };
+        let (authority, path) = match s.strip_prefix("//") {
+            Some(s) => {
+                let end = s.find('/').unwrap_or(s.len());
+                (Some(&s[..end]), &s[end..])
+            }
+            None => (None, s),
+        };
+
+        Self {
+            scheme,
+            authority,
+            path,
+            query,
+            fragment,
+        }
+    }
+
+    /// Returns the lowercased `scheme://authority` of an absolute URI with an authority.
+    pub(crate) fn origin(&self) -> Option<String> {
+        let mut origin = String::from(self.scheme?);
+        origin.push_str("://");
+        origin.push_str(self.authority?);
+        origin.make_ascii_lowercase();
+        Some(origin)
+    }
+
+    fn recompose(&self) -> String {
+        let mut uri = String::new();
+        if let Some(scheme) = self.scheme {
+            uri.push_str(scheme);
+            uri.push(':');
+        }
+        if let Some(authority) = self.authority {
+            uri.push_str("//");
+            uri.push_str(authority);
+        }
+        uri.push_str(self.path);
+        if let Some(query) = self.query {
+            uri.push('?');
+            uri.push_str(query);
+        }
+        if let Some(fragment) = self.fragment {
+            uri.push('#');
+            uri.push_str(fragment);
+        }
+        uri
+    }
+}
+
+/// Resolves a URI reference against a base URI, following RFC 3986 section 5.2.
+///
+/// References that already have a scheme are returned unchanged apart from the removal of the dot
+/// segments.
+pub(crate) fn resolve(base: &str, reference: &str) -> String {
+    let base = Components::parse(base);
+    let reference = Components::parse(reference);
+
+    let merged;
+    let target = if reference.scheme.is_some() {
+        merged = remove_dot_segments(reference.path);
+        Components {
+            path: &merged,
+            ..reference
+        }
+    } else if reference.authority.is_some() {
+        merged = remove_dot_segments(reference.path);
+        Components {
+            scheme: base.scheme,
+            path: &merged,
+            ..reference
+        }
+    } else if reference.path.is_empty() {
+        Components {
+            query: reference.query.or(base.query),
+            fragment: reference.fragment,
+            ..base
+        }
+    } else {
+        merged = if reference.path.starts_with('/') {
+            remove_dot_segments(reference.path)
+        } else if base.authority.is_some() && base.path.is_empty() {
+            remove_dot_segments(&format!("/{}", reference.path))
+        } else {
+            let directory = base.path.rfind('/').map_or("", |end| &base.path[..=end]);
+            remove_dot_segments(&format!("{directory}{}", reference.path))
+        };
+        Components {
+            path: &merged,
+            query: reference.query,
+            fragment: reference.fragment,
+            ..base
+        }
+    };
+
+    target.recompose()
+}
+
+fn remove_dot_segments(path: &str) -> String {
+    let mut output: Vec<&str> = Vec::new();
+    let mut segments = path.split('/').peekable();
+    let absolute = path.starts_with('/');
+    if absolute {
+        segments.next();
+    }
+
+    while let Some(segment) = segments.next() {
+        let last = segments.peek().is_none();
+        match segment {
+            "." | ".." => {
+                if segment == ".." {
+                    output.pop();
+                }
+                if last {
+                    output.push("");
+                }
+            }
+            segment => output.push(segment),
+        }
+    }
+
+    let mut path = output.join("/");
+    if absolute {
+        path.insert(0, '/');
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_uri_references() {
+        assert!(is_uri_reference(
+            "https://proxy.example.com:8080/path?query#fragment"
+        ));
+        assert!(is_uri_reference("urn:dev:ops:32473-WoTLamp-1234"));
+        assert!(is_uri_reference("coap+tcp://[2001:db8::1]/"));
+        assert!(is_uri_reference("/relative/path%20with%2Fescapes"));
+        assert!(is_uri_reference("proxy"));
+        assert!(is_uri_reference(""));
+    }
+
+    #[test]
+    fn invalid_uri_references() {
+        assert!(!is_uri_reference("http://exa mple.com"));
+        assert!(!is_uri_reference("http://example.com/%zz"));
+        assert!(!is_uri_reference("http://example.com/%2"));
+        assert!(!is_uri_reference("http://example.com/#a#b"));
+        assert!(!is_uri_reference("1http://example.com"));
+        assert!(!is_uri_reference(":no-scheme"));
+        assert!(!is_uri_reference("http://example.com/\u{e8}"));
+    }
+
+    #[cfg(feature = "uri-strict")]
+    #[test]
+    fn template_references() {
+        assert!(is_valid_template_reference(
+            "/things/{id}/properties{?lang}"
+        ));
+        assert!(is_valid_template_reference("{+base}/status"));
+        assert!(!is_valid_template_reference("/things/{id}/my status"));
+    }
+
+    #[test]
+    fn resolve_references() {
+        // Examples from RFC 3986 section 5.4
+        let base = "http://a/b/c/d;p?q";
+        for (reference, target) in [
+            ("g:h", "g:h"),
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            ("//g", "http://g"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q#s"),
+            ("g#s", "http://a/b/c/g#s"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../..", "http://a/"),
+            ("../../../g", "http://a/g"),
+            ("/./g", "http://a/g"),
+            ("g/../h", "http://a/b/c/h"),
+        ] {
+            assert_eq!(resolve(base, reference), target, "{reference}");
+        }
+
+        assert_eq!(
+            resolve("coap://example.com", "status"),
+            "coap://example.com/status"
+        );
+    }
+
+    #[test]
+    fn uri_origin() {
+        assert_eq!(
+            Components::parse("HTTPS://Example.com:8443/a?b#c").origin(),
+            Some("https://example.com:8443".into()),
+        );
+        assert_eq!(Components::parse("urn:dev:ops:1").origin(), None);
+        assert_eq!(Components::parse("/relative").origin(), None);
+    }
+}
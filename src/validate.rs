@@ -0,0 +1,904 @@
+//! Validation of values against data schemas
+//!
+//! [`DataSchema::validate_value`] checks a JSON value, e.g. the payload of a property, against
+//! the constraints of a data schema. The Thing Description relies on JSON Schema for the
+//! semantic of the constraints, but some details are commonly handled differently by the
+//! consumers: the behavior can be tuned using [`ValidationOptions`] with
+//! [`DataSchema::validate_value_with`].
+//!
+//! The `pattern` of a string schema is only checked when the `regex` feature is enabled.
+//!
+//! # Example
+//!
+//! ```
+//! # use serde_json::json;
+//! # use wot_td::{
+//! #     thing::{DataSchema, DataSchemaSubtype, IntegerSchema},
+//! #     validate::{ValidationError, ValidationOptions},
+//! # };
+//! #
+//! let data_schema = DataSchema::<(), (), ()> {
+//!     subtype: Some(DataSchemaSubtype::Integer(IntegerSchema::default())),
+//!     ..Default::default()
+//! };
+//!
+//! assert_eq!(data_schema.validate_value(&json!(5.0)), Ok(()));
+//!
+//! let options = ValidationOptions {
+//!     integer_accepts_float_with_zero_fraction: false,
+//!     ..Default::default()
+//! };
+//! assert_eq!(
+//!     data_schema.validate_value_with(&json!(5.0), &options),
+//!     Err(ValidationError::NotAnInteger),
+//! );
+//! ```
+//!
+//! [`DataSchema::validate_value`]: crate::thing::DataSchema::validate_value
+//! [`DataSchema::validate_value_with`]: crate::thing::DataSchema::validate_value_with
+
+use alloc::{boxed::Box, format, string::String};
+
+use serde_json::{Map, Number, Value};
+
+use crate::thing::{
+    ArraySchema, BoxedElemOrVec, DataSchema, DataSchemaSubtype, IntegerSchema, Maximum, Minimum,
+    NumberSchema, ObjectSchema, StringSchema,
+};
+
+/// The options to tune the validation of values.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ValidationOptions {
+    /// Whether a number with a zero fractional part, like `5.0`, is a valid _integer_.
+    ///
+    /// JSON Schema does not distinguish between the two representations, therefore it defaults
+    /// to `true`.
+    pub integer_accepts_float_with_zero_fraction: bool,
+
+    /// Whether `minLength` and `maxLength` count the Unicode scalar values of a string instead of
+    /// its UTF-16 code units.
+    ///
+    /// It defaults to `false`, counting the UTF-16 code units like the `length` of ECMAScript
+    /// strings. Characters outside the Basic Multilingual Plane, like most emojis, count as two.
+    pub string_length_counts_unicode_scalar_values: bool,
+
+    /// The regular expression dialect used to interpret the `pattern` of string schemas.
+    ///
+    /// It defaults to [`PatternDialect::Ecma`], as required by JSON Schema.
+    pub pattern_dialect: PatternDialect,
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            integer_accepts_float_with_zero_fraction: true,
+            string_length_counts_unicode_scalar_values: false,
+            pattern_dialect: PatternDialect::default(),
+        }
+    }
+}
+
+/// The dialect of the regular expressions of string patterns.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PatternDialect {
+    /// ECMA-262 regular expressions, in which `\d`, `\w` and `\b` only consider ASCII characters.
+    #[default]
+    Ecma,
+
+    /// The syntax of the [`regex`](https://docs.rs/regex) crate, in which character classes are
+    /// Unicode-aware.
+    Rust,
+}
+
+/// A value not satisfying a data schema.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum ValidationError {
+    /// The value is not of the type required by the data schema.
+    #[error("Expected a value of type {0}")]
+    UnexpectedType(&'static str),
+
+    /// The value is a number, but not an integer.
+    #[error("The number is not an integer")]
+    NotAnInteger,
+
+    /// The value is different from the `const` of the data schema.
+    #[error("The value is different from the constant")]
+    NotConstant,
+
+    /// The value is not one of the `enum` values of the data schema.
+    #[error("The value is not one of the enumerated values")]
+    NotEnumerated,
+
+    /// The value is not valid against exactly one of the `oneOf` data schemas.
+    #[error("The value is valid against {0} schemas of oneOf instead of one")]
+    OneOfMismatch(usize),
+
+    /// The number is lower than the minimum.
+    #[error("The number is lower than the minimum")]
+    BelowMinimum,
+
+    /// The number is greater than the maximum.
+    #[error("The number is greater than the maximum")]
+    AboveMaximum,
+
+    /// The number is not a multiple of `multipleOf`.
+    #[error("The number is not a multiple of multipleOf")]
+    NotMultipleOf,
+
+    /// The string is shorter than `minLength`.
+    #[error("The string is shorter than minLength")]
+    TooShort,
+
+    /// The string is longer than `maxLength`.
+    #[error("The string is longer than maxLength")]
+    TooLong,
+
+    /// The string does not match the `pattern`.
+    #[error("The string does not match the pattern \"{0}\"")]
+    PatternMismatch(String),
+
+    /// The `pattern` is not a valid regular expression.
+    #[error("Invalid pattern \"{0}\"")]
+    InvalidPattern(String),
+
+    /// The array has less items than `minItems`.
+    #[error("The array has less items than minItems")]
+    TooFewItems,
+
+    /// The array has more items than `maxItems`.
+    #[error("The array has more items than maxItems")]
+    TooManyItems,
+
+    /// An item of an array is not valid.
+    #[error("Invalid item {0}: {1}")]
+    InvalidItem(usize, Box<ValidationError>),
+
+    /// A required property of an object is missing.
+    #[error("Missing required property \"{0}\"")]
+    MissingProperty(String),
+
+    /// A property of an object is not valid.
+    #[error("Invalid property \"{0}\": {1}")]
+    InvalidProperty(String, Box<ValidationError>),
+}
+
+/// The state threaded through the validation of a data schema and its nested schemas.
+pub(crate) struct SchemaContext<'a> {
+    options: &'a ValidationOptions,
+}
+
+impl<'a> SchemaContext<'a> {
+    pub(crate) fn new(options: &'a ValidationOptions) -> Self {
+        Self { options }
+    }
+
+    pub(crate) fn validate<DS, AS, OS>(
+        &self,
+        data_schema: &DataSchema<DS, AS, OS>,
+        value: &Value,
+    ) -> Result<(), ValidationError> {
+        if data_schema
+            .constant
+            .as_ref()
+            .is_some_and(|constant| !json_equal(constant, value))
+        {
+            return Err(ValidationError::NotConstant);
+        }
+
+        if data_schema.enumeration.as_ref().is_some_and(|enumeration| {
+            !enumeration
+                .iter()
+                .any(|enumerated| json_equal(enumerated, value))
+        }) {
+            return Err(ValidationError::NotEnumerated);
+        }
+
+        if let Some(one_of) = &data_schema.one_of {
+            let valid = one_of
+                .iter()
+                .filter(|data_schema| self.validate(data_schema, value).is_ok())
+                .count();
+            if valid != 1 {
+                return Err(ValidationError::OneOfMismatch(valid));
+            }
+        }
+
+        match &data_schema.subtype {
+            None => Ok(()),
+            Some(DataSchemaSubtype::Null) => match value {
+                Value::Null => Ok(()),
+                _ => Err(ValidationError::UnexpectedType("null")),
+            },
+            Some(DataSchemaSubtype::Boolean) => match value {
+                Value::Bool(_) => Ok(()),
+                _ => Err(ValidationError::UnexpectedType("boolean")),
+            },
+            Some(DataSchemaSubtype::Integer(integer)) => match value {
+                Value::Number(number) => self.validate_integer(integer, number),
+                _ => Err(ValidationError::UnexpectedType("integer")),
+            },
+            Some(DataSchemaSubtype::Number(number_schema)) => match value.as_f64() {
+                Some(number) => validate_number(number_schema, number),
+                None => Err(ValidationError::UnexpectedType("number")),
+            },
+            Some(DataSchemaSubtype::String(string)) => match value {
+                Value::String(value) => self.validate_string(string, value),
+                _ => Err(ValidationError::UnexpectedType("string")),
+            },
+            Some(DataSchemaSubtype::Array(array)) => match value {
+                Value::Array(items) => self.validate_array(array, items),
+                _ => Err(ValidationError::UnexpectedType("array")),
+            },
+            Some(DataSchemaSubtype::Object(object)) => match value {
+                Value::Object(members) => self.validate_object(object, members),
+                _ => Err(ValidationError::UnexpectedType("object")),
+            },
+        }
+    }
+
+    fn validate_integer(
+        &self,
+        integer: &IntegerSchema,
+        number: &Number,
+    ) -> Result<(), ValidationError> {
+        let exact = number
+            .as_i64()
+            .map(i128::from)
+            .or_else(|| number.as_u64().map(i128::from));
+
+        let Some(value) = exact else {
+            let value = number.as_f64().unwrap_or(f64::NAN);
+            if !self.options.integer_accepts_float_with_zero_fraction || !is_integral(value) {
+                return Err(ValidationError::NotAnInteger);
+            }
+
+            // An integral float in the range of `i128` is converted exactly, therefore it is
+            // compared with the bounds without rounding them (`i64::MAX as f64` is 2^63).
+            if value.abs() < i128::MAX as f64 {
+                return self.validate_exact_integer(integer, value as i128);
+            }
+
+            let bounds = NumberSchema {
+                minimum: integer.minimum.map(|minimum| match minimum {
+                    Minimum::Inclusive(x) => Minimum::Inclusive(x as f64),
+                    Minimum::Exclusive(x) => Minimum::Exclusive(x as f64),
+                }),
+                maximum: integer.maximum.map(|maximum| match maximum {
+                    Maximum::Inclusive(x) => Maximum::Inclusive(x as f64),
+                    Maximum::Exclusive(x) => Maximum::Exclusive(x as f64),
+                }),
+                multiple_of: integer
+                    .multiple_of
+                    .map(|multiple_of| multiple_of.get() as f64),
+            };
+            return validate_number(&bounds, value);
+        };
+
+        self.validate_exact_integer(integer, value)
+    }
+
+    fn validate_exact_integer(
+        &self,
+        integer: &IntegerSchema,
+        value: i128,
+    ) -> Result<(), ValidationError> {
+        match integer.minimum {
+            Some(Minimum::Inclusive(minimum)) if value < i128::from(minimum) => {
+                return Err(ValidationError::BelowMinimum)
+            }
+            Some(Minimum::Exclusive(minimum)) if value <= i128::from(minimum) => {
+                return Err(ValidationError::BelowMinimum)
+            }
+            _ => {}
+        }
+
+        match integer.maximum {
+            Some(Maximum::Inclusive(maximum)) if value > i128::from(maximum) => {
+                return Err(ValidationError::AboveMaximum)
+            }
+            Some(Maximum::Exclusive(maximum)) if value >= i128::from(maximum) => {
+                return Err(ValidationError::AboveMaximum)
+            }
+            _ => {}
+        }
+
+        if integer
+            .multiple_of
+            .is_some_and(|multiple_of| value % i128::from(multiple_of.get()) != 0)
+        {
+            return Err(ValidationError::NotMultipleOf);
+        }
+
+        Ok(())
+    }
+
+    fn validate_string(&self, string: &StringSchema, value: &str) -> Result<(), ValidationError> {
+        let length = if self.options.string_length_counts_unicode_scalar_values {
+            value.chars().count()
+        } else {
+            value.encode_utf16().count()
+        };
+
+        if string
+            .min_length
+            .is_some_and(|min_length| length < min_length as usize)
+        {
+            return Err(ValidationError::TooShort);
+        }
+
+        if string
+            .max_length
+            .is_some_and(|max_length| length > max_length as usize)
+        {
+            return Err(ValidationError::TooLong);
+        }
+
+        match &string.pattern {
+            Some(pattern) if !self.matches_pattern(pattern, value)? => {
+                Err(ValidationError::PatternMismatch(pattern.clone()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    fn matches_pattern(&self, pattern: &str, value: &str) -> Result<bool, ValidationError> {
+        let translated;
+        let regex = match self.options.pattern_dialect {
+            PatternDialect::Ecma => {
+                translated = ecma_to_rust(pattern);
+                translated.as_str()
+            }
+            PatternDialect::Rust => pattern,
+        };
+
+        regex::Regex::new(regex)
+            .map(|regex| regex.is_match(value))
+            .map_err(|_| ValidationError::InvalidPattern(pattern.into()))
+    }
+
+    #[cfg(not(feature = "regex"))]
+    fn matches_pattern(&self, _pattern: &str, _value: &str) -> Result<bool, ValidationError> {
+        Ok(true)
+    }
+
+    fn validate_array<DS, AS, OS>(
+        &self,
+        array: &ArraySchema<DS, AS, OS>,
+        items: &[Value],
+    ) -> Result<(), ValidationError> {
+        if array
+            .min_items
+            .is_some_and(|min_items| items.len() < min_items as usize)
+        {
+            return Err(ValidationError::TooFewItems);
+        }
+
+        if array
+            .max_items
+            .is_some_and(|max_items| items.len() > max_items as usize)
+        {
+            return Err(ValidationError::TooManyItems);
+        }
+
+        let validate_item = |index, data_schema, item| {
+            self.validate(data_schema, item)
+                .map_err(|err| ValidationError::InvalidItem(index, Box::new(err)))
+        };
+
+        match &array.items {
+            None => Ok(()),
+            Some(BoxedElemOrVec::Elem(data_schema)) => items
+                .iter()
+                .enumerate()
+                .try_for_each(|(index, item)| validate_item(index, data_schema, item)),
+            Some(BoxedElemOrVec::Vec(data_schemas)) => {
+                data_schemas.iter().zip(items).enumerate().try_for_each(
+                    |(index, (data_schema, item))| validate_item(index, data_schema, item),
+                )
+            }
+        }
+    }
+
+    fn validate_object<DS, AS, OS>(
+        &self,
+        object: &ObjectSchema<DS, AS, OS>,
+        members: &Map<String, Value>,
+    ) -> Result<(), ValidationError> {
+        if let Some(name) = object
+            .required
+            .iter()
+            .flatten()
+            .find(|name| !members.contains_key(name.as_str()))
+        {
+            return Err(ValidationError::MissingProperty(name.clone()));
+        }
+
+        object
+            .properties
+            .iter()
+            .flatten()
+            .filter_map(|(name, data_schema)| {
+                members.get(name).map(|member| (name, data_schema, member))
+            })
+            .try_for_each(|(name, data_schema, member)| {
+                self.validate(data_schema, member)
+                    .map_err(|err| ValidationError::InvalidProperty(name.clone(), Box::new(err)))
+            })
+    }
+}
+
+fn validate_number(number: &NumberSchema, value: f64) -> Result<(), ValidationError> {
+    match number.minimum {
+        Some(Minimum::Inclusive(minimum)) if value < minimum => {
+            return Err(ValidationError::BelowMinimum)
+        }
+        Some(Minimum::Exclusive(minimum)) if value <= minimum => {
+            return Err(ValidationError::BelowMinimum)
+        }
+        _ => {}
+    }
+
+    match number.maximum {
+        Some(Maximum::Inclusive(maximum)) if value > maximum => {
+            return Err(ValidationError::AboveMaximum)
+        }
+        Some(Maximum::Exclusive(maximum)) if value >= maximum => {
+            return Err(ValidationError::AboveMaximum)
+        }
+        _ => {}
+    }
+
+    if number
+        .multiple_of
+        .is_some_and(|multiple_of| !is_multiple_of(value, multiple_of))
+    {
+        return Err(ValidationError::NotMultipleOf);
+    }
+
+    Ok(())
+}
+
+/// Compares two JSON values like JSON Schema, where the numbers are equal when they have the same
+/// mathematical value: `1` equals `1.0` and `0` equals `-0.0`.
+///
+/// The integers are compared exactly, therefore `u64::MAX` is not equal to the closest float.
+fn json_equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => number_equal(a, b),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| json_equal(a, b))
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, a)| b.get(key).is_some_and(|b| json_equal(a, b)))
+        }
+        (a, b) => a == b,
+    }
+}
+
+fn number_equal(a: &Number, b: &Number) -> bool {
+    let exact = |number: &Number| {
+        number
+            .as_i64()
+            .map(i128::from)
+            .or_else(|| number.as_u64().map(i128::from))
+    };
+
+    match (exact(a), exact(b)) {
+        (Some(a), Some(b)) => a == b,
+        (Some(integer), None) | (None, Some(integer)) => {
+            let float = if exact(a).is_some() { b } else { a };
+            float.as_f64().is_some_and(|float| {
+                // An integral float in the range of `i128` is converted exactly.
+                is_integral(float) && float.abs() < i128::MAX as f64 && float as i128 == integer
+            })
+        }
+        (None, None) => a.as_f64() == b.as_f64(),
+    }
+}
+
+/// Checks whether `x` has no fractional part, without relying on `f64::fract` from `std`.
+fn is_integral(x: f64) -> bool {
+    // Every float with a magnitude of at least 2^53 is an integer.
+    const EXACT_LIMIT: f64 = (1u64 << f64::MANTISSA_DIGITS) as f64;
+
+    if (-EXACT_LIMIT..=EXACT_LIMIT).contains(&x) {
+        x as i64 as f64 == x
+    } else {
+        x.is_finite()
+    }
+}
+
+/// Checks whether `value` is an integer multiple of `step`, see [`NumberSchema::is_multiple`].
+pub(crate) fn is_multiple_of(value: f64, step: f64) -> bool {
+    // The number of significant digits that an `f64` always preserves.
+    const DECIMAL_DIGITS: usize = f64::DIGITS as usize;
+
+    if !value.is_finite() || !step.is_finite() || step == 0. {
+        return false;
+    }
+    if value == 0. {
+        return true;
+    }
+
+    match decimal(step) {
+        Some((step_mantissa, step_exponent, digits)) if digits <= DECIMAL_DIGITS => {
+            let (value_mantissa, value_exponent, _) =
+                decimal(value).expect("value is finite and non-zero");
+
+            if value_exponent >= step_exponent {
+                // value_mantissa * 10^k must be divisible by step_mantissa.
+                let mut remainder = value_mantissa % step_mantissa;
+                for _ in step_exponent..value_exponent {
+                    if remainder == 0 {
+                        break;
+                    }
+                    remainder = remainder * 10 % step_mantissa;
+                }
+                remainder == 0
+            } else {
+                // value_mantissa must be divisible by step_mantissa * 10^k, which is greater than
+                // any mantissa with at most 17 digits when k > 17.
+                let scale = u32::try_from(step_exponent - value_exponent).unwrap_or(u32::MAX);
+                10u128
+                    .checked_pow(scale)
+                    .and_then(|scale| scale.checked_mul(step_mantissa))
+                    .is_some_and(|step| value_mantissa % step == 0)
+            }
+        }
+        _ => {
+            let quotient = value / step;
+            if is_integral(quotient) {
+                return true;
+            }
+            if !quotient.is_finite() {
+                return false;
+            }
+
+            // `quotient` is smaller than 2^53 here, so it fits an `i64`.
+            let nearest = if quotient < 0. {
+                (quotient - 0.5) as i64
+            } else {
+                (quotient + 0.5) as i64
+            };
+            let tolerance = 4. * f64::EPSILON * quotient.abs().max(1.);
+            (quotient - nearest as f64).abs() <= tolerance
+        }
+    }
+}
+
+/// Returns the shortest decimal representation of a finite non-zero float, as a mantissa, an
+/// exponent of ten and the number of significant digits.
+fn decimal(x: f64) -> Option<(u128, i32, usize)> {
+    // The `Display` implementation of `LowerExp` prints the shortest representation that
+    // round-trips, e.g. `1e-1` for `0.1`.
+    let repr = format!("{:e}", x.abs());
+    let (mantissa, exponent) = repr.split_once('e')?;
+    let mut exponent: i32 = exponent.parse().ok()?;
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    exponent -= i32::try_from(fraction.len()).ok()?;
+
+    let digits = integer.len() + fraction.len();
+    let mantissa = format!("{integer}{fraction}").parse().ok()?;
+    (mantissa != 0).then_some((mantissa, exponent, digits))
+}
+
+/// Translates an ECMA-262 regular expression into the syntax of the `regex` crate.
+///
+/// The shorthand classes and the word boundaries are restricted to ASCII characters, `[\b]` is
+/// the backspace character and the characters with a special meaning only inside the classes of
+/// the `regex` crate are escaped.
+#[cfg(feature = "regex")]
+fn ecma_to_rust(pattern: &str) -> String {
+    let mut translated = String::with_capacity(pattern.len());
+    let mut in_class = false;
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('d') => translated.push_str("[0-9]"),
+                Some('D') => translated.push_str("[^0-9]"),
+                Some('w') => translated.push_str("[0-9A-Za-z_]"),
+                Some('W') => translated.push_str("[^0-9A-Za-z_]"),
+                Some('b') if in_class => translated.push_str("\\x08"),
+                Some(c @ ('b' | 'B')) => {
+                    translated.push_str("(?-u:\\");
+                    translated.push(c);
+                    translated.push(')');
+                }
+                Some(c) => {
+                    translated.push('\\');
+                    translated.push(c);
+                }
+                None => translated.push('\\'),
+            },
+            '[' if !in_class => {
+                in_class = true;
+                translated.push('[');
+            }
+            ']' if in_class => {
+                in_class = false;
+                translated.push(']');
+            }
+            '[' | '&' | '~' if in_class => {
+                translated.push('\\');
+                translated.push(c);
+            }
+            c => translated.push(c),
+        }
+    }
+
+    translated
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{hlist::Nil, thing::DataSchemaFromOther};
+
+    use super::*;
+
+    fn data_schema(value: Value) -> DataSchemaFromOther<Nil> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn numeric_const_and_enum() {
+        let negative_zero = data_schema(json!({ "const": -0.0 }));
+        for value in [json!(0), json!(0.0), json!(-0.0)] {
+            assert_eq!(negative_zero.validate_value(&value), Ok(()));
+        }
+        assert_eq!(
+            negative_zero.validate_value(&json!(1e-300)),
+            Err(ValidationError::NotConstant),
+        );
+
+        let largest = data_schema(json!({ "type": "integer", "const": u64::MAX }));
+        assert_eq!(largest.validate_value(&json!(u64::MAX)), Ok(()));
+        for value in [json!(u64::MAX - 1), json!(u64::MAX as f64), json!(-1)] {
+            assert_eq!(
+                largest.validate_value(&value),
+                Err(ValidationError::NotConstant),
+            );
+        }
+
+        let enumeration = data_schema(json!({ "enum": [1, i64::MIN, u64::MAX, [0.5, 2]] }));
+        for value in [
+            json!(1.0),
+            json!(i64::MIN),
+            json!(i64::MIN as f64),
+            json!(u64::MAX),
+            json!([0.5, 2.0]),
+        ] {
+            assert_eq!(enumeration.validate_value(&value), Ok(()), "{value}");
+        }
+        for value in [json!(1.5), json!(u64::MAX as f64), json!([0.5]), json!("1")] {
+            assert_eq!(
+                enumeration.validate_value(&value),
+                Err(ValidationError::NotEnumerated),
+                "{value}",
+            );
+        }
+
+        let bounded = data_schema(json!({ "type": "integer", "maximum": i64::MAX }));
+        assert_eq!(
+            bounded.validate_value(&json!(u64::MAX)),
+            Err(ValidationError::AboveMaximum),
+        );
+        assert_eq!(
+            bounded.validate_value(&json!(i64::MAX as u64 + 1)),
+            Err(ValidationError::AboveMaximum),
+        );
+        assert_eq!(bounded.validate_value(&json!(i64::MAX)), Ok(()));
+    }
+
+    #[test]
+    fn integer_accepts_float_with_zero_fraction() {
+        let data_schema = data_schema(json!({
+            "type": "integer",
+            "minimum": 0,
+            "maximum": 10,
+            "multipleOf": 5,
+        }));
+        let strict = ValidationOptions {
+            integer_accepts_float_with_zero_fraction: false,
+            ..Default::default()
+        };
+
+        assert_eq!(data_schema.validate_value(&json!(5)), Ok(()));
+        assert_eq!(data_schema.validate_value(&json!(5.0)), Ok(()));
+        assert_eq!(
+            data_schema.validate_value(&json!(5.5)),
+            Err(ValidationError::NotAnInteger),
+        );
+        assert_eq!(
+            data_schema.validate_value(&json!(15.0)),
+            Err(ValidationError::AboveMaximum),
+        );
+        assert_eq!(data_schema.validate_value_with(&json!(5), &strict), Ok(()));
+        assert_eq!(
+            data_schema.validate_value_with(&json!(5.0), &strict),
+            Err(ValidationError::NotAnInteger),
+        );
+    }
+
+    #[test]
+    fn integer_float_representation() {
+        let integer = data_schema(json!({ "type": "integer" }));
+        assert_eq!(integer.validate_value(&json!(3.0)), Ok(()));
+        assert_eq!(
+            integer.validate_value(&json!(3.5)),
+            Err(ValidationError::NotAnInteger),
+        );
+        assert_eq!(integer.validate_value(&json!(1e300)), Ok(()),);
+
+        let bounded = data_schema(json!({
+            "type": "integer",
+            "maximum": i64::MAX,
+            "multipleOf": 7,
+        }));
+        assert_eq!(bounded.validate_value(&json!(i64::MAX)), Ok(()));
+        assert_eq!(
+            bounded.validate_value(&json!(i64::MAX as u64 + 1)),
+            Err(ValidationError::AboveMaximum),
+        );
+        // 2^63 is integral, but it is greater than `i64::MAX` even if they have the same `f64`
+        // representation.
+        assert_eq!(
+            bounded.validate_value(&json!(9223372036854775808.0)),
+            Err(ValidationError::AboveMaximum),
+        );
+        assert_eq!(bounded.validate_value(&json!(i64::MAX - 7)), Ok(()),);
+        assert_eq!(
+            bounded.validate_value(&json!(i64::MAX - 1)),
+            Err(ValidationError::NotMultipleOf),
+        );
+        // 2^62 is not a multiple of 7, the check must not be approximated.
+        assert_eq!(
+            bounded.validate_value(&json!(4611686018427387904.0)),
+            Err(ValidationError::NotMultipleOf),
+        );
+    }
+
+    #[test]
+    fn string_length_counts_unicode_scalar_values() {
+        let data_schema = data_schema(json!({
+            "type": "string",
+            "maxLength": 2,
+        }));
+        let scalar_values = ValidationOptions {
+            string_length_counts_unicode_scalar_values: true,
+            ..Default::default()
+        };
+
+        assert_eq!(data_schema.validate_value(&json!("éè")), Ok(()));
+        assert_eq!(
+            data_schema.validate_value(&json!("🌡🌡")),
+            Err(ValidationError::TooLong),
+        );
+        assert_eq!(
+            data_schema.validate_value_with(&json!("🌡🌡"), &scalar_values),
+            Ok(()),
+        );
+        assert_eq!(
+            data_schema.validate_value_with(&json!("🌡🌡🌡"), &scalar_values),
+            Err(ValidationError::TooLong),
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn pattern_dialect() {
+        let data_schema = data_schema(json!({
+            "type": "string",
+            "pattern": r"^\d+$",
+        }));
+        let rust = ValidationOptions {
+            pattern_dialect: PatternDialect::Rust,
+            ..Default::default()
+        };
+
+        assert_eq!(data_schema.validate_value(&json!("123")), Ok(()));
+        assert_eq!(
+            data_schema.validate_value(&json!("١٢٣")),
+            Err(ValidationError::PatternMismatch(r"^\d+$".into())),
+        );
+        assert_eq!(
+            data_schema.validate_value_with(&json!("١٢٣"), &rust),
+            Ok(())
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn ecma_pattern_translation() {
+        assert_eq!(ecma_to_rust(r"^\w+\b"), r"^[0-9A-Za-z_]+(?-u:\b)");
+        assert_eq!(ecma_to_rust(r"[\d\b&&[]"), r"[[0-9]\x08\&\&\[]");
+        assert_eq!(ecma_to_rust(r"[^\]a]\]"), r"[^\]a]\]");
+    }
+
+    #[test]
+    fn nested_values() {
+        let data_schema = data_schema(json!({
+            "type": "object",
+            "properties": {
+                "tags": {
+                    "type": "array",
+                    "items": { "type": "string", "enum": ["a", "b"] },
+                },
+                "level": {
+                    "oneOf": [
+                        { "type": "number", "maximum": 1. },
+                        { "type": "null" },
+                    ],
+                },
+            },
+            "required": ["level"],
+        }));
+
+        assert_eq!(
+            data_schema.validate_value(&json!({ "tags": ["a"], "level": null })),
+            Ok(()),
+        );
+        assert_eq!(
+            data_schema.validate_value(&json!({ "tags": [] })),
+            Err(ValidationError::MissingProperty("level".into())),
+        );
+        assert_eq!(
+            data_schema.validate_value(&json!({ "tags": ["a", "c"], "level": 0.5 })),
+            Err(ValidationError::InvalidProperty(
+                "tags".into(),
+                Box::new(ValidationError::InvalidItem(
+                    1,
+                    Box::new(ValidationError::NotEnumerated)
+                )),
+            )),
+        );
+        assert_eq!(
+            data_schema.validate_value(&json!({ "level": 2 })),
+            Err(ValidationError::InvalidProperty(
+                "level".into(),
+                Box::new(ValidationError::OneOfMismatch(0)),
+            )),
+        );
+    }
+
+    #[test]
+    fn decimal_multiple_of() {
+        let number = |multiple_of| NumberSchema {
+            multiple_of: Some(multiple_of),
+            ..Default::default()
+        };
+
+        assert_eq!(number(0.1).is_multiple(0.3), Some(true));
+        assert_eq!(number(0.1).is_multiple(-0.7), Some(true));
+        assert_eq!(number(0.01).is_multiple(1.23), Some(true));
+        assert_eq!(number(0.1).is_multiple(0.35), Some(false));
+        assert_eq!(number(0.2).is_multiple(0.3), Some(false));
+        assert_eq!(number(2.5).is_multiple(1e300), Some(true));
+        assert_eq!(number(0.1).is_multiple(0.), Some(true));
+        assert_eq!(number(0.1).is_multiple(f64::NAN), Some(false));
+        assert_eq!(number(0.1).is_multiple(f64::INFINITY), Some(false));
+        assert_eq!(NumberSchema::default().is_multiple(0.3), None);
+
+        assert_eq!(number(1e-17).is_multiple(3e-17), Some(true));
+        assert_eq!(number(1e-17).is_multiple(1.), Some(true));
+        assert_eq!(number(1e-17).is_multiple(1.5e-17), Some(false));
+        assert_eq!(number(0.1).is_multiple(1e-17), Some(false));
+
+        // More than 15 significant digits use the relative epsilon.
+        let third = 1. / 3.;
+        assert_eq!(number(third).is_multiple(1.), Some(true));
+        assert_eq!(number(third).is_multiple(2.), Some(true));
+        assert_eq!(number(third).is_multiple(0.5), Some(false));
+
+        assert_eq!(
+            data_schema(json!({ "type": "number", "multipleOf": 0.1 })).validate_value(&json!(0.3)),
+            Ok(()),
+        );
+    }
+}
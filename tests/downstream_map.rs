@@ -0,0 +1,62 @@
+//! A downstream crate exposing the types of `wot_td` in its API, naming the maps through
+//! `wot_td::Map` without depending on `hashbrown`.
+
+use wot_td::Thing;
+
+mod registry {
+    use wot_td::{
+        hlist::Nil,
+        thing::{PropertyAffordance, SecurityScheme},
+        Map, Thing,
+    };
+
+    #[derive(Default)]
+    pub struct Registry {
+        things: Map<String, Thing>,
+    }
+
+    impl Registry {
+        pub fn register(&mut self, id: impl Into<String>, thing: Thing) {
+            self.things.insert(id.into(), thing);
+        }
+
+        pub fn things(&self) -> &Map<String, Thing> {
+            &self.things
+        }
+
+        pub fn properties(&self, id: &str) -> Option<&Map<String, PropertyAffordance<Nil>>> {
+            self.things.get(id)?.properties.as_ref()
+        }
+
+        pub fn security_definitions(&self, id: &str) -> Option<&Map<String, SecurityScheme>> {
+            Some(&self.things.get(id)?.security_definitions)
+        }
+    }
+}
+
+#[test]
+fn downstream_api_names_map() {
+    use wot_td::builder::{
+        affordance::BuildableInteractionAffordance, data_schema::SpecializableDataSchema,
+    };
+
+    let thing = Thing::builder("Lamp")
+        .finish_extend()
+        .security(|b| b.no_sec().with_key("nosec_sc").required())
+        .property("on", |b| {
+            b.finish_extend_data_schema().bool().form(|b| b.href("/on"))
+        })
+        .build()
+        .unwrap();
+
+    let mut registry = registry::Registry::default();
+    registry.register("lamp", thing);
+
+    let properties: &wot_td::Map<String, _> = registry.properties("lamp").unwrap();
+    assert!(properties.contains_key("on"));
+    assert!(registry
+        .security_definitions("lamp")
+        .unwrap()
+        .contains_key("nosec_sc"));
+    assert_eq!(registry.things().len(), 1);
+}
@@ -0,0 +1,7 @@
+use wot_td::{builder::data_schema::SpecializableDataSchema, thing::Thing};
+
+fn main() {
+    let _ = Thing::builder("Thing name")
+        .property("on", |b| b.finish_extend_data_schema().bool())
+        .build();
+}
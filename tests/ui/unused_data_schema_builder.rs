@@ -0,0 +1,17 @@
+#![deny(unused_must_use)]
+
+use wot_td::{
+    builder::data_schema::{
+        DataSchemaBuilder, NumberDataSchemaBuilderLike, ReadableWriteableDataSchema,
+        SpecializableDataSchema,
+    },
+    hlist::Nil,
+};
+
+fn main() {
+    let schema = DataSchemaBuilder::<Nil, Nil, Nil, _>::default().number();
+    schema.minimum(0.);
+
+    let schema = DataSchemaBuilder::<Nil, Nil, Nil, _>::default().bool();
+    schema.read_only();
+}